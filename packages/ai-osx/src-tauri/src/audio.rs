@@ -0,0 +1,282 @@
+// Audio Module - Field Resonance Analysis
+// Spectral analysis of the captured field buffer: resonant peaks, Q factors
+// and harmonic structure
+
+use crate::models::*;
+use std::collections::VecDeque;
+use std::f64::consts::PI;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{info, debug};
+use chrono::Utc;
+use anyhow::{Result, anyhow};
+
+pub const DEFAULT_SAMPLE_RATE_HZ: u32 = 48_000;
+pub const DEFAULT_WINDOW_SIZE: usize = 4096;
+
+/// Peaks weaker than this, relative to the strongest, are not resonances
+const PEAK_THRESHOLD_DB: f64 = -20.0;
+const MAX_RESONANT_PEAKS: usize = 8;
+
+#[derive(Debug)]
+pub struct ResonanceEngine {
+    sample_rate_hz: u32,
+    window_size: usize,
+    /// Most recent `window_size` samples of the field
+    field_buffer: Arc<RwLock<VecDeque<f32>>>,
+    state: Arc<RwLock<ResonanceState>>,
+}
+
+impl ResonanceEngine {
+    pub async fn new() -> Result<Self> {
+        Self::with_config(DEFAULT_SAMPLE_RATE_HZ, DEFAULT_WINDOW_SIZE)
+    }
+
+    pub fn with_config(sample_rate_hz: u32, window_size: usize) -> Result<Self> {
+        if sample_rate_hz == 0 || window_size < 4 {
+            return Err(anyhow!(
+                "Invalid resonance config: {} Hz, window of {} samples",
+                sample_rate_hz, window_size
+            ));
+        }
+
+        info!("Initializing Resonance Engine at {} Hz, {} sample window", sample_rate_hz, window_size);
+
+        Ok(Self {
+            sample_rate_hz,
+            window_size,
+            field_buffer: Arc::new(RwLock::new(VecDeque::with_capacity(window_size))),
+            state: Arc::new(RwLock::new(ResonanceState::default())),
+        })
+    }
+
+    /// Append captured samples, keeping only the latest analysis window
+    pub async fn push_samples(&self, samples: &[f32]) {
+        let mut buffer = self.field_buffer.write().await;
+        buffer.extend(samples.iter().copied());
+        let excess = buffer.len().saturating_sub(self.window_size);
+        buffer.drain(..excess);
+    }
+
+    pub async fn analyze_current_field(&self) -> Result<ResonanceAnalysis> {
+        let samples: Vec<f32> = self.field_buffer.read().await.iter().copied().collect();
+        if samples.is_empty() {
+            return Err(anyhow!("Field buffer is empty; no samples to analyze"));
+        }
+
+        let analysis = analyze_spectrum(&samples, self.sample_rate_hz);
+        debug!(
+            "Resonance analysis found {} peaks, dominant {:?} Hz",
+            analysis.resonant_peaks.len(), analysis.dominant_frequency_hz
+        );
+
+        let mut state = self.state.write().await;
+        state.frequency = analysis.dominant_frequency_hz.unwrap_or(state.frequency);
+        state.amplitude = analysis.rms_level;
+        state.harmonics = analysis.harmonic_ratios.clone();
+        state.last_analysis = analysis.analyzed_at;
+
+        Ok(analysis)
+    }
+
+    pub async fn get_state(&self) -> ResonanceState {
+        self.state.read().await.clone()
+    }
+}
+
+/// Hann-windowed spectrum of `samples`, zero-padded to a power of two, with
+/// local maxima above `PEAK_THRESHOLD_DB` reported as resonant peaks.
+/// Peak frequencies are refined by parabolic interpolation of the log
+/// magnitude; Q is measured against the interpolated half-power bandwidth,
+/// so it is bounded by the window's frequency resolution.
+pub fn analyze_spectrum(samples: &[f32], sample_rate_hz: u32) -> ResonanceAnalysis {
+    let n = samples.len().next_power_of_two().max(4);
+    let bin_hz = sample_rate_hz as f64 / n as f64;
+
+    let mut re: Vec<f64> = apodize::hanning_iter(samples.len())
+        .zip(samples)
+        .map(|(w, &x)| w * x as f64)
+        .collect();
+    re.resize(n, 0.0);
+    let mut im = vec![0.0; n];
+    fft(&mut re, &mut im);
+
+    let magnitudes: Vec<f64> = (0..=n / 2).map(|k| re[k].hypot(im[k])).collect();
+    // A full-scale sine under a Hann window peaks at len / 4
+    let full_scale = samples.len().max(1) as f64 / 4.0;
+    let strongest = magnitudes[1..].iter().cloned().fold(0.0, f64::max);
+    let threshold = strongest * 10f64.powf(PEAK_THRESHOLD_DB / 20.0);
+
+    let mut peaks: Vec<ResonantPeak> = (1..magnitudes.len() - 1)
+        .filter(|&k| {
+            magnitudes[k] > 0.0
+                && magnitudes[k] >= threshold
+                && magnitudes[k] > magnitudes[k - 1]
+                && magnitudes[k] >= magnitudes[k + 1]
+        })
+        .map(|k| {
+            let (offset, peak_magnitude) = interpolate_peak(&magnitudes, k);
+            let frequency_hz = (k as f64 + offset) * bin_hz;
+            let bandwidth_hz = half_power_bandwidth(&magnitudes, k, peak_magnitude) * bin_hz;
+            ResonantPeak {
+                frequency_hz: frequency_hz as f32,
+                magnitude_db: (20.0 * (peak_magnitude / full_scale).log10()) as f32,
+                q_factor: (frequency_hz / bandwidth_hz.max(f64::EPSILON)) as f32,
+            }
+        })
+        .collect();
+    peaks.sort_by(|a, b| b.magnitude_db.total_cmp(&a.magnitude_db));
+    peaks.truncate(MAX_RESONANT_PEAKS);
+
+    let fundamental_hz = peaks.iter().map(|p| p.frequency_hz).min_by(f32::total_cmp);
+    let mut harmonic_ratios: Vec<f32> = match fundamental_hz {
+        Some(f0) => peaks.iter().map(|p| p.frequency_hz / f0).collect(),
+        None => Vec::new(),
+    };
+    harmonic_ratios.sort_by(f32::total_cmp);
+
+    let power_total: f64 = magnitudes.iter().map(|m| m * m).sum();
+    let spectral_centroid_hz = if power_total > 0.0 {
+        magnitudes.iter().enumerate().map(|(k, m)| k as f64 * bin_hz * m * m).sum::<f64>() / power_total
+    } else {
+        0.0
+    };
+    let rms_level = (samples.iter().map(|&x| (x as f64).powi(2)).sum::<f64>() / samples.len().max(1) as f64).sqrt();
+
+    ResonanceAnalysis {
+        sample_rate_hz,
+        window_size: samples.len(),
+        frequency_resolution_hz: bin_hz as f32,
+        dominant_frequency_hz: peaks.first().map(|p| p.frequency_hz),
+        resonant_peaks: peaks,
+        fundamental_hz,
+        harmonic_ratios,
+        spectral_centroid_hz: spectral_centroid_hz as f32,
+        rms_level: rms_level as f32,
+        analyzed_at: Utc::now(),
+    }
+}
+
+/// Fractional bin offset and magnitude of the vertex of a parabola fitted
+/// through the log magnitudes around bin `k`
+fn interpolate_peak(magnitudes: &[f64], k: usize) -> (f64, f64) {
+    let ln = |m: f64| m.max(f64::MIN_POSITIVE).ln();
+    let (alpha, beta, gamma) = (ln(magnitudes[k - 1]), ln(magnitudes[k]), ln(magnitudes[k + 1]));
+    let denominator = alpha - 2.0 * beta + gamma;
+    if denominator.abs() < f64::EPSILON {
+        return (0.0, magnitudes[k]);
+    }
+    let offset = (0.5 * (alpha - gamma) / denominator).clamp(-0.5, 0.5);
+    (offset, (beta - 0.25 * (alpha - gamma) * offset).exp())
+}
+
+/// Width in bins between the points either side of `k` where the magnitude
+/// falls below `peak / √2`, linearly interpolated between bins
+fn half_power_bandwidth(magnitudes: &[f64], k: usize, peak: f64) -> f64 {
+    let half_power = peak / std::f64::consts::SQRT_2;
+    let crossing = |inside: usize, outside: usize| {
+        let (a, b) = (magnitudes[inside], magnitudes[outside]);
+        let fraction = if a > b { (a - half_power) / (a - b) } else { 0.0 };
+        inside as f64 + (outside as f64 - inside as f64) * fraction.clamp(0.0, 1.0)
+    };
+
+    let mut left = k;
+    while left > 0 && magnitudes[left - 1] >= half_power {
+        left -= 1;
+    }
+    let mut right = k;
+    while right + 1 < magnitudes.len() && magnitudes[right + 1] >= half_power {
+        right += 1;
+    }
+
+    let lower = if left > 0 { crossing(left, left - 1) } else { 0.0 };
+    let upper = if right + 1 < magnitudes.len() { crossing(right, right + 1) } else { right as f64 };
+    upper - lower
+}
+
+/// In-place iterative radix-2 FFT; `re.len()` must be a power of two
+fn fft(re: &mut [f64], im: &mut [f64]) {
+    let n = re.len();
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let step = -2.0 * PI / len as f64;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (step * k as f64).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let t_re = re[b] * cos - im[b] * sin;
+                let t_im = re[b] * sin + im[b] * cos;
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] += t_re;
+                im[a] += t_im;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tones(sample_rate_hz: u32, len: usize, components: &[(f64, f64)]) -> Vec<f32> {
+        (0..len)
+            .map(|i| {
+                let t = i as f64 / sample_rate_hz as f64;
+                components.iter().map(|(hz, amp)| amp * (2.0 * PI * hz * t).sin()).sum::<f64>() as f32
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_detects_resonant_frequencies_of_multi_tone_field() {
+        let engine = ResonanceEngine::with_config(8_000, 4096).unwrap();
+        assert!(engine.analyze_current_field().await.is_err());
+
+        // A 220 Hz fundamental with two weaker harmonics, pushed in chunks
+        let field = tones(8_000, 4096, &[(220.0, 1.0), (440.0, 0.5), (660.0, 0.25)]);
+        for chunk in field.chunks(1000) {
+            engine.push_samples(chunk).await;
+        }
+
+        let analysis = engine.analyze_current_field().await.unwrap();
+        let mut detected: Vec<f32> = analysis.resonant_peaks.iter().map(|p| p.frequency_hz).collect();
+        detected.sort_by(f32::total_cmp);
+
+        assert_eq!(detected.len(), 3, "peaks: {:?}", analysis.resonant_peaks);
+        for (found, expected) in detected.iter().zip([220.0, 440.0, 660.0]) {
+            assert!((found - expected).abs() < 1.0, "{} vs {}", found, expected);
+        }
+        assert!((analysis.dominant_frequency_hz.unwrap() - 220.0).abs() < 1.0);
+        for (ratio, expected) in analysis.harmonic_ratios.iter().zip([1.0, 2.0, 3.0]) {
+            assert!((ratio - expected).abs() < 0.01);
+        }
+
+        // Magnitudes are relative to full scale and Q grows with frequency
+        // at a fixed resolution
+        let strongest = &analysis.resonant_peaks[0];
+        assert!(strongest.magnitude_db.abs() < 0.5);
+        assert!((analysis.resonant_peaks[1].magnitude_db + 6.0).abs() < 0.5);
+        assert!(analysis.resonant_peaks[2].q_factor > strongest.q_factor);
+
+        let state = engine.get_state().await;
+        assert_eq!(state.harmonics.len(), 3);
+        assert!((state.frequency - 220.0).abs() < 1.0);
+    }
+}
@@ -0,0 +1,631 @@
+// Braun Module - Computational Layer
+// Implements the "Braun" component of the Brain-Braun-Beyond architecture
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, debug};
+use anyhow::Result;
+use nalgebra::{DMatrix, DVector};
+use uuid::Uuid;
+
+/// Relative tolerance for the symmetry check before an eigendecomposition
+const SYMMETRY_TOLERANCE: f64 = 1e-9;
+/// Largest row or column count accepted from the frontend
+const DEFAULT_MAX_DIMENSION: usize = 2048;
+/// Rows multiplied between cancellation checks
+const ROW_BLOCK: usize = 64;
+/// Finished computations kept for `ComputationRegistry::status`
+const FINISHED_CAPACITY: usize = 256;
+
+/// Emitted with a `ComputationInfo` when a background computation ends
+pub const COMPUTATION_FINISHED_EVENT: &str = "computation-finished";
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum MatrixError {
+    #[error("Unknown matrix operation '{0}'; expected multiply, svd, eigen or inverse")]
+    UnknownOperation(String),
+    #[error("{0}")]
+    InvalidArgument(String),
+    #[error("{0}")]
+    DimensionMismatch(String),
+    #[error("Matrix is singular and has no inverse")]
+    Singular,
+}
+
+/// Outcome of a matrix operation:
+/// - `multiply`: `matrices` holds the product
+/// - `inverse`: `matrices` holds the inverse
+/// - `svd`: `matrices` holds U and Vᵀ, `values` the singular values
+/// - `eigen`: `matrices` holds the eigenvectors as columns, `values` the
+///   matching eigenvalues
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatrixResult {
+    pub operation: String,
+    pub matrices: Vec<Vec<Vec<f64>>>,
+    pub values: Vec<f64>,
+    pub computation_time_ms: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ComputationError {
+    #[error("Unknown computation type '{0}'; expected matrix or power_iteration")]
+    UnknownType(String),
+    #[error("Invalid computation parameters: {0}")]
+    InvalidParameters(String),
+    #[error(transparent)]
+    Matrix(#[from] MatrixError),
+    #[error("Computation was cancelled")]
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComputationRequest {
+    pub computation_type: String,
+    pub parameters: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct MatrixParameters {
+    operation: String,
+    matrices: Vec<Vec<Vec<f64>>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PowerIterationParameters {
+    matrix: Vec<Vec<f64>>,
+    #[serde(default = "default_max_iterations")]
+    max_iterations: u64,
+    /// Stop once the eigenvalue estimate moves less than this
+    #[serde(default = "default_tolerance")]
+    tolerance: f64,
+}
+
+fn default_max_iterations() -> u64 {
+    1000
+}
+
+fn default_tolerance() -> f64 {
+    1e-10
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComputationStatus {
+    Running,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComputationInfo {
+    pub id: String,
+    pub computation_type: String,
+    pub status: ComputationStatus,
+    pub result: Option<Value>,
+    pub error: Option<String>,
+    pub computation_time_ms: Option<f64>,
+}
+
+#[derive(Debug)]
+struct ComputationEntry {
+    info: ComputationInfo,
+    token: CancellationToken,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    running: HashMap<String, ComputationEntry>,
+    finished: HashMap<String, ComputationInfo>,
+    /// Finished ids, oldest first, so the oldest is dropped once more than
+    /// `FINISHED_CAPACITY` are kept
+    finished_order: VecDeque<String>,
+}
+
+impl Entries {
+    fn record_finished(&mut self, info: ComputationInfo) {
+        let id = info.id.clone();
+        if self.finished.insert(id.clone(), info).is_none() {
+            self.finished_order.push_back(id);
+        }
+        while self.finished_order.len() > FINISHED_CAPACITY {
+            if let Some(oldest) = self.finished_order.pop_front() {
+                self.finished.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Computations started through `execute_computation`. Running ones carry
+/// the token `cancel_computation` signals; the final `ComputationInfo` of
+/// the last `FINISHED_CAPACITY` finished ones is kept for `status` and also
+/// goes to the `on_finish` callback.
+#[derive(Debug, Default)]
+pub struct ComputationRegistry {
+    entries: Mutex<Entries>,
+}
+
+impl ComputationRegistry {
+    /// Run `request` on a blocking thread and return its id straight away.
+    /// `on_finish` receives the outcome, including after a cancellation.
+    pub fn spawn(
+        self: &Arc<Self>,
+        engine: BraunEngine,
+        request: ComputationRequest,
+        on_finish: impl FnOnce(ComputationInfo) + Send + 'static,
+    ) -> (String, tokio::task::JoinHandle<()>) {
+        let id = Uuid::new_v4().to_string();
+        let token = CancellationToken::new();
+        self.entries.lock().unwrap().running.insert(
+            id.clone(),
+            ComputationEntry {
+                info: ComputationInfo {
+                    id: id.clone(),
+                    computation_type: request.computation_type.clone(),
+                    status: ComputationStatus::Running,
+                    result: None,
+                    error: None,
+                    computation_time_ms: None,
+                },
+                token: token.clone(),
+            },
+        );
+
+        let registry = Arc::clone(self);
+        let task_id = id.clone();
+        let handle = tokio::task::spawn_blocking(move || {
+            let start = Instant::now();
+            let outcome = engine.run_computation(&request, &token);
+            let info = registry.finish(&task_id, &request, outcome, start.elapsed().as_secs_f64() * 1000.0);
+            on_finish(info);
+        });
+
+        (id, handle)
+    }
+
+    /// Move a computation from running to finished and describe how it ended
+    fn finish(
+        &self,
+        id: &str,
+        request: &ComputationRequest,
+        outcome: Result<Value, ComputationError>,
+        elapsed_ms: f64,
+    ) -> ComputationInfo {
+        // A missing entry means it was cancelled, even if it then completed
+        let mut entries = self.entries.lock().unwrap();
+        let running = entries.running.remove(id).is_some();
+        let mut info = ComputationInfo {
+            id: id.to_string(),
+            computation_type: request.computation_type.clone(),
+            status: ComputationStatus::Cancelled,
+            result: None,
+            error: None,
+            computation_time_ms: Some(elapsed_ms),
+        };
+        match outcome {
+            Ok(result) if running => {
+                info.status = ComputationStatus::Completed;
+                info.result = Some(result);
+            }
+            Ok(_) | Err(ComputationError::Cancelled) => {}
+            Err(e) => {
+                info.status = ComputationStatus::Failed;
+                info.error = Some(e.to_string());
+            }
+        }
+        entries.record_finished(info.clone());
+        info
+    }
+
+    /// Signal a running computation to stop; it reads back as cancelled
+    /// from then on. Returns false if it isn't running.
+    pub fn cancel(&self, id: &str) -> bool {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.running.remove(id) {
+            Some(entry) => {
+                entry.token.cancel();
+                entries.record_finished(ComputationInfo {
+                    status: ComputationStatus::Cancelled,
+                    ..entry.info
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Status of a running or recently finished computation
+    pub fn status(&self, id: &str) -> Option<ComputationInfo> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .running
+            .get(id)
+            .map(|entry| entry.info.clone())
+            .or_else(|| entries.finished.get(id).cloned())
+    }
+
+    /// Computations still running
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().running.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BraunEngine {
+    max_dimension: usize,
+}
+
+impl BraunEngine {
+    pub async fn new() -> Result<Self> {
+        info!("Initializing Braun Engine...");
+
+        Ok(Self {
+            max_dimension: DEFAULT_MAX_DIMENSION,
+        })
+    }
+
+    /// Run `operation` on `matrices`, given as rows, and time it
+    pub fn compute_matrix(&self, operation: &str, matrices: &[Vec<Vec<f64>>]) -> Result<MatrixResult, MatrixError> {
+        self.compute_matrix_cancellable(operation, matrices, &CancellationToken::new())
+            .map_err(|e| match e {
+                ComputationError::Matrix(e) => e,
+                other => MatrixError::InvalidArgument(other.to_string()),
+            })
+    }
+
+    /// `compute_matrix`, checking `token` between row blocks of a product
+    /// and around each decomposition
+    pub fn compute_matrix_cancellable(
+        &self,
+        operation: &str,
+        matrices: &[Vec<Vec<f64>>],
+        token: &CancellationToken,
+    ) -> Result<MatrixResult, ComputationError> {
+        let start = Instant::now();
+        let too_large = matrices
+            .iter()
+            .any(|rows| rows.len() > self.max_dimension || rows.iter().any(|row| row.len() > self.max_dimension));
+        if too_large {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Matrices are limited to {} rows and columns",
+                self.max_dimension
+            ))
+            .into());
+        }
+        check_cancelled(token)?;
+
+        let (outputs, values) = match operation {
+            "multiply" => {
+                let [a, b] = matrices else {
+                    return Err(MatrixError::InvalidArgument(format!(
+                        "Matrix multiplication requires exactly 2 matrices, got {}",
+                        matrices.len()
+                    ))
+                    .into());
+                };
+                let (a, b) = (to_dmatrix(a)?, to_dmatrix(b)?);
+                if a.ncols() != b.nrows() {
+                    return Err(MatrixError::DimensionMismatch(format!(
+                        "Cannot multiply {}x{} by {}x{} matrix",
+                        a.nrows(), a.ncols(), b.nrows(), b.ncols()
+                    ))
+                    .into());
+                }
+                let mut product = DMatrix::zeros(a.nrows(), b.ncols());
+                for first_row in (0..a.nrows()).step_by(ROW_BLOCK) {
+                    check_cancelled(token)?;
+                    let rows = ROW_BLOCK.min(a.nrows() - first_row);
+                    product.rows_mut(first_row, rows).copy_from(&(a.rows(first_row, rows) * &b));
+                }
+                (vec![product], Vec::new())
+            }
+            "svd" => {
+                let matrix = to_dmatrix(single_matrix(operation, matrices)?)?;
+                let svd = matrix.svd(true, true);
+                let (Some(u), Some(v_t)) = (svd.u, svd.v_t) else {
+                    return Err(MatrixError::InvalidArgument("SVD did not converge".to_string()).into());
+                };
+                (vec![u, v_t], svd.singular_values.iter().copied().collect())
+            }
+            "eigen" => {
+                let matrix = to_dmatrix(single_matrix(operation, matrices)?)?;
+                require_square(operation, &matrix)?;
+                // Only symmetric matrices are guaranteed real eigenvalues
+                let scale = matrix.amax().max(1.0);
+                if (&matrix - matrix.transpose()).amax() > SYMMETRY_TOLERANCE * scale {
+                    return Err(MatrixError::InvalidArgument("Eigendecomposition requires a symmetric matrix".to_string()).into());
+                }
+                let eigen = matrix.symmetric_eigen();
+                (vec![eigen.eigenvectors], eigen.eigenvalues.iter().copied().collect())
+            }
+            "inverse" => {
+                let matrix = to_dmatrix(single_matrix(operation, matrices)?)?;
+                require_square(operation, &matrix)?;
+                (vec![matrix.try_inverse().ok_or(MatrixError::Singular)?], Vec::new())
+            }
+            _ => return Err(MatrixError::UnknownOperation(operation.to_string()).into()),
+        };
+        check_cancelled(token)?;
+
+        let computation_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+        debug!("Matrix {} completed in {:.3}ms", operation, computation_time_ms);
+
+        Ok(MatrixResult {
+            operation: operation.to_string(),
+            matrices: outputs.iter().map(matrix_to_rows).collect(),
+            values,
+            computation_time_ms,
+        })
+    }
+}
+
+impl BraunEngine {
+    /// Run a computation, checking `token` between steps so cancellation
+    /// takes effect within one iteration or row block
+    pub fn run_computation(&self, request: &ComputationRequest, token: &CancellationToken) -> Result<Value, ComputationError> {
+        check_cancelled(token)?;
+
+        match request.computation_type.as_str() {
+            "matrix" => {
+                let params: MatrixParameters = parse_parameters(&request.parameters)?;
+                let result = self.compute_matrix_cancellable(&params.operation, &params.matrices, token)?;
+                serde_json::to_value(result).map_err(|e| ComputationError::InvalidParameters(e.to_string()))
+            }
+            "power_iteration" => {
+                let params: PowerIterationParameters = parse_parameters(&request.parameters)?;
+                self.power_iteration(&params, token)
+            }
+            other => Err(ComputationError::UnknownType(other.to_string())),
+        }
+    }
+
+    /// Dominant eigenvalue and eigenvector by repeated multiplication
+    fn power_iteration(&self, params: &PowerIterationParameters, token: &CancellationToken) -> Result<Value, ComputationError> {
+        let matrix = to_dmatrix(&params.matrix)?;
+        require_square("power_iteration", &matrix)?;
+
+        let n = matrix.nrows();
+        let mut vector = DVector::from_element(n, 1.0 / (n as f64).sqrt());
+        let mut eigenvalue = 0.0;
+        let mut iterations = 0;
+        let mut converged = false;
+
+        while iterations < params.max_iterations {
+            check_cancelled(token)?;
+            iterations += 1;
+
+            let next = &matrix * &vector;
+            let norm = next.norm();
+            if norm == 0.0 {
+                eigenvalue = 0.0;
+                converged = true;
+                break;
+            }
+            let estimate = vector.dot(&next);
+            vector = next / norm;
+
+            let delta = (estimate - eigenvalue).abs();
+            eigenvalue = estimate;
+            if delta < params.tolerance {
+                converged = true;
+                break;
+            }
+        }
+
+        Ok(serde_json::json!({
+            "eigenvalue": eigenvalue,
+            "eigenvector": vector.iter().copied().collect::<Vec<_>>(),
+            "iterations": iterations,
+            "converged": converged,
+        }))
+    }
+}
+
+fn check_cancelled(token: &CancellationToken) -> Result<(), ComputationError> {
+    if token.is_cancelled() {
+        Err(ComputationError::Cancelled)
+    } else {
+        Ok(())
+    }
+}
+
+fn parse_parameters<T: serde::de::DeserializeOwned>(parameters: &Value) -> Result<T, ComputationError> {
+    serde_json::from_value(parameters.clone()).map_err(|e| ComputationError::InvalidParameters(e.to_string()))
+}
+
+fn single_matrix<'a>(operation: &str, matrices: &'a [Vec<Vec<f64>>]) -> Result<&'a [Vec<f64>], MatrixError> {
+    match matrices {
+        [matrix] => Ok(matrix),
+        _ => Err(MatrixError::InvalidArgument(format!(
+            "Matrix {} requires exactly 1 matrix, got {}",
+            operation,
+            matrices.len()
+        ))),
+    }
+}
+
+fn require_square(operation: &str, matrix: &DMatrix<f64>) -> Result<(), MatrixError> {
+    if matrix.is_square() {
+        Ok(())
+    } else {
+        Err(MatrixError::DimensionMismatch(format!(
+            "Matrix {} requires a square matrix, got {}x{}",
+            operation,
+            matrix.nrows(),
+            matrix.ncols()
+        )))
+    }
+}
+
+fn to_dmatrix(rows: &[Vec<f64>]) -> Result<DMatrix<f64>, MatrixError> {
+    let cols = rows.first().map_or(0, |row| row.len());
+    if cols == 0 {
+        return Err(MatrixError::DimensionMismatch("Matrix must have at least one row and one column".to_string()));
+    }
+    if rows.iter().any(|row| row.len() != cols) {
+        return Err(MatrixError::DimensionMismatch("Matrix rows must all have the same length".to_string()));
+    }
+    if rows.iter().flatten().any(|value| !value.is_finite()) {
+        return Err(MatrixError::InvalidArgument("Matrix values must be finite".to_string()));
+    }
+
+    Ok(DMatrix::from_row_slice(rows.len(), cols, &rows.concat()))
+}
+
+fn matrix_to_rows(matrix: &DMatrix<f64>) -> Vec<Vec<f64>> {
+    (0..matrix.nrows())
+        .map(|i| matrix.row(i).iter().copied().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_matrix_operations_return_shaped_results() {
+        let engine = BraunEngine::new().await.unwrap();
+
+        let product = engine
+            .compute_matrix("multiply", &[vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]], vec![vec![1.0], vec![0.0], vec![2.0]]])
+            .unwrap();
+        assert_eq!(product.matrices, vec![vec![vec![7.0], vec![16.0]]]);
+        assert!(product.computation_time_ms >= 0.0);
+
+        let inverse = engine.compute_matrix("inverse", &[vec![vec![2.0, 0.0], vec![0.0, 4.0]]]).unwrap();
+        assert_eq!(inverse.matrices[0], vec![vec![0.5, 0.0], vec![0.0, 0.25]]);
+
+        let svd = engine.compute_matrix("svd", &[vec![vec![3.0, 0.0], vec![0.0, 4.0], vec![0.0, 0.0]]]).unwrap();
+        assert_eq!(svd.matrices.len(), 2);
+        let mut singular_values = svd.values.clone();
+        singular_values.sort_by(|a, b| b.total_cmp(a));
+        assert!((singular_values[0] - 4.0).abs() < 1e-9 && (singular_values[1] - 3.0).abs() < 1e-9);
+
+        let eigen = engine.compute_matrix("eigen", &[vec![vec![2.0, 1.0], vec![1.0, 2.0]]]).unwrap();
+        let mut eigenvalues = eigen.values.clone();
+        eigenvalues.sort_by(|a, b| a.total_cmp(b));
+        assert!((eigenvalues[0] - 1.0).abs() < 1e-9 && (eigenvalues[1] - 3.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_cancelling_a_running_computation() {
+        let engine = BraunEngine::new().await.unwrap();
+        let registry = Arc::new(ComputationRegistry::default());
+        let (finished_tx, mut finished) = tokio::sync::mpsc::unbounded_channel();
+        let on_finish = move |info: ComputationInfo| finished_tx.send(info).unwrap();
+
+        // Zero tolerance never converges, so this runs until cancelled
+        let request = ComputationRequest {
+            computation_type: "power_iteration".to_string(),
+            parameters: serde_json::json!({
+                "matrix": [[2.0, 1.0], [1.0, 3.0]],
+                "max_iterations": u64::MAX,
+                "tolerance": 0.0
+            }),
+        };
+        let (id, handle) = registry.spawn(engine.clone(), request, on_finish.clone());
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        assert_eq!(registry.status(&id).unwrap().status, ComputationStatus::Running);
+
+        assert!(registry.cancel(&id));
+        assert_eq!(registry.status(&id).unwrap().status, ComputationStatus::Cancelled);
+        assert!(registry.is_empty());
+        tokio::time::timeout(std::time::Duration::from_secs(1), handle).await.unwrap().unwrap();
+        let info = finished.recv().await.unwrap();
+        assert_eq!((info.id.as_str(), info.status), (id.as_str(), ComputationStatus::Cancelled));
+        assert!(info.result.is_none() && info.computation_time_ms.is_some());
+        assert!(!registry.cancel(&id));
+        // The final outcome replaces the record made on cancel
+        assert_eq!(registry.status(&id).unwrap().status, ComputationStatus::Cancelled);
+        assert!(registry.status(&id).unwrap().computation_time_ms.is_some());
+
+        let quick = ComputationRequest {
+            computation_type: "power_iteration".to_string(),
+            parameters: serde_json::json!({ "matrix": [[2.0, 0.0], [0.0, 1.0]] }),
+        };
+        let (id, handle) = registry.spawn(engine, quick, on_finish);
+        handle.await.unwrap();
+        let info = finished.recv().await.unwrap();
+        assert_eq!(info.status, ComputationStatus::Completed);
+        assert!((info.result.unwrap()["eigenvalue"].as_f64().unwrap() - 2.0).abs() < 1e-6);
+        let status = registry.status(&id).unwrap();
+        assert_eq!(status.status, ComputationStatus::Completed);
+        assert!(status.result.is_some() && registry.is_empty());
+    }
+
+    #[test]
+    fn test_only_the_most_recent_finished_computations_are_kept() {
+        let registry = ComputationRegistry::default();
+        let request = ComputationRequest {
+            computation_type: "matrix".to_string(),
+            parameters: Value::Null,
+        };
+        let ids: Vec<String> = (0..=FINISHED_CAPACITY).map(|i| format!("computation-{}", i)).collect();
+        for id in &ids {
+            registry.finish(id, &request, Err(ComputationError::Cancelled), 1.0);
+        }
+
+        assert!(registry.status(&ids[0]).is_none());
+        assert_eq!(registry.status(&ids[1]).unwrap().status, ComputationStatus::Cancelled);
+        assert!(registry.status(&ids[FINISHED_CAPACITY]).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cancelling_a_large_multiply_stops_between_row_blocks() {
+        let engine = BraunEngine::new().await.unwrap();
+        let n = 1024;
+        let matrix: Vec<Vec<f64>> = (0..n).map(|i| (0..n).map(|j| ((i + j) % 7) as f64).collect()).collect();
+        let request = ComputationRequest {
+            computation_type: "matrix".to_string(),
+            parameters: serde_json::json!({ "operation": "multiply", "matrices": [matrix.clone(), matrix] }),
+        };
+
+        let token = CancellationToken::new();
+        let running = {
+            let token = token.clone();
+            tokio::task::spawn_blocking(move || engine.run_computation(&request, &token))
+        };
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        token.cancel();
+
+        // Stops within a row block rather than finishing the product
+        let outcome = tokio::time::timeout(std::time::Duration::from_secs(2), running).await.unwrap().unwrap();
+        assert_eq!(outcome.unwrap_err(), ComputationError::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_matrix_input_is_rejected() {
+        let engine = BraunEngine::new().await.unwrap();
+        let row = |values: &[f64]| vec![values.to_vec()];
+
+        assert!(matches!(
+            engine.compute_matrix("multiply", &[row(&[1.0, 2.0, 3.0]), row(&[1.0, 2.0])]),
+            Err(MatrixError::DimensionMismatch(_))
+        ));
+        assert!(matches!(engine.compute_matrix("multiply", &[row(&[1.0])]), Err(MatrixError::InvalidArgument(_))));
+        assert!(matches!(
+            engine.compute_matrix("inverse", &[vec![vec![1.0, 2.0], vec![3.0]]]),
+            Err(MatrixError::DimensionMismatch(_))
+        ));
+        assert_eq!(
+            engine.compute_matrix("inverse", &[vec![vec![1.0, 2.0], vec![2.0, 4.0]]]).unwrap_err(),
+            MatrixError::Singular
+        );
+        assert!(matches!(
+            engine.compute_matrix("eigen", &[vec![vec![1.0, 2.0], vec![0.0, 1.0]]]),
+            Err(MatrixError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            engine.compute_matrix("transpose", &[row(&[1.0])]).unwrap_err(),
+            MatrixError::UnknownOperation("transpose".to_string())
+        );
+    }
+}
@@ -0,0 +1,153 @@
+// Cognitive Module - Per-session cognitive state
+// Sessions outlive the process, so `CognitiveState` is persisted as versioned
+// JSON and migrated forward when loaded
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use anyhow::{Result, anyhow};
+use uuid::Uuid;
+
+/// Schema version written with every persisted `CognitiveState`. Bump it and
+/// append to `MIGRATIONS` whenever a change can't be absorbed by
+/// `#[serde(default)]`.
+pub const COGNITIVE_STATE_VERSION: u32 = 1;
+
+/// `MIGRATIONS[n]` upgrades a version `n + 1` document to version `n + 2`
+type Migration = fn(&mut Value);
+const MIGRATIONS: &[Migration] = &[];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CognitiveState {
+    pub session_id: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub consciousness_level: f32,    // 0.0 to 1.0
+    pub reasoning_depth: f32,        // 0.0 to 1.0
+    pub field_coherence: f32,        // 0.0 to 1.0
+    #[serde(default)]
+    pub attention_focus: Vec<String>,
+    #[serde(default)]
+    pub working_memory: HashMap<String, Value>,
+}
+
+impl CognitiveState {
+    pub fn new() -> Self {
+        let now = Utc::now();
+        Self {
+            session_id: Uuid::new_v4().to_string(),
+            created_at: now,
+            updated_at: now,
+            consciousness_level: 0.5,
+            reasoning_depth: 0.5,
+            field_coherence: 0.0,
+            attention_focus: Vec::new(),
+            working_memory: HashMap::new(),
+        }
+    }
+
+    /// Apply a partial JSON object over this state. `session_id` and
+    /// `created_at` can't be changed.
+    pub fn apply_update(&mut self, updates: Value) -> Result<()> {
+        let Value::Object(updates) = updates else {
+            return Err(anyhow!("Session update must be a JSON object"));
+        };
+
+        let mut merged = serde_json::to_value(&*self)?;
+        if let Value::Object(fields) = &mut merged {
+            for (key, value) in updates {
+                if key != "session_id" && key != "created_at" {
+                    fields.insert(key, value);
+                }
+            }
+        }
+
+        let mut updated: CognitiveState = serde_json::from_value(merged)
+            .map_err(|e| anyhow!("Invalid session update: {}", e))?;
+        updated.updated_at = Utc::now();
+        *self = updated;
+        Ok(())
+    }
+
+    /// Decode a persisted state written at schema `version`
+    pub fn from_versioned(value: Value, version: u32) -> Result<Self> {
+        migrate(value, version, COGNITIVE_STATE_VERSION, MIGRATIONS)
+    }
+}
+
+impl Default for CognitiveState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn migrate(mut value: Value, version: u32, current: u32, migrations: &[Migration]) -> Result<CognitiveState> {
+    debug_assert_eq!(current as usize, migrations.len() + 1);
+    if version == 0 || version > current {
+        return Err(anyhow!(
+            "Cognitive state version {} is not supported (expected 1 to {})",
+            version,
+            current
+        ));
+    }
+
+    for migration in &migrations[version as usize - 1..] {
+        migration(&mut value);
+    }
+    serde_json::from_value(value).map_err(|e| anyhow!("Malformed cognitive state: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_older_states_are_migrated_forward() {
+        // A hypothetical v2 that renamed `attention` and added `field_coherence`
+        fn v1_to_v2(value: &mut Value) {
+            if let Value::Object(fields) = value {
+                if let Some(attention) = fields.remove("attention") {
+                    fields.insert("attention_focus".to_string(), attention);
+                }
+                fields.entry("field_coherence").or_insert(Value::from(0.0));
+            }
+        }
+
+        let v1 = json!({
+            "session_id": "s-1",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-02T00:00:00Z",
+            "consciousness_level": 0.7,
+            "reasoning_depth": 0.4,
+            "attention": ["code-review"]
+        });
+
+        let state = migrate(v1, 1, 2, &[v1_to_v2]).unwrap();
+        assert_eq!(state.session_id, "s-1");
+        assert_eq!(state.attention_focus, ["code-review"]);
+        assert_eq!(state.field_coherence, 0.0);
+
+        let current = serde_json::to_value(&state).unwrap();
+        assert_eq!(CognitiveState::from_versioned(current, COGNITIVE_STATE_VERSION).unwrap(), state);
+        assert!(CognitiveState::from_versioned(json!({}), COGNITIVE_STATE_VERSION + 1).is_err());
+        assert!(CognitiveState::from_versioned(json!({}), 0).is_err());
+    }
+
+    #[test]
+    fn test_apply_update_keeps_identity() {
+        let mut state = CognitiveState::new();
+        let id = state.session_id.clone();
+
+        state
+            .apply_update(json!({ "session_id": "other", "reasoning_depth": 0.9, "attention_focus": ["tests"] }))
+            .unwrap();
+        assert_eq!(state.session_id, id);
+        assert_eq!(state.reasoning_depth, 0.9);
+        assert_eq!(state.attention_focus, ["tests"]);
+
+        assert!(state.apply_update(json!({ "reasoning_depth": "deep" })).is_err());
+        assert_eq!(state.reasoning_depth, 0.9);
+    }
+}
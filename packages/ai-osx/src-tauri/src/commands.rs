@@ -0,0 +1,157 @@
+// Tauri command handlers exposed to the frontend
+
+use crate::AppState;
+use crate::braun::{BraunEngine, ComputationInfo, ComputationRequest, MatrixResult, COMPUTATION_FINISHED_EVENT};
+use crate::cognitive::CognitiveState;
+use crate::networking::EdgeStatusSnapshot;
+use tauri::{State, Window};
+use tracing::warn;
+
+// Braun commands
+
+/// Start a computation in the background and return its id, for use with
+/// `get_computation_status` and `cancel_computation`. The outcome is sent to
+/// `window` as `COMPUTATION_FINISHED_EVENT`.
+#[tauri::command]
+pub async fn execute_computation(
+    window: Window,
+    state: State<'_, AppState>,
+    computation_type: String,
+    parameters: serde_json::Value,
+) -> Result<String, String> {
+    let engine = state.braun.read().await.clone();
+    let (id, _) = state.computations.spawn(
+        engine,
+        ComputationRequest { computation_type, parameters },
+        move |info| {
+            if let Err(e) = window.emit(COMPUTATION_FINISHED_EVENT, &info) {
+                warn!("Failed to emit computation {} outcome: {}", info.id, e);
+            }
+        },
+    );
+    Ok(id)
+}
+
+/// Status of a running, completed, failed or cancelled computation. Only
+/// recently finished ones are kept, so older ids are reported as unknown.
+#[tauri::command]
+pub async fn get_computation_status(state: State<'_, AppState>, computation_id: String) -> Result<ComputationInfo, String> {
+    state
+        .computations
+        .status(&computation_id)
+        .ok_or_else(|| format!("Unknown computation '{}'", computation_id))
+}
+
+/// Returns false if the computation isn't running
+#[tauri::command]
+pub async fn cancel_computation(state: State<'_, AppState>, computation_id: String) -> Result<bool, String> {
+    Ok(state.computations.cancel(&computation_id))
+}
+
+/// Run a matrix operation (multiply, svd, eigen or inverse) on the local
+/// Braun engine
+#[tauri::command]
+pub async fn compute_matrix(
+    state: State<'_, AppState>,
+    operation: String,
+    matrices: Vec<Vec<Vec<f64>>>,
+) -> Result<MatrixResult, String> {
+    let engine = state.braun.read().await.clone();
+    run_compute_matrix(engine, operation, matrices).await
+}
+
+/// Matrix math is CPU bound, so it runs off the async runtime
+async fn run_compute_matrix(
+    engine: BraunEngine,
+    operation: String,
+    matrices: Vec<Vec<Vec<f64>>>,
+) -> Result<MatrixResult, String> {
+    tokio::task::spawn_blocking(move || engine.compute_matrix(&operation, &matrices))
+        .await
+        .map_err(|e| format!("Matrix computation failed: {}", e))?
+        .map_err(|e| e.to_string())
+}
+
+// Network commands
+
+/// Start maintaining a connection to `endpoint`; progress arrives as
+/// `edge-status` events
+#[tauri::command]
+pub async fn connect_to_edge(state: State<'_, AppState>, endpoint: String) -> Result<EdgeStatusSnapshot, String> {
+    let mut connector = state.edge_connector.write().await;
+    connector
+        .connect(&endpoint)
+        .map_err(|e| format!("Failed to connect to edge: {}", e))?;
+    Ok(connector.snapshot())
+}
+
+#[tauri::command]
+pub async fn disconnect_from_edge(state: State<'_, AppState>) -> Result<bool, String> {
+    Ok(state.edge_connector.write().await.disconnect())
+}
+
+#[tauri::command]
+pub async fn get_edge_status(state: State<'_, AppState>) -> Result<EdgeStatusSnapshot, String> {
+    Ok(state.edge_connector.read().await.snapshot())
+}
+
+// Session management
+
+#[tauri::command]
+pub async fn create_session(state: State<'_, AppState>) -> Result<CognitiveState, String> {
+    state
+        .create_session()
+        .await
+        .map_err(|e| format!("Failed to create session: {}", e))
+}
+
+#[tauri::command]
+pub async fn get_session(state: State<'_, AppState>, session_id: String) -> Result<Option<CognitiveState>, String> {
+    Ok(state.sessions.read().await.get(&session_id).cloned())
+}
+
+/// Merge `updates`, a partial `CognitiveState` object, into the session
+#[tauri::command]
+pub async fn update_session(
+    state: State<'_, AppState>,
+    session_id: String,
+    updates: serde_json::Value,
+) -> Result<CognitiveState, String> {
+    state
+        .update_session(&session_id, updates)
+        .await
+        .map_err(|e| format!("Failed to update session: {}", e))
+}
+
+#[tauri::command]
+pub async fn delete_session(state: State<'_, AppState>, session_id: String) -> Result<bool, String> {
+    state
+        .delete_session(&session_id)
+        .await
+        .map_err(|e| format!("Failed to delete session: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_compute_matrix_multiply() {
+        let engine = BraunEngine::new().await.unwrap();
+        let identity = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+        let wide = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+
+        let result = run_compute_matrix(engine.clone(), "multiply".to_string(), vec![identity, wide.clone()])
+            .await
+            .unwrap();
+        assert_eq!(result.operation, "multiply");
+        assert_eq!(result.matrices.len(), 1);
+        assert_eq!((result.matrices[0].len(), result.matrices[0][0].len()), (2, 3));
+        assert_eq!(result.matrices[0], wide);
+
+        let error = run_compute_matrix(engine, "multiply".to_string(), vec![wide.clone(), wide])
+            .await
+            .unwrap_err();
+        assert_eq!(error, "Cannot multiply 2x3 by 2x3 matrix");
+    }
+}
@@ -17,16 +17,16 @@ mod performance;
 mod platform;
 
 use brain::BrainProcessor;
-use braun::BraunEngine;
+use braun::{BraunEngine, ComputationRegistry};
 use beyond::BeyondTranscender;
 use cognitive::{CognitiveCoordinator, CognitiveState};
 use commands::*;
 use models::*;
 use storage::LocalStorage;
-use networking::EdgeConnector;
+use networking::{EdgeConnector, EDGE_STATUS_EVENT};
 use audio::ResonanceEngine;
 use security::SecurityManager;
-use performance::PerformanceMonitor;
+use performance::{PerformanceMonitor, PERFORMANCE_METRICS_EVENT, PERFORMANCE_STREAM_INTERVAL_MS, PERFORMANCE_WINDOW_LABEL};
 
 use tauri::{
     async_runtime, 
@@ -61,6 +61,7 @@ use std::collections::HashMap;
 pub struct AppState {
     pub brain: Arc<RwLock<BrainProcessor>>,
     pub braun: Arc<RwLock<BraunEngine>>,
+    pub computations: Arc<ComputationRegistry>,
     pub beyond: Arc<RwLock<BeyondTranscender>>,
     pub cognitive_coordinator: Arc<RwLock<CognitiveCoordinator>>,
     pub storage: Arc<RwLock<LocalStorage>>,
@@ -68,14 +69,21 @@ pub struct AppState {
     pub resonance_engine: Arc<RwLock<ResonanceEngine>>,
     pub security_manager: Arc<RwLock<SecurityManager>>,
     pub performance_monitor: Arc<RwLock<PerformanceMonitor>>,
+    /// Task emitting `PERFORMANCE_METRICS_EVENT`, while streaming
+    pub performance_stream: Arc<Mutex<Option<async_runtime::JoinHandle<()>>>>,
     pub sessions: Arc<RwLock<HashMap<String, CognitiveState>>>,
 }
 
 impl AppState {
     pub async fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::with_storage(LocalStorage::new().await?).await
+    }
+
+    /// Build the system around `storage`, restoring its persisted sessions
+    pub async fn with_storage(storage: LocalStorage) -> Result<Self, Box<dyn std::error::Error>> {
         info!("Initializing AI-OSX Brain-Braun-Beyond system...");
 
-        let storage = LocalStorage::new().await?;
+        let sessions = storage.load_sessions()?;
         let security_manager = SecurityManager::new().await?;
         let performance_monitor = PerformanceMonitor::new().await?;
         
@@ -95,6 +103,7 @@ impl AppState {
         Ok(Self {
             brain: Arc::new(RwLock::new(brain)),
             braun: Arc::new(RwLock::new(braun)),
+            computations: Arc::new(ComputationRegistry::default()),
             beyond: Arc::new(RwLock::new(beyond)),
             cognitive_coordinator: Arc::new(RwLock::new(cognitive_coordinator)),
             storage: Arc::new(RwLock::new(storage)),
@@ -102,9 +111,39 @@ impl AppState {
             resonance_engine: Arc::new(RwLock::new(resonance_engine)),
             security_manager: Arc::new(RwLock::new(security_manager)),
             performance_monitor: Arc::new(RwLock::new(performance_monitor)),
-            sessions: Arc::new(RwLock::new(HashMap::new())),
+            performance_stream: Arc::new(Mutex::new(None)),
+            sessions: Arc::new(RwLock::new(sessions)),
         })
     }
+
+    // Session mutations are written to storage before the in-memory map, so
+    // a failed write leaves both unchanged
+
+    pub async fn create_session(&self) -> anyhow::Result<CognitiveState> {
+        let session = CognitiveState::new();
+        self.storage.read().await.save_session(&session)?;
+        self.sessions.write().await.insert(session.session_id.clone(), session.clone());
+        Ok(session)
+    }
+
+    pub async fn update_session(&self, session_id: &str, updates: serde_json::Value) -> anyhow::Result<CognitiveState> {
+        let mut sessions = self.sessions.write().await;
+        let session = sessions
+            .get_mut(session_id)
+            .ok_or_else(|| anyhow::anyhow!("Unknown session '{}'", session_id))?;
+
+        let mut updated = session.clone();
+        updated.apply_update(updates)?;
+        self.storage.read().await.save_session(&updated)?;
+        *session = updated.clone();
+        Ok(updated)
+    }
+
+    /// Returns whether the session existed
+    pub async fn delete_session(&self, session_id: &str) -> anyhow::Result<bool> {
+        self.storage.read().await.delete_session(session_id)?;
+        Ok(self.sessions.write().await.remove(session_id).is_some())
+    }
 }
 
 // System tray menu construction
@@ -277,18 +316,31 @@ fn open_cognitive_monitor(app: &tauri::AppHandle) -> Result<(), Box<dyn std::err
 }
 
 fn analyze_field_resonance(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error::Error>> {
-    // Trigger field resonance analysis
+    // Trigger field resonance analysis and hand the result to the frontend
     let app_state: State<AppState> = app.state();
     let resonance_engine = app_state.resonance_engine.clone();
+    let app_handle = app.clone();
     
     async_runtime::spawn(async move {
         let resonance = resonance_engine.read().await;
         match resonance.analyze_current_field().await {
             Ok(analysis) => {
-                info!("Field resonance analysis completed: {:?}", analysis);
+                info!(
+                    "Field resonance analysis completed: {} peaks, dominant {:?} Hz",
+                    analysis.resonant_peaks.len(),
+                    analysis.dominant_frequency_hz
+                );
+                if let Some(window) = app_handle.get_window("main") {
+                    if let Err(e) = window.emit("field-resonance-analysis", &analysis) {
+                        error!("Failed to emit field resonance analysis: {}", e);
+                    }
+                }
             }
             Err(e) => {
                 error!("Field resonance analysis failed: {}", e);
+                if let Some(window) = app_handle.get_window("main") {
+                    let _ = window.emit("field-resonance-error", e.to_string());
+                }
             }
         }
     });
@@ -297,18 +349,57 @@ fn analyze_field_resonance(app: &tauri::AppHandle) -> Result<(), Box<dyn std::er
 }
 
 fn show_performance_metrics(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error::Error>> {
+    // Stream snapshots to the main window and, when open, the performance
+    // window until `stop_performance_stream` is called
     let app_state: State<AppState> = app.state();
+    let mut stream = app_state.performance_stream.lock().unwrap();
+    if stream.is_some() {
+        debug!("Performance metrics stream already running");
+        return Ok(());
+    }
+
     let performance_monitor = app_state.performance_monitor.clone();
+    let app_handle = app.clone();
     
-    async_runtime::spawn(async move {
-        let monitor = performance_monitor.read().await;
-        let metrics = monitor.get_current_metrics().await;
-        info!("Current performance metrics: {:?}", metrics);
-    });
+    *stream = Some(async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_millis(PERFORMANCE_STREAM_INTERVAL_MS));
+        loop {
+            interval.tick().await;
+            let metrics = performance_monitor.read().await.get_current_metrics().await;
+            let payload = match performance::metrics_payload(&metrics) {
+                Ok(payload) => payload,
+                Err(e) => {
+                    error!("Failed to serialize performance metrics: {}", e);
+                    continue;
+                }
+            };
+
+            for label in ["main", PERFORMANCE_WINDOW_LABEL] {
+                if let Some(window) = app_handle.get_window(label) {
+                    if let Err(e) = window.emit(PERFORMANCE_METRICS_EVENT, &payload) {
+                        warn!("Failed to emit performance metrics to {}: {}", label, e);
+                    }
+                }
+            }
+        }
+    }));
+    info!("Started performance metrics stream");
     
     Ok(())
 }
 
+#[tauri::command]
+fn stop_performance_stream(state: State<'_, AppState>) -> bool {
+    match state.performance_stream.lock().unwrap().take() {
+        Some(stream) => {
+            stream.abort();
+            info!("Stopped performance metrics stream");
+            true
+        }
+        None => false,
+    }
+}
+
 fn open_settings(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error::Error>> {
     // Open settings window or navigate to settings page
     let window = app.get_window("main").unwrap();
@@ -389,8 +480,19 @@ async fn setup_app(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Error
 
     // Initialize application state
     let app_state = AppState::new().await?;
+    let edge_status = app_state.edge_connector.read().await.subscribe();
     app.manage(app_state);
 
+    // Forward edge connection changes to the main window
+    let edge_handle = app.handle();
+    async_runtime::spawn(networking::forward_status_changes(edge_status, move |snapshot| {
+        if let Some(window) = edge_handle.get_window("main") {
+            if let Err(e) = window.emit(EDGE_STATUS_EVENT, snapshot) {
+                warn!("Failed to emit edge status: {}", e);
+            }
+        }
+    }));
+
     // Setup global shortcuts
     setup_global_shortcuts(&app.handle())?;
 
@@ -441,6 +543,7 @@ async fn main() {
             execute_computation,
             get_computation_status,
             cancel_computation,
+            compute_matrix,
             
             // Beyond commands
             transcend_request,
@@ -450,6 +553,7 @@ async fn main() {
             // System commands
             get_system_info,
             get_performance_metrics,
+            stop_performance_stream,
             get_security_status,
             
             // Storage commands
@@ -480,4 +584,35 @@ async fn main() {
         ])
         .run(context)
         .expect("error while running tauri application");
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sessions_survive_restart() {
+        let path = std::env::temp_dir().join(format!("ai-osx-sessions-{}.db", Uuid::new_v4()));
+
+        let state = AppState::with_storage(LocalStorage::open(&path).unwrap()).await.unwrap();
+        let session = state.create_session().await.unwrap();
+        let discarded = state.create_session().await.unwrap();
+        state
+            .update_session(&session.session_id, serde_json::json!({ "attention_focus": ["refactoring"], "reasoning_depth": 0.8 }))
+            .await
+            .unwrap();
+        assert!(state.delete_session(&discarded.session_id).await.unwrap());
+        drop(state);
+
+        let restarted = AppState::with_storage(LocalStorage::open(&path).unwrap()).await.unwrap();
+        let sessions = restarted.sessions.read().await;
+        assert_eq!(sessions.len(), 1);
+        let restored = &sessions[&session.session_id];
+        assert_eq!(restored.attention_focus, ["refactoring"]);
+        assert_eq!(restored.reasoning_depth, 0.8);
+        assert_eq!(restored.created_at, session.created_at);
+        drop(sessions);
+
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -219,7 +219,7 @@ pub struct StorageUsage {
 }
 
 // Network and edge computing structures
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct EdgeConnectionStatus {
     pub connected: bool,
     pub edge_location: String,
@@ -232,7 +232,7 @@ pub struct EdgeConnectionStatus {
     pub error_count: usize,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ConnectionQuality {
     Excellent,
     Good,
@@ -309,6 +309,32 @@ pub enum PatternType {
     Custom(String),
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResonanceAnalysis {
+    pub sample_rate_hz: u32,
+    pub window_size: usize,
+    pub frequency_resolution_hz: f32,
+    /// Strongest peak first
+    pub resonant_peaks: Vec<ResonantPeak>,
+    pub dominant_frequency_hz: Option<f32>,
+    /// Lowest resonant frequency, the reference for `harmonic_ratios`
+    pub fundamental_hz: Option<f32>,
+    /// Each peak's frequency over the fundamental, in ascending frequency order
+    pub harmonic_ratios: Vec<f32>,
+    pub spectral_centroid_hz: f32,
+    pub rms_level: f32,
+    pub analyzed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResonantPeak {
+    pub frequency_hz: f32,
+    /// Relative to a full-scale sine
+    pub magnitude_db: f32,
+    /// Centre frequency over the -3 dB bandwidth
+    pub q_factor: f32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AudioAnalysis {
     pub frequency_spectrum: Vec<f32>,
@@ -90,6 +90,9 @@ fn create_pipeline(config_term: Term) -> NifResult<ResourceArc<PipelineResource>
         enable_emergent_detection: config.enable_emergent_detection,
         batch_size: config.batch_size,
         timeout_seconds: config.timeout_seconds,
+        breaker: Default::default(),
+        embedding_dim: crate::context::multimodal::embedding::EMBEDDING_DIM,
+        cache_capacity: 256,
     };
 
     // Create async runtime
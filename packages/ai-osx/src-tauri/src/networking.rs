@@ -0,0 +1,341 @@
+// Networking Module - Edge connection supervision
+// Keeps a WebSocket to the edge endpoint alive, reconnecting with exponential
+// backoff, and publishes every status change for the frontend
+
+use crate::models::*;
+use chrono::Utc;
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{info, warn};
+use anyhow::{Result, anyhow};
+
+/// Event the main window receives an `EdgeStatusSnapshot` on
+pub const EDGE_STATUS_EVENT: &str = "edge-status";
+
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+const RECONNECT_BASE_DELAY_MS: u64 = 500;
+const RECONNECT_MAX_DELAY_MS: u64 = 30_000;
+const MAX_RECONNECT_ATTEMPTS: u32 = 10;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReconnectStatus {
+    /// Consecutive failed attempts; 0 while connected
+    pub attempt: u32,
+    pub backoff_ms: u64,
+    pub next_retry_at: Option<chrono::DateTime<Utc>>,
+    /// Set once `MAX_RECONNECT_ATTEMPTS` is exhausted
+    pub gave_up: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EdgeStatusSnapshot {
+    #[serde(flatten)]
+    pub connection: EdgeConnectionStatus,
+    pub endpoint: Option<String>,
+    pub reconnect: ReconnectStatus,
+}
+
+impl EdgeStatusSnapshot {
+    fn disconnected() -> Self {
+        Self {
+            connection: EdgeConnectionStatus {
+                connected: false,
+                edge_location: String::new(),
+                latency_ms: 0.0,
+                bandwidth_mbps: 0.0,
+                last_heartbeat: Utc::now(),
+                connection_quality: ConnectionQuality::Disconnected,
+                active_streams: 0,
+                data_transferred_bytes: 0,
+                error_count: 0,
+            },
+            endpoint: None,
+            reconnect: ReconnectStatus::default(),
+        }
+    }
+
+    fn on_connected(&mut self, endpoint: &str) {
+        self.connection.connected = true;
+        self.connection.edge_location = edge_location(endpoint);
+        self.connection.last_heartbeat = Utc::now();
+        self.connection.active_streams = 1;
+        self.connection.connection_quality = ConnectionQuality::Good;
+        self.reconnect = ReconnectStatus::default();
+    }
+
+    fn on_latency(&mut self, latency_ms: f64) {
+        self.connection.latency_ms = latency_ms;
+        self.connection.last_heartbeat = Utc::now();
+        self.connection.connection_quality = quality_for_latency(latency_ms);
+    }
+
+    fn on_throughput(&mut self, bytes: u64, elapsed: Duration) {
+        let seconds = elapsed.as_secs_f64();
+        if seconds > 0.0 {
+            self.connection.bandwidth_mbps = bytes as f64 * 8.0 / seconds / 1_000_000.0;
+        }
+    }
+
+    fn on_disconnected(&mut self, failed: bool) {
+        self.connection.connected = false;
+        self.connection.active_streams = 0;
+        self.connection.bandwidth_mbps = 0.0;
+        self.connection.connection_quality = ConnectionQuality::Disconnected;
+        if failed {
+            self.connection.error_count += 1;
+        }
+    }
+
+    fn on_retry_scheduled(&mut self, attempt: u32, delay: Duration) {
+        self.reconnect.attempt = attempt;
+        self.reconnect.backoff_ms = delay.as_millis() as u64;
+        self.reconnect.next_retry_at = chrono::Duration::from_std(delay).ok().map(|delay| Utc::now() + delay);
+    }
+
+    fn on_gave_up(&mut self) {
+        self.reconnect.next_retry_at = None;
+        self.reconnect.gave_up = true;
+    }
+}
+
+fn quality_for_latency(latency_ms: f64) -> ConnectionQuality {
+    match latency_ms {
+        l if l < 50.0 => ConnectionQuality::Excellent,
+        l if l < 150.0 => ConnectionQuality::Good,
+        l if l < 400.0 => ConnectionQuality::Fair,
+        _ => ConnectionQuality::Poor,
+    }
+}
+
+/// Host part of the endpoint URL, shown as the edge location
+fn edge_location(endpoint: &str) -> String {
+    let without_scheme = endpoint.split("://").nth(1).unwrap_or(endpoint);
+    without_scheme.split(['/', '?']).next().unwrap_or(without_scheme).to_string()
+}
+
+/// Delay before reconnect `attempt` (1-based): doubling from
+/// `RECONNECT_BASE_DELAY_MS` up to `RECONNECT_MAX_DELAY_MS`
+pub fn backoff_delay(attempt: u32) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(16);
+    Duration::from_millis((RECONNECT_BASE_DELAY_MS << exponent).min(RECONNECT_MAX_DELAY_MS))
+}
+
+/// Apply `change` and notify subscribers only if the snapshot changed
+fn update(status: &watch::Sender<EdgeStatusSnapshot>, change: impl FnOnce(&mut EdgeStatusSnapshot)) {
+    status.send_if_modified(|snapshot| {
+        let before = snapshot.clone();
+        change(snapshot);
+        *snapshot != before
+    });
+}
+
+#[derive(Debug)]
+pub struct EdgeConnector {
+    status: Arc<watch::Sender<EdgeStatusSnapshot>>,
+    supervisor: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl EdgeConnector {
+    pub async fn new() -> Result<Self> {
+        info!("Initializing Edge Connector...");
+
+        let (status, _) = watch::channel(EdgeStatusSnapshot::disconnected());
+        Ok(Self {
+            status: Arc::new(status),
+            supervisor: None,
+        })
+    }
+
+    /// Latest status
+    pub fn snapshot(&self) -> EdgeStatusSnapshot {
+        self.status.borrow().clone()
+    }
+
+    /// Receiver that wakes on every status change
+    pub fn subscribe(&self) -> watch::Receiver<EdgeStatusSnapshot> {
+        self.status.subscribe()
+    }
+
+    /// Connect to `endpoint` (a ws:// or wss:// URL) in the background,
+    /// replacing any existing connection
+    pub fn connect(&mut self, endpoint: &str) -> Result<()> {
+        if !(endpoint.starts_with("ws://") || endpoint.starts_with("wss://")) {
+            return Err(anyhow!("Edge endpoint must be a ws:// or wss:// URL, got '{}'", endpoint));
+        }
+
+        self.disconnect();
+        update(&self.status, |snapshot| {
+            snapshot.endpoint = Some(endpoint.to_string());
+            snapshot.reconnect = ReconnectStatus::default();
+        });
+        self.supervisor = Some(tokio::spawn(supervise(endpoint.to_string(), Arc::clone(&self.status))));
+        Ok(())
+    }
+
+    /// Returns whether a connection was being maintained
+    pub fn disconnect(&mut self) -> bool {
+        let Some(supervisor) = self.supervisor.take() else {
+            return false;
+        };
+        supervisor.abort();
+        update(&self.status, |snapshot| {
+            snapshot.on_disconnected(false);
+            snapshot.reconnect = ReconnectStatus::default();
+        });
+        true
+    }
+}
+
+impl Drop for EdgeConnector {
+    fn drop(&mut self) {
+        if let Some(supervisor) = self.supervisor.take() {
+            supervisor.abort();
+        }
+    }
+}
+
+async fn supervise(endpoint: String, status: Arc<watch::Sender<EdgeStatusSnapshot>>) {
+    let mut attempt = 0;
+    loop {
+        match connect_async(endpoint.as_str()).await {
+            Ok((socket, _)) => {
+                info!("Connected to edge at {}", endpoint);
+                attempt = 0;
+                update(&status, |snapshot| snapshot.on_connected(&endpoint));
+
+                let outcome = run_connection(socket, &status).await;
+                if let Err(e) = &outcome {
+                    warn!("Edge connection to {} dropped: {}", endpoint, e);
+                }
+                update(&status, |snapshot| snapshot.on_disconnected(outcome.is_err()));
+            }
+            Err(e) => {
+                warn!("Failed to connect to edge at {}: {}", endpoint, e);
+                update(&status, |snapshot| snapshot.on_disconnected(true));
+            }
+        }
+
+        attempt += 1;
+        if attempt > MAX_RECONNECT_ATTEMPTS {
+            warn!("Giving up on edge at {} after {} attempts", endpoint, MAX_RECONNECT_ATTEMPTS);
+            update(&status, EdgeStatusSnapshot::on_gave_up);
+            return;
+        }
+        let delay = backoff_delay(attempt);
+        update(&status, |snapshot| snapshot.on_retry_scheduled(attempt, delay));
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Ping every `HEARTBEAT_INTERVAL` to measure latency and sample throughput.
+/// Returns `Ok` when the edge closes the connection.
+async fn run_connection<S>(
+    socket: tokio_tungstenite::WebSocketStream<S>,
+    status: &watch::Sender<EdgeStatusSnapshot>,
+) -> Result<()>
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    let (mut sink, mut stream) = socket.split();
+    let mut heartbeat = tokio::time::interval(HEARTBEAT_INTERVAL);
+    let mut ping_sent: Option<Instant> = None;
+    let mut window_start = Instant::now();
+    let mut window_bytes = 0u64;
+
+    loop {
+        tokio::select! {
+            _ = heartbeat.tick() => {
+                if ping_sent.is_some() {
+                    return Err(anyhow!("No pong within {:?}", HEARTBEAT_INTERVAL));
+                }
+                sink.send(Message::Ping(Vec::new())).await?;
+                ping_sent = Some(Instant::now());
+
+                let elapsed = window_start.elapsed();
+                update(status, |snapshot| snapshot.on_throughput(window_bytes, elapsed));
+                window_start = Instant::now();
+                window_bytes = 0;
+            }
+            message = stream.next() => match message {
+                Some(Ok(Message::Pong(_))) => {
+                    if let Some(sent) = ping_sent.take() {
+                        let latency_ms = sent.elapsed().as_secs_f64() * 1000.0;
+                        update(status, |snapshot| snapshot.on_latency(latency_ms));
+                    }
+                }
+                Some(Ok(Message::Close(_))) | None => return Ok(()),
+                Some(Ok(message)) => {
+                    let bytes = message.len() as u64;
+                    window_bytes += bytes;
+                    update(status, |snapshot| snapshot.connection.data_transferred_bytes += bytes);
+                }
+                Some(Err(e)) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+/// Call `emit` with each new snapshot until the connector is dropped
+pub async fn forward_status_changes(
+    mut receiver: watch::Receiver<EdgeStatusSnapshot>,
+    mut emit: impl FnMut(&EdgeStatusSnapshot),
+) {
+    while receiver.changed().await.is_ok() {
+        let snapshot = receiver.borrow_and_update().clone();
+        emit(&snapshot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_status_change_is_forwarded() {
+        let connector = EdgeConnector::new().await.unwrap();
+        let (events_tx, mut events) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(forward_status_changes(connector.subscribe(), move |snapshot| {
+            let _ = events_tx.send(snapshot.clone());
+        }));
+
+        update(&connector.status, |snapshot| snapshot.on_connected("wss://edge-eu.example.net/ws"));
+        let connected = events.recv().await.unwrap();
+        assert!(connected.connection.connected);
+        assert_eq!(connected.connection.edge_location, "edge-eu.example.net");
+
+        update(&connector.status, |snapshot| snapshot.on_latency(180.0));
+        let slow = events.recv().await.unwrap();
+        assert_eq!(slow.connection.connection_quality, ConnectionQuality::Fair);
+
+        // Unchanged values don't produce an event
+        update(&connector.status, |snapshot| snapshot.on_throughput(0, Duration::ZERO));
+
+        update(&connector.status, |snapshot| {
+            snapshot.on_disconnected(true);
+            snapshot.on_retry_scheduled(2, backoff_delay(2));
+        });
+        let retrying = events.recv().await.unwrap();
+        assert!(!retrying.connection.connected);
+        assert_eq!(retrying.connection.error_count, 1);
+        assert_eq!((retrying.reconnect.attempt, retrying.reconnect.backoff_ms), (2, 1000));
+        assert!(retrying.reconnect.next_retry_at.is_some());
+        assert_eq!(connector.snapshot(), retrying);
+
+        let json = serde_json::to_value(&retrying).unwrap();
+        assert_eq!(json["connected"], false);
+        assert_eq!(json["reconnect"]["backoff_ms"], 1000);
+        assert!(events.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_cap() {
+        assert_eq!(backoff_delay(1), Duration::from_millis(500));
+        assert_eq!(backoff_delay(3), Duration::from_millis(2000));
+        assert_eq!(backoff_delay(10), Duration::from_millis(RECONNECT_MAX_DELAY_MS));
+        assert_eq!(backoff_delay(u32::MAX), Duration::from_millis(RECONNECT_MAX_DELAY_MS));
+    }
+}
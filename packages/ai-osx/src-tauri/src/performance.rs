@@ -0,0 +1,155 @@
+// Performance Module - Process metrics for the performance monitor UI
+
+use crate::models::*;
+use std::collections::VecDeque;
+use std::time::Instant;
+use tokio::sync::RwLock;
+use tracing::info;
+use chrono::Utc;
+use anyhow::Result;
+
+/// Event the frontend listens on for metric snapshots
+pub const PERFORMANCE_METRICS_EVENT: &str = "performance-metrics";
+/// Label of the dedicated performance window, which also receives snapshots
+pub const PERFORMANCE_WINDOW_LABEL: &str = "performance-monitor";
+pub const PERFORMANCE_STREAM_INTERVAL_MS: u64 = 1000;
+
+/// Response times kept for the `response_time_ms` series
+const RESPONSE_TIME_WINDOW: usize = 100;
+
+#[derive(Debug, Default)]
+struct OperationLog {
+    response_times_ms: VecDeque<f64>,
+    operations: u64,
+    errors: u64,
+}
+
+#[derive(Debug)]
+pub struct PerformanceMonitor {
+    started_at: Instant,
+    operations: RwLock<OperationLog>,
+    /// Wall clock and process CPU seconds at the previous snapshot
+    last_cpu_sample: RwLock<(Instant, f64)>,
+}
+
+impl PerformanceMonitor {
+    pub async fn new() -> Result<Self> {
+        info!("Initializing Performance Monitor...");
+
+        let now = Instant::now();
+        Ok(Self {
+            started_at: now,
+            operations: RwLock::new(OperationLog::default()),
+            last_cpu_sample: RwLock::new((now, process_cpu_seconds())),
+        })
+    }
+
+    pub async fn record_operation(&self, response_time_ms: f64, success: bool) {
+        let mut log = self.operations.write().await;
+        log.operations += 1;
+        if !success {
+            log.errors += 1;
+        }
+        log.response_times_ms.push_back(response_time_ms);
+        if log.response_times_ms.len() > RESPONSE_TIME_WINDOW {
+            log.response_times_ms.pop_front();
+        }
+    }
+
+    /// Snapshot of the process. CPU usage is measured since the previous
+    /// snapshot, so the first call after startup covers the whole uptime.
+    pub async fn get_current_metrics(&self) -> PerformanceMetrics {
+        let now = Instant::now();
+        let cpu_seconds = process_cpu_seconds();
+        let cpu_usage = {
+            let mut last = self.last_cpu_sample.write().await;
+            let wall = now.duration_since(last.0).as_secs_f64();
+            let usage = if wall > 0.0 { (cpu_seconds - last.1) / wall * 100.0 } else { 0.0 };
+            *last = (now, cpu_seconds);
+            usage
+        };
+
+        let log = self.operations.read().await;
+        let uptime = now.duration_since(self.started_at);
+
+        PerformanceMetrics {
+            cpu_usage: cpu_usage.max(0.0) as f32,
+            memory_usage: peak_resident_mb(),
+            response_time_ms: log.response_times_ms.iter().copied().collect(),
+            throughput_ops_per_sec: log.operations as f64 / uptime.as_secs_f64().max(1.0),
+            error_rate: if log.operations > 0 { log.errors as f32 / log.operations as f32 } else { 0.0 },
+            uptime_seconds: uptime.as_secs(),
+            timestamp: Utc::now(),
+            ..PerformanceMetrics::default()
+        }
+    }
+}
+
+/// Payload sent with `PERFORMANCE_METRICS_EVENT`: the metrics in their
+/// serialized form, so the frontend sees the same shape as `get_performance_metrics`
+pub fn metrics_payload(metrics: &PerformanceMetrics) -> serde_json::Result<serde_json::Value> {
+    serde_json::to_value(metrics)
+}
+
+#[cfg(unix)]
+fn resource_usage() -> Option<libc::rusage> {
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    (unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } == 0).then_some(usage)
+}
+
+#[cfg(unix)]
+fn process_cpu_seconds() -> f64 {
+    let seconds = |t: libc::timeval| t.tv_sec as f64 + t.tv_usec as f64 / 1e6;
+    resource_usage().map_or(0.0, |usage| seconds(usage.ru_utime) + seconds(usage.ru_stime))
+}
+
+#[cfg(not(unix))]
+fn process_cpu_seconds() -> f64 {
+    0.0
+}
+
+/// Peak resident set size in megabytes
+#[cfg(unix)]
+fn peak_resident_mb() -> f64 {
+    // ru_maxrss is bytes on macOS and kilobytes elsewhere
+    let unit = if cfg!(target_os = "macos") { 1.0 } else { 1024.0 };
+    resource_usage().map_or(0.0, |usage| usage.ru_maxrss as f64 * unit / (1024.0 * 1024.0))
+}
+
+#[cfg(not(unix))]
+fn peak_resident_mb() -> f64 {
+    0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_payload_matches_serialized_metrics() {
+        let monitor = PerformanceMonitor::new().await.unwrap();
+        monitor.record_operation(12.5, true).await;
+        monitor.record_operation(40.0, false).await;
+
+        let metrics = monitor.get_current_metrics().await;
+        let payload = metrics_payload(&metrics).unwrap();
+        assert_eq!(payload, serde_json::to_value(&metrics).unwrap());
+
+        let mut keys: Vec<&str> = payload.as_object().unwrap().keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            [
+                "cpu_usage", "disk_io", "error_rate", "gpu_usage", "memory_usage", "network_io",
+                "power_consumption", "response_time_ms", "temperature", "throughput_ops_per_sec",
+                "timestamp", "uptime_seconds",
+            ]
+        );
+        assert_eq!(payload["response_time_ms"], serde_json::json!([12.5, 40.0]));
+        assert_eq!(payload["error_rate"], serde_json::json!(0.5));
+
+        // The frontend's shape deserializes back into the same metrics
+        let round_trip: PerformanceMetrics = serde_json::from_value(payload).unwrap();
+        assert_eq!(round_trip.response_time_ms, metrics.response_time_ms);
+    }
+}
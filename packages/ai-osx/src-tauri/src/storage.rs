@@ -0,0 +1,139 @@
+// Storage Module - Local SQLite persistence
+// Cognitive sessions are written through on every mutation and reloaded at
+// startup
+
+use crate::cognitive::{CognitiveState, COGNITIVE_STATE_VERSION};
+use rusqlite::{params, Connection};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::{info, warn};
+use anyhow::{Result, anyhow};
+
+const DATABASE_FILE: &str = "ai-osx.db";
+
+#[derive(Debug)]
+pub struct LocalStorage {
+    connection: Mutex<Connection>,
+}
+
+impl LocalStorage {
+    /// Open the database in the platform data directory
+    pub async fn new() -> Result<Self> {
+        info!("Initializing Local Storage...");
+
+        let directory = dirs::data_dir()
+            .ok_or_else(|| anyhow!("No data directory available on this platform"))?
+            .join("ai-osx");
+        std::fs::create_dir_all(&directory)?;
+        Self::open(directory.join(DATABASE_FILE))
+    }
+
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let connection = Connection::open(path)?;
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS cognitive_sessions (
+                session_id TEXT PRIMARY KEY,
+                schema_version INTEGER NOT NULL,
+                state TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            );",
+        )?;
+
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    pub fn save_session(&self, state: &CognitiveState) -> Result<()> {
+        let json = serde_json::to_string(state)?;
+        self.connection.lock().unwrap().execute(
+            "INSERT INTO cognitive_sessions (session_id, schema_version, state, updated_at)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(session_id) DO UPDATE SET
+                schema_version = excluded.schema_version,
+                state = excluded.state,
+                updated_at = excluded.updated_at",
+            params![state.session_id, COGNITIVE_STATE_VERSION, json, state.updated_at.to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    pub fn delete_session(&self, session_id: &str) -> Result<bool> {
+        let deleted = self
+            .connection
+            .lock()
+            .unwrap()
+            .execute("DELETE FROM cognitive_sessions WHERE session_id = ?1", params![session_id])?;
+        Ok(deleted > 0)
+    }
+
+    /// Every persisted session, migrated to the current schema. Rows that
+    /// can't be decoded are skipped, so one bad session doesn't block startup.
+    pub fn load_sessions(&self) -> Result<HashMap<String, CognitiveState>> {
+        let connection = self.connection.lock().unwrap();
+        let mut statement = connection.prepare("SELECT session_id, schema_version, state FROM cognitive_sessions")?;
+        let rows = statement.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, u32>(1)?, row.get::<_, String>(2)?))
+        })?;
+
+        let mut sessions = HashMap::new();
+        let mut migrated = Vec::new();
+        for row in rows {
+            let (session_id, version, json) = row?;
+            let state = serde_json::from_str(&json)
+                .map_err(anyhow::Error::from)
+                .and_then(|value| CognitiveState::from_versioned(value, version));
+            match state {
+                Ok(state) => {
+                    if version != COGNITIVE_STATE_VERSION {
+                        migrated.push(state.clone());
+                    }
+                    sessions.insert(session_id, state);
+                }
+                Err(e) => warn!("Skipping cognitive session {}: {}", session_id, e),
+            }
+        }
+        drop(statement);
+        drop(connection);
+
+        // Rewrite migrated rows so they're only upgraded once
+        for state in &migrated {
+            self.save_session(state)?;
+        }
+
+        info!("Loaded {} cognitive sessions", sessions.len());
+        Ok(sessions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sessions_round_trip_and_skip_unreadable_rows() {
+        let path = std::env::temp_dir().join(format!("ai-osx-storage-{}.db", uuid::Uuid::new_v4()));
+        let storage = LocalStorage::open(&path).unwrap();
+
+        let state = CognitiveState::new();
+        storage.save_session(&state).unwrap();
+        storage
+            .connection
+            .lock()
+            .unwrap()
+            .execute(
+                "INSERT INTO cognitive_sessions VALUES ('future', ?1, '{}', '')",
+                params![COGNITIVE_STATE_VERSION + 1],
+            )
+            .unwrap();
+
+        let loaded = LocalStorage::open(&path).unwrap().load_sessions().unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[&state.session_id], state);
+
+        assert!(storage.delete_session(&state.session_id).unwrap());
+        assert!(!storage.delete_session(&state.session_id).unwrap());
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -1,12 +1,17 @@
-use rustler::{Atom, Encoder, Env, Error, NifResult, Term};
+use rustler::{Atom, Encoder, Env, Error, NifResult, ResourceArc, Term};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::StandardNormal;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use rayon::prelude::*;
 use ndarray::{Array2, ArrayView2};
 use nalgebra::{DMatrix, DVector};
 use tokio::runtime::Runtime;
 use std::collections::HashMap;
+use once_cell::sync::Lazy;
+use katalyst_compute_core::{euclidean_distance, k_means_from, validate_k_means_input, DistanceMetric};
 
 mod atoms {
     rustler::atoms! {
@@ -20,6 +25,57 @@ mod atoms {
     }
 }
 
+/// Failure categories surfaced to Elixir as `{:error, {category, detail}}`,
+/// so callers can match on the atom and still log the detail
+#[derive(Debug, thiserror::Error)]
+pub enum BraunError {
+    #[error("{0}")]
+    Parse(String),
+    #[error("{0}")]
+    DimensionMismatch(String),
+    #[error("{0}")]
+    Singular(String),
+    #[error("{0}")]
+    UnknownOperation(String),
+    #[error("{0}")]
+    InvalidArgument(String),
+    #[error("{0}")]
+    Timeout(String),
+    #[error("Response serialization error: {0}")]
+    Serialization(String),
+    #[error("{0}")]
+    NonFinite(String),
+}
+
+impl BraunError {
+    /// The Elixir atom naming this error's category
+    pub fn kind(&self) -> &'static str {
+        match self {
+            BraunError::Parse(_) => "parse_error",
+            BraunError::DimensionMismatch(_) => "dimension_mismatch",
+            BraunError::Singular(_) => "singular",
+            BraunError::UnknownOperation(_) => "unknown_operation",
+            BraunError::InvalidArgument(_) => "invalid_argument",
+            BraunError::Timeout(_) => "timeout",
+            BraunError::Serialization(_) => "serialization_error",
+            BraunError::NonFinite(_) => "non_finite",
+        }
+    }
+}
+
+impl Encoder for BraunError {
+    fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        let kind = Atom::from_str(env, self.kind()).unwrap_or_else(|_| atoms::error());
+        (kind, self.to_string()).encode(env)
+    }
+}
+
+impl From<BraunError> for Error {
+    fn from(error: BraunError) -> Self {
+        Error::Term(Box::new(error))
+    }
+}
+
 // Core computational structures
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ComputationRequest {
@@ -40,6 +96,9 @@ pub struct ComputationResponse {
     pub cpu_utilization: f64,
     pub convergence_status: String,
     pub error_metrics: HashMap<String, f64>,
+    /// Whether the result came from the compute cache
+    #[serde(default)]
+    pub cached: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -49,9 +108,18 @@ pub struct OptimizationParams {
     pub convergence_threshold: f64,
     pub learning_rate: f64,
     pub regularization: f64,
+    /// Seed for the optimizer's RNG; a fixed seed makes runs reproducible
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Wall-clock budget; the optimizer stops early with a partial result
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+    /// Record the best energy after every iteration in `energy_history`
+    #[serde(default)]
+    pub track_history: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldState {
     pub field_values: HashMap<String, f64>,
     pub topology: Vec<Vec<f64>>,
@@ -60,6 +128,99 @@ pub struct FieldState {
     pub temporal_signature: Vec<f64>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MatrixOperationItem {
+    pub operation: String,
+    pub matrices: Vec<Vec<Vec<f64>>>,
+    #[serde(flatten)]
+    pub options: MatrixOptions,
+}
+
+/// Per-call tuning for matrix operations; every field is optional in JSON
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MatrixOptions {
+    #[serde(default)]
+    pub precision: MatrixPrecision,
+    /// Target rank for `truncated_svd`
+    #[serde(default)]
+    pub rank: Option<usize>,
+    /// Seed for the random projection in `truncated_svd`
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Serve repeated identical calls from the compute cache
+    #[serde(default)]
+    pub cache: bool,
+}
+
+/// Floating-point width the matrix math runs in; f32 halves memory and
+/// bandwidth at the cost of precision
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatrixPrecision {
+    F32,
+    #[default]
+    F64,
+}
+
+/// Matrix operation input: either a bare list of matrices or an object
+/// carrying the matrices together with `MatrixOptions`
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum MatrixOperationsInput {
+    Matrices(Vec<Vec<Vec<f64>>>),
+    WithOptions {
+        matrices: Vec<Vec<Vec<f64>>>,
+        #[serde(flatten)]
+        options: MatrixOptions,
+    },
+}
+
+/// Scalar types the matrix operations can run in; values cross the JSON
+/// boundary as f64 either way
+trait MatrixScalar: nalgebra::RealField + Copy {
+    fn narrow(value: f64) -> Self;
+    fn widen(self) -> f64;
+    /// Row-major product from the shared compute core
+    fn matmul(a: &[Self], b: &[Self], rows_a: usize, cols_a: usize, cols_b: usize) -> Result<Vec<Self>, String>;
+}
+
+impl MatrixScalar for f32 {
+    fn narrow(value: f64) -> Self {
+        value as f32
+    }
+
+    fn widen(self) -> f64 {
+        self as f64
+    }
+
+    fn matmul(a: &[Self], b: &[Self], rows_a: usize, cols_a: usize, cols_b: usize) -> Result<Vec<Self>, String> {
+        katalyst_compute_core::matmul(a, b, rows_a, cols_a, cols_b)
+    }
+}
+
+impl MatrixScalar for f64 {
+    fn narrow(value: f64) -> Self {
+        value
+    }
+
+    fn widen(self) -> f64 {
+        self
+    }
+
+    fn matmul(a: &[Self], b: &[Self], rows_a: usize, cols_a: usize, cols_b: usize) -> Result<Vec<Self>, String> {
+        katalyst_compute_core::matmul(a, b, rows_a, cols_a, cols_b)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MatrixOperationResult {
+    pub status: String,
+    pub result: Option<Vec<Vec<f64>>>,
+    pub error: Option<String>,
+    /// Error category, matching the atom the single-operation NIF returns
+    pub error_kind: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PatternData {
     pub pattern_id: String,
@@ -69,242 +230,825 @@ pub struct PatternData {
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
+/// Pattern recognition input: either a bare list of patterns or an object
+/// carrying the patterns together with a `timeout_ms` budget
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PatternRecognitionInput {
+    Patterns(Vec<PatternData>),
+    WithOptions {
+        patterns: Vec<PatternData>,
+        #[serde(default)]
+        timeout_ms: Option<u64>,
+        #[serde(default)]
+        preprocessing: Option<Preprocessing>,
+    },
+}
+
+/// Transformation applied to every `feature_vector` before clustering,
+/// e.g. `{"method": "pca", "components": 2}`
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+enum Preprocessing {
+    Pca { components: usize },
+}
+
+/// Wall-clock budget checked once per iteration by the iterative algorithms,
+/// so an overrunning computation returns a partial result instead of holding
+/// a dirty scheduler
+#[derive(Debug, Clone, Copy)]
+struct Deadline(Option<std::time::Instant>);
+
+impl Deadline {
+    fn none() -> Self {
+        Deadline(None)
+    }
+
+    fn after_ms(timeout_ms: Option<u64>) -> Self {
+        Deadline(timeout_ms.map(|ms| std::time::Instant::now() + std::time::Duration::from_millis(ms)))
+    }
+
+    fn expired(&self) -> bool {
+        self.0.map_or(false, |at| std::time::Instant::now() >= at)
+    }
+}
+
+/// Run a NIF body inside a `braun_nif` span carrying the NIF name,
+/// operation, input size and a request id (which bodies use as the response
+/// id), and log failures. Disabled spans cost one atomic load, so this is
+/// negligible without a subscriber.
+fn traced<T>(nif: &'static str, operation: &str, input_size: usize, body: impl FnOnce(&str) -> Result<T, BraunError>) -> NifResult<T> {
+    let request_id = uuid::Uuid::new_v4().to_string();
+    let span = tracing::info_span!("braun_nif", nif, operation, input_size, request_id = request_id.as_str());
+    let _entered = span.enter();
+    
+    body(&request_id).map_err(|error| {
+        tracing::warn!(kind = error.kind(), error = %error, "computation failed");
+        error.into()
+    })
+}
+
+/// Serialize a NIF response, emitting its convergence status as an event
+fn finish(response: &ComputationResponse) -> Result<String, BraunError> {
+    if response.convergence_status == "timeout" {
+        tracing::warn!(computation_time_ms = response.computation_time_ms, "computation timed out");
+    } else {
+        tracing::info!(
+            status = response.convergence_status.as_str(),
+            computation_time_ms = response.computation_time_ms,
+            "computation finished"
+        );
+    }
+    
+    serde_json::to_string(response)
+        .map_err(|e| BraunError::Serialization(e.to_string()))
+}
+
+/// NaN and infinity counts found in a result before serialization
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct NonFiniteCounts {
+    nan: usize,
+    inf: usize,
+}
+
+impl NonFiniteCounts {
+    /// `nan_count` and `inf_count` entries for a response's `error_metrics`
+    fn metrics(&self) -> HashMap<String, f64> {
+        HashMap::from([
+            ("nan_count".to_string(), self.nan as f64),
+            ("inf_count".to_string(), self.inf as f64),
+        ])
+    }
+}
+
+/// Results whose floats are checked before serialization; serde_json would
+/// otherwise write NaN and infinity as `null` without complaint
+trait SanitizeFloats {
+    fn sanitize_floats(&mut self, counts: &mut NonFiniteCounts);
+}
+
+/// NaN becomes 0.0 and infinities the largest finite value of their sign
+impl SanitizeFloats for f64 {
+    fn sanitize_floats(&mut self, counts: &mut NonFiniteCounts) {
+        if self.is_nan() {
+            counts.nan += 1;
+            *self = 0.0;
+        } else if self.is_infinite() {
+            counts.inf += 1;
+            *self = f64::MAX.copysign(*self);
+        }
+    }
+}
+
+impl<T: SanitizeFloats> SanitizeFloats for Vec<T> {
+    fn sanitize_floats(&mut self, counts: &mut NonFiniteCounts) {
+        self.iter_mut().for_each(|value| value.sanitize_floats(counts));
+    }
+}
+
+impl<T: SanitizeFloats> SanitizeFloats for Option<T> {
+    fn sanitize_floats(&mut self, counts: &mut NonFiniteCounts) {
+        if let Some(value) = self {
+            value.sanitize_floats(counts);
+        }
+    }
+}
+
+impl<K, T: SanitizeFloats> SanitizeFloats for HashMap<K, T> {
+    fn sanitize_floats(&mut self, counts: &mut NonFiniteCounts) {
+        self.values_mut().for_each(|value| value.sanitize_floats(counts));
+    }
+}
+
+/// When set, a non-finite value anywhere in a result fails the call with
+/// `non_finite` instead of being replaced
+static STRICT_NUMERICS: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+fn sanitize_result<T: SanitizeFloats>(result: &mut T) -> Result<NonFiniteCounts, BraunError> {
+    let mut counts = NonFiniteCounts::default();
+    result.sanitize_floats(&mut counts);
+    
+    if counts != NonFiniteCounts::default() {
+        tracing::warn!(nan_count = counts.nan, inf_count = counts.inf, "non-finite values in result");
+        if STRICT_NUMERICS.load(std::sync::atomic::Ordering::Relaxed) {
+            return Err(BraunError::NonFinite(format!(
+                "Result contains {} NaN and {} infinite values",
+                counts.nan, counts.inf
+            )));
+        }
+    }
+    Ok(counts)
+}
+
+/// Dedicated pool for every parallel section, kept separate from the global
+/// rayon pool so its size can be set relative to the BEAM schedulers
+static COMPUTE_POOL: Lazy<RwLock<Arc<rayon::ThreadPool>>> = Lazy::new(|| {
+    let pool = build_compute_pool(default_compute_threads(None), false)
+        .expect("failed to start braun compute pool");
+    RwLock::new(Arc::new(pool))
+});
+
+/// Cores left over after the BEAM schedulers when their count is known,
+/// otherwise half the cores; always at least one thread
+fn default_compute_threads(schedulers: Option<usize>) -> usize {
+    let cpus = num_cpus::get();
+    match schedulers {
+        Some(schedulers) if cpus > schedulers => cpus - schedulers,
+        _ => (cpus / 2).max(1),
+    }
+}
+
+fn build_compute_pool(num_threads: usize, pin_to_cores: bool) -> Result<rayon::ThreadPool, BraunError> {
+    let mut builder = rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .thread_name(|index| format!("braun-compute-{}", index));
+    
+    if pin_to_cores {
+        let core_ids = core_affinity::get_core_ids().unwrap_or_default();
+        if !core_ids.is_empty() {
+            builder = builder.start_handler(move |index| {
+                core_affinity::set_for_current(core_ids[index % core_ids.len()]);
+            });
+        }
+    }
+    
+    builder.build()
+        .map_err(|e| BraunError::InvalidArgument(format!("Could not start compute pool: {}", e)))
+}
+
+/// Replace the compute pool; `num_threads == 0` picks the default size.
+/// Work already running finishes on the old pool.
+fn configure_compute_pool(num_threads: usize, pin_to_cores: bool) -> Result<usize, BraunError> {
+    let num_threads = if num_threads == 0 { default_compute_threads(None) } else { num_threads };
+    let pool = build_compute_pool(num_threads, pin_to_cores)?;
+    *COMPUTE_POOL.write() = Arc::new(pool);
+    Ok(num_threads)
+}
+
+/// Run `op` inside the compute pool so its `par_iter`s use braun's threads
+fn in_compute_pool<R: Send>(op: impl FnOnce() -> R + Send) -> R {
+    let pool = COMPUTE_POOL.read().clone();
+    pool.install(op)
+}
+
+// Size (and optionally pin) the threads used by every parallel computation
+#[rustler::nif]
+fn configure_compute_threads(num_threads: usize, pin_to_cores: bool) -> NifResult<usize> {
+    traced("configure_compute_threads", "configure", num_threads, |_| {
+        configure_compute_pool(num_threads, pin_to_cores)
+    })
+}
+
+/// Matrix results kept for `cache: true` calls unless reconfigured
+const DEFAULT_COMPUTE_CACHE_CAPACITY: usize = 64;
+
+/// Results of `cache: true` matrix operations keyed by a hash of the
+/// operation and its raw JSON input; the least recently used entry is
+/// evicted once `capacity` is reached
+struct ComputeCache {
+    capacity: usize,
+    entries: HashMap<u64, CachedResult>,
+    clock: u64,
+}
+
+/// The operation and input a result was computed from, with their hash
+#[derive(Clone)]
+struct CacheKey {
+    hash: u64,
+    operation: String,
+    input_json: String,
+}
+
+struct CachedResult {
+    // Compared on lookup so a hash collision is a miss, not another
+    // computation's result
+    operation: String,
+    input_json: String,
+    result: serde_json::Value,
+    memory_used_bytes: u64,
+    error_metrics: HashMap<String, f64>,
+    last_used: u64,
+}
+
+impl ComputeCache {
+    fn new(capacity: usize) -> Self {
+        ComputeCache { capacity, entries: HashMap::new(), clock: 0 }
+    }
+
+    fn key(operation: &str, input_json: &str) -> CacheKey {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        (operation, input_json).hash(&mut hasher);
+        CacheKey {
+            hash: hasher.finish(),
+            operation: operation.to_string(),
+            input_json: input_json.to_string(),
+        }
+    }
+
+    fn get(&mut self, key: &CacheKey) -> Option<(serde_json::Value, u64, HashMap<String, f64>)> {
+        self.clock += 1;
+        let entry = self
+            .entries
+            .get_mut(&key.hash)
+            .filter(|entry| entry.operation == key.operation && entry.input_json == key.input_json)?;
+        entry.last_used = self.clock;
+        Some((entry.result.clone(), entry.memory_used_bytes, entry.error_metrics.clone()))
+    }
+
+    fn insert(&mut self, key: CacheKey, result: serde_json::Value, memory_used_bytes: u64, error_metrics: HashMap<String, f64>) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.clock += 1;
+        self.entries.insert(
+            key.hash,
+            CachedResult {
+                operation: key.operation,
+                input_json: key.input_json,
+                result,
+                memory_used_bytes,
+                error_metrics,
+                last_used: self.clock,
+            },
+        );
+        self.evict_to(self.capacity);
+    }
+
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict_to(capacity);
+    }
+
+    fn evict_to(&mut self, capacity: usize) {
+        while self.entries.len() > capacity {
+            let oldest = self.entries.iter().min_by_key(|(_, entry)| entry.last_used).map(|(key, _)| *key);
+            match oldest {
+                Some(key) => self.entries.remove(&key),
+                None => break,
+            };
+        }
+    }
+
+    /// Drop every entry, returning how many there were
+    fn clear(&mut self) -> usize {
+        let cleared = self.entries.len();
+        self.entries.clear();
+        cleared
+    }
+}
+
+static COMPUTE_CACHE: Lazy<Mutex<ComputeCache>> = Lazy::new(|| Mutex::new(ComputeCache::new(DEFAULT_COMPUTE_CACHE_CAPACITY)));
+
+// Resize the matrix result cache; a capacity of 0 disables caching
+#[rustler::nif]
+fn configure_compute_cache(capacity: usize) -> NifResult<usize> {
+    traced("configure_compute_cache", "configure", capacity, |_| {
+        COMPUTE_CACHE.lock().set_capacity(capacity);
+        Ok(capacity)
+    })
+}
+
+// Drop every cached matrix result, returning how many were removed
+#[rustler::nif]
+fn clear_compute_cache() -> NifResult<usize> {
+    traced("clear_compute_cache", "clear", 0, |_| Ok(COMPUTE_CACHE.lock().clear()))
+}
+
+// Fail calls whose results contain NaN or infinity instead of replacing them
+#[rustler::nif]
+fn configure_strict_numerics(strict: bool) -> NifResult<bool> {
+    traced("configure_strict_numerics", "configure", 0, |_| {
+        STRICT_NUMERICS.store(strict, std::sync::atomic::Ordering::Relaxed);
+        Ok(strict)
+    })
+}
+
+// Compare two JSON matrices entry by entry within max(abs_tol, rel_tol * max(|a|, |b|));
+// differently shaped matrices are never equal
+#[rustler::nif]
+fn approx_equal_matrix(a_json: String, b_json: String, rel_tol: f64, abs_tol: f64) -> NifResult<bool> {
+    traced("approx_equal_matrix", "compare", a_json.len() + b_json.len(), |_| {
+        let parse = |json: &str| serde_json::from_str::<Vec<Vec<f64>>>(json)
+            .map_err(|e| BraunError::Parse(format!("Matrix parsing error: {}", e)));
+        let (a, b) = (parse(&a_json)?, parse(&b_json)?);
+        Ok(matrices_approx_equal(&a, &b, rel_tol, abs_tol))
+    })
+}
+
+fn matrices_approx_equal(a: &[Vec<f64>], b: &[Vec<f64>], rel_tol: f64, abs_tol: f64) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).all(|(row_a, row_b)| katalyst_compute_core::approx_equal_matrix(row_a, row_b, rel_tol, abs_tol))
+}
+
 // High-performance numerical computation engine
 #[rustler::nif(schedule = "DirtyCpu")]
 fn compute_matrix_operations(operation: String, matrices_json: String) -> NifResult<String> {
-    let start_time = std::time::Instant::now();
+    traced("compute_matrix_operations", &operation, matrices_json.len(), |request_id| {
+        let start_time = std::time::Instant::now();
+    
+        let input: MatrixOperationsInput = serde_json::from_str(&matrices_json)
+            .map_err(|e| BraunError::Parse(format!("Matrix parsing error: {}", e)))?;
+        let (matrices, options) = match input {
+            MatrixOperationsInput::Matrices(matrices) => (matrices, MatrixOptions::default()),
+            MatrixOperationsInput::WithOptions { matrices, options } => (matrices, options),
+        };
+    
+        let cache_key = options.cache.then(|| ComputeCache::key(&operation, &matrices_json));
+        if let Some((result, memory_used_bytes, error_metrics)) = cache_key.as_ref().and_then(|key| COMPUTE_CACHE.lock().get(key)) {
+            return finish(&ComputationResponse {
+                id: request_id.to_string(),
+                result,
+                computation_time_ms: start_time.elapsed().as_millis() as u64,
+                memory_used_bytes,
+                cpu_utilization: 0.0,
+                convergence_status: "completed".to_string(),
+                error_metrics,
+                cached: true,
+            });
+        }
+    
+        let mut result = run_matrix_operation(&operation, &matrices, &options)?;
+        let error_metrics = sanitize_result(&mut result)?.metrics();
+        let result_value = serde_json::to_value(&result).unwrap();
+        let memory_used_bytes = estimate_memory_usage(&result);
+        if let Some(key) = cache_key {
+            COMPUTE_CACHE.lock().insert(key, result_value.clone(), memory_used_bytes, error_metrics.clone());
+        }
+    
+        let computation_time = start_time.elapsed().as_millis() as u64;
+    
+        let response = ComputationResponse {
+            id: request_id.to_string(),
+            result: result_value,
+            computation_time_ms: computation_time,
+            memory_used_bytes,
+            cpu_utilization: 0.0, // Would be measured in real implementation
+            convergence_status: "completed".to_string(),
+            error_metrics,
+            cached: false,
+        };
+    
+        finish(&response)
+    })
+}
+
+// Batched matrix operations: one JSON parse and one round trip for many small operations
+#[rustler::nif(schedule = "DirtyCpu")]
+fn compute_matrix_operations_batch(operations_json: String) -> NifResult<String> {
+    traced("compute_matrix_operations_batch", "batch", operations_json.len(), |request_id| {
+        let start_time = std::time::Instant::now();
+    
+        let operations: Vec<MatrixOperationItem> = serde_json::from_str(&operations_json)
+            .map_err(|e| BraunError::Parse(format!("Batch parsing error: {}", e)))?;
+    
+        let mut results = run_matrix_operations_batch(&operations);
+        let failed = results.iter().filter(|r| r.status == "error").count();
+        let non_finite = sanitize_result(&mut results)?;
+    
+        let computation_time = start_time.elapsed().as_millis() as u64;
+    
+        let mut error_metrics = non_finite.metrics();
+        error_metrics.insert("failed_items".to_string(), failed as f64);
+    
+        let response = ComputationResponse {
+            id: request_id.to_string(),
+            result: serde_json::to_value(&results).unwrap(),
+            computation_time_ms: computation_time,
+            memory_used_bytes: estimate_memory_usage(&results),
+            cpu_utilization: 0.0,
+            convergence_status: if failed == 0 { "completed".to_string() } else { "partial".to_string() },
+            error_metrics,
+            cached: false,
+        };
+    
+        finish(&response)
+    })
+}
+
+// Streamed matrix operations: the result stays in Rust and is pulled as
+// newline-delimited JSON with `read_stream_chunk`, so a large result never
+// becomes one giant binary on the BEAM side
+#[rustler::nif(schedule = "DirtyCpu")]
+fn compute_matrix_operations_stream(operation: String, matrices_json: String) -> NifResult<ResourceArc<ResultStream>> {
+    traced("compute_matrix_operations_stream", &operation, matrices_json.len(), |request_id| {
+        let start_time = std::time::Instant::now();
+    
+        let input: MatrixOperationsInput = serde_json::from_str(&matrices_json)
+            .map_err(|e| BraunError::Parse(format!("Matrix parsing error: {}", e)))?;
+        let (matrices, options) = match input {
+            MatrixOperationsInput::Matrices(matrices) => (matrices, MatrixOptions::default()),
+            MatrixOperationsInput::WithOptions { matrices, options } => (matrices, options),
+        };
     
-    let matrices: Vec<Vec<Vec<f64>>> = serde_json::from_str(&matrices_json)
-        .map_err(|e| Error::Term(Box::new(format!("Matrix parsing error: {}", e))))?;
+        let mut result = run_matrix_operation(&operation, &matrices, &options)?;
+        let non_finite = sanitize_result(&mut result)?;
     
-    let result = match operation.as_str() {
+        let header = ComputationResponse {
+            id: request_id.to_string(),
+            result: serde_json::json!({"format": "ndjson", "rows": result.len()}),
+            computation_time_ms: start_time.elapsed().as_millis() as u64,
+            memory_used_bytes: estimate_memory_usage(&result),
+            cpu_utilization: 0.0,
+            convergence_status: "completed".to_string(),
+            error_metrics: non_finite.metrics(),
+            cached: false,
+        };
+    
+        Ok(ResourceArc::new(ResultStream::new(header, result)?))
+    })
+}
+
+// Next chunk of a streamed result, or nil once every row has been read
+#[rustler::nif(schedule = "DirtyCpu")]
+fn read_stream_chunk(stream: ResourceArc<ResultStream>, max_rows: usize) -> NifResult<Option<String>> {
+    traced("read_stream_chunk", "read", max_rows, |_| {
+        stream.next_chunk(max_rows)
+    })
+}
+
+/// Runs every item in parallel; a failing item yields an error entry at its
+/// index instead of failing the whole batch
+fn run_matrix_operations_batch(operations: &[MatrixOperationItem]) -> Vec<MatrixOperationResult> {
+    in_compute_pool(|| {
+        operations.par_iter()
+            .map(|item| match run_matrix_operation(&item.operation, &item.matrices, &item.options) {
+                Ok(result) => MatrixOperationResult {
+                    status: "ok".to_string(),
+                    result: Some(result),
+                    error: None,
+                    error_kind: None,
+                },
+                Err(error) => MatrixOperationResult {
+                    status: "error".to_string(),
+                    result: None,
+                    error: Some(error.to_string()),
+                    error_kind: Some(error.kind().to_string()),
+                },
+            })
+            .collect()
+    })
+}
+
+fn run_matrix_operation(operation: &str, matrices: &[Vec<Vec<f64>>], options: &MatrixOptions) -> Result<Vec<Vec<f64>>, BraunError> {
+    match options.precision {
+        MatrixPrecision::F32 => run_matrix_operation_as::<f32>(operation, matrices, options),
+        MatrixPrecision::F64 => run_matrix_operation_as::<f64>(operation, matrices, options),
+    }
+}
+
+fn run_matrix_operation_as<T: MatrixScalar>(operation: &str, matrices: &[Vec<Vec<f64>>], options: &MatrixOptions) -> Result<Vec<Vec<f64>>, BraunError> {
+    let result = match operation {
         "multiply" => {
             if matrices.len() != 2 {
-                return Err(Error::Term(Box::new("Matrix multiplication requires exactly 2 matrices")));
+                return Err(BraunError::InvalidArgument("Matrix multiplication requires exactly 2 matrices".to_string()));
             }
             
-            let a = DMatrix::from_row_slice(matrices[0].len(), matrices[0][0].len(), 
-                &matrices[0].iter().flatten().copied().collect::<Vec<_>>());
-            let b = DMatrix::from_row_slice(matrices[1].len(), matrices[1][0].len(),
-                &matrices[1].iter().flatten().copied().collect::<Vec<_>>());
-            
-            let result_matrix = a * b;
-            matrix_to_vec2d(&result_matrix)
+            multiply_matrices::<T>(&matrices[0], &matrices[1])?
         },
         "eigendecomposition" => {
             if matrices.is_empty() {
-                return Err(Error::Term(Box::new("Eigendecomposition requires at least one matrix")));
+                return Err(BraunError::InvalidArgument("Eigendecomposition requires at least one matrix".to_string()));
             }
             
-            let matrix = DMatrix::from_row_slice(matrices[0].len(), matrices[0][0].len(),
-                &matrices[0].iter().flatten().copied().collect::<Vec<_>>());
-            
-            match matrix.symmetric_eigen() {
-                eigen => {
-                    let eigenvalues = eigen.eigenvalues.as_slice().to_vec();
-                    let eigenvectors = matrix_to_vec2d(&eigen.eigenvectors);
-                    vec![eigenvalues, eigenvectors.into_iter().flatten().collect()]
-                }
+            let matrix = to_dmatrix::<T>(&matrices[0])?;
+            if !matrix.is_square() {
+                return Err(BraunError::DimensionMismatch("Eigendecomposition requires a square matrix".to_string()));
             }
+            
+            let eigen = matrix.symmetric_eigen();
+            let eigenvalues = eigen.eigenvalues.iter().map(|v| v.widen()).collect();
+            let eigenvectors = matrix_to_vec2d(&eigen.eigenvectors);
+            vec![eigenvalues, eigenvectors.into_iter().flatten().collect()]
         },
         "svd" => {
             if matrices.is_empty() {
-                return Err(Error::Term(Box::new("SVD requires at least one matrix")));
+                return Err(BraunError::InvalidArgument("SVD requires at least one matrix".to_string()));
             }
             
-            let matrix = DMatrix::from_row_slice(matrices[0].len(), matrices[0][0].len(),
-                &matrices[0].iter().flatten().copied().collect::<Vec<_>>());
+            let matrix = to_dmatrix::<T>(&matrices[0])?;
             
-            match matrix.svd(true, true) {
-                svd => {
-                    let mut result = Vec::new();
-                    if let Some(u) = svd.u {
-                        result.push(matrix_to_vec2d(&u).into_iter().flatten().collect());
-                    }
-                    result.push(svd.singular_values.as_slice().to_vec());
-                    if let Some(vt) = svd.v_t {
-                        result.push(matrix_to_vec2d(&vt).into_iter().flatten().collect());
-                    }
-                    result
-                }
+            let svd = matrix.svd(true, true);
+            let mut result = Vec::new();
+            if let Some(u) = svd.u {
+                result.push(matrix_to_vec2d(&u).into_iter().flatten().collect());
+            }
+            result.push(svd.singular_values.iter().map(|v| v.widen()).collect());
+            if let Some(vt) = svd.v_t {
+                result.push(matrix_to_vec2d(&vt).into_iter().flatten().collect());
             }
+            result
         },
-        _ => return Err(Error::Term(Box::new("Unknown matrix operation")))
-    };
-    
-    let computation_time = start_time.elapsed().as_millis() as u64;
-    
-    let response = ComputationResponse {
-        id: uuid::Uuid::new_v4().to_string(),
-        result: serde_json::to_value(&result).unwrap(),
-        computation_time_ms: computation_time,
-        memory_used_bytes: estimate_memory_usage(&result),
-        cpu_utilization: 0.0, // Would be measured in real implementation
-        convergence_status: "completed".to_string(),
-        error_metrics: HashMap::new(),
+        "truncated_svd" => {
+            if matrices.is_empty() {
+                return Err(BraunError::InvalidArgument("Truncated SVD requires at least one matrix".to_string()));
+            }
+            let rank = options.rank
+                .ok_or_else(|| BraunError::InvalidArgument("Truncated SVD requires a rank".to_string()))?;
+            
+            let matrix = to_dmatrix::<T>(&matrices[0])?;
+            let mut rng = optimization_rng(options.seed);
+            
+            let (u, singular_values, v_t) = randomized_svd(&matrix, rank, &mut rng)?;
+            vec![
+                matrix_to_vec2d(&u).into_iter().flatten().collect(),
+                singular_values,
+                matrix_to_vec2d(&v_t).into_iter().flatten().collect(),
+            ]
+        },
+        _ => return Err(BraunError::UnknownOperation(format!("Unknown matrix operation: {}", operation)))
     };
     
-    serde_json::to_string(&response)
-        .map_err(|e| Error::Term(Box::new(format!("Response serialization error: {}", e))))
+    Ok(result)
 }
 
 // Quantum-inspired optimization algorithms
 #[rustler::nif(schedule = "DirtyCpu")]
 fn quantum_inspired_optimization(problem_json: String, params_json: String) -> NifResult<String> {
-    let start_time = std::time::Instant::now();
+    traced("quantum_inspired_optimization", "optimization", problem_json.len() + params_json.len(), |request_id| {
+        let start_time = std::time::Instant::now();
     
-    let problem: serde_json::Value = serde_json::from_str(&problem_json)
-        .map_err(|e| Error::Term(Box::new(format!("Problem parsing error: {}", e))))?;
-    
-    let params: OptimizationParams = serde_json::from_str(&params_json)
-        .map_err(|e| Error::Term(Box::new(format!("Parameters parsing error: {}", e))))?;
-    
-    // Quantum-inspired algorithm implementation
-    let result = match params.algorithm.as_str() {
-        "quantum_annealing" => quantum_annealing_optimization(&problem, &params),
-        "quantum_genetic" => quantum_genetic_algorithm(&problem, &params),
-        "adiabatic_evolution" => adiabatic_evolution_optimization(&problem, &params),
-        "variational_quantum" => variational_quantum_eigensolver(&problem, &params),
-        _ => return Err(Error::Term(Box::new("Unknown quantum optimization algorithm")))
-    }?;
-    
-    let computation_time = start_time.elapsed().as_millis() as u64;
-    
-    let response = ComputationResponse {
-        id: uuid::Uuid::new_v4().to_string(),
-        result: serde_json::to_value(&result).unwrap(),
-        computation_time_ms: computation_time,
-        memory_used_bytes: std::mem::size_of_val(&result) as u64,
-        cpu_utilization: measure_cpu_utilization(),
-        convergence_status: if result.converged { "converged".to_string() } else { "max_iterations".to_string() },
-        error_metrics: result.error_metrics,
-    };
+        let problem: serde_json::Value = serde_json::from_str(&problem_json)
+            .map_err(|e| BraunError::Parse(format!("Problem parsing error: {}", e)))?;
+    
+        let params: OptimizationParams = serde_json::from_str(&params_json)
+            .map_err(|e| BraunError::Parse(format!("Parameters parsing error: {}", e)))?;
+        tracing::Span::current().record("operation", params.algorithm.as_str());
     
-    serde_json::to_string(&response)
-        .map_err(|e| Error::Term(Box::new(format!("Response serialization error: {}", e))))
+        // Quantum-inspired algorithm implementation
+        let mut result = match params.algorithm.as_str() {
+            "quantum_annealing" => quantum_annealing_optimization(&problem, &params),
+            "quantum_genetic" => quantum_genetic_algorithm(&problem, &params),
+            "adiabatic_evolution" => adiabatic_evolution_optimization(&problem, &params),
+            "variational_quantum" => variational_quantum_eigensolver(&problem, &params),
+            _ => return Err(BraunError::UnknownOperation("Unknown quantum optimization algorithm".to_string()).into())
+        }?;
+        let non_finite = sanitize_result(&mut result)?;
+        result.error_metrics.extend(non_finite.metrics());
+    
+        let computation_time = start_time.elapsed().as_millis() as u64;
+    
+        let response = ComputationResponse {
+            id: request_id.to_string(),
+            result: serde_json::to_value(&result).unwrap(),
+            computation_time_ms: computation_time,
+            memory_used_bytes: std::mem::size_of_val(&result) as u64,
+            cpu_utilization: measure_cpu_utilization(),
+            convergence_status: if result.timed_out {
+                "timeout".to_string()
+            } else if result.converged {
+                "converged".to_string()
+            } else {
+                "max_iterations".to_string()
+            },
+            error_metrics: result.error_metrics,
+            cached: false,
+        };
+    
+        finish(&response)
+    })
 }
 
 // High-performance field dynamics simulation
 #[rustler::nif(schedule = "DirtyCpu")]
 fn simulate_field_dynamics(field_state_json: String, perturbation_json: String, time_steps: u32) -> NifResult<String> {
-    let start_time = std::time::Instant::now();
+    traced("simulate_field_dynamics", "field_evolution", field_state_json.len(), |request_id| {
+        let start_time = std::time::Instant::now();
     
-    let field_state: FieldState = serde_json::from_str(&field_state_json)
-        .map_err(|e| Error::Term(Box::new(format!("Field state parsing error: {}", e))))?;
+        let field_state: FieldState = serde_json::from_str(&field_state_json)
+            .map_err(|e| BraunError::Parse(format!("Field state parsing error: {}", e)))?;
     
-    let perturbation: serde_json::Value = serde_json::from_str(&perturbation_json)
-        .map_err(|e| Error::Term(Box::new(format!("Perturbation parsing error: {}", e))))?;
+        let perturbation: serde_json::Value = serde_json::from_str(&perturbation_json)
+            .map_err(|e| BraunError::Parse(format!("Perturbation parsing error: {}", e)))?;
     
-    let evolution = simulate_field_evolution(&field_state, &perturbation, time_steps)?;
+        // The perturbation may carry a "timeout_ms" budget for the whole simulation
+        let deadline = Deadline::after_ms(perturbation.get("timeout_ms").and_then(|v| v.as_u64()));
+        let mut evolution = simulate_field_evolution(&field_state, &perturbation, time_steps, deadline)?;
+        let non_finite = sanitize_result(&mut evolution)?;
     
-    let computation_time = start_time.elapsed().as_millis() as u64;
+        let computation_time = start_time.elapsed().as_millis() as u64;
+        let mut error_metrics = calculate_field_errors(&evolution);
+        error_metrics.extend(non_finite.metrics());
     
-    let response = ComputationResponse {
-        id: uuid::Uuid::new_v4().to_string(),
-        result: serde_json::to_value(&evolution).unwrap(),
-        computation_time_ms: computation_time,
-        memory_used_bytes: estimate_memory_usage(&evolution),
-        cpu_utilization: measure_cpu_utilization(),
-        convergence_status: "field_evolved".to_string(),
-        error_metrics: calculate_field_errors(&evolution),
-    };
+        let response = ComputationResponse {
+            id: request_id.to_string(),
+            result: serde_json::to_value(&evolution).unwrap(),
+            computation_time_ms: computation_time,
+            memory_used_bytes: estimate_memory_usage(&evolution),
+            cpu_utilization: measure_cpu_utilization(),
+            convergence_status: if evolution.timed_out { "timeout".to_string() } else { "field_evolved".to_string() },
+            error_metrics,
+            cached: false,
+        };
     
-    serde_json::to_string(&response)
-        .map_err(|e| Error::Term(Box::new(format!("Response serialization error: {}", e))))
+        finish(&response)
+    })
 }
 
 // Parallel pattern recognition and clustering
 #[rustler::nif(schedule = "DirtyCpu")]
 fn parallel_pattern_recognition(patterns_json: String, algorithm: String) -> NifResult<String> {
-    let start_time = std::time::Instant::now();
+    traced("parallel_pattern_recognition", &algorithm, patterns_json.len(), |request_id| {
+        let start_time = std::time::Instant::now();
     
-    let patterns: Vec<PatternData> = serde_json::from_str(&patterns_json)
-        .map_err(|e| Error::Term(Box::new(format!("Patterns parsing error: {}", e))))?;
+        let input: PatternRecognitionInput = serde_json::from_str(&patterns_json)
+            .map_err(|e| BraunError::Parse(format!("Patterns parsing error: {}", e)))?;
+        let (mut patterns, deadline, preprocessing) = match input {
+            PatternRecognitionInput::Patterns(patterns) => (patterns, Deadline::none(), None),
+            PatternRecognitionInput::WithOptions { patterns, timeout_ms, preprocessing } => {
+                (patterns, Deadline::after_ms(timeout_ms), preprocessing)
+            }
+        };
     
-    let recognition_result = match algorithm.as_str() {
-        "kmeans" => parallel_kmeans_clustering(&patterns)?,
-        "dbscan" => parallel_dbscan_clustering(&patterns)?,
-        "hierarchical" => parallel_hierarchical_clustering(&patterns)?,
-        "spectral" => parallel_spectral_clustering(&patterns)?,
-        "neural_gas" => parallel_neural_gas(&patterns)?,
-        _ => return Err(Error::Term(Box::new("Unknown pattern recognition algorithm")))
-    };
+        if let Some(preprocessing) = preprocessing {
+            preprocess_patterns(&mut patterns, preprocessing)?;
+        }
     
-    let computation_time = start_time.elapsed().as_millis() as u64;
+        // "kmeans" takes an optional distance metric suffix, e.g. "kmeans:cosine",
+        // "gmm" an optional covariance type, e.g. "gmm:diagonal", and "dbscan"
+        // an optional neighbourhood radius, e.g. "dbscan:0.5"
+        let (algorithm, variant) = match algorithm.split_once(':') {
+            Some((algorithm, variant)) => (algorithm, Some(variant)),
+            None => (algorithm.as_str(), None),
+        };
     
-    let response = ComputationResponse {
-        id: uuid::Uuid::new_v4().to_string(),
-        result: serde_json::to_value(&recognition_result).unwrap(),
-        computation_time_ms: computation_time,
-        memory_used_bytes: estimate_memory_usage(&recognition_result),
-        cpu_utilization: measure_cpu_utilization(),
-        convergence_status: "pattern_detected".to_string(),
-        error_metrics: HashMap::new(),
-    };
+        let mut recognition_result = match algorithm {
+            "kmeans" => {
+                let metric = variant.map(parse_distance_metric).transpose()?.unwrap_or(DistanceMetric::Euclidean);
+                parallel_kmeans_clustering(&patterns, metric, deadline)?
+            }
+            "gmm" => {
+                let covariance = variant.map(CovarianceType::parse).transpose()?.unwrap_or(CovarianceType::Full);
+                gmm_clustering(&patterns, covariance, deadline)?
+            }
+            "dbscan" => {
+                let eps = variant
+                    .map(|eps| eps.parse::<f64>().map_err(|e| BraunError::InvalidArgument(format!("Invalid DBSCAN eps '{}': {}", eps, e))))
+                    .transpose()?;
+                parallel_dbscan_clustering(&patterns, eps)?
+            }
+            "hierarchical" => parallel_hierarchical_clustering(&patterns)?,
+            "spectral" => parallel_spectral_clustering(&patterns)?,
+            "neural_gas" => parallel_neural_gas(&patterns)?,
+            _ => return Err(BraunError::UnknownOperation("Unknown pattern recognition algorithm".to_string()).into())
+        };
+        score_clustering(&patterns, &mut recognition_result);
+        let non_finite = sanitize_result(&mut recognition_result)?;
+    
+        let computation_time = start_time.elapsed().as_millis() as u64;
+    
+        let response = ComputationResponse {
+            id: request_id.to_string(),
+            result: serde_json::to_value(&recognition_result).unwrap(),
+            computation_time_ms: computation_time,
+            memory_used_bytes: estimate_memory_usage(&recognition_result),
+            cpu_utilization: measure_cpu_utilization(),
+            convergence_status: if recognition_result.timed_out { "timeout".to_string() } else { "pattern_detected".to_string() },
+            error_metrics: non_finite.metrics(),
+            cached: false,
+        };
+    
+        finish(&response)
+    })
+}
+
+// Dimensionality reduction of feature vectors via principal component analysis
+#[rustler::nif(schedule = "DirtyCpu")]
+fn reduce_dimensions(features_json: String, components: usize) -> NifResult<String> {
+    traced("reduce_dimensions", "pca", features_json.len(), |request_id| {
+        let start_time = std::time::Instant::now();
+    
+        let features: Vec<Vec<f64>> = serde_json::from_str(&features_json)
+            .map_err(|e| BraunError::Parse(format!("Feature parsing error: {}", e)))?;
+    
+        let mut pca = principal_components(&features, components)?;
+        let non_finite = sanitize_result(&mut pca)?;
+    
+        let computation_time = start_time.elapsed().as_millis() as u64;
+    
+        let mut error_metrics = non_finite.metrics();
+        error_metrics.insert("explained_variance".to_string(), pca.explained_variance_ratio.iter().sum());
+    
+        let response = ComputationResponse {
+            id: request_id.to_string(),
+            result: serde_json::to_value(&pca).unwrap(),
+            computation_time_ms: computation_time,
+            memory_used_bytes: estimate_memory_usage(&pca),
+            cpu_utilization: 0.0,
+            convergence_status: "completed".to_string(),
+            error_metrics,
+            cached: false,
+        };
     
-    serde_json::to_string(&response)
-        .map_err(|e| Error::Term(Box::new(format!("Response serialization error: {}", e))))
+        finish(&response)
+    })
 }
 
 // GPU-accelerated tensor operations (placeholder for CUDA/OpenCL)
 #[rustler::nif(schedule = "DirtyCpu")]
 fn gpu_tensor_operations(tensors_json: String, operation: String, device: String) -> NifResult<String> {
-    let start_time = std::time::Instant::now();
+    traced("gpu_tensor_operations", &operation, tensors_json.len(), |request_id| {
+        let start_time = std::time::Instant::now();
     
-    // In a real implementation, this would use CUDA or OpenCL
-    // For now, we'll simulate GPU acceleration with parallel CPU computation
-    let tensors: Vec<Vec<Vec<Vec<f64>>>> = serde_json::from_str(&tensors_json)
-        .map_err(|e| Error::Term(Box::new(format!("Tensor parsing error: {}", e))))?;
-    
-    let result = match operation.as_str() {
-        "convolution" => gpu_simulate_convolution(&tensors)?,
-        "matrix_multiply" => gpu_simulate_matrix_multiply(&tensors)?,
-        "fft" => gpu_simulate_fft(&tensors)?,
-        "reduce_sum" => gpu_simulate_reduce_sum(&tensors)?,
-        _ => return Err(Error::Term(Box::new("Unknown GPU tensor operation")))
-    };
+        // In a real implementation, this would use CUDA or OpenCL
+        // For now, we'll simulate GPU acceleration with parallel CPU computation
+        let tensors: Vec<Vec<Vec<Vec<f64>>>> = serde_json::from_str(&tensors_json)
+            .map_err(|e| BraunError::Parse(format!("Tensor parsing error: {}", e)))?;
     
-    let computation_time = start_time.elapsed().as_millis() as u64;
+        let mut result = match operation.as_str() {
+            "convolution" => gpu_simulate_convolution(&tensors)?,
+            "matrix_multiply" => gpu_simulate_matrix_multiply(&tensors)?,
+            "fft" => gpu_simulate_fft(&tensors)?,
+            "reduce_sum" => gpu_simulate_reduce_sum(&tensors)?,
+            _ => return Err(BraunError::UnknownOperation("Unknown GPU tensor operation".to_string()).into())
+        };
+        let non_finite = sanitize_result(&mut result)?;
     
-    let response = ComputationResponse {
-        id: uuid::Uuid::new_v4().to_string(),
-        result: serde_json::to_value(&result).unwrap(),
-        computation_time_ms: computation_time,
-        memory_used_bytes: estimate_memory_usage(&result),
-        cpu_utilization: measure_cpu_utilization(),
-        convergence_status: "gpu_computation_complete".to_string(),
-        error_metrics: HashMap::new(),
-    };
+        let computation_time = start_time.elapsed().as_millis() as u64;
+    
+        let response = ComputationResponse {
+            id: request_id.to_string(),
+            result: serde_json::to_value(&result).unwrap(),
+            computation_time_ms: computation_time,
+            memory_used_bytes: estimate_memory_usage(&result),
+            cpu_utilization: measure_cpu_utilization(),
+            convergence_status: "gpu_computation_complete".to_string(),
+            error_metrics: non_finite.metrics(),
+            cached: false,
+        };
     
-    serde_json::to_string(&response)
-        .map_err(|e| Error::Term(Box::new(format!("Response serialization error: {}", e))))
+        finish(&response)
+    })
 }
 
 // Distributed computation coordination
 #[rustler::nif(schedule = "DirtyCpu")]
 fn coordinate_distributed_computation(job_description_json: String, worker_nodes: Vec<String>) -> NifResult<String> {
-    let start_time = std::time::Instant::now();
+    traced("coordinate_distributed_computation", "distributed", worker_nodes.len(), |request_id| {
+        let start_time = std::time::Instant::now();
     
-    let job_description: serde_json::Value = serde_json::from_str(&job_description_json)
-        .map_err(|e| Error::Term(Box::new(format!("Job description parsing error: {}", e))))?;
+        let job_description: serde_json::Value = serde_json::from_str(&job_description_json)
+            .map_err(|e| BraunError::Parse(format!("Job description parsing error: {}", e)))?;
     
-    // Simulate distributed computation coordination
-    let coordination_result = coordinate_workers(&job_description, &worker_nodes)?;
+        // Simulate distributed computation coordination
+        let mut coordination_result = coordinate_workers(&job_description, &worker_nodes)?;
+        let non_finite = sanitize_result(&mut coordination_result)?;
     
-    let computation_time = start_time.elapsed().as_millis() as u64;
+        let computation_time = start_time.elapsed().as_millis() as u64;
     
-    let response = ComputationResponse {
-        id: uuid::Uuid::new_v4().to_string(),
-        result: serde_json::to_value(&coordination_result).unwrap(),
-        computation_time_ms: computation_time,
-        memory_used_bytes: estimate_memory_usage(&coordination_result),
-        cpu_utilization: measure_cpu_utilization(),
-        convergence_status: "distributed_complete".to_string(),
-        error_metrics: HashMap::new(),
-    };
+        let response = ComputationResponse {
+            id: request_id.to_string(),
+            result: serde_json::to_value(&coordination_result).unwrap(),
+            computation_time_ms: computation_time,
+            memory_used_bytes: estimate_memory_usage(&coordination_result),
+            cpu_utilization: measure_cpu_utilization(),
+            convergence_status: "distributed_complete".to_string(),
+            error_metrics: non_finite.metrics(),
+            cached: false,
+        };
     
-    serde_json::to_string(&response)
-        .map_err(|e| Error::Term(Box::new(format!("Response serialization error: {}", e))))
+        finish(&response)
+    })
 }
 
 // Specialized data structures and algorithms
@@ -316,7 +1060,12 @@ struct OptimizationResult {
     converged: bool,
     iterations_used: u32,
     final_energy: f64,
+    /// Best energy after each iteration, when `track_history` is set
+    #[serde(default)]
+    energy_history: Vec<f64>,
     error_metrics: HashMap<String, f64>,
+    #[serde(default)]
+    timed_out: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -326,6 +1075,8 @@ struct FieldEvolution {
     energy_landscape: Vec<Vec<f64>>,
     critical_points: Vec<Vec<f64>>,
     phase_transitions: Vec<HashMap<String, serde_json::Value>>,
+    #[serde(default)]
+    timed_out: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -335,30 +1086,174 @@ struct PatternRecognitionResult {
     pattern_strengths: Vec<f64>,
     anomalies: Vec<usize>,
     recognition_confidence: f64,
+    #[serde(default)]
+    timed_out: bool,
+}
+
+impl PatternRecognitionResult {
+    /// Algorithm output before `score_clustering` fills in the metrics
+    fn unscored(clusters: Vec<Vec<usize>>, cluster_centers: Vec<Vec<f64>>, timed_out: bool) -> Self {
+        PatternRecognitionResult {
+            clusters,
+            cluster_centers,
+            pattern_strengths: vec![],
+            anomalies: vec![],
+            recognition_confidence: 0.0,
+            timed_out,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct DistributedResult {
-    worker_results: HashMap<String, serde_json::Value>,
-    aggregated_result: serde_json::Value,
-    execution_statistics: HashMap<String, f64>,
-    load_balancing_metrics: HashMap<String, f64>,
+struct PcaResult {
+    /// Each input row expressed in the principal component basis
+    projection: Vec<Vec<f64>>,
+    /// Principal axes, one row per component, by decreasing variance
+    components: Vec<Vec<f64>>,
+    explained_variance_ratio: Vec<f64>,
+    mean: Vec<f64>,
 }
 
-// Implementation of quantum-inspired algorithms
-fn quantum_annealing_optimization(problem: &serde_json::Value, params: &OptimizationParams) -> Result<OptimizationResult, Error> {
-    // Simulated quantum annealing
-    let mut current_solution = initialize_random_solution(problem)?;
-    let mut best_solution = current_solution.clone();
+/// A finished result read back in pieces. The first chunk starts with the
+/// response header line, whose `result` holds the row count; each following
+/// line is one row as a JSON array.
+pub struct ResultStream {
+    header: String,
+    state: Mutex<ResultStreamState>,
+}
+
+struct ResultStreamState {
+    rows: Vec<Vec<f64>>,
+    next_row: usize,
+    header_sent: bool,
+}
+
+impl ResultStream {
+    fn new(header: ComputationResponse, rows: Vec<Vec<f64>>) -> Result<Self, BraunError> {
+        let header = serde_json::to_string(&header)
+            .map_err(|e| BraunError::Serialization(e.to_string()))?;
+        Ok(ResultStream {
+            header,
+            state: Mutex::new(ResultStreamState { rows, next_row: 0, header_sent: false }),
+        })
+    }
+
+    /// Serialize up to `max_rows` further rows as NDJSON; `None` once exhausted
+    fn next_chunk(&self, max_rows: usize) -> Result<Option<String>, BraunError> {
+        if max_rows == 0 {
+            return Err(BraunError::InvalidArgument("max_rows must be at least 1".to_string()));
+        }
+        
+        let mut state = self.state.lock();
+        let mut chunk = String::new();
+        if !state.header_sent {
+            state.header_sent = true;
+            chunk.push_str(&self.header);
+            chunk.push('\n');
+        }
+        
+        let end = (state.next_row + max_rows).min(state.rows.len());
+        for row in &state.rows[state.next_row..end] {
+            chunk.push_str(&serde_json::to_string(row).map_err(|e| BraunError::Serialization(e.to_string()))?);
+            chunk.push('\n');
+        }
+        state.next_row = end;
+        
+        Ok(if chunk.is_empty() { None } else { Some(chunk) })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DistributedResult {
+    worker_results: HashMap<String, serde_json::Value>,
+    aggregated_result: serde_json::Value,
+    execution_statistics: HashMap<String, f64>,
+    load_balancing_metrics: HashMap<String, f64>,
+}
+
+impl SanitizeFloats for MatrixOperationResult {
+    fn sanitize_floats(&mut self, counts: &mut NonFiniteCounts) {
+        self.result.sanitize_floats(counts);
+    }
+}
+
+impl SanitizeFloats for OptimizationResult {
+    fn sanitize_floats(&mut self, counts: &mut NonFiniteCounts) {
+        self.optimal_solution.sanitize_floats(counts);
+        self.optimization_path.sanitize_floats(counts);
+        self.convergence_metrics.sanitize_floats(counts);
+        self.final_energy.sanitize_floats(counts);
+        self.energy_history.sanitize_floats(counts);
+        self.error_metrics.sanitize_floats(counts);
+    }
+}
+
+impl SanitizeFloats for FieldState {
+    fn sanitize_floats(&mut self, counts: &mut NonFiniteCounts) {
+        self.field_values.sanitize_floats(counts);
+        self.topology.sanitize_floats(counts);
+        self.energy_density.sanitize_floats(counts);
+        self.coherence_measure.sanitize_floats(counts);
+        self.temporal_signature.sanitize_floats(counts);
+    }
+}
+
+impl SanitizeFloats for FieldEvolution {
+    fn sanitize_floats(&mut self, counts: &mut NonFiniteCounts) {
+        self.trajectory.sanitize_floats(counts);
+        self.stability_analysis.sanitize_floats(counts);
+        self.energy_landscape.sanitize_floats(counts);
+        self.critical_points.sanitize_floats(counts);
+    }
+}
+
+impl SanitizeFloats for PatternRecognitionResult {
+    fn sanitize_floats(&mut self, counts: &mut NonFiniteCounts) {
+        self.cluster_centers.sanitize_floats(counts);
+        self.pattern_strengths.sanitize_floats(counts);
+        self.recognition_confidence.sanitize_floats(counts);
+    }
+}
+
+impl SanitizeFloats for PcaResult {
+    fn sanitize_floats(&mut self, counts: &mut NonFiniteCounts) {
+        self.projection.sanitize_floats(counts);
+        self.components.sanitize_floats(counts);
+        self.explained_variance_ratio.sanitize_floats(counts);
+        self.mean.sanitize_floats(counts);
+    }
+}
+
+impl SanitizeFloats for DistributedResult {
+    fn sanitize_floats(&mut self, counts: &mut NonFiniteCounts) {
+        self.execution_statistics.sanitize_floats(counts);
+        self.load_balancing_metrics.sanitize_floats(counts);
+    }
+}
+
+// Implementation of quantum-inspired algorithms
+fn quantum_annealing_optimization(problem: &serde_json::Value, params: &OptimizationParams) -> Result<OptimizationResult, BraunError> {
+    // Simulated quantum annealing
+    let mut rng = optimization_rng(params.seed);
+    let mut current_solution = initialize_random_solution(problem)?;
+    let mut best_solution = current_solution.clone();
     let mut best_energy = evaluate_energy(&best_solution, problem)?;
     let mut path = Vec::new();
+    let mut energy_history = Vec::new();
+    let deadline = Deadline::after_ms(params.timeout_ms);
+    let mut timed_out = false;
     
     for iteration in 0..params.max_iterations {
+        if deadline.expired() {
+            timed_out = true;
+            break;
+        }
+        
         let temperature = calculate_annealing_temperature(iteration, params.max_iterations);
-        let candidate = perturb_solution(&current_solution, temperature)?;
+        let candidate = perturb_solution(&current_solution, temperature, &mut rng)?;
         let candidate_energy = evaluate_energy(&candidate, problem)?;
         
-        if accept_solution(candidate_energy, best_energy, temperature) {
+        if accept_solution(candidate_energy, best_energy, temperature, &mut rng) {
             current_solution = candidate.clone();
             if candidate_energy < best_energy {
                 best_solution = candidate;
@@ -367,6 +1262,9 @@ fn quantum_annealing_optimization(problem: &serde_json::Value, params: &Optimiza
         }
         
         path.push(current_solution.clone());
+        if params.track_history {
+            energy_history.push(best_energy);
+        }
         
         if (best_energy - candidate_energy).abs() < params.convergence_threshold {
             return Ok(OptimizationResult {
@@ -376,35 +1274,51 @@ fn quantum_annealing_optimization(problem: &serde_json::Value, params: &Optimiza
                 converged: true,
                 iterations_used: iteration + 1,
                 final_energy: best_energy,
+                energy_history,
                 error_metrics: HashMap::new(),
+                timed_out: false,
             });
         }
     }
     
+    let iterations_used = path.len() as u32;
     Ok(OptimizationResult {
         optimal_solution: best_solution,
         optimization_path: path,
-        convergence_metrics: build_convergence_metrics(params.max_iterations, best_energy),
+        convergence_metrics: build_convergence_metrics(iterations_used, best_energy),
         converged: false,
-        iterations_used: params.max_iterations,
+        iterations_used,
         final_energy: best_energy,
+        energy_history,
         error_metrics: HashMap::new(),
+        timed_out,
     })
 }
 
-fn quantum_genetic_algorithm(problem: &serde_json::Value, params: &OptimizationParams) -> Result<OptimizationResult, Error> {
+fn quantum_genetic_algorithm(problem: &serde_json::Value, params: &OptimizationParams) -> Result<OptimizationResult, BraunError> {
     // Quantum-inspired genetic algorithm with superposition and entanglement
     let population_size = 100;
-    let mut population = initialize_quantum_population(population_size, problem)?;
+    let mut rng = optimization_rng(params.seed);
+    let mut population = initialize_quantum_population(population_size, problem, &mut rng)?;
     let mut best_solution = Vec::new();
     let mut best_fitness = f64::INFINITY;
     let mut path = Vec::new();
+    let mut energy_history = Vec::new();
+    let deadline = Deadline::after_ms(params.timeout_ms);
+    let mut timed_out = false;
     
     for generation in 0..params.max_iterations {
+        if deadline.expired() {
+            timed_out = true;
+            break;
+        }
+        
         // Evaluate fitness with quantum measurement
-        let fitness_values = population.par_iter()
-            .map(|individual| evaluate_quantum_fitness(individual, problem))
-            .collect::<Result<Vec<_>, _>>()?;
+        let fitness_values = in_compute_pool(|| {
+            population.par_iter()
+                .map(|individual| evaluate_quantum_fitness(individual, problem))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
         
         // Find best individual
         for (i, &fitness) in fitness_values.iter().enumerate() {
@@ -415,9 +1329,12 @@ fn quantum_genetic_algorithm(problem: &serde_json::Value, params: &OptimizationP
         }
         
         path.push(best_solution.clone());
+        if params.track_history {
+            energy_history.push(best_fitness);
+        }
         
         // Quantum selection, crossover, and mutation
-        population = quantum_evolution_step(population, &fitness_values, params)?;
+        population = quantum_evolution_step(population, &fitness_values, params, &mut rng)?;
         
         if best_fitness < params.convergence_threshold {
             return Ok(OptimizationResult {
@@ -427,23 +1344,28 @@ fn quantum_genetic_algorithm(problem: &serde_json::Value, params: &OptimizationP
                 converged: true,
                 iterations_used: generation + 1,
                 final_energy: best_fitness,
+                energy_history,
                 error_metrics: HashMap::new(),
+                timed_out: false,
             });
         }
     }
     
+    let iterations_used = path.len() as u32;
     Ok(OptimizationResult {
         optimal_solution: best_solution,
         optimization_path: path,
-        convergence_metrics: build_convergence_metrics(params.max_iterations, best_fitness),
+        convergence_metrics: build_convergence_metrics(iterations_used, best_fitness),
         converged: false,
-        iterations_used: params.max_iterations,
+        iterations_used,
         final_energy: best_fitness,
+        energy_history,
         error_metrics: HashMap::new(),
+        timed_out,
     })
 }
 
-fn adiabatic_evolution_optimization(_problem: &serde_json::Value, _params: &OptimizationParams) -> Result<OptimizationResult, Error> {
+fn adiabatic_evolution_optimization(_problem: &serde_json::Value, params: &OptimizationParams) -> Result<OptimizationResult, BraunError> {
     // Placeholder for adiabatic quantum computation
     Ok(OptimizationResult {
         optimal_solution: vec![0.0; 10],
@@ -452,11 +1374,13 @@ fn adiabatic_evolution_optimization(_problem: &serde_json::Value, _params: &Opti
         converged: true,
         iterations_used: 1,
         final_energy: 0.0,
+        energy_history: if params.track_history { vec![0.0] } else { Vec::new() },
         error_metrics: HashMap::new(),
+        timed_out: false,
     })
 }
 
-fn variational_quantum_eigensolver(_problem: &serde_json::Value, _params: &OptimizationParams) -> Result<OptimizationResult, Error> {
+fn variational_quantum_eigensolver(_problem: &serde_json::Value, params: &OptimizationParams) -> Result<OptimizationResult, BraunError> {
     // Placeholder for VQE algorithm
     Ok(OptimizationResult {
         optimal_solution: vec![0.0; 10],
@@ -465,16 +1389,24 @@ fn variational_quantum_eigensolver(_problem: &serde_json::Value, _params: &Optim
         converged: true,
         iterations_used: 1,
         final_energy: 0.0,
+        energy_history: if params.track_history { vec![0.0] } else { Vec::new() },
         error_metrics: HashMap::new(),
+        timed_out: false,
     })
 }
 
 // Field dynamics simulation
-fn simulate_field_evolution(field_state: &FieldState, perturbation: &serde_json::Value, time_steps: u32) -> Result<FieldEvolution, Error> {
+fn simulate_field_evolution(field_state: &FieldState, perturbation: &serde_json::Value, time_steps: u32, deadline: Deadline) -> Result<FieldEvolution, BraunError> {
     let mut trajectory = Vec::new();
     let mut current_state = field_state.clone();
+    let mut timed_out = false;
     
     for _t in 0..time_steps {
+        if deadline.expired() {
+            timed_out = true;
+            break;
+        }
+        
         current_state = evolve_field_one_step(&current_state, perturbation)?;
         trajectory.push(current_state.clone());
     }
@@ -490,117 +1422,464 @@ fn simulate_field_evolution(field_state: &FieldState, perturbation: &serde_json:
         energy_landscape,
         critical_points,
         phase_transitions,
+        timed_out,
     })
 }
 
 // Pattern recognition implementations
-fn parallel_kmeans_clustering(patterns: &[PatternData]) -> Result<PatternRecognitionResult, Error> {
+fn preprocess_patterns(patterns: &mut [PatternData], preprocessing: Preprocessing) -> Result<(), BraunError> {
+    match preprocessing {
+        Preprocessing::Pca { components } => {
+            let features: Vec<Vec<f64>> = patterns.iter().map(|p| p.feature_vector.clone()).collect();
+            let pca = principal_components(&features, components)?;
+            for (pattern, projected) in patterns.iter_mut().zip(pca.projection) {
+                pattern.feature_vector = projected;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parallel_kmeans_clustering(patterns: &[PatternData], metric: DistanceMetric, deadline: Deadline) -> Result<PatternRecognitionResult, BraunError> {
     let k = estimate_optimal_clusters(patterns)?;
     let feature_vectors: Vec<Vec<f64>> = patterns.iter()
         .map(|p| p.feature_vector.clone())
         .collect();
     
-    let (clusters, centers) = kmeans_parallel(&feature_vectors, k, 100)?;
+    let (clusters, centers, timed_out) = kmeans_parallel(&feature_vectors, k, 100, metric, deadline)?;
     
-    Ok(PatternRecognitionResult {
+    Ok(PatternRecognitionResult::unscored(clusters, centers, timed_out))
+}
+
+fn gmm_clustering(patterns: &[PatternData], covariance: CovarianceType, deadline: Deadline) -> Result<PatternRecognitionResult, BraunError> {
+    let k = estimate_optimal_clusters(patterns)?;
+    let feature_vectors: Vec<Vec<f64>> = patterns.iter()
+        .map(|p| p.feature_vector.clone())
+        .collect();
+    
+    let mixture = fit_gaussian_mixture(&feature_vectors, k, 100, covariance, deadline)?;
+    
+    // Each sample goes to its most responsible component
+    let mut clusters = vec![Vec::new(); k];
+    for (point_idx, row) in mixture.responsibilities.iter().enumerate() {
+        let component = row.iter()
+            .enumerate()
+            .fold((0, 0.0), |best, (component, &r)| if r > best.1 { (component, r) } else { best })
+            .0;
+        clusters[component].push(point_idx);
+    }
+    
+    Ok(PatternRecognitionResult::unscored(
         clusters,
-        cluster_centers: centers,
-        pattern_strengths: calculate_pattern_strengths(patterns, &clusters)?,
-        anomalies: detect_anomalies(patterns, &clusters)?,
-        recognition_confidence: calculate_recognition_confidence(&clusters)?,
-    })
+        mixture.means.iter().map(|mean| mean.iter().copied().collect()).collect(),
+        mixture.timed_out,
+    ))
 }
 
-fn parallel_dbscan_clustering(_patterns: &[PatternData]) -> Result<PatternRecognitionResult, Error> {
-    // Placeholder for DBSCAN implementation
-    Ok(PatternRecognitionResult {
-        clusters: vec![],
-        cluster_centers: vec![],
-        pattern_strengths: vec![],
-        anomalies: vec![],
-        recognition_confidence: 0.0,
-    })
+/// Patterns with at least this many patterns (themselves included) within
+/// `eps` are DBSCAN core points
+const DBSCAN_MIN_POINTS: usize = 4;
+
+/// Density-based clustering; patterns outside every dense region stay
+/// unclustered. Without an explicit `eps` it is twice the 75th percentile of
+/// the distance from each pattern to its `DBSCAN_MIN_POINTS - 1`th nearest
+/// neighbour, which tolerates a few sparse points and outliers.
+fn parallel_dbscan_clustering(patterns: &[PatternData], eps: Option<f64>) -> Result<PatternRecognitionResult, BraunError> {
+    let data: Vec<Vec<f64>> = patterns.iter()
+        .map(|p| p.feature_vector.clone())
+        .collect();
+    if data.is_empty() {
+        return Ok(PatternRecognitionResult::unscored(vec![], vec![], false));
+    }
+    
+    let distances: Vec<Vec<f64>> = in_compute_pool(|| {
+        data.par_iter()
+            .map(|a| data.iter().map(|b| euclidean_distance(a, b)).collect())
+            .collect()
+    });
+    
+    let eps = match eps {
+        Some(eps) if eps > 0.0 => eps,
+        Some(eps) => return Err(BraunError::InvalidArgument(format!("DBSCAN eps must be positive, got {}", eps))),
+        None => {
+            let mut neighbour_distances: Vec<f64> = distances.iter()
+                .map(|row| {
+                    let mut row = row.clone();
+                    row.sort_by(f64::total_cmp);
+                    row[(DBSCAN_MIN_POINTS - 1).min(row.len() - 1)]
+                })
+                .collect();
+            neighbour_distances.sort_by(f64::total_cmp);
+            2.0 * neighbour_distances[neighbour_distances.len() * 3 / 4]
+        }
+    };
+    
+    let neighbours: Vec<Vec<usize>> = distances.iter()
+        .map(|row| row.iter().enumerate().filter(|(_, &d)| d <= eps).map(|(j, _)| j).collect())
+        .collect();
+    
+    let mut labelled = vec![false; data.len()];
+    let mut clusters = Vec::new();
+    for start in 0..data.len() {
+        if labelled[start] || neighbours[start].len() < DBSCAN_MIN_POINTS {
+            continue;
+        }
+        
+        // Grow the cluster through core points; border points join but
+        // don't extend it
+        let mut cluster = Vec::new();
+        let mut frontier = vec![start];
+        labelled[start] = true;
+        while let Some(point) = frontier.pop() {
+            cluster.push(point);
+            if neighbours[point].len() < DBSCAN_MIN_POINTS {
+                continue;
+            }
+            for &next in &neighbours[point] {
+                if !labelled[next] {
+                    labelled[next] = true;
+                    frontier.push(next);
+                }
+            }
+        }
+        cluster.sort_unstable();
+        clusters.push(cluster);
+    }
+    
+    let dims = data[0].len();
+    let centers = clusters.iter().map(|cluster| mean_center(&data, cluster, dims)).collect();
+    Ok(PatternRecognitionResult::unscored(clusters, centers, false))
 }
 
-fn parallel_hierarchical_clustering(_patterns: &[PatternData]) -> Result<PatternRecognitionResult, Error> {
+fn parallel_hierarchical_clustering(_patterns: &[PatternData]) -> Result<PatternRecognitionResult, BraunError> {
     // Placeholder for hierarchical clustering
-    Ok(PatternRecognitionResult {
-        clusters: vec![],
-        cluster_centers: vec![],
-        pattern_strengths: vec![],
-        anomalies: vec![],
-        recognition_confidence: 0.0,
-    })
+    Ok(PatternRecognitionResult::unscored(vec![], vec![], false))
 }
 
-fn parallel_spectral_clustering(_patterns: &[PatternData]) -> Result<PatternRecognitionResult, Error> {
+fn parallel_spectral_clustering(_patterns: &[PatternData]) -> Result<PatternRecognitionResult, BraunError> {
     // Placeholder for spectral clustering
-    Ok(PatternRecognitionResult {
-        clusters: vec![],
-        cluster_centers: vec![],
-        pattern_strengths: vec![],
-        anomalies: vec![],
-        recognition_confidence: 0.0,
-    })
+    Ok(PatternRecognitionResult::unscored(vec![], vec![], false))
 }
 
-fn parallel_neural_gas(_patterns: &[PatternData]) -> Result<PatternRecognitionResult, Error> {
+fn parallel_neural_gas(_patterns: &[PatternData]) -> Result<PatternRecognitionResult, BraunError> {
     // Placeholder for neural gas algorithm
-    Ok(PatternRecognitionResult {
-        clusters: vec![],
-        cluster_centers: vec![],
-        pattern_strengths: vec![],
-        anomalies: vec![],
-        recognition_confidence: 0.0,
-    })
+    Ok(PatternRecognitionResult::unscored(vec![], vec![], false))
 }
 
 // GPU simulation functions
-fn gpu_simulate_convolution(_tensors: &[Vec<Vec<Vec<f64>>>]) -> Result<Vec<Vec<Vec<f64>>>, Error> {
+fn gpu_simulate_convolution(_tensors: &[Vec<Vec<Vec<f64>>>]) -> Result<Vec<Vec<Vec<f64>>>, BraunError> {
     // Placeholder for GPU convolution
     Ok(vec![vec![vec![0.0]]])
 }
 
-fn gpu_simulate_matrix_multiply(_tensors: &[Vec<Vec<Vec<f64>>>]) -> Result<Vec<Vec<Vec<f64>>>, Error> {
-    // Placeholder for GPU matrix multiplication
-    Ok(vec![vec![vec![0.0]]])
+/// Batched product: matrix `i` of the first tensor times matrix `i` of the second
+fn gpu_simulate_matrix_multiply(tensors: &[Vec<Vec<Vec<f64>>>]) -> Result<Vec<Vec<Vec<f64>>>, BraunError> {
+    let [lhs, rhs] = tensors else {
+        return Err(BraunError::InvalidArgument("matrix_multiply requires exactly 2 tensors".to_string()));
+    };
+    if lhs.len() != rhs.len() {
+        return Err(BraunError::DimensionMismatch(format!("Cannot multiply batches of {} and {} matrices", lhs.len(), rhs.len())));
+    }
+    
+    in_compute_pool(|| {
+        lhs.par_iter()
+            .zip(rhs)
+            .map(|(a, b)| multiply_matrices::<f64>(a, b))
+            .collect()
+    })
 }
 
-fn gpu_simulate_fft(_tensors: &[Vec<Vec<Vec<f64>>>]) -> Result<Vec<Vec<Vec<f64>>>, Error> {
-    // Placeholder for GPU FFT
-    Ok(vec![vec![vec![0.0]]])
+/// Forward FFT of every slice of every tensor. A slice is `[real]` or
+/// `[real, imag]`, any length; each comes back as `[real, imag]` spectra,
+/// in input order.
+fn gpu_simulate_fft(tensors: &[Vec<Vec<Vec<f64>>>]) -> Result<Vec<Vec<Vec<f64>>>, BraunError> {
+    in_compute_pool(|| {
+        tensors.par_iter()
+            .flatten()
+            .map(|slice| fft_slice(slice))
+            .collect()
+    })
 }
 
-fn gpu_simulate_reduce_sum(_tensors: &[Vec<Vec<Vec<f64>>>]) -> Result<Vec<f64>, Error> {
+fn fft_slice(slice: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, BraunError> {
+    let (mut real, mut imag) = match slice {
+        [real] => (real.clone(), vec![0.0; real.len()]),
+        [real, imag] => (real.clone(), imag.clone()),
+        _ => return Err(BraunError::InvalidArgument(format!("FFT slices must be [real] or [real, imag], got {} rows", slice.len()))),
+    };
+    katalyst_compute_core::fft(&mut real, &mut imag, false).map_err(BraunError::DimensionMismatch)?;
+    Ok(vec![real, imag])
+}
+
+fn gpu_simulate_reduce_sum(_tensors: &[Vec<Vec<Vec<f64>>>]) -> Result<Vec<f64>, BraunError> {
     // Placeholder for GPU reduction
     Ok(vec![0.0])
 }
 
 // Distributed computation
-fn coordinate_workers(_job_description: &serde_json::Value, worker_nodes: &[String]) -> Result<DistributedResult, Error> {
-    let mut worker_results = HashMap::new();
+
+/// Runtime the coordinator drives worker tasks on; workers mostly wait, so
+/// two threads are plenty
+static DISTRIBUTED_RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .thread_name("braun-distributed")
+        .enable_all()
+        .build()
+        .expect("failed to start braun distributed runtime")
+});
+
+/// Coordinator settings read from the job description; any other keys are
+/// ignored
+#[derive(Debug, Deserialize)]
+struct DistributedJobOptions {
+    #[serde(default = "default_worker_timeout_ms")]
+    worker_timeout_ms: u64,
+    /// Items split into one contiguous chunk per worker
+    #[serde(default)]
+    data_size: usize,
+    #[serde(default)]
+    simulated_latency_ms: SimulatedLatency,
+}
+
+fn default_worker_timeout_ms() -> u64 {
+    30_000
+}
+
+/// How long each simulated worker takes, either one value for every worker
+/// or a map from worker name to milliseconds
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum SimulatedLatency {
+    Uniform(u64),
+    PerWorker(HashMap<String, u64>),
+}
+
+impl Default for SimulatedLatency {
+    fn default() -> Self {
+        SimulatedLatency::Uniform(0)
+    }
+}
+
+impl SimulatedLatency {
+    fn for_worker(&self, worker: &str) -> u64 {
+        match self {
+            SimulatedLatency::Uniform(ms) => *ms,
+            SimulatedLatency::PerWorker(latencies) => latencies.get(worker).copied().unwrap_or(0),
+        }
+    }
+}
+
+/// Simulated worker: processes its chunk after the configured latency
+async fn run_worker(latency_ms: u64, chunk_len: usize) -> usize {
+    tokio::time::sleep(std::time::Duration::from_millis(latency_ms)).await;
+    chunk_len
+}
+
+/// Dispatch every worker concurrently, each under `worker_timeout_ms`, and
+/// aggregate results in the order they complete. `speedup` in the execution
+/// statistics is total worker time over wall-clock time.
+fn coordinate_workers(job_description: &serde_json::Value, worker_nodes: &[String]) -> Result<DistributedResult, BraunError> {
+    let options: DistributedJobOptions = serde_json::from_value(job_description.clone())
+        .map_err(|e| BraunError::Parse(format!("Job description parsing error: {}", e)))?;
+    let timeout = std::time::Duration::from_millis(options.worker_timeout_ms);
+    let start_time = std::time::Instant::now();
     
-    for worker in worker_nodes {
-        // Simulate worker computation
+    let (outcomes, failed) = DISTRIBUTED_RUNTIME.block_on(async {
+        let mut tasks = tokio::task::JoinSet::new();
+        for (index, worker) in worker_nodes.iter().enumerate() {
+            let worker = worker.clone();
+            let chunk_len = options.data_size / worker_nodes.len()
+                + usize::from(index < options.data_size % worker_nodes.len());
+            let latency_ms = options.simulated_latency_ms.for_worker(&worker);
+            tasks.spawn(async move {
+                let started = std::time::Instant::now();
+                let processed = tokio::time::timeout(timeout, run_worker(latency_ms, chunk_len)).await.ok();
+                (worker, processed, started.elapsed().as_secs_f64() * 1000.0)
+            });
+        }
+        
+        let mut outcomes = Vec::with_capacity(worker_nodes.len());
+        let mut failed = 0usize;
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok(outcome) => outcomes.push(outcome),
+                Err(_) => failed += 1,
+            }
+        }
+        (outcomes, failed)
+    });
+    let wall_clock_ms = start_time.elapsed().as_secs_f64() * 1000.0;
+    
+    let mut worker_results = HashMap::new();
+    let mut completion_order = Vec::new();
+    let mut data_processed = 0;
+    let mut timed_out = 0usize;
+    for (worker, processed, elapsed_ms) in &outcomes {
+        let status = match processed {
+            Some(count) => {
+                completion_order.push(worker.clone());
+                data_processed += count;
+                "completed"
+            }
+            None => {
+                timed_out += 1;
+                "timeout"
+            }
+        };
         worker_results.insert(worker.clone(), serde_json::json!({
-            "result": "completed",
-            "computation_time": 100,
-            "data_processed": 1000
+            "result": status,
+            "computation_time": elapsed_ms,
+            "data_processed": processed.unwrap_or(0)
         }));
     }
     
+    let worker_times: Vec<f64> = outcomes.iter().map(|(_, _, elapsed_ms)| *elapsed_ms).collect();
+    let total_worker_ms: f64 = worker_times.iter().sum();
+    let max_worker_ms = worker_times.iter().cloned().fold(0.0, f64::max);
+    let mean_worker_ms = if worker_times.is_empty() { 0.0 } else { total_worker_ms / worker_times.len() as f64 };
+    
+    let mut execution_statistics = HashMap::new();
+    execution_statistics.insert("wall_clock_ms".to_string(), wall_clock_ms);
+    execution_statistics.insert("total_worker_time_ms".to_string(), total_worker_ms);
+    execution_statistics.insert("speedup".to_string(), if wall_clock_ms > 0.0 { total_worker_ms / wall_clock_ms } else { 1.0 });
+    execution_statistics.insert("workers_completed".to_string(), completion_order.len() as f64);
+    execution_statistics.insert("workers_timed_out".to_string(), timed_out as f64);
+    execution_statistics.insert("workers_failed".to_string(), failed as f64);
+    
+    let mut load_balancing_metrics = HashMap::new();
+    load_balancing_metrics.insert("max_worker_time_ms".to_string(), max_worker_ms);
+    load_balancing_metrics.insert("mean_worker_time_ms".to_string(), mean_worker_ms);
+    load_balancing_metrics.insert("imbalance_ratio".to_string(), if mean_worker_ms > 0.0 { max_worker_ms / mean_worker_ms } else { 1.0 });
+    
+    let status = if completion_order.len() == worker_nodes.len() { "success" } else { "partial" };
     Ok(DistributedResult {
         worker_results,
-        aggregated_result: serde_json::json!({"status": "success"}),
-        execution_statistics: HashMap::new(),
-        load_balancing_metrics: HashMap::new(),
+        aggregated_result: serde_json::json!({
+            "status": status,
+            "completion_order": completion_order,
+            "data_processed": data_processed
+        }),
+        execution_statistics,
+        load_balancing_metrics,
+    })
+}
+
+const RANDOMIZED_SVD_OVERSAMPLING: usize = 5;
+const RANDOMIZED_SVD_POWER_ITERATIONS: usize = 2;
+
+/// Top-`rank` singular triplets via randomized SVD (Halko, Martinsson & Tropp):
+/// project onto a random subspace slightly larger than `rank`, refine it with
+/// a few power iterations, then take the exact SVD of the small projected
+/// matrix. Returns U_k (m×k), Σ_k in descending order and V_kᵀ (k×n), the
+/// same layout as the full `svd` operation.
+fn randomized_svd<T: MatrixScalar>(matrix: &DMatrix<T>, rank: usize, rng: &mut StdRng) -> Result<(DMatrix<T>, Vec<f64>, DMatrix<T>), BraunError> {
+    let (rows, cols) = matrix.shape();
+    let max_rank = rows.min(cols);
+    if rank == 0 || rank > max_rank {
+        return Err(BraunError::InvalidArgument(format!("Rank must be between 1 and {}, got {}", max_rank, rank)));
+    }
+    
+    let sketch_size = (rank + RANDOMIZED_SVD_OVERSAMPLING).min(max_rank);
+    let omega = DMatrix::<T>::from_fn(cols, sketch_size, |_, _| T::narrow(rng.sample(StandardNormal)));
+    
+    // Orthonormal basis Q for the approximate range of the matrix
+    let mut q = (matrix * omega).qr().q();
+    for _ in 0..RANDOMIZED_SVD_POWER_ITERATIONS {
+        let z = (matrix.transpose() * &q).qr().q();
+        q = (matrix * z).qr().q();
+    }
+    
+    let projected = q.transpose() * matrix;
+    let svd = projected.svd(true, true);
+    let (Some(u_small), Some(v_t)) = (svd.u, svd.v_t) else {
+        return Err(BraunError::Singular("SVD of projected matrix did not converge".to_string()));
+    };
+    
+    let mut order: Vec<usize> = (0..svd.singular_values.len()).collect();
+    order.sort_by(|&a, &b| svd.singular_values[b].widen().total_cmp(&svd.singular_values[a].widen()));
+    order.truncate(rank);
+    
+    let u = q * u_small.select_columns(&order);
+    let singular_values = order.iter().map(|&i| svd.singular_values[i].widen()).collect();
+    let v_t = v_t.select_rows(&order);
+    
+    Ok((u, singular_values, v_t))
+}
+
+/// PCA by eigendecomposition of the sample covariance: center the rows,
+/// take the top `components` eigenvectors of the covariance matrix and
+/// project onto them
+fn principal_components(data: &[Vec<f64>], components: usize) -> Result<PcaResult, BraunError> {
+    if data.len() < 2 {
+        return Err(BraunError::InvalidArgument("PCA needs at least two samples".to_string()));
+    }
+    let x = to_dmatrix::<f64>(data)?;
+    let (samples, dims) = x.shape();
+    if components == 0 || components > dims {
+        return Err(BraunError::InvalidArgument(format!("Components must be between 1 and {}, got {}", dims, components)));
+    }
+    
+    let mean = x.row_mean();
+    let centered = DMatrix::from_fn(samples, dims, |i, j| x[(i, j)] - mean[j]);
+    let covariance = centered.transpose() * &centered / (samples - 1) as f64;
+    let eigen = covariance.symmetric_eigen();
+    
+    let mut order: Vec<usize> = (0..dims).collect();
+    order.sort_by(|&a, &b| eigen.eigenvalues[b].total_cmp(&eigen.eigenvalues[a]));
+    order.truncate(components);
+    
+    // Round-off can leave tiny negative eigenvalues on rank-deficient data
+    let total_variance: f64 = eigen.eigenvalues.iter().map(|v| v.max(0.0)).sum();
+    let explained_variance_ratio = order.iter()
+        .map(|&i| if total_variance > 0.0 { eigen.eigenvalues[i].max(0.0) / total_variance } else { 0.0 })
+        .collect();
+    
+    let basis = eigen.eigenvectors.select_columns(&order);
+    let projection = &centered * &basis;
+    
+    Ok(PcaResult {
+        projection: matrix_to_vec2d(&projection),
+        components: matrix_to_vec2d(&basis.transpose()),
+        explained_variance_ratio,
+        mean: mean.iter().copied().collect(),
     })
 }
 
 // Utility functions
-fn matrix_to_vec2d(matrix: &DMatrix<f64>) -> Vec<Vec<f64>> {
+/// Row-major values of a non-empty rectangular matrix, with its row and
+/// column counts
+fn to_row_major<T: MatrixScalar>(rows: &[Vec<f64>]) -> Result<(Vec<T>, usize, usize), BraunError> {
+    let cols = rows.first().map_or(0, |row| row.len());
+    if cols == 0 {
+        return Err(BraunError::DimensionMismatch("Matrix must have at least one row and one column".to_string()));
+    }
+    if rows.iter().any(|row| row.len() != cols) {
+        return Err(BraunError::DimensionMismatch("Matrix rows must all have the same length".to_string()));
+    }
+    
+    Ok((rows.iter().flatten().map(|&v| T::narrow(v)).collect(), rows.len(), cols))
+}
+
+fn to_dmatrix<T: MatrixScalar>(rows: &[Vec<f64>]) -> Result<DMatrix<T>, BraunError> {
+    let (values, n_rows, cols) = to_row_major::<T>(rows)?;
+    Ok(DMatrix::from_row_slice(n_rows, cols, &values))
+}
+
+fn multiply_matrices<T: MatrixScalar>(a: &[Vec<f64>], b: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, BraunError> {
+    let (a, rows_a, cols_a) = to_row_major::<T>(a)?;
+    let (b, rows_b, cols_b) = to_row_major::<T>(b)?;
+    if cols_a != rows_b {
+        return Err(BraunError::DimensionMismatch(format!("Cannot multiply {}x{} by {}x{} matrix", rows_a, cols_a, rows_b, cols_b)));
+    }
+    
+    let product = T::matmul(&a, &b, rows_a, cols_a, cols_b).map_err(BraunError::DimensionMismatch)?;
+    Ok(product.chunks(cols_b).map(|row| row.iter().map(|v| v.widen()).collect()).collect())
+}
+
+fn matrix_to_vec2d<T: MatrixScalar>(matrix: &DMatrix<T>) -> Vec<Vec<f64>> {
     (0..matrix.nrows())
-        .map(|i| matrix.row(i).iter().copied().collect())
+        .map(|i| matrix.row(i).iter().map(|v| v.widen()).collect())
         .collect()
 }
 
@@ -619,11 +1898,11 @@ fn calculate_field_errors(_evolution: &FieldEvolution) -> HashMap<String, f64> {
 }
 
 // Placeholder implementations for quantum algorithms
-fn initialize_random_solution(_problem: &serde_json::Value) -> Result<Vec<f64>, Error> {
+fn initialize_random_solution(_problem: &serde_json::Value) -> Result<Vec<f64>, BraunError> {
     Ok(vec![0.0; 10])
 }
 
-fn evaluate_energy(_solution: &[f64], _problem: &serde_json::Value) -> Result<f64, Error> {
+fn evaluate_energy(solution: &[f64], _problem: &serde_json::Value) -> Result<f64, BraunError> {
     Ok(solution.iter().map(|&x| x * x).sum())
 }
 
@@ -631,16 +1910,24 @@ fn calculate_annealing_temperature(iteration: u32, max_iterations: u32) -> f64 {
     1.0 - (iteration as f64 / max_iterations as f64)
 }
 
-fn perturb_solution(solution: &[f64], temperature: f64) -> Result<Vec<f64>, Error> {
-    Ok(solution.iter().map(|&x| x + temperature * (rand::random::<f64>() - 0.5)).collect())
+/// RNG for an optimization run: seeded when `seed` is given, otherwise from entropy
+fn optimization_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+fn perturb_solution(solution: &[f64], temperature: f64, rng: &mut StdRng) -> Result<Vec<f64>, BraunError> {
+    Ok(solution.iter().map(|&x| x + temperature * (rng.gen::<f64>() - 0.5)).collect())
 }
 
-fn accept_solution(candidate_energy: f64, current_energy: f64, temperature: f64) -> bool {
+fn accept_solution(candidate_energy: f64, current_energy: f64, temperature: f64, rng: &mut StdRng) -> bool {
     if candidate_energy < current_energy {
         true
     } else {
         let probability = (-(candidate_energy - current_energy) / temperature).exp();
-        rand::random::<f64>() < probability
+        rng.gen::<f64>() < probability
     }
 }
 
@@ -651,123 +1938,1135 @@ fn build_convergence_metrics(iterations: u32, final_energy: f64) -> HashMap<Stri
     metrics
 }
 
-fn initialize_quantum_population(_size: usize, _problem: &serde_json::Value) -> Result<Vec<Vec<f64>>, Error> {
+fn initialize_quantum_population(_size: usize, _problem: &serde_json::Value, _rng: &mut StdRng) -> Result<Vec<Vec<f64>>, BraunError> {
     Ok(vec![vec![0.0; 10]; 100])
 }
 
-fn evaluate_quantum_fitness(_individual: &[f64], _problem: &serde_json::Value) -> Result<f64, Error> {
+fn evaluate_quantum_fitness(_individual: &[f64], _problem: &serde_json::Value) -> Result<f64, BraunError> {
     Ok(0.0)
 }
 
-fn measure_quantum_state(quantum_state: &[f64]) -> Result<Vec<f64>, Error> {
+fn measure_quantum_state(quantum_state: &[f64]) -> Result<Vec<f64>, BraunError> {
     Ok(quantum_state.to_vec())
 }
 
-fn quantum_evolution_step(population: Vec<Vec<f64>>, _fitness: &[f64], _params: &OptimizationParams) -> Result<Vec<Vec<f64>>, Error> {
+fn quantum_evolution_step(population: Vec<Vec<f64>>, _fitness: &[f64], _params: &OptimizationParams, _rng: &mut StdRng) -> Result<Vec<Vec<f64>>, BraunError> {
     Ok(population)
 }
 
-fn evolve_field_one_step(state: &FieldState, _perturbation: &serde_json::Value) -> Result<FieldState, Error> {
+fn evolve_field_one_step(state: &FieldState, _perturbation: &serde_json::Value) -> Result<FieldState, BraunError> {
     Ok(state.clone())
 }
 
-fn analyze_field_stability(_trajectory: &[FieldState]) -> Result<HashMap<String, f64>, Error> {
+fn analyze_field_stability(_trajectory: &[FieldState]) -> Result<HashMap<String, f64>, BraunError> {
     Ok(HashMap::new())
 }
 
-fn compute_energy_landscape(_trajectory: &[FieldState]) -> Result<Vec<Vec<f64>>, Error> {
+fn compute_energy_landscape(_trajectory: &[FieldState]) -> Result<Vec<Vec<f64>>, BraunError> {
     Ok(vec![vec![0.0]])
 }
 
-fn find_critical_points(_landscape: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, Error> {
+fn find_critical_points(_landscape: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, BraunError> {
     Ok(vec![])
 }
 
-fn detect_phase_transitions(_trajectory: &[FieldState]) -> Result<Vec<HashMap<String, serde_json::Value>>, Error> {
+fn detect_phase_transitions(_trajectory: &[FieldState]) -> Result<Vec<HashMap<String, serde_json::Value>>, BraunError> {
     Ok(vec![])
 }
 
-fn estimate_optimal_clusters(_patterns: &[PatternData]) -> Result<usize, Error> {
+fn estimate_optimal_clusters(_patterns: &[PatternData]) -> Result<usize, BraunError> {
     Ok(3)
 }
 
-fn kmeans_parallel(data: &[Vec<f64>], k: usize, max_iterations: usize) -> Result<(Vec<Vec<usize>>, Vec<Vec<f64>>), Error> {
-    // Simplified k-means implementation
-    let mut clusters = vec![Vec::new(); k];
-    let mut centers = vec![vec![0.0; data[0].len()]; k];
+/// Distance metrics available to k-means
+fn parse_distance_metric(name: &str) -> Result<DistanceMetric, BraunError> {
+    DistanceMetric::parse(name).map_err(BraunError::UnknownOperation)
+}
+
+/// Returns the clusters, their centers and whether the deadline cut the run
+/// short. Runs the shared core k-means on the compute pool, seeded with the
+/// first `k` points.
+fn kmeans_parallel(data: &[Vec<f64>], k: usize, max_iterations: usize, metric: DistanceMetric, deadline: Deadline) -> Result<(Vec<Vec<usize>>, Vec<Vec<f64>>, bool), BraunError> {
+    let dims = data.first().map_or(0, |point| point.len());
+    if data.iter().any(|point| point.len() != dims) {
+        return Err(BraunError::DimensionMismatch("Feature vectors must all have the same length".to_string()));
+    }
+    let flattened = data.concat();
+    validate_k_means_input(&flattened, dims, k).map_err(BraunError::InvalidArgument)?;
+    
+    let seeds = flattened[..k * dims].to_vec();
+    let pool = COMPUTE_POOL.read().clone();
+    let result = k_means_from(&flattened, dims, seeds, max_iterations, metric, Some(&pool), || deadline.expired());
     
-    // Initialize centers randomly
-    for i in 0..k {
-        if i < data.len() {
-            centers[i] = data[i].clone();
+    let mut clusters = vec![Vec::new(); k];
+    for (point_idx, &cluster) in result.assignments.iter().enumerate() {
+        clusters[cluster as usize].push(point_idx);
+    }
+    let centers = result.centroids.chunks(dims).map(|center| center.to_vec()).collect();
+    Ok((clusters, centers, result.interrupted))
+}
+
+/// Covariance structure fitted per mixture component
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CovarianceType {
+    Full,
+    Diagonal,
+}
+
+impl CovarianceType {
+    fn parse(name: &str) -> Result<Self, BraunError> {
+        match name {
+            "full" => Ok(CovarianceType::Full),
+            "diagonal" => Ok(CovarianceType::Diagonal),
+            _ => Err(BraunError::UnknownOperation(format!("Unknown covariance type: {}", name))),
         }
     }
+}
+
+/// Added to every covariance diagonal so collapsed or duplicate-point
+/// components stay invertible
+const GMM_REGULARIZATION: f64 = 1e-6;
+/// EM stops once the mean per-sample log-likelihood improves by less than this
+const GMM_TOLERANCE: f64 = 1e-8;
+
+struct GaussianMixture {
+    weights: Vec<f64>,
+    means: Vec<DVector<f64>>,
+    covariances: Vec<DMatrix<f64>>,
+    /// Posterior probability of each component, one row per sample
+    responsibilities: Vec<Vec<f64>>,
+    /// Mean per-sample log-likelihood of the final model
+    log_likelihood: f64,
+    timed_out: bool,
+}
+
+/// Fit a `k`-component Gaussian mixture by expectation-maximization. Means
+/// start at mutually distant samples and covariances at the overall data
+/// covariance, so the fit is deterministic for a given input.
+fn fit_gaussian_mixture(data: &[Vec<f64>], k: usize, max_iterations: usize, covariance: CovarianceType, deadline: Deadline) -> Result<GaussianMixture, BraunError> {
+    let x = to_dmatrix::<f64>(data)?;
+    let (samples, dims) = x.shape();
+    if k == 0 || k > samples {
+        return Err(BraunError::InvalidArgument(format!("Component count must be between 1 and {}, got {}", samples, k)));
+    }
+    let points: Vec<DVector<f64>> = (0..samples).map(|i| x.row(i).transpose()).collect();
+    
+    // Farthest-point initialization: each new mean is the sample farthest from all chosen ones
+    let mut means = vec![points[0].clone()];
+    while means.len() < k {
+        let farthest = points.iter()
+            .max_by(|a, b| {
+                let da = means.iter().map(|m| (*a - m).norm_squared()).fold(f64::INFINITY, f64::min);
+                let db = means.iter().map(|m| (*b - m).norm_squared()).fold(f64::INFINITY, f64::min);
+                da.total_cmp(&db)
+            })
+            .unwrap();
+        means.push(farthest.clone());
+    }
+    
+    let overall_mean = x.row_mean().transpose();
+    let overall = weighted_covariance(&points, &vec![1.0; samples], &overall_mean, covariance);
+    let mut mixture = GaussianMixture {
+        weights: vec![1.0 / k as f64; k],
+        means,
+        covariances: vec![overall; k],
+        responsibilities: Vec::new(),
+        log_likelihood: f64::NEG_INFINITY,
+        timed_out: false,
+    };
     
     for _ in 0..max_iterations {
-        // Clear clusters
-        for cluster in &mut clusters {
-            cluster.clear();
+        if deadline.expired() {
+            mixture.timed_out = true;
+            break;
         }
         
-        // Assign points to clusters
-        for (point_idx, point) in data.iter().enumerate() {
-            let mut best_cluster = 0;
-            let mut best_distance = f64::INFINITY;
-            
-            for (cluster_idx, center) in centers.iter().enumerate() {
-                let distance = euclidean_distance(point, center);
-                if distance < best_distance {
-                    best_distance = distance;
-                    best_cluster = cluster_idx;
-                }
-            }
-            
-            clusters[best_cluster].push(point_idx);
+        // E-step
+        let previous = mixture.log_likelihood;
+        let (responsibilities, log_likelihood) = gmm_expectation(&points, &mixture)?;
+        mixture.responsibilities = responsibilities;
+        mixture.log_likelihood = log_likelihood;
+        if (log_likelihood - previous).abs() < GMM_TOLERANCE {
+            break;
         }
         
-        // Update centers
-        for (cluster_idx, cluster) in clusters.iter().enumerate() {
-            if !cluster.is_empty() {
-                for dim in 0..centers[cluster_idx].len() {
-                    let sum: f64 = cluster.iter()
-                        .map(|&point_idx| data[point_idx][dim])
-                        .sum();
-                    centers[cluster_idx][dim] = sum / cluster.len() as f64;
-                }
-            }
+        // M-step
+        for component in 0..k {
+            let weights: Vec<f64> = mixture.responsibilities.iter().map(|row| row[component]).collect();
+            let total = weights.iter().sum::<f64>().max(f64::MIN_POSITIVE);
+            let mean = points.iter().zip(&weights).fold(DVector::zeros(dims), |acc, (p, &w)| acc + p * w) / total;
+            mixture.covariances[component] = weighted_covariance(&points, &weights, &mean, covariance);
+            mixture.means[component] = mean;
+            mixture.weights[component] = total / samples as f64;
         }
     }
     
-    Ok((clusters, centers))
+    // Keep responsibilities consistent with the final parameters
+    let (responsibilities, log_likelihood) = gmm_expectation(&points, &mixture)?;
+    mixture.responsibilities = responsibilities;
+    mixture.log_likelihood = log_likelihood;
+    
+    Ok(mixture)
 }
 
-fn euclidean_distance(a: &[f64], b: &[f64]) -> f64 {
-    a.iter().zip(b.iter())
-        .map(|(&x, &y)| (x - y).powi(2))
-        .sum::<f64>()
-        .sqrt()
+/// Weighted sample covariance around `mean`, regularized on the diagonal
+fn weighted_covariance(points: &[DVector<f64>], weights: &[f64], mean: &DVector<f64>, covariance: CovarianceType) -> DMatrix<f64> {
+    let dims = mean.len();
+    let total = weights.iter().sum::<f64>().max(f64::MIN_POSITIVE);
+    let mut sigma = points.iter().zip(weights).fold(DMatrix::zeros(dims, dims), |acc, (p, &w)| {
+        let diff = p - mean;
+        acc + &diff * diff.transpose() * w
+    }) / total;
+    
+    if covariance == CovarianceType::Diagonal {
+        sigma = DMatrix::from_diagonal(&sigma.diagonal());
+    }
+    for d in 0..dims {
+        sigma[(d, d)] += GMM_REGULARIZATION;
+    }
+    sigma
 }
 
-fn calculate_pattern_strengths(_patterns: &[PatternData], _clusters: &[Vec<usize>]) -> Result<Vec<f64>, Error> {
-    Ok(vec![])
+/// Responsibilities for every sample and the mean per-sample log-likelihood
+fn gmm_expectation(points: &[DVector<f64>], mixture: &GaussianMixture) -> Result<(Vec<Vec<f64>>, f64), BraunError> {
+    let dims = points[0].len() as f64;
+    let factors = mixture.covariances.iter()
+        .map(|sigma| {
+            sigma.clone().cholesky()
+                .map(|cholesky| cholesky.unpack())
+                .ok_or_else(|| BraunError::Singular("Component covariance is not positive definite".to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let log_norms: Vec<f64> = factors.iter()
+        .zip(&mixture.weights)
+        .map(|(lower, weight)| {
+            let log_det = 2.0 * lower.diagonal().iter().map(|v| v.ln()).sum::<f64>();
+            weight.ln() - 0.5 * (dims * (2.0 * std::f64::consts::PI).ln() + log_det)
+        })
+        .collect();
+    
+    let rows: Vec<(Vec<f64>, f64)> = in_compute_pool(|| {
+        points.par_iter()
+            .map(|point| {
+                let log_probs: Vec<f64> = factors.iter()
+                    .zip(&mixture.means)
+                    .zip(&log_norms)
+                    .map(|((lower, mean), log_norm)| {
+                        let whitened = lower.solve_lower_triangular(&(point - mean))
+                            .unwrap_or_else(|| DVector::from_element(point.len(), f64::INFINITY));
+                        log_norm - 0.5 * whitened.norm_squared()
+                    })
+                    .collect();
+                let max = log_probs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                let log_sum = max + log_probs.iter().map(|lp| (lp - max).exp()).sum::<f64>().ln();
+                (log_probs.iter().map(|lp| (lp - log_sum).exp()).collect(), log_sum)
+            })
+            .collect()
+    });
+    
+    let log_likelihood = rows.iter().map(|(_, ll)| ll).sum::<f64>() / points.len() as f64;
+    Ok((rows.into_iter().map(|(r, _)| r).collect(), log_likelihood))
 }
 
-fn detect_anomalies(_patterns: &[PatternData], _clusters: &[Vec<usize>]) -> Result<Vec<usize>, Error> {
-    Ok(vec![])
+fn mean_center(data: &[Vec<f64>], cluster: &[usize], dims: usize) -> Vec<f64> {
+    (0..dims)
+        .map(|dim| {
+            let sum: f64 = cluster.iter()
+                .map(|&point_idx| data[point_idx][dim])
+                .sum();
+            sum / cluster.len() as f64
+        })
+        .collect()
+}
+
+/// Fill in the metrics every clustering algorithm reports, computed from
+/// its `clusters` alone so results compare across algorithms. Missing
+/// centers become cluster means.
+fn score_clustering(patterns: &[PatternData], result: &mut PatternRecognitionResult) {
+    let data: Vec<Vec<f64>> = patterns.iter()
+        .map(|p| p.feature_vector.clone())
+        .collect();
+    
+    if result.cluster_centers.len() != result.clusters.len() {
+        let dims = data.first().map_or(0, |row| row.len());
+        result.cluster_centers = result.clusters.iter()
+            .map(|cluster| if cluster.is_empty() { vec![0.0; dims] } else { mean_center(&data, cluster, dims) })
+            .collect();
+    }
+    
+    result.pattern_strengths = calculate_pattern_strengths(&data, &result.clusters);
+    result.anomalies = detect_anomalies(&data, &result.clusters, &result.cluster_centers);
+    result.recognition_confidence = calculate_recognition_confidence(&result.pattern_strengths, &result.clusters);
+}
+
+/// Silhouette of every pattern, in input order. Unclustered patterns,
+/// patterns alone in their cluster and single-cluster results score 0.
+fn calculate_pattern_strengths(data: &[Vec<f64>], clusters: &[Vec<usize>]) -> Vec<f64> {
+    let mut membership = vec![None; data.len()];
+    for (cluster_idx, cluster) in clusters.iter().enumerate() {
+        for &point_idx in cluster {
+            membership[point_idx] = Some(cluster_idx);
+        }
+    }
+    
+    in_compute_pool(|| {
+        (0..data.len()).into_par_iter()
+            .map(|point_idx| {
+                let Some(own) = membership[point_idx] else {
+                    return 0.0;
+                };
+                if clusters[own].len() < 2 {
+                    return 0.0;
+                }
+                let total_distance = |cluster: &[usize]| -> f64 {
+                    cluster.iter().map(|&other| euclidean_distance(&data[point_idx], &data[other])).sum()
+                };
+                
+                let a = total_distance(&clusters[own]) / (clusters[own].len() - 1) as f64;
+                let b = clusters.iter()
+                    .enumerate()
+                    .filter(|(cluster_idx, cluster)| *cluster_idx != own && !cluster.is_empty())
+                    .map(|(_, cluster)| total_distance(cluster) / cluster.len() as f64)
+                    .fold(f64::INFINITY, f64::min);
+                
+                if b.is_finite() && a.max(b) > 0.0 { (b - a) / a.max(b) } else { 0.0 }
+            })
+            .collect()
+    })
 }
 
-fn calculate_recognition_confidence(_clusters: &[Vec<usize>]) -> Result<f64, Error> {
-    Ok(0.8)
+/// Distance from its center, in standard deviations above the cluster's
+/// mean, beyond which a clustered pattern counts as an outlier
+const ANOMALY_STD_DEVIATIONS: f64 = 3.0;
+
+/// Unclustered patterns plus outliers within each cluster, sorted
+fn detect_anomalies(data: &[Vec<f64>], clusters: &[Vec<usize>], centers: &[Vec<f64>]) -> Vec<usize> {
+    let mut clustered = vec![false; data.len()];
+    let mut anomalies = Vec::new();
+    
+    for (cluster, center) in clusters.iter().zip(centers) {
+        for &point_idx in cluster {
+            clustered[point_idx] = true;
+        }
+        if cluster.len() < 3 {
+            continue;
+        }
+        
+        let distances: Vec<f64> = cluster.iter()
+            .map(|&point_idx| euclidean_distance(&data[point_idx], center))
+            .collect();
+        let mean = distances.iter().sum::<f64>() / distances.len() as f64;
+        let std_dev = (distances.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / distances.len() as f64).sqrt();
+        anomalies.extend(cluster.iter()
+            .zip(&distances)
+            .filter(|(_, &distance)| distance > mean + ANOMALY_STD_DEVIATIONS * std_dev)
+            .map(|(&point_idx, _)| point_idx));
+    }
+    
+    anomalies.extend((0..data.len()).filter(|&point_idx| !clustered[point_idx]));
+    anomalies.sort_unstable();
+    anomalies
+}
+
+/// Validity index in 0..=1: the clustered patterns' mean silhouette rescaled
+/// from -1..=1, weighted by the fraction of patterns that were clustered
+fn calculate_recognition_confidence(strengths: &[f64], clusters: &[Vec<usize>]) -> f64 {
+    let clustered: Vec<usize> = clusters.iter().flatten().copied().collect();
+    if clustered.is_empty() {
+        return 0.0;
+    }
+    
+    let mean_silhouette = clustered.iter().map(|&point_idx| strengths[point_idx]).sum::<f64>() / clustered.len() as f64;
+    (mean_silhouette + 1.0) / 2.0 * clustered.len() as f64 / strengths.len() as f64
+}
+
+/// `load_data` may carry the BEAM scheduler count (e.g.
+/// `System.schedulers_online()`) so the default compute pool leaves those
+/// cores to the schedulers
+fn load(env: Env, info: Term) -> bool {
+    rustler::resource!(ResultStream, env);
+    if let Ok(schedulers) = info.decode::<usize>() {
+        let _ = configure_compute_pool(default_compute_threads(Some(schedulers)), false);
+    }
+    true
 }
 
 rustler::init!(
     "Elixir.AiOsx.Braun",
     [
         compute_matrix_operations,
+        compute_matrix_operations_batch,
+        compute_matrix_operations_stream,
+        read_stream_chunk,
+        configure_compute_threads,
+        configure_compute_cache,
+        clear_compute_cache,
+        configure_strict_numerics,
+        approx_equal_matrix,
         quantum_inspired_optimization,
         simulate_field_dynamics,
         parallel_pattern_recognition,
+        reduce_dimensions,
         gpu_tensor_operations,
         coordinate_distributed_computation
-    ]
-);
\ No newline at end of file
+    ],
+    load = load
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn annealing_params(seed: Option<u64>) -> OptimizationParams {
+        OptimizationParams {
+            algorithm: "quantum_annealing".to_string(),
+            max_iterations: 200,
+            convergence_threshold: 0.0,
+            learning_rate: 0.1,
+            regularization: 0.0,
+            seed,
+            timeout_ms: None,
+            track_history: false,
+        }
+    }
+
+    fn anneal(params: &OptimizationParams) -> OptimizationResult {
+        match quantum_annealing_optimization(&serde_json::json!({}), params) {
+            Ok(result) => result,
+            Err(_) => panic!("annealing failed"),
+        }
+    }
+
+    #[test]
+    fn test_seeded_annealing_is_reproducible() {
+        let first = anneal(&annealing_params(Some(42)));
+        let second = anneal(&annealing_params(Some(42)));
+        assert_eq!(first.optimization_path.len(), 200);
+        assert_eq!(
+            serde_json::to_string(&first.optimization_path).unwrap(),
+            serde_json::to_string(&second.optimization_path).unwrap()
+        );
+        assert_eq!(first.final_energy.to_bits(), second.final_energy.to_bits());
+
+        let other = anneal(&annealing_params(Some(7)));
+        assert_ne!(first.optimization_path, other.optimization_path);
+    }
+
+    #[test]
+    fn test_energy_history_tracks_best_energy() {
+        let untracked = anneal(&annealing_params(Some(3)));
+        assert!(untracked.energy_history.is_empty());
+
+        let params = OptimizationParams { track_history: true, ..annealing_params(Some(3)) };
+        let result = anneal(&params);
+        assert_eq!(result.energy_history.len(), result.iterations_used as usize);
+        assert!(result.energy_history.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert_eq!(*result.energy_history.last().unwrap(), result.final_energy);
+        assert_eq!(result.optimization_path, untracked.optimization_path);
+    }
+
+    #[test]
+    fn test_seed_is_optional_in_params_json() {
+        let params: OptimizationParams = serde_json::from_str(
+            r#"{"algorithm":"quantum_annealing","max_iterations":10,"convergence_threshold":0.01,"learning_rate":0.1,"regularization":0.0}"#,
+        )
+        .unwrap();
+        assert_eq!(params.seed, None);
+    }
+
+    #[test]
+    fn test_cosine_kmeans_groups_by_direction() {
+        // Alternate directions so the first two points seed different clusters
+        let a = [1.0, 0.1, 0.0];
+        let b = [0.0, 0.2, 1.0];
+        let data: Vec<Vec<f64>> = [1.0, 50.0, 0.02, 400.0]
+            .iter()
+            .flat_map(|scale| vec![a.iter().map(|v| v * scale).collect(), b.iter().map(|v| v * scale).collect()])
+            .collect();
+
+        let Ok((clusters, centers, false)) = kmeans_parallel(&data, 2, 10, DistanceMetric::Cosine, Deadline::none()) else {
+            panic!("cosine k-means failed");
+        };
+        assert_eq!(clusters[0], vec![0, 2, 4, 6]);
+        assert_eq!(clusters[1], vec![1, 3, 5, 7]);
+        for center in &centers {
+            let norm = center.iter().map(|x| x * x).sum::<f64>().sqrt();
+            assert!((norm - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_manhattan_kmeans_median_centers() {
+        let data: Vec<Vec<f64>> = [0.0, 30.0, 1.0, 31.0, 2.0, 35.0]
+            .iter()
+            .map(|&x| vec![x])
+            .collect();
+
+        let Ok((clusters, centers, false)) = kmeans_parallel(&data, 2, 10, DistanceMetric::Manhattan, Deadline::none()) else {
+            panic!("manhattan k-means failed");
+        };
+        assert_eq!(clusters[0], vec![0, 2, 4]);
+        assert_eq!(clusters[1], vec![1, 3, 5]);
+        assert_eq!(centers[0], vec![1.0]);
+        assert_eq!(centers[1], vec![31.0]);
+    }
+
+    #[test]
+    fn test_unknown_metric_is_rejected() {
+        assert!(DistanceMetric::parse("chebyshev").is_err());
+        assert!(matches!(DistanceMetric::parse("cosine"), Ok(DistanceMetric::Cosine)));
+    }
+
+    #[test]
+    fn test_tiny_timeout_returns_partial_results() {
+        let params = OptimizationParams {
+            max_iterations: u32::MAX,
+            timeout_ms: Some(5),
+            ..annealing_params(Some(1))
+        };
+        let started = std::time::Instant::now();
+        let result = anneal(&params);
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+        assert!(result.timed_out);
+        assert!(!result.converged);
+        assert!(result.iterations_used < u32::MAX);
+        assert_eq!(result.optimization_path.len(), result.iterations_used as usize);
+
+        let data: Vec<Vec<f64>> = (0..10).map(|i| vec![i as f64]).collect();
+        let Ok((_, _, timed_out)) = kmeans_parallel(&data, 2, usize::MAX, DistanceMetric::Euclidean, Deadline::after_ms(Some(0))) else {
+            panic!("k-means failed");
+        };
+        assert!(timed_out);
+
+        let state = FieldState {
+            field_values: HashMap::new(),
+            topology: vec![],
+            energy_density: 0.0,
+            coherence_measure: 0.0,
+            temporal_signature: vec![],
+        };
+        let Ok(evolution) = simulate_field_evolution(&state, &serde_json::json!({}), u32::MAX, Deadline::after_ms(Some(0))) else {
+            panic!("field evolution failed");
+        };
+        assert!(evolution.timed_out);
+        assert!(evolution.trajectory.is_empty());
+    }
+
+    #[test]
+    fn test_pattern_input_accepts_timeout_object() {
+        let input: PatternRecognitionInput = serde_json::from_str(r#"{"patterns": [], "timeout_ms": 10}"#).unwrap();
+        assert!(matches!(input, PatternRecognitionInput::WithOptions { timeout_ms: Some(10), .. }));
+
+        let input: PatternRecognitionInput = serde_json::from_str("[]").unwrap();
+        assert!(matches!(input, PatternRecognitionInput::Patterns(_)));
+    }
+
+    #[test]
+    fn test_matrix_batch_mixes_multiply_and_svd() {
+        let operations: Vec<MatrixOperationItem> = serde_json::from_str(r#"[
+            {"operation": "multiply", "matrices": [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]},
+            {"operation": "svd", "matrices": [[[3, 0], [0, 4]]]},
+            {"operation": "multiply", "matrices": [[[1, 2, 3]], [[1, 2]]]},
+            {"operation": "transpose", "matrices": []}
+        ]"#).unwrap();
+
+        let results = run_matrix_operations_batch(&operations);
+        assert_eq!(results.len(), 4);
+
+        assert_eq!(results[0].status, "ok");
+        assert_eq!(results[0].result, Some(vec![vec![19.0, 22.0], vec![43.0, 50.0]]));
+
+        assert_eq!(results[1].status, "ok");
+        let svd = results[1].result.as_ref().unwrap();
+        let mut singular_values = svd[1].clone();
+        singular_values.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        assert!((singular_values[0] - 4.0).abs() < 1e-9);
+        assert!((singular_values[1] - 3.0).abs() < 1e-9);
+
+        // Bad items are isolated to their own entries
+        assert_eq!(results[2].status, "error");
+        assert!(results[2].error.as_ref().unwrap().contains("Cannot multiply"));
+        assert_eq!(results[3].status, "error");
+        assert!(results[3].result.is_none());
+    }
+
+    #[test]
+    fn test_gpu_matrix_multiply_uses_core_matmul() {
+        let lhs = vec![vec![vec![1.0, 2.0], vec![3.0, 4.0]], vec![vec![1.0, 0.0, 2.0]]];
+        let rhs = vec![vec![vec![5.0, 6.0], vec![7.0, 8.0]], vec![vec![1.0], vec![1.0], vec![1.0]]];
+        let products = gpu_simulate_matrix_multiply(&[lhs.clone(), rhs.clone()]).unwrap();
+        assert_eq!(products, vec![vec![vec![19.0, 22.0], vec![43.0, 50.0]], vec![vec![3.0]]]);
+        for (product, (a, b)) in products.iter().zip(lhs.iter().zip(&rhs)) {
+            assert_eq!(product, &multiply_matrices::<f64>(a, b).unwrap());
+        }
+
+        assert!(matches!(gpu_simulate_matrix_multiply(&[lhs.clone()]), Err(BraunError::InvalidArgument(_))));
+        assert!(matches!(gpu_simulate_matrix_multiply(&[lhs.clone(), rhs[..1].to_vec()]), Err(BraunError::DimensionMismatch(_))));
+        assert!(matches!(gpu_simulate_matrix_multiply(&[lhs[1..].to_vec(), lhs[1..].to_vec()]), Err(BraunError::DimensionMismatch(_))));
+    }
+
+    #[test]
+    fn test_gpu_fft_matches_core_spectrum_per_slice() {
+        let real: Vec<f64> = (0..12).map(|i| ((i * 5) % 7) as f64 - 3.0).collect();
+        let imag: Vec<f64> = (0..12).map(|i| i as f64 * 0.25).collect();
+        let tensors = vec![
+            vec![vec![vec![1.0, 1.0, 1.0, 1.0]], vec![real.clone(), imag.clone()]],
+            vec![vec![real[..5].to_vec()]],
+        ];
+        let spectra = gpu_simulate_fft(&tensors).unwrap();
+        assert_eq!(spectra.len(), 3);
+        assert_eq!(spectra[0], vec![vec![4.0, 0.0, 0.0, 0.0], vec![0.0; 4]]);
+
+        // Same core routine as the WASM binding, so identical spectra
+        let (mut core_real, mut core_imag) = (real.clone(), imag.clone());
+        katalyst_compute_core::fft(&mut core_real, &mut core_imag, false).unwrap();
+        assert_eq!(spectra[1], vec![core_real, core_imag]);
+
+        let (mut core_real, mut core_imag) = (real[..5].to_vec(), vec![0.0; 5]);
+        katalyst_compute_core::fft(&mut core_real, &mut core_imag, false).unwrap();
+        assert_eq!(spectra[2], vec![core_real, core_imag]);
+
+        assert!(matches!(gpu_simulate_fft(&[vec![vec![]]]), Err(BraunError::InvalidArgument(_))));
+        assert!(matches!(gpu_simulate_fft(&[vec![vec![real.clone(), imag[..4].to_vec()]]]), Err(BraunError::DimensionMismatch(_))));
+    }
+
+    #[test]
+    fn test_f32_precision_multiply() {
+        let input: MatrixOperationsInput = serde_json::from_str(
+            r#"{"matrices": [[[0.1, 0.2], [0.3, 0.4]], [[0.5, 0.6], [0.7, 0.8]]], "precision": "f32"}"#,
+        ).unwrap();
+        let MatrixOperationsInput::WithOptions { matrices, options } = input else {
+            panic!("expected matrices with options");
+        };
+        assert_eq!(options.precision, MatrixPrecision::F32);
+
+        let single = run_matrix_operation("multiply", &matrices, &options).unwrap();
+        let double = run_matrix_operation("multiply", &matrices, &MatrixOptions::default()).unwrap();
+
+        // The f32 path returns f32-rounded values, close to but not equal to f64
+        assert!(single.iter().flatten().all(|&v| (v as f32) as f64 == v));
+        assert!(double.iter().flatten().any(|&v| (v as f32) as f64 != v));
+        assert!(matrices_approx_equal(&single, &double, 1e-6, 1e-6));
+        assert!(!matrices_approx_equal(&single, &double, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_matrices_approx_equal_checks_shape_and_tolerance() {
+        let a = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+        let near = vec![vec![1.0 + 1e-12, 2.0], vec![3.0, 4.0 - 1e-12]];
+        assert!(matrices_approx_equal(&a, &near, 1e-9, 0.0));
+        assert!(!matrices_approx_equal(&a, &[vec![1.0, 2.0], vec![3.0, 4.5]], 1e-3, 1e-3));
+        assert!(!matrices_approx_equal(&a, &a[..1], 1.0, 1.0));
+        assert!(!matrices_approx_equal(&a, &[vec![1.0, 2.0], vec![3.0]], 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_precision_defaults_to_f64() {
+        let input: MatrixOperationsInput = serde_json::from_str("[[[1.0]]]").unwrap();
+        assert!(matches!(input, MatrixOperationsInput::Matrices(_)));
+
+        let item: MatrixOperationItem = serde_json::from_str(r#"{"operation": "svd", "matrices": [[[1.0]]]}"#).unwrap();
+        assert_eq!(item.options.precision, MatrixPrecision::F64);
+    }
+
+    fn matrix_error_kind(operation: &str, matrices_json: &str) -> &'static str {
+        let matrices: Vec<Vec<Vec<f64>>> = serde_json::from_str(matrices_json).unwrap();
+        match run_matrix_operation(operation, &matrices, &MatrixOptions::default()) {
+            Ok(_) => panic!("{} should have failed", operation),
+            Err(error) => error.kind(),
+        }
+    }
+
+    #[test]
+    fn test_errors_carry_category_atoms() {
+        assert_eq!(matrix_error_kind("multiply", "[[[1, 2, 3]], [[1, 2]]]"), "dimension_mismatch");
+        assert_eq!(matrix_error_kind("multiply", "[[[1, 2], [3]], [[1], [2]]]"), "dimension_mismatch");
+        assert_eq!(matrix_error_kind("eigendecomposition", "[[[1, 2, 3], [4, 5, 6]]]"), "dimension_mismatch");
+        assert_eq!(matrix_error_kind("multiply", "[[[1]]]"), "invalid_argument");
+        assert_eq!(matrix_error_kind("svd", "[]"), "invalid_argument");
+        assert_eq!(matrix_error_kind("transpose", "[[[1]]]"), "unknown_operation");
+
+        let Err(error) = parse_distance_metric("chebyshev") else {
+            panic!("unknown metric accepted");
+        };
+        assert_eq!(error.kind(), "unknown_operation");
+
+        let parse_error = serde_json::from_str::<MatrixOperationsInput>("not json")
+            .map_err(|e| BraunError::Parse(e.to_string()))
+            .unwrap_err();
+        assert_eq!(parse_error.kind(), "parse_error");
+
+        assert_eq!(BraunError::Singular("matrix is singular".to_string()).kind(), "singular");
+        assert_eq!(BraunError::Timeout("deadline exceeded".to_string()).kind(), "timeout");
+    }
+
+    #[test]
+    fn test_batch_results_report_error_kind() {
+        let operations: Vec<MatrixOperationItem> = serde_json::from_str(r#"[
+            {"operation": "svd", "matrices": [[[1.0]]]},
+            {"operation": "eigendecomposition", "matrices": [[[1, 2]]]}
+        ]"#).unwrap();
+
+        let results = run_matrix_operations_batch(&operations);
+        assert_eq!(results[0].error_kind, None);
+        assert_eq!(results[1].error_kind.as_deref(), Some("dimension_mismatch"));
+    }
+
+    /// Rebuilds U Σ Vᵀ from the flattened `[u, sigma, v_t]` operation output
+    fn reconstruct(output: &[Vec<f64>], rows: usize, cols: usize) -> DMatrix<f64> {
+        let rank = output[1].len();
+        let u = DMatrix::from_row_slice(rows, output[0].len() / rows, &output[0]).columns(0, rank).into_owned();
+        let v_t = DMatrix::from_row_slice(output[2].len() / cols, cols, &output[2]).rows(0, rank).into_owned();
+        u * DMatrix::from_diagonal(&DVector::from_vec(output[1].clone())) * v_t
+    }
+
+    #[test]
+    fn test_truncated_svd_matches_full_svd_on_low_rank_data() {
+        let (rows, cols) = (30, 20);
+        // Rank-3 matrix: sum of three outer products with distinct weights
+        let matrix: Vec<Vec<f64>> = (0..rows)
+            .map(|i| {
+                (0..cols)
+                    .map(|j| {
+                        let (x, y) = (i as f64, j as f64);
+                        9.0 * (0.3 * x).sin() * (0.2 * y).cos()
+                            + 4.0 * (0.1 * x + 1.0) * (0.05 * y).sin()
+                            + 1.0 * (0.7 * x).cos() * (y - 10.0) / 10.0
+                    })
+                    .collect()
+            })
+            .collect();
+        let original = DMatrix::from_row_slice(rows, cols, &matrix.concat());
+        let matrices = vec![matrix];
+
+        let full = run_matrix_operation("svd", &matrices, &MatrixOptions::default()).unwrap();
+
+        for rank in [2, 3] {
+            let options = MatrixOptions { rank: Some(rank), seed: Some(7), ..Default::default() };
+            let truncated = run_matrix_operation("truncated_svd", &matrices, &options).unwrap();
+            assert_eq!(truncated[0].len(), rows * rank);
+            assert_eq!(truncated[1].len(), rank);
+            assert_eq!(truncated[2].len(), rank * cols);
+
+            let mut full_top = full.clone();
+            full_top[1].sort_by(|a, b| b.total_cmp(a));
+            full_top[1].truncate(rank);
+            let full_error = (&original - reconstruct(&full_top, rows, cols)).norm();
+            let truncated_error = (&original - reconstruct(&truncated, rows, cols)).norm();
+
+            // Eckart–Young: the best rank-k error is the full SVD's; randomized
+            // SVD with oversampling should match it on exactly low-rank data
+            assert!(truncated_error <= full_error + 1e-6 * original.norm(), "rank {}: {} vs {}", rank, truncated_error, full_error);
+            for (a, b) in truncated[1].iter().zip(&full_top[1]) {
+                assert!((a - b).abs() < 1e-6 * b.max(1.0));
+            }
+        }
+        // Rank 3 captures the matrix exactly
+        let options = MatrixOptions { rank: Some(3), seed: Some(7), ..Default::default() };
+        let exact = run_matrix_operation("truncated_svd", &matrices, &options).unwrap();
+        assert!((&original - reconstruct(&exact, rows, cols)).norm() < 1e-8 * original.norm());
+    }
+
+    #[test]
+    fn test_truncated_svd_requires_valid_rank() {
+        let matrices = vec![vec![vec![1.0, 2.0], vec![3.0, 4.0]]];
+        let kind = |rank: Option<usize>| {
+            let options = MatrixOptions { rank, ..Default::default() };
+            run_matrix_operation("truncated_svd", &matrices, &options).unwrap_err().kind()
+        };
+        assert_eq!(kind(None), "invalid_argument");
+        assert_eq!(kind(Some(0)), "invalid_argument");
+        assert_eq!(kind(Some(3)), "invalid_argument");
+    }
+
+    /// Points on a tilted plane through (1, 2, 3, 4, 5) in 5D, plus a tiny
+    /// deterministic wobble off the plane
+    fn planar_features(count: usize) -> Vec<Vec<f64>> {
+        let u = [1.0, 0.5, -0.5, 0.0, 2.0];
+        let v = [0.0, 1.0, 1.0, -1.0, 0.5];
+        (0..count)
+            .map(|i| {
+                let (a, b) = ((i as f64 * 0.7).sin() * 3.0, (i as f64 * 1.3).cos() * 2.0);
+                let wobble = 1e-4 * ((i % 5) as f64 - 2.0);
+                (0..5).map(|d| (d + 1) as f64 + a * u[d] + b * v[d] + wobble).collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_pca_two_components_capture_planar_data() {
+        let features = planar_features(60);
+        let pca = principal_components(&features, 2).unwrap();
+
+        assert_eq!(pca.projection.len(), 60);
+        assert!(pca.projection.iter().all(|row| row.len() == 2));
+        assert!(pca.explained_variance_ratio[0] >= pca.explained_variance_ratio[1]);
+        assert!(pca.explained_variance_ratio.iter().sum::<f64>() > 0.9999);
+
+        // Mapping the projection back reproduces the input almost exactly
+        for (row, projected) in features.iter().zip(&pca.projection) {
+            for d in 0..5 {
+                let rebuilt = pca.mean[d] + (0..2).map(|c| projected[c] * pca.components[c][d]).sum::<f64>();
+                assert!((rebuilt - row[d]).abs() < 1e-3);
+            }
+        }
+
+        assert!(principal_components(&features, 0).is_err());
+        assert!(principal_components(&features, 6).is_err());
+        assert!(principal_components(&features[..1], 1).is_err());
+    }
+
+    #[test]
+    fn test_pca_preprocessing_replaces_feature_vectors() {
+        let patterns: Vec<serde_json::Value> = planar_features(10).into_iter()
+            .enumerate()
+            .map(|(i, features)| serde_json::json!({
+                "pattern_id": i.to_string(),
+                "temporal_data": [],
+                "spatial_coordinates": [],
+                "feature_vector": features,
+                "metadata": {},
+            }))
+            .collect();
+        let input = serde_json::json!({"patterns": patterns, "preprocessing": {"method": "pca", "components": 2}});
+
+        let PatternRecognitionInput::WithOptions { mut patterns, preprocessing: Some(preprocessing), .. } =
+            serde_json::from_value(input).unwrap() else {
+            panic!("expected options with preprocessing");
+        };
+        preprocess_patterns(&mut patterns, preprocessing).unwrap();
+        assert!(patterns.iter().all(|p| p.feature_vector.len() == 2));
+    }
+
+    /// `count` samples from N(mean, L Lᵀ) for a lower-triangular 2x2 `l`
+    fn gaussian_samples(count: usize, mean: [f64; 2], l: [[f64; 2]; 2], rng: &mut StdRng) -> Vec<Vec<f64>> {
+        (0..count)
+            .map(|_| {
+                let z: [f64; 2] = [rng.sample(StandardNormal), rng.sample(StandardNormal)];
+                vec![mean[0] + l[0][0] * z[0], mean[1] + l[1][0] * z[0] + l[1][1] * z[1]]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_gmm_recovers_two_gaussians() {
+        let mut rng = StdRng::seed_from_u64(11);
+        // True covariances [[1, 0], [0, 0.25]] and [[2.25, 0.9], [0.9, 1.0]]
+        let mut data = gaussian_samples(600, [0.0, 0.0], [[1.0, 0.0], [0.0, 0.5]], &mut rng);
+        data.extend(gaussian_samples(400, [12.0, 8.0], [[1.5, 0.0], [0.6, 0.8]], &mut rng));
+
+        let mixture = fit_gaussian_mixture(&data, 2, 200, CovarianceType::Full, Deadline::none()).unwrap();
+        assert!(!mixture.timed_out);
+        let (first, second) = if mixture.means[0][0] < mixture.means[1][0] { (0, 1) } else { (1, 0) };
+
+        assert!((mixture.weights[first] - 0.6).abs() < 0.01);
+        assert!((mixture.means[first][0]).abs() < 0.15 && (mixture.means[first][1]).abs() < 0.1);
+        assert!((mixture.means[second][0] - 12.0).abs() < 0.2 && (mixture.means[second][1] - 8.0).abs() < 0.15);
+
+        // The fitted covariances match each source's sample covariance, which
+        // is what EM converges to once the components separate cleanly
+        for (component, source) in [(first, &data[..600]), (second, &data[600..])] {
+            let points: Vec<DVector<f64>> = source.iter().map(|row| DVector::from_vec(row.clone())).collect();
+            let mean = points.iter().fold(DVector::zeros(2), |acc, p| acc + p) / points.len() as f64;
+            let sample = weighted_covariance(&points, &vec![1.0; points.len()], &mean, CovarianceType::Full);
+            assert!((&mixture.covariances[component] - sample).amax() < 1e-3);
+        }
+        assert!((mixture.covariances[second][(0, 1)] - 0.9).abs() < 0.3);
+
+        // Well separated, so every sample is confidently assigned to its source
+        for (i, row) in mixture.responsibilities.iter().enumerate() {
+            let source = if i < 600 { first } else { second };
+            assert!(row[source] > 0.99);
+        }
+    }
+
+    #[test]
+    fn test_gmm_diagonal_and_duplicate_points() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut data = gaussian_samples(200, [0.0, 0.0], [[1.0, 0.0], [0.8, 0.6]], &mut rng);
+        // A cluster of identical points has zero variance before regularization
+        data.extend(std::iter::repeat(vec![20.0, 20.0]).take(50));
+
+        let mixture = fit_gaussian_mixture(&data, 2, 100, CovarianceType::Diagonal, Deadline::none()).unwrap();
+        for covariance in &mixture.covariances {
+            assert_eq!(covariance[(0, 1)], 0.0);
+            assert_eq!(covariance[(1, 0)], 0.0);
+        }
+        assert!(mixture.log_likelihood.is_finite());
+        assert!(CovarianceType::parse("spherical").is_err());
+        assert!(fit_gaussian_mixture(&data, 0, 10, CovarianceType::Full, Deadline::none()).is_err());
+    }
+
+    #[test]
+    fn test_streamed_result_reconstructs_large_matrix() {
+        let (rows, inner, cols) = (300, 40, 250);
+        let a: Vec<Vec<f64>> = (0..rows).map(|i| (0..inner).map(|j| ((i * 7 + j * 3) % 11) as f64 - 5.0).collect()).collect();
+        let b: Vec<Vec<f64>> = (0..inner).map(|i| (0..cols).map(|j| ((i * 5 + j) % 13) as f64 * 0.5).collect()).collect();
+        let expected = run_matrix_operation("multiply", &[a, b], &MatrixOptions::default()).unwrap();
+
+        let header = ComputationResponse {
+            id: "stream".to_string(),
+            result: serde_json::json!({"format": "ndjson", "rows": expected.len()}),
+            computation_time_ms: 0,
+            memory_used_bytes: 0,
+            cpu_utilization: 0.0,
+            convergence_status: "completed".to_string(),
+            error_metrics: HashMap::new(),
+            cached: false,
+        };
+        let stream = ResultStream::new(header, expected.clone()).unwrap();
+        assert!(stream.next_chunk(0).is_err());
+
+        let mut lines = Vec::new();
+        let mut chunks = 0;
+        while let Some(chunk) = stream.next_chunk(64).unwrap() {
+            chunks += 1;
+            lines.extend(chunk.lines().map(str::to_string));
+        }
+        assert_eq!(chunks, 5);
+        assert_eq!(stream.next_chunk(64).unwrap(), None);
+
+        let header: ComputationResponse = serde_json::from_str(&lines[0]).unwrap();
+        assert_eq!(header.result["rows"], 300);
+        let reconstructed: Vec<Vec<f64>> = lines[1..].iter().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(reconstructed, expected);
+    }
+
+    #[test]
+    fn test_configured_pool_size_is_used() {
+        assert_eq!(configure_compute_pool(3, false).unwrap(), 3);
+        assert_eq!(in_compute_pool(rayon::current_num_threads), 3);
+
+        let operations: Vec<MatrixOperationItem> = serde_json::from_str(r#"[
+            {"operation": "multiply", "matrices": [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]},
+            {"operation": "multiply", "matrices": [[[1, 2, 3]], [[1], [1], [1]]]}
+        ]"#).unwrap();
+        let results = run_matrix_operations_batch(&operations);
+        assert_eq!(results[0].result, Some(vec![vec![19.0, 22.0], vec![43.0, 50.0]]));
+        assert_eq!(results[1].result, Some(vec![vec![6.0]]));
+
+        assert_eq!(configure_compute_pool(2, true).unwrap(), 2);
+        assert_eq!(in_compute_pool(rayon::current_num_threads), 2);
+        assert!(configure_compute_pool(0, false).unwrap() >= 1);
+        assert_eq!(default_compute_threads(Some(usize::MAX)), (num_cpus::get() / 2).max(1));
+    }
+
+    /// Records the fields of every span and event it sees
+    #[derive(Clone, Default)]
+    struct CapturedTraces(Arc<Mutex<Vec<HashMap<String, String>>>>);
+
+    struct FieldMap(HashMap<String, String>);
+
+    impl tracing::field::Visit for FieldMap {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0.insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for CapturedTraces {
+        fn on_new_span(&self, attrs: &tracing::span::Attributes<'_>, _id: &tracing::span::Id, _ctx: tracing_subscriber::layer::Context<'_, S>) {
+            let mut fields = FieldMap(HashMap::from([("span".to_string(), attrs.metadata().name().to_string())]));
+            attrs.record(&mut fields);
+            self.0.lock().push(fields.0);
+        }
+
+        fn on_event(&self, event: &tracing::Event<'_>, _ctx: tracing_subscriber::layer::Context<'_, S>) {
+            let mut fields = FieldMap(HashMap::from([("level".to_string(), event.metadata().level().to_string())]));
+            event.record(&mut fields);
+            self.0.lock().push(fields.0);
+        }
+    }
+
+    #[test]
+    fn test_nif_spans_capture_matrix_multiply() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let captured = CapturedTraces::default();
+        let subscriber = tracing_subscriber::registry().with(captured.clone());
+        let matrices_json = "[[[1, 2], [3, 4]], [[5, 6], [7, 8]]]";
+
+        let output = tracing::subscriber::with_default(subscriber, || {
+            let ok = traced("compute_matrix_operations", "multiply", matrices_json.len(), |request_id| {
+                let matrices: Vec<Vec<Vec<f64>>> = serde_json::from_str(matrices_json).unwrap();
+                let result = run_matrix_operation("multiply", &matrices, &MatrixOptions::default())?;
+                finish(&ComputationResponse {
+                    id: request_id.to_string(),
+                    result: serde_json::to_value(&result).unwrap(),
+                    computation_time_ms: 0,
+                    memory_used_bytes: 0,
+                    cpu_utilization: 0.0,
+                    convergence_status: "completed".to_string(),
+                    error_metrics: HashMap::new(),
+                    cached: false,
+                })
+            });
+            let failed = traced("compute_matrix_operations", "multiply", 2, |_| {
+                run_matrix_operation("multiply", &[vec![vec![1.0, 2.0, 3.0]], vec![vec![1.0, 2.0]]], &MatrixOptions::default())
+            });
+            (ok, failed.is_err())
+        });
+
+        let (Ok(json), true) = output else {
+            panic!("expected one success and one failure");
+        };
+        let response: ComputationResponse = serde_json::from_str(&json).unwrap();
+
+        let records = captured.0.lock();
+        let spans: Vec<_> = records.iter().filter(|r| r.get("span").map(String::as_str) == Some("braun_nif")).collect();
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0]["nif"], "compute_matrix_operations");
+        assert_eq!(spans[0]["operation"], "multiply");
+        assert_eq!(spans[0]["input_size"], matrices_json.len().to_string());
+        assert_eq!(spans[0]["request_id"], response.id);
+        assert_ne!(spans[0]["request_id"], spans[1]["request_id"]);
+
+        let messages: Vec<_> = records.iter().filter_map(|r| r.get("message").map(|m| (r["level"].as_str(), m.as_str()))).collect();
+        assert_eq!(messages, vec![("INFO", "computation finished"), ("WARN", "computation failed")]);
+        assert!(records.iter().any(|r| r.get("kind").map(String::as_str) == Some("dimension_mismatch")));
+    }
+
+    #[test]
+    fn test_distributed_workers_run_concurrently() {
+        let workers: Vec<String> = ["node-a", "node-b", "node-c", "node-d", "node-slow"].iter().map(|w| w.to_string()).collect();
+        let job = serde_json::json!({
+            "data_size": 1001,
+            "worker_timeout_ms": 1000,
+            "simulated_latency_ms": { "node-a": 150, "node-b": 200, "node-c": 250, "node-d": 300, "node-slow": 5000 }
+        });
+
+        let result = coordinate_workers(&job, &workers).unwrap();
+        let stats = &result.execution_statistics;
+
+        // Bounded by the timeout on the slowest worker, not the 6.9s sum
+        assert!(stats["wall_clock_ms"] < 2000.0, "{:?}", stats);
+        assert!(stats["speedup"] > 1.5, "{:?}", stats);
+        assert_eq!(stats["workers_completed"], 4.0);
+        assert_eq!(stats["workers_timed_out"], 1.0);
+        assert_eq!(result.worker_results["node-slow"]["result"], "timeout");
+
+        assert_eq!(result.aggregated_result["status"], "partial");
+        assert_eq!(result.aggregated_result["completion_order"], serde_json::json!(["node-a", "node-b", "node-c", "node-d"]));
+        assert_eq!(result.worker_results["node-a"]["data_processed"], 201);
+        assert_eq!(result.aggregated_result["data_processed"], 1001 - 200);
+    }
+
+    #[test]
+    fn test_cached_matrix_results() {
+        let call = |matrices_json: &str| -> ComputationResponse {
+            let json = compute_matrix_operations("multiply".to_string(), matrices_json.to_string()).unwrap();
+            serde_json::from_str(&json).unwrap()
+        };
+        let input = r#"{"matrices": [[[1, 2], [3, 4]], [[7, 0], [0, 7]]], "cache": true}"#;
+
+        let first = call(input);
+        assert!(!first.cached);
+        let second = call(input);
+        assert!(second.cached);
+        assert_eq!(second.result, first.result);
+        assert_ne!(second.id, first.id);
+
+        let other = call(r#"{"matrices": [[[1, 2], [3, 4]], [[8, 0], [0, 8]]], "cache": true}"#);
+        assert!(!other.cached);
+        assert_eq!(other.result, serde_json::json!([[8.0, 16.0], [24.0, 32.0]]));
+
+        // Without the flag the cache is neither read nor written
+        assert!(!call("[[[1, 2], [3, 4]], [[7, 0], [0, 7]]]").cached);
+
+        let mut cache = ComputeCache::new(2);
+        let keys: Vec<CacheKey> = (0..3).map(|i| ComputeCache::key("multiply", &i.to_string())).collect();
+        for (i, key) in keys.iter().enumerate() {
+            cache.insert(key.clone(), serde_json::json!(i), 0, HashMap::new());
+            cache.get(&keys[0]);
+        }
+        assert!(cache.get(&keys[0]).is_some() && cache.get(&keys[1]).is_none() && cache.get(&keys[2]).is_some());
+
+        // A different input that hashes the same is a miss
+        let colliding = CacheKey { input_json: "other".to_string(), ..keys[0].clone() };
+        assert!(cache.get(&colliding).is_none());
+        assert_eq!(cache.clear(), 2);
+    }
+
+    #[test]
+    fn test_non_finite_results_are_reported() {
+        // Rank one, and large enough that the product overflows: one column
+        // sums to inf + inf, the other to inf - inf
+        let singular = "[[[1e300, 1e300], [1e300, 1e300]], [[1e10, 1e10], [1e10, -1e10]]]";
+
+        let json = compute_matrix_operations("multiply".to_string(), singular.to_string()).unwrap();
+        let response: ComputationResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(response.error_metrics["inf_count"], 2.0);
+        assert_eq!(response.error_metrics["nan_count"], 2.0);
+        assert_eq!(response.result, serde_json::json!([[f64::MAX, 0.0], [f64::MAX, 0.0]]));
+
+        let clean = compute_matrix_operations("multiply".to_string(), "[[[1, 0], [0, 1]], [[2, 3], [4, 5]]]".to_string()).unwrap();
+        let clean: ComputationResponse = serde_json::from_str(&clean).unwrap();
+        assert_eq!(clean.error_metrics["nan_count"] + clean.error_metrics["inf_count"], 0.0);
+
+        let mut path = OptimizationResult {
+            optimal_solution: vec![f64::NEG_INFINITY],
+            optimization_path: vec![vec![1.0, f64::NAN]],
+            convergence_metrics: HashMap::new(),
+            converged: false,
+            iterations_used: 1,
+            final_energy: f64::NAN,
+            energy_history: vec![f64::INFINITY],
+            error_metrics: HashMap::new(),
+            timed_out: false,
+        };
+        assert_eq!(sanitize_result(&mut path).unwrap(), NonFiniteCounts { nan: 2, inf: 2 });
+        assert_eq!(path.optimal_solution, vec![-f64::MAX]);
+
+        STRICT_NUMERICS.store(true, std::sync::atomic::Ordering::Relaxed);
+        let strict = compute_matrix_operations("multiply".to_string(), singular.to_string());
+        STRICT_NUMERICS.store(false, std::sync::atomic::Ordering::Relaxed);
+        assert_eq!(strict.unwrap_err().kind(), "non_finite");
+    }
+
+    #[test]
+    fn test_kmeans_and_dbscan_report_comparable_metrics() {
+        // Three tight blobs, interleaved so k-means seeds one center in each,
+        // and one far outlier
+        let mut rng = StdRng::seed_from_u64(11);
+        let blobs = [[0.0, 0.0], [10.0, 0.0], [0.0, 10.0]];
+        let mut features: Vec<Vec<f64>> = (0..120)
+            .map(|i| {
+                let [x, y] = blobs[i % 3];
+                vec![x + rng.gen_range(-0.5..0.5), y + rng.gen_range(-0.5..0.5)]
+            })
+            .collect();
+        features.push(vec![40.0, 40.0]);
+        let patterns: Vec<serde_json::Value> = features.iter().enumerate()
+            .map(|(i, f)| serde_json::json!({
+                "pattern_id": format!("p{}", i),
+                "temporal_data": [],
+                "spatial_coordinates": [],
+                "feature_vector": f,
+                "metadata": {}
+            }))
+            .collect();
+        let patterns_json = serde_json::to_string(&patterns).unwrap();
+
+        for algorithm in ["kmeans", "dbscan"] {
+            let json = parallel_pattern_recognition(patterns_json.clone(), algorithm.to_string()).unwrap();
+            let response: ComputationResponse = serde_json::from_str(&json).unwrap();
+            let result: PatternRecognitionResult = serde_json::from_value(response.result).unwrap();
+
+            assert_eq!(result.pattern_strengths.len(), features.len(), "{}", algorithm);
+            assert_eq!(result.cluster_centers.len(), result.clusters.len(), "{}", algorithm);
+            assert!(result.recognition_confidence > 0.5, "{}: {}", algorithm, result.recognition_confidence);
+            assert!(result.pattern_strengths[..120].iter().all(|&s| s > 0.5), "{}", algorithm);
+        }
+
+        // DBSCAN finds the blobs by density and leaves the outlier out
+        let json = parallel_pattern_recognition(patterns_json, "dbscan".to_string()).unwrap();
+        let response: ComputationResponse = serde_json::from_str(&json).unwrap();
+        let result: PatternRecognitionResult = serde_json::from_value(response.result).unwrap();
+        assert_eq!(result.clusters.len(), 3);
+        assert_eq!(result.anomalies, vec![120]);
+        assert_eq!(result.pattern_strengths[120], 0.0);
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+use tokio::sync::broadcast::error::RecvError;
+
+/// What a subscriber receives: either the next event, or a notice that it
+/// fell behind and missed `missed` events. After a resync the subscriber's
+/// view may be stale and should be refetched (e.g. via `get_session`)
+/// before applying further events.
+#[derive(Debug, Clone)]
+pub enum SubscriptionEvent {
+    Event(CollaborationEvent),
+    Resync { missed: u64 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionClosed {
+    /// The manager shut down and no more events will arrive
+    Closed,
+    /// The subscriber lagged by more than its `max_lag` and was disconnected
+    SlowConsumer { missed: u64 },
+}
+
+impl std::fmt::Display for SubscriptionClosed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubscriptionClosed::Closed => write!(f, "event stream closed"),
+            SubscriptionClosed::SlowConsumer { missed } => {
+                write!(f, "disconnected as a slow consumer after missing {} events", missed)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SubscriptionClosed {}
+
+/// Broadcast receiver that turns lag into an explicit resync signal instead
+/// of silently skipping events, and counts what was dropped
+pub struct EventSubscription {
+    receiver: broadcast::Receiver<CollaborationEvent>,
+    metrics: Arc<RwLock<CollaborationMetrics>>,
+    max_lag: Option<u64>,
+    disconnected: bool,
+}
+
+impl EventSubscription {
+    pub(crate) fn new(
+        receiver: broadcast::Receiver<CollaborationEvent>,
+        metrics: Arc<RwLock<CollaborationMetrics>>,
+        max_lag: Option<u64>,
+    ) -> Self {
+        EventSubscription {
+            receiver,
+            metrics,
+            max_lag,
+            disconnected: false,
+        }
+    }
+
+    pub async fn recv(&mut self) -> Result<SubscriptionEvent, SubscriptionClosed> {
+        if self.disconnected {
+            return Err(SubscriptionClosed::Closed);
+        }
+
+        match self.receiver.recv().await {
+            Ok(event) => Ok(SubscriptionEvent::Event(event)),
+            Err(RecvError::Lagged(missed)) => {
+                if let Ok(mut metrics) = self.metrics.write() {
+                    metrics.events_dropped += missed;
+                    metrics.lagged_subscribers += 1;
+                }
+
+                if self.max_lag.map_or(false, |max_lag| missed > max_lag) {
+                    self.disconnected = true;
+                    return Err(SubscriptionClosed::SlowConsumer { missed });
+                }
+
+                Ok(SubscriptionEvent::Resync { missed })
+            }
+            Err(RecvError::Closed) => Err(SubscriptionClosed::Closed),
+        }
+    }
+}
@@ -10,6 +10,10 @@ pub mod sync;
 pub mod presence;
 pub mod conflict;
 pub mod streaming;
+pub mod events;
+
+/// Events buffered per subscriber before a slow one starts lagging
+const DEFAULT_EVENT_CAPACITY: usize = 10000;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CollaborationSession {
@@ -124,6 +128,17 @@ pub enum CollaborationEvent {
         request: AIRequest,
         timestamp: DateTime<Utc>,
     },
+    AIAssistanceCompleted {
+        participant_id: Uuid,
+        response: AIResponse,
+        timestamp: DateTime<Utc>,
+    },
+    AIAssistanceFailed {
+        participant_id: Uuid,
+        request_id: Uuid,
+        error: String,
+        timestamp: DateTime<Utc>,
+    },
     FileOperation {
         participant_id: Uuid,
         operation: FileOperation,
@@ -192,6 +207,29 @@ pub enum AIRequestType {
     Custom(String),
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AIResponse {
+    /// Id of the `AIRequest` this answers
+    pub request_id: Uuid,
+    pub request_type: AIRequestType,
+    pub content: String,
+    pub metadata: HashMap<String, String>,
+}
+
+/// Where an AI request came from, passed to the handler alongside it
+#[derive(Debug, Clone)]
+pub struct AIRequestContext {
+    pub session_id: Uuid,
+    pub participant_id: Uuid,
+}
+
+/// Produces responses for `RequestAIAssistance` commands. Every
+/// `AIRequestType` is routed here; the handler decides how to serve each.
+#[async_trait::async_trait]
+pub trait AiAssistanceHandler: Send + Sync {
+    async fn handle(&self, request: &AIRequest, context: &AIRequestContext) -> Result<AIResponse, String>;
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum FileOperation {
     Create { path: String, content: String },
@@ -209,11 +247,13 @@ pub struct CollaborationManager {
     presence_tracker: Arc<presence::PresenceTracker>,
     sync_engine: Arc<sync::SyncEngine>,
     metrics: Arc<RwLock<CollaborationMetrics>>,
+    ai_handler: Arc<TokioRwLock<Option<Arc<dyn AiAssistanceHandler>>>>,
 }
 
 #[derive(Debug)]
 pub enum CollaborationCommand {
     CreateSession {
+        session_id: Uuid,
         name: String,
         creator_id: String,
         permissions: SessionPermissions,
@@ -246,6 +286,11 @@ pub enum CollaborationCommand {
         request: AIRequest,
         participant_id: Uuid,
     },
+    SubmitFileOperation {
+        session_id: Uuid,
+        operation: FileOperation,
+        participant_id: Uuid,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -258,11 +303,20 @@ pub struct CollaborationMetrics {
     pub ai_requests_processed: u64,
     pub average_session_duration_seconds: f64,
     pub peak_concurrent_participants: u64,
+    /// Events skipped by subscribers that fell behind the broadcast buffer
+    pub events_dropped: u64,
+    /// Times a subscriber lagged and was sent a resync signal
+    pub lagged_subscribers: u64,
 }
 
 impl CollaborationManager {
     pub async fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        let (event_tx, _event_rx) = broadcast::channel(10000);
+        Self::with_event_capacity(DEFAULT_EVENT_CAPACITY).await
+    }
+
+    /// Manager whose event broadcast buffers `capacity` events per subscriber
+    pub async fn with_event_capacity(capacity: usize) -> Result<Self, Box<dyn std::error::Error>> {
+        let (event_tx, _event_rx) = broadcast::channel(capacity);
         let (cmd_tx, mut cmd_rx) = mpsc::channel(1000);
 
         let sessions = Arc::new(TokioRwLock::new(HashMap::new()));
@@ -281,8 +335,12 @@ impl CollaborationManager {
             ai_requests_processed: 0,
             average_session_duration_seconds: 0.0,
             peak_concurrent_participants: 0,
+            events_dropped: 0,
+            lagged_subscribers: 0,
         }));
 
+        let ai_handler: Arc<TokioRwLock<Option<Arc<dyn AiAssistanceHandler>>>> = Arc::new(TokioRwLock::new(None));
+
         let manager = CollaborationManager {
             sessions: sessions.clone(),
             participant_sessions: participant_sessions.clone(),
@@ -292,6 +350,7 @@ impl CollaborationManager {
             presence_tracker: presence_tracker.clone(),
             sync_engine: sync_engine.clone(),
             metrics: metrics.clone(),
+            ai_handler: ai_handler.clone(),
         };
 
         // Start command processor
@@ -299,13 +358,14 @@ impl CollaborationManager {
         let participant_sessions_clone = participant_sessions.clone();
         let event_tx_clone = event_tx.clone();
         let metrics_clone = metrics.clone();
+        let ai_handler_clone = ai_handler.clone();
 
         tokio::spawn(async move {
             while let Some(cmd) = cmd_rx.recv().await {
                 match cmd {
-                    CollaborationCommand::CreateSession { name, creator_id, permissions } => {
+                    CollaborationCommand::CreateSession { session_id, name, creator_id, permissions } => {
                         let session = CollaborationSession {
-                            id: Uuid::new_v4(),
+                            id: session_id,
                             name,
                             created_at: Utc::now(),
                             participants: Vec::new(),
@@ -314,7 +374,6 @@ impl CollaborationManager {
                             metadata: HashMap::new(),
                         };
 
-                        let session_id = session.id;
                         sessions_clone.write().await.insert(session_id, session);
 
                         // Update metrics
@@ -423,16 +482,52 @@ impl CollaborationManager {
                         // Broadcast AI request event
                         let _ = event_tx_clone.send(CollaborationEvent::AIAssistanceRequested {
                             participant_id,
-                            request,
+                            request: request.clone(),
                             timestamp: Utc::now(),
                         });
 
+                        // Run the handler off the command loop and broadcast its answer
+                        if let Some(handler) = ai_handler_clone.read().await.clone() {
+                            let event_tx = event_tx_clone.clone();
+                            tokio::spawn(async move {
+                                let context = AIRequestContext { session_id, participant_id };
+                                let event = match handler.handle(&request, &context).await {
+                                    Ok(response) => CollaborationEvent::AIAssistanceCompleted {
+                                        participant_id,
+                                        response,
+                                        timestamp: Utc::now(),
+                                    },
+                                    Err(error) => CollaborationEvent::AIAssistanceFailed {
+                                        participant_id,
+                                        request_id: request.id,
+                                        error,
+                                        timestamp: Utc::now(),
+                                    },
+                                };
+                                let _ = event_tx.send(event);
+                            });
+                        }
+
                         // Update metrics
                         if let Ok(mut metrics) = metrics_clone.write() {
                             metrics.ai_requests_processed += 1;
                             metrics.events_processed += 1;
                         }
                     }
+
+                    CollaborationCommand::SubmitFileOperation { session_id, operation, participant_id } => {
+                        // Already validated and applied by submit_file_operation
+                        let _ = event_tx_clone.send(CollaborationEvent::FileOperation {
+                            participant_id,
+                            operation,
+                            timestamp: Utc::now(),
+                        });
+
+                        // Update metrics
+                        if let Ok(mut metrics) = metrics_clone.write() {
+                            metrics.events_processed += 1;
+                        }
+                    }
                 }
             }
         });
@@ -449,6 +544,7 @@ impl CollaborationManager {
         let session_id = Uuid::new_v4();
         
         self.command_sender.send(CollaborationCommand::CreateSession {
+            session_id,
             name,
             creator_id,
             permissions,
@@ -548,10 +644,60 @@ impl CollaborationManager {
         Ok(())
     }
 
+    /// Validate a file operation against the participant's role and the
+    /// files the sync engine knows about, apply it, then broadcast it
+    pub async fn submit_file_operation(
+        &self,
+        session_id: Uuid,
+        operation: FileOperation,
+        participant_id: Uuid,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let role = {
+            let sessions = self.sessions.read().await;
+            let session = sessions.get(&session_id).ok_or("Session not found")?;
+            session.participants.iter()
+                .find(|p| p.id == participant_id)
+                .map(|p| p.role.clone())
+                .ok_or("Participant is not in this session")?
+        };
+
+        if !role.can_edit() {
+            return Err(format!("{:?} participants cannot modify files", role).into());
+        }
+
+        self.sync_engine.apply_file_operation(&operation).await?;
+
+        self.command_sender.send(CollaborationCommand::SubmitFileOperation {
+            session_id,
+            operation,
+            participant_id,
+        }).await?;
+
+        Ok(())
+    }
+
+    /// Register the handler that answers AI assistance requests, replacing
+    /// any previous one
+    pub async fn set_ai_handler(&self, handler: Arc<dyn AiAssistanceHandler>) {
+        *self.ai_handler.write().await = Some(handler);
+    }
+
     pub fn subscribe_to_events(&self) -> broadcast::Receiver<CollaborationEvent> {
         self.event_broadcaster.subscribe()
     }
 
+    /// Subscribe with lag detection: a subscriber that falls behind gets a
+    /// `SubscriptionEvent::Resync` instead of silently missing events
+    pub fn subscribe(&self) -> events::EventSubscription {
+        events::EventSubscription::new(self.event_broadcaster.subscribe(), self.metrics.clone(), None)
+    }
+
+    /// Like `subscribe`, but disconnects the subscriber once it has missed
+    /// more than `max_lag` events in one go
+    pub fn subscribe_with_max_lag(&self, max_lag: u64) -> events::EventSubscription {
+        events::EventSubscription::new(self.event_broadcaster.subscribe(), self.metrics.clone(), Some(max_lag))
+    }
+
     pub async fn get_session(&self, session_id: Uuid) -> Option<CollaborationSession> {
         self.sessions.read().await.get(&session_id).cloned()
     }
@@ -683,4 +829,181 @@ mod tests {
         let session = manager.get_session(session_id).await;
         assert!(session.is_none());
     }
-}
\ No newline at end of file
+
+    fn chat(content: &str) -> ChatMessage {
+        ChatMessage {
+            id: Uuid::new_v4(),
+            content: content.to_string(),
+            reply_to: None,
+            mentions: Vec::new(),
+            attachments: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_gets_resync_signal() {
+        let manager = CollaborationManager::with_event_capacity(4).await.unwrap();
+        let mut slow = manager.subscribe();
+        let mut disconnected = manager.subscribe_with_max_lag(2);
+
+        let (session_id, participant_id) = (Uuid::new_v4(), Uuid::new_v4());
+        for i in 0..10 {
+            manager.send_message(session_id, chat(&i.to_string()), participant_id).await.unwrap();
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        // The first six messages fell out of the buffer
+        match slow.recv().await.unwrap() {
+            events::SubscriptionEvent::Resync { missed } => assert_eq!(missed, 6),
+            other => panic!("expected resync, got {:?}", other),
+        }
+        // After the resync the remaining events arrive in order
+        for expected in 6..10 {
+            match slow.recv().await.unwrap() {
+                events::SubscriptionEvent::Event(CollaborationEvent::ChatMessage { message, .. }) => {
+                    assert_eq!(message.content, expected.to_string());
+                }
+                other => panic!("expected chat message, got {:?}", other),
+            }
+        }
+
+        assert_eq!(
+            disconnected.recv().await.unwrap_err(),
+            events::SubscriptionClosed::SlowConsumer { missed: 6 }
+        );
+        assert_eq!(disconnected.recv().await.unwrap_err(), events::SubscriptionClosed::Closed);
+
+        let metrics = manager.get_metrics();
+        assert_eq!(metrics.events_dropped, 12);
+        assert_eq!(metrics.lagged_subscribers, 2);
+    }
+
+    struct EchoHandler;
+
+    #[async_trait::async_trait]
+    impl AiAssistanceHandler for EchoHandler {
+        async fn handle(&self, request: &AIRequest, context: &AIRequestContext) -> Result<AIResponse, String> {
+            match request.request_type {
+                AIRequestType::Explanation => Ok(AIResponse {
+                    request_id: request.id,
+                    request_type: request.request_type.clone(),
+                    content: format!("explained: {}", request.context),
+                    metadata: HashMap::from([("session".to_string(), context.session_id.to_string())]),
+                }),
+                _ => Err("unsupported".to_string()),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ai_handler_response_reaches_subscribers() {
+        let manager = CollaborationManager::new().await.unwrap();
+        manager.set_ai_handler(Arc::new(EchoHandler)).await;
+        let mut events = manager.subscribe_to_events();
+
+        let (session_id, participant_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let request = AIRequest {
+            id: Uuid::new_v4(),
+            request_type: AIRequestType::Explanation,
+            context: "fn main() {}".to_string(),
+            parameters: HashMap::new(),
+        };
+        manager.request_ai_assistance(session_id, request.clone(), participant_id).await.unwrap();
+
+        let wait = tokio::time::Duration::from_secs(1);
+        let requested = tokio::time::timeout(wait, events.recv()).await.unwrap().unwrap();
+        assert!(matches!(requested, CollaborationEvent::AIAssistanceRequested { .. }));
+
+        match tokio::time::timeout(wait, events.recv()).await.unwrap().unwrap() {
+            CollaborationEvent::AIAssistanceCompleted { participant_id: from, response, .. } => {
+                assert_eq!(from, participant_id);
+                assert_eq!(response.request_id, request.id);
+                assert_eq!(response.content, "explained: fn main() {}");
+                assert_eq!(response.metadata["session"], session_id.to_string());
+            }
+            other => panic!("expected completion, got {:?}", other),
+        }
+
+        let unsupported = AIRequest { id: Uuid::new_v4(), request_type: AIRequestType::BugFix, ..request };
+        manager.request_ai_assistance(session_id, unsupported.clone(), participant_id).await.unwrap();
+        let _requested = tokio::time::timeout(wait, events.recv()).await.unwrap().unwrap();
+        match tokio::time::timeout(wait, events.recv()).await.unwrap().unwrap() {
+            CollaborationEvent::AIAssistanceFailed { request_id, error, .. } => {
+                assert_eq!(request_id, unsupported.id);
+                assert_eq!(error, "unsupported");
+            }
+            other => panic!("expected failure, got {:?}", other),
+        }
+    }
+
+    fn participant(role: ParticipantRole) -> Participant {
+        Participant {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4().to_string(),
+            display_name: format!("{:?}", role),
+            role,
+            status: PresenceStatus::Online,
+            cursor_position: None,
+            selection: None,
+            joined_at: Utc::now(),
+            last_activity: Utc::now(),
+        }
+    }
+
+    async fn session_with(manager: &CollaborationManager, participants: &[Participant]) -> Uuid {
+        let permissions = SessionPermissions {
+            allow_guests: true,
+            require_approval: false,
+            max_participants: None,
+            allowed_actions: HashSet::new(),
+            recording_enabled: false,
+            ai_assistance_enabled: true,
+        };
+        let session_id = manager.create_session("Files".to_string(), "owner".to_string(), permissions).await.unwrap();
+        for participant in participants {
+            manager.join_session(session_id, participant.clone()).await.unwrap();
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        session_id
+    }
+
+    #[tokio::test]
+    async fn test_submit_file_create() {
+        let manager = CollaborationManager::new().await.unwrap();
+        let editor = participant(ParticipantRole::Editor);
+        let session_id = session_with(&manager, &[editor.clone()]).await;
+        let mut events = manager.subscribe_to_events();
+
+        let create = FileOperation::Create { path: "src/main.rs".to_string(), content: String::new() };
+        manager.submit_file_operation(session_id, create, editor.id).await.unwrap();
+
+        let event = tokio::time::timeout(tokio::time::Duration::from_secs(1), events.recv()).await.unwrap().unwrap();
+        match event {
+            CollaborationEvent::FileOperation { participant_id, operation: FileOperation::Create { path, .. }, .. } => {
+                assert_eq!(participant_id, editor.id);
+                assert_eq!(path, "src/main.rs");
+            }
+            other => panic!("expected file operation, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_file_operation_rejections() {
+        let manager = CollaborationManager::new().await.unwrap();
+        let editor = participant(ParticipantRole::Editor);
+        let viewer = participant(ParticipantRole::Viewer);
+        let session_id = session_with(&manager, &[editor.clone(), viewer.clone()]).await;
+
+        for path in ["a.rs", "b.rs"] {
+            let create = FileOperation::Create { path: path.to_string(), content: String::new() };
+            manager.submit_file_operation(session_id, create, editor.id).await.unwrap();
+        }
+
+        let collision = FileOperation::Rename { old_path: "a.rs".to_string(), new_path: "b.rs".to_string() };
+        let error = manager.submit_file_operation(session_id, collision, editor.id).await.unwrap_err();
+        assert!(error.to_string().contains("already exists"));
+
+        let create = FileOperation::Create { path: "c.rs".to_string(), content: String::new() };
+        assert!(manager.submit_file_operation(session_id, create, viewer.id).await.is_err());
+    }
+}
@@ -393,6 +393,61 @@ impl SyncEngine {
         self.document_states.read().await.get(file_path).cloned()
     }
 
+    /// Validate a file operation against the known files and apply it:
+    /// creating an existing path, or touching or overwriting a path that
+    /// shouldn't be, is rejected without changing any state
+    pub async fn apply_file_operation(
+        &self,
+        operation: &FileOperation,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut states = self.document_states.write().await;
+        
+        match operation {
+            FileOperation::Create { path, content } => {
+                if states.contains_key(path) {
+                    return Err(format!("Cannot create {}: file already exists", path).into());
+                }
+                
+                states.insert(path.clone(), DocumentState {
+                    file_path: path.clone(),
+                    content: content.clone(),
+                    version: 0,
+                    checksum: self.calculate_checksum(content),
+                    last_modified: Utc::now(),
+                    active_editors: HashSet::new(),
+                });
+            }
+            FileOperation::Delete { path } => {
+                if states.remove(path).is_none() {
+                    return Err(format!("Cannot delete {}: no such file", path).into());
+                }
+                self.operation_history.write().await.remove(path);
+            }
+            FileOperation::Rename { old_path: from, new_path: to }
+            | FileOperation::Move { from, to } => {
+                if !states.contains_key(from) {
+                    return Err(format!("Cannot move {}: no such file", from).into());
+                }
+                if states.contains_key(to) {
+                    return Err(format!("Cannot move {} to {}: target already exists", from, to).into());
+                }
+                
+                if let Some(mut state) = states.remove(from) {
+                    state.file_path = to.clone();
+                    state.last_modified = Utc::now();
+                    states.insert(to.clone(), state);
+                }
+                
+                let mut histories = self.operation_history.write().await;
+                if let Some(history) = histories.remove(from) {
+                    histories.insert(to.clone(), history);
+                }
+            }
+        }
+        
+        Ok(())
+    }
+
     pub async fn resolve_conflict(
         &self,
         file_path: &str,
@@ -576,4 +631,27 @@ mod tests {
         let transformed = engine.transform_against(&op1, &op2).unwrap();
         assert_eq!(transformed.position, 10); // Adjusted for earlier insert
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_file_operations_are_validated() {
+        let engine = SyncEngine::new().await.unwrap();
+        let create = |path: &str| FileOperation::Create { path: path.to_string(), content: "fn main() {}".to_string() };
+
+        engine.apply_file_operation(&create("src/main.rs")).await.unwrap();
+        assert_eq!(engine.get_document_state("src/main.rs").await.unwrap().content, "fn main() {}");
+        assert!(engine.apply_file_operation(&create("src/main.rs")).await.is_err());
+
+        engine.apply_file_operation(&create("src/lib.rs")).await.unwrap();
+        let collision = FileOperation::Rename { old_path: "src/main.rs".to_string(), new_path: "src/lib.rs".to_string() };
+        assert!(engine.apply_file_operation(&collision).await.is_err());
+        assert!(engine.get_document_state("src/main.rs").await.is_some());
+
+        let missing = FileOperation::Delete { path: "src/missing.rs".to_string() };
+        assert!(engine.apply_file_operation(&missing).await.is_err());
+
+        let rename = FileOperation::Move { from: "src/main.rs".to_string(), to: "src/bin/app.rs".to_string() };
+        engine.apply_file_operation(&rename).await.unwrap();
+        assert!(engine.get_document_state("src/main.rs").await.is_none());
+        assert_eq!(engine.get_document_state("src/bin/app.rs").await.unwrap().file_path, "src/bin/app.rs");
+    }
+}
@@ -3,6 +3,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::{RwLock, Mutex, Semaphore};
 use tracing::{info, warn, error, debug};
@@ -25,6 +26,45 @@ pub struct FederatedLearningConfig {
     pub training_parameters: TrainingParameters,
     pub security_parameters: SecurityParameters,
     pub performance_targets: PerformanceTargets,
+    /// Write a checkpoint automatically as rounds complete
+    #[serde(default)]
+    pub checkpoint_policy: Option<CheckpointPolicy>,
+    /// What to do when a round's aggregated update fails consensus
+    #[serde(default)]
+    pub consensus_failure_policy: ConsensusFailurePolicy,
+}
+
+impl FederatedLearningConfig {
+    /// The proximal term clients must train with, only under FedProx
+    pub fn proximal_mu(&self) -> Option<f32> {
+        match self.learning_algorithm {
+            FederatedAlgorithm::FedProx => Some(self.training_parameters.proximal_mu),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointPolicy {
+    pub path: PathBuf,
+    /// Checkpoint after every `every_n_rounds` completed rounds
+    pub every_n_rounds: usize,
+}
+
+/// Under any `ConsensusMechanism` but `None`, a round whose aggregated update
+/// fails consensus is rolled back without touching the global model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConsensusFailurePolicy {
+    /// Start a fresh round, giving up after `max_attempts` failures in a row
+    Retry { max_attempts: usize },
+    /// Stop training at the first failure
+    Abort,
+}
+
+impl Default for ConsensusFailurePolicy {
+    fn default() -> Self {
+        ConsensusFailurePolicy::Retry { max_attempts: 3 }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -185,6 +225,22 @@ pub struct TrainingParameters {
     pub min_clients: usize,
     pub max_clients: usize,
     pub convergence_threshold: f32,
+    /// FedProx proximal coefficient μ: local objectives add
+    /// `μ/2 * ||w - w_global||²` to stay near the global model
+    #[serde(default = "default_proximal_mu")]
+    pub proximal_mu: f32,
+    /// How long selected clients have to report before the round is closed
+    /// with whatever updates arrived
+    #[serde(default = "default_round_timeout_ms")]
+    pub round_timeout_ms: u64,
+}
+
+fn default_proximal_mu() -> f32 {
+    0.01
+}
+
+fn default_round_timeout_ms() -> u64 {
+    300_000
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -225,6 +281,13 @@ pub struct SecurityParameters {
     pub backdoor_detection: bool,
     pub model_inversion_protection: bool,
     pub membership_inference_protection: bool,
+    /// Robust z-score beyond which an update is treated as poisoned
+    #[serde(default = "default_poisoning_z_threshold")]
+    pub poisoning_z_threshold: f32,
+}
+
+fn default_poisoning_z_threshold() -> f32 {
+    3.5
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -360,6 +423,10 @@ pub struct FederatedModelUpdate {
     pub privacy_audit: PrivacyAudit,
     pub quality_metrics: QualityMetrics,
     pub timestamp: DateTime<Utc>,
+    /// Under `AggregationStrategy::SecureAggregation` the real weights travel
+    /// only in masked form and `model_weights` is left empty
+    #[serde(default)]
+    pub masked_weights: Option<MaskedWeights>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -412,6 +479,7 @@ pub enum CompressionType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GradientUpdates {
     pub gradients: Vec<Array2<f32>>,
+    /// Per-layer norms measured before clipping
     pub gradient_norms: Vec<f32>,
     pub clipped_gradients: bool,
     pub clipping_threshold: f32,
@@ -426,6 +494,9 @@ pub struct TrainingMetadata {
     pub learning_rate: f32,
     pub loss_values: Vec<f32>,
     pub accuracy_values: Vec<f32>,
+    /// FedProx μ the client trained with, echoed from its training config
+    #[serde(default)]
+    pub proximal_mu: Option<f32>,
     pub convergence_metrics: ConvergenceMetrics,
     pub computational_cost: ComputationalCost,
     pub data_statistics: LocalDataStatistics,
@@ -478,6 +549,9 @@ pub struct PrivacyAudit {
     pub t_closeness_satisfied: bool,
     pub privacy_risk_score: f32,
     pub potential_privacy_violations: Vec<String>,
+    /// Adaptive clip bound in force when the update was audited
+    #[serde(default)]
+    pub clipping_bound: Option<f32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -523,20 +597,43 @@ pub struct FederatedLearningOrchestrator {
     config: FederatedLearningConfig,
     participants: Arc<RwLock<HashMap<String, FederatedParticipant>>>,
     global_model: Arc<RwLock<GlobalModel>>,
-    aggregator: Arc<RwLock<dyn ModelAggregator + Send + Sync>>,
+    aggregator: Arc<RwLock<Box<dyn ModelAggregator + Send + Sync>>>,
     privacy_engine: Arc<RwLock<PrivacyEngine>>,
     communication_manager: Arc<RwLock<CommunicationManager>>,
-    consensus_engine: Arc<RwLock<ConsensusEngine>>,
+    consensus_engine: Arc<RwLock<Box<dyn ConsensusValidator>>>,
+    /// Rounds in a row whose update failed consensus
+    consensus_failures: Arc<RwLock<usize>>,
     security_manager: Arc<RwLock<FederatedSecurityManager>>,
     performance_monitor: Arc<RwLock<FederatedPerformanceMonitor>>,
     round_history: Arc<RwLock<VecDeque<TrainingRound>>>,
+    /// Id of the most recently completed round, which survives a resume
+    /// even though `round_history` does not
+    last_round_id: Arc<RwLock<usize>>,
     active_round: Arc<RwLock<Option<TrainingRound>>>,
     client_selector: Arc<RwLock<ClientSelector>>,
     model_validator: Arc<RwLock<ModelValidator>>,
     incentive_mechanism: Arc<RwLock<IncentiveMechanism>>,
+    /// Held-out data the global model is evaluated on after each round
+    validation_set: Option<ValidationSet>,
+    /// Set once training has met its target or stalled; no new rounds start
+    stop_reason: Arc<RwLock<Option<StopReason>>>,
 }
 
 impl FederatedLearningOrchestrator {
+    /// An orchestrator that evaluates the global model on `validation_set`
+    /// after every round, stopping at `target_accuracy` or when accuracy
+    /// improves by less than `convergence_threshold`. Validation loss also
+    /// becomes the utility for scoring client contributions
+    pub async fn with_validation_set(config: FederatedLearningConfig, validation_set: ValidationSet) -> Result<Self> {
+        let mut orchestrator = Self::new(config).await?;
+        let loss_set = validation_set.clone();
+        orchestrator.incentive_mechanism.write().await.utility = Some(Arc::new(move |weights: &ModelWeights| {
+            loss_set.evaluate(weights).map_or(f32::NEG_INFINITY, |metrics| -metrics.loss)
+        }));
+        orchestrator.validation_set = Some(validation_set);
+        Ok(orchestrator)
+    }
+
     pub async fn new(config: FederatedLearningConfig) -> Result<Self> {
         info!("Initializing Federated Learning Orchestrator for federation: {}", config.federation_id);
 
@@ -547,7 +644,7 @@ impl FederatedLearningOrchestrator {
         let security_manager = FederatedSecurityManager::new(&config.security_parameters).await?;
         let performance_monitor = FederatedPerformanceMonitor::new(&config.performance_targets).await?;
         let client_selector = ClientSelector::new(&config.training_parameters).await?;
-        let model_validator = ModelValidator::new(&config.model_architecture).await?;
+        let model_validator = ModelValidator::new(&config.model_architecture, config.proximal_mu()).await?;
         let incentive_mechanism = IncentiveMechanism::new().await?;
 
         let global_model = GlobalModel::new(&config.model_architecture).await?;
@@ -559,14 +656,18 @@ impl FederatedLearningOrchestrator {
             aggregator: Arc::new(RwLock::new(aggregator)),
             privacy_engine: Arc::new(RwLock::new(privacy_engine)),
             communication_manager: Arc::new(RwLock::new(communication_manager)),
-            consensus_engine: Arc::new(RwLock::new(consensus_engine)),
+            consensus_engine: Arc::new(RwLock::new(Box::new(consensus_engine))),
+            consensus_failures: Arc::new(RwLock::new(0)),
             security_manager: Arc::new(RwLock::new(security_manager)),
             performance_monitor: Arc::new(RwLock::new(performance_monitor)),
             round_history: Arc::new(RwLock::new(VecDeque::with_capacity(1000))),
+            last_round_id: Arc::new(RwLock::new(0)),
             active_round: Arc::new(RwLock::new(None)),
             client_selector: Arc::new(RwLock::new(client_selector)),
             model_validator: Arc::new(RwLock::new(model_validator)),
             incentive_mechanism: Arc::new(RwLock::new(incentive_mechanism)),
+            validation_set: None,
+            stop_reason: Arc::new(RwLock::new(None)),
         })
     }
 
@@ -596,6 +697,10 @@ impl FederatedLearningOrchestrator {
     pub async fn start_training_round(&self) -> Result<TrainingRound> {
         info!("Starting new federated learning round");
 
+        if let Some(reason) = self.stop_reason.read().await.as_ref() {
+            return Err(anyhow!("Training has stopped: {:?}", reason));
+        }
+
         // Check if a round is already active
         {
             let active_round = self.active_round.read().await;
@@ -605,9 +710,11 @@ impl FederatedLearningOrchestrator {
         }
 
         // Select participants for this round
-        let client_selector = self.client_selector.read().await;
-        let participants = self.participants.read().await;
-        let selected_clients = client_selector.select_clients(&*participants, &self.config.training_parameters).await?;
+        let selected_clients = {
+            let client_selector = self.client_selector.read().await;
+            let participants = self.participants.read().await;
+            client_selector.select_clients(&participants, &self.config.training_parameters).await?
+        };
 
         if selected_clients.len() < self.config.training_parameters.min_clients {
             return Err(anyhow!("Insufficient clients available for training round"));
@@ -615,11 +722,13 @@ impl FederatedLearningOrchestrator {
 
         // Create new training round
         let round_id = self.get_next_round_id().await;
+        let start_time = Utc::now();
         let mut training_round = TrainingRound {
             round_id,
             federation_id: self.config.federation_id.clone(),
-            start_time: Utc::now(),
+            start_time,
             end_time: None,
+            deadline: start_time + chrono::Duration::milliseconds(self.config.training_parameters.round_timeout_ms as i64),
             selected_clients,
             client_updates: HashMap::new(),
             aggregated_update: None,
@@ -628,6 +737,7 @@ impl FederatedLearningOrchestrator {
             privacy_budget_consumed: 0.0,
             consensus_achieved: false,
             round_status: RoundStatus::InProgress,
+            flagged_updates: Vec::new(),
         };
 
         // Distribute global model to selected clients
@@ -655,28 +765,25 @@ impl FederatedLearningOrchestrator {
         Ok(training_round)
     }
 
-    pub async fn receive_client_update(&self, update: FederatedModelUpdate) -> Result<()> {
+    pub async fn receive_client_update(&self, mut update: FederatedModelUpdate) -> Result<()> {
         info!("Received model update from client: {}", update.client_id);
 
         // Validate the update
-        let model_validator = self.model_validator.read().await;
-        model_validator.validate_update(&update).await?;
+        self.model_validator.read().await.validate_update(&update).await?;
 
         // Security checks
-        let security_manager = self.security_manager.read().await;
-        security_manager.validate_update_security(&update).await?;
+        self.security_manager.read().await.validate_update_security(&update).await?;
 
-        // Privacy audit
-        let privacy_engine = self.privacy_engine.read().await;
-        let privacy_audit = privacy_engine.audit_update(&update).await?;
+        // Privacy audit; the stored update carries the server's audit
+        update.privacy_audit = self.privacy_engine.read().await.audit_update(&update).await?;
 
         // Store the update
         {
             let mut active_round = self.active_round.write().await;
             if let Some(ref mut round) = *active_round {
                 if round.round_id == update.round_id {
+                    round.privacy_budget_consumed += update.privacy_audit.privacy_budget_consumed;
                     round.client_updates.insert(update.client_id.clone(), update.clone());
-                    round.privacy_budget_consumed += privacy_audit.privacy_budget_consumed;
                 } else {
                     return Err(anyhow!("Update received for inactive round: {}", update.round_id));
                 }
@@ -685,11 +792,12 @@ impl FederatedLearningOrchestrator {
             }
         }
 
-        // Check if we have enough updates to proceed with aggregation
+        // Aggregate once every selected client has reported; stragglers are
+        // handled by `enforce_round_deadline`
         let should_aggregate = {
             let active_round = self.active_round.read().await;
             if let Some(ref round) = *active_round {
-                round.client_updates.len() >= self.config.training_parameters.min_clients
+                round.selected_clients.iter().all(|client_id| round.client_updates.contains_key(client_id))
             } else {
                 false
             }
@@ -719,26 +827,58 @@ impl FederatedLearningOrchestrator {
             return Err(anyhow!("No client updates available for aggregation"));
         }
 
+        // Screen out poisoned updates and penalize their senders
+        let flagged_updates = self.security_manager.read().await.detect_poisoned_updates(&updates).await;
+        let updates = if flagged_updates.is_empty() {
+            updates
+        } else {
+            let mut participants = self.participants.write().await;
+            for flagged in &flagged_updates {
+                warn!("Excluding update from {} as likely poisoned: {:?}", flagged.client_id, flagged.signals);
+                if let Some(participant) = participants.get_mut(&flagged.client_id) {
+                    participant.trust_score *= POISONED_UPDATE_TRUST_PENALTY;
+                    participant.reputation *= POISONED_UPDATE_TRUST_PENALTY;
+                }
+            }
+            updates
+                .into_iter()
+                .filter(|update| !flagged_updates.iter().any(|flagged| flagged.client_id == update.client_id))
+                .collect::<Vec<_>>()
+        };
+        if updates.is_empty() {
+            return Err(anyhow!("Every client update was flagged as poisoned"));
+        }
+
         // Perform secure aggregation
-        let aggregator = self.aggregator.read().await;
-        let privacy_engine = self.privacy_engine.read().await;
-        
-        let aggregated_update = aggregator.aggregate_updates(&updates).await?;
-        let privacy_preserved_update = privacy_engine.apply_privacy_mechanism(&aggregated_update).await?;
+        let aggregated_update = self.aggregator.read().await.aggregate_updates(&updates).await?;
+        let privacy_preserved_update = self.privacy_engine.read().await.apply_privacy_mechanism(&aggregated_update).await?;
 
         // Consensus validation
         let consensus_engine = self.consensus_engine.read().await;
         let consensus_achieved = consensus_engine.validate_aggregated_update(&privacy_preserved_update, &updates).await?;
+        drop(consensus_engine);
+        if !matches!(self.config.consensus_mechanism, ConsensusMechanism::None) {
+            if !consensus_achieved {
+                return Err(self.reject_round(flagged_updates).await);
+            }
+            *self.consensus_failures.write().await = 0;
+        }
 
         // Update global model
-        let mut global_model = self.global_model.write().await;
-        global_model.apply_update(&privacy_preserved_update).await?;
+        let validation = {
+            let mut global_model = self.global_model.write().await;
+            global_model.apply_update(&privacy_preserved_update).await?;
+            self.evaluate_global_model(&mut global_model)?
+        };
+        let gradient_norms: Vec<f32> = updates.iter().map(reported_gradient_norm).collect();
+        self.privacy_engine.write().await.update_clip_bound(&gradient_norms);
 
         // Complete the round
         {
             let mut active_round = self.active_round.write().await;
             if let Some(ref mut round) = *active_round {
                 round.aggregated_update = Some(privacy_preserved_update.clone());
+                round.flagged_updates = flagged_updates;
                 round.consensus_achieved = consensus_achieved;
                 round.end_time = Some(Utc::now());
                 round.round_status = if consensus_achieved { 
@@ -749,6 +889,11 @@ impl FederatedLearningOrchestrator {
 
                 // Calculate round metrics
                 round.round_metrics = self.calculate_round_metrics(&round.client_updates).await?;
+                self.record_participation(round).await;
+                if let Some((metrics, _)) = &validation {
+                    round.round_metrics.validation_accuracy = Some(metrics.accuracy);
+                    round.round_metrics.validation_loss = Some(metrics.loss);
+                }
             }
         }
 
@@ -758,12 +903,14 @@ impl FederatedLearningOrchestrator {
             active_round.take()
         };
 
+        let completed_round_id = completed_round.as_ref().map(|round| round.round_id);
         if let Some(round) = completed_round {
-            let mut history = self.round_history.write().await;
-            history.push_back(round);
-            if history.len() > 1000 {
-                history.pop_front();
-            }
+            self.privacy_engine.write().await.consume_budget(round.privacy_budget_consumed);
+            self.archive_round(round).await;
+        }
+
+        if let Some((metrics, previous_accuracy)) = validation {
+            self.check_stopping_criteria(metrics.accuracy, previous_accuracy).await;
         }
 
         // Update performance metrics
@@ -771,48 +918,272 @@ impl FederatedLearningOrchestrator {
         performance_monitor.record_round_completion(&privacy_preserved_update).await?;
 
         // Distribute incentives
-        let mut incentive_mechanism = self.incentive_mechanism.write().await;
-        incentive_mechanism.distribute_incentives(&updates).await?;
+        let marginals = self.incentive_mechanism.write().await.distribute_incentives(&updates).await?;
+        self.record_contributions(&updates, &marginals).await;
+
+        if let (Some(policy), Some(round_id)) = (&self.config.checkpoint_policy, completed_round_id) {
+            if policy.every_n_rounds > 0 && round_id % policy.every_n_rounds == 0 {
+                self.checkpoint(&policy.path).await?;
+            }
+        }
 
         info!("Model aggregation completed successfully");
         Ok(privacy_preserved_update)
     }
 
+    /// Close the active round if its deadline has passed: aggregate the
+    /// updates that arrived if there are at least `min_clients`, otherwise
+    /// fail the round and start another with a fresh selection. Either way
+    /// clients that never reported are marked as dropped. Call periodically
+    pub async fn enforce_round_deadline(&self) -> Result<RoundDeadlineOutcome> {
+        self.enforce_round_deadline_at(Utc::now()).await
+    }
+
+    async fn enforce_round_deadline_at(&self, now: DateTime<Utc>) -> Result<RoundDeadlineOutcome> {
+        let reported = {
+            let active_round = self.active_round.read().await;
+            match active_round.as_ref() {
+                Some(round) if now >= round.deadline => round.client_updates.len(),
+                _ => return Ok(RoundDeadlineOutcome::Pending),
+            }
+        };
+
+        if reported >= self.config.training_parameters.min_clients {
+            warn!("Round deadline passed; aggregating {} updates", reported);
+            return Ok(RoundDeadlineOutcome::Aggregated(Box::new(self.aggregate_updates().await?)));
+        }
+
+        let Some(mut round) = self.active_round.write().await.take() else {
+            return Ok(RoundDeadlineOutcome::Pending);
+        };
+        warn!(
+            "Round {} failed: {} of {} required updates before the deadline",
+            round.round_id, reported, self.config.training_parameters.min_clients
+        );
+        round.round_status = RoundStatus::Failed;
+        round.end_time = Some(now);
+        self.record_participation(&round).await;
+        let failed_round_id = round.round_id;
+        self.archive_round(round).await;
+
+        let next_round = match self.start_training_round().await {
+            Ok(next_round) => Some(Box::new(next_round)),
+            Err(e) => {
+                warn!("Could not start a replacement for round {}: {}", failed_round_id, e);
+                None
+            }
+        };
+        Ok(RoundDeadlineOutcome::Restarted { failed_round_id, next_round })
+    }
+
+    /// Roll back a round whose update failed consensus: archive it as failed
+    /// with nothing applied, then retry or stop per the failure policy. The
+    /// update was never released, so no privacy budget is charged for it
+    async fn reject_round(&self, flagged_updates: Vec<FlaggedUpdate>) -> anyhow::Error {
+        let Some(mut round) = self.active_round.write().await.take() else {
+            return anyhow!("No active training round");
+        };
+        round.flagged_updates = flagged_updates;
+        round.consensus_achieved = false;
+        round.round_status = RoundStatus::Failed;
+        round.end_time = Some(Utc::now());
+        self.record_participation(&round).await;
+        let round_id = round.round_id;
+        self.archive_round(round).await;
+
+        let attempts = {
+            let mut failures = self.consensus_failures.write().await;
+            *failures += 1;
+            *failures
+        };
+        warn!("Round {} failed consensus ({} in a row); global model unchanged", round_id, attempts);
+
+        let retry = match self.config.consensus_failure_policy {
+            ConsensusFailurePolicy::Retry { max_attempts } => attempts < max_attempts,
+            ConsensusFailurePolicy::Abort => false,
+        };
+        if !retry {
+            let reason = StopReason::ConsensusFailed { round_id, attempts };
+            info!("Stopping federated training: {:?}", reason);
+            *self.stop_reason.write().await = Some(reason);
+        } else if let Err(e) = self.start_training_round().await {
+            warn!("Could not start a retry for round {}: {}", round_id, e);
+        }
+        anyhow!("Consensus was not reached for round {}; the update was not applied", round_id)
+    }
+
+    /// Update each selected client's participation history once a round
+    /// ends; clients that never reported count as dropped
+    async fn record_participation(&self, round: &TrainingRound) {
+        let mut participants = self.participants.write().await;
+        for client_id in &round.selected_clients {
+            let Some(participant) = participants.get_mut(client_id) else { continue };
+            let history = &mut participant.participation_history;
+            history.total_rounds_participated += 1;
+            if round.client_updates.contains_key(client_id) {
+                history.successful_rounds += 1;
+                history.last_participation = round.end_time.unwrap_or_else(Utc::now);
+            } else {
+                history.failed_rounds += 1;
+                history.reliability_score *= DROPOUT_RELIABILITY_PENALTY;
+            }
+        }
+    }
+
+    async fn archive_round(&self, round: TrainingRound) {
+        *self.last_round_id.write().await = round.round_id;
+        let mut history = self.round_history.write().await;
+        history.push_back(round);
+        if history.len() > 1000 {
+            history.pop_front();
+        }
+    }
+
+    /// Write the global model, last completed round and privacy budget to
+    /// `path`, replacing any previous checkpoint atomically. Updates received
+    /// for a round still in progress are not saved; a resumed orchestrator
+    /// starts with the next round
+    pub async fn checkpoint(&self, path: impl AsRef<Path>) -> Result<FederatedCheckpoint> {
+        let path = path.as_ref();
+        let checkpoint = {
+            let global_model = self.global_model.read().await;
+            let privacy_engine = self.privacy_engine.read().await;
+            FederatedCheckpoint {
+                config: self.config.clone(),
+                round_id: *self.last_round_id.read().await,
+                global_model_version: global_model.version,
+                global_weights: global_model.get_weights().await?,
+                performance_history: global_model.performance_history.clone(),
+                participants: self.participants.read().await.clone(),
+                privacy_budget: privacy_engine.budget.clone(),
+                clip_bound: privacy_engine.clip_bound,
+                created_at: Utc::now(),
+            }
+        };
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let temp_path = path.with_extension("tmp");
+        tokio::fs::write(&temp_path, serde_json::to_vec(&checkpoint)?).await?;
+        tokio::fs::rename(&temp_path, path).await?;
+
+        info!("Checkpointed federation {} at round {} to {}", checkpoint.config.federation_id, checkpoint.round_id, path.display());
+        Ok(checkpoint)
+    }
+
+    /// Rebuild an orchestrator from a checkpoint written by `checkpoint`
+    pub async fn resume_from(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = tokio::fs::read(path)
+            .await
+            .map_err(|e| anyhow!("Failed to read checkpoint {}: {}", path.display(), e))?;
+        let checkpoint: FederatedCheckpoint = serde_json::from_slice(&content)?;
+
+        let orchestrator = Self::new(checkpoint.config).await?;
+        {
+            let mut global_model = orchestrator.global_model.write().await;
+            global_model.version = checkpoint.global_model_version;
+            global_model.weights = checkpoint.global_weights;
+            global_model.performance_history = checkpoint.performance_history;
+        }
+        *orchestrator.participants.write().await = checkpoint.participants;
+        *orchestrator.last_round_id.write().await = checkpoint.round_id;
+        {
+            let mut privacy_engine = orchestrator.privacy_engine.write().await;
+            privacy_engine.budget = checkpoint.privacy_budget;
+            if checkpoint.clip_bound.is_some() {
+                privacy_engine.clip_bound = checkpoint.clip_bound;
+            }
+        }
+
+        info!("Resumed federation {} after round {}", orchestrator.config.federation_id, checkpoint.round_id);
+        Ok(orchestrator)
+    }
+
+    /// Score candidate models with `utility` (e.g. validation accuracy) when
+    /// computing client contributions
+    /// Replace the consensus check run on each aggregated update
+    pub async fn set_consensus_validator(&self, validator: Box<dyn ConsensusValidator>) {
+        *self.consensus_engine.write().await = validator;
+    }
+
+    pub async fn set_contribution_utility(&self, utility: ModelUtility) {
+        self.incentive_mechanism.write().await.utility = Some(utility);
+    }
+
+    pub async fn get_contribution_scores(&self) -> HashMap<String, ContributionMetrics> {
+        self.participants
+            .read()
+            .await
+            .iter()
+            .map(|(client_id, participant)| (client_id.clone(), participant.contribution_metrics.clone()))
+            .collect()
+    }
+
+    /// Total reward credited to `client_id` so far
+    pub async fn get_rewards(&self, client_id: &str) -> f64 {
+        self.incentive_mechanism.read().await.rewards.get(client_id).copied().unwrap_or(0.0)
+    }
+
+    /// Snapshot of training progress for dashboards. The active round and
+    /// round history are read together, always in that order (aggregation
+    /// never holds both), so a round is never seen both active and finished;
+    /// every other lock is taken on its own and released straight away
     pub async fn get_federation_status(&self) -> Result<FederationStatus> {
         info!("Retrieving federation status");
 
-        let participants = self.participants.read().await;
-        let performance_monitor = self.performance_monitor.read().await;
-        let global_model = self.global_model.read().await;
+        let (current_round, round_clients, updates_received, round_status, total_rounds_completed, latest_validation) = {
+            let active_round = self.active_round.read().await;
+            let history = self.round_history.read().await;
+            let latest_validation = history.iter().rev().find_map(|round| {
+                let metrics = &round.round_metrics;
+                Some(ValidationMetrics { accuracy: metrics.validation_accuracy?, loss: metrics.validation_loss? })
+            });
+            (
+                active_round.as_ref().map(|r| r.round_id),
+                active_round.as_ref().map_or(0, |r| r.selected_clients.len()),
+                active_round.as_ref().map_or(0, |r| r.client_updates.len()),
+                active_round.as_ref().or(history.back()).map(|r| r.round_status.clone()),
+                history.len(),
+                latest_validation,
+            )
+        };
+
+        let (total_participants, active_participants) = {
+            let participants = self.participants.read().await;
+            (participants.len(), participants.values().filter(|p| p.reputation > 0.5).count())
+        };
+        let global_model_accuracy = self.global_model.read().await.get_accuracy().await?;
+        let (average_round_duration_ms, consensus_rate) = {
+            let performance_monitor = self.performance_monitor.read().await;
+            (performance_monitor.get_average_round_duration().await?, performance_monitor.get_consensus_rate().await?)
+        };
 
-        let status = FederationStatus {
+        Ok(FederationStatus {
             federation_id: self.config.federation_id.clone(),
-            total_participants: participants.len(),
-            active_participants: participants.values().filter(|p| p.reputation > 0.5).count(),
-            current_round: {
-                let active_round = self.active_round.read().await;
-                active_round.as_ref().map(|r| r.round_id)
-            },
-            global_model_accuracy: global_model.get_accuracy().await?,
-            total_rounds_completed: {
-                let history = self.round_history.read().await;
-                history.len()
-            },
-            average_round_duration_ms: performance_monitor.get_average_round_duration().await?,
+            total_participants,
+            active_participants,
+            current_round,
+            round_status,
+            round_clients,
+            updates_received,
+            global_model_accuracy,
+            latest_validation,
+            total_rounds_completed,
+            stop_reason: self.stop_reason().await,
+            average_round_duration_ms,
             privacy_budget_remaining: self.calculate_privacy_budget_remaining().await?,
-            consensus_rate: performance_monitor.get_consensus_rate().await?,
+            consensus_rate,
             participant_satisfaction: self.calculate_participant_satisfaction().await?,
             federation_health_score: self.calculate_federation_health().await?,
             last_update: Utc::now(),
-        };
-
-        Ok(status)
+        })
     }
 
     // Helper methods
     async fn get_next_round_id(&self) -> usize {
-        let history = self.round_history.read().await;
-        history.len() + 1
+        *self.last_round_id.read().await + 1
     }
 
     async fn get_global_model_version(&self) -> Result<usize> {
@@ -834,9 +1205,70 @@ impl FederatedLearningOrchestrator {
             learning_rate: self.config.training_parameters.learning_rate,
             privacy_budget: participant.privacy_preferences.max_epsilon,
             timeout_ms: participant.resource_constraints.max_compute_time_ms,
+            proximal_mu: self.config.proximal_mu(),
+            clipping_threshold: self.privacy_engine.read().await.clip_bound,
         })
     }
 
+    /// Score the freshly updated global model on the validation set, recording
+    /// its accuracy in the model's history. Returns the metrics and the
+    /// accuracy before this round
+    fn evaluate_global_model(&self, global_model: &mut GlobalModel) -> Result<Option<(ValidationMetrics, Option<f32>)>> {
+        let Some(validation_set) = &self.validation_set else { return Ok(None) };
+        let metrics = validation_set.evaluate(&global_model.weights)?;
+        let previous_accuracy = global_model.performance_history.last().copied();
+        global_model.performance_history.push(metrics.accuracy);
+        info!("Global model v{} validation accuracy {:.4}, loss {:.4}", global_model.version, metrics.accuracy, metrics.loss);
+        Ok(Some((metrics, previous_accuracy)))
+    }
+
+    async fn check_stopping_criteria(&self, accuracy: f32, previous_accuracy: Option<f32>) {
+        let threshold = self.config.training_parameters.convergence_threshold;
+        let reason = if accuracy >= self.config.performance_targets.target_accuracy {
+            Some(StopReason::TargetAccuracyReached { accuracy })
+        } else {
+            previous_accuracy
+                .map(|previous| accuracy - previous)
+                .filter(|improvement| threshold > 0.0 && *improvement < threshold)
+                .map(|improvement| StopReason::Converged { accuracy, improvement })
+        };
+
+        if let Some(reason) = reason {
+            info!("Stopping federated training: {:?}", reason);
+            *self.stop_reason.write().await = Some(reason);
+        }
+    }
+
+    /// Why training stopped, if it has
+    pub async fn stop_reason(&self) -> Option<StopReason> {
+        self.stop_reason.read().await.clone()
+    }
+
+    /// Fold a round's marginal contributions into each client's
+    /// `ContributionMetrics`
+    async fn record_contributions(&self, updates: &[FederatedModelUpdate], marginals: &HashMap<String, f32>) {
+        let total_samples: usize = updates.iter().map(|u| u.training_metadata.data_statistics.sample_count).sum();
+        let total_time: u64 = updates.iter().map(|u| u.training_metadata.computational_cost.training_time_ms).sum();
+
+        let mut participants = self.participants.write().await;
+        for update in updates {
+            let Some(participant) = participants.get_mut(&update.client_id) else { continue };
+            let metrics = &mut participant.contribution_metrics;
+            let marginal = marginals.get(&update.client_id).copied().unwrap_or(0.0);
+
+            metrics.model_improvement_score = marginal;
+            metrics.total_contribution_score += marginal;
+            if total_samples > 0 {
+                metrics.data_contribution_score =
+                    update.training_metadata.data_statistics.sample_count as f32 / total_samples as f32;
+            }
+            if total_time > 0 {
+                metrics.computational_contribution =
+                    update.training_metadata.computational_cost.training_time_ms as f32 / total_time as f32;
+            }
+        }
+    }
+
     async fn calculate_round_metrics(&self, updates: &HashMap<String, FederatedModelUpdate>) -> Result<RoundMetrics> {
         let total_clients = updates.len();
         let avg_accuracy = updates.values()
@@ -864,6 +1296,8 @@ impl FederatedLearningOrchestrator {
             convergence_rate: self.calculate_convergence_rate(updates).await?,
             fairness_score: self.calculate_fairness_score(updates).await?,
             robustness_score: self.calculate_robustness_score(updates).await?,
+            validation_accuracy: None,
+            validation_loss: None,
         })
     }
 
@@ -883,8 +1317,7 @@ impl FederatedLearningOrchestrator {
     }
 
     async fn calculate_privacy_budget_remaining(&self) -> Result<f32> {
-        // Placeholder implementation
-        Ok(0.65)
+        Ok(self.privacy_engine.read().await.budget.remaining_fraction())
     }
 
     async fn calculate_participant_satisfaction(&self) -> Result<f32> {
@@ -905,6 +1338,8 @@ pub struct TrainingRound {
     pub federation_id: String,
     pub start_time: DateTime<Utc>,
     pub end_time: Option<DateTime<Utc>>,
+    /// After this the round closes with whichever clients have reported
+    pub deadline: DateTime<Utc>,
     pub selected_clients: Vec<String>,
     pub client_updates: HashMap<String, FederatedModelUpdate>,
     pub aggregated_update: Option<AggregatedUpdate>,
@@ -913,9 +1348,33 @@ pub struct TrainingRound {
     pub privacy_budget_consumed: f32,
     pub consensus_achieved: bool,
     pub round_status: RoundStatus,
+    /// Updates excluded from aggregation as likely poisoned
+    #[serde(default)]
+    pub flagged_updates: Vec<FlaggedUpdate>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlaggedUpdate {
+    pub client_id: String,
+    pub signals: Vec<PoisoningSignal>,
+}
+
+/// Why an update was flagged; `z` is its robust z-score against the round
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PoisoningSignal {
+    /// Weights don't have the same shape as the rest of the round
+    ShapeMismatch,
+    /// L2 distance from the coordinate-wise median update
+    WeightDeviation { z: f32 },
+    /// Cosine distance from the coordinate-wise median update
+    WeightDirection { z: f32 },
+    /// Gradient L2 norm, a common backdoor signature
+    GradientNorm { z: f32 },
+    /// Cosine distance from the median gradient
+    GradientDirection { z: f32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RoundStatus {
     InProgress,
     Completed,
@@ -933,6 +1392,90 @@ pub struct RoundMetrics {
     pub convergence_rate: f32,
     pub fairness_score: f32,
     pub robustness_score: f32,
+    /// Global model scores on the held-out validation set, if one was given
+    #[serde(default)]
+    pub validation_accuracy: Option<f32>,
+    #[serde(default)]
+    pub validation_loss: Option<f32>,
+}
+
+#[derive(Debug, Clone)]
+pub enum RoundDeadlineOutcome {
+    /// No round is active or its deadline hasn't passed
+    Pending,
+    /// Enough clients reported; their updates were aggregated
+    Aggregated(Box<AggregatedUpdate>),
+    /// Too few clients reported, so the round failed and a new one started
+    /// if enough clients remain
+    Restarted { failed_round_id: usize, next_round: Option<Box<TrainingRound>> },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StopReason {
+    TargetAccuracyReached { accuracy: f32 },
+    /// Validation accuracy improved by less than `convergence_threshold`
+    Converged { accuracy: f32, improvement: f32 },
+    /// The consensus failure policy gave up after `attempts` failed rounds
+    ConsensusFailed { round_id: usize, attempts: usize },
+}
+
+/// Held-out labelled examples for server-side evaluation. The global model
+/// is read as a classifier: dense layers (`weights[i]` is outputs x inputs)
+/// with ReLU between them, then a sigmoid for a single output or softmax
+/// over several, where labels are class indices
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationSet {
+    pub features: Vec<Vec<f32>>,
+    pub labels: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ValidationMetrics {
+    pub accuracy: f32,
+    /// Mean cross-entropy
+    pub loss: f32,
+}
+
+impl ValidationSet {
+    pub fn evaluate(&self, model: &ModelWeights) -> Result<ValidationMetrics> {
+        if self.features.is_empty() || self.features.len() != self.labels.len() {
+            return Err(anyhow!("Validation set needs one label per example"));
+        }
+        if model.weights.is_empty() || model.weights.len() != model.biases.len() {
+            return Err(anyhow!("Model has no dense layers to evaluate"));
+        }
+
+        let mut correct = 0;
+        let mut loss = 0.0;
+        for (features, &label) in self.features.iter().zip(&self.labels) {
+            let mut activations = Array1::from(features.clone());
+            for (i, (w, b)) in model.weights.iter().zip(&model.biases).enumerate() {
+                if w.ncols() != activations.len() || w.nrows() != b.len() {
+                    return Err(anyhow!("Layer {} shape {:?} doesn't fit its {} inputs", i, w.dim(), activations.len()));
+                }
+                activations = w.dot(&activations) + b;
+                if i + 1 < model.weights.len() {
+                    activations.mapv_inplace(|a| a.max(0.0));
+                }
+            }
+
+            let (predicted, label_probability) = if activations.len() == 1 {
+                let p = 1.0 / (1.0 + (-activations[0]).exp());
+                (usize::from(p >= 0.5), if label == 1 { p } else { 1.0 - p })
+            } else {
+                let max = activations.fold(f32::NEG_INFINITY, |a, &b| a.max(b));
+                let exp = activations.mapv(|a| (a - max).exp());
+                let predicted = exp.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1)).map_or(0, |(i, _)| i);
+                (predicted, exp.get(label).copied().unwrap_or(0.0) / exp.sum())
+            };
+
+            correct += usize::from(predicted == label);
+            loss -= label_probability.max(1e-7).ln();
+        }
+
+        let n = self.labels.len() as f32;
+        Ok(ValidationMetrics { accuracy: correct as f32 / n, loss: loss / n })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -976,6 +1519,57 @@ pub struct ClientTrainingConfig {
     pub learning_rate: f32,
     pub privacy_budget: f32,
     pub timeout_ms: u64,
+    /// Set under FedProx; clients add the proximal term to their local loss
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proximal_mu: Option<f32>,
+    /// Set under adaptive clipping; clients clip their gradients to this norm
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clipping_threshold: Option<f32>,
+}
+
+/// Everything needed to continue training after a restart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FederatedCheckpoint {
+    pub config: FederatedLearningConfig,
+    /// Last completed round; training resumes at `round_id + 1`
+    pub round_id: usize,
+    pub global_model_version: usize,
+    pub global_weights: ModelWeights,
+    pub performance_history: Vec<f32>,
+    pub participants: HashMap<String, FederatedParticipant>,
+    pub privacy_budget: PrivacyBudgetState,
+    /// Adaptive clip bound reached so far, under adaptive clipping
+    #[serde(default)]
+    pub clip_bound: Option<f32>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Epsilon spent so far against the mechanism's total, if it has one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrivacyBudgetState {
+    pub total_epsilon: Option<f32>,
+    pub consumed_epsilon: f32,
+}
+
+impl PrivacyBudgetState {
+    fn for_mechanism(mechanism: &PrivacyMechanism) -> Self {
+        let total_epsilon = match mechanism {
+            PrivacyMechanism::DifferentialPrivacy { epsilon, .. }
+            | PrivacyMechanism::LocalDifferentialPrivacy { epsilon }
+            | PrivacyMechanism::CentralDifferentialPrivacy { epsilon, .. } => Some(*epsilon),
+            _ => None,
+        };
+        Self { total_epsilon, consumed_epsilon: 0.0 }
+    }
+
+    /// Fraction of the budget left, 1.0 for mechanisms without one
+    pub fn remaining_fraction(&self) -> f32 {
+        match self.total_epsilon {
+            Some(total) if total > 0.0 => ((total - self.consumed_epsilon) / total).max(0.0),
+            Some(_) => 0.0,
+            None => 1.0,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -984,8 +1578,17 @@ pub struct FederationStatus {
     pub total_participants: usize,
     pub active_participants: usize,
     pub current_round: Option<usize>,
+    /// The active round's status, or the last completed round's
+    pub round_status: Option<RoundStatus>,
+    /// Clients selected for the active round
+    pub round_clients: usize,
+    /// Updates received so far in the active round
+    pub updates_received: usize,
     pub global_model_accuracy: f32,
+    /// Most recent validation-set evaluation of the global model
+    pub latest_validation: Option<ValidationMetrics>,
     pub total_rounds_completed: usize,
+    pub stop_reason: Option<StopReason>,
     pub average_round_duration_ms: f64,
     pub privacy_budget_remaining: f32,
     pub consensus_rate: f32,
@@ -995,6 +1598,7 @@ pub struct FederationStatus {
 }
 
 // Component trait definitions and implementations
+#[async_trait::async_trait]
 trait ModelAggregator: Send + Sync {
     async fn aggregate_updates(&self, updates: &[FederatedModelUpdate]) -> Result<AggregatedUpdate>;
 }
@@ -1060,10 +1664,9 @@ impl WeightedAverageAggregator {
 #[async_trait::async_trait]
 impl ModelAggregator for WeightedAverageAggregator {
     async fn aggregate_updates(&self, updates: &[FederatedModelUpdate]) -> Result<AggregatedUpdate> {
-        // Placeholder implementation
         Ok(AggregatedUpdate {
             round_id: updates[0].round_id,
-            aggregated_weights: updates[0].model_weights.clone(),
+            aggregated_weights: federated_average(updates)?,
             aggregation_method: "WeightedAverage".to_string(),
             participating_clients: updates.iter().map(|u| u.client_id.clone()).collect(),
             aggregation_quality: AggregationQuality {
@@ -1082,6 +1685,45 @@ impl ModelAggregator for WeightedAverageAggregator {
     }
 }
 
+/// FedAvg: weights and biases averaged by each client's sample count, or
+/// equally if no client reports any samples
+fn federated_average(updates: &[FederatedModelUpdate]) -> Result<ModelWeights> {
+    let first = &updates.first().ok_or_else(|| anyhow!("No updates to average"))?.model_weights;
+    let shapes_match = updates.iter().all(|u| {
+        let weights = &u.model_weights;
+        weights.weights.len() == first.weights.len()
+            && weights.biases.len() == first.biases.len()
+            && weights.weights.iter().zip(&first.weights).all(|(a, b)| a.dim() == b.dim())
+            && weights.biases.iter().zip(&first.biases).all(|(a, b)| a.dim() == b.dim())
+    });
+    if !shapes_match {
+        return Err(anyhow!("Cannot average client updates with mismatched weight shapes"));
+    }
+
+    let total_samples: usize = updates.iter().map(|u| u.training_metadata.data_statistics.sample_count).sum();
+    let share = |u: &FederatedModelUpdate| {
+        if total_samples == 0 {
+            1.0 / updates.len() as f32
+        } else {
+            u.training_metadata.data_statistics.sample_count as f32 / total_samples as f32
+        }
+    };
+
+    let mut averaged = first.clone();
+    averaged.weights.iter_mut().for_each(|w| w.fill(0.0));
+    averaged.biases.iter_mut().for_each(|b| b.fill(0.0));
+    for update in updates {
+        let share = share(update);
+        for (total, w) in averaged.weights.iter_mut().zip(&update.model_weights.weights) {
+            total.scaled_add(share, w);
+        }
+        for (total, b) in averaged.biases.iter_mut().zip(&update.model_weights.biases) {
+            total.scaled_add(share, b);
+        }
+    }
+    Ok(averaged)
+}
+
 struct SecureAggregator;
 impl SecureAggregator {
     fn new() -> Self { Self }
@@ -1089,11 +1731,26 @@ impl SecureAggregator {
 
 #[async_trait::async_trait]
 impl ModelAggregator for SecureAggregator {
+    /// Unmask the mean of every client's masked weights. Clients that were
+    /// in the masking cohort but never reported leave masks that only their
+    /// peers' revealed seeds can remove, which needs a `SecureAggregationRound`
     async fn aggregate_updates(&self, updates: &[FederatedModelUpdate]) -> Result<AggregatedUpdate> {
-        // Placeholder implementation for secure aggregation
+        let masked: Vec<&MaskedWeights> = updates
+            .iter()
+            .map(|u| u.masked_weights.as_ref().ok_or_else(|| anyhow!("Update from {} is not masked", u.client_id)))
+            .collect::<Result<_>>()?;
+        let mut round = SecureAggregationRound::new(updates[0].round_id, masked[0].cohort.iter().cloned());
+        for m in masked {
+            round.submit(m.clone())?;
+        }
+        let dropped = round.dropped_clients();
+        if !dropped.is_empty() {
+            return Err(anyhow!("Clients {:?} dropped out; their masks need seeds revealed by the survivors", dropped));
+        }
+
         Ok(AggregatedUpdate {
             round_id: updates[0].round_id,
-            aggregated_weights: updates[0].model_weights.clone(),
+            aggregated_weights: round.unmask_mean()?,
             aggregation_method: "SecureAggregation".to_string(),
             participating_clients: updates.iter().map(|u| u.client_id.clone()).collect(),
             aggregation_quality: AggregationQuality {
@@ -1112,13 +1769,281 @@ impl ModelAggregator for SecureAggregator {
     }
 }
 
+// Secure aggregation (simplified single-server Bonawitz et al. protocol).
+// Every pair of clients agrees a secret by Diffie-Hellman; for each pair the
+// lower client id adds a pseudorandom mask and the higher one subtracts it,
+// so masks cancel in the sum and the server learns only the aggregate.
+// Weights are summed as wrapping fixed-point integers so cancellation is
+// exact. The 61-bit group is a stand-in for a real key agreement (X25519)
+
+/// Mersenne prime 2^61 - 1, the Diffie-Hellman modulus
+const SECURE_AGG_MODULUS: u64 = (1 << 61) - 1;
+const SECURE_AGG_GENERATOR: u64 = 3;
+/// Fixed-point scale for masked weights (about 6 decimal digits)
+const SECURE_AGG_FIXED_POINT_SCALE: f64 = (1u64 << 20) as f64;
+
+fn mod_pow(base: u64, mut exponent: u64) -> u64 {
+    let modulus = SECURE_AGG_MODULUS as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1u128;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+/// Deterministic mask stream for one pair of clients in one round
+fn pairwise_masks(shared_secret: u64, round_id: usize, len: usize) -> impl Iterator<Item = u64> {
+    // splitmix64
+    let mut state = shared_secret ^ (round_id as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    (0..len).map(move |_| {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    })
+}
+
+/// Add (`add == true`) or subtract a pair's mask stream from `values`
+fn apply_pairwise_mask(values: &mut [u64], shared_secret: u64, round_id: usize, add: bool) {
+    let masks = pairwise_masks(shared_secret, round_id, values.len());
+    for (value, mask) in values.iter_mut().zip(masks) {
+        *value = if add { value.wrapping_add(mask) } else { value.wrapping_sub(mask) };
+    }
+}
+
+/// A client's masked model weights, flattened to fixed point
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaskedWeights {
+    pub client_id: String,
+    pub round_id: usize,
+    /// Every client this update was masked against, including itself
+    pub cohort: Vec<String>,
+    pub weight_shapes: Vec<(usize, usize)>,
+    pub bias_lengths: Vec<usize>,
+    pub values: Vec<u64>,
+}
+
+/// A survivor's secret shared with a client that dropped out, letting the
+/// server strip the survivor's mask for that pair
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevealedSeed {
+    pub client_id: String,
+    pub dropped_client_id: String,
+    pub shared_secret: u64,
+}
+
+pub struct SecureAggregationClient {
+    client_id: String,
+    secret: u64,
+}
+
+impl SecureAggregationClient {
+    pub fn new(client_id: impl Into<String>) -> Self {
+        let secret = (Uuid::new_v4().as_u128() as u64) % (SECURE_AGG_MODULUS - 2) + 1;
+        Self { client_id: client_id.into(), secret }
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// Shared with the cohort through the server before masking
+    pub fn public_key(&self) -> u64 {
+        mod_pow(SECURE_AGG_GENERATOR, self.secret)
+    }
+
+    fn shared_secret(&self, peer_public_key: u64) -> u64 {
+        mod_pow(peer_public_key, self.secret)
+    }
+
+    /// Mask `weights` against every other client in `cohort_keys` (client id
+    /// to public key)
+    pub fn mask(&self, round_id: usize, weights: &ModelWeights, cohort_keys: &HashMap<String, u64>) -> MaskedWeights {
+        let mut values: Vec<u64> = flatten_tensors(&weights.weights, &weights.biases)
+            .into_iter()
+            .map(|v| (v as f64 * SECURE_AGG_FIXED_POINT_SCALE).round() as i64 as u64)
+            .collect();
+        for (peer, public_key) in cohort_keys {
+            if *peer != self.client_id {
+                apply_pairwise_mask(&mut values, self.shared_secret(*public_key), round_id, self.client_id < *peer);
+            }
+        }
+
+        let mut cohort: Vec<String> = cohort_keys.keys().cloned().collect();
+        cohort.sort();
+        MaskedWeights {
+            client_id: self.client_id.clone(),
+            round_id,
+            cohort,
+            weight_shapes: weights.weights.iter().map(|w| w.dim()).collect(),
+            bias_lengths: weights.biases.iter().map(|b| b.len()).collect(),
+            values,
+        }
+    }
+
+    /// Reveal the secret shared with `dropped_client_id` so the server can
+    /// remove this client's mask for that pair
+    pub fn reveal_seed(&self, dropped_client_id: &str, dropped_public_key: u64) -> RevealedSeed {
+        RevealedSeed {
+            client_id: self.client_id.clone(),
+            dropped_client_id: dropped_client_id.to_string(),
+            shared_secret: self.shared_secret(dropped_public_key),
+        }
+    }
+}
+
+/// Server-side state for one masked round
+pub struct SecureAggregationRound {
+    round_id: usize,
+    cohort: Vec<String>,
+    received: HashMap<String, MaskedWeights>,
+    revealed: Vec<RevealedSeed>,
+}
+
+impl SecureAggregationRound {
+    pub fn new(round_id: usize, cohort: impl IntoIterator<Item = String>) -> Self {
+        let mut cohort: Vec<String> = cohort.into_iter().collect();
+        cohort.sort();
+        cohort.dedup();
+        Self { round_id, cohort, received: HashMap::new(), revealed: Vec::new() }
+    }
+
+    pub fn submit(&mut self, masked: MaskedWeights) -> Result<()> {
+        if masked.round_id != self.round_id || !self.cohort.contains(&masked.client_id) {
+            return Err(anyhow!("Masked update from {} does not belong to round {}", masked.client_id, self.round_id));
+        }
+        if masked.cohort != self.cohort {
+            return Err(anyhow!("{} masked against a different cohort", masked.client_id));
+        }
+        self.received.insert(masked.client_id.clone(), masked);
+        Ok(())
+    }
+
+    /// Cohort members that never submitted
+    pub fn dropped_clients(&self) -> Vec<String> {
+        self.cohort.iter().filter(|c| !self.received.contains_key(*c)).cloned().collect()
+    }
+
+    pub fn reveal(&mut self, seed: RevealedSeed) {
+        self.revealed.push(seed);
+    }
+
+    /// Mean of the survivors' weights. Every survivor must have revealed its
+    /// seed with every dropped client first
+    pub fn unmask_mean(&self) -> Result<ModelWeights> {
+        let template = self.received.values().next().ok_or_else(|| anyhow!("No masked updates received"))?;
+        if self.received.values().any(|m| m.weight_shapes != template.weight_shapes || m.bias_lengths != template.bias_lengths) {
+            return Err(anyhow!("Cannot aggregate masked updates with mismatched weight shapes"));
+        }
+
+        let mut sum = vec![0u64; template.values.len()];
+        for masked in self.received.values() {
+            for (total, value) in sum.iter_mut().zip(&masked.values) {
+                *total = total.wrapping_add(*value);
+            }
+        }
+
+        // Masks between a survivor and a dropped client never cancelled
+        for dropped in self.dropped_clients() {
+            for survivor in self.received.keys() {
+                let seed = self
+                    .revealed
+                    .iter()
+                    .find(|s| s.client_id == *survivor && s.dropped_client_id == dropped)
+                    .ok_or_else(|| anyhow!("{} has not revealed its seed for dropped client {}", survivor, dropped))?;
+                apply_pairwise_mask(&mut sum, seed.shared_secret, self.round_id, *survivor >= dropped);
+            }
+        }
+
+        let count = self.received.len() as f64;
+        let mut values = sum
+            .into_iter()
+            .map(|v| (v as i64 as f64 / SECURE_AGG_FIXED_POINT_SCALE / count) as f32);
+        let weights = template
+            .weight_shapes
+            .iter()
+            .map(|&shape| Array2::from_shape_fn(shape, |_| values.next().unwrap_or(0.0)))
+            .collect();
+        let biases = template
+            .bias_lengths
+            .iter()
+            .map(|&len| Array1::from_shape_fn(len, |_| values.next().unwrap_or(0.0)))
+            .collect();
+
+        Ok(ModelWeights {
+            weights,
+            biases,
+            batch_norm_params: None,
+            optimizer_state: None,
+            weight_compression: CompressionInfo {
+                compression_type: CompressionType::None,
+                compression_ratio: 1.0,
+                original_size_bytes: 0,
+                compressed_size_bytes: 0,
+                reconstruction_error: 0.0,
+            },
+        })
+    }
+}
+
 // Additional component implementations would be added here
-struct PrivacyEngine;
+/// Norm quantile the adaptive clip bound tracks
+const ADAPTIVE_CLIPPING_TARGET_QUANTILE: f32 = 0.5;
+/// Step size of the geometric clip bound update
+const ADAPTIVE_CLIPPING_LEARNING_RATE: f32 = 0.2;
+const ADAPTIVE_CLIPPING_INITIAL_BOUND: f32 = 1.0;
+
+struct PrivacyEngine {
+    budget: PrivacyBudgetState,
+    /// Adaptive clip bound, only under `DPMechanism::AdaptiveClipping`
+    clip_bound: Option<f32>,
+}
 impl PrivacyEngine {
-    async fn new(_mechanism: &PrivacyMechanism) -> Result<Self> { Ok(Self) }
+    async fn new(mechanism: &PrivacyMechanism) -> Result<Self> {
+        let clip_bound = match mechanism {
+            PrivacyMechanism::DifferentialPrivacy { mechanism: DPMechanism::AdaptiveClipping, .. } => {
+                Some(ADAPTIVE_CLIPPING_INITIAL_BOUND)
+            }
+            _ => None,
+        };
+        Ok(Self { budget: PrivacyBudgetState::for_mechanism(mechanism), clip_bound })
+    }
+
+    /// Move the clip bound geometrically toward the target quantile of this
+    /// round's pre-clipping norms (Andrew et al., 2021): it shrinks while more
+    /// than the target fraction of clients fall under it and grows otherwise
+    fn update_clip_bound(&mut self, norms: &[f32]) {
+        let Some(bound) = self.clip_bound.as_mut() else { return };
+        if norms.is_empty() {
+            return;
+        }
+        let unclipped = norms.iter().filter(|&&norm| norm <= *bound).count() as f32 / norms.len() as f32;
+        *bound *= (-ADAPTIVE_CLIPPING_LEARNING_RATE * (unclipped - ADAPTIVE_CLIPPING_TARGET_QUANTILE)).exp();
+    }
+
+    fn consume_budget(&mut self, epsilon: f32) {
+        self.budget.consumed_epsilon += epsilon;
+    }
     async fn validate_privacy_preferences(&self, _prefs: &PrivacyPreferences) -> Result<()> { Ok(()) }
-    async fn audit_update(&self, _update: &FederatedModelUpdate) -> Result<PrivacyAudit> {
-        Ok(_update.privacy_audit.clone())
+    async fn audit_update(&self, update: &FederatedModelUpdate) -> Result<PrivacyAudit> {
+        let mut audit = update.privacy_audit.clone();
+        if let Some(bound) = self.clip_bound {
+            audit.clipping_bound = Some(bound);
+            let submitted_norm = l2_norm(&flatten_tensors(&update.gradient_updates.gradients, &[]));
+            if submitted_norm > bound * (1.0 + f32::EPSILON.sqrt()) {
+                audit.potential_privacy_violations.push(format!(
+                    "Gradient norm {:.4} exceeds the clip bound {:.4}",
+                    submitted_norm, bound
+                ));
+            }
+        }
+        Ok(audit)
     }
     async fn apply_privacy_mechanism(&self, update: &AggregatedUpdate) -> Result<AggregatedUpdate> {
         Ok(update.clone())
@@ -1131,19 +2056,186 @@ impl CommunicationManager {
     async fn send_model_to_client(&self, _client_id: &str, _distribution: &ModelDistribution) -> Result<()> { Ok(()) }
 }
 
+/// Decides whether the federation agrees on a round's aggregated update
+#[async_trait::async_trait]
+pub trait ConsensusValidator: Send + Sync {
+    async fn validate_aggregated_update(&self, update: &AggregatedUpdate, client_updates: &[FederatedModelUpdate]) -> Result<bool>;
+}
+
 struct ConsensusEngine;
 impl ConsensusEngine {
     async fn new(_mechanism: &ConsensusMechanism) -> Result<Self> { Ok(Self) }
+}
+
+#[async_trait::async_trait]
+impl ConsensusValidator for ConsensusEngine {
     async fn validate_aggregated_update(&self, _update: &AggregatedUpdate, _client_updates: &[FederatedModelUpdate]) -> Result<bool> {
         Ok(true)
     }
 }
 
-struct FederatedSecurityManager;
+/// Trust and reputation multiplier applied to a client whose update is
+/// flagged as poisoned
+const POISONED_UPDATE_TRUST_PENALTY: f32 = 0.5;
+
+/// Fewer updates than this give no meaningful notion of an outlier
+const MIN_UPDATES_FOR_POISONING_DETECTION: usize = 3;
+
+struct FederatedSecurityManager {
+    params: SecurityParameters,
+}
 impl FederatedSecurityManager {
-    async fn new(_params: &SecurityParameters) -> Result<Self> { Ok(Self) }
+    async fn new(params: &SecurityParameters) -> Result<Self> { Ok(Self { params: params.clone() }) }
     async fn validate_participant(&self, _participant: &FederatedParticipant) -> Result<()> { Ok(()) }
     async fn validate_update_security(&self, _update: &FederatedModelUpdate) -> Result<()> { Ok(()) }
+
+    /// Compare each update with the round's median update and flag the
+    /// outliers. Poisoning detection looks at the model weights; backdoor
+    /// detection looks at gradient norms and directions
+    async fn detect_poisoned_updates(&self, updates: &[FederatedModelUpdate]) -> Vec<FlaggedUpdate> {
+        if updates.len() < MIN_UPDATES_FOR_POISONING_DETECTION
+            || !(self.params.poisoning_detection || self.params.backdoor_detection)
+        {
+            return Vec::new();
+        }
+
+        let mut signals = vec![Vec::new(); updates.len()];
+        let threshold = self.params.poisoning_z_threshold;
+        let mut flag_outliers = |scores: &[f32], signal: fn(f32) -> PoisoningSignal| {
+            for (i, z) in robust_z_scores(scores).into_iter().enumerate() {
+                if z > threshold {
+                    signals[i].push(signal(z));
+                }
+            }
+        };
+
+        if self.params.poisoning_detection {
+            let flattened: Vec<Vec<f32>> = updates
+                .iter()
+                .map(|u| flatten_tensors(&u.model_weights.weights, &u.model_weights.biases))
+                .collect();
+            let (deviations, directions) = median_deviations(&flattened);
+            flag_outliers(&deviations, |z| PoisoningSignal::WeightDeviation { z });
+            flag_outliers(&directions, |z| PoisoningSignal::WeightDirection { z });
+        }
+
+        if self.params.backdoor_detection {
+            let gradients: Vec<Vec<f32>> = updates
+                .iter()
+                .map(|u| flatten_tensors(&u.gradient_updates.gradients, &[]))
+                .collect();
+            let norms: Vec<f32> = gradients.iter().map(|g| l2_norm(g)).collect();
+            let (_, directions) = median_deviations(&gradients);
+            flag_outliers(&norms, |z| PoisoningSignal::GradientNorm { z });
+            flag_outliers(&directions, |z| PoisoningSignal::GradientDirection { z });
+        }
+
+        // Updates whose shape disagrees with the majority can't be compared
+        let shape = |u: &FederatedModelUpdate| {
+            u.model_weights.weights.iter().map(|w| w.dim()).collect::<Vec<_>>()
+        };
+        let shapes: Vec<_> = updates.iter().map(shape).collect();
+        let majority = shapes
+            .iter()
+            .max_by_key(|candidate| shapes.iter().filter(|s| s == candidate).count())
+            .cloned()
+            .unwrap_or_default();
+        for (i, s) in shapes.iter().enumerate() {
+            if *s != majority {
+                signals[i].insert(0, PoisoningSignal::ShapeMismatch);
+            }
+        }
+
+        updates
+            .iter()
+            .zip(signals)
+            .filter(|(_, signals)| !signals.is_empty())
+            .map(|(update, signals)| FlaggedUpdate { client_id: update.client_id.clone(), signals })
+            .collect()
+    }
+}
+
+fn flatten_tensors(matrices: &[Array2<f32>], vectors: &[Array1<f32>]) -> Vec<f32> {
+    matrices
+        .iter()
+        .flat_map(|m| m.iter().copied())
+        .chain(vectors.iter().flat_map(|v| v.iter().copied()))
+        .collect()
+}
+
+fn l2_norm(values: &[f32]) -> f32 {
+    values.iter().map(|v| v * v).sum::<f32>().sqrt()
+}
+
+/// The update's gradient norm before clipping, from its per-layer norms, or
+/// from the gradients themselves if it reported none
+fn reported_gradient_norm(update: &FederatedModelUpdate) -> f32 {
+    let gradients = &update.gradient_updates;
+    if gradients.gradient_norms.is_empty() {
+        l2_norm(&flatten_tensors(&gradients.gradients, &[]))
+    } else {
+        l2_norm(&gradients.gradient_norms)
+    }
+}
+
+fn median(values: &[f32]) -> f32 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mid = sorted.len() / 2;
+    if sorted.is_empty() {
+        0.0
+    } else if sorted.len() % 2 == 1 {
+        sorted[mid]
+    } else {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    }
+}
+
+/// Coordinate-wise median of the vectors sharing the most common length
+fn coordinate_median(vectors: &[Vec<f32>]) -> Vec<f32> {
+    let len = vectors
+        .iter()
+        .map(Vec::len)
+        .max_by_key(|len| vectors.iter().filter(|v| v.len() == *len).count())
+        .unwrap_or(0);
+    let comparable: Vec<&Vec<f32>> = vectors.iter().filter(|v| v.len() == len).collect();
+    (0..len)
+        .map(|j| median(&comparable.iter().map(|v| v[j]).collect::<Vec<_>>()))
+        .collect()
+}
+
+/// L2 and cosine distance of each vector from the coordinate-wise median of
+/// the vectors sharing the most common length; others score infinitely far
+fn median_deviations(vectors: &[Vec<f32>]) -> (Vec<f32>, Vec<f32>) {
+    let center = coordinate_median(vectors);
+    let center_norm = l2_norm(&center);
+
+    vectors
+        .iter()
+        .map(|v| {
+            if v.len() != center.len() {
+                return (f32::INFINITY, f32::INFINITY);
+            }
+            let distance = v.iter().zip(&center).map(|(a, b)| (a - b) * (a - b)).sum::<f32>().sqrt();
+            let norm = l2_norm(v);
+            let cosine_distance = if norm == 0.0 || center_norm == 0.0 {
+                0.0
+            } else {
+                1.0 - v.iter().zip(&center).map(|(a, b)| a * b).sum::<f32>() / (norm * center_norm)
+            };
+            (distance, cosine_distance)
+        })
+        .unzip()
+}
+
+/// Modified z-scores (Iglewicz and Hoaglin) using the median absolute
+/// deviation, so a single large outlier can't mask itself by inflating the
+/// spread. Only deviations above the median score positively
+fn robust_z_scores(values: &[f32]) -> Vec<f32> {
+    let center = median(values);
+    let deviations: Vec<f32> = values.iter().map(|v| (v - center).abs()).collect();
+    let mad = median(&deviations).max(1e-6);
+    values.iter().map(|v| 0.6745 * (v - center) / mad).collect()
 }
 
 struct FederatedPerformanceMonitor;
@@ -1155,22 +2247,925 @@ impl FederatedPerformanceMonitor {
     async fn get_consensus_rate(&self) -> Result<f32> { Ok(0.95) }
 }
 
+/// Reliability multiplier for a client that misses a round's deadline
+const DROPOUT_RELIABILITY_PENALTY: f32 = 0.8;
+
 struct ClientSelector;
 impl ClientSelector {
     async fn new(_params: &TrainingParameters) -> Result<Self> { Ok(Self) }
-    async fn select_clients(&self, participants: &HashMap<String, FederatedParticipant>, _params: &TrainingParameters) -> Result<Vec<String>> {
-        Ok(participants.keys().take(10).cloned().collect())
+
+    /// The most reliable clients first, so clients that keep dropping out
+    /// are passed over when there are enough others
+    async fn select_clients(&self, participants: &HashMap<String, FederatedParticipant>, params: &TrainingParameters) -> Result<Vec<String>> {
+        let mut candidates: Vec<&FederatedParticipant> = participants.values().collect();
+        candidates.sort_by(|a, b| {
+            b.participation_history
+                .reliability_score
+                .total_cmp(&a.participation_history.reliability_score)
+                .then_with(|| a.client_id.cmp(&b.client_id))
+        });
+        Ok(candidates.into_iter().take(params.max_clients).map(|p| p.client_id.clone()).collect())
     }
 }
 
-struct ModelValidator;
+struct ModelValidator {
+    proximal_mu: Option<f32>,
+}
 impl ModelValidator {
-    async fn new(_arch: &ModelArchitecture) -> Result<Self> { Ok(Self) }
-    async fn validate_update(&self, _update: &FederatedModelUpdate) -> Result<()> { Ok(()) }
+    async fn new(_arch: &ModelArchitecture, proximal_mu: Option<f32>) -> Result<Self> { Ok(Self { proximal_mu }) }
+
+    /// Under FedProx, an update trained without the distributed proximal
+    /// term drifts like plain FedAvg on non-IID data, so it is rejected
+    async fn validate_update(&self, update: &FederatedModelUpdate) -> Result<()> {
+        if let Some(expected) = self.proximal_mu {
+            let reported = update.training_metadata.proximal_mu;
+            if !reported.is_some_and(|mu| (mu - expected).abs() <= f32::EPSILON) {
+                return Err(anyhow!(
+                    "Update from {} trained with proximal mu {:?}, expected {}",
+                    update.client_id, reported, expected
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
-struct IncentiveMechanism;
+/// Scores a candidate global model; higher is better
+pub type ModelUtility = Arc<dyn Fn(&ModelWeights) -> f32 + Send + Sync>;
+
+/// Reward shared each round among clients in proportion to their positive
+/// marginal contributions
+const REWARD_POOL_PER_ROUND: f64 = 1.0;
+
+struct IncentiveMechanism {
+    /// Without one, a model scores by its closeness to the round's
+    /// coordinate-wise median update
+    utility: Option<ModelUtility>,
+    rewards: HashMap<String, f64>,
+}
 impl IncentiveMechanism {
-    async fn new() -> Result<Self> { Ok(Self) }
-    async fn distribute_incentives(&mut self, _updates: &[FederatedModelUpdate]) -> Result<()> { Ok(()) }
-}
\ No newline at end of file
+    async fn new() -> Result<Self> { Ok(Self { utility: None, rewards: HashMap::new() }) }
+
+    /// Score each client by leave-one-out: the utility of the FedAvg of all
+    /// updates minus the utility without that client's update. Credits the
+    /// round's reward pool and returns the per-client marginal contributions
+    async fn distribute_incentives(&mut self, updates: &[FederatedModelUpdate]) -> Result<HashMap<String, f32>> {
+        if updates.len() < 2 {
+            return Ok(updates.iter().map(|u| (u.client_id.clone(), 0.0)).collect());
+        }
+
+        let consensus = coordinate_median(
+            &updates
+                .iter()
+                .map(|u| flatten_tensors(&u.model_weights.weights, &u.model_weights.biases))
+                .collect::<Vec<_>>(),
+        );
+        let utility = |weights: &ModelWeights| match &self.utility {
+            Some(utility) => utility(weights),
+            None => {
+                let flattened = flatten_tensors(&weights.weights, &weights.biases);
+                -flattened.iter().zip(&consensus).map(|(a, b)| (a - b) * (a - b)).sum::<f32>().sqrt()
+            }
+        };
+
+        let full_utility = utility(&federated_average(updates)?);
+        let mut marginals = HashMap::new();
+        for (i, update) in updates.iter().enumerate() {
+            let without: Vec<FederatedModelUpdate> = updates
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, u)| u.clone())
+                .collect();
+            let marginal = full_utility - utility(&federated_average(&without)?);
+            marginals.insert(update.client_id.clone(), marginal);
+        }
+
+        let positive_total: f32 = marginals.values().filter(|m| **m > 0.0).sum();
+        for (client_id, marginal) in &marginals {
+            let reward = if positive_total > 0.0 && *marginal > 0.0 {
+                REWARD_POOL_PER_ROUND * (*marginal / positive_total) as f64
+            } else {
+                0.0
+            };
+            *self.rewards.entry(client_id.clone()).or_insert(0.0) += reward;
+        }
+        Ok(marginals)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    fn test_config() -> FederatedLearningConfig {
+        FederatedLearningConfig {
+            federation_id: "test-federation".to_string(),
+            learning_algorithm: FederatedAlgorithm::FedAvg,
+            aggregation_strategy: AggregationStrategy::WeightedAverage { weights: vec![] },
+            privacy_mechanism: PrivacyMechanism::DifferentialPrivacy {
+                epsilon: 10.0,
+                delta: 1e-5,
+                mechanism: DPMechanism::Gaussian,
+            },
+            communication_protocol: CommunicationProtocol::HTTP,
+            consensus_mechanism: ConsensusMechanism::None,
+            model_architecture: ModelArchitecture {
+                model_type: ModelType::LinearRegression,
+                layers: vec![],
+                parameters_count: 3,
+                model_size_mb: 0.0,
+                input_shape: vec![2],
+                output_shape: vec![1],
+                activation_functions: vec![],
+                optimization_algorithm: "sgd".to_string(),
+                loss_function: "mse".to_string(),
+            },
+            training_parameters: TrainingParameters {
+                global_rounds: 10,
+                local_epochs: 1,
+                local_batch_size: 32,
+                learning_rate: 0.1,
+                learning_rate_schedule: LearningRateSchedule {
+                    schedule_type: "constant".to_string(),
+                    initial_rate: 0.1,
+                    decay_rate: 0.0,
+                    decay_steps: 0,
+                    minimum_rate: 0.1,
+                },
+                regularization: RegularizationConfig {
+                    l1_lambda: 0.0,
+                    l2_lambda: 0.0,
+                    dropout_rate: 0.0,
+                    batch_normalization: false,
+                    weight_decay: 0.0,
+                },
+                early_stopping: EarlyStoppingConfig {
+                    enabled: false,
+                    patience: 0,
+                    min_delta: 0.0,
+                    metric: "loss".to_string(),
+                    restore_best_weights: false,
+                },
+                client_fraction: 1.0,
+                min_clients: 2,
+                max_clients: 10,
+                convergence_threshold: 0.0,
+                proximal_mu: 0.01,
+                round_timeout_ms: 60_000,
+            },
+            security_parameters: SecurityParameters {
+                encryption_enabled: false,
+                authentication_required: false,
+                integrity_checks: false,
+                byzantine_tolerance: 0,
+                adversary_fraction: 0.0,
+                poisoning_detection: false,
+                backdoor_detection: false,
+                model_inversion_protection: false,
+                membership_inference_protection: false,
+                poisoning_z_threshold: 3.5,
+            },
+            performance_targets: PerformanceTargets {
+                target_accuracy: 1.0,
+                max_training_time_hours: 1.0,
+                max_communication_rounds: 10,
+                max_bandwidth_usage_mb: 100.0,
+                min_convergence_rate: 0.0,
+                max_memory_usage_mb: 100.0,
+                min_client_participation: 0.0,
+            },
+            checkpoint_policy: None,
+            consensus_failure_policy: ConsensusFailurePolicy::default(),
+        }
+    }
+
+    fn participant(client_id: &str) -> FederatedParticipant {
+        FederatedParticipant {
+            client_id: client_id.to_string(),
+            client_type: ClientType::Desktop,
+            capabilities: ClientCapabilities {
+                compute_power_tflops: 1.0,
+                memory_gb: 8.0,
+                storage_gb: 64.0,
+                network_bandwidth_mbps: 100.0,
+                gpu_available: false,
+                specialized_hardware: vec![],
+                supported_algorithms: vec![FederatedAlgorithm::FedAvg],
+                privacy_mechanisms: vec![],
+            },
+            trust_score: 1.0,
+            reputation: 1.0,
+            participation_history: ParticipationHistory {
+                total_rounds_participated: 0,
+                successful_rounds: 0,
+                failed_rounds: 0,
+                average_computation_time_ms: 0.0,
+                average_communication_latency_ms: 0.0,
+                data_quality_scores: vec![],
+                reliability_score: 1.0,
+                last_participation: Utc::now(),
+            },
+            data_characteristics: DataCharacteristics {
+                dataset_size: 100,
+                data_quality_score: 1.0,
+                class_distribution: HashMap::new(),
+                feature_statistics: FeatureStatistics {
+                    mean_values: vec![],
+                    std_values: vec![],
+                    min_values: vec![],
+                    max_values: vec![],
+                    correlation_matrix: vec![],
+                    feature_importance: vec![],
+                },
+                data_freshness: Utc::now(),
+                data_drift_score: 0.0,
+                label_noise_level: 0.0,
+                missing_values_ratio: 0.0,
+            },
+            privacy_preferences: PrivacyPreferences {
+                max_epsilon: 10.0,
+                max_delta: 1e-5,
+                allow_model_sharing: true,
+                allow_gradient_sharing: true,
+                require_local_dp: false,
+                anonymization_level: 0,
+                retention_period_days: 30,
+            },
+            resource_constraints: ResourceConstraints {
+                max_compute_time_ms: 60_000,
+                max_memory_usage_mb: 1024.0,
+                max_bandwidth_usage_mb: 100.0,
+                battery_level_threshold: 0.0,
+                network_type_restrictions: vec![],
+                availability_schedule: AvailabilitySchedule {
+                    timezone: "UTC".to_string(),
+                    available_hours: vec![(0, 24)],
+                    available_days: (0..7).collect(),
+                    blackout_periods: vec![],
+                },
+            },
+            contribution_metrics: ContributionMetrics {
+                data_contribution_score: 0.0,
+                model_improvement_score: 0.0,
+                computational_contribution: 0.0,
+                communication_efficiency: 0.0,
+                stability_contribution: 0.0,
+                innovation_score: 0.0,
+                total_contribution_score: 0.0,
+            },
+        }
+    }
+
+    fn weights(w: Array2<f32>, b: Array1<f32>) -> ModelWeights {
+        ModelWeights {
+            weights: vec![w],
+            biases: vec![b],
+            batch_norm_params: None,
+            optimizer_state: None,
+            weight_compression: CompressionInfo {
+                compression_type: CompressionType::None,
+                compression_ratio: 1.0,
+                original_size_bytes: 0,
+                compressed_size_bytes: 0,
+                reconstruction_error: 0.0,
+            },
+        }
+    }
+
+    fn client_update(round_id: usize, client_id: &str, model_weights: ModelWeights) -> FederatedModelUpdate {
+        FederatedModelUpdate {
+            update_id: Uuid::new_v4().to_string(),
+            round_id,
+            client_id: client_id.to_string(),
+            gradient_updates: GradientUpdates {
+                gradients: model_weights.weights.clone(),
+                gradient_norms: vec![],
+                clipped_gradients: false,
+                clipping_threshold: 0.0,
+                noise_added: false,
+                privacy_budget_used: 0.5,
+            },
+            model_weights,
+            training_metadata: TrainingMetadata {
+                local_epochs: 1,
+                batch_size: 32,
+                learning_rate: 0.1,
+                loss_values: vec![],
+                accuracy_values: vec![],
+                proximal_mu: None,
+                convergence_metrics: ConvergenceMetrics {
+                    gradient_norm: 0.0,
+                    parameter_change_norm: 0.0,
+                    loss_improvement: 0.0,
+                    accuracy_improvement: 0.0,
+                    convergence_indicator: 0.0,
+                },
+                computational_cost: ComputationalCost {
+                    training_time_ms: 10,
+                    forward_pass_time_ms: 5,
+                    backward_pass_time_ms: 5,
+                    communication_time_ms: 1,
+                    memory_peak_mb: 1.0,
+                    energy_consumption_joules: 0.0,
+                },
+                data_statistics: LocalDataStatistics {
+                    sample_count: 100,
+                    class_distribution: HashMap::new(),
+                    data_quality_indicators: DataQualityIndicators {
+                        completeness: 1.0,
+                        accuracy: 1.0,
+                        consistency: 1.0,
+                        validity: 1.0,
+                        uniqueness: 1.0,
+                        timeliness: 1.0,
+                    },
+                    feature_drift_detected: false,
+                    outliers_detected: 0,
+                },
+            },
+            privacy_audit: PrivacyAudit {
+                privacy_budget_consumed: 0.5,
+                differential_privacy_guarantee: Some((1.0, 1e-5)),
+                k_anonymity_level: None,
+                l_diversity_satisfied: true,
+                t_closeness_satisfied: true,
+                privacy_risk_score: 0.0,
+                potential_privacy_violations: vec![],
+                clipping_bound: None,
+            },
+            quality_metrics: QualityMetrics {
+                model_accuracy: 0.8,
+                model_loss: 0.2,
+                generalization_score: 0.0,
+                robustness_score: 0.0,
+                fairness_metrics: FairnessMetrics {
+                    demographic_parity: 0.0,
+                    equalized_odds: 0.0,
+                    equality_of_opportunity: 0.0,
+                    calibration_score: 0.0,
+                    individual_fairness: 0.0,
+                    counterfactual_fairness: 0.0,
+                },
+                uncertainty_quantification: UncertaintyQuantification {
+                    epistemic_uncertainty: 0.0,
+                    aleatoric_uncertainty: 0.0,
+                    prediction_intervals: vec![],
+                    confidence_scores: vec![],
+                    calibration_error: 0.0,
+                },
+                adversarial_robustness: AdversarialRobustness {
+                    adversarial_accuracy: 0.0,
+                    attack_success_rate: 0.0,
+                    certified_radius: 0.0,
+                    gradient_masking_detected: false,
+                    robust_accuracy_bounds: (0.0, 0.0),
+                },
+            },
+            timestamp: Utc::now(),
+            masked_weights: None,
+        }
+    }
+
+    /// Run one round in which every participant reports `model_weights`
+    async fn train_round(orchestrator: &FederatedLearningOrchestrator, model_weights: ModelWeights) -> usize {
+        let round = orchestrator.start_training_round().await.unwrap();
+        for client_id in &round.selected_clients {
+            orchestrator
+                .receive_client_update(client_update(round.round_id, client_id, model_weights.clone()))
+                .await
+                .unwrap();
+        }
+        round.round_id
+    }
+
+    fn temp_checkpoint_path() -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("federated-checkpoint-{}", Uuid::new_v4()))
+            .join("checkpoint.json")
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_and_resume_restores_global_model() {
+        let path = temp_checkpoint_path();
+        let mut config = test_config();
+        config.checkpoint_policy = Some(CheckpointPolicy { path: path.clone(), every_n_rounds: 2 });
+
+        let orchestrator = FederatedLearningOrchestrator::new(config).await.unwrap();
+        orchestrator.register_participant(participant("client-a")).await.unwrap();
+        orchestrator.register_participant(participant("client-b")).await.unwrap();
+
+        for step in 1..=3 {
+            let scale = step as f32;
+            train_round(&orchestrator, weights(array![[scale, 2.0 * scale]], array![-scale])).await;
+            // The policy writes after rounds 2, 4, ...
+            assert_eq!(path.exists(), step >= 2);
+        }
+        let automatic: FederatedCheckpoint = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(automatic.round_id, 2);
+
+        let saved = orchestrator.checkpoint(&path).await.unwrap();
+        assert_eq!(saved.round_id, 3);
+        assert_eq!(saved.privacy_budget.consumed_epsilon, 3.0);
+
+        let resumed = FederatedLearningOrchestrator::resume_from(&path).await.unwrap();
+        {
+            let original = orchestrator.global_model.read().await;
+            let restored = resumed.global_model.read().await;
+            assert_eq!(restored.version, original.version);
+            assert_eq!(restored.weights.weights, original.weights.weights);
+            assert_eq!(restored.weights.biases, original.weights.biases);
+            assert_eq!(restored.weights.weights[0], array![[3.0, 6.0]]);
+        }
+        assert_eq!(resumed.participants.read().await.len(), 2);
+        assert_eq!(
+            resumed.privacy_engine.read().await.budget,
+            orchestrator.privacy_engine.read().await.budget
+        );
+        assert_eq!(resumed.get_federation_status().await.unwrap().privacy_budget_remaining, 0.7);
+
+        // Training carries on from the next round
+        assert_eq!(train_round(&resumed, weights(array![[4.0, 8.0]], array![-4.0])).await, 4);
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_poisoned_update_is_detected_and_excluded() {
+        let mut config = test_config();
+        config.training_parameters.min_clients = 5;
+        config.security_parameters.poisoning_detection = true;
+        config.security_parameters.backdoor_detection = true;
+
+        let orchestrator = FederatedLearningOrchestrator::new(config).await.unwrap();
+        let clients = ["client-a", "client-b", "client-c", "client-d", "attacker"];
+        for client_id in clients {
+            orchestrator.register_participant(participant(client_id)).await.unwrap();
+        }
+
+        let round = orchestrator.start_training_round().await.unwrap();
+        for (i, client_id) in clients.iter().enumerate() {
+            // Honest clients agree up to a little noise; the attacker pushes
+            // the model far away in the opposite direction
+            let update = if *client_id == "attacker" {
+                weights(array![[-40.0, -80.0]], array![30.0])
+            } else {
+                let noise = i as f32 * 0.01;
+                weights(array![[1.0 + noise, 2.0 - noise]], array![-1.0 + noise])
+            };
+            orchestrator.receive_client_update(client_update(round.round_id, client_id, update)).await.unwrap();
+        }
+
+        let completed = orchestrator.round_history.read().await.back().cloned().unwrap();
+        assert_eq!(completed.flagged_updates.len(), 1);
+        let flagged = &completed.flagged_updates[0];
+        assert_eq!(flagged.client_id, "attacker");
+        assert!(flagged.signals.iter().any(|s| matches!(s, PoisoningSignal::WeightDeviation { .. })));
+        assert!(flagged.signals.iter().any(|s| matches!(s, PoisoningSignal::GradientNorm { .. })));
+
+        let aggregated = completed.aggregated_update.unwrap();
+        assert_eq!(aggregated.participating_clients.len(), 4);
+        assert!(!aggregated.participating_clients.contains(&"attacker".to_string()));
+        assert!(aggregated.aggregated_weights.weights[0][[0, 0]] > 0.0);
+
+        let participants = orchestrator.participants.read().await;
+        assert_eq!(participants["attacker"].trust_score, POISONED_UPDATE_TRUST_PENALTY);
+        assert_eq!(participants["attacker"].reputation, POISONED_UPDATE_TRUST_PENALTY);
+        assert_eq!(participants["client-a"].trust_score, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_helpful_client_outscores_noisy_client() {
+        let mut config = test_config();
+        config.training_parameters.min_clients = 3;
+        let orchestrator = FederatedLearningOrchestrator::new(config).await.unwrap();
+        for client_id in ["helpful-a", "helpful-b", "noisy"] {
+            orchestrator.register_participant(participant(client_id)).await.unwrap();
+        }
+
+        // Validation utility: closeness to the true model
+        let target = weights(array![[1.0, 2.0]], array![-1.0]);
+        let target_flat = flatten_tensors(&target.weights, &target.biases);
+        orchestrator
+            .set_contribution_utility(Arc::new(move |candidate: &ModelWeights| {
+                let flat = flatten_tensors(&candidate.weights, &candidate.biases);
+                -l2_norm(&flat.iter().zip(&target_flat).map(|(a, b)| a - b).collect::<Vec<_>>())
+            }))
+            .await;
+
+        let round = orchestrator.start_training_round().await.unwrap();
+        for (client_id, update) in [
+            ("helpful-a", weights(array![[1.1, 1.9]], array![-1.0])),
+            ("helpful-b", weights(array![[0.9, 2.1]], array![-0.9])),
+            ("noisy", weights(array![[7.3, -4.2]], array![5.5])),
+        ] {
+            orchestrator.receive_client_update(client_update(round.round_id, client_id, update)).await.unwrap();
+        }
+
+        let scores = orchestrator.get_contribution_scores().await;
+        assert!(scores["helpful-a"].model_improvement_score > 0.0);
+        assert!(scores["noisy"].model_improvement_score < 0.0);
+        assert!(scores["helpful-b"].total_contribution_score > scores["noisy"].total_contribution_score);
+        assert!((scores["noisy"].data_contribution_score - 1.0 / 3.0).abs() < 1e-6);
+
+        let helpful_reward = orchestrator.get_rewards("helpful-a").await + orchestrator.get_rewards("helpful-b").await;
+        assert!((helpful_reward - REWARD_POOL_PER_ROUND).abs() < 1e-6);
+        assert_eq!(orchestrator.get_rewards("noisy").await, 0.0);
+        assert_eq!(orchestrator.get_rewards("unknown").await, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_fedprox_distributes_and_requires_proximal_term() {
+        let mut config = test_config();
+        config.learning_algorithm = FederatedAlgorithm::FedProx;
+        config.training_parameters.proximal_mu = 0.05;
+        let fedprox = FederatedLearningOrchestrator::new(config).await.unwrap();
+        let fedavg = FederatedLearningOrchestrator::new(test_config()).await.unwrap();
+        for orchestrator in [&fedprox, &fedavg] {
+            orchestrator.register_participant(participant("client-a")).await.unwrap();
+            orchestrator.register_participant(participant("client-b")).await.unwrap();
+        }
+
+        let distributed = fedprox.create_client_training_config("client-a").await.unwrap();
+        assert_eq!(distributed.proximal_mu, Some(0.05));
+        assert_eq!(serde_json::to_value(&distributed).unwrap()["proximal_mu"], serde_json::json!(0.05f32));
+        let plain = fedavg.create_client_training_config("client-a").await.unwrap();
+        assert_eq!(plain.proximal_mu, None);
+        assert!(serde_json::to_value(&plain).unwrap().get("proximal_mu").is_none());
+
+        // FedProx updates must echo the distributed mu
+        let round = fedprox.start_training_round().await.unwrap();
+        let model = weights(array![[1.0, 2.0]], array![0.0]);
+        let without_mu = client_update(round.round_id, "client-a", model.clone());
+        assert!(fedprox.receive_client_update(without_mu).await.is_err());
+        let mut with_mu = client_update(round.round_id, "client-a", model.clone());
+        with_mu.training_metadata.proximal_mu = Some(0.05);
+        fedprox.receive_client_update(with_mu).await.unwrap();
+
+        let round = fedavg.start_training_round().await.unwrap();
+        fedavg.receive_client_update(client_update(round.round_id, "client-a", model)).await.unwrap();
+    }
+
+    /// Label 1 when the first feature exceeds the second
+    fn comparison_validation_set() -> ValidationSet {
+        ValidationSet {
+            features: vec![
+                vec![2.0, 1.0], vec![1.0, 2.0], vec![3.0, 0.0], vec![0.0, 3.0],
+                vec![1.0, 1.5], vec![1.5, 1.0], vec![0.2, 0.1], vec![0.1, 0.2],
+            ],
+            labels: vec![1, 0, 1, 0, 0, 1, 1, 0],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validation_accuracy_improves_until_target() {
+        let mut config = test_config();
+        config.performance_targets.target_accuracy = 0.95;
+        let orchestrator = FederatedLearningOrchestrator::with_validation_set(config, comparison_validation_set())
+            .await
+            .unwrap();
+        orchestrator.register_participant(participant("client-a")).await.unwrap();
+        orchestrator.register_participant(participant("client-b")).await.unwrap();
+
+        // Each round the clients' logistic model gets closer to x0 - x1
+        for w in [array![[0.0, 1.0]], array![[1.0, -0.5]], array![[1.0, -1.0]]] {
+            assert_eq!(orchestrator.stop_reason().await, None);
+            train_round(&orchestrator, weights(w, array![0.0])).await;
+        }
+
+        let accuracies: Vec<f32> = orchestrator
+            .round_history
+            .read()
+            .await
+            .iter()
+            .map(|round| round.round_metrics.validation_accuracy.unwrap())
+            .collect();
+        assert_eq!(accuracies, vec![0.5, 0.625, 1.0]);
+        assert!(orchestrator.round_history.read().await.iter().all(|r| r.round_metrics.validation_loss.is_some()));
+        assert_eq!(orchestrator.get_federation_status().await.unwrap().global_model_accuracy, 1.0);
+
+        assert_eq!(orchestrator.stop_reason().await, Some(StopReason::TargetAccuracyReached { accuracy: 1.0 }));
+        assert!(orchestrator.start_training_round().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_training_stops_when_improvement_stalls() {
+        let mut config = test_config();
+        config.training_parameters.convergence_threshold = 0.05;
+        let orchestrator = FederatedLearningOrchestrator::with_validation_set(config, comparison_validation_set())
+            .await
+            .unwrap();
+        orchestrator.register_participant(participant("client-a")).await.unwrap();
+        orchestrator.register_participant(participant("client-b")).await.unwrap();
+
+        train_round(&orchestrator, weights(array![[1.0, -0.5]], array![0.0])).await;
+        assert_eq!(orchestrator.stop_reason().await, None);
+        train_round(&orchestrator, weights(array![[1.0, -0.5]], array![0.0])).await;
+        assert_eq!(
+            orchestrator.stop_reason().await,
+            Some(StopReason::Converged { accuracy: 0.625, improvement: 0.0 })
+        );
+    }
+
+    fn empty_weights() -> ModelWeights {
+        weights(Array2::zeros((0, 0)), Array1::zeros(0))
+    }
+
+    #[tokio::test]
+    async fn test_secure_aggregation_hides_updates_but_recovers_sum() {
+        let mut config = test_config();
+        config.aggregation_strategy = AggregationStrategy::SecureAggregation;
+        config.training_parameters.min_clients = 3;
+        let orchestrator = FederatedLearningOrchestrator::new(config).await.unwrap();
+
+        let clients: Vec<SecureAggregationClient> =
+            ["client-a", "client-b", "client-c"].into_iter().map(SecureAggregationClient::new).collect();
+        for client in &clients {
+            orchestrator.register_participant(participant(client.client_id())).await.unwrap();
+        }
+        let cohort_keys: HashMap<String, u64> =
+            clients.iter().map(|c| (c.client_id().to_string(), c.public_key())).collect();
+        let plain = [
+            weights(array![[1.0, 2.0]], array![0.5]),
+            weights(array![[3.0, -2.0]], array![1.5]),
+            weights(array![[-1.0, 6.0]], array![-0.5]),
+        ];
+
+        let round = orchestrator.start_training_round().await.unwrap();
+        for (client, model) in clients.iter().zip(&plain) {
+            let masked = client.mask(round.round_id, model, &cohort_keys);
+            // On its own a masked update looks nothing like the weights
+            let decoded: Vec<f32> = masked
+                .values
+                .iter()
+                .map(|v| (*v as i64 as f64 / SECURE_AGG_FIXED_POINT_SCALE) as f32)
+                .collect();
+            let original = flatten_tensors(&model.weights, &model.biases);
+            assert!(decoded.iter().zip(&original).all(|(d, o)| (d - o).abs() > 1.0));
+
+            let mut update = client_update(round.round_id, client.client_id(), empty_weights());
+            update.masked_weights = Some(masked);
+            orchestrator.receive_client_update(update).await.unwrap();
+        }
+
+        let global = orchestrator.global_model.read().await.weights.clone();
+        let expected_w = array![[1.0, 2.0]];
+        let expected_b = array![0.5];
+        assert!(global.weights[0].iter().zip(&expected_w).all(|(a, b)| (a - b).abs() < 1e-5));
+        assert!(global.biases[0].iter().zip(&expected_b).all(|(a, b)| (a - b).abs() < 1e-5));
+    }
+
+    #[test]
+    fn test_secure_aggregation_recovers_from_dropout() {
+        let clients: Vec<SecureAggregationClient> =
+            ["a", "b", "c", "d"].into_iter().map(SecureAggregationClient::new).collect();
+        let cohort_keys: HashMap<String, u64> =
+            clients.iter().map(|c| (c.client_id().to_string(), c.public_key())).collect();
+        let models = [
+            weights(array![[2.0]], array![1.0]),
+            weights(array![[4.0]], array![-1.0]),
+            weights(array![[6.0]], array![3.0]),
+            weights(array![[100.0]], array![100.0]),
+        ];
+
+        let mut round = SecureAggregationRound::new(7, cohort_keys.keys().cloned());
+        // "d" masks but never submits
+        for (client, model) in clients.iter().zip(&models).take(3) {
+            round.submit(client.mask(7, model, &cohort_keys)).unwrap();
+        }
+        assert_eq!(round.dropped_clients(), vec!["d".to_string()]);
+        assert!(round.unmask_mean().is_err());
+
+        for client in clients.iter().take(3) {
+            round.reveal(client.reveal_seed("d", cohort_keys["d"]));
+        }
+        let mean = round.unmask_mean().unwrap();
+        assert!((mean.weights[0][[0, 0]] - 4.0).abs() < 1e-5);
+        assert!((mean.biases[0][0] - 1.0).abs() < 1e-5);
+
+        // Updates from outside the cohort are refused
+        let outsider = SecureAggregationClient::new("e");
+        assert!(round.submit(outsider.mask(7, &models[0], &cohort_keys)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_status_reflects_round_in_progress() {
+        let mut config = test_config();
+        config.training_parameters.min_clients = 4;
+        let orchestrator = FederatedLearningOrchestrator::with_validation_set(config, comparison_validation_set())
+            .await
+            .unwrap();
+        let clients = ["client-a", "client-b", "client-c", "client-d"];
+        for client_id in clients {
+            orchestrator.register_participant(participant(client_id)).await.unwrap();
+        }
+
+        let idle = orchestrator.get_federation_status().await.unwrap();
+        assert_eq!((idle.current_round, idle.round_status, idle.latest_validation), (None, None, None));
+
+        let round = orchestrator.start_training_round().await.unwrap();
+        let model = weights(array![[1.0, -0.5]], array![0.0]);
+        for client_id in &clients[..2] {
+            let update = client_update(round.round_id, client_id, model.clone());
+            // Status reads alongside an update without deadlocking
+            let (status, received) = tokio::time::timeout(
+                std::time::Duration::from_secs(5),
+                async { tokio::join!(orchestrator.get_federation_status(), orchestrator.receive_client_update(update)) },
+            )
+            .await
+            .unwrap();
+            status.unwrap();
+            received.unwrap();
+        }
+
+        let status = orchestrator.get_federation_status().await.unwrap();
+        assert_eq!(status.current_round, Some(1));
+        assert_eq!(status.round_status, Some(RoundStatus::InProgress));
+        assert_eq!(status.round_clients, 4);
+        assert_eq!(status.updates_received, 2);
+        assert_eq!(status.total_participants, 4);
+        assert_eq!(status.total_rounds_completed, 0);
+        assert_eq!(status.privacy_budget_remaining, 1.0);
+
+        for client_id in &clients[2..] {
+            orchestrator.receive_client_update(client_update(round.round_id, client_id, model.clone())).await.unwrap();
+        }
+        let status = orchestrator.get_federation_status().await.unwrap();
+        assert_eq!((status.current_round, status.updates_received), (None, 0));
+        assert_eq!(status.round_status, Some(RoundStatus::Completed));
+        assert_eq!(status.total_rounds_completed, 1);
+        assert_eq!(status.latest_validation.map(|v| v.accuracy), Some(0.625));
+        assert!(status.privacy_budget_remaining < 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_round_closes_on_survivors_after_deadline() {
+        let orchestrator = FederatedLearningOrchestrator::new(test_config()).await.unwrap();
+        for client_id in ["client-a", "client-b", "straggler"] {
+            orchestrator.register_participant(participant(client_id)).await.unwrap();
+        }
+        let model = weights(array![[1.0, 2.0]], array![0.0]);
+
+        let round = orchestrator.start_training_round().await.unwrap();
+        assert_eq!(round.selected_clients.len(), 3);
+        for client_id in ["client-a", "client-b"] {
+            orchestrator.receive_client_update(client_update(round.round_id, client_id, model.clone())).await.unwrap();
+        }
+        // min_clients have reported, but the straggler still has time
+        assert!(orchestrator.active_round.read().await.is_some());
+        let before = round.deadline - chrono::Duration::seconds(1);
+        assert!(matches!(orchestrator.enforce_round_deadline_at(before).await.unwrap(), RoundDeadlineOutcome::Pending));
+
+        let after = round.deadline + chrono::Duration::seconds(1);
+        match orchestrator.enforce_round_deadline_at(after).await.unwrap() {
+            RoundDeadlineOutcome::Aggregated(update) => assert_eq!(update.participating_clients.len(), 2),
+            other => panic!("expected aggregation, got {:?}", other),
+        }
+        {
+            let participants = orchestrator.participants.read().await;
+            let straggler = &participants["straggler"].participation_history;
+            assert_eq!((straggler.failed_rounds, straggler.reliability_score), (1, DROPOUT_RELIABILITY_PENALTY));
+            assert_eq!(participants["client-a"].participation_history.successful_rounds, 1);
+        }
+
+        // Too few updates: the round fails and a new one is selected
+        let round = orchestrator.start_training_round().await.unwrap();
+        orchestrator.receive_client_update(client_update(round.round_id, "client-a", model.clone())).await.unwrap();
+        let after = round.deadline + chrono::Duration::seconds(1);
+        match orchestrator.enforce_round_deadline_at(after).await.unwrap() {
+            RoundDeadlineOutcome::Restarted { failed_round_id, next_round } => {
+                assert_eq!(failed_round_id, round.round_id);
+                let next_round = next_round.unwrap();
+                assert_eq!(next_round.round_id, round.round_id + 1);
+                // Reliable clients come first in the new selection
+                assert_eq!(next_round.selected_clients[0], "client-a");
+                assert_eq!(next_round.selected_clients.last().unwrap(), "straggler");
+            }
+            other => panic!("expected a restarted round, got {:?}", other),
+        }
+        let failed = orchestrator.round_history.read().await.back().cloned().unwrap();
+        assert_eq!(failed.round_status, RoundStatus::Failed);
+        assert!(failed.aggregated_update.is_none());
+        assert_eq!(orchestrator.participants.read().await["straggler"].participation_history.failed_rounds, 2);
+        assert_eq!(orchestrator.participants.read().await["client-b"].participation_history.failed_rounds, 1);
+    }
+
+    #[tokio::test]
+    async fn test_adaptive_clip_bound_converges_to_norm_median() {
+        let mechanism = PrivacyMechanism::DifferentialPrivacy {
+            epsilon: 10.0,
+            delta: 1e-5,
+            mechanism: DPMechanism::AdaptiveClipping,
+        };
+        let mut engine = PrivacyEngine::new(&mechanism).await.unwrap();
+        assert_eq!(engine.clip_bound, Some(ADAPTIVE_CLIPPING_INITIAL_BOUND));
+
+        // Client norms spread evenly over 0.1..=10.0, so the median is 5.05
+        let norms: Vec<f32> = (1..=100).map(|i| i as f32 * 0.1).collect();
+        let median_norm = median(&norms);
+        let relative_error = |bound: Option<f32>| (bound.unwrap() - median_norm).abs() / median_norm;
+
+        let initial_error = relative_error(engine.clip_bound);
+        for _ in 0..10 {
+            engine.update_clip_bound(&norms);
+        }
+        let early_error = relative_error(engine.clip_bound);
+        for _ in 0..50 {
+            engine.update_clip_bound(&norms);
+        }
+        let final_error = relative_error(engine.clip_bound);
+        assert!(early_error < initial_error);
+        assert!(final_error < early_error);
+        assert!(final_error < 0.05, "bound {:?} is not near {}", engine.clip_bound, median_norm);
+
+        // A fixed-threshold mechanism has no adaptive bound to move
+        let mut fixed = PrivacyEngine::new(&test_config().privacy_mechanism).await.unwrap();
+        fixed.update_clip_bound(&norms);
+        assert_eq!(fixed.clip_bound, None);
+    }
+
+    #[tokio::test]
+    async fn test_adaptive_clip_bound_is_audited_and_distributed() {
+        let mut config = test_config();
+        config.privacy_mechanism = PrivacyMechanism::DifferentialPrivacy {
+            epsilon: 10.0,
+            delta: 1e-5,
+            mechanism: DPMechanism::AdaptiveClipping,
+        };
+        let orchestrator = FederatedLearningOrchestrator::new(config).await.unwrap();
+        orchestrator.register_participant(participant("client-a")).await.unwrap();
+        orchestrator.register_participant(participant("client-b")).await.unwrap();
+
+        // Gradients of norm 5 are all above the initial bound of 1, so the
+        // round both flags them and raises the bound
+        train_round(&orchestrator, weights(array![[3.0, 4.0]], array![0.0])).await;
+        let round = orchestrator.round_history.read().await.back().cloned().unwrap();
+        for update in round.client_updates.values() {
+            assert_eq!(update.privacy_audit.clipping_bound, Some(ADAPTIVE_CLIPPING_INITIAL_BOUND));
+            assert_eq!(update.privacy_audit.potential_privacy_violations.len(), 1);
+        }
+
+        let bound = orchestrator.privacy_engine.read().await.clip_bound.unwrap();
+        assert!(bound > ADAPTIVE_CLIPPING_INITIAL_BOUND);
+        let training_config = orchestrator.create_client_training_config("client-a").await.unwrap();
+        assert_eq!(training_config.clipping_threshold, Some(bound));
+    }
+
+    struct DenyingConsensus;
+
+    #[async_trait::async_trait]
+    impl ConsensusValidator for DenyingConsensus {
+        async fn validate_aggregated_update(&self, _update: &AggregatedUpdate, _client_updates: &[FederatedModelUpdate]) -> Result<bool> {
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_denied_consensus_leaves_global_model_unchanged() {
+        let mut config = test_config();
+        config.consensus_mechanism = ConsensusMechanism::PracticalByzantineFaultTolerance;
+        config.consensus_failure_policy = ConsensusFailurePolicy::Retry { max_attempts: 2 };
+        let orchestrator = FederatedLearningOrchestrator::new(config).await.unwrap();
+        orchestrator.register_participant(participant("client-a")).await.unwrap();
+        orchestrator.register_participant(participant("client-b")).await.unwrap();
+        orchestrator.set_consensus_validator(Box::new(DenyingConsensus)).await;
+
+        let initial = orchestrator.global_model.read().await.get_weights().await.unwrap();
+        let submit_round = || async {
+            let round = orchestrator.active_round.read().await.clone().unwrap();
+            orchestrator
+                .receive_client_update(client_update(round.round_id, "client-a", weights(array![[5.0, 5.0]], array![5.0])))
+                .await
+                .unwrap();
+            orchestrator
+                .receive_client_update(client_update(round.round_id, "client-b", weights(array![[5.0, 5.0]], array![5.0])))
+                .await
+                .unwrap_err()
+        };
+
+        orchestrator.start_training_round().await.unwrap();
+        let error = submit_round().await;
+        assert!(error.to_string().contains("Consensus was not reached for round 1"));
+
+        let failed = orchestrator.round_history.read().await.back().cloned().unwrap();
+        assert!(!failed.consensus_achieved);
+        assert_eq!(failed.round_status, RoundStatus::Failed);
+        assert!(failed.aggregated_update.is_none());
+        let global_model = orchestrator.global_model.read().await;
+        assert_eq!(global_model.version, 1);
+        assert_eq!(global_model.weights.weights, initial.weights);
+        assert_eq!(global_model.weights.biases, initial.biases);
+        drop(global_model);
+
+        // The policy allows a second attempt, then gives up
+        assert_eq!(orchestrator.active_round.read().await.as_ref().map(|r| r.round_id), Some(2));
+        submit_round().await;
+        assert!(orchestrator.active_round.read().await.is_none());
+        assert_eq!(
+            orchestrator.stop_reason().await,
+            Some(StopReason::ConsensusFailed { round_id: 2, attempts: 2 })
+        );
+        assert_eq!(orchestrator.global_model.read().await.weights.biases, initial.biases);
+        assert!(orchestrator.start_training_round().await.is_err());
+    }
+}
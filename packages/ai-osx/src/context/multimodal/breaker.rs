@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BreakerConfig {
+    /// Consecutive failures that open the breaker
+    pub failure_threshold: u32,
+    /// How long an open breaker rejects inputs before letting a trial through
+    pub cooldown_ms: u64,
+}
+
+impl Default for BreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown_ms: 30_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BreakerState {
+    /// Inputs flow normally
+    Closed,
+    /// Inputs are rejected until the cooldown elapses
+    Open,
+    /// Cooldown elapsed and one trial input is in flight; its result
+    /// decides whether to close or reopen, and other inputs are rejected
+    HalfOpen,
+}
+
+/// Per-modality circuit breaker: trips after `failure_threshold`
+/// consecutive failures, rejects inputs for `cooldown_ms`, then half-opens
+/// to let exactly one trial through: its success closes the breaker again
+/// and its failure reopens it
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    config: BreakerConfig,
+    state: BreakerState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: BreakerConfig) -> Self {
+        Self {
+            config,
+            state: BreakerState::Closed,
+            consecutive_failures: 0,
+            opened_at: None,
+        }
+    }
+
+    pub fn state(&self) -> BreakerState {
+        self.state
+    }
+
+    /// Whether an input may be processed at `now`. An open breaker whose
+    /// cooldown has elapsed moves to half-open and lets this one input
+    /// through as the trial; everything else waits for its outcome.
+    pub fn allow(&mut self, now: Instant) -> bool {
+        match self.state {
+            BreakerState::Closed => true,
+            BreakerState::HalfOpen => false,
+            BreakerState::Open => {
+                let cooldown = Duration::from_millis(self.config.cooldown_ms);
+                if self.opened_at.map_or(true, |opened_at| now.duration_since(opened_at) >= cooldown) {
+                    self.state = BreakerState::HalfOpen;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    pub fn record_success(&mut self) {
+        self.state = BreakerState::Closed;
+        self.consecutive_failures = 0;
+        self.opened_at = None;
+    }
+
+    pub fn record_failure(&mut self, now: Instant) {
+        self.consecutive_failures += 1;
+        if self.state == BreakerState::HalfOpen || self.consecutive_failures >= self.config.failure_threshold.max(1) {
+            self.state = BreakerState::Open;
+            self.opened_at = Some(now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breaker_trips_and_recovers() {
+        let mut breaker = CircuitBreaker::new(BreakerConfig {
+            failure_threshold: 3,
+            cooldown_ms: 1_000,
+        });
+        let start = Instant::now();
+
+        for _ in 0..2 {
+            assert!(breaker.allow(start));
+            breaker.record_failure(start);
+        }
+        assert_eq!(breaker.state(), BreakerState::Closed);
+
+        breaker.record_failure(start);
+        assert_eq!(breaker.state(), BreakerState::Open);
+        assert!(!breaker.allow(start + Duration::from_millis(500)));
+
+        // After the cooldown a trial is let through; failing it reopens at once
+        assert!(breaker.allow(start + Duration::from_millis(1_000)));
+        assert_eq!(breaker.state(), BreakerState::HalfOpen);
+        assert!(!breaker.allow(start + Duration::from_millis(1_000)));
+        breaker.record_failure(start + Duration::from_millis(1_000));
+        assert_eq!(breaker.state(), BreakerState::Open);
+        assert!(!breaker.allow(start + Duration::from_millis(1_500)));
+
+        assert!(breaker.allow(start + Duration::from_millis(2_000)));
+        breaker.record_success();
+        assert_eq!(breaker.state(), BreakerState::Closed);
+        breaker.record_failure(start + Duration::from_millis(2_000));
+        assert_eq!(breaker.state(), BreakerState::Closed);
+    }
+}
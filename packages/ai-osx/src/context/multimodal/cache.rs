@@ -0,0 +1,144 @@
+use super::{ModalInput, ModalityType, ProcessedModal};
+use std::collections::{HashMap, VecDeque};
+
+/// Metadata key that makes an input bypass the cache in both directions
+pub const NO_CACHE_KEY: &str = "no_cache";
+
+pub type ContentHash = [u8; 32];
+
+impl ModalInput {
+    /// SHA-256 of the raw input bytes
+    pub fn content_hash(&self) -> ContentHash {
+        let digest = ring::digest::digest(&ring::digest::SHA256, &self.data);
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(digest.as_ref());
+        hash
+    }
+
+    /// Whether the sender asked for fresh processing via `no_cache`
+    pub fn bypasses_cache(&self) -> bool {
+        self.metadata
+            .get(NO_CACHE_KEY)
+            .map_or(false, |value| value != "false" && value != "0")
+    }
+}
+
+/// Bounded least-recently-used cache of processor output, keyed by modality
+/// and content hash so identical bytes sent as different modalities don't
+/// collide
+pub struct ProcessedCache {
+    capacity: usize,
+    entries: HashMap<(ModalityType, ContentHash), ProcessedModal>,
+    /// Keys from least to most recently used
+    order: VecDeque<(ModalityType, ContentHash)>,
+}
+
+impl ProcessedCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Cached output for `input`, re-addressed to it: a fresh id, its
+    /// `input_id` and metadata, and zero processing time
+    pub fn get(&mut self, input: &ModalInput, hash: &ContentHash) -> Option<ProcessedModal> {
+        let key = (input.modality.clone(), *hash);
+        let cached = self.entries.get(&key)?;
+        let hit = ProcessedModal {
+            id: uuid::Uuid::new_v4(),
+            input_id: input.id,
+            metadata: input.metadata.clone(),
+            processing_time_ms: 0,
+            ..cached.clone()
+        };
+        self.touch(key);
+        Some(hit)
+    }
+
+    pub fn insert(&mut self, hash: ContentHash, processed: ProcessedModal) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let key = (processed.modality.clone(), hash);
+        if self.entries.insert(key.clone(), processed).is_some() {
+            self.touch(key);
+            return;
+        }
+
+        self.order.push_back(key);
+        while self.entries.len() > self.capacity {
+            match self.order.pop_front() {
+                Some(oldest) => {
+                    self.entries.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+    }
+
+    fn touch(&mut self, key: (ModalityType, ContentHash)) {
+        if let Some(position) = self.order.iter().position(|k| *k == key) {
+            self.order.remove(position);
+        }
+        self.order.push_back(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn processed(modality: ModalityType, confidence: f32) -> ProcessedModal {
+        ProcessedModal {
+            id: uuid::Uuid::new_v4(),
+            input_id: uuid::Uuid::new_v4(),
+            modality,
+            features: vec![confidence],
+            embeddings: vec![confidence],
+            confidence,
+            metadata: HashMap::new(),
+            processing_time_ms: 5,
+        }
+    }
+
+    fn input(data: &[u8]) -> ModalInput {
+        ModalInput {
+            id: uuid::Uuid::new_v4(),
+            modality: ModalityType::Image,
+            data: data.to_vec(),
+            metadata: HashMap::new(),
+            timestamp: chrono::Utc::now(),
+            source: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_lru_eviction_and_modality_keys() {
+        let mut cache = ProcessedCache::new(2);
+        let (a, b, c) = (input(b"a"), input(b"b"), input(b"c"));
+        assert_ne!(a.content_hash(), b.content_hash());
+
+        cache.insert(a.content_hash(), processed(ModalityType::Image, 0.1));
+        cache.insert(b.content_hash(), processed(ModalityType::Image, 0.2));
+        // Using `a` makes `b` the eviction candidate
+        let hit = cache.get(&a, &a.content_hash()).unwrap();
+        assert_eq!(hit.input_id, a.id);
+        assert_eq!(hit.processing_time_ms, 0);
+
+        cache.insert(c.content_hash(), processed(ModalityType::Image, 0.3));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&b, &b.content_hash()).is_none());
+        assert!(cache.get(&a, &a.content_hash()).is_some());
+
+        let same_bytes_as_text = ModalInput { modality: ModalityType::Text, ..input(b"a") };
+        assert!(cache.get(&same_bytes_as_text, &same_bytes_as_text.content_hash()).is_none());
+    }
+}
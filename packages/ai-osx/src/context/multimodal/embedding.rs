@@ -0,0 +1,104 @@
+use super::{ModalityType, ProcessedModal};
+
+/// Length every `ProcessedModal.embeddings` must have unless
+/// `PipelineConfig.embedding_dim` says otherwise, so fusion compares
+/// embeddings of different modalities in one space
+pub const EMBEDDING_DIM: usize = 512;
+
+/// Fixed random projection from a processor's native embedding size into the
+/// shared dimension. Entries are ±1/√output_dim drawn from a seeded
+/// generator, so a given seed and shape always produce the same matrix and
+/// distances are approximately preserved.
+pub struct EmbeddingProjection {
+    input_dim: usize,
+    output_dim: usize,
+    /// Row-major `output_dim x input_dim`
+    weights: Vec<f32>,
+}
+
+impl EmbeddingProjection {
+    pub fn new(input_dim: usize, output_dim: usize, seed: u64) -> Self {
+        let scale = 1.0 / (output_dim.max(1) as f32).sqrt();
+        let mut state = seed;
+        let weights = (0..input_dim * output_dim)
+            .map(|_| if splitmix64(&mut state) & 1 == 0 { scale } else { -scale })
+            .collect();
+
+        Self { input_dim, output_dim, weights }
+    }
+
+    pub fn output_dim(&self) -> usize {
+        self.output_dim
+    }
+
+    /// Project a native embedding; extra input values are ignored and
+    /// missing ones count as zero
+    pub fn project(&self, native: &[f32]) -> Vec<f32> {
+        let native = &native[..native.len().min(self.input_dim)];
+        self.weights
+            .chunks_exact(self.input_dim.max(1))
+            .take(self.output_dim)
+            .map(|row| row.iter().zip(native).map(|(w, x)| w * x).sum())
+            .collect()
+    }
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddingDimensionMismatch {
+    pub modality: ModalityType,
+    pub expected: usize,
+    pub actual: usize,
+}
+
+impl std::fmt::Display for EmbeddingDimensionMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} processor produced a {}-dimensional embedding, expected {}",
+            self.modality, self.actual, self.expected
+        )
+    }
+}
+
+impl std::error::Error for EmbeddingDimensionMismatch {}
+
+/// Reject processor output whose embedding breaks the shared-dimension contract
+pub fn validate_embedding(processed: &ProcessedModal, expected: usize) -> Result<(), EmbeddingDimensionMismatch> {
+    if processed.embeddings.len() == expected {
+        Ok(())
+    } else {
+        Err(EmbeddingDimensionMismatch {
+            modality: processed.modality.clone(),
+            expected,
+            actual: processed.embeddings.len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_projection_is_deterministic_and_fixed_size() {
+        let native: Vec<f32> = (0..768).map(|i| (i as f32 * 0.01).sin()).collect();
+        let a = EmbeddingProjection::new(768, EMBEDDING_DIM, 7);
+        let b = EmbeddingProjection::new(768, EMBEDDING_DIM, 7);
+
+        let projected = a.project(&native);
+        assert_eq!(projected.len(), EMBEDDING_DIM);
+        assert_eq!(projected, b.project(&native));
+        assert_ne!(projected, EmbeddingProjection::new(768, EMBEDDING_DIM, 8).project(&native));
+
+        // Short inputs are zero-extended rather than rejected
+        assert_eq!(a.project(&native[..10]).len(), EMBEDDING_DIM);
+    }
+}
@@ -1,16 +1,159 @@
 use super::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use serde_json::Value;
 
-pub struct FeatureExtractor;
+/// Extracts numeric features from raw modal data. Image, audio and video
+/// extraction is language independent; text extraction looks words up in the
+/// extractor's `TextLexicon`.
+#[derive(Debug, Clone)]
+pub struct FeatureExtractor {
+    lexicon: TextLexicon,
+    char_ngrams: bool,
+}
+
+/// Word lists used by the text features. Entries are matched against
+/// lowercased tokens, so they should be lowercase themselves.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextLexicon {
+    pub stopwords: HashSet<String>,
+    pub positive: HashSet<String>,
+    pub negative: HashSet<String>,
+    pub nouns: HashSet<String>,
+    pub verbs: HashSet<String>,
+    pub adjectives: HashSet<String>,
+    /// Word endings that mark an adverb, e.g. "ly" in English
+    pub adverb_suffixes: Vec<String>,
+}
+
+fn word_set(words: &[&str]) -> HashSet<String> {
+    words.iter().map(|word| word.to_string()).collect()
+}
+
+impl TextLexicon {
+    pub fn english() -> Self {
+        Self {
+            stopwords: word_set(&[
+                "a", "an", "the", "and", "or", "but", "if", "of", "at", "by", "for", "with", "about",
+                "to", "from", "in", "on", "is", "are", "was", "were", "it", "this", "that", "these",
+                "those", "i", "you", "he", "she", "we", "they", "not", "no", "as", "so", "than",
+            ]),
+            positive: word_set(&[
+                "good", "great", "excellent", "amazing", "wonderful", "fantastic",
+                "love", "best", "perfect", "awesome", "brilliant", "outstanding",
+                "beautiful", "incredible", "superb", "magnificent", "remarkable"
+            ]),
+            negative: word_set(&[
+                "bad", "terrible", "awful", "horrible", "disappointing", "worst",
+                "hate", "disgusting", "pathetic", "useless", "boring", "stupid",
+                "ugly", "annoying", "frustrating", "painful", "sad", "angry"
+            ]),
+            nouns: word_set(&["person", "people", "time", "way", "day", "man", "thing", "woman", "life", "child", "world", "school", "state", "family", "student", "group", "country", "problem", "hand", "part", "place", "case", "week", "company", "system", "program", "question", "work", "government", "number", "night", "point", "home", "water", "room", "mother", "area", "money", "story", "fact", "month", "lot", "right", "study", "book", "eye", "job", "word", "business", "issue", "side", "kind", "head", "house", "service", "friend", "father", "power", "hour", "game", "line", "end", "member", "law", "car", "city", "community", "name", "president", "team", "minute", "idea", "kid", "body", "information", "back", "parent", "face", "others", "level", "office", "door", "health", "person", "art", "war", "history", "party", "within", "result", "change", "morning", "reason", "research", "girl", "guy", "moment", "air", "teacher", "force", "education"]),
+            verbs: word_set(&["be", "have", "do", "say", "get", "make", "go", "know", "take", "see", "come", "think", "look", "want", "give", "use", "find", "tell", "ask", "work", "seem", "feel", "try", "leave", "call", "need", "become", "would", "could", "should", "might", "must", "will", "can", "may", "shall", "ought", "dare", "used", "going", "being", "having", "doing", "saying", "getting", "making", "knowing", "taking", "seeing", "coming", "thinking", "looking", "wanting", "giving", "using", "finding", "telling", "asking", "working", "seeming", "feeling", "trying", "leaving", "calling", "needing", "becoming"]),
+            adjectives: word_set(&["good", "new", "first", "last", "long", "great", "little", "own", "other", "old", "right", "big", "high", "different", "small", "large", "next", "early", "young", "important", "few", "public", "bad", "same", "able", "human", "local", "sure", "without", "common", "poor", "possible", "social", "only", "national", "black", "white", "far", "hard", "open", "red", "easy", "strong", "real", "best", "left", "short", "clear", "hot", "cold", "nice", "beautiful", "happy", "sad", "angry", "tired", "hungry", "thirsty", "full", "empty", "clean", "dirty", "safe", "dangerous", "fast", "slow", "cheap", "expensive"]),
+            adverb_suffixes: vec!["ly".to_string()],
+        }
+    }
+}
+
+impl Default for FeatureExtractor {
+    fn default() -> Self {
+        Self::new(TextLexicon::english())
+    }
+}
+
+/// Gradient orientation bins for the edge histogram, covering 0–180°
+const EDGE_ORIENTATION_BINS: usize = 8;
+const EDGE_ORIENTATION_BIN_WIDTH: f32 = std::f32::consts::PI / EDGE_ORIENTATION_BINS as f32;
+
+/// Scanlines per parallel chunk when accumulating colour statistics
+const COLOR_ROWS_PER_CHUNK: usize = 16;
+
+/// Per-channel sums and the 8×8×8 colour histogram of a set of pixels.
+/// Everything is an integer count, so partial results from parallel chunks
+/// merge to exactly the sequential totals.
+#[derive(Debug, Clone, PartialEq)]
+struct ColorAccumulator {
+    sums: [u64; 3],
+    squared_sums: [u64; 3],
+    /// Indexed by `r_bin * 64 + g_bin * 8 + b_bin`
+    histogram: Vec<u32>,
+}
+
+impl ColorAccumulator {
+    fn new() -> Self {
+        Self {
+            sums: [0; 3],
+            squared_sums: [0; 3],
+            histogram: vec![0; 512],
+        }
+    }
+
+    /// Accumulate packed RGB bytes in order
+    fn add_pixels(mut self, rgb: &[u8]) -> Self {
+        for pixel in rgb.chunks_exact(3) {
+            for (channel, &value) in pixel.iter().enumerate() {
+                let value = value as u64;
+                self.sums[channel] += value;
+                self.squared_sums[channel] += value * value;
+            }
+            let bin = (pixel[0] / 32) as usize * 64 + (pixel[1] / 32) as usize * 8 + (pixel[2] / 32) as usize;
+            self.histogram[bin] += 1;
+        }
+        self
+    }
+
+    fn merge(mut self, other: Self) -> Self {
+        for (sum, other_sum) in self.sums.iter_mut().zip(other.sums) {
+            *sum += other_sum;
+        }
+        for (sum, other_sum) in self.squared_sums.iter_mut().zip(other.squared_sums) {
+            *sum += other_sum;
+        }
+        for (count, other_count) in self.histogram.iter_mut().zip(other.histogram) {
+            *count += other_count;
+        }
+        self
+    }
+
+    /// Single pass over the image, with chunks of scanlines accumulated in
+    /// parallel and reduced
+    fn from_image(image: &image::RgbImage) -> Self {
+        use rayon::prelude::*;
+
+        let chunk_len = (image.width() as usize * 3 * COLOR_ROWS_PER_CHUNK).max(3);
+        image
+            .as_raw()
+            .par_chunks(chunk_len)
+            .fold(Self::new, Self::add_pixels)
+            .reduce(Self::new, Self::merge)
+    }
+}
 
 impl FeatureExtractor {
+    pub fn new(lexicon: TextLexicon) -> Self {
+        Self {
+            lexicon,
+            char_ngrams: true,
+        }
+    }
+
+    /// Enable or disable the character n-gram entropy features
+    pub fn with_char_ngrams(mut self, enabled: bool) -> Self {
+        self.char_ngrams = enabled;
+        self
+    }
+
+    /// Text features using the English lexicon
     pub fn extract_text_features(text: &str) -> HashMap<String, f32> {
+        Self::default().text_features(text)
+    }
+
+    pub fn text_features(&self, text: &str) -> HashMap<String, f32> {
         let mut features = HashMap::new();
         
         // Basic linguistic features
         let words: Vec<&str> = text.split_whitespace().collect();
-        let sentences = text.split('.').filter(|s| !s.trim().is_empty()).count();
+        let sentences = text.split(['.', '!', '?']).filter(|s| !s.trim().is_empty()).count();
         let characters = text.len();
         
         features.insert("word_count".to_string(), words.len() as f32);
@@ -34,12 +177,9 @@ impl FeatureExtractor {
         };
         features.insert("lexical_diversity".to_string(), lexical_diversity);
 
-        // Readability metrics (simplified Flesch-Kincaid)
-        let avg_sentence_length = if sentences > 0 {
-            words.len() as f32 / sentences as f32
-        } else {
-            0.0
-        };
+        // Readability metrics (simplified Flesch-Kincaid). Text without
+        // terminal punctuation is still at least one sentence.
+        let avg_sentence_length = words.len() as f32 / sentences.max(1) as f32;
         
         let syllable_count = Self::estimate_syllables(&words);
         let avg_syllables_per_word = if !words.is_empty() {
@@ -49,16 +189,32 @@ impl FeatureExtractor {
         };
         
         let flesch_score = 206.835 - (1.015 * avg_sentence_length) - (84.6 * avg_syllables_per_word);
-        features.insert("readability_score".to_string(), flesch_score);
+        features.insert("readability_score".to_string(), Self::clamp_readability(flesch_score));
+
+        // Lexicon features work on tokens rather than whitespace-separated
+        // words so punctuation doesn't hide matches
+        let tokens = Self::tokenize(text);
+        let stopword_ratio = if !tokens.is_empty() {
+            tokens.iter().filter(|token| self.lexicon.stopwords.contains(*token)).count() as f32 / tokens.len() as f32
+        } else {
+            0.0
+        };
+        features.insert("stopword_ratio".to_string(), stopword_ratio);
 
         // Sentiment features
-        let sentiment_scores = Self::analyze_sentiment(&words);
+        let sentiment_scores = self.analyze_sentiment(&tokens);
         features.extend(sentiment_scores);
 
         // Part of speech estimation
-        let pos_features = Self::estimate_pos_distribution(&words);
+        let pos_features = self.estimate_pos_distribution(&tokens);
         features.extend(pos_features);
 
+        // Character n-grams, which don't depend on the lexicon
+        if self.char_ngrams {
+            features.insert("char_bigram_entropy".to_string(), Self::char_ngram_entropy(text, 2));
+            features.insert("char_trigram_entropy".to_string(), Self::char_ngram_entropy(text, 3));
+        }
+
         // Named entity estimation
         let ne_features = Self::estimate_named_entities(&text);
         features.extend(ne_features);
@@ -168,6 +324,18 @@ impl FeatureExtractor {
     }
 
     // Text analysis helper methods
+
+    /// Limit a Flesch reading ease score to the interpretable 0 (very hard)
+    /// to 100 (very easy) range; run-on text or long tokens such as URLs
+    /// otherwise push it far below zero
+    fn clamp_readability(score: f32) -> f32 {
+        if score.is_nan() {
+            0.0
+        } else {
+            score.clamp(0.0, 100.0)
+        }
+    }
+
     fn estimate_syllables(words: &[&str]) -> usize {
         words.iter()
             .map(|word| {
@@ -194,28 +362,58 @@ impl FeatureExtractor {
             .sum()
     }
 
-    fn analyze_sentiment(words: &[&str]) -> HashMap<String, f32> {
-        let positive_words = [
-            "good", "great", "excellent", "amazing", "wonderful", "fantastic",
-            "love", "best", "perfect", "awesome", "brilliant", "outstanding",
-            "beautiful", "incredible", "superb", "magnificent", "remarkable"
-        ];
-        
-        let negative_words = [
-            "bad", "terrible", "awful", "horrible", "disappointing", "worst",
-            "hate", "disgusting", "pathetic", "useless", "boring", "stupid",
-            "ugly", "annoying", "frustrating", "painful", "sad", "angry"
-        ];
+    /// Lowercased runs of alphanumeric characters (apostrophes included),
+    /// in any script
+    fn tokenize(text: &str) -> Vec<String> {
+        text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+            .map(|token| token.trim_matches('\''))
+            .filter(|token| !token.is_empty())
+            .map(|token| token.to_lowercase())
+            .collect()
+    }
+
+    /// Shannon entropy of the character `n`-gram frequencies, normalised by
+    /// its maximum for the number of n-grams so the value is in 0..=1 for any
+    /// text length. Repetitive, structured text scores lower than random
+    /// characters in any script.
+    fn char_ngram_entropy(text: &str, n: usize) -> f32 {
+        let chars: Vec<char> = text
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+            .chars()
+            .collect();
+        if chars.len() <= n {
+            return 0.0;
+        }
+
+        let mut counts: HashMap<&[char], u32> = HashMap::new();
+        for ngram in chars.windows(n) {
+            *counts.entry(ngram).or_insert(0) += 1;
+        }
+
+        let total = (chars.len() - n + 1) as f32;
+        let entropy: f32 = counts
+            .values()
+            .map(|&count| {
+                let p = count as f32 / total;
+                -p * p.log2()
+            })
+            .sum();
+        entropy / total.log2()
+    }
 
-        let positive_count = words.iter()
-            .filter(|word| positive_words.contains(&word.to_lowercase().as_str()))
+    fn analyze_sentiment(&self, tokens: &[String]) -> HashMap<String, f32> {
+        let positive_count = tokens.iter()
+            .filter(|token| self.lexicon.positive.contains(*token))
             .count() as f32;
         
-        let negative_count = words.iter()
-            .filter(|word| negative_words.contains(&word.to_lowercase().as_str()))
+        let negative_count = tokens.iter()
+            .filter(|token| self.lexicon.negative.contains(*token))
             .count() as f32;
 
-        let total_words = words.len() as f32;
+        let total_words = tokens.len() as f32;
         
         let mut sentiment_features = HashMap::new();
         sentiment_features.insert("positive_word_ratio".to_string(), 
@@ -230,39 +428,26 @@ impl FeatureExtractor {
         sentiment_features
     }
 
-    fn estimate_pos_distribution(words: &[&str]) -> HashMap<String, f32> {
+    fn estimate_pos_distribution(&self, tokens: &[String]) -> HashMap<String, f32> {
         let mut pos_counts = HashMap::new();
         pos_counts.insert("noun_ratio".to_string(), 0.0);
         pos_counts.insert("verb_ratio".to_string(), 0.0);
         pos_counts.insert("adjective_ratio".to_string(), 0.0);
         pos_counts.insert("adverb_ratio".to_string(), 0.0);
 
-        // Simple heuristics for POS tagging
-        let common_nouns = ["person", "people", "time", "way", "day", "man", "thing", "woman", "life", "child", "world", "school", "state", "family", "student", "group", "country", "problem", "hand", "part", "place", "case", "week", "company", "system", "program", "question", "work", "government", "number", "night", "point", "home", "water", "room", "mother", "area", "money", "story", "fact", "month", "lot", "right", "study", "book", "eye", "job", "word", "business", "issue", "side", "kind", "head", "house", "service", "friend", "father", "power", "hour", "game", "line", "end", "member", "law", "car", "city", "community", "name", "president", "team", "minute", "idea", "kid", "body", "information", "back", "parent", "face", "others", "level", "office", "door", "health", "person", "art", "war", "history", "party", "within", "result", "change", "morning", "reason", "research", "girl", "guy", "moment", "air", "teacher", "force", "education"];
-        
-        let common_verbs = ["be", "have", "do", "say", "get", "make", "go", "know", "take", "see", "come", "think", "look", "want", "give", "use", "find", "tell", "ask", "work", "seem", "feel", "try", "leave", "call", "need", "become", "would", "could", "should", "might", "must", "will", "can", "may", "shall", "ought", "dare", "used", "going", "being", "having", "doing", "saying", "getting", "making", "knowing", "taking", "seeing", "coming", "thinking", "looking", "wanting", "giving", "using", "finding", "telling", "asking", "working", "seeming", "feeling", "trying", "leaving", "calling", "needing", "becoming"];
-        
-        let common_adjectives = ["good", "new", "first", "last", "long", "great", "little", "own", "other", "old", "right", "big", "high", "different", "small", "large", "next", "early", "young", "important", "few", "public", "bad", "same", "able", "human", "local", "sure", "without", "common", "poor", "possible", "social", "only", "national", "black", "white", "far", "hard", "open", "red", "easy", "strong", "real", "best", "left", "short", "clear", "hot", "cold", "nice", "beautiful", "happy", "sad", "angry", "tired", "hungry", "thirsty", "full", "empty", "clean", "dirty", "safe", "dangerous", "fast", "slow", "cheap", "expensive"];
-
-        let total_words = words.len() as f32;
+        let total_words = tokens.len() as f32;
         if total_words == 0.0 {
             return pos_counts;
         }
 
-        let noun_count = words.iter()
-            .filter(|word| common_nouns.contains(&word.to_lowercase().as_str()))
-            .count() as f32;
-        
-        let verb_count = words.iter()
-            .filter(|word| common_verbs.contains(&word.to_lowercase().as_str()))
-            .count() as f32;
-        
-        let adj_count = words.iter()
-            .filter(|word| common_adjectives.contains(&word.to_lowercase().as_str()))
-            .count() as f32;
+        // Simple lexicon lookups for POS tagging
+        let count_in = |words: &HashSet<String>| tokens.iter().filter(|token| words.contains(*token)).count() as f32;
+        let noun_count = count_in(&self.lexicon.nouns);
+        let verb_count = count_in(&self.lexicon.verbs);
+        let adj_count = count_in(&self.lexicon.adjectives);
 
-        let adv_count = words.iter()
-            .filter(|word| word.ends_with("ly"))
+        let adv_count = tokens.iter()
+            .filter(|token| self.lexicon.adverb_suffixes.iter().any(|suffix| token.ends_with(suffix.as_str())))
             .count() as f32;
 
         pos_counts.insert("noun_ratio".to_string(), noun_count / total_words);
@@ -297,25 +482,14 @@ impl FeatureExtractor {
 
     // Image analysis helper methods
     fn analyze_color_distribution(image: &image::RgbImage) -> HashMap<String, f32> {
+        Self::color_features(&ColorAccumulator::from_image(image), image.dimensions())
+    }
+
+    fn color_features(colors: &ColorAccumulator, (width, height): (u32, u32)) -> HashMap<String, f32> {
         let mut features = HashMap::new();
-        let (width, height) = image.dimensions();
         let total_pixels = (width * height) as f64;
-
-        let mut r_sum = 0u64;
-        let mut g_sum = 0u64;
-        let mut b_sum = 0u64;
-        let mut r_squared_sum = 0u64;
-        let mut g_squared_sum = 0u64;
-        let mut b_squared_sum = 0u64;
-
-        for pixel in image.pixels() {
-            r_sum += pixel[0] as u64;
-            g_sum += pixel[1] as u64;
-            b_sum += pixel[2] as u64;
-            r_squared_sum += (pixel[0] as u64) * (pixel[0] as u64);
-            g_squared_sum += (pixel[1] as u64) * (pixel[1] as u64);
-            b_squared_sum += (pixel[2] as u64) * (pixel[2] as u64);
-        }
+        let [r_sum, g_sum, b_sum] = colors.sums;
+        let [r_squared_sum, g_squared_sum, b_squared_sum] = colors.squared_sums;
 
         // Color means
         let r_mean = r_sum as f32 / total_pixels as f32;
@@ -342,23 +516,11 @@ impl FeatureExtractor {
         features.insert("saturation".to_string(), saturation);
 
         // Color histogram entropy
-        let mut histogram = vec![vec![vec![0u32; 8]; 8]; 8];
-        for pixel in image.pixels() {
-            let r_bin = (pixel[0] / 32) as usize;
-            let g_bin = (pixel[1] / 32) as usize;
-            let b_bin = (pixel[2] / 32) as usize;
-            histogram[r_bin][g_bin][b_bin] += 1;
-        }
-
         let mut entropy = 0.0;
-        for r in 0..8 {
-            for g in 0..8 {
-                for b in 0..8 {
-                    if histogram[r][g][b] > 0 {
-                        let p = histogram[r][g][b] as f32 / total_pixels as f32;
-                        entropy -= p * p.log2();
-                    }
-                }
+        for &count in &colors.histogram {
+            if count > 0 {
+                let p = count as f32 / total_pixels as f32;
+                entropy -= p * p.log2();
             }
         }
 
@@ -444,9 +606,14 @@ impl FeatureExtractor {
         features
     }
 
+    /// Sobel edge statistics plus a magnitude-weighted histogram of gradient
+    /// orientations. Orientations are unsigned (0–180°) and bins are centred
+    /// on multiples of 22.5°, so bin 0 collects horizontal gradients, i.e.
+    /// vertical edges, and bin 4 collects horizontal edges.
     fn analyze_edges(image: &image::RgbImage) -> HashMap<String, f32> {
         let mut features = HashMap::new();
         let (width, height) = image.dimensions();
+        let mut orientation_histogram = [0.0f32; EDGE_ORIENTATION_BINS];
 
         // Convert to grayscale
         let gray_pixels: Vec<f32> = image.pixels()
@@ -480,6 +647,12 @@ impl FeatureExtractor {
                 let magnitude = (gx * gx + gy * gy).sqrt();
                 edge_magnitude_sum += magnitude;
                 edge_count += 1;
+
+                if magnitude > 0.0 {
+                    let angle = gy.atan2(gx).rem_euclid(std::f32::consts::PI);
+                    let bin = (angle / EDGE_ORIENTATION_BIN_WIDTH).round() as usize % EDGE_ORIENTATION_BINS;
+                    orientation_histogram[bin] += magnitude;
+                }
             }
         }
 
@@ -492,6 +665,22 @@ impl FeatureExtractor {
         features.insert("edge_density".to_string(), average_edge_strength);
         features.insert("edge_strength".to_string(), edge_magnitude_sum);
 
+        // Bins are fractions of the total magnitude so they compare across
+        // image sizes and contrasts
+        for (bin, weight) in orientation_histogram.iter().enumerate() {
+            let fraction = if edge_magnitude_sum > 0.0 { weight / edge_magnitude_sum } else { 0.0 };
+            features.insert(format!("edge_orientation_bin_{}", bin), fraction);
+        }
+        let dominant_bin = orientation_histogram
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map_or(0, |(bin, _)| bin);
+        features.insert(
+            "dominant_edge_orientation".to_string(),
+            (dominant_bin as f32 * EDGE_ORIENTATION_BIN_WIDTH).to_degrees(),
+        );
+
         features
     }
 
@@ -960,7 +1149,7 @@ impl FeatureExtractor {
             features.insert("motion_variability".to_string(), motion_variance.sqrt());
             
             // Estimate motion type based on patterns
-            let max_motion = motion_indicators.iter().fold(0.0, |a, &b| a.max(b));
+            let max_motion = motion_indicators.iter().fold(0.0f32, |a, &b| a.max(b));
             let min_motion = motion_indicators.iter().fold(f32::INFINITY, |a, &b| a.min(b));
             
             let motion_range = max_motion - min_motion;
@@ -1005,7 +1194,7 @@ impl FeatureExtractor {
             features.insert("complexity_variation".to_string(), complexity_variance.sqrt());
             
             // Estimate visual detail level
-            let max_complexity = complexity_scores.iter().fold(0.0, |a, &b| a.max(b));
+            let max_complexity = complexity_scores.iter().fold(0.0f32, |a, &b| a.max(b));
             features.insert("max_detail_level".to_string(), max_complexity);
             
             // Consistency of complexity (indicates scene stability)
@@ -1081,7 +1270,7 @@ impl FeatureExtractor {
         }
         
         if !periodicity_scores.is_empty() {
-            let max_periodicity = periodicity_scores.iter().fold(0.0, |a, &b| a.max(b));
+            let max_periodicity = periodicity_scores.iter().fold(0.0f32, |a, &b| a.max(b));
             features.insert("temporal_periodicity".to_string(), max_periodicity);
         }
 
@@ -1104,6 +1293,121 @@ mod tests {
         assert!(*features.get("word_count").unwrap() > 0.0);
     }
 
+    #[test]
+    fn test_char_ngram_entropy_separates_structure_from_noise() {
+        // Pseudo-random characters drawn from the same alphabet as each text
+        fn scramble(text: &str) -> String {
+            let alphabet: Vec<char> = text.chars().collect();
+            let mut seed = 0x2545_f491_4f6c_dd1du64;
+            (0..alphabet.len())
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    alphabet[(seed % alphabet.len() as u64) as usize]
+                })
+                .collect()
+        }
+
+        let texts = [
+            "the cat sat on the mat and the dog sat on the log ".repeat(4),
+            "der hund und die katze und der hund und die maus ".repeat(4),
+            "猫が庭で寝ている。犬も庭で寝ている。鳥も庭で歌っている。".repeat(4),
+        ];
+        for text in &texts {
+            let structured = FeatureExtractor::char_ngram_entropy(text, 3);
+            let random = FeatureExtractor::char_ngram_entropy(&scramble(text), 3);
+            assert!(structured < random, "{}: {} >= {}", text, structured, random);
+            assert!((0.0..=1.0).contains(&random));
+        }
+
+        let features = FeatureExtractor::default().with_char_ngrams(false).text_features(&texts[0]);
+        assert!(!features.contains_key("char_trigram_entropy"));
+    }
+
+    #[test]
+    fn test_injected_lexicon_replaces_english_lists() {
+        let spanish = TextLexicon {
+            stopwords: ["el", "la", "es"].iter().map(|w| w.to_string()).collect(),
+            positive: ["excelente", "bueno"].iter().map(|w| w.to_string()).collect(),
+            negative: ["malo"].iter().map(|w| w.to_string()).collect(),
+            adverb_suffixes: vec!["mente".to_string()],
+            ..TextLexicon::default()
+        };
+        let text = "El servicio es excelente, la comida es buena y llegó rápidamente.";
+
+        let english = FeatureExtractor::extract_text_features(text);
+        assert_eq!(english["positive_word_ratio"], 0.0);
+
+        let features = FeatureExtractor::new(spanish).text_features(text);
+        assert!(features["positive_word_ratio"] > 0.0);
+        assert!(features["stopword_ratio"] > english["stopword_ratio"]);
+        assert!(features["adverb_ratio"] > 0.0);
+    }
+
+    #[test]
+    fn test_parallel_color_distribution_matches_sequential() {
+        // Height isn't a multiple of the chunk size, so the last chunk is partial
+        let image = image::RgbImage::from_fn(97, 53, |x, y| {
+            image::Rgb([(x * 7 + y * 3) as u8, (x * y) as u8, (255 - x * 2) as u8])
+        });
+
+        let sequential = ColorAccumulator::new().add_pixels(image.as_raw());
+        let parallel = ColorAccumulator::from_image(&image);
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel.histogram.iter().sum::<u32>(), 97 * 53);
+
+        assert_eq!(
+            FeatureExtractor::analyze_color_distribution(&image),
+            FeatureExtractor::color_features(&sequential, image.dimensions())
+        );
+    }
+
+    #[test]
+    fn test_readability_edge_cases_are_finite() {
+        let unpunctuated = "this paragraph just keeps going without ever stopping to take a breath \
+            because nobody told it where the sentences were supposed to end and so it continues \
+            on and on through every clause";
+        let features = FeatureExtractor::extract_text_features(unpunctuated);
+        assert_eq!(features["sentence_count"], 1.0);
+        assert!((0.0..=100.0).contains(&features["readability_score"]));
+
+        let questions = FeatureExtractor::extract_text_features("Is it done? Yes! It is.");
+        assert_eq!(questions["sentence_count"], 3.0);
+
+        let long_word = format!("{} ok", "ba".repeat(200));
+        assert_eq!(FeatureExtractor::extract_text_features(&long_word)["readability_score"], 0.0);
+
+        for blank in ["", "   \n\t  ", " . ! ? "] {
+            let score = FeatureExtractor::extract_text_features(blank)["readability_score"];
+            assert!(score.is_finite() && (0.0..=100.0).contains(&score), "{:?}: {}", blank, score);
+        }
+        assert_eq!(FeatureExtractor::clamp_readability(f32::NAN), 0.0);
+    }
+
+    #[test]
+    fn test_vertical_edges_peak_in_horizontal_gradient_bin() {
+        // Vertical stripes 4px wide
+        let image = image::RgbImage::from_fn(32, 32, |x, _| {
+            if (x / 4) % 2 == 0 { image::Rgb([0, 0, 0]) } else { image::Rgb([255, 255, 255]) }
+        });
+        let features = FeatureExtractor::analyze_edges(&image);
+
+        assert!(features["edge_strength"] > 0.0);
+        assert!(features["edge_orientation_bin_0"] > 0.99);
+        assert_eq!(features["dominant_edge_orientation"], 0.0);
+        let total: f32 = (0..EDGE_ORIENTATION_BINS)
+            .map(|bin| features[&format!("edge_orientation_bin_{}", bin)])
+            .sum();
+        assert!((total - 1.0).abs() < 1e-4);
+
+        // The same stripes turned horizontal land in the opposite bin
+        let rotated = image::imageops::rotate90(&image);
+        let features = FeatureExtractor::analyze_edges(&rotated);
+        assert!(features["edge_orientation_bin_4"] > 0.99);
+        assert_eq!(features["dominant_edge_orientation"], 90.0);
+    }
+
     #[test]
     fn test_empty_text_features() {
         let features = FeatureExtractor::extract_text_features("");
@@ -842,7 +842,7 @@ mod tests {
             &ModalityType::Image
         );
         
-        assert!(score.is_ok());
-        assert!(score.unwrap() >= 0.0 && score.unwrap() <= 1.0);
+        let score = score.unwrap();
+        assert!((0.0..=1.0).contains(&score));
     }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 use uuid::Uuid;
@@ -8,8 +8,16 @@ pub mod processor;
 pub mod fusion;
 pub mod extractors;
 pub mod transformers;
+pub mod breaker;
+pub mod embedding;
+pub mod cache;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use breaker::{BreakerConfig, BreakerState, CircuitBreaker};
+use embedding::EMBEDDING_DIM;
+
+/// Serialized as the bare variant name (e.g. `"Image"`), which also makes it
+/// a plain string key in JSON maps such as `modality_distribution`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ModalityType {
     Text,
     Image,
@@ -54,6 +62,9 @@ pub struct FusedOutput {
     pub emergent_properties: HashMap<String, f32>,
 }
 
+/// Boxed through `async_trait` so processors can be stored as
+/// `Arc<dyn ModalProcessor>` and registered by modality
+#[async_trait::async_trait]
 pub trait ModalProcessor: Send + Sync {
     async fn process(&self, input: ModalInput) -> Result<ProcessedModal, Box<dyn std::error::Error>>;
     fn supported_modality(&self) -> ModalityType;
@@ -83,6 +94,8 @@ pub struct MultiModalPipeline {
     output_queue: mpsc::Receiver<FusedOutput>,
     config: PipelineConfig,
     metrics: Arc<RwLock<PipelineMetrics>>,
+    breakers: Arc<RwLock<HashMap<ModalityType, CircuitBreaker>>>,
+    cache: Arc<Mutex<cache::ProcessedCache>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,18 +106,68 @@ pub struct PipelineConfig {
     pub enable_emergent_detection: bool,
     pub batch_size: usize,
     pub timeout_seconds: u64,
+    /// Per-modality circuit breaking for failing processors
+    #[serde(default)]
+    pub breaker: BreakerConfig,
+    /// Length every processor projects its embeddings to
+    #[serde(default = "default_embedding_dim")]
+    pub embedding_dim: usize,
+    /// Processed outputs kept for identical repeat inputs; 0 disables caching
+    #[serde(default = "default_cache_capacity")]
+    pub cache_capacity: usize,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+fn default_embedding_dim() -> usize {
+    EMBEDDING_DIM
+}
+
+fn default_cache_capacity() -> usize {
+    256
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PipelineMetrics {
     pub total_inputs_processed: u64,
     pub successful_fusions: u64,
     pub failed_processing: u64,
+    /// Inputs refused by `try_process_input` because the queue was full
+    #[serde(default)]
+    pub rejected_inputs: u64,
+    /// Inputs refused because their modality's circuit breaker was open
+    #[serde(default)]
+    pub short_circuited_inputs: u64,
+    #[serde(default)]
+    pub breaker_states: HashMap<ModalityType, BreakerState>,
+    /// Inputs answered from the content cache without reprocessing
+    #[serde(default)]
+    pub cache_hits: u64,
     pub average_pipeline_latency: f64,
     pub modality_distribution: HashMap<ModalityType, u64>,
     pub fusion_quality_scores: Vec<f32>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PipelineError {
+    /// The input queue is at capacity; the caller should shed or retry later
+    QueueFull { capacity: usize },
+    /// The processing loop has stopped
+    Closed,
+    /// This modality's processor keeps failing and its breaker is open
+    CircuitOpen { modality: ModalityType },
+}
+
+impl std::fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::QueueFull { capacity } => write!(f, "Input queue full ({} pending)", capacity),
+            Self::Closed => write!(f, "Pipeline is no longer accepting input"),
+            Self::CircuitOpen { modality } => write!(f, "Circuit breaker open for {:?} inputs", modality),
+        }
+    }
+}
+
+impl std::error::Error for PipelineError {}
+
 impl MultiModalPipeline {
     pub async fn new(config: PipelineConfig) -> Result<Self, Box<dyn std::error::Error>> {
         let (input_tx, input_rx) = mpsc::channel(config.max_concurrent_processing);
@@ -116,25 +179,29 @@ impl MultiModalPipeline {
         // Initialize modality-specific processors
         processors.insert(
             ModalityType::Text,
-            Arc::new(processor::TextProcessor::new().await?) as Arc<dyn ModalProcessor>
+            Arc::new(processor::TextProcessor::with_embedding_dim(config.embedding_dim).await?) as Arc<dyn ModalProcessor>
         );
         processors.insert(
             ModalityType::Image,
-            Arc::new(processor::ImageProcessor::new().await?) as Arc<dyn ModalProcessor>
+            Arc::new(processor::ImageProcessor::with_embedding_dim(config.embedding_dim).await?) as Arc<dyn ModalProcessor>
         );
         processors.insert(
             ModalityType::Audio,
-            Arc::new(processor::AudioProcessor::new().await?) as Arc<dyn ModalProcessor>
+            Arc::new(processor::AudioProcessor::with_embedding_dim(config.embedding_dim).await?) as Arc<dyn ModalProcessor>
         );
         processors.insert(
             ModalityType::Video,
-            Arc::new(processor::VideoProcessor::new().await?) as Arc<dyn ModalProcessor>
+            Arc::new(processor::VideoProcessor::with_embedding_dim(config.embedding_dim).await?) as Arc<dyn ModalProcessor>
         );
 
         let metrics = Arc::new(RwLock::new(PipelineMetrics {
             total_inputs_processed: 0,
             successful_fusions: 0,
             failed_processing: 0,
+            rejected_inputs: 0,
+            short_circuited_inputs: 0,
+            breaker_states: HashMap::new(),
+            cache_hits: 0,
             average_pipeline_latency: 0.0,
             modality_distribution: HashMap::new(),
             fusion_quality_scores: Vec::new(),
@@ -145,8 +212,10 @@ impl MultiModalPipeline {
             fusion_engine,
             input_queue: input_tx,
             output_queue: output_rx,
-            config,
             metrics,
+            breakers: Arc::new(RwLock::new(HashMap::new())),
+            cache: Arc::new(Mutex::new(cache::ProcessedCache::new(config.cache_capacity))),
+            config,
         };
 
         // Start processing loops
@@ -156,10 +225,41 @@ impl MultiModalPipeline {
     }
 
     pub async fn process_input(&self, input: ModalInput) -> Result<(), Box<dyn std::error::Error>> {
+        self.check_breaker(&input.modality)?;
         self.input_queue.send(input).await?;
         Ok(())
     }
 
+    /// Enqueue without waiting: fails with `QueueFull` when the backlog is at
+    /// `max_concurrent_processing`, so producers can shed load instead of
+    /// blocking
+    pub fn try_process_input(&self, input: ModalInput) -> Result<(), PipelineError> {
+        self.check_breaker(&input.modality)?;
+        match self.input_queue.try_send(input) {
+            Ok(()) => Ok(()),
+            Err(mpsc::error::TrySendError::Full(_)) => {
+                self.metrics.write().unwrap().rejected_inputs += 1;
+                Err(PipelineError::QueueFull { capacity: self.input_queue.max_capacity() })
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => Err(PipelineError::Closed),
+        }
+    }
+
+    /// Fail fast when the modality's breaker is open, or half-open with its
+    /// trial input still in flight. This is the only place inputs are gated.
+    fn check_breaker(&self, modality: &ModalityType) -> Result<(), PipelineError> {
+        if breaker_allows(&self.breakers, &self.metrics, modality, &self.config.breaker) {
+            Ok(())
+        } else {
+            Err(PipelineError::CircuitOpen { modality: modality.clone() })
+        }
+    }
+
+    /// Inputs waiting in the queue for the processing loop
+    pub fn queue_depth(&self) -> usize {
+        self.input_queue.max_capacity() - self.input_queue.capacity()
+    }
+
     pub async fn get_next_output(&mut self) -> Option<FusedOutput> {
         self.output_queue.recv().await
     }
@@ -173,6 +273,8 @@ impl MultiModalPipeline {
         let fusion_engine = self.fusion_engine.clone();
         let config = self.config.clone();
         let metrics = self.metrics.clone();
+        let breakers = self.breakers.clone();
+        let cache = self.cache.clone();
 
         tokio::spawn(async move {
             let mut batch_buffer: HashMap<String, Vec<ProcessedModal>> = HashMap::new();
@@ -189,9 +291,14 @@ impl MultiModalPipeline {
                 }
 
                 // Process individual modality
+                // The breaker was consulted when the input was enqueued
                 if let Some(processor) = processors.get(&input.modality) {
-                    match processor.process(input.clone()).await {
+                    let result = process_validated(processor, &cache, &metrics, input.clone(), config.embedding_dim).await;
+
+                    match result {
                         Ok(processed) => {
+                            record_processor_outcome(&breakers, &metrics, &input.modality, true);
+
                             // Group by session or correlation ID for batch fusion
                             let batch_key = input.metadata
                                 .get("session_id")
@@ -211,7 +318,7 @@ impl MultiModalPipeline {
                                     let batch_data = batch_buffer.remove(&batch_key).unwrap();
                                     
                                     // Perform multi-modal fusion
-                                    match fusion_engine.fuse_modalities(batch_data).await {
+                                    match fusion_engine.fuse_modalities(batch_data).await.map_err(|e| e.to_string()) {
                                         Ok(fused_output) => {
                                             let processing_time = start_time.elapsed().as_millis() as f64;
                                             
@@ -243,8 +350,8 @@ impl MultiModalPipeline {
                         }
                         Err(e) => {
                             eprintln!("Processing failed for {:?}: {}", input.modality, e);
-                            let mut metrics = metrics.write().unwrap();
-                            metrics.failed_processing += 1;
+                            metrics.write().unwrap().failed_processing += 1;
+                            record_processor_outcome(&breakers, &metrics, &input.modality, false);
                         }
                     }
                 }
@@ -262,6 +369,84 @@ impl MultiModalPipeline {
     }
 }
 
+/// Run `input` through `processor`, answering from the content cache when an
+/// identical input of the same modality was processed before
+async fn process_cached(
+    processor: &Arc<dyn ModalProcessor>,
+    cache: &Mutex<cache::ProcessedCache>,
+    metrics: &RwLock<PipelineMetrics>,
+    input: ModalInput,
+) -> Result<ProcessedModal, Box<dyn std::error::Error>> {
+    if input.bypasses_cache() {
+        return processor.process(input).await;
+    }
+
+    let hash = input.content_hash();
+    let cached = cache.lock().unwrap().get(&input, &hash);
+    if let Some(processed) = cached {
+        metrics.write().unwrap().cache_hits += 1;
+        return Ok(processed);
+    }
+
+    let processed = processor.process(input).await?;
+    cache.lock().unwrap().insert(hash, processed.clone());
+    Ok(processed)
+}
+
+/// `process_cached`, rejecting output whose embedding isn't `embedding_dim`
+/// long. `Box<dyn Error>` isn't `Send`, so errors are stringified before the
+/// processing loop holds them across awaits.
+async fn process_validated(
+    processor: &Arc<dyn ModalProcessor>,
+    cache: &Mutex<cache::ProcessedCache>,
+    metrics: &RwLock<PipelineMetrics>,
+    input: ModalInput,
+    embedding_dim: usize,
+) -> Result<ProcessedModal, String> {
+    let processed = process_cached(processor, cache, metrics, input).await.map_err(|e| e.to_string())?;
+    embedding::validate_embedding(&processed, embedding_dim).map_err(|e| e.to_string())?;
+    Ok(processed)
+}
+
+/// Ask the modality's breaker whether an input may proceed, counting a
+/// short-circuit when it may not
+fn breaker_allows(
+    breakers: &RwLock<HashMap<ModalityType, CircuitBreaker>>,
+    metrics: &RwLock<PipelineMetrics>,
+    modality: &ModalityType,
+    config: &BreakerConfig,
+) -> bool {
+    let mut breakers = breakers.write().unwrap();
+    let breaker = breakers
+        .entry(modality.clone())
+        .or_insert_with(|| CircuitBreaker::new(config.clone()));
+    let allowed = breaker.allow(std::time::Instant::now());
+
+    let mut metrics = metrics.write().unwrap();
+    metrics.breaker_states.insert(modality.clone(), breaker.state());
+    if !allowed {
+        metrics.short_circuited_inputs += 1;
+    }
+    allowed
+}
+
+fn record_processor_outcome(
+    breakers: &RwLock<HashMap<ModalityType, CircuitBreaker>>,
+    metrics: &RwLock<PipelineMetrics>,
+    modality: &ModalityType,
+    success: bool,
+) {
+    let mut breakers = breakers.write().unwrap();
+    if let Some(breaker) = breakers.get_mut(modality) {
+        if success {
+            breaker.record_success();
+        } else {
+            breaker.record_failure(std::time::Instant::now());
+        }
+        metrics.write().unwrap().breaker_states.insert(modality.clone(), breaker.state());
+    }
+}
+
 fn should_trigger_fusion(batch: &[ProcessedModal], config: &PipelineConfig) -> bool {
     if batch.is_empty() {
         return false;
@@ -289,12 +474,258 @@ mod tests {
             enable_emergent_detection: true,
             batch_size: 3,
             timeout_seconds: 5,
+            breaker: BreakerConfig::default(),
+            embedding_dim: EMBEDDING_DIM,
+            cache_capacity: 16,
         };
 
         let pipeline = MultiModalPipeline::new(config).await;
         assert!(pipeline.is_ok());
     }
 
+    fn text_input() -> ModalInput {
+        ModalInput {
+            id: Uuid::new_v4(),
+            modality: ModalityType::Text,
+            data: b"Hello, world!".to_vec(),
+            metadata: HashMap::new(),
+            timestamp: chrono::Utc::now(),
+            source: "test".to_string(),
+        }
+    }
+
+    /// A pipeline whose processing loop never runs, so queued inputs stay put
+    async fn idle_pipeline(capacity: usize) -> (MultiModalPipeline, mpsc::Receiver<ModalInput>) {
+        let (input_tx, input_rx) = mpsc::channel(capacity);
+        let (_output_tx, output_rx) = mpsc::channel(capacity);
+        let pipeline = MultiModalPipeline {
+            processors: HashMap::new(),
+            fusion_engine: Arc::new(fusion::FusionEngine::new().await.unwrap()),
+            input_queue: input_tx,
+            output_queue: output_rx,
+            config: PipelineConfig {
+                max_concurrent_processing: capacity,
+                fusion_threshold: 0.8,
+                enable_cross_modal_attention: true,
+                enable_emergent_detection: true,
+                batch_size: 3,
+                timeout_seconds: 5,
+                breaker: BreakerConfig {
+                    failure_threshold: 2,
+                    cooldown_ms: 50,
+                },
+                embedding_dim: EMBEDDING_DIM,
+                cache_capacity: 16,
+            },
+            metrics: Arc::new(RwLock::new(PipelineMetrics::default())),
+            breakers: Arc::new(RwLock::new(HashMap::new())),
+            cache: Arc::new(Mutex::new(cache::ProcessedCache::new(16))),
+        };
+        (pipeline, input_rx)
+    }
+
+    #[tokio::test]
+    async fn test_try_process_input_reports_queue_full() {
+        let (pipeline, mut input_rx) = idle_pipeline(2).await;
+        assert_eq!(pipeline.queue_depth(), 0);
+
+        pipeline.try_process_input(text_input()).unwrap();
+        pipeline.try_process_input(text_input()).unwrap();
+        assert_eq!(pipeline.queue_depth(), 2);
+
+        assert_eq!(pipeline.try_process_input(text_input()), Err(PipelineError::QueueFull { capacity: 2 }));
+        assert_eq!(pipeline.queue_depth(), 2);
+        assert_eq!(pipeline.get_metrics().rejected_inputs, 1);
+
+        // Draining one slot lets the next input in
+        input_rx.recv().await.unwrap();
+        assert_eq!(pipeline.queue_depth(), 1);
+        pipeline.try_process_input(text_input()).unwrap();
+
+        drop(input_rx);
+        assert_eq!(pipeline.try_process_input(text_input()), Err(PipelineError::Closed));
+    }
+
+    #[tokio::test]
+    async fn test_failing_modality_trips_breaker_and_recovers() {
+        let (pipeline, _input_rx) = idle_pipeline(10).await;
+        let image_input = || ModalInput { modality: ModalityType::Image, ..text_input() };
+
+        pipeline.try_process_input(image_input()).unwrap();
+        for _ in 0..2 {
+            record_processor_outcome(&pipeline.breakers, &pipeline.metrics, &ModalityType::Image, false);
+        }
+
+        assert_eq!(
+            pipeline.try_process_input(image_input()),
+            Err(PipelineError::CircuitOpen { modality: ModalityType::Image })
+        );
+        assert!(pipeline.process_input(image_input()).await.is_err());
+        // Other modalities are unaffected
+        pipeline.try_process_input(text_input()).unwrap();
+
+        let metrics = pipeline.get_metrics();
+        assert_eq!(metrics.breaker_states[&ModalityType::Image], BreakerState::Open);
+        assert_eq!(metrics.breaker_states[&ModalityType::Text], BreakerState::Closed);
+        assert_eq!(metrics.short_circuited_inputs, 2);
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(60)).await;
+        pipeline.try_process_input(image_input()).unwrap();
+        assert_eq!(pipeline.get_metrics().breaker_states[&ModalityType::Image], BreakerState::HalfOpen);
+
+        record_processor_outcome(&pipeline.breakers, &pipeline.metrics, &ModalityType::Image, true);
+        assert_eq!(pipeline.get_metrics().breaker_states[&ModalityType::Image], BreakerState::Closed);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_half_open_breaker_admits_a_single_trial() {
+        let (pipeline, _input_rx) = idle_pipeline(16).await;
+        let pipeline = Arc::new(pipeline);
+        let image_input = || ModalInput { modality: ModalityType::Image, ..text_input() };
+
+        pipeline.try_process_input(image_input()).unwrap();
+        for _ in 0..2 {
+            record_processor_outcome(&pipeline.breakers, &pipeline.metrics, &ModalityType::Image, false);
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(60)).await;
+
+        let attempts: Vec<_> = (0..8)
+            .map(|_| {
+                let pipeline = pipeline.clone();
+                tokio::spawn(async move { pipeline.try_process_input(image_input()) })
+            })
+            .collect();
+        let mut admitted = 0;
+        for attempt in attempts {
+            match attempt.await.unwrap() {
+                Ok(()) => admitted += 1,
+                Err(e) => assert_eq!(e, PipelineError::CircuitOpen { modality: ModalityType::Image }),
+            }
+        }
+        assert_eq!(admitted, 1);
+        assert_eq!(pipeline.get_metrics().breaker_states[&ModalityType::Image], BreakerState::HalfOpen);
+
+        // The trial's failure reopens the breaker for another cooldown
+        record_processor_outcome(&pipeline.breakers, &pipeline.metrics, &ModalityType::Image, false);
+        assert_eq!(pipeline.get_metrics().breaker_states[&ModalityType::Image], BreakerState::Open);
+        assert!(pipeline.try_process_input(image_input()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_repeated_image_is_served_from_cache() {
+        let image: image::RgbImage = image::ImageBuffer::from_fn(8, 8, |x, y| image::Rgb([(x * 32) as u8, (y * 32) as u8, 0]));
+        let mut png = Vec::new();
+        image::DynamicImage::ImageRgb8(image)
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageOutputFormat::Png)
+            .unwrap();
+
+        let image_processor = Arc::new(processor::ImageProcessor::new().await.unwrap());
+        let processor = image_processor.clone() as Arc<dyn ModalProcessor>;
+        let cache = Mutex::new(cache::ProcessedCache::new(4));
+        let metrics = RwLock::new(PipelineMetrics::default());
+        let send = |metadata: HashMap<String, String>| ModalInput {
+            modality: ModalityType::Image,
+            data: png.clone(),
+            metadata,
+            ..text_input()
+        };
+
+        let first = process_cached(&processor, &cache, &metrics, send(HashMap::new())).await.unwrap();
+        let second_input = send(HashMap::new());
+        let second = process_cached(&processor, &cache, &metrics, second_input.clone()).await.unwrap();
+
+        assert_eq!(image_processor.get_performance_metrics().total_processed, 1);
+        assert_eq!(metrics.read().unwrap().cache_hits, 1);
+        assert_eq!(second.input_id, second_input.id);
+        assert_eq!(second.embeddings, first.embeddings);
+
+        // Freshness-sensitive inputs are always reprocessed
+        let no_cache = HashMap::from([(cache::NO_CACHE_KEY.to_string(), "true".to_string())]);
+        process_cached(&processor, &cache, &metrics, send(no_cache)).await.unwrap();
+        assert_eq!(image_processor.get_performance_metrics().total_processed, 2);
+        assert_eq!(metrics.read().unwrap().cache_hits, 1);
+    }
+
+    /// Minimal out-of-tree processor: sensor readings are the features, and
+    /// projected into the shared space like the built-in processors do
+    struct SensorEchoProcessor {
+        projection: embedding::EmbeddingProjection,
+    }
+
+    impl SensorEchoProcessor {
+        /// Readings beyond this many channels are ignored
+        const CHANNELS: usize = 16;
+
+        fn new() -> Self {
+            Self { projection: embedding::EmbeddingProjection::new(Self::CHANNELS, EMBEDDING_DIM, 0x5E45) }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ModalProcessor for SensorEchoProcessor {
+        async fn process(&self, input: ModalInput) -> Result<ProcessedModal, Box<dyn std::error::Error>> {
+            let features: Vec<f32> = input.data.iter().map(|&b| b as f32 / 255.0).collect();
+            Ok(ProcessedModal {
+                id: Uuid::new_v4(),
+                input_id: input.id,
+                modality: ModalityType::Sensor,
+                embeddings: self.projection.project(&features),
+                features,
+                confidence: 1.0,
+                metadata: input.metadata,
+                processing_time_ms: 0,
+            })
+        }
+
+        fn supported_modality(&self) -> ModalityType {
+            ModalityType::Sensor
+        }
+
+        fn get_performance_metrics(&self) -> ProcessorMetrics {
+            ProcessorMetrics {
+                total_processed: 0,
+                average_processing_time: 0.0,
+                accuracy_score: 1.0,
+                resource_usage: ResourceUsage {
+                    cpu_usage_percent: 0.0,
+                    memory_usage_mb: 0.0,
+                    gpu_usage_percent: 0.0,
+                    vram_usage_mb: 0.0,
+                },
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_processor_registers_as_trait_object() {
+        let (mut pipeline, _input_rx) = idle_pipeline(4).await;
+        let custom: Arc<dyn ModalProcessor> = Arc::new(SensorEchoProcessor::new());
+        pipeline.processors.insert(custom.supported_modality(), custom);
+        // Built-in processors share the same trait object type
+        pipeline.processors.insert(
+            ModalityType::Text,
+            Arc::new(processor::TextProcessor::new().await.unwrap()) as Arc<dyn ModalProcessor>,
+        );
+
+        let input = ModalInput { modality: ModalityType::Sensor, data: vec![0, 255], ..text_input() };
+        let processor = &pipeline.processors[&ModalityType::Sensor];
+        let embedding_dim = pipeline.config.embedding_dim;
+        let processed = process_validated(processor, &pipeline.cache, &pipeline.metrics, input.clone(), embedding_dim)
+            .await
+            .unwrap();
+        assert_eq!(processed.input_id, input.id);
+        assert_eq!(processed.features, vec![0.0, 1.0]);
+        assert_eq!(processed.embeddings.len(), EMBEDDING_DIM);
+
+        // The same output breaks a pipeline configured for another dimension
+        let narrow = ModalInput { data: vec![1, 2], ..input };
+        let error = process_validated(processor, &pipeline.cache, &pipeline.metrics, narrow, 64).await.unwrap_err();
+        assert!(error.contains("expected 64"), "{}", error);
+
+        let text = &pipeline.processors[&ModalityType::Text];
+        assert_eq!(text.process(text_input()).await.unwrap().modality, ModalityType::Text);
+    }
+
     #[tokio::test]
     async fn test_modal_input_processing() {
         // Test input processing workflow
@@ -310,4 +741,28 @@ mod tests {
         // Test would require full pipeline setup
         assert_eq!(input.modality, ModalityType::Text);
     }
+
+    #[test]
+    fn test_pipeline_metrics_round_trip() {
+        let mut metrics = PipelineMetrics {
+            total_inputs_processed: 7,
+            successful_fusions: 2,
+            failed_processing: 1,
+            average_pipeline_latency: 12.5,
+            modality_distribution: HashMap::new(),
+            fusion_quality_scores: vec![0.9, 0.75],
+            ..Default::default()
+        };
+        metrics.modality_distribution.insert(ModalityType::Text, 4);
+        metrics.modality_distribution.insert(ModalityType::Image, 2);
+        metrics.modality_distribution.insert(ModalityType::Neural, 1);
+
+        let json = serde_json::to_value(&metrics).unwrap();
+        assert_eq!(json["modality_distribution"]["Text"], 4);
+        assert_eq!(json["modality_distribution"]["Image"], 2);
+
+        let restored: PipelineMetrics = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.modality_distribution, metrics.modality_distribution);
+        assert_eq!(restored.total_inputs_processed, 7);
+    }
 }
\ No newline at end of file
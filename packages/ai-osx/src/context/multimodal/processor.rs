@@ -4,25 +4,30 @@ use std::sync::{Arc, RwLock};
 use tokio::process::Command;
 use image::{ImageBuffer, RgbImage};
 use hound::WavReader;
+use super::embedding::{EmbeddingProjection, EMBEDDING_DIM};
 
 pub struct TextProcessor {
     model: Arc<RwLock<TextModel>>,
     metrics: Arc<RwLock<ProcessorMetrics>>,
+    projection: EmbeddingProjection,
 }
 
 pub struct ImageProcessor {
     model: Arc<RwLock<VisionModel>>,
     metrics: Arc<RwLock<ProcessorMetrics>>,
+    projection: EmbeddingProjection,
 }
 
 pub struct AudioProcessor {
     model: Arc<RwLock<AudioModel>>,
     metrics: Arc<RwLock<ProcessorMetrics>>,
+    projection: EmbeddingProjection,
 }
 
 pub struct VideoProcessor {
     model: Arc<RwLock<VideoModel>>,
     metrics: Arc<RwLock<ProcessorMetrics>>,
+    projection: EmbeddingProjection,
 }
 
 // Model abstractions
@@ -52,6 +57,11 @@ struct VideoModel {
 
 impl TextProcessor {
     pub async fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::with_embedding_dim(EMBEDDING_DIM).await
+    }
+
+    /// Processor whose embeddings are projected to `embedding_dim` values
+    pub async fn with_embedding_dim(embedding_dim: usize) -> Result<Self, Box<dyn std::error::Error>> {
         let model = Arc::new(RwLock::new(TextModel {
             tokenizer: Some("bert-base-uncased".to_string()),
             embedding_dim: 768,
@@ -69,7 +79,10 @@ impl TextProcessor {
             },
         }));
 
-        Ok(TextProcessor { model, metrics })
+        let native_dim = model.read().unwrap().embedding_dim;
+        let projection = EmbeddingProjection::new(native_dim, embedding_dim, 0x7E47);
+
+        Ok(TextProcessor { model, metrics, projection })
     }
 
     async fn extract_text_features(&self, text: &str) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
@@ -123,6 +136,7 @@ impl TextProcessor {
     }
 }
 
+#[async_trait::async_trait]
 impl ModalProcessor for TextProcessor {
     async fn process(&self, input: ModalInput) -> Result<ProcessedModal, Box<dyn std::error::Error>> {
         let start_time = std::time::Instant::now();
@@ -131,7 +145,7 @@ impl ModalProcessor for TextProcessor {
         let features = self.extract_text_features(&text).await?;
         
         // Generate embeddings (simplified - in production use actual embeddings)
-        let embeddings = self.generate_text_embeddings(&text).await?;
+        let embeddings = self.projection.project(&self.generate_text_embeddings(&text).await?);
         
         let processing_time = start_time.elapsed().as_millis() as u64;
         let confidence = self.calculate_text_confidence(&text, &features);
@@ -187,6 +201,11 @@ impl TextProcessor {
 
 impl ImageProcessor {
     pub async fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::with_embedding_dim(EMBEDDING_DIM).await
+    }
+
+    /// Processor whose embeddings are projected to `embedding_dim` values
+    pub async fn with_embedding_dim(embedding_dim: usize) -> Result<Self, Box<dyn std::error::Error>> {
         let model = Arc::new(RwLock::new(VisionModel {
             model_type: "resnet50".to_string(),
             input_size: (224, 224),
@@ -205,7 +224,10 @@ impl ImageProcessor {
             },
         }));
 
-        Ok(ImageProcessor { model, metrics })
+        let native_dim = model.read().unwrap().embedding_dim;
+        let projection = EmbeddingProjection::new(native_dim, embedding_dim, 0x1A6E);
+
+        Ok(ImageProcessor { model, metrics, projection })
     }
 
     async fn extract_image_features(&self, image_data: &[u8]) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
@@ -328,12 +350,13 @@ impl ImageProcessor {
     }
 }
 
+#[async_trait::async_trait]
 impl ModalProcessor for ImageProcessor {
     async fn process(&self, input: ModalInput) -> Result<ProcessedModal, Box<dyn std::error::Error>> {
         let start_time = std::time::Instant::now();
         
         let features = self.extract_image_features(&input.data).await?;
-        let embeddings = self.generate_image_embeddings(&input.data).await?;
+        let embeddings = self.projection.project(&self.generate_image_embeddings(&input.data).await?);
         
         let processing_time = start_time.elapsed().as_millis() as u64;
         let confidence = self.calculate_image_confidence(&input.data, &features)?;
@@ -389,6 +412,11 @@ impl ImageProcessor {
 
 impl AudioProcessor {
     pub async fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::with_embedding_dim(EMBEDDING_DIM).await
+    }
+
+    /// Processor whose embeddings are projected to `embedding_dim` values
+    pub async fn with_embedding_dim(embedding_dim: usize) -> Result<Self, Box<dyn std::error::Error>> {
         let model = Arc::new(RwLock::new(AudioModel {
             sample_rate: 44100,
             window_size: 1024,
@@ -407,7 +435,10 @@ impl AudioProcessor {
             },
         }));
 
-        Ok(AudioProcessor { model, metrics })
+        let native_dim = model.read().unwrap().embedding_dim;
+        let projection = EmbeddingProjection::new(native_dim, embedding_dim, 0xA0D1);
+
+        Ok(AudioProcessor { model, metrics, projection })
     }
 
     async fn extract_audio_features(&self, audio_data: &[u8]) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
@@ -516,12 +547,13 @@ impl AudioProcessor {
     }
 }
 
+#[async_trait::async_trait]
 impl ModalProcessor for AudioProcessor {
     async fn process(&self, input: ModalInput) -> Result<ProcessedModal, Box<dyn std::error::Error>> {
         let start_time = std::time::Instant::now();
         
         let features = self.extract_audio_features(&input.data).await?;
-        let embeddings = self.generate_audio_embeddings(&input.data).await?;
+        let embeddings = self.projection.project(&self.generate_audio_embeddings(&input.data).await?);
         
         let processing_time = start_time.elapsed().as_millis() as u64;
         let confidence = self.calculate_audio_confidence(&input.data, &features);
@@ -577,6 +609,11 @@ impl AudioProcessor {
 
 impl VideoProcessor {
     pub async fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::with_embedding_dim(EMBEDDING_DIM).await
+    }
+
+    /// Processor whose embeddings are projected to `embedding_dim` values
+    pub async fn with_embedding_dim(embedding_dim: usize) -> Result<Self, Box<dyn std::error::Error>> {
         let model = Arc::new(RwLock::new(VideoModel {
             frame_rate: 30.0,
             frame_size: (640, 480),
@@ -596,7 +633,10 @@ impl VideoProcessor {
             },
         }));
 
-        Ok(VideoProcessor { model, metrics })
+        let native_dim = model.read().unwrap().embedding_dim;
+        let projection = EmbeddingProjection::new(native_dim, embedding_dim, 0x51DE);
+
+        Ok(VideoProcessor { model, metrics, projection })
     }
 
     async fn extract_video_features(&self, video_data: &[u8]) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
@@ -705,12 +745,13 @@ impl VideoProcessor {
     }
 }
 
+#[async_trait::async_trait]
 impl ModalProcessor for VideoProcessor {
     async fn process(&self, input: ModalInput) -> Result<ProcessedModal, Box<dyn std::error::Error>> {
         let start_time = std::time::Instant::now();
         
         let features = self.extract_video_features(&input.data).await?;
-        let embeddings = self.generate_video_embeddings(&input.data).await?;
+        let embeddings = self.projection.project(&self.generate_video_embeddings(&input.data).await?);
         
         let processing_time = start_time.elapsed().as_millis() as u64;
         let confidence = self.calculate_video_confidence(&input.data, &features);
@@ -762,4 +803,49 @@ impl VideoProcessor {
         
         (size_score + feature_score.min(1.0)) / 2.0
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(modality: ModalityType, data: Vec<u8>) -> ModalInput {
+        ModalInput {
+            id: Uuid::new_v4(),
+            modality,
+            data,
+            metadata: HashMap::new(),
+            timestamp: chrono::Utc::now(),
+            source: "test".to_string(),
+        }
+    }
+
+    fn png_bytes() -> Vec<u8> {
+        let image: RgbImage = ImageBuffer::from_fn(16, 16, |x, y| image::Rgb([(x * 16) as u8, (y * 16) as u8, 128]));
+        let mut bytes = Vec::new();
+        image::DynamicImage::ImageRgb8(image)
+            .write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageOutputFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[tokio::test]
+    async fn test_every_processor_emits_shared_embedding_dim() {
+        let sample: Vec<u8> = (0..4096).map(|i| (i % 251) as u8).collect();
+
+        let text = TextProcessor::new().await.unwrap().process(input(ModalityType::Text, b"a good day".to_vec())).await.unwrap();
+        let image = ImageProcessor::new().await.unwrap().process(input(ModalityType::Image, png_bytes())).await.unwrap();
+        let audio = AudioProcessor::new().await.unwrap().process(input(ModalityType::Audio, sample.clone())).await.unwrap();
+        let video = VideoProcessor::new().await.unwrap().process(input(ModalityType::Video, sample)).await.unwrap();
+
+        for processed in [&text, &image, &audio, &video] {
+            assert_eq!(processed.embeddings.len(), EMBEDDING_DIM, "{:?}", processed.modality);
+        }
+
+        let custom = TextProcessor::with_embedding_dim(64).await.unwrap();
+        let processed = custom.process(input(ModalityType::Text, b"a good day".to_vec())).await.unwrap();
+        assert_eq!(processed.embeddings.len(), 64);
+        assert!(super::super::embedding::validate_embedding(&processed, 64).is_ok());
+        assert!(super::super::embedding::validate_embedding(&processed, EMBEDDING_DIM).is_err());
+    }
+}
@@ -2,6 +2,9 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use std::collections::{HashMap, VecDeque};
 use chrono::{DateTime, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 use prometheus::{
     Counter, CounterVec, Gauge, GaugeVec, Histogram, HistogramVec, 
     Registry, Encoder, TextEncoder
@@ -9,6 +12,9 @@ use prometheus::{
 use sysinfo::{System, SystemExt, ProcessExt, CpuExt, NetworkExt, DiskExt};
 use tracing::{info, warn, error};
 
+/// Recent samples kept per custom metric for percentile queries
+const CUSTOM_METRIC_WINDOW: usize = 1000;
+
 /// High-performance metrics collection system
 pub struct MetricsCollector {
     registry: Registry,
@@ -32,7 +38,8 @@ pub struct MetricsCollector {
     ai_cache_hit_ratio: Gauge,
     
     // Custom metrics
-    custom_metrics: Arc<RwLock<HashMap<String, f64>>>,
+    custom_metrics: Arc<RwLock<HashMap<String, OnlineMetric>>>,
+    sampler: Arc<tokio::sync::Mutex<MetricSampler>>,
     
     // Historical data
     historical_metrics: Arc<RwLock<VecDeque<SystemMetrics>>>,
@@ -40,6 +47,13 @@ pub struct MetricsCollector {
 
 impl MetricsCollector {
     pub fn new() -> Self {
+        Self::with_sampling(1.0, None)
+    }
+
+    /// Collector that keeps each custom metric sample with probability
+    /// `sampling_rate`. A fixed `seed` makes the sampling decisions
+    /// reproducible, which tests rely on.
+    pub fn with_sampling(sampling_rate: f64, seed: Option<u64>) -> Self {
         let registry = Registry::new();
         
         // Initialize system metrics
@@ -124,6 +138,7 @@ impl MetricsCollector {
             ai_token_count,
             ai_cache_hit_ratio,
             custom_metrics: Arc::new(RwLock::new(HashMap::new())),
+            sampler: Arc::new(tokio::sync::Mutex::new(MetricSampler::new(sampling_rate, seed))),
             historical_metrics: Arc::new(RwLock::new(VecDeque::with_capacity(1000))),
         }
     }
@@ -273,8 +288,33 @@ impl MetricsCollector {
     }
 
     pub async fn record_custom_metric(&self, name: &str, value: f64, _tags: HashMap<String, String>) {
+        let keep = self.sampler.lock().await.keep();
+        
         let mut metrics = self.custom_metrics.write().await;
-        metrics.insert(name.to_string(), value);
+        let metric = metrics
+            .entry(name.to_string())
+            .or_insert_with(|| OnlineMetric::new(CUSTOM_METRIC_WINDOW));
+        
+        if keep {
+            metric.record(value);
+        } else {
+            metric.skip();
+        }
+    }
+
+    /// Aggregates over every sample ever recorded for a custom metric
+    pub async fn get_metric_summary(&self, name: &str) -> Option<MetricSummary> {
+        let metrics = self.custom_metrics.read().await;
+        metrics.get(name).map(OnlineMetric::summary)
+    }
+
+    /// Percentiles over the most recent samples of a custom metric
+    pub async fn get_metric_percentiles(&self, name: &str) -> Option<super::LatencyPercentiles> {
+        let window: Vec<f64> = {
+            let metrics = self.custom_metrics.read().await;
+            metrics.get(name)?.window.iter().copied().collect()
+        };
+        Some(self.calculate_latency_percentiles(window).await)
     }
 
     pub async fn check_thresholds(&self, thresholds: &super::AlertThresholds) -> Result<(), Box<dyn std::error::Error>> {
@@ -348,6 +388,105 @@ impl MetricsCollector {
     }
 }
 
+/// Count, mean, variance and range of a custom metric. Under sampling,
+/// `count` is still the exact number of recorded values while the other
+/// statistics are estimated from the `sampled` subset; because every value
+/// is kept with the same probability those estimates are unbiased.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricSummary {
+    pub count: u64,
+    pub sampled: u64,
+    pub mean: f64,
+    /// Sample variance; zero until two samples have been kept
+    pub variance: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+/// Bernoulli sampler deciding which custom metric samples are kept
+#[derive(Debug)]
+struct MetricSampler {
+    rate: f64,
+    rng: StdRng,
+}
+
+impl MetricSampler {
+    fn new(rate: f64, seed: Option<u64>) -> Self {
+        Self {
+            rate: rate.clamp(0.0, 1.0),
+            rng: match seed {
+                Some(seed) => StdRng::seed_from_u64(seed),
+                None => StdRng::from_entropy(),
+            },
+        }
+    }
+
+    fn keep(&mut self) -> bool {
+        self.rate >= 1.0 || self.rng.gen::<f64>() < self.rate
+    }
+}
+
+/// Constant-memory running statistics for one metric: Welford's algorithm
+/// for mean/variance plus a bounded window of recent samples for percentiles
+#[derive(Debug, Clone)]
+struct OnlineMetric {
+    count: u64,
+    sampled: u64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+    window: VecDeque<f64>,
+    window_size: usize,
+}
+
+impl OnlineMetric {
+    fn new(window_size: usize) -> Self {
+        Self {
+            count: 0,
+            sampled: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            window: VecDeque::with_capacity(window_size),
+            window_size,
+        }
+    }
+
+    fn record(&mut self, value: f64) {
+        self.count += 1;
+        self.sampled += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.sampled as f64;
+        self.m2 += delta * (value - self.mean);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+
+        if self.window.len() == self.window_size {
+            self.window.pop_front();
+        }
+        self.window.push_back(value);
+    }
+
+    /// Count a value dropped by sampling
+    fn skip(&mut self) {
+        self.count += 1;
+    }
+
+    fn summary(&self) -> MetricSummary {
+        let has_samples = self.sampled > 0;
+        MetricSummary {
+            count: self.count,
+            sampled: self.sampled,
+            mean: self.mean,
+            variance: if self.sampled > 1 { self.m2 / (self.sampled - 1) as f64 } else { 0.0 },
+            min: if has_samples { self.min } else { 0.0 },
+            max: if has_samples { self.max } else { 0.0 },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -377,4 +516,60 @@ mod tests {
         assert!(prometheus_output.contains("system_cpu_usage"));
         assert!(prometheus_output.contains("http_request_duration_seconds"));
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_online_metric_summary_matches_batch() {
+        let collector = MetricsCollector::new();
+        let samples: Vec<f64> = (0..200_000)
+            .map(|i| 1_000.0 + (i as f64 * 0.37).sin() * 50.0 + (i % 97) as f64)
+            .collect();
+
+        for &sample in &samples {
+            collector.record_custom_metric("latency", sample, HashMap::new()).await;
+        }
+
+        let n = samples.len() as f64;
+        let batch_mean = samples.iter().sum::<f64>() / n;
+        let batch_variance = samples.iter().map(|x| (x - batch_mean).powi(2)).sum::<f64>() / (n - 1.0);
+
+        let summary = collector.get_metric_summary("latency").await.unwrap();
+        assert_eq!(summary.count, samples.len() as u64);
+        assert!((summary.mean - batch_mean).abs() < 1e-9 * batch_mean);
+        assert!((summary.variance - batch_variance).abs() < 1e-6 * batch_variance);
+        assert_eq!(summary.min, samples.iter().copied().fold(f64::INFINITY, f64::min));
+        assert_eq!(summary.max, samples.iter().copied().fold(f64::NEG_INFINITY, f64::max));
+
+        // Only the most recent window is retained for percentiles
+        let metrics = collector.custom_metrics.read().await;
+        assert_eq!(metrics["latency"].window.len(), CUSTOM_METRIC_WINDOW);
+        drop(metrics);
+        assert!(collector.get_metric_percentiles("latency").await.is_some());
+        assert!(collector.get_metric_summary("missing").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sampling_keeps_half_with_unbiased_mean() {
+        let collector = MetricsCollector::with_sampling(0.5, Some(7));
+        let samples: Vec<f64> = (0..100_000).map(|i| (i % 1000) as f64).collect();
+
+        for &sample in &samples {
+            collector.record_custom_metric("requests", sample, HashMap::new()).await;
+        }
+
+        let batch_mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let summary = collector.get_metric_summary("requests").await.unwrap();
+        assert_eq!(summary.count, samples.len() as u64);
+        let kept = summary.sampled as f64 / samples.len() as f64;
+        assert!((kept - 0.5).abs() < 0.01, "kept {}", kept);
+        assert!((summary.mean - batch_mean).abs() < 0.01 * batch_mean, "{} vs {}", summary.mean, batch_mean);
+
+        // The same seed makes the same sampling decisions
+        let replay = MetricsCollector::with_sampling(0.5, Some(7));
+        for &sample in &samples {
+            replay.record_custom_metric("requests", sample, HashMap::new()).await;
+        }
+        let replayed = replay.get_metric_summary("requests").await.unwrap();
+        assert_eq!(replayed.sampled, summary.sampled);
+        assert_eq!(replayed.mean, summary.mean);
+    }
+}
@@ -36,7 +36,11 @@ pub struct PerformanceMonitor {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerformanceConfig {
+    /// Fraction of custom metric samples kept, in [0, 1]
     pub sampling_rate: f64,
+    /// Fixed seed for sampling decisions, for reproducible runs
+    #[serde(default)]
+    pub sampling_seed: Option<u64>,
     pub buffer_size: usize,
     pub alert_thresholds: AlertThresholds,
     pub optimization_enabled: bool,
@@ -57,6 +61,7 @@ impl Default for PerformanceConfig {
     fn default() -> Self {
         Self {
             sampling_rate: 0.1,
+            sampling_seed: None,
             buffer_size: 10000,
             alert_thresholds: AlertThresholds {
                 cpu_percent: 80.0,
@@ -74,7 +79,7 @@ impl Default for PerformanceConfig {
 
 impl PerformanceMonitor {
     pub fn new(config: PerformanceConfig) -> Self {
-        let metrics_collector = Arc::new(MetricsCollector::new());
+        let metrics_collector = Arc::new(MetricsCollector::with_sampling(config.sampling_rate, config.sampling_seed));
         let profiler = Arc::new(PerformanceProfiler::new());
         let optimizer = Arc::new(AdaptiveOptimizer::new());
         let predictor = Arc::new(PerformancePredictor::new());
@@ -212,6 +217,11 @@ impl PerformanceMonitor {
         self.metrics_collector.record_custom_metric(name, value, tags).await;
     }
 
+    /// Get running count, mean, variance, min and max for a custom metric
+    pub async fn get_metric_summary(&self, name: &str) -> Option<MetricSummary> {
+        self.metrics_collector.get_metric_summary(name).await
+    }
+
     /// Create span for distributed tracing
     pub fn create_span(&self, name: &str) -> Span {
         self.telemetry.create_span(name)
@@ -7,6 +7,9 @@ use anyhow::Result;
 use std::collections::HashMap;
 
 use crate::store::{AppState, ConfigValue};
+use crate::utils::logger::{self, LogLevel, LogRecord};
+use crate::utils::config::{self, AppConfig, ConfigError};
+use crate::utils::error::{CommandError, CommandResult};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppInfo {
@@ -75,42 +78,34 @@ pub async fn get_system_info() -> Result<SystemInfo, String> {
 
 // File system commands
 
+/// The app config, created with defaults on first read
 #[tauri::command]
-pub async fn read_config_file(app: AppHandle, file_path: String) -> Result<String, String> {
-    use std::fs;
-    
-    let app_dir = app.path_resolver()
-        .app_config_dir()
-        .ok_or("Failed to get app config directory")?;
-    
-    let full_path = app_dir.join(&file_path);
-    
-    fs::read_to_string(full_path)
-        .map_err(|e| format!("Failed to read config file '{}': {}", file_path, e))
+pub async fn read_config_file(app: AppHandle) -> CommandResult<AppConfig> {
+    let path = config_path(&app)?;
+    config::load_or_create(&path).map_err(config_error)
 }
 
+/// Validate `config` against the `AppConfig` schema and replace the stored
+/// config with it. Returns the config as stored, with defaults filled in.
 #[tauri::command]
-pub async fn write_config_file(
-    app: AppHandle,
-    file_path: String,
-    content: String,
-) -> Result<(), String> {
-    use std::fs;
-    
-    let app_dir = app.path_resolver()
+pub async fn write_config_file(app: AppHandle, config: serde_json::Value) -> CommandResult<AppConfig> {
+    let config = AppConfig::from_json(config).map_err(config_error)?;
+    config::save(&config_path(&app)?, &config).map_err(config_error)?;
+    Ok(config)
+}
+
+fn config_path(app: &AppHandle) -> CommandResult<std::path::PathBuf> {
+    app.path_resolver()
         .app_config_dir()
-        .ok_or("Failed to get app config directory")?;
-    
-    let full_path = app_dir.join(&file_path);
-    
-    // Create parent directories if they don't exist
-    if let Some(parent) = full_path.parent() {
-        fs::create_dir_all(parent)
-            .map_err(|e| format!("Failed to create directories: {}", e))?;
+        .map(|dir| dir.join(config::CONFIG_FILE))
+        .ok_or_else(|| CommandError::Internal("Failed to get app config directory".to_string()))
+}
+
+fn config_error(error: ConfigError) -> CommandError {
+    match error {
+        ConfigError::Invalid(_) => CommandError::InvalidArgument(error.to_string()),
+        ConfigError::Io { .. } => CommandError::Internal(error.to_string()),
     }
-    
-    fs::write(full_path, content)
-        .map_err(|e| format!("Failed to write config file '{}': {}", file_path, e))
 }
 
 #[tauri::command]
@@ -135,8 +130,7 @@ pub async fn store_get(
     state: State<'_, AppState>,
     key: String,
 ) -> Result<Option<ConfigValue>, String> {
-    let store = state.store.lock().await;
-    Ok(store.get(&key).cloned())
+    state.get(&key).await.map_err(|e| format!("Failed to read config: {}", e))
 }
 
 #[tauri::command]
@@ -145,9 +139,7 @@ pub async fn store_set(
     key: String,
     value: ConfigValue,
 ) -> Result<(), String> {
-    let mut store = state.store.lock().await;
-    store.insert(key, value);
-    Ok(())
+    state.set(key, value).await.map_err(|e| format!("Failed to write config: {}", e))
 }
 
 #[tauri::command]
@@ -155,15 +147,12 @@ pub async fn store_delete(
     state: State<'_, AppState>,
     key: String,
 ) -> Result<bool, String> {
-    let mut store = state.store.lock().await;
-    Ok(store.remove(&key).is_some())
+    state.delete(&key).await.map_err(|e| format!("Failed to delete config: {}", e))
 }
 
 #[tauri::command]
 pub async fn store_clear(state: State<'_, AppState>) -> Result<(), String> {
-    let mut store = state.store.lock().await;
-    store.clear();
-    Ok(())
+    state.clear().await.map_err(|e| format!("Failed to clear config: {}", e))
 }
 
 // Development commands
@@ -204,31 +193,16 @@ pub async fn open_devtools(window: Window) -> Result<(), String> {
 }
 
 #[tauri::command]
-pub async fn get_logs(app: AppHandle, lines: Option<usize>) -> Result<Vec<String>, String> {
-    use std::fs;
-    
-    let log_dir = app.path_resolver()
-        .app_log_dir()
-        .ok_or("Failed to get log directory")?;
-    
-    let log_file = log_dir.join("katalyst.log");
-    
-    if !log_file.exists() {
-        return Ok(vec!["No log file found".to_string()]);
-    }
-    
-    let content = fs::read_to_string(log_file)
-        .map_err(|e| format!("Failed to read log file: {}", e))?;
-    
-    let mut lines_vec: Vec<String> = content.lines().map(|s| s.to_string()).collect();
-    
-    if let Some(limit) = lines {
-        if lines_vec.len() > limit {
-            lines_vec = lines_vec.into_iter().rev().take(limit).rev().collect();
-        }
-    }
+pub async fn get_logs(
+    level_filter: Option<String>,
+    limit: Option<usize>,
+    since: Option<u64>,
+) -> Result<Vec<LogRecord>, String> {
+    let level_filter = level_filter
+        .map(|level| level.parse::<LogLevel>())
+        .transpose()?;
     
-    Ok(lines_vec)
+    Ok(logger::log_buffer().query(level_filter, limit, since))
 }
 
 // Utility functions
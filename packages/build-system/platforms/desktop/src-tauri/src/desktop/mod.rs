@@ -15,6 +15,15 @@ pub mod themes;
 pub mod shortcuts;
 
 use window_manager::WindowManager;
+use crate::utils::error::{require_window, CommandError, CommandResult};
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum TransparencyError {
+    #[error("Window transparency is not supported here: {0}")]
+    Unsupported(String),
+    #[error("Failed to access the native window: {0}")]
+    NativeWindow(String),
+}
 
 // Desktop feature setup
 pub fn setup_desktop_features(app: AppHandle) -> Result<()> {
@@ -86,41 +95,44 @@ pub async fn create_desktop_window(
     url: String,
     width: Option<f64>,
     height: Option<f64>,
-) -> Result<String, String> {
+) -> CommandResult<String> {
     let window_url = if url.starts_with("http") {
-        WindowUrl::External(url.parse().map_err(|e| format!("Invalid URL: {}", e))?)
+        WindowUrl::External(url.parse().map_err(|e| CommandError::InvalidArgument(format!("Invalid URL: {}", e)))?)
     } else {
         WindowUrl::App(url.into())
     };
     
+    if app.get_window(&label).is_some() {
+        return Err(CommandError::InvalidArgument(format!("Window '{}' already exists", label)));
+    }
+    
     let window = WindowBuilder::new(&app, &label, window_url)
         .title(&title)
         .inner_size(width.unwrap_or(800.0), height.unwrap_or(600.0))
         .center()
         .resizable(true)
         .build()
-        .map_err(|e| format!("Failed to create window: {}", e))?;
+        .map_err(|e| CommandError::internal("Failed to create window", e))?;
     
     Ok(format!("Window '{}' created successfully", label))
 }
 
 // Tauri command: Toggle always on top
 #[tauri::command]
-pub async fn toggle_always_on_top(window: Window) -> Result<bool, String> {
+pub async fn toggle_always_on_top(window: Window) -> CommandResult<bool> {
     let is_always_on_top = window.is_always_on_top()
-        .map_err(|e| format!("Failed to get always on top state: {}", e))?;
+        .map_err(|e| CommandError::internal("Failed to get always on top state", e))?;
     
     window.set_always_on_top(!is_always_on_top)
-        .map_err(|e| format!("Failed to set always on top: {}", e))?;
+        .map_err(|e| CommandError::internal("Failed to set always on top", e))?;
     
     Ok(!is_always_on_top)
 }
 
 // Tauri command: Set window transparency
 #[tauri::command]
-pub async fn set_window_transparency(window: Window, transparency: f64) -> Result<(), String> {
-    // Clamp transparency between 0.0 and 1.0
-    let alpha = transparency.max(0.0).min(1.0);
+pub async fn set_window_transparency(window: Window, transparency: f64) -> CommandResult<()> {
+    let alpha = clamp_alpha(transparency);
     
     #[cfg(target_os = "macos")]
     {
@@ -128,7 +140,7 @@ pub async fn set_window_transparency(window: Window, transparency: f64) -> Resul
         use cocoa::base::id;
         use objc::{msg_send, sel, sel_impl};
         
-        let ns_window = window.ns_window().map_err(|e| format!("Failed to get NSWindow: {}", e))?;
+        let ns_window = window.ns_window().map_err(|e| TransparencyError::NativeWindow(e.to_string()))?;
         unsafe {
             let _: () = msg_send![ns_window as id, setAlphaValue: alpha];
         }
@@ -139,7 +151,7 @@ pub async fn set_window_transparency(window: Window, transparency: f64) -> Resul
         use windows::Win32::Foundation::HWND;
         use windows::Win32::UI::WindowsAndMessaging::{SetLayeredWindowAttributes, SetWindowLongW, GWL_EXSTYLE, WS_EX_LAYERED, LWA_ALPHA};
         
-        let hwnd = HWND(window.hwnd().map_err(|e| format!("Failed to get HWND: {}", e))?.0);
+        let hwnd = HWND(window.hwnd().map_err(|e| TransparencyError::NativeWindow(e.to_string()))?.0);
         unsafe {
             SetWindowLongW(hwnd, GWL_EXSTYLE, WS_EX_LAYERED.0 as i32);
             SetLayeredWindowAttributes(hwnd, 0, (alpha * 255.0) as u8, LWA_ALPHA);
@@ -148,27 +160,71 @@ pub async fn set_window_transparency(window: Window, transparency: f64) -> Resul
     
     #[cfg(target_os = "linux")]
     {
-        // GTK transparency implementation
-        // Note: This requires the window to be created with transparency support
-        eprintln!("Window transparency not yet implemented for Linux");
+        // GTK objects live on the main thread, so the opacity is set there
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let target = window.clone();
+        window
+            .run_on_main_thread(move || {
+                let result = target
+                    .gtk_window()
+                    .map_err(|e| TransparencyError::NativeWindow(e.to_string()))
+                    .and_then(|gtk_window| set_gtk_opacity(&gtk_window, alpha));
+                let _ = tx.send(result);
+            })
+            .map_err(|e| TransparencyError::NativeWindow(e.to_string()))?;
+        
+        rx.await
+            .map_err(|_| TransparencyError::NativeWindow("main thread dropped the request".to_string()))??;
+    }
+    
+    Ok(())
+}
+
+/// Opacity for a requested transparency: clamped to 0.0..=1.0, with NaN
+/// treated as fully opaque
+pub fn clamp_alpha(transparency: f64) -> f64 {
+    if transparency.is_nan() {
+        1.0
+    } else {
+        transparency.clamp(0.0, 1.0)
+    }
+}
+
+/// Set a GTK window's opacity. GTK silently ignores opacity without a
+/// compositing window manager, so that case is reported as unsupported.
+#[cfg(target_os = "linux")]
+fn set_gtk_opacity<W: gtk::glib::IsA<gtk::Widget>>(window: &W, alpha: f64) -> Result<(), TransparencyError> {
+    use gtk::prelude::*;
+    
+    let screen = window
+        .screen()
+        .ok_or_else(|| TransparencyError::Unsupported("window is not attached to a screen".to_string()))?;
+    if !screen.is_composited() {
+        return Err(TransparencyError::Unsupported("no compositing window manager is running".to_string()));
     }
     
+    window.set_opacity(alpha);
     Ok(())
 }
 
 // Tauri command: Capture screenshot
 #[tauri::command]
-pub async fn capture_screenshot(app: AppHandle, window_label: Option<String>) -> Result<String, String> {
+pub async fn capture_screenshot(
+    app: AppHandle,
+    window_label: Option<String>,
+    include_data_url: Option<bool>,
+) -> CommandResult<screenshot::ScreenshotCapture> {
     let label = window_label.unwrap_or_else(|| "main".to_string());
-    screenshot::capture_window_screenshot(app, &label)
-        .map_err(|e| format!("Failed to capture screenshot: {}", e))
+    require_window(app.get_window(&label), &label)?;
+    screenshot::capture_window(&app, &label, include_data_url.unwrap_or(false))
+        .map_err(|e| CommandError::internal("Failed to capture screenshot", format!("{:#}", e)))
 }
 
 // Tauri command: Set application theme
 #[tauri::command]
-pub async fn set_theme(app: AppHandle, theme: String) -> Result<(), String> {
+pub async fn set_theme(app: AppHandle, theme: String) -> CommandResult<()> {
     themes::apply_theme(&app, &theme)
-        .map_err(|e| format!("Failed to set theme: {}", e))
+        .map_err(|e| CommandError::internal("Failed to set theme", e))
 }
 
 // Window management utilities
@@ -188,4 +244,35 @@ pub fn center_window_on_monitor(window: &Window, monitor_size: PhysicalSize<u32>
     
     window.set_position(PhysicalPosition::new(x.max(0), y.max(0)))?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_alpha() {
+        assert_eq!(clamp_alpha(-0.5), 0.0);
+        assert_eq!(clamp_alpha(0.4), 0.4);
+        assert_eq!(clamp_alpha(3.0), 1.0);
+        assert_eq!(clamp_alpha(f64::NAN), 1.0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_linux_transparency_applies_or_reports_unsupported() {
+        use gtk::prelude::*;
+        
+        // Headless environments have no display to open
+        if gtk::init().is_err() {
+            return;
+        }
+        
+        let window = gtk::Window::new(gtk::WindowType::Toplevel);
+        match set_gtk_opacity(&window, clamp_alpha(0.5)) {
+            Ok(()) => assert!((window.opacity() - 0.5).abs() < 0.01),
+            Err(TransparencyError::Unsupported(_)) => {}
+            Err(other) => panic!("unexpected transparency error: {}", other),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+// Screenshot capture for Katalyst windows
+// Captures the monitor showing a window, crops to the window and saves a PNG
+// under the app data directory
+
+use anyhow::{anyhow, Context, Result};
+use image::{ImageOutputFormat, RgbaImage};
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::AppHandle;
+use xcap::Monitor;
+
+const SCREENSHOT_DIR: &str = "screenshots";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreenshotCapture {
+    pub path: String,
+    pub width: u32,
+    pub height: u32,
+    /// `data:image/png;base64,...` form of the file, when requested
+    pub data_url: Option<String>,
+}
+
+/// Rectangle in desktop coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    fn right(&self) -> i64 {
+        self.x as i64 + self.width as i64
+    }
+
+    fn bottom(&self) -> i64 {
+        self.y as i64 + self.height as i64
+    }
+
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let left = self.x.max(other.x);
+        let top = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        (right > left as i64 && bottom > top as i64).then(|| Rect {
+            x: left,
+            y: top,
+            width: (right - left as i64) as u32,
+            height: (bottom - top as i64) as u32,
+        })
+    }
+
+    fn area(&self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+}
+
+pub fn init_screenshot_service(app: &AppHandle) -> Result<()> {
+    std::fs::create_dir_all(screenshot_dir(app)?)?;
+    Ok(())
+}
+
+fn screenshot_dir(app: &AppHandle) -> Result<PathBuf> {
+    let app_dir = app.path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| anyhow!("Failed to get app data directory"))?;
+    Ok(app_dir.join(SCREENSHOT_DIR))
+}
+
+/// Capture the window labelled `label` to a PNG in the app data directory
+pub fn capture_window_screenshot(app: AppHandle, label: &str) -> Result<String> {
+    capture_window(&app, label, false).map(|capture| capture.path)
+}
+
+pub fn capture_window(app: &AppHandle, label: &str, include_data_url: bool) -> Result<ScreenshotCapture> {
+    use tauri::Manager;
+
+    let window = app.get_window(label)
+        .ok_or_else(|| anyhow!("Window '{}' not found", label))?;
+    let position = window.outer_position()?;
+    let size = window.outer_size()?;
+    let region = Rect {
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+    };
+
+    let image = capture_region(region)?;
+    let png = encode_png(&image)?;
+
+    let dir = screenshot_dir(app)?;
+    std::fs::create_dir_all(&dir)?;
+    let millis = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_millis());
+    let path = dir.join(format!("{}-{}.png", label, millis));
+    std::fs::write(&path, &png).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    Ok(ScreenshotCapture {
+        path: path.to_string_lossy().into_owned(),
+        width: image.width(),
+        height: image.height(),
+        data_url: include_data_url.then(|| {
+            use base64::Engine;
+            format!("data:image/png;base64,{}", base64::engine::general_purpose::STANDARD.encode(&png))
+        }),
+    })
+}
+
+/// Capture `region` from the monitor it overlaps most. A region spanning
+/// several monitors is clipped to that monitor.
+pub fn capture_region(region: Rect) -> Result<RgbaImage> {
+    let monitors = Monitor::all().map_err(|e| anyhow!("Failed to list monitors: {}", e))?;
+    let bounds: Vec<Rect> = monitors.iter().map(monitor_rect).collect();
+    let index = select_monitor(&bounds, &region)
+        .ok_or_else(|| anyhow!("Region {:?} is not on any monitor", region))?;
+
+    let captured = monitors[index]
+        .capture_image()
+        .map_err(|e| anyhow!("Failed to capture monitor: {}", e))?;
+    let (width, height) = (captured.width(), captured.height());
+    let image = RgbaImage::from_raw(width, height, captured.into_raw())
+        .ok_or_else(|| anyhow!("Monitor capture returned a malformed buffer"))?;
+
+    crop_to_region(&image, &bounds[index], &region)
+        .ok_or_else(|| anyhow!("Region {:?} is not on the captured monitor", region))
+}
+
+fn monitor_rect(monitor: &Monitor) -> Rect {
+    Rect {
+        x: monitor.x(),
+        y: monitor.y(),
+        width: monitor.width(),
+        height: monitor.height(),
+    }
+}
+
+/// Index of the monitor with the largest overlap with `region`
+pub fn select_monitor(monitors: &[Rect], region: &Rect) -> Option<usize> {
+    monitors
+        .iter()
+        .enumerate()
+        .filter_map(|(index, monitor)| monitor.intersection(region).map(|overlap| (index, overlap.area())))
+        .max_by_key(|&(_, area)| area)
+        .map(|(index, _)| index)
+}
+
+/// Crop a capture of `monitor` to the part of `region` it shows. The capture
+/// may be at a higher pixel density than monitor coordinates, so offsets are
+/// scaled by the capture-to-monitor ratio.
+pub fn crop_to_region(image: &RgbaImage, monitor: &Rect, region: &Rect) -> Option<RgbaImage> {
+    let visible = monitor.intersection(region)?;
+    let scale_x = image.width() as f64 / monitor.width as f64;
+    let scale_y = image.height() as f64 / monitor.height as f64;
+
+    let x = ((visible.x - monitor.x) as f64 * scale_x).round() as u32;
+    let y = ((visible.y - monitor.y) as f64 * scale_y).round() as u32;
+    let width = ((visible.width as f64 * scale_x).round() as u32).min(image.width() - x);
+    let height = ((visible.height as f64 * scale_y).round() as u32).min(image.height() - y);
+
+    Some(image::imageops::crop_imm(image, x, y, width, height).to_image())
+}
+
+pub fn encode_png(image: &RgbaImage) -> Result<Vec<u8>> {
+    let mut png = Vec::new();
+    image.write_to(&mut Cursor::new(&mut png), ImageOutputFormat::Png)?;
+    Ok(png)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_region_selects_monitor_with_largest_overlap() {
+        let monitors = [
+            Rect { x: 0, y: 0, width: 1920, height: 1080 },
+            Rect { x: 1920, y: 0, width: 2560, height: 1440 },
+        ];
+        // Mostly on the second monitor
+        let window = Rect { x: 1800, y: 100, width: 800, height: 600 };
+        assert_eq!(select_monitor(&monitors, &window), Some(1));
+        assert_eq!(select_monitor(&monitors, &Rect { x: -900, y: 0, width: 100, height: 100 }), None);
+
+        // A 2x capture of the second monitor, clipped at its left edge
+        let capture = RgbaImage::new(5120, 2880);
+        let cropped = crop_to_region(&capture, &monitors[1], &window).unwrap();
+        assert_eq!(cropped.dimensions(), (680 * 2, 600 * 2));
+    }
+
+    #[test]
+    fn test_capture_produces_png_of_region_size() {
+        // Headless environments have no monitors to capture
+        let Ok(monitors) = Monitor::all() else { return };
+        let Some(monitor) = monitors.first() else { return };
+
+        let bounds = monitor_rect(monitor);
+        let region = Rect { x: bounds.x + 10, y: bounds.y + 10, width: 64, height: 48 };
+        let Ok(image) = capture_region(region) else { return };
+
+        let png = encode_png(&image).unwrap();
+        let decoded = image::load_from_memory_with_format(&png, image::ImageFormat::Png).unwrap();
+        let scale = decoded.width() as f64 / 64.0;
+        assert!(scale >= 1.0);
+        assert_eq!(decoded.height(), (48.0 * scale).round() as u32);
+    }
+}
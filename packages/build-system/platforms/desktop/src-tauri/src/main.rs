@@ -56,6 +56,7 @@ fn main() {
     tauri::Builder::default()
         .manage(AppState::default())
         .manage(KatalystState::default())
+        .manage(webxr::SharedWebXRState::default())
         .system_tray(system_tray)
         .on_system_tray_event(|app, event| match event {
             SystemTrayEvent::LeftClick {
@@ -123,6 +124,23 @@ fn main() {
             // Initialize database
             store::init_database(app.handle())?;
             
+            // Permissions may have changed in system settings while closed
+            #[cfg(mobile)]
+            {
+                let handle = app.handle();
+                tauri::async_runtime::spawn(async move {
+                    let store = handle.state::<AppState>();
+                    let result = mobile::permissions::reconcile_with_os(&store, |name| {
+                        let handle = handle.clone();
+                        async move { mobile::permissions::os_permission_status(&handle, &name).await }
+                    })
+                    .await;
+                    if let Err(e) = result {
+                        eprintln!("Failed to reconcile permissions: {}", e);
+                    }
+                });
+            }
+            
             // Auto-updater setup
             #[cfg(not(debug_assertions))]
             {
@@ -169,6 +187,7 @@ fn main() {
             // WebXR commands
             webxr::initialize_webxr,
             webxr::create_webxr_session,
+            webxr::end_webxr_session,
             webxr::get_xr_devices,
             webxr::toggle_xr_mode,
             
@@ -176,6 +195,7 @@ fn main() {
             mobile::get_device_info,
             mobile::trigger_haptic_feedback,
             mobile::request_permissions,
+            mobile::get_permission_status,
             mobile::open_camera,
             
             // File system commands
@@ -0,0 +1,239 @@
+// Device information for the host Katalyst runs on
+// Desktop values come from the OS and Android's from its build properties.
+// iOS apps can't spawn processes, so there only the manufacturer is known
+// and everything else is reported as unknown.
+
+use super::{DeviceCapabilities, DeviceInfo};
+use anyhow::Result;
+use std::process::Command;
+use std::sync::OnceLock;
+use tauri::AppHandle;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScreenMetrics {
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f64,
+}
+
+/// Manufacturer, model and OS version, which can't change while running
+#[derive(Debug, Clone, PartialEq)]
+struct HostIdentity {
+    manufacturer: String,
+    model: String,
+    version: String,
+}
+
+pub async fn collect_device_info(app: &AppHandle) -> Result<DeviceInfo> {
+    let screen = app.primary_monitor()?.map(|monitor| ScreenMetrics {
+        width: monitor.size().width,
+        height: monitor.size().height,
+        scale_factor: monitor.scale_factor(),
+    });
+
+    Ok(host_device_info(screen))
+}
+
+/// Device info for this host, using `screen` when a monitor was found and
+/// zeros for the screen fields otherwise
+pub fn host_device_info(screen: Option<ScreenMetrics>) -> DeviceInfo {
+    let screen = screen.filter(|screen| screen.width > 0 && screen.height > 0);
+    let identity = host_identity();
+
+    DeviceInfo {
+        platform: std::env::consts::OS.to_string(),
+        is_virtual: is_virtual_machine(&identity.manufacturer, &identity.model),
+        model: identity.model.clone(),
+        version: identity.version.clone(),
+        manufacturer: identity.manufacturer.clone(),
+        screen_width: screen.map_or(0, |screen| screen.width),
+        screen_height: screen.map_or(0, |screen| screen.height),
+        pixel_density: screen.map_or(0.0, |screen| screen.scale_factor as f32),
+        capabilities: plugin_capabilities(),
+    }
+}
+
+/// Capabilities backed by plugins this build registers. The camera,
+/// biometric, geolocation and haptics plugins are only registered on mobile.
+pub fn plugin_capabilities() -> DeviceCapabilities {
+    let mobile = cfg!(mobile);
+    DeviceCapabilities {
+        has_camera: mobile,
+        has_microphone: has_microphone(),
+        has_gps: mobile,
+        has_accelerometer: mobile,
+        has_gyroscope: mobile,
+        has_magnetometer: mobile,
+        has_biometric: mobile,
+        has_nfc: false,
+        has_telephony: cfg!(target_os = "android") || cfg!(target_os = "ios"),
+        supports_haptics: mobile,
+    }
+}
+
+/// Phones and tablets always have a microphone. Linux lists capture
+/// devices as `/dev/snd/pcmC<card>D<device>c`; other desktops are unknown.
+fn has_microphone() -> Option<bool> {
+    if cfg!(mobile) {
+        return Some(true);
+    }
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+
+    let devices = std::fs::read_dir("/dev/snd").ok()?;
+    Some(devices.flatten().any(|device| {
+        let name = device.file_name();
+        let name = name.to_string_lossy();
+        name.starts_with("pcmC") && name.ends_with('c')
+    }))
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !text.is_empty()).then_some(text)
+}
+
+fn read_trimmed(path: &str) -> Option<String> {
+    let text = std::fs::read_to_string(path).ok()?.trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// Looked up once; on Windows each lookup starts PowerShell
+fn host_identity() -> &'static HostIdentity {
+    static IDENTITY: OnceLock<HostIdentity> = OnceLock::new();
+    IDENTITY.get_or_init(|| {
+        let (manufacturer, model) = hardware_identity();
+        HostIdentity {
+            manufacturer,
+            model,
+            version: os_version(),
+        }
+    })
+}
+
+/// Manufacturer and model, or "unknown" where the OS doesn't say
+fn hardware_identity() -> (String, String) {
+    let unknown = || "unknown".to_string();
+
+    if cfg!(target_os = "ios") {
+        ("Apple".to_string(), unknown())
+    } else if cfg!(target_os = "android") {
+        (
+            command_output("getprop", &["ro.product.manufacturer"]).unwrap_or_else(unknown),
+            command_output("getprop", &["ro.product.model"]).unwrap_or_else(unknown),
+        )
+    } else if cfg!(target_os = "macos") {
+        let model = command_output("sysctl", &["-n", "hw.model"]).unwrap_or_else(unknown);
+        ("Apple".to_string(), model)
+    } else if cfg!(target_os = "linux") {
+        (
+            read_trimmed("/sys/devices/virtual/dmi/id/sys_vendor").unwrap_or_else(unknown),
+            read_trimmed("/sys/devices/virtual/dmi/id/product_name").unwrap_or_else(unknown),
+        )
+    } else if cfg!(target_os = "windows") {
+        // One PowerShell start for both properties, one per line
+        command_output(
+            "powershell",
+            &["-NoProfile", "-Command", "$s = Get-CimInstance Win32_ComputerSystem; $s.Manufacturer; $s.Model"],
+        )
+        .and_then(|output| {
+            let mut lines = output.lines().map(|line| line.trim().to_string());
+            Some((lines.next()?, lines.next().filter(|model| !model.is_empty())?))
+        })
+        .unwrap_or_else(|| (unknown(), unknown()))
+    } else {
+        (unknown(), unknown())
+    }
+}
+
+fn os_version() -> String {
+    let version = if cfg!(target_os = "ios") {
+        None
+    } else if cfg!(target_os = "android") {
+        command_output("getprop", &["ro.build.version.release"])
+    } else if cfg!(target_os = "macos") {
+        command_output("sw_vers", &["-productVersion"])
+    } else if cfg!(target_os = "linux") {
+        std::fs::read_to_string("/etc/os-release")
+            .ok()
+            .and_then(|release| {
+                release
+                    .lines()
+                    .find_map(|line| line.strip_prefix("VERSION_ID="))
+                    .map(|id| id.trim_matches('"').to_string())
+            })
+            .or_else(|| read_trimmed("/proc/sys/kernel/osrelease"))
+    } else if cfg!(target_os = "windows") {
+        // "Microsoft Windows [Version 10.0.22631.3007]"
+        command_output("cmd", &["/C", "ver"]).and_then(|ver| {
+            ver.split("Version ").nth(1).map(|v| v.trim_end_matches(']').to_string())
+        })
+    } else {
+        None
+    };
+
+    version.unwrap_or_else(|| "unknown".to_string())
+}
+
+fn is_virtual_machine(manufacturer: &str, model: &str) -> bool {
+    const HYPERVISORS: [&str; 7] = ["VirtualBox", "VMware", "KVM", "QEMU", "Parallels", "Hyper-V", "Virtual Machine"];
+    if HYPERVISORS
+        .iter()
+        .any(|hypervisor| manufacturer.contains(hypervisor) || model.contains(hypervisor))
+    {
+        return true;
+    }
+
+    // Linux guests advertise the CPU hypervisor flag
+    cfg!(target_os = "linux")
+        && std::fs::read_to_string("/proc/cpuinfo").is_ok_and(|cpuinfo| {
+            cpuinfo
+                .lines()
+                .filter(|line| line.starts_with("flags"))
+                .any(|line| line.split_whitespace().any(|flag| flag == "hypervisor"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_desktop_device_info_reports_host() {
+        // Without a monitor the screen is reported as zeros rather than made up
+        let headless = host_device_info(None);
+        assert_eq!(headless.platform, std::env::consts::OS);
+        assert_eq!((headless.screen_width, headless.screen_height, headless.pixel_density), (0, 0, 0.0));
+        let empty = host_device_info(Some(ScreenMetrics { width: 0, height: 0, scale_factor: 1.0 }));
+        assert_eq!(empty.pixel_density, 0.0);
+
+        let retina = host_device_info(Some(ScreenMetrics { width: 2880, height: 1800, scale_factor: 2.0 }));
+        assert_eq!((retina.screen_width, retina.screen_height), (2880, 1800));
+        assert_eq!(retina.pixel_density, 2.0);
+
+        // Identity matches what the host exposes, or says it's unknown
+        if cfg!(target_os = "linux") {
+            let vendor = read_trimmed("/sys/devices/virtual/dmi/id/sys_vendor");
+            assert_eq!(headless.manufacturer, vendor.as_deref().unwrap_or("unknown"));
+
+            let capture_devices = std::fs::read_dir("/dev/snd").ok().map(|devices| {
+                devices
+                    .flatten()
+                    .filter(|device| {
+                        let name = device.file_name().to_string_lossy().into_owned();
+                        name.starts_with("pcmC") && name.ends_with('c')
+                    })
+                    .count()
+            });
+            assert_eq!(headless.capabilities.has_microphone, capture_devices.map(|count| count > 0));
+        }
+        assert!(std::ptr::eq(host_identity(), host_identity()));
+
+        // Desktop builds register none of the mobile hardware plugins
+        if !cfg!(mobile) {
+            assert!(!retina.capabilities.has_camera && !retina.capabilities.supports_haptics);
+        }
+    }
+}
@@ -0,0 +1,184 @@
+// Haptic feedback patterns
+// Named presets and explicit on/off duration arrays are parsed into timed
+// vibration steps, which the haptics plugin plays back on mobile
+
+use serde::{Deserialize, Serialize};
+
+/// Longest single vibration or pause accepted in a custom pattern
+pub const MAX_SEGMENT_MS: u64 = 5_000;
+/// Longest total pattern accepted
+pub const MAX_PATTERN_MS: u64 = 10_000;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum HapticError {
+    #[error("Unknown haptic pattern '{0}'; expected success, warning, error, selection or a duration array")]
+    UnknownPattern(String),
+    #[error("Invalid haptic duration array '{0}': {1}")]
+    InvalidDurations(String, String),
+    #[error("Haptic intensity must be between 0.0 and 1.0, got {0}")]
+    InvalidIntensity(f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum HapticStep {
+    /// Vibrate for `duration_ms` at `intensity` (0.0 to 1.0)
+    Vibrate { duration_ms: u64, intensity: f32 },
+    Pause { duration_ms: u64 },
+}
+
+impl HapticStep {
+    pub fn duration_ms(&self) -> u64 {
+        match self {
+            HapticStep::Vibrate { duration_ms, .. } | HapticStep::Pause { duration_ms } => *duration_ms,
+        }
+    }
+}
+
+/// Preset steps as (on or off, duration, relative intensity)
+fn preset(name: &str) -> Option<&'static [(bool, u64, f32)]> {
+    match name {
+        "success" => Some(&[(true, 30, 0.6), (false, 60, 0.0), (true, 40, 1.0)]),
+        "warning" => Some(&[(true, 50, 0.8), (false, 100, 0.0), (true, 50, 0.8)]),
+        "error" => Some(&[(true, 60, 1.0), (false, 50, 0.0), (true, 60, 1.0), (false, 50, 0.0), (true, 60, 1.0)]),
+        "selection" => Some(&[(true, 10, 0.4)]),
+        _ => None,
+    }
+}
+
+/// Parse a named preset or a JSON array of alternating on/off durations in
+/// milliseconds, starting with a vibration. Vibration intensities are
+/// scaled by `intensity`.
+pub fn parse_pattern(pattern: &str, intensity: f32) -> Result<Vec<HapticStep>, HapticError> {
+    if !(0.0..=1.0).contains(&intensity) {
+        return Err(HapticError::InvalidIntensity(intensity));
+    }
+
+    let pattern = pattern.trim();
+    let segments: Vec<(bool, u64, f32)> = if pattern.starts_with('[') {
+        let invalid = |reason: &str| HapticError::InvalidDurations(pattern.to_string(), reason.to_string());
+        let durations: Vec<u64> = serde_json::from_str(pattern)
+            .map_err(|_| invalid("expected an array of non-negative integers"))?;
+        if durations.is_empty() {
+            return Err(invalid("no durations given"));
+        }
+        if durations.iter().any(|&ms| ms == 0 || ms > MAX_SEGMENT_MS) {
+            return Err(invalid(&format!("each duration must be 1 to {} ms", MAX_SEGMENT_MS)));
+        }
+        if durations.iter().sum::<u64>() > MAX_PATTERN_MS {
+            return Err(invalid(&format!("total duration exceeds {} ms", MAX_PATTERN_MS)));
+        }
+        durations
+            .iter()
+            .enumerate()
+            .map(|(index, &ms)| (index % 2 == 0, ms, 1.0))
+            .collect()
+    } else {
+        preset(&pattern.to_lowercase())
+            .ok_or_else(|| HapticError::UnknownPattern(pattern.to_string()))?
+            .to_vec()
+    };
+
+    Ok(segments
+        .into_iter()
+        .map(|(on, duration_ms, relative)| {
+            if on {
+                HapticStep::Vibrate { duration_ms, intensity: relative * intensity }
+            } else {
+                HapticStep::Pause { duration_ms }
+            }
+        })
+        .collect())
+}
+
+/// Play `pattern` through the haptics plugin. The plugin has no amplitude
+/// control, so short pulses become impact taps whose style follows the
+/// intensity and longer ones become timed vibrations.
+#[cfg(mobile)]
+pub async fn trigger_haptic<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    pattern: &str,
+    intensity: f32,
+) -> anyhow::Result<()> {
+    use std::time::Duration;
+    use tauri_plugin_haptics::{HapticsExt, ImpactFeedbackStyle};
+
+    const TAP_MAX_MS: u64 = 20;
+
+    for step in parse_pattern(pattern, intensity)? {
+        match step {
+            HapticStep::Vibrate { duration_ms, intensity } if intensity > 0.0 => {
+                if duration_ms <= TAP_MAX_MS {
+                    let style = match intensity {
+                        i if i < 0.34 => ImpactFeedbackStyle::Light,
+                        i if i < 0.67 => ImpactFeedbackStyle::Medium,
+                        _ => ImpactFeedbackStyle::Heavy,
+                    };
+                    app.haptics().impact_feedback(style)?;
+                } else {
+                    app.haptics().vibrate(duration_ms as u32)?;
+                }
+            }
+            _ => {}
+        }
+        // Plugin calls return immediately, so each step's time is waited out here
+        tokio::time::sleep(Duration::from_millis(step.duration_ms())).await;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vibrations(steps: &[HapticStep]) -> Vec<(u64, f32)> {
+        steps
+            .iter()
+            .filter_map(|step| match *step {
+                HapticStep::Vibrate { duration_ms, intensity } => Some((duration_ms, intensity)),
+                HapticStep::Pause { .. } => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_presets_parse_with_scaled_intensity() {
+        let success = parse_pattern("success", 0.5).unwrap();
+        assert_eq!(success.len(), 3);
+        assert_eq!(success[1], HapticStep::Pause { duration_ms: 60 });
+        assert_eq!(vibrations(&success), [(30, 0.3), (40, 0.5)]);
+
+        let warning = parse_pattern("Warning", 1.0).unwrap();
+        assert_eq!(vibrations(&warning), [(50, 0.8), (50, 0.8)]);
+
+        let error = parse_pattern("error", 1.0).unwrap();
+        assert_eq!(vibrations(&error).len(), 3);
+        assert_eq!(error.iter().map(HapticStep::duration_ms).sum::<u64>(), 280);
+
+        assert_eq!(parse_pattern("selection", 1.0).unwrap(), [HapticStep::Vibrate { duration_ms: 10, intensity: 0.4 }]);
+    }
+
+    #[test]
+    fn test_custom_duration_array_alternates_on_and_off() {
+        let steps = parse_pattern("[100, 50, 100]", 0.8).unwrap();
+        assert_eq!(
+            steps,
+            [
+                HapticStep::Vibrate { duration_ms: 100, intensity: 0.8 },
+                HapticStep::Pause { duration_ms: 50 },
+                HapticStep::Vibrate { duration_ms: 100, intensity: 0.8 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_patterns_are_rejected() {
+        assert_eq!(parse_pattern("buzz", 0.5), Err(HapticError::UnknownPattern("buzz".to_string())));
+        assert!(matches!(parse_pattern("[]", 0.5), Err(HapticError::InvalidDurations(..))));
+        assert!(matches!(parse_pattern("[100, -5]", 0.5), Err(HapticError::InvalidDurations(..))));
+        assert!(matches!(parse_pattern("[100, 0]", 0.5), Err(HapticError::InvalidDurations(..))));
+        assert!(matches!(parse_pattern("[6000]", 0.5), Err(HapticError::InvalidDurations(..))));
+        assert_eq!(parse_pattern("success", 1.5), Err(HapticError::InvalidIntensity(1.5)));
+        assert!(parse_pattern("success", f32::NAN).is_err());
+    }
+}
@@ -1,10 +1,13 @@
 // Mobile platform integration for Katalyst Tauri
 // Handles iOS and Android specific features, device APIs, and mobile optimizations
 
-use tauri::{AppHandle, Manager, Window, WindowBuilder, WindowUrl};
+use tauri::{AppHandle, Manager, State, Window, WindowBuilder, WindowUrl};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
+use crate::store::AppState;
+use crate::utils::error::{CommandError, CommandResult};
+
 pub mod device_info;
 pub mod permissions;
 pub mod camera;
@@ -19,6 +22,8 @@ pub struct DeviceInfo {
     pub version: String,
     pub manufacturer: String,
     pub is_virtual: bool,
+    /// Primary monitor size in physical pixels and its scale factor. All
+    /// three are 0 when no monitor is reported, rather than a guessed size.
     pub screen_width: u32,
     pub screen_height: u32,
     pub pixel_density: f32,
@@ -28,7 +33,8 @@ pub struct DeviceInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeviceCapabilities {
     pub has_camera: bool,
-    pub has_microphone: bool,
+    /// `None` where audio inputs can't be enumerated
+    pub has_microphone: Option<bool>,
     pub has_gps: bool,
     pub has_accelerometer: bool,
     pub has_gyroscope: bool,
@@ -46,7 +52,7 @@ pub struct Permission {
     pub description: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PermissionStatus {
     Granted,
     Denied,
@@ -147,10 +153,10 @@ fn setup_mobile_preview_window(window: &Window) -> Result<()> {
 
 // Tauri command: Get device information
 #[tauri::command]
-pub async fn get_device_info() -> Result<DeviceInfo, String> {
-    device_info::collect_device_info()
+pub async fn get_device_info(app: AppHandle) -> CommandResult<DeviceInfo> {
+    device_info::collect_device_info(&app)
         .await
-        .map_err(|e| format!("Failed to get device info: {}", e))
+        .map_err(|e| CommandError::internal("Failed to get device info", e))
 }
 
 // Tauri command: Trigger haptic feedback
@@ -159,18 +165,21 @@ pub async fn trigger_haptic_feedback(
     app: AppHandle,
     pattern: String,
     intensity: Option<f32>,
-) -> Result<(), String> {
+) -> CommandResult<()> {
     #[cfg(mobile)]
     {
-        haptics::trigger_haptic(&pattern, intensity.unwrap_or(0.5))
+        // Validate first so a bad pattern is reported as such, not as a plugin failure
+        haptics::parse_pattern(&pattern, intensity.unwrap_or(0.5))?;
+        haptics::trigger_haptic(&app, &pattern, intensity.unwrap_or(0.5))
             .await
-            .map_err(|e| format!("Failed to trigger haptic feedback: {}", e))
+            .map_err(|e| CommandError::internal("Failed to trigger haptic feedback", e))
     }
     
     #[cfg(not(mobile))]
     {
-        // Simulate haptic feedback on desktop
-        println!("Haptic feedback simulated: {} (intensity: {})", pattern, intensity.unwrap_or(0.5));
+        // Simulate haptic feedback on desktop, still rejecting invalid patterns
+        let steps = haptics::parse_pattern(&pattern, intensity.unwrap_or(0.5))?;
+        println!("Haptic feedback simulated: {} ({} steps)", pattern, steps.len());
         Ok(())
     }
 }
@@ -179,28 +188,31 @@ pub async fn trigger_haptic_feedback(
 #[tauri::command]
 pub async fn request_permissions(
     app: AppHandle,
+    store: State<'_, AppState>,
     permissions: Vec<String>,
-) -> Result<Vec<Permission>, String> {
+) -> CommandResult<Vec<Permission>> {
     #[cfg(mobile)]
-    {
-        permissions::request_permissions(permissions)
-            .await
-            .map_err(|e| format!("Failed to request permissions: {}", e))
-    }
+    let result = permissions::request_permissions(&store, permissions, |names| permissions::os_request(&app, names)).await;
     
+    // Desktop has no OS permission prompts, so anything undetermined is granted
     #[cfg(not(mobile))]
-    {
-        // Mock permissions for desktop
-        let mock_permissions = permissions
-            .into_iter()
-            .map(|name| Permission {
-                name: name.clone(),
-                status: PermissionStatus::Granted,
-                description: format!("Mock permission for {}", name),
-            })
-            .collect();
-        Ok(mock_permissions)
-    }
+    let result = permissions::request_permissions(&store, permissions, |names| async move {
+        Ok(names.into_iter().map(|name| (name, PermissionStatus::Granted)).collect())
+    })
+    .await;
+    
+    result.map_err(|e| CommandError::internal("Failed to request permissions", e))
+}
+
+// Tauri command: Last known permission status, without prompting
+#[tauri::command]
+pub async fn get_permission_status(
+    store: State<'_, AppState>,
+    name: String,
+) -> CommandResult<PermissionStatus> {
+    permissions::get_permission_status(&store, &name)
+        .await
+        .map_err(|e| CommandError::internal("Failed to get permission status", e))
 }
 
 // Tauri command: Open camera
@@ -208,12 +220,12 @@ pub async fn request_permissions(
 pub async fn open_camera(
     app: AppHandle,
     camera_type: String,
-) -> Result<String, String> {
+) -> CommandResult<String> {
     #[cfg(mobile)]
     {
         camera::open_camera(&camera_type)
             .await
-            .map_err(|e| format!("Failed to open camera: {}", e))
+            .map_err(|e| CommandError::internal("Failed to open camera", e))
     }
     
     #[cfg(not(mobile))]
@@ -0,0 +1,260 @@
+// Permission state caching
+// The last known status of each permission is kept in the config store under
+// `permission.<name>`, so status checks never prompt and only permissions
+// that are still `NotDetermined` are requested from the OS
+
+use super::{Permission, PermissionStatus};
+use crate::store::{AppState, ConfigValue};
+use anyhow::Result;
+use std::future::Future;
+
+const PERMISSION_KEY_PREFIX: &str = "permission.";
+
+fn status_key(name: &str) -> String {
+    format!("{}{}", PERMISSION_KEY_PREFIX, name)
+}
+
+fn status_name(status: &PermissionStatus) -> &'static str {
+    match status {
+        PermissionStatus::Granted => "granted",
+        PermissionStatus::Denied => "denied",
+        PermissionStatus::NotDetermined => "not_determined",
+        PermissionStatus::Restricted => "restricted",
+    }
+}
+
+fn parse_status(name: &str) -> Option<PermissionStatus> {
+    match name {
+        "granted" => Some(PermissionStatus::Granted),
+        "denied" => Some(PermissionStatus::Denied),
+        "not_determined" => Some(PermissionStatus::NotDetermined),
+        "restricted" => Some(PermissionStatus::Restricted),
+        _ => None,
+    }
+}
+
+fn describe(name: &str, status: &PermissionStatus) -> String {
+    match status {
+        PermissionStatus::Granted => format!("Access to {} is granted", name),
+        PermissionStatus::Denied => format!("Access to {} was denied", name),
+        PermissionStatus::NotDetermined => format!("Access to {} has not been requested", name),
+        PermissionStatus::Restricted => format!("Access to {} is restricted by the system", name),
+    }
+}
+
+pub async fn cached_status(store: &AppState, name: &str) -> Result<Option<PermissionStatus>> {
+    Ok(match store.get(&status_key(name)).await? {
+        Some(ConfigValue::String(status)) => parse_status(&status),
+        _ => None,
+    })
+}
+
+pub async fn record_status(store: &AppState, name: &str, status: &PermissionStatus) -> Result<()> {
+    store
+        .set(status_key(name), ConfigValue::String(status_name(status).to_string()))
+        .await
+}
+
+/// Last known status of `name`, without prompting
+pub async fn get_permission_status(store: &AppState, name: &str) -> Result<PermissionStatus> {
+    Ok(cached_status(store, name).await?.unwrap_or(PermissionStatus::NotDetermined))
+}
+
+/// Report each permission's status, prompting through `prompt` only for
+/// those not yet determined and caching what the prompt returns
+pub async fn request_permissions<F, Fut>(store: &AppState, names: Vec<String>, prompt: F) -> Result<Vec<Permission>>
+where
+    F: FnOnce(Vec<String>) -> Fut,
+    Fut: Future<Output = Result<Vec<(String, PermissionStatus)>>>,
+{
+    let mut statuses = Vec::with_capacity(names.len());
+    let mut undetermined = Vec::new();
+    for name in &names {
+        let status = get_permission_status(store, name).await?;
+        if status == PermissionStatus::NotDetermined {
+            undetermined.push(name.clone());
+        }
+        statuses.push(status);
+    }
+
+    if !undetermined.is_empty() {
+        for (name, status) in prompt(undetermined).await? {
+            record_status(store, &name, &status).await?;
+            for (requested, cached) in names.iter().zip(statuses.iter_mut()) {
+                if *requested == name {
+                    *cached = status.clone();
+                }
+            }
+        }
+    }
+
+    Ok(names
+        .into_iter()
+        .zip(statuses)
+        .map(|(name, status)| Permission {
+            description: describe(&name, &status),
+            name,
+            status,
+        })
+        .collect())
+}
+
+/// Refresh every cached permission from the OS, which may have changed
+/// while the app was closed. `check` returns `None` for permissions the OS
+/// can't be asked about, and their cached status is kept. One failing
+/// permission doesn't stop the rest; failures are reported together.
+pub async fn reconcile_with_os<F, Fut>(store: &AppState, check: F) -> Result<()>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<Option<PermissionStatus>>>,
+{
+    let mut failures = Vec::new();
+    for key in store.keys_with_prefix(PERMISSION_KEY_PREFIX).await? {
+        let name = &key[PERMISSION_KEY_PREFIX.len()..];
+        let result = match check(name.to_string()).await {
+            Ok(Some(status)) => record_status(store, name, &status).await,
+            Ok(None) => Ok(()),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            failures.push(format!("{}: {}", name, e));
+        }
+    }
+
+    if !failures.is_empty() {
+        anyhow::bail!("Failed to reconcile {}", failures.join("; "));
+    }
+    Ok(())
+}
+
+#[cfg(mobile)]
+fn from_plugin_state(state: tauri::plugin::PermissionState) -> PermissionStatus {
+    use tauri::plugin::PermissionState;
+    match state {
+        PermissionState::Granted => PermissionStatus::Granted,
+        PermissionState::Denied => PermissionStatus::Denied,
+        _ => PermissionStatus::NotDetermined,
+    }
+}
+
+/// Current OS status of a permission. Only permissions backed by a
+/// registered plugin can be queried; others are `None`.
+#[cfg(mobile)]
+pub async fn os_permission_status<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    name: &str,
+) -> Result<Option<PermissionStatus>> {
+    use tauri_plugin_geolocation::GeolocationExt;
+    use tauri_plugin_notification::NotificationExt;
+
+    Ok(match name {
+        "notifications" => Some(from_plugin_state(app.notification().permission_state()?)),
+        "location" => Some(from_plugin_state(app.geolocation().check_permissions()?.location)),
+        _ => None,
+    })
+}
+
+/// Prompt the OS for each permission in `names`. Permissions without a
+/// plugin, or whose prompt fails, are left out so they stay undetermined.
+#[cfg(mobile)]
+pub async fn os_request<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    names: Vec<String>,
+) -> Result<Vec<(String, PermissionStatus)>> {
+    use tauri_plugin_geolocation::{GeolocationExt, PermissionType};
+    use tauri_plugin_notification::NotificationExt;
+
+    let mut results = Vec::with_capacity(names.len());
+    for name in names {
+        let status = match name.as_str() {
+            "notifications" => app.notification().request_permission().map_err(anyhow::Error::from),
+            "location" => app
+                .geolocation()
+                .request_permissions(Some(vec![PermissionType::Location]))
+                .map(|state| state.location)
+                .map_err(anyhow::Error::from),
+            _ => continue,
+        };
+        match status {
+            Ok(state) => results.push((name, from_plugin_state(state))),
+            Err(e) => eprintln!("Failed to request {} permission: {}", name, e),
+        }
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[tokio::test]
+    async fn test_cached_grant_is_reported_without_prompting() {
+        let store = AppState::default();
+        record_status(&store, "camera", &PermissionStatus::Granted).await.unwrap();
+
+        let permissions = request_permissions(&store, vec!["camera".to_string()], |names| async move {
+            panic!("prompted for {:?} despite a cached grant", names)
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(permissions[0].status, PermissionStatus::Granted);
+        assert_eq!(get_permission_status(&store, "camera").await.unwrap(), PermissionStatus::Granted);
+    }
+
+    #[tokio::test]
+    async fn test_not_determined_permission_triggers_request() {
+        let store = AppState::default();
+        record_status(&store, "camera", &PermissionStatus::Denied).await.unwrap();
+        let prompted = Mutex::new(Vec::new());
+
+        let permissions = request_permissions(
+            &store,
+            vec!["camera".to_string(), "location".to_string()],
+            |names| {
+                prompted.lock().unwrap().extend(names.clone());
+                async move { Ok(names.into_iter().map(|name| (name, PermissionStatus::Granted)).collect()) }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(*prompted.lock().unwrap(), ["location"]);
+        assert_eq!(permissions[0].status, PermissionStatus::Denied);
+        assert_eq!(permissions[1].status, PermissionStatus::Granted);
+        assert_eq!(get_permission_status(&store, "location").await.unwrap(), PermissionStatus::Granted);
+
+        // Startup reconciliation picks up changes made in system settings
+        reconcile_with_os(&store, |name| async move {
+            Ok(Some(if name == "camera" { PermissionStatus::Granted } else { PermissionStatus::Restricted }))
+        })
+        .await
+        .unwrap();
+        assert_eq!(get_permission_status(&store, "camera").await.unwrap(), PermissionStatus::Granted);
+        assert_eq!(get_permission_status(&store, "location").await.unwrap(), PermissionStatus::Restricted);
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_keeps_statuses_the_os_cannot_report() {
+        let store = AppState::default();
+        record_status(&store, "camera", &PermissionStatus::Granted).await.unwrap();
+        record_status(&store, "location", &PermissionStatus::Denied).await.unwrap();
+        record_status(&store, "notifications", &PermissionStatus::Denied).await.unwrap();
+
+        let result = reconcile_with_os(&store, |name| async move {
+            match name.as_str() {
+                "camera" => Ok(None),
+                "location" => Err(anyhow::anyhow!("plugin unavailable")),
+                _ => Ok(Some(PermissionStatus::Granted)),
+            }
+        })
+        .await;
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("location: plugin unavailable"), "{}", error);
+        assert_eq!(get_permission_status(&store, "camera").await.unwrap(), PermissionStatus::Granted);
+        assert_eq!(get_permission_status(&store, "location").await.unwrap(), PermissionStatus::Denied);
+        assert_eq!(get_permission_status(&store, "notifications").await.unwrap(), PermissionStatus::Granted);
+    }
+}
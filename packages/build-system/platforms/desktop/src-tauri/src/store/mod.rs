@@ -3,9 +3,11 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 use tokio::sync::Mutex;
-use tauri::AppHandle;
+use tauri::{AppHandle, Manager};
 use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -17,9 +19,101 @@ pub enum ConfigValue {
     Object(HashMap<String, ConfigValue>),
 }
 
+/// Config store backed by the SQLite `config` table, with `store` as a
+/// write-through cache. Until a database is attached, values live only in
+/// the cache.
 #[derive(Default)]
 pub struct AppState {
     pub store: Mutex<HashMap<String, ConfigValue>>,
+    db: std::sync::Mutex<Option<Connection>>,
+}
+
+impl AppState {
+    /// State backed by the database at `db_path`, creating tables as needed
+    pub fn open(db_path: &Path) -> Result<Self> {
+        let state = Self::default();
+        let conn = Connection::open(db_path)?;
+        create_tables(&conn)?;
+        state.attach_database(conn);
+        Ok(state)
+    }
+
+    pub fn attach_database(&self, conn: Connection) {
+        *self.db.lock().unwrap() = Some(conn);
+    }
+
+    pub async fn get(&self, key: &str) -> Result<Option<ConfigValue>> {
+        let mut store = self.store.lock().await;
+        if let Some(value) = store.get(key) {
+            return Ok(Some(value.clone()));
+        }
+
+        let db = self.db.lock().unwrap();
+        let Some(conn) = db.as_ref() else {
+            return Ok(None);
+        };
+        let stored: Option<String> = conn
+            .query_row("SELECT value FROM config WHERE key = ?1", params![key], |row| row.get(0))
+            .optional()?;
+
+        match stored {
+            Some(json) => {
+                let value: ConfigValue = serde_json::from_str(&json)?;
+                store.insert(key.to_string(), value.clone());
+                Ok(Some(value))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub async fn set(&self, key: String, value: ConfigValue) -> Result<()> {
+        let mut store = self.store.lock().await;
+        if let Some(conn) = self.db.lock().unwrap().as_ref() {
+            conn.execute(
+                "INSERT INTO config (key, value) VALUES (?1, ?2)
+                 ON CONFLICT(key) DO UPDATE SET value = excluded.value, updated_at = CURRENT_TIMESTAMP",
+                params![key, serde_json::to_string(&value)?],
+            )?;
+        }
+        store.insert(key, value);
+        Ok(())
+    }
+
+    pub async fn delete(&self, key: &str) -> Result<bool> {
+        let mut store = self.store.lock().await;
+        let mut removed = store.remove(key).is_some();
+        if let Some(conn) = self.db.lock().unwrap().as_ref() {
+            removed |= conn.execute("DELETE FROM config WHERE key = ?1", params![key])? > 0;
+        }
+        Ok(removed)
+    }
+
+    /// Keys starting with `prefix`, from the database and the cache
+    pub async fn keys_with_prefix(&self, prefix: &str) -> Result<Vec<String>> {
+        let store = self.store.lock().await;
+        let mut keys: Vec<String> = store.keys().filter(|key| key.starts_with(prefix)).cloned().collect();
+
+        if let Some(conn) = self.db.lock().unwrap().as_ref() {
+            let mut statement = conn.prepare("SELECT key FROM config WHERE substr(key, 1, length(?1)) = ?1")?;
+            let stored = statement.query_map(params![prefix], |row| row.get::<_, String>(0))?;
+            for key in stored {
+                keys.push(key?);
+            }
+        }
+
+        keys.sort();
+        keys.dedup();
+        Ok(keys)
+    }
+
+    pub async fn clear(&self) -> Result<()> {
+        let mut store = self.store.lock().await;
+        if let Some(conn) = self.db.lock().unwrap().as_ref() {
+            conn.execute("DELETE FROM config", [])?;
+        }
+        store.clear();
+        Ok(())
+    }
 }
 
 pub fn init_database(app: AppHandle) -> Result<()> {
@@ -32,9 +126,16 @@ pub fn init_database(app: AppHandle) -> Result<()> {
     
     let db_path = app_dir.join("katalyst.db");
     
-    // Initialize database tables
-    let conn = rusqlite::Connection::open(db_path)?;
+    let conn = Connection::open(db_path)?;
+    create_tables(&conn)?;
+    
+    // Back the managed config store with the database
+    app.state::<AppState>().attach_database(conn);
     
+    Ok(())
+}
+
+fn create_tables(conn: &Connection) -> Result<()> {
     conn.execute(
         "CREATE TABLE IF NOT EXISTS config (
             key TEXT PRIMARY KEY,
@@ -58,4 +159,45 @@ pub fn init_database(app: AppHandle) -> Result<()> {
     )?;
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_db_path(name: &str) -> std::path::PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("katalyst-{}-{}-{}.db", name, std::process::id(), nanos))
+    }
+
+    #[tokio::test]
+    async fn test_values_survive_restart() {
+        let path = temp_db_path("store");
+
+        {
+            let state = AppState::open(&path).unwrap();
+            state.set("theme".to_string(), ConfigValue::String("dark".to_string())).await.unwrap();
+            state.set("zoom".to_string(), ConfigValue::Number(1.25)).await.unwrap();
+            state.set("zoom".to_string(), ConfigValue::Number(1.5)).await.unwrap();
+            state.set("scratch".to_string(), ConfigValue::Boolean(true)).await.unwrap();
+            assert!(state.delete("scratch").await.unwrap());
+        }
+
+        // A fresh state starts with an empty cache and reads through to the DB
+        let restarted = AppState::open(&path).unwrap();
+        assert!(restarted.store.lock().await.is_empty());
+        assert!(matches!(restarted.get("theme").await.unwrap(), Some(ConfigValue::String(theme)) if theme == "dark"));
+        assert!(matches!(restarted.get("zoom").await.unwrap(), Some(ConfigValue::Number(zoom)) if zoom == 1.5));
+        assert!(restarted.get("scratch").await.unwrap().is_none());
+        assert!(!restarted.delete("scratch").await.unwrap());
+
+        restarted.clear().await.unwrap();
+        drop(restarted);
+        assert!(AppState::open(&path).unwrap().get("theme").await.unwrap().is_none());
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,238 @@
+// Application configuration for Katalyst Tauri
+// `AppConfig` is stored as JSON in the app config directory; writes are
+// validated against the schema and replace the file atomically
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use super::logger::LogLevel;
+
+pub const CONFIG_FILE: &str = "config.json";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Invalid config: {0}")]
+    Invalid(String),
+    #[error("Config I/O failed for {path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    Light,
+    Dark,
+    System,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WindowConfig {
+    pub width: f64,
+    pub height: f64,
+    pub always_on_top: bool,
+    /// Window opacity, 0.0 to 1.0
+    pub transparency: f64,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            width: 1200.0,
+            height: 800.0,
+            always_on_top: false,
+            transparency: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WebXRConfig {
+    pub enabled: bool,
+    pub preferred_refresh_rate: u32,
+}
+
+impl Default for WebXRConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            preferred_refresh_rate: 90,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UpdateConfig {
+    pub auto_check: bool,
+    pub check_interval_hours: u32,
+}
+
+impl Default for UpdateConfig {
+    fn default() -> Self {
+        Self {
+            auto_check: true,
+            check_interval_hours: 24,
+        }
+    }
+}
+
+/// Every field is optional in the file and falls back to its default;
+/// unknown fields are rejected so typos don't go unnoticed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppConfig {
+    pub theme: Theme,
+    pub log_level: LogLevel,
+    pub window: WindowConfig,
+    pub webxr: WebXRConfig,
+    pub updates: UpdateConfig,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            theme: Theme::System,
+            log_level: LogLevel::Info,
+            window: WindowConfig::default(),
+            webxr: WebXRConfig::default(),
+            updates: UpdateConfig::default(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Parse and validate a config document
+    pub fn from_json(value: serde_json::Value) -> Result<Self, ConfigError> {
+        let config: AppConfig = serde_json::from_value(value).map_err(|e| ConfigError::Invalid(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        fn check(ok: bool, message: &str) -> Result<(), ConfigError> {
+            if ok { Ok(()) } else { Err(ConfigError::Invalid(message.to_string())) }
+        }
+
+        check((200.0..=10_000.0).contains(&self.window.width), "window.width must be between 200 and 10000")?;
+        check((200.0..=10_000.0).contains(&self.window.height), "window.height must be between 200 and 10000")?;
+        check((0.0..=1.0).contains(&self.window.transparency), "window.transparency must be between 0.0 and 1.0")?;
+        check(
+            (30..=240).contains(&self.webxr.preferred_refresh_rate),
+            "webxr.preferred_refresh_rate must be between 30 and 240",
+        )?;
+        check(
+            (1..=168).contains(&self.updates.check_interval_hours),
+            "updates.check_interval_hours must be between 1 and 168",
+        )
+    }
+}
+
+fn io_error(path: &Path) -> impl FnOnce(std::io::Error) -> ConfigError + '_ {
+    move |source| ConfigError::Io { path: path.display().to_string(), source }
+}
+
+/// Read the config at `path`, writing the defaults there if it doesn't exist
+pub fn load_or_create(path: &Path) -> Result<AppConfig, ConfigError> {
+    match fs::read_to_string(path) {
+        Ok(content) => {
+            let value = serde_json::from_str(&content).map_err(|e| ConfigError::Invalid(e.to_string()))?;
+            AppConfig::from_json(value)
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            let config = AppConfig::default();
+            save(path, &config)?;
+            Ok(config)
+        }
+        Err(e) => Err(io_error(path)(e)),
+    }
+}
+
+/// Validate and write `config`, via a temporary file renamed over `path` so
+/// readers never see a partial write
+pub fn save(path: &Path, config: &AppConfig) -> Result<(), ConfigError> {
+    config.validate()?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error(parent))?;
+    }
+    let content = serde_json::to_vec_pretty(config).map_err(|e| ConfigError::Invalid(e.to_string()))?;
+
+    let temp_path = path.with_extension("json.tmp");
+    let mut file = fs::File::create(&temp_path).map_err(io_error(&temp_path))?;
+    file.write_all(&content)
+        .and_then(|_| file.sync_all())
+        .map_err(io_error(&temp_path))?;
+    fs::rename(&temp_path, path).map_err(io_error(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn temp_config_path() -> std::path::PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("katalyst-config-{}", nanos)).join(CONFIG_FILE)
+    }
+
+    #[test]
+    fn test_missing_config_is_created_with_defaults() {
+        let path = temp_config_path();
+
+        let config = load_or_create(&path).unwrap();
+        assert_eq!(config, AppConfig::default());
+        assert!(path.exists());
+        assert!(!path.with_extension("json.tmp").exists());
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_config_round_trips() {
+        let path = temp_config_path();
+
+        // Omitted fields take their defaults
+        let config = AppConfig::from_json(json!({
+            "theme": "dark",
+            "log_level": "debug",
+            "window": { "width": 1440.0, "always_on_top": true }
+        }))
+        .unwrap();
+        assert_eq!(config.window.height, WindowConfig::default().height);
+
+        save(&path, &config).unwrap();
+        assert_eq!(load_or_create(&path).unwrap(), config);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_config_is_rejected() {
+        let path = temp_config_path();
+        save(&path, &AppConfig::default()).unwrap();
+
+        assert!(AppConfig::from_json(json!({ "window": { "transparency": 1.5 } })).is_err());
+        assert!(AppConfig::from_json(json!({ "theme": "neon" })).is_err());
+        assert!(AppConfig::from_json(json!({ "telemetry": true })).is_err());
+        assert!(AppConfig::from_json(json!({ "updates": { "check_interval_hours": 0 } })).is_err());
+
+        let mut invalid = AppConfig::default();
+        invalid.webxr.preferred_refresh_rate = 1000;
+        assert!(matches!(save(&path, &invalid), Err(ConfigError::Invalid(_))));
+        // The file on disk is untouched
+        assert_eq!(load_or_create(&path).unwrap(), AppConfig::default());
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
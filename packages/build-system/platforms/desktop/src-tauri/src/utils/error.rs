@@ -0,0 +1,111 @@
+// Command error handling for Katalyst Tauri
+// Commands return `CommandError`, which reaches the frontend as a tagged
+// object such as `{ "kind": "NotFound", "message": "Window 'settings' not found" }`
+
+use serde::Serialize;
+
+use crate::desktop::TransparencyError;
+use crate::mobile::haptics::HapticError;
+use crate::webxr::session_manager::SessionError;
+
+#[derive(Debug, Clone, PartialEq, Serialize, thiserror::Error)]
+#[serde(tag = "kind", content = "message")]
+pub enum CommandError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    PermissionDenied(String),
+    #[error("{0}")]
+    Unsupported(String),
+    #[error("{0}")]
+    InvalidArgument(String),
+    #[error("{0}")]
+    Internal(String),
+}
+
+pub type CommandResult<T> = Result<T, CommandError>;
+
+impl CommandError {
+    /// Internal error described as "`context`: `error`"
+    pub fn internal(context: &str, error: impl std::fmt::Display) -> Self {
+        CommandError::Internal(format!("{}: {}", context, error))
+    }
+}
+
+/// The window `label` refers to, or `NotFound`
+pub fn require_window<W>(window: Option<W>, label: &str) -> CommandResult<W> {
+    window.ok_or_else(|| CommandError::NotFound(format!("Window '{}' not found", label)))
+}
+
+impl From<tauri::Error> for CommandError {
+    fn from(error: tauri::Error) -> Self {
+        match error {
+            tauri::Error::WebviewNotFound => CommandError::NotFound(error.to_string()),
+            _ => CommandError::Internal(error.to_string()),
+        }
+    }
+}
+
+impl From<anyhow::Error> for CommandError {
+    fn from(error: anyhow::Error) -> Self {
+        CommandError::Internal(format!("{:#}", error))
+    }
+}
+
+impl From<TransparencyError> for CommandError {
+    fn from(error: TransparencyError) -> Self {
+        match error {
+            TransparencyError::Unsupported(_) => CommandError::Unsupported(error.to_string()),
+            TransparencyError::NativeWindow(_) => CommandError::Internal(error.to_string()),
+        }
+    }
+}
+
+impl From<HapticError> for CommandError {
+    fn from(error: HapticError) -> Self {
+        CommandError::InvalidArgument(error.to_string())
+    }
+}
+
+impl From<SessionError> for CommandError {
+    fn from(error: SessionError) -> Self {
+        match error {
+            SessionError::UnknownDevice(_) | SessionError::UnknownSession(_) => {
+                CommandError::NotFound(error.to_string())
+            }
+            SessionError::DeviceBusy { .. } => CommandError::InvalidArgument(error.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_missing_window_is_not_found() {
+        let error = require_window(None::<()>, "settings").unwrap_err();
+        assert_eq!(error, CommandError::NotFound("Window 'settings' not found".to_string()));
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({ "kind": "NotFound", "message": "Window 'settings' not found" })
+        );
+        assert_eq!(require_window(Some(7), "main"), Ok(7));
+    }
+
+    #[test]
+    fn test_domain_errors_map_to_categories() {
+        let unknown = CommandError::from(SessionError::UnknownSession("xr-session-9".to_string()));
+        assert!(matches!(unknown, CommandError::NotFound(_)));
+
+        let unsupported = CommandError::from(TransparencyError::Unsupported("no compositor".to_string()));
+        assert_eq!(serde_json::to_value(&unsupported).unwrap()["kind"], "Unsupported");
+
+        let haptic = CommandError::from(HapticError::InvalidIntensity(2.0));
+        assert!(matches!(haptic, CommandError::InvalidArgument(_)));
+
+        let internal = CommandError::internal("Failed to get device info", "disk on fire");
+        assert_eq!(internal.to_string(), "Failed to get device info: disk on fire");
+    }
+}
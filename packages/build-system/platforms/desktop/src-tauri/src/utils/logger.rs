@@ -0,0 +1,215 @@
+// Logging setup for Katalyst Tauri
+// Logs go to stdout and to a bounded in-memory buffer backing the in-app log viewer
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+/// Records kept for `get_logs`; older ones are dropped first
+pub const LOG_BUFFER_CAPACITY: usize = 2000;
+
+static LOG_BUFFER: OnceLock<Arc<LogBuffer>> = OnceLock::new();
+
+/// Ordered from most to least severe, so `level <= filter` selects the
+/// filter level and everything more severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<&tracing::Level> for LogLevel {
+    fn from(level: &tracing::Level) -> Self {
+        match *level {
+            tracing::Level::ERROR => LogLevel::Error,
+            tracing::Level::WARN => LogLevel::Warn,
+            tracing::Level::INFO => LogLevel::Info,
+            tracing::Level::DEBUG => LogLevel::Debug,
+            tracing::Level::TRACE => LogLevel::Trace,
+        }
+    }
+}
+
+impl FromStr for LogLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "error" => Ok(LogLevel::Error),
+            "warn" | "warning" => Ok(LogLevel::Warn),
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            "trace" => Ok(LogLevel::Trace),
+            _ => Err(format!("Unknown log level: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogRecord {
+    pub level: LogLevel,
+    pub target: String,
+    pub message: String,
+    /// Structured fields other than the message
+    pub fields: HashMap<String, String>,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+}
+
+#[derive(Debug)]
+pub struct LogBuffer {
+    capacity: usize,
+    records: Mutex<VecDeque<LogRecord>>,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    pub fn push(&self, record: LogRecord) {
+        let mut records = self.records.lock().unwrap();
+        if records.len() >= self.capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// Records at `level_filter` or more severe, at or after `since`
+    /// (milliseconds since the Unix epoch), keeping the newest `limit`
+    /// in chronological order
+    pub fn query(&self, level_filter: Option<LogLevel>, limit: Option<usize>, since: Option<u64>) -> Vec<LogRecord> {
+        let records = self.records.lock().unwrap();
+        let mut matching: Vec<LogRecord> = records
+            .iter()
+            .rev()
+            .filter(|record| level_filter.map_or(true, |filter| record.level <= filter))
+            .filter(|record| since.map_or(true, |since| record.timestamp >= since))
+            .take(limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect();
+        matching.reverse();
+        matching
+    }
+}
+
+/// Buffer shared by the installed logger and `get_logs`
+pub fn log_buffer() -> Arc<LogBuffer> {
+    LOG_BUFFER.get_or_init(|| Arc::new(LogBuffer::new(LOG_BUFFER_CAPACITY))).clone()
+}
+
+/// `tracing` layer copying every event it sees into a `LogBuffer`
+pub struct RingBufferLayer {
+    buffer: Arc<LogBuffer>,
+}
+
+impl RingBufferLayer {
+    pub fn new(buffer: Arc<LogBuffer>) -> Self {
+        Self { buffer }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for RingBufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = RecordVisitor::default();
+        event.record(&mut visitor);
+
+        let metadata = event.metadata();
+        self.buffer.push(LogRecord {
+            level: metadata.level().into(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+            fields: visitor.fields,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_millis() as u64),
+        });
+    }
+}
+
+#[derive(Default)]
+struct RecordVisitor {
+    message: String,
+    fields: HashMap<String, String>,
+}
+
+impl Visit for RecordVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.fields.insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+}
+
+pub fn init_logger() -> anyhow::Result<()> {
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .with(tracing_subscriber::fmt::layer())
+        .with(RingBufferLayer::new(log_buffer()))
+        .try_init()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_filter_returns_expected_subset() {
+        let buffer = Arc::new(LogBuffer::new(16));
+        let subscriber = tracing_subscriber::registry().with(RingBufferLayer::new(buffer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::trace!("trace detail");
+            tracing::debug!("debug detail");
+            tracing::info!(window = "main", "window created");
+            tracing::warn!("slow frame");
+            tracing::error!("render failed");
+        });
+
+        let messages = |records: Vec<LogRecord>| records.into_iter().map(|r| r.message).collect::<Vec<_>>();
+
+        assert_eq!(buffer.query(None, None, None).len(), 5);
+        assert_eq!(
+            messages(buffer.query(Some("warn".parse().unwrap()), None, None)),
+            ["slow frame", "render failed"]
+        );
+        assert_eq!(
+            messages(buffer.query(Some(LogLevel::Info), Some(2), None)),
+            ["slow frame", "render failed"]
+        );
+
+        let info = &buffer.query(Some(LogLevel::Info), Some(3), None)[0];
+        assert_eq!(info.level, LogLevel::Info);
+        assert_eq!(info.fields["window"], "main");
+        assert!(info.target.ends_with("logger::tests"));
+
+        let future = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64 + 60_000;
+        assert!(buffer.query(None, None, Some(future)).is_empty());
+    }
+}
@@ -0,0 +1,191 @@
+// XR device discovery
+// WebXR is only reachable from the webview, so devices are discovered by
+// emitting `query-xr-devices` and awaiting the frontend's `xr-devices-reply`,
+// which reports what `navigator.xr` supports:
+//
+//   { "requestId": "...", "xrAvailable": true,
+//     "sessionSupport": { "inline": true, "immersiveVr": true, "immersiveAr": false },
+//     "supportedFeatures": ["hand-tracking"], "supportedFrameRates": [72, 90],
+//     "deviceName": "Quest 3", "fieldOfView": 110.0 }
+
+use super::{XRCapabilities, XRDevice, XRDeviceType};
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+pub const XR_QUERY_EVENT: &str = "query-xr-devices";
+pub const XR_REPLY_EVENT: &str = "xr-devices-reply";
+/// How long to wait for the webview before assuming no XR devices
+pub const XR_QUERY_TIMEOUT_MS: u64 = 2000;
+
+const DEFAULT_REFRESH_RATE: u32 = 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XRQuery {
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XRSessionSupport {
+    #[serde(default)]
+    pub inline: bool,
+    #[serde(default)]
+    pub immersive_vr: bool,
+    #[serde(default)]
+    pub immersive_ar: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XRQueryReply {
+    pub request_id: String,
+    pub xr_available: bool,
+    #[serde(default)]
+    pub session_support: XRSessionSupport,
+    #[serde(default)]
+    pub supported_features: Vec<String>,
+    #[serde(default)]
+    pub supported_frame_rates: Vec<f32>,
+    pub device_name: Option<String>,
+    pub field_of_view: Option<f32>,
+}
+
+/// Ask the webview which XR devices it can reach. Returns an empty list when
+/// no webview answers within `XR_QUERY_TIMEOUT_MS`.
+pub async fn scan_xr_devices(app: &AppHandle) -> Result<Vec<XRDevice>> {
+    let request_id = format!(
+        "xr-{}",
+        std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_nanos()
+    );
+    let (tx, rx) = tokio::sync::oneshot::channel::<String>();
+    let tx = Arc::new(Mutex::new(Some(tx)));
+
+    let expected_id = request_id.clone();
+    let handler = app.listen_global(XR_REPLY_EVENT, move |event| {
+        let Some(payload) = event.payload() else { return };
+        // Replies to other queries are left for their own listeners
+        let matches = serde_json::from_str::<XRQueryReply>(payload)
+            .map_or(false, |reply| reply.request_id == expected_id);
+        if matches {
+            if let Some(tx) = tx.lock().unwrap().take() {
+                let _ = tx.send(payload.to_string());
+            }
+        }
+    });
+
+    if let Err(e) = app.emit_all(XR_QUERY_EVENT, XRQuery { request_id }) {
+        app.unlisten(handler);
+        return Err(anyhow!("Failed to query webview for XR devices: {}", e));
+    }
+
+    let reply = tokio::time::timeout(Duration::from_millis(XR_QUERY_TIMEOUT_MS), rx).await;
+    app.unlisten(handler);
+
+    match reply {
+        Ok(Ok(payload)) => parse_xr_reply(&payload),
+        // Timed out or the listener went away: treat as no XR support
+        _ => Ok(Vec::new()),
+    }
+}
+
+pub async fn get_connected_devices(app: &AppHandle) -> Result<Vec<XRDevice>> {
+    Ok(scan_xr_devices(app)
+        .await?
+        .into_iter()
+        .filter(|device| device.is_connected)
+        .collect())
+}
+
+/// Map a webview reply to devices. WebXR exposes one XR system per page
+/// rather than individual devices, so an immersive-capable system becomes a
+/// single device and inline-only support yields none.
+pub fn parse_xr_reply(payload: &str) -> Result<Vec<XRDevice>> {
+    let reply: XRQueryReply = serde_json::from_str(payload)
+        .map_err(|e| anyhow!("Malformed XR device reply: {}", e))?;
+
+    let support = &reply.session_support;
+    if !reply.xr_available || !(support.immersive_vr || support.immersive_ar) {
+        return Ok(Vec::new());
+    }
+
+    let has_feature = |feature: &str| reply.supported_features.iter().any(|f| f == feature);
+    let device_type = if support.immersive_vr {
+        XRDeviceType::HeadMountedDisplay
+    } else {
+        // Immersive AR without VR is a passthrough camera, e.g. a phone
+        XRDeviceType::Camera
+    };
+    let max_refresh_rate = reply
+        .supported_frame_rates
+        .iter()
+        .cloned()
+        .fold(None, |max: Option<f32>, rate| Some(max.map_or(rate, |max| max.max(rate))))
+        .map_or(DEFAULT_REFRESH_RATE, |rate| rate.round() as u32);
+
+    Ok(vec![XRDevice {
+        id: format!("webxr:{}", if support.immersive_vr { "immersive-vr" } else { "immersive-ar" }),
+        name: reply.device_name.clone().unwrap_or_else(|| "WebXR Device".to_string()),
+        device_type,
+        capabilities: XRCapabilities {
+            supports_vr: support.immersive_vr,
+            supports_ar: support.immersive_ar,
+            supports_hand_tracking: has_feature("hand-tracking"),
+            supports_eye_tracking: has_feature("eye-tracking"),
+            supports_spatial_audio: has_feature("spatial-audio"),
+            max_refresh_rate,
+            field_of_view: reply.field_of_view,
+        },
+        is_connected: true,
+        is_available: true,
+    }])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frontend_reply_maps_to_devices() {
+        let headset = parse_xr_reply(
+            r#"{
+                "requestId": "xr-1",
+                "xrAvailable": true,
+                "sessionSupport": { "inline": true, "immersiveVr": true, "immersiveAr": true },
+                "supportedFeatures": ["local-floor", "hand-tracking"],
+                "supportedFrameRates": [72, 90, 120],
+                "deviceName": "Quest 3",
+                "fieldOfView": 110.0
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(headset.len(), 1);
+        let device = &headset[0];
+        assert_eq!(device.id, "webxr:immersive-vr");
+        assert_eq!(device.name, "Quest 3");
+        assert!(matches!(device.device_type, XRDeviceType::HeadMountedDisplay));
+        assert!(device.is_connected);
+        assert!(device.capabilities.supports_vr && device.capabilities.supports_ar);
+        assert!(device.capabilities.supports_hand_tracking);
+        assert!(!device.capabilities.supports_eye_tracking);
+        assert_eq!(device.capabilities.max_refresh_rate, 120);
+        assert_eq!(device.capabilities.field_of_view, Some(110.0));
+
+        let phone = parse_xr_reply(
+            r#"{ "requestId": "xr-2", "xrAvailable": true, "sessionSupport": { "immersiveAr": true } }"#,
+        )
+        .unwrap();
+        assert!(matches!(phone[0].device_type, XRDeviceType::Camera));
+        assert_eq!(phone[0].name, "WebXR Device");
+        assert_eq!(phone[0].capabilities.max_refresh_rate, DEFAULT_REFRESH_RATE);
+
+        let inline_only = r#"{ "requestId": "xr-3", "xrAvailable": true, "sessionSupport": { "inline": true } }"#;
+        assert!(parse_xr_reply(inline_only).unwrap().is_empty());
+        assert!(parse_xr_reply(r#"{ "requestId": "xr-4", "xrAvailable": false }"#).unwrap().is_empty());
+        assert!(parse_xr_reply("not json").is_err());
+    }
+}
@@ -1,11 +1,13 @@
 // WebXR integration for Katalyst Tauri
 // Provides VR/AR capabilities, spatial UI, and metaverse features
 
-use tauri::{AppHandle, Manager, Window, WindowBuilder, WindowUrl};
+use tauri::{AppHandle, Manager, State, Window, WindowBuilder, WindowUrl};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::utils::error::{CommandError, CommandResult};
+
 pub mod spatial_ui;
 pub mod device_manager;
 pub mod session_manager;
@@ -18,6 +20,8 @@ pub struct XRDevice {
     pub device_type: XRDeviceType,
     pub capabilities: XRCapabilities,
     pub is_connected: bool,
+    /// False while an immersive session holds the device
+    pub is_available: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -111,82 +115,103 @@ fn setup_webxr_window(window: &Window) -> Result<()> {
 
 // Tauri command: Initialize WebXR
 #[tauri::command]
-pub async fn initialize_webxr(app: AppHandle) -> Result<Vec<XRDevice>, String> {
-    device_manager::scan_xr_devices()
+pub async fn initialize_webxr(
+    app: AppHandle,
+    state: State<'_, SharedWebXRState>,
+) -> CommandResult<Vec<XRDevice>> {
+    let devices = device_manager::scan_xr_devices(&app)
         .await
-        .map_err(|e| format!("Failed to initialize WebXR: {}", e))
+        .map_err(|e| CommandError::internal("Failed to initialize WebXR", e))?;
+    
+    let mut state = state.lock().await;
+    for device in &devices {
+        // Keep availability of devices that already have a session running
+        let busy = state.devices.get(&device.id).map_or(false, |known| !known.is_available);
+        state.add_device(XRDevice { is_available: !busy, ..device.clone() });
+    }
+    
+    Ok(devices)
 }
 
 // Tauri command: Create WebXR session
 #[tauri::command]
 pub async fn create_webxr_session(
-    app: AppHandle,
+    state: State<'_, SharedWebXRState>,
     device_id: String,
     mode: String,
-) -> Result<XRSession, String> {
+) -> CommandResult<XRSession> {
     let session_mode = match mode.as_str() {
         "inline" => XRSessionMode::Inline,
         "immersive-vr" => XRSessionMode::ImmersiveVR,
         "immersive-ar" => XRSessionMode::ImmersiveAR,
-        _ => return Err(format!("Invalid session mode: {}", mode)),
+        _ => return Err(CommandError::InvalidArgument(format!("Invalid session mode: {}", mode))),
     };
     
-    session_manager::create_session(&device_id, session_mode)
-        .await
-        .map_err(|e| format!("Failed to create XR session: {}", e))
+    Ok(session_manager::create_session(&mut *state.lock().await, &device_id, session_mode)?)
+}
+
+// Tauri command: End WebXR session
+#[tauri::command]
+pub async fn end_webxr_session(
+    state: State<'_, SharedWebXRState>,
+    session_id: String,
+) -> CommandResult<XRSession> {
+    Ok(session_manager::end_session(&mut *state.lock().await, &session_id)?)
 }
 
 // Tauri command: Get XR devices
 #[tauri::command]
-pub async fn get_xr_devices() -> Result<Vec<XRDevice>, String> {
-    device_manager::get_connected_devices()
+pub async fn get_xr_devices(app: AppHandle) -> CommandResult<Vec<XRDevice>> {
+    device_manager::get_connected_devices(&app)
         .await
-        .map_err(|e| format!("Failed to get XR devices: {}", e))
+        .map_err(|e| CommandError::internal("Failed to get XR devices", e))
 }
 
 // Tauri command: Toggle XR mode
 #[tauri::command]
-pub async fn toggle_xr_mode(app: AppHandle, enable: bool) -> Result<bool, String> {
+pub async fn toggle_xr_mode(app: AppHandle, enable: bool) -> CommandResult<bool> {
     if enable {
         // Enter XR mode
         if let Some(window) = app.get_window("main") {
             window.set_fullscreen(true)
-                .map_err(|e| format!("Failed to enter fullscreen: {}", e))?;
+                .map_err(|e| CommandError::internal("Failed to enter fullscreen", e))?;
         }
         
         // Launch WebXR window if not already open
         if app.get_window("webxr").is_none() {
             launch_webxr_window(app)
-                .map_err(|e| format!("Failed to launch WebXR window: {}", e))?;
+                .map_err(|e| CommandError::internal("Failed to launch WebXR window", e))?;
         }
         
         // Initialize WebXR performance optimizations
         performance::optimize_for_xr(&app)
             .await
-            .map_err(|e| format!("Failed to optimize for XR: {}", e))?;
+            .map_err(|e| CommandError::internal("Failed to optimize for XR", e))?;
     } else {
         // Exit XR mode
         if let Some(window) = app.get_window("main") {
             window.set_fullscreen(false)
-                .map_err(|e| format!("Failed to exit fullscreen: {}", e))?;
+                .map_err(|e| CommandError::internal("Failed to exit fullscreen", e))?;
         }
         
         // Close WebXR window
         if let Some(webxr_window) = app.get_window("webxr") {
             webxr_window.close()
-                .map_err(|e| format!("Failed to close WebXR window: {}", e))?;
+                .map_err(|e| CommandError::internal("Failed to close WebXR window", e))?;
         }
         
         // Reset performance settings
         performance::reset_performance_settings(&app)
             .await
-            .map_err(|e| format!("Failed to reset performance: {}", e))?;
+            .map_err(|e| CommandError::internal("Failed to reset performance", e))?;
     }
     
     Ok(enable)
 }
 
 // WebXR state management
+pub type SharedWebXRState = tokio::sync::Mutex<WebXRState>;
+
 #[derive(Default)]
 pub struct WebXRState {
     pub devices: HashMap<String, XRDevice>,
@@ -0,0 +1,155 @@
+// XR session lifecycle
+// A device runs at most one immersive session at a time; inline sessions
+// render into the page and never hold the device
+
+use super::{WebXRState, XRSession, XRSessionMode};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static NEXT_SESSION_ID: AtomicU64 = AtomicU64::new(1);
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum SessionError {
+    #[error("Unknown XR device '{0}'; initialize WebXR first")]
+    UnknownDevice(String),
+    #[error("XR device '{device_id}' already has active immersive session '{session_id}'")]
+    DeviceBusy { device_id: String, session_id: String },
+    #[error("Unknown XR session '{0}'")]
+    UnknownSession(String),
+}
+
+fn is_immersive(mode: &XRSessionMode) -> bool {
+    matches!(mode, XRSessionMode::ImmersiveVR | XRSessionMode::ImmersiveAR)
+}
+
+/// Start a session on `device_id`. An immersive session marks the device
+/// unavailable and becomes the current session.
+pub fn create_session(
+    state: &mut WebXRState,
+    device_id: &str,
+    mode: XRSessionMode,
+) -> Result<XRSession, SessionError> {
+    if !state.devices.contains_key(device_id) {
+        return Err(SessionError::UnknownDevice(device_id.to_string()));
+    }
+
+    let immersive = is_immersive(&mode);
+    if immersive {
+        let active = state.sessions.values().find(|session| {
+            session.device_id == device_id && session.is_active && is_immersive(&session.mode)
+        });
+        if let Some(active) = active {
+            return Err(SessionError::DeviceBusy {
+                device_id: device_id.to_string(),
+                session_id: active.id.clone(),
+            });
+        }
+    }
+
+    let session = XRSession {
+        id: format!("xr-session-{}", NEXT_SESSION_ID.fetch_add(1, Ordering::Relaxed)),
+        mode,
+        is_active: true,
+        start_time: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_millis() as u64),
+        device_id: device_id.to_string(),
+    };
+
+    if immersive {
+        if let Some(device) = state.devices.get_mut(device_id) {
+            device.is_available = false;
+        }
+        state.set_current_session(Some(session.id.clone()));
+    }
+    state.add_session(session.clone());
+
+    Ok(session)
+}
+
+/// Stop a session, releasing its device and clearing `current_session` if
+/// it was current. Returns the session as it ended.
+pub fn end_session(state: &mut WebXRState, session_id: &str) -> Result<XRSession, SessionError> {
+    let mut session = state
+        .sessions
+        .get(session_id)
+        .cloned()
+        .ok_or_else(|| SessionError::UnknownSession(session_id.to_string()))?;
+    state.remove_session(session_id);
+    session.is_active = false;
+
+    if state.current_session.as_deref() == Some(session_id) {
+        state.set_current_session(None);
+    }
+    if is_immersive(&session.mode) {
+        if let Some(device) = state.devices.get_mut(&session.device_id) {
+            device.is_available = true;
+        }
+    }
+
+    Ok(session)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::webxr::{XRCapabilities, XRDevice, XRDeviceType};
+
+    fn state_with_headset() -> WebXRState {
+        let mut state = WebXRState::new();
+        state.add_device(XRDevice {
+            id: "headset".to_string(),
+            name: "Headset".to_string(),
+            device_type: XRDeviceType::HeadMountedDisplay,
+            capabilities: XRCapabilities {
+                supports_vr: true,
+                supports_ar: false,
+                supports_hand_tracking: false,
+                supports_eye_tracking: false,
+                supports_spatial_audio: false,
+                max_refresh_rate: 90,
+                field_of_view: None,
+            },
+            is_connected: true,
+            is_available: true,
+        });
+        state
+    }
+
+    #[test]
+    fn test_duplicate_immersive_session_is_rejected() {
+        let mut state = state_with_headset();
+
+        let vr = create_session(&mut state, "headset", XRSessionMode::ImmersiveVR).unwrap();
+        assert!(vr.is_active && vr.start_time > 0);
+        assert_eq!(state.current_session.as_deref(), Some(vr.id.as_str()));
+        assert!(!state.devices["headset"].is_available);
+
+        assert_eq!(
+            create_session(&mut state, "headset", XRSessionMode::ImmersiveAR).unwrap_err(),
+            SessionError::DeviceBusy { device_id: "headset".to_string(), session_id: vr.id.clone() }
+        );
+        // Inline sessions don't compete for the device
+        assert!(create_session(&mut state, "headset", XRSessionMode::Inline).is_ok());
+        assert_eq!(state.current_session.as_deref(), Some(vr.id.as_str()));
+
+        assert_eq!(
+            create_session(&mut state, "missing", XRSessionMode::Inline).unwrap_err(),
+            SessionError::UnknownDevice("missing".to_string())
+        );
+    }
+
+    #[test]
+    fn test_end_session_releases_device() {
+        let mut state = state_with_headset();
+        let vr = create_session(&mut state, "headset", XRSessionMode::ImmersiveVR).unwrap();
+
+        let ended = end_session(&mut state, &vr.id).unwrap();
+        assert!(!ended.is_active);
+        assert_eq!(state.current_session, None);
+        assert!(state.devices["headset"].is_available);
+        assert!(!state.sessions.contains_key(&vr.id));
+        assert_eq!(end_session(&mut state, &vr.id).unwrap_err(), SessionError::UnknownSession(vr.id.clone()));
+
+        // The device can be entered again
+        assert!(create_session(&mut state, "headset", XRSessionMode::ImmersiveVR).is_ok());
+    }
+}
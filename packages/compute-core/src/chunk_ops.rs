@@ -0,0 +1,128 @@
+use rayon::prelude::*;
+
+use crate::distance::norm;
+use crate::Scalar;
+
+/// Built-in transforms applied independently to each fixed-size chunk
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChunkOp<T> {
+    /// Divide by the chunk's L2 norm; all-zero chunks are left as they are
+    Normalize,
+    /// Multiply every value by the factor
+    Scale(T),
+    /// Clamp negative values to zero
+    Relu,
+    /// Softmax over the chunk, treating it as one row
+    SoftmaxRow,
+}
+
+impl<T: Scalar> ChunkOp<T> {
+    /// Parse an op name; `factor` is required by "scale" and ignored otherwise
+    pub fn parse(name: &str, factor: Option<T>) -> Result<Self, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "normalize" => Ok(ChunkOp::Normalize),
+            "scale" => factor
+                .map(ChunkOp::Scale)
+                .ok_or_else(|| "The scale op requires a factor".to_string()),
+            "relu" => Ok(ChunkOp::Relu),
+            "softmax-row" | "softmax_row" => Ok(ChunkOp::SoftmaxRow),
+            other => Err(format!(
+                "Unknown chunk op '{}', expected one of: normalize, scale, relu, softmax-row",
+                other
+            )),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChunkOp::Normalize => "normalize",
+            ChunkOp::Scale(_) => "scale",
+            ChunkOp::Relu => "relu",
+            ChunkOp::SoftmaxRow => "softmax-row",
+        }
+    }
+
+    /// Transform one chunk in place
+    pub fn apply(&self, chunk: &mut [T]) {
+        match *self {
+            ChunkOp::Normalize => {
+                let length = norm(chunk);
+                if length > T::zero() {
+                    chunk.iter_mut().for_each(|value| *value = *value / length);
+                }
+            }
+            ChunkOp::Scale(factor) => chunk.iter_mut().for_each(|value| *value = *value * factor),
+            ChunkOp::Relu => chunk.iter_mut().for_each(|value| *value = value.max(T::zero())),
+            ChunkOp::SoftmaxRow => {
+                // Shifting by the maximum keeps exp from overflowing
+                let max = chunk.iter().copied().fold(T::neg_infinity(), T::max);
+                chunk.iter_mut().for_each(|value| *value = (*value - max).exp());
+                let sum: T = chunk.iter().copied().sum();
+                chunk.iter_mut().for_each(|value| *value = *value / sum);
+            }
+        }
+    }
+}
+
+/// Rejects chunk sizes that don't split `len` values into whole chunks
+pub fn validate_chunking(len: usize, chunk: usize) -> Result<(), String> {
+    if chunk == 0 {
+        return Err("chunk size must be at least 1".to_string());
+    }
+    if !len.is_multiple_of(chunk) {
+        return Err(format!("data length {} is not a multiple of chunk size {}", len, chunk));
+    }
+    Ok(())
+}
+
+/// Apply `op` to every `chunk`-sized slice of `data`. With a `pool` the
+/// chunks are spread across its workers by work stealing; without one they
+/// run in order on the calling thread. Each chunk is transformed
+/// identically either way, so both paths give the same result.
+pub fn for_each_chunk<T: Scalar>(data: &mut [T], chunk: usize, op: ChunkOp<T>, pool: Option<&rayon::ThreadPool>) -> Result<(), String> {
+    validate_chunking(data.len(), chunk)?;
+    match pool {
+        Some(pool) => pool.install(|| data.par_chunks_mut(chunk).for_each(|values| op.apply(values))),
+        None => data.chunks_mut(chunk).for_each(|values| op.apply(values)),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pooled_chunks_match_sequential_for_each_op() {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+        let data: Vec<f32> = (0..4096).map(|i| ((i * 37) % 101) as f32 * 0.1 - 5.0).collect();
+
+        for op in [ChunkOp::Normalize, ChunkOp::Scale(-2.5), ChunkOp::Relu, ChunkOp::SoftmaxRow] {
+            let mut sequential = data.clone();
+            let mut pooled = data.clone();
+            for_each_chunk(&mut sequential, 64, op, None).unwrap();
+            for_each_chunk(&mut pooled, 64, op, Some(&pool)).unwrap();
+            assert_eq!(pooled, sequential, "{}", op.name());
+            assert_ne!(sequential, data, "{}", op.name());
+        }
+    }
+
+    #[test]
+    fn test_chunk_op_results() {
+        let mut values = [3.0f64, -4.0, 0.0, 0.0];
+        for_each_chunk(&mut values, 2, ChunkOp::Normalize, None).unwrap();
+        assert_eq!(values, [0.6, -0.8, 0.0, 0.0]);
+
+        let mut values = [1000.0f64, 1000.0, -1.0, 2.0];
+        for_each_chunk(&mut values, 2, ChunkOp::SoftmaxRow, None).unwrap();
+        assert_eq!(&values[..2], &[0.5, 0.5]);
+        assert!((values[2] + values[3] - 1.0).abs() < 1e-12);
+
+        assert_eq!(ChunkOp::parse("softmax_row", None), Ok(ChunkOp::<f32>::SoftmaxRow));
+        assert_eq!(ChunkOp::parse("scale", Some(2.0f32)), Ok(ChunkOp::Scale(2.0)));
+        assert!(ChunkOp::<f32>::parse("scale", None).is_err());
+        assert!(ChunkOp::<f32>::parse("gelu", None).is_err());
+        assert!(for_each_chunk(&mut [0.0f32; 5], 2, ChunkOp::Relu, None).is_err());
+        assert!(for_each_chunk(&mut [0.0f32; 4], 0, ChunkOp::Relu, None).is_err());
+    }
+}
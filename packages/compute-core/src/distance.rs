@@ -0,0 +1,94 @@
+use crate::Scalar;
+
+/// Distance metrics supported by k-means
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DistanceMetric {
+    Euclidean,
+    Manhattan,
+    Cosine,
+}
+
+impl DistanceMetric {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "euclidean" => Ok(DistanceMetric::Euclidean),
+            "manhattan" => Ok(DistanceMetric::Manhattan),
+            "cosine" => Ok(DistanceMetric::Cosine),
+            other => Err(format!(
+                "Unknown distance metric '{}', expected one of: euclidean, manhattan, cosine",
+                other
+            )),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DistanceMetric::Euclidean => "euclidean",
+            DistanceMetric::Manhattan => "manhattan",
+            DistanceMetric::Cosine => "cosine",
+        }
+    }
+
+    /// Distance used for assignment. Euclidean is left squared since only
+    /// the ordering matters.
+    pub fn distance<T: Scalar>(&self, a: &[T], b: &[T]) -> T {
+        match self {
+            DistanceMetric::Euclidean => squared_euclidean_distance(a, b),
+            DistanceMetric::Manhattan => manhattan_distance(a, b),
+            DistanceMetric::Cosine => cosine_distance(a, b),
+        }
+    }
+}
+
+pub fn norm<T: Scalar>(v: &[T]) -> T {
+    v.iter().map(|&x| x * x).sum::<T>().sqrt()
+}
+
+pub fn squared_euclidean_distance<T: Scalar>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b).map(|(&x, &y)| (x - y) * (x - y)).sum()
+}
+
+pub fn euclidean_distance<T: Scalar>(a: &[T], b: &[T]) -> T {
+    squared_euclidean_distance(a, b).sqrt()
+}
+
+pub fn manhattan_distance<T: Scalar>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b).map(|(&x, &y)| (x - y).abs()).sum()
+}
+
+/// One minus cosine similarity; zero vectors are treated as maximally distant
+pub fn cosine_distance<T: Scalar>(a: &[T], b: &[T]) -> T {
+    let dot: T = a.iter().zip(b).map(|(&x, &y)| x * y).sum();
+    let norms = norm(a) * norm(b);
+    if norms == T::zero() {
+        T::one()
+    } else {
+        T::one() - dot / norms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let a = [0.0f64, 3.0];
+        let b = [4.0f64, 0.0];
+        assert_eq!(euclidean_distance(&a, &b), 5.0);
+        assert_eq!(DistanceMetric::Euclidean.distance(&a, &b), 25.0);
+        assert_eq!(manhattan_distance(&a, &b), 7.0);
+        assert_eq!(cosine_distance(&a, &b), 1.0);
+        assert!(cosine_distance(&[2.0f32, 2.0], &[1.0, 1.0]).abs() < 1e-6);
+        assert_eq!(cosine_distance(&[0.0f32, 0.0], &[1.0, 1.0]), 1.0);
+    }
+
+    #[test]
+    fn test_metric_names_round_trip() {
+        for metric in [DistanceMetric::Euclidean, DistanceMetric::Manhattan, DistanceMetric::Cosine] {
+            assert_eq!(DistanceMetric::parse(metric.name()), Ok(metric));
+        }
+        assert_eq!(DistanceMetric::parse("Cosine"), Ok(DistanceMetric::Cosine));
+        assert!(DistanceMetric::parse("chebyshev").is_err());
+    }
+}
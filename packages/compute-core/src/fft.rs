@@ -0,0 +1,485 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::Scalar;
+
+/// 2D FFT over a row-major `rows x cols` grid: the 1D FFT is applied to
+/// every row, then every column. Either dimension may be any size.
+pub fn fft_2d<T: Scalar>(real: &mut [T], imag: &mut [T], rows: usize, cols: usize, inverse: bool) -> Result<(), String> {
+    if real.len() != rows * cols || imag.len() != rows * cols {
+        return Err(format!(
+            "Expected {} values for a {}x{} grid, got real={} imag={}",
+            rows * cols, rows, cols, real.len(), imag.len()
+        ));
+    }
+    if rows == 0 || cols == 0 {
+        return Ok(());
+    }
+
+    let row_plan = cached_plan::<T>(cols)?;
+    for (row_real, row_imag) in real.chunks_mut(cols).zip(imag.chunks_mut(cols)) {
+        row_plan.execute(row_real, row_imag, inverse)?;
+    }
+
+    let col_plan = cached_plan::<T>(rows)?;
+    let mut col_real = vec![T::zero(); rows];
+    let mut col_imag = vec![T::zero(); rows];
+    for col in 0..cols {
+        for row in 0..rows {
+            col_real[row] = real[row * cols + col];
+            col_imag[row] = imag[row * cols + col];
+        }
+        col_plan.execute(&mut col_real, &mut col_imag, inverse)?;
+        for row in 0..rows {
+            real[row * cols + col] = col_real[row];
+            imag[row * cols + col] = col_imag[row];
+        }
+    }
+
+    Ok(())
+}
+
+/// Window functions applied before an FFT
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WindowFunction {
+    Hann,
+    Hamming,
+    Blackman,
+}
+
+impl WindowFunction {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "hann" | "hanning" => Ok(WindowFunction::Hann),
+            "hamming" => Ok(WindowFunction::Hamming),
+            "blackman" => Ok(WindowFunction::Blackman),
+            other => Err(format!(
+                "Unknown window function '{}', expected one of: hann, hamming, blackman",
+                other
+            )),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            WindowFunction::Hann => "hann",
+            WindowFunction::Hamming => "hamming",
+            WindowFunction::Blackman => "blackman",
+        }
+    }
+
+    /// Symmetric window coefficient for sample `i` of `n`
+    pub fn coefficient(&self, i: usize, n: usize) -> f32 {
+        if n <= 1 {
+            return 1.0;
+        }
+        let phase = 2.0 * std::f32::consts::PI * i as f32 / (n - 1) as f32;
+        match self {
+            WindowFunction::Hann => 0.5 - 0.5 * phase.cos(),
+            WindowFunction::Hamming => 0.54 - 0.46 * phase.cos(),
+            WindowFunction::Blackman => 0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos(),
+        }
+    }
+
+    pub fn apply(&self, data: &mut [f32]) {
+        let n = data.len();
+        for (i, value) in data.iter_mut().enumerate() {
+            *value *= self.coefficient(i, n);
+        }
+    }
+}
+
+pub fn validate_fft_input<T>(real: &[T], imag: &[T]) -> Result<(), String> {
+    if real.len() != imag.len() {
+        return Err(format!(
+            "real and imaginary buffers differ in length: {} vs {}",
+            real.len(), imag.len()
+        ));
+    }
+    Ok(())
+}
+
+/// In-place FFT of any length using a cached plan; the inverse is scaled by
+/// 1/n. Every binding's FFT goes through here, so results are identical
+/// across them for the same input.
+pub fn fft<T: Scalar>(real: &mut [T], imag: &mut [T], inverse: bool) -> Result<(), String> {
+    validate_fft_input(real, imag)?;
+    if real.is_empty() {
+        return Ok(());
+    }
+    cached_plan::<T>(real.len())?.execute(real, imag, inverse)
+}
+
+/// Plans kept before the cache is cleared, bounding memory when callers
+/// transform many distinct sizes
+const PLAN_CACHE_CAPACITY: usize = 64;
+
+type PlanCache = Mutex<HashMap<(TypeId, usize), Arc<dyn Any + Send + Sync>>>;
+
+static PLAN_CACHE: OnceLock<PlanCache> = OnceLock::new();
+
+/// Shared plan for size `n` and element type `T`, built on first use
+pub fn cached_plan<T: Scalar>(n: usize) -> Result<Arc<FftPlan<T>>, String> {
+    let cache = PLAN_CACHE.get_or_init(Default::default);
+    let key = (TypeId::of::<T>(), n);
+
+    let cached = cache.lock().unwrap_or_else(|e| e.into_inner()).get(&key).cloned();
+    if let Some(plan) = cached {
+        return plan
+            .downcast::<FftPlan<T>>()
+            .map_err(|_| format!("FFT plan cache holds the wrong type for size {}", n));
+    }
+
+    let plan = Arc::new(FftPlan::new(n)?);
+    let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+    if cache.len() >= PLAN_CACHE_CAPACITY {
+        cache.clear();
+    }
+    cache.insert(key, plan.clone());
+    Ok(plan)
+}
+
+/// Precomputed FFT for one fixed size. Powers of two use radix-2
+/// Cooley-Tukey with the bit-reversal swaps and every stage's twiddle
+/// factors built once; other sizes use Bluestein's algorithm, which
+/// rewrites the transform as a convolution evaluated with a power-of-two
+/// plan of at least `2n - 1` points.
+#[derive(Debug, Clone)]
+pub struct FftPlan<T> {
+    n: usize,
+    kind: PlanKind<T>,
+}
+
+#[derive(Debug, Clone)]
+enum PlanKind<T> {
+    Radix2(Radix2<T>),
+    Bluestein {
+        convolution: Radix2<T>,
+        /// `exp(-iπk²/n)` for each of the `n` output bins
+        chirp: Vec<(T, T)>,
+        /// Forward transform of the conjugate chirp, wrapped to the
+        /// convolution size
+        kernel_real: Vec<T>,
+        kernel_imag: Vec<T>,
+    },
+}
+
+impl<T: Scalar> FftPlan<T> {
+    pub fn new(n: usize) -> Result<Self, String> {
+        if n == 0 {
+            return Err("FFT plan size must be at least 1".to_string());
+        }
+        if n.is_power_of_two() {
+            return Ok(FftPlan { n, kind: PlanKind::Radix2(Radix2::new(n)) });
+        }
+
+        let m = (2 * n - 1).next_power_of_two();
+        let convolution = Radix2::new(m);
+
+        // k² is reduced mod 2n before scaling so large k keep their precision
+        let pi = std::f64::consts::PI;
+        let chirp: Vec<(T, T)> = (0..n)
+            .map(|k| {
+                let angle = -pi * ((k * k) % (2 * n)) as f64 / n as f64;
+                (to_scalar(angle.cos()), to_scalar(angle.sin()))
+            })
+            .collect();
+
+        let mut kernel_real = vec![T::zero(); m];
+        let mut kernel_imag = vec![T::zero(); m];
+        for (k, &(re, im)) in chirp.iter().enumerate() {
+            kernel_real[k] = re;
+            kernel_imag[k] = -im;
+            if k > 0 {
+                kernel_real[m - k] = re;
+                kernel_imag[m - k] = -im;
+            }
+        }
+        convolution.transform(&mut kernel_real, &mut kernel_imag, false);
+
+        Ok(FftPlan {
+            n,
+            kind: PlanKind::Bluestein { convolution, chirp, kernel_real, kernel_imag },
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// "radix2" or "bluestein"
+    pub fn algorithm(&self) -> &'static str {
+        match self.kind {
+            PlanKind::Radix2(_) => "radix2",
+            PlanKind::Bluestein { .. } => "bluestein",
+        }
+    }
+
+    /// Transform in place; both buffers must have exactly `size()` elements.
+    /// The inverse is scaled by 1/n.
+    pub fn execute(&self, real: &mut [T], imag: &mut [T], inverse: bool) -> Result<(), String> {
+        if real.len() != self.n || imag.len() != self.n {
+            return Err(format!(
+                "FFT plan is for {} points, got real={} imag={}",
+                self.n, real.len(), imag.len()
+            ));
+        }
+
+        match &self.kind {
+            PlanKind::Radix2(radix2) => radix2.transform(real, imag, inverse),
+            PlanKind::Bluestein { convolution, chirp, kernel_real, kernel_imag } => {
+                // The inverse DFT is the conjugate of the forward DFT of the
+                // conjugate input
+                if inverse {
+                    imag.iter_mut().for_each(|value| *value = -*value);
+                }
+
+                let m = convolution.n;
+                let mut a_real = vec![T::zero(); m];
+                let mut a_imag = vec![T::zero(); m];
+                for k in 0..self.n {
+                    let (c_re, c_im) = chirp[k];
+                    a_real[k] = real[k] * c_re - imag[k] * c_im;
+                    a_imag[k] = real[k] * c_im + imag[k] * c_re;
+                }
+
+                convolution.transform(&mut a_real, &mut a_imag, false);
+                for k in 0..m {
+                    let (re, im) = (a_real[k], a_imag[k]);
+                    a_real[k] = re * kernel_real[k] - im * kernel_imag[k];
+                    a_imag[k] = re * kernel_imag[k] + im * kernel_real[k];
+                }
+                convolution.transform(&mut a_real, &mut a_imag, true);
+
+                let m_scale = to_scalar::<T>(m as f64);
+                for k in 0..self.n {
+                    let (c_re, c_im) = chirp[k];
+                    let (re, im) = (a_real[k] / m_scale, a_imag[k] / m_scale);
+                    real[k] = re * c_re - im * c_im;
+                    imag[k] = re * c_im + im * c_re;
+                }
+
+                if inverse {
+                    imag.iter_mut().for_each(|value| *value = -*value);
+                }
+            }
+        }
+
+        if inverse {
+            let n_f = to_scalar::<T>(self.n as f64);
+            for i in 0..self.n {
+                real[i] = real[i] / n_f;
+                imag[i] = imag[i] / n_f;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn to_scalar<T: Scalar>(value: f64) -> T {
+    T::from(value).unwrap_or_else(T::nan)
+}
+
+/// Unscaled radix-2 butterflies for a power-of-two size
+#[derive(Debug, Clone)]
+struct Radix2<T> {
+    n: usize,
+    swaps: Vec<(usize, usize)>,
+    /// Twiddles for stage length `2h` start at offset `h - 1`
+    forward: Vec<(T, T)>,
+    inverse: Vec<(T, T)>,
+}
+
+impl<T: Scalar> Radix2<T> {
+    fn new(n: usize) -> Self {
+        let mut swaps = Vec::new();
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j ^= bit;
+            
+            if i < j {
+                swaps.push((i, j));
+            }
+        }
+        
+        Radix2 {
+            n,
+            swaps,
+            forward: Self::twiddles(n, false),
+            inverse: Self::twiddles(n, true),
+        }
+    }
+
+    fn twiddles(n: usize, inverse: bool) -> Vec<(T, T)> {
+        let two_pi = to_scalar::<T>(2.0 * std::f64::consts::PI);
+        let mut twiddles = Vec::with_capacity(n.saturating_sub(1));
+        let mut length = 2;
+        while length <= n {
+            let angle = if inverse { two_pi / to_scalar(length as f64) } else { -two_pi / to_scalar(length as f64) };
+            let wlen_real = angle.cos();
+            let wlen_imag = angle.sin();
+            
+            let mut w_real = T::one();
+            let mut w_imag = T::zero();
+            for _ in 0..(length / 2) {
+                twiddles.push((w_real, w_imag));
+                let w_temp = w_real * wlen_real - w_imag * wlen_imag;
+                w_imag = w_real * wlen_imag + w_imag * wlen_real;
+                w_real = w_temp;
+            }
+            length <<= 1;
+        }
+        twiddles
+    }
+
+    fn transform(&self, real: &mut [T], imag: &mut [T], inverse: bool) {
+        for &(i, j) in &self.swaps {
+            real.swap(i, j);
+            imag.swap(i, j);
+        }
+        
+        let twiddles = if inverse { &self.inverse } else { &self.forward };
+        let mut length = 2;
+        while length <= self.n {
+            let half = length / 2;
+            let stage = &twiddles[half - 1..length - 1];
+            
+            for i in (0..self.n).step_by(length) {
+                for (j, &(w_real, w_imag)) in stage.iter().enumerate() {
+                    let u_real = real[i + j];
+                    let u_imag = imag[i + j];
+                    let v_real = real[i + j + half] * w_real - imag[i + j + half] * w_imag;
+                    let v_imag = real[i + j + half] * w_imag + imag[i + j + half] * w_real;
+                    
+                    real[i + j] = u_real + v_real;
+                    imag[i + j] = u_imag + v_imag;
+                    real[i + j + half] = u_real - v_real;
+                    imag[i + j + half] = u_imag - v_imag;
+                }
+            }
+            length <<= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::approx_equal_matrix;
+
+    /// Direct O(n²) DFT in f64 as the reference spectrum
+    fn naive_dft(real: &[f64], imag: &[f64], inverse: bool) -> (Vec<f64>, Vec<f64>) {
+        let n = real.len();
+        let sign = if inverse { 1.0 } else { -1.0 };
+        let scale = if inverse { 1.0 / n as f64 } else { 1.0 };
+        (0..n)
+            .map(|k| {
+                (0..n).fold((0.0, 0.0), |(re, im), j| {
+                    let angle = sign * 2.0 * std::f64::consts::PI * ((j * k) % n) as f64 / n as f64;
+                    (
+                        re + real[j] * angle.cos() - imag[j] * angle.sin(),
+                        im + real[j] * angle.sin() + imag[j] * angle.cos(),
+                    )
+                })
+            })
+            .map(|(re, im)| (re * scale, im * scale))
+            .unzip()
+    }
+
+    fn signal(n: usize) -> (Vec<f64>, Vec<f64>) {
+        let real = (0..n).map(|i| ((i * 7) % 11) as f64 * 0.3 - 1.0).collect();
+        let imag = (0..n).map(|i| ((i * 3) % 5) as f64 * 0.1).collect();
+        (real, imag)
+    }
+
+    #[test]
+    fn test_fft_matches_naive_dft_for_any_length() {
+        for n in [1, 2, 3, 5, 6, 8, 12, 17, 64, 100] {
+            for inverse in [false, true] {
+                let (real, imag) = signal(n);
+                let (expected_real, expected_imag) = naive_dft(&real, &imag, inverse);
+                let (mut out_real, mut out_imag) = (real.clone(), imag.clone());
+                fft(&mut out_real, &mut out_imag, inverse).unwrap();
+
+                assert!(approx_equal_matrix(&out_real, &expected_real, 1e-9, 1e-9), "n={}", n);
+                assert!(approx_equal_matrix(&out_imag, &expected_imag, 1e-9, 1e-9), "n={}", n);
+            }
+        }
+        assert_eq!(FftPlan::<f64>::new(12).unwrap().algorithm(), "bluestein");
+        assert_eq!(FftPlan::<f64>::new(16).unwrap().algorithm(), "radix2");
+    }
+
+    #[test]
+    fn test_fft_round_trip() {
+        let original: Vec<f32> = (0..15).map(|i| ((i * 5) % 7) as f32 - 3.0).collect();
+        let mut real = original.clone();
+        let mut imag = vec![0.0; 15];
+
+        fft(&mut real, &mut imag, false).unwrap();
+        // The DC bin is the sum of the input
+        assert!((real[0] - original.iter().sum::<f32>()).abs() < 1e-4);
+
+        fft(&mut real, &mut imag, true).unwrap();
+        assert!(approx_equal_matrix(&real, &original, 1e-4, 1e-4));
+        assert!(approx_equal_matrix(&imag, &[0.0; 15], 0.0, 1e-4));
+        assert!(validate_fft_input(&real[..12], &imag[..10]).is_err());
+        assert!(fft::<f32>(&mut [], &mut [], false).is_ok());
+    }
+
+    #[test]
+    fn test_cached_plans_are_shared_and_match_fresh_plans() {
+        let first = cached_plan::<f32>(24).unwrap();
+        assert!(Arc::ptr_eq(&first, &cached_plan::<f32>(24).unwrap()));
+        // f32 and f64 plans of the same size are kept apart
+        assert_eq!(cached_plan::<f64>(24).unwrap().size(), 24);
+
+        for n in [1, 8, 24, 256] {
+            let plan = FftPlan::<f32>::new(n).unwrap();
+            for inverse in [false, true] {
+                let mut real: Vec<f32> = (0..n).map(|i| ((i * 7) % 11) as f32 * 0.3 - 1.0).collect();
+                let mut imag: Vec<f32> = (0..n).map(|i| ((i * 3) % 5) as f32 * 0.1).collect();
+                let (mut plan_real, mut plan_imag) = (real.clone(), imag.clone());
+
+                fft(&mut real, &mut imag, inverse).unwrap();
+                plan.execute(&mut plan_real, &mut plan_imag, inverse).unwrap();
+                assert_eq!(plan_real, real);
+                assert_eq!(plan_imag, imag);
+            }
+        }
+
+        assert!(FftPlan::<f32>::new(0).is_err());
+        let plan = FftPlan::<f32>::new(8).unwrap();
+        assert!(plan.execute(&mut [0.0; 4], &mut [0.0; 4], false).is_err());
+    }
+
+    #[test]
+    fn test_window_names_round_trip() {
+        for window in [WindowFunction::Hann, WindowFunction::Hamming, WindowFunction::Blackman] {
+            assert_eq!(WindowFunction::parse(window.name()), Ok(window));
+        }
+        assert_eq!(WindowFunction::parse("Hanning"), Ok(WindowFunction::Hann));
+    }
+
+    #[test]
+    fn test_fft_2d_accepts_any_dimensions() {
+        let (rows, cols) = (3, 5);
+        let (real, imag) = signal(rows * cols);
+        let (mut out_real, mut out_imag) = (real.clone(), imag.clone());
+        fft_2d(&mut out_real, &mut out_imag, rows, cols, false).unwrap();
+
+        // The DC bin is the sum of the grid
+        assert!((out_real[0] - real.iter().sum::<f64>()).abs() < 1e-9);
+
+        fft_2d(&mut out_real, &mut out_imag, rows, cols, true).unwrap();
+        assert!(approx_equal_matrix(&out_real, &real, 1e-9, 1e-9));
+        assert!(approx_equal_matrix(&out_imag, &imag, 1e-9, 1e-9));
+        assert!(fft_2d(&mut out_real, &mut out_imag, 4, 4, false).is_err());
+    }
+}
@@ -0,0 +1,288 @@
+use rayon::prelude::*;
+
+use crate::distance::{norm, DistanceMetric};
+use crate::Scalar;
+
+/// K-means assignments with convergence diagnostics
+#[derive(Debug, Clone)]
+pub struct KMeans<T> {
+    /// Cluster of each point; empty if interrupted before the first
+    /// assignment step
+    pub assignments: Vec<u32>,
+    /// Row-major `k x dimensions` centroids at the end of the run
+    pub centroids: Vec<T>,
+    /// Inertia after each iteration's assignment step
+    pub inertia_history: Vec<f64>,
+    /// Iterations actually run, at most `max_iterations`
+    pub iterations: usize,
+    /// Whether assignments stopped changing before `max_iterations`
+    pub converged: bool,
+    /// Whether the `interrupted` check ended the run early
+    pub interrupted: bool,
+}
+
+/// Rejects k-means inputs that would index out of bounds or divide by zero
+pub fn validate_k_means_input<T>(data: &[T], dimensions: usize, k: usize) -> Result<(), String> {
+    if dimensions == 0 {
+        return Err("dimensions must be at least 1".to_string());
+    }
+    if !data.len().is_multiple_of(dimensions) {
+        return Err(format!(
+            "data length {} is not a multiple of dimensions {}",
+            data.len(), dimensions
+        ));
+    }
+    let n_points = data.len() / dimensions;
+    if k == 0 || k > n_points {
+        return Err(format!("k must be between 1 and the number of points ({}), got {}", n_points, k));
+    }
+    Ok(())
+}
+
+/// `k` starting centroids taken from evenly spaced points, so results are
+/// deterministic
+pub fn evenly_spaced_centroids<T: Scalar>(data: &[T], dimensions: usize, k: usize) -> Vec<T> {
+    let n_points = data.len() / dimensions;
+    let mut centroids = Vec::with_capacity(k * dimensions);
+    for i in 0..k {
+        let point = i * n_points / k;
+        centroids.extend_from_slice(&data[point * dimensions..(point + 1) * dimensions]);
+    }
+    centroids
+}
+
+/// Lloyd's k-means over row-major `data`, starting from evenly spaced
+/// points. Iteration stops early once the assignments stop changing. When
+/// `pool` is given the assignment step runs on it; each point's assignment
+/// is computed identically either way.
+pub fn k_means<T: Scalar>(data: &[T], dimensions: usize, k: usize, max_iterations: usize, metric: DistanceMetric, pool: Option<&rayon::ThreadPool>) -> KMeans<T> {
+    let centroids = evenly_spaced_centroids(data, dimensions, k);
+    k_means_from(data, dimensions, centroids, max_iterations, metric, pool, || false)
+}
+
+/// Lloyd's k-means from the given row-major starting `centroids`.
+/// `interrupted` is checked before each iteration so callers can enforce a
+/// deadline; the result then reflects the last completed iteration.
+pub fn k_means_from<T: Scalar>(
+    data: &[T],
+    dimensions: usize,
+    mut centroids: Vec<T>,
+    max_iterations: usize,
+    metric: DistanceMetric,
+    pool: Option<&rayon::ThreadPool>,
+    interrupted: impl Fn() -> bool,
+) -> KMeans<T> {
+    let n_points = data.len() / dimensions;
+    let k = centroids.len() / dimensions;
+
+    let mut assignments = vec![0u32; n_points];
+    let mut distances = vec![T::zero(); n_points];
+    let mut previous: Option<Vec<u32>> = None;
+    let mut inertia_history = Vec::new();
+    let mut converged = false;
+    let mut was_interrupted = false;
+
+    for _iteration in 0..max_iterations {
+        if interrupted() {
+            was_interrupted = true;
+            break;
+        }
+
+        // Assign points to closest centroids
+        match pool {
+            Some(pool) => pool.install(|| {
+                assignments.par_iter_mut().zip(distances.par_iter_mut()).enumerate().for_each(|(point_idx, (assignment, distance))| {
+                    let point = &data[point_idx * dimensions..(point_idx + 1) * dimensions];
+                    (*assignment, *distance) = nearest_centroid(point, &centroids, k, dimensions, metric);
+                });
+            }),
+            None => {
+                for (point_idx, (assignment, distance)) in assignments.iter_mut().zip(distances.iter_mut()).enumerate() {
+                    let point = &data[point_idx * dimensions..(point_idx + 1) * dimensions];
+                    (*assignment, *distance) = nearest_centroid(point, &centroids, k, dimensions, metric);
+                }
+            }
+        }
+
+        // Summed sequentially so both paths report identical inertia
+        inertia_history.push(distances.iter().map(|d| d.to_f64().unwrap_or(f64::NAN)).sum());
+
+        // Unchanged assignments give unchanged centroids, so this is a fixed point
+        if previous.as_ref() == Some(&assignments) {
+            converged = true;
+            break;
+        }
+        previous = Some(assignments.clone());
+
+        centroids = match metric {
+            DistanceMetric::Euclidean => mean_centroids(data, dimensions, k, &assignments),
+            DistanceMetric::Manhattan => median_centroids(data, dimensions, k, &assignments),
+            DistanceMetric::Cosine => {
+                let mut centroids = mean_centroids(data, dimensions, k, &assignments);
+                for centroid in centroids.chunks_mut(dimensions) {
+                    let length = norm(centroid);
+                    if length > T::zero() {
+                        centroid.iter_mut().for_each(|value| *value = *value / length);
+                    }
+                }
+                centroids
+            }
+        };
+    }
+
+    if inertia_history.is_empty() {
+        assignments.clear();
+    }
+
+    KMeans {
+        iterations: inertia_history.len(),
+        assignments,
+        centroids,
+        inertia_history,
+        converged,
+        interrupted: was_interrupted,
+    }
+}
+
+/// Per-cluster mean; clusters with no points are reset to the origin
+pub fn mean_centroids<T: Scalar>(data: &[T], dimensions: usize, k: usize, assignments: &[u32]) -> Vec<T> {
+    let mut new_centroids = vec![T::zero(); k * dimensions];
+    let mut counts = vec![0usize; k];
+
+    for point_idx in 0..assignments.len() {
+        let cluster = assignments[point_idx] as usize;
+        counts[cluster] += 1;
+        for dim in 0..dimensions {
+            let sum = &mut new_centroids[cluster * dimensions + dim];
+            *sum = *sum + data[point_idx * dimensions + dim];
+        }
+    }
+
+    for cluster in 0..k {
+        if counts[cluster] > 0 {
+            let count = T::from(counts[cluster]).unwrap_or_else(T::one);
+            for dim in 0..dimensions {
+                let sum = &mut new_centroids[cluster * dimensions + dim];
+                *sum = *sum / count;
+            }
+        }
+    }
+
+    new_centroids
+}
+
+/// Per-cluster, per-dimension median, which minimises total L1 distance
+pub fn median_centroids<T: Scalar>(data: &[T], dimensions: usize, k: usize, assignments: &[u32]) -> Vec<T> {
+    let mut new_centroids = vec![T::zero(); k * dimensions];
+    let mut values = Vec::new();
+    let two = T::one() + T::one();
+
+    for cluster in 0..k {
+        for dim in 0..dimensions {
+            values.clear();
+            values.extend(
+                assignments.iter().enumerate()
+                    .filter(|(_, &assignment)| assignment as usize == cluster)
+                    .map(|(point_idx, _)| data[point_idx * dimensions + dim])
+            );
+            if values.is_empty() {
+                continue;
+            }
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            let mid = values.len() / 2;
+            new_centroids[cluster * dimensions + dim] = if values.len().is_multiple_of(2) {
+                (values[mid - 1] + values[mid]) / two
+            } else {
+                values[mid]
+            };
+        }
+    }
+
+    new_centroids
+}
+
+/// Index of the centroid closest to `point` under `metric`, and its distance
+pub fn nearest_centroid<T: Scalar>(point: &[T], centroids: &[T], k: usize, dimensions: usize, metric: DistanceMetric) -> (u32, T) {
+    let mut best_distance = T::infinity();
+    let mut best_centroid = 0;
+
+    for centroid_idx in 0..k {
+        let centroid = &centroids[centroid_idx * dimensions..(centroid_idx + 1) * dimensions];
+        let distance = metric.distance(point, centroid);
+
+        if distance < best_distance {
+            best_distance = distance;
+            best_centroid = centroid_idx;
+        }
+    }
+
+    (best_centroid as u32, best_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k_means_separates_clusters() {
+        let data = [0.0f32, 0.1, 0.2, 10.0, 10.1, 10.2];
+        let result = k_means(&data, 1, 2, 20, DistanceMetric::Euclidean, None);
+        assert_eq!(result.assignments, vec![0, 0, 0, 1, 1, 1]);
+        assert!(result.converged);
+        assert_eq!(result.iterations, result.inertia_history.len());
+        assert!((result.centroids[1] - 10.1).abs() < 1e-5);
+        assert!(validate_k_means_input(&data, 1, 7).is_err());
+    }
+
+    #[test]
+    fn test_pooled_k_means_matches_sequential() {
+        let data: Vec<f64> = (0..200).map(|i| ((i * 37) % 101) as f64 * 0.25).collect();
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+        for metric in [DistanceMetric::Euclidean, DistanceMetric::Manhattan, DistanceMetric::Cosine] {
+            let sequential = k_means(&data, 2, 4, 50, metric, None);
+            let pooled = k_means(&data, 2, 4, 50, metric, Some(&pool));
+            assert_eq!(pooled.assignments, sequential.assignments);
+            assert_eq!(pooled.centroids, sequential.centroids);
+            assert_eq!(pooled.inertia_history, sequential.inertia_history);
+        }
+    }
+
+    #[test]
+    fn test_f64_and_f32_k_means_agree() {
+        let data: Vec<f64> = (0..600)
+            .map(|i| (i as f64 * 0.61).sin() * 3.0 + (i / 200) as f64 * 40.0)
+            .collect();
+        let single: Vec<f32> = data.iter().map(|&x| x as f32).collect();
+
+        for metric in [DistanceMetric::Euclidean, DistanceMetric::Manhattan, DistanceMetric::Cosine] {
+            let double = k_means(&data, 2, 3, 30, metric, None);
+            let single = k_means(&single, 2, 3, 30, metric, None);
+            assert_eq!(double.assignments, single.assignments, "{}", metric.name());
+
+            let widened: Vec<f64> = single.centroids.iter().map(|&x| x as f64).collect();
+            assert!(crate::approx_equal_matrix(&double.centroids, &widened, 1e-5, 1e-6), "{}", metric.name());
+        }
+    }
+
+    #[test]
+    fn test_interrupted_k_means_stops_early() {
+        let data: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        let centroids = evenly_spaced_centroids(&data, 1, 2);
+        assert_eq!(centroids, vec![0.0, 5.0]);
+
+        let result = k_means_from(&data, 1, centroids.clone(), usize::MAX, DistanceMetric::Euclidean, None, || true);
+        assert!(result.interrupted);
+        assert_eq!(result.iterations, 0);
+        assert!(result.assignments.is_empty());
+        assert_eq!(result.centroids, centroids);
+
+        let iterations = std::cell::Cell::new(0);
+        let result = k_means_from(&data, 1, centroids, usize::MAX, DistanceMetric::Euclidean, None, || {
+            iterations.set(iterations.get() + 1);
+            iterations.get() > 1
+        });
+        assert!(result.interrupted);
+        assert_eq!(result.iterations, 1);
+        assert_eq!(result.assignments.len(), 10);
+    }
+}
@@ -0,0 +1,29 @@
+//! Numeric cores shared by the Katalyst bindings: matrix multiply, FFT and
+//! STFT, k-means, distance metrics, per-chunk transforms and tolerance-based
+//! result comparison as plain functions with no `wasm_bindgen`, `rustler`
+//! or `napi` types. The WASM runtime, braun's NIFs and the Node addon
+//! delegate here, and native consumers can depend on it directly.
+//!
+//! Errors are plain `String` messages each binding maps to its own error type.
+
+pub mod chunk_ops;
+pub mod distance;
+pub mod fft;
+pub mod kmeans;
+pub mod matmul;
+pub mod stft;
+pub mod tolerance;
+
+pub use chunk_ops::{for_each_chunk, validate_chunking, ChunkOp};
+pub use distance::{cosine_distance, euclidean_distance, manhattan_distance, norm, squared_euclidean_distance, DistanceMetric};
+pub use fft::{cached_plan, fft, fft_2d, validate_fft_input, FftPlan, WindowFunction};
+pub use kmeans::{evenly_spaced_centroids, k_means, k_means_from, mean_centroids, median_centroids, nearest_centroid, validate_k_means_input, KMeans};
+pub use matmul::{matmul, validate_matmul_input};
+pub use stft::Stft;
+pub use tolerance::{approx_equal, approx_equal_matrix};
+
+/// Floating-point element types the generic cores accept: `f32` and `f64`
+pub trait Scalar: num_traits::Float + std::iter::Sum + std::fmt::Debug + Send + Sync + 'static {}
+
+impl Scalar for f32 {}
+impl Scalar for f64 {}
@@ -0,0 +1,58 @@
+use crate::Scalar;
+
+/// Check that row-major operand buffers match an `rows_a x cols_a` times
+/// `cols_a x cols_b` product
+pub fn validate_matmul_input(a_len: usize, b_len: usize, rows_a: usize, cols_a: usize, cols_b: usize) -> Result<(), String> {
+    if a_len != rows_a * cols_a {
+        return Err(format!("Matrix A is not {}x{}: got {} values", rows_a, cols_a, a_len));
+    }
+    if b_len != cols_a * cols_b {
+        return Err(format!("Matrix B is not {}x{}: got {} values", cols_a, cols_b, b_len));
+    }
+    Ok(())
+}
+
+/// Row-major `rows_a x cols_a` times `cols_a x cols_b` product. Rows of B
+/// are streamed in order so the inner loop stays contiguous.
+pub fn matmul<T: Scalar>(a: &[T], b: &[T], rows_a: usize, cols_a: usize, cols_b: usize) -> Result<Vec<T>, String> {
+    validate_matmul_input(a.len(), b.len(), rows_a, cols_a, cols_b)?;
+
+    let mut product = vec![T::zero(); rows_a * cols_b];
+    if cols_b == 0 {
+        return Ok(product);
+    }
+    for (a_row, out_row) in a.chunks_exact(cols_a.max(1)).zip(product.chunks_exact_mut(cols_b)) {
+        for (&a_value, b_row) in a_row.iter().zip(b.chunks_exact(cols_b)) {
+            for (out, &b_value) in out_row.iter_mut().zip(b_row) {
+                *out = *out + a_value * b_value;
+            }
+        }
+    }
+    Ok(product)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matmul() {
+        let a = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let b = [7.0f32, 8.0, 9.0, 10.0, 11.0, 12.0];
+        assert_eq!(matmul(&a, &b, 2, 3, 2).unwrap(), vec![58.0, 64.0, 139.0, 154.0]);
+        assert!(matmul(&a, &b, 3, 3, 2).is_err());
+        assert!(validate_matmul_input(6, 6, 2, 3, 2).is_ok());
+        assert!(validate_matmul_input(6, 5, 2, 3, 2).is_err());
+    }
+
+    #[test]
+    fn test_matmul_f64_and_degenerate_shapes() {
+        let a = [0.5f64, -1.0, 2.0, 0.25];
+        let identity = [1.0f64, 0.0, 0.0, 1.0];
+        assert_eq!(matmul(&a, &identity, 2, 2, 2).unwrap(), a.to_vec());
+
+        // An empty inner dimension gives a zero matrix of the outer shape
+        assert_eq!(matmul::<f64>(&[], &[], 2, 0, 3).unwrap(), vec![0.0; 6]);
+        assert!(matmul(&a, &[], 2, 2, 0).unwrap().is_empty());
+    }
+}
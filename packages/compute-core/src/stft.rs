@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use crate::fft::{cached_plan, FftPlan, WindowFunction};
+
+/// Short-time Fourier transform over a stream of samples. Samples are
+/// buffered as they arrive and every `hop_size` new samples complete a
+/// frame over the latest `window_size` of them, so overlapping frames reuse
+/// the buffered overlap instead of copying it in again. The plan, window
+/// coefficients and FFT scratch buffers are allocated once.
+#[derive(Debug, Clone)]
+pub struct Stft {
+    window_size: usize,
+    hop_size: usize,
+    window: Option<WindowFunction>,
+    coefficients: Vec<f32>,
+    plan: Arc<FftPlan<f32>>,
+    /// Samples not yet dropped by a hop; the next frame is the first
+    /// `window_size` of them
+    pending: Vec<f32>,
+    /// Samples still to discard when the hop is longer than the window
+    skip: usize,
+    real: Vec<f32>,
+    imag: Vec<f32>,
+    frames_emitted: usize,
+}
+
+impl Stft {
+    pub fn new(window_size: usize, hop_size: usize, window: Option<WindowFunction>) -> Result<Self, String> {
+        if window_size == 0 {
+            return Err("STFT window size must be at least 1".to_string());
+        }
+        if hop_size == 0 {
+            return Err("STFT hop size must be at least 1".to_string());
+        }
+
+        let coefficients = (0..window_size)
+            .map(|i| window.map_or(1.0, |window| window.coefficient(i, window_size)))
+            .collect();
+
+        Ok(Stft {
+            window_size,
+            hop_size,
+            window,
+            coefficients,
+            plan: cached_plan(window_size)?,
+            pending: Vec::with_capacity(window_size + hop_size),
+            skip: 0,
+            real: vec![0.0; window_size],
+            imag: vec![0.0; window_size],
+            frames_emitted: 0,
+        })
+    }
+
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    pub fn hop_size(&self) -> usize {
+        self.hop_size
+    }
+
+    pub fn window(&self) -> Option<WindowFunction> {
+        self.window
+    }
+
+    /// Magnitudes per frame: the one-sided spectrum, `window_size / 2 + 1` bins
+    pub fn bins(&self) -> usize {
+        self.window_size / 2 + 1
+    }
+
+    pub fn frames_emitted(&self) -> usize {
+        self.frames_emitted
+    }
+
+    /// Whether buffered samples already complete a frame
+    pub fn frame_ready(&self) -> bool {
+        self.pending.len() >= self.window_size
+    }
+
+    /// Buffer more samples; call `next_frame` until it returns `None` to
+    /// drain the frames they complete
+    pub fn push(&mut self, samples: &[f32]) {
+        let skipped = self.skip.min(samples.len());
+        self.skip -= skipped;
+        self.pending.extend_from_slice(&samples[skipped..]);
+    }
+
+    /// Magnitude spectrum of the next completed frame, advancing by one hop
+    pub fn next_frame(&mut self) -> Option<Vec<f32>> {
+        if !self.frame_ready() {
+            return None;
+        }
+
+        for i in 0..self.window_size {
+            self.real[i] = self.pending[i] * self.coefficients[i];
+            self.imag[i] = 0.0;
+        }
+        self.plan
+            .execute(&mut self.real, &mut self.imag, false)
+            .expect("STFT scratch buffers match the plan size");
+
+        let drained = self.hop_size.min(self.pending.len());
+        self.pending.drain(..drained);
+        self.skip = self.hop_size - drained;
+        self.frames_emitted += 1;
+
+        Some((0..self.bins()).map(|k| self.real[k].hypot(self.imag[k])).collect())
+    }
+
+    /// Drop buffered samples so the next frame starts from fresh input
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.skip = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(start: usize, len: usize, cycles_per_sample: f32) -> Vec<f32> {
+        (start..start + len)
+            .map(|i| (2.0 * std::f32::consts::PI * cycles_per_sample * i as f32).sin())
+            .collect()
+    }
+
+    fn peak(spectrum: &[f32]) -> usize {
+        (0..spectrum.len()).max_by(|&a, &b| spectrum[a].total_cmp(&spectrum[b])).unwrap()
+    }
+
+    #[test]
+    fn test_continuous_tone_gives_stable_peak() {
+        let mut stft = Stft::new(64, 16, Some(WindowFunction::Hann)).unwrap();
+        // Bin 8 of a 64-point frame
+        let frequency = 8.0 / 64.0;
+
+        // Uneven chunks, as audio callbacks deliver them
+        let mut frames = Vec::new();
+        let mut position = 0;
+        for chunk in [10, 50, 7, 100, 33, 56] {
+            stft.push(&tone(position, chunk, frequency));
+            position += chunk;
+            while let Some(frame) = stft.next_frame() {
+                frames.push(frame);
+            }
+        }
+
+        // 256 samples: the first frame after 64, then one per 16-sample hop
+        assert_eq!(frames.len(), (position - 64) / 16 + 1);
+        assert_eq!(stft.frames_emitted(), frames.len());
+        for frame in &frames {
+            assert_eq!(frame.len(), stft.bins());
+            assert_eq!(peak(frame), 8);
+            assert!((frame[8] - frames[0][8]).abs() < 1e-3 * frames[0][8]);
+        }
+    }
+
+    #[test]
+    fn test_hop_longer_than_window_skips_samples() {
+        let mut stft = Stft::new(4, 6, None).unwrap();
+        stft.push(&[1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(stft.next_frame().unwrap()[0], 4.0);
+
+        // The two 9s fall in the gap between frames
+        stft.push(&[9.0, 9.0, 2.0, 2.0, 2.0]);
+        assert!(stft.next_frame().is_none());
+        stft.push(&[2.0]);
+        assert_eq!(stft.next_frame().unwrap()[0], 8.0);
+
+        assert!(Stft::new(0, 1, None).is_err());
+        assert!(Stft::new(8, 0, None).is_err());
+    }
+}
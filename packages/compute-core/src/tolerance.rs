@@ -0,0 +1,43 @@
+use crate::Scalar;
+
+/// Whether `a` and `b` differ by at most `max(abs_tol, rel_tol * max(|a|, |b|))`.
+/// Equal infinities match; NaN matches nothing.
+pub fn approx_equal<T: Scalar>(a: T, b: T, rel_tol: T, abs_tol: T) -> bool {
+    if a == b {
+        return true;
+    }
+    let difference = (a - b).abs();
+    difference <= abs_tol.max(rel_tol * a.abs().max(b.abs()))
+}
+
+/// Element-wise `approx_equal` over two flattened matrices of the same
+/// length. `abs_tol` covers entries near zero, where a relative bound alone
+/// would demand exact equality.
+pub fn approx_equal_matrix<T: Scalar>(a: &[T], b: &[T], rel_tol: T, abs_tol: T) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(&x, &y)| approx_equal(x, y, rel_tol, abs_tol))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_near_equal_matrices() {
+        let a = [1.0f64, -2.5, 1e6, 0.0];
+        let b = [1.0 + 1e-10, -2.5 - 1e-10, 1e6 + 1e-4, 1e-12];
+        assert!(approx_equal_matrix(&a, &b, 1e-9, 1e-10));
+        assert!(approx_equal_matrix(&[f32::INFINITY], &[f32::INFINITY], 0.0, 0.0));
+        assert!(approx_equal_matrix::<f32>(&[], &[], 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_rejects_diverging_matrices() {
+        let a = [1.0f64, 2.0, 3.0];
+        assert!(!approx_equal_matrix(&a, &[1.0, 2.0, 3.1], 1e-6, 1e-6));
+        assert!(!approx_equal_matrix(&a, &a[..2], 1.0, 1.0));
+        assert!(!approx_equal_matrix(&[f64::NAN], &[f64::NAN], 1.0, 1.0));
+        // Near zero only the absolute tolerance can accept a difference
+        assert!(!approx_equal_matrix(&[0.0f32], &[1e-7], 1e-3, 0.0));
+        assert!(approx_equal_matrix(&[0.0f32], &[1e-7], 1e-3, 1e-6));
+    }
+}
@@ -901,11 +901,11 @@ export class MultithreadingIntegration implements KatalystIntegration {
 
   async cleanup(): Promise<void> {
     if (this.manager) {
-      this.manager.cleanup();
+      await this.manager.cleanup();
     }
 
     if (this.nativeModule) {
-      this.nativeModule.shutdownMultithreading();
+      await this.nativeModule.shutdownMultithreading();
     }
 
     this.initialized = false;
@@ -1002,6 +1002,13 @@ declare module '@katalyst/multithreading' {
     activeTasks: number;
   }
 
+  export interface ShutdownSummary {
+    completedTasks: number;
+    abandonedTasks: number;
+    timedOut: boolean;
+    durationMs: number;
+  }
+
   export interface RayonConfig {
     numThreads?: number;
     threadName?: string;
@@ -1096,7 +1103,11 @@ declare module '@katalyst/multithreading' {
     getTokioRuntime(): TokioRuntime | null;
     isRayonInitialized(): boolean;
     isTokioInitialized(): boolean;
-    cleanup(): void;
+    isAccepting(): boolean;
+    pendingTasks(): number;
+    parallelMap(data: number[], operation: string): Promise<number[]>;
+    delay(durationMs: number): Promise<void>;
+    cleanup(timeoutMs?: number): Promise<ShutdownSummary>;
   }
 
   export function getMultithreadingInfo(): string;
@@ -1129,7 +1140,7 @@ declare module '@katalyst/multithreading' {
   export function getRayonGlobalThreadCount(): number;
   export function getTokioRuntimeMetrics(): string;
   export function getPerformanceMetrics(): PerformanceMetrics;
-  export function shutdownMultithreading(): string;
+  export function shutdownMultithreading(timeoutMs?: number): Promise<ShutdownSummary>;
 }
     `.trim();
   }
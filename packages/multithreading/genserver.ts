@@ -20,9 +20,10 @@ export interface GenServerCallbacks<State = any> {
   init(args?: any): Promise<State> | State;
 
   /**
-   * Handle synchronous calls
+   * Handle synchronous calls. `from` is the calling actor's id, or
+   * undefined when the caller isn't an actor.
    */
-  handleCall?(request: any, from: ActorId, state: State): Promise<CallResponse<State>> | CallResponse<State>;
+  handleCall?(request: any, from: ActorId | undefined, state: State): Promise<CallResponse<State>> | CallResponse<State>;
 
   /**
    * Handle asynchronous casts
@@ -67,6 +68,7 @@ export class GenServer<State = any> {
   private actorId?: ActorId;
   private state?: State;
   private callbacks: GenServerCallbacks<State>;
+  private stopping?: Promise<void>;
 
   constructor(callbacks: GenServerCallbacks<State>) {
     this.callbacks = callbacks;
@@ -77,17 +79,59 @@ export class GenServer<State = any> {
    * Start the GenServer
    */
   async start(args?: any, options?: { name?: string }): Promise<void> {
+    this.stopping = undefined;
+
     // Initialize state
     this.state = await Promise.resolve(this.callbacks.init(args));
     
-    // Start the native GenServer
-    const actorId = await this.nativeGenServer.startCounter(options?.name || 'genserver');
-    this.actorId = actorId;
-    
-    // Register with the actor system if named
-    if (options?.name) {
-      actorSystem.register(options.name, actorId);
+    // Start the native GenServer; state stays on the JS side, so the
+    // handler replies without `newState` and native state is left as-is
+    this.actorId = await this.nativeGenServer.start(
+      'null',
+      (err: Error | null, envelope: string) => {
+        if (err) throw err;
+        return this.dispatch(envelope);
+      },
+      options?.name
+    );
+  }
+
+  /**
+   * Route a native mailbox message to the matching callback
+   */
+  private async dispatch(envelope: string): Promise<string> {
+    const { type, request, from } = JSON.parse(envelope);
+
+    if (type === 'call' && this.callbacks.handleCall) {
+      const response = await Promise.resolve(
+        this.callbacks.handleCall(request, from ?? undefined, this.state!)
+      );
+      this.state = response.newState;
+      if ('stop' in response) {
+        await this.stop(response.stop);
+      }
+      return JSON.stringify('noReply' in response
+        ? { noReply: true }
+        : { reply: response.reply ?? null, stop: 'stop' in response ? response.stop : undefined });
     }
+
+    const callback = type === 'cast' ? this.callbacks.handleCast : this.callbacks.handleInfo;
+    if (callback) {
+      const response = await Promise.resolve(callback(request, this.state!));
+      this.state = response.newState;
+      if ('stop' in response) {
+        await this.stop(response.stop);
+      }
+    }
+    return JSON.stringify({ noReply: true });
+  }
+
+  /**
+   * This server's actor id once started, to pass as `from` when it calls
+   * another GenServer
+   */
+  get id(): ActorId | undefined {
+    return this.actorId;
   }
 
   /**
@@ -99,30 +143,17 @@ export class GenServer<State = any> {
   }
 
   /**
-   * Make a synchronous call to the GenServer
+   * Make a synchronous call to the GenServer.
+   * Rejects if no reply arrives within `timeout` ms. Pass the calling
+   * actor's id as `from` so `handleCall` knows who is asking.
    */
-  async call(request: any, timeout: number = 5000): Promise<any> {
+  async call(request: any, timeout: number = 5000, from?: ActorId): Promise<any> {
     if (!this.actorId) {
       throw new Error('GenServer not started');
     }
 
-    if (this.callbacks.handleCall) {
-      const response = await Promise.resolve(
-        this.callbacks.handleCall(request, 'caller', this.state!)
-      );
-
-      if ('reply' in response) {
-        this.state = response.newState;
-        return response.reply;
-      } else if ('stop' in response) {
-        await this.stop(response.stop);
-        return response.reply;
-      }
-    }
-
-    const payload = Buffer.from(JSON.stringify(request));
-    const result = await this.nativeGenServer.call(this.actorId, payload, timeout);
-    return JSON.parse(result.toString());
+    const result = await this.nativeGenServer.call(JSON.stringify(request), timeout, from);
+    return JSON.parse(result);
   }
 
   /**
@@ -133,26 +164,21 @@ export class GenServer<State = any> {
       throw new Error('GenServer not started');
     }
 
-    if (this.callbacks.handleCast) {
-      const response = await Promise.resolve(
-        this.callbacks.handleCast(request, this.state!)
-      );
-
-      if ('noReply' in response) {
-        this.state = response.newState;
-      } else if ('stop' in response) {
-        await this.stop(response.stop);
-      }
-    }
-
-    const payload = Buffer.from(JSON.stringify(request));
-    await this.nativeGenServer.cast(this.actorId, payload);
+    await this.nativeGenServer.cast(JSON.stringify(request));
   }
 
   /**
-   * Stop the GenServer
+   * Stop the GenServer. A `stop` returned from a callback and an explicit
+   * call share one shutdown, so `terminate` runs once.
    */
-  async stop(reason: string = 'normal'): Promise<void> {
+  stop(reason: string = 'normal'): Promise<void> {
+    if (!this.stopping) {
+      this.stopping = this.shutdown(reason);
+    }
+    return this.stopping;
+  }
+
+  private async shutdown(reason: string): Promise<void> {
     if (this.callbacks.terminate && this.state) {
       await Promise.resolve(this.callbacks.terminate(reason, this.state));
     }
@@ -15,6 +15,27 @@ export declare class BumpAllocator {
   chunkCapacity(): number
 }
 
+/**
+ * Concurrent cache with per-entry TTL.
+ *
+ * Expired entries are evicted lazily on read and periodically by a
+ * background sweeper thread, which exits once the cache is dropped.
+ */
+export declare class ConcurrentCache {
+  constructor(sweepIntervalMs?: number | undefined | null)
+  set(key: string, value: string, ttlMs: number): string | null
+  get(key: string): string | null
+  remove(key: string): string | null
+  /** Purge expired entries now, returning how many were removed */
+  sweep(): number
+  /** Number of stored entries, including expired ones not yet swept */
+  len(): number
+  isEmpty(): boolean
+  clear(): void
+  /** Fraction of `get` calls that found a live entry */
+  hitRate(): number
+}
+
 export declare class CrossbeamArrayQueue {
   constructor(capacity: number)
   push(item: string): boolean
@@ -37,10 +58,30 @@ export declare class CrossbeamAtomicCell {
 
 export declare class CrossbeamChannel {
   constructor(bounded?: number | undefined | null)
+  /**
+   * Send a JSON payload, returning `true` once queued. A full or closed
+   * channel is an error.
+   */
   send(message: string): boolean
+  /** Send a JSON payload, returning `false` instead of failing when the channel is full */
+  trySend(message: string): boolean
+  /**
+   * Wait for a payload, failing once the channel is closed and drained.
+   * `close` wakes a pending wait.
+   */
+  recv(): Promise<string>
+  /** Receive a payload if one is queued; `null` means empty, an error means closed */
+  tryRecv(): string | null
+  /** Wait up to `timeout_ms` for a payload; `null` means the wait timed out */
+  recvTimeout(timeoutMs: number): Promise<string | null>
   receive(): string | null
+  /** Close the sending side; queued payloads can still be drained */
+  close(): void
+  isClosed(): boolean
   isEmpty(): boolean
+  isFull(): boolean
   len(): number
+  capacity(): number | null
 }
 
 export declare class CrossbeamSegQueue {
@@ -136,6 +177,37 @@ export declare class FlumeSender {
   senderCount(): number
 }
 
+/**
+ * Flume channel consumed from Node with `for await`. `next` resolves with
+ * each value as it arrives and reports `done` once the sender is closed
+ * and everything already sent has been read.
+ */
+export declare class FlumeStream {
+  /** Unbounded unless `capacity` is given */
+  constructor(capacity?: number | undefined | null)
+  /**
+   * Producer handle; every handle shares one sender, so closing any of
+   * them ends the stream
+   */
+  sender(): FlumeStreamSender
+  next(): Promise<FlumeStreamResult>
+  len(): number
+  isEmpty(): boolean
+}
+
+export declare class FlumeStreamSender {
+  /**
+   * Send a value, resolving once there is room if the stream is bounded
+   * and full. Waiting never blocks the JS thread, so the producer can
+   * share it with a `for await` consumer.
+   */
+  send(message: string): Promise<void>
+  trySend(message: string): boolean
+  /** End the stream once the values already sent have been consumed */
+  close(): void
+  isClosed(): boolean
+}
+
 export declare class MemoryMappedFile {
   static openRead(path: string): MemoryMappedFile
   static openWrite(path: string, size: number): MemoryMappedFile
@@ -168,7 +240,27 @@ export declare class MultithreadingManager {
   getTokioRuntime(): boolean
   isRayonInitialized(): boolean
   isTokioInitialized(): boolean
-  cleanup(): void
+  /** False once shutdown has started, until a pool is initialized again */
+  isAccepting(): boolean
+  /** Tasks spawned through this manager that haven't finished */
+  pendingTasks(): number
+  /**
+   * Map `data` with `operation` ("square", "double" or "increment") on
+   * this manager's rayon pool, as a task `cleanup` waits for
+   */
+  parallelMap(data: Array<number>, operation: string): Promise<Array<number>>
+  /**
+   * Resolve after `duration_ms` on this manager's tokio runtime, as a
+   * task `cleanup` waits for and aborts once it times out
+   */
+  delay(durationMs: number): Promise<void>
+  /**
+   * Gracefully shut down: stop accepting work, wait up to `timeout_ms`
+   * (default 5000) for in-flight tasks, then release both pools. New
+   * work is refused right away; the promise resolves once the wait is
+   * over. Initializing a pool again reopens the manager.
+   */
+  cleanup(timeoutMs?: number | undefined | null): Promise<ShutdownSummary>
 }
 
 export declare class Mutex {
@@ -265,6 +357,13 @@ export declare class SimdMatrix {
   toVec(): Array<number>
 }
 
+/** Cancellation handle for a running `stress_test_concurrency` worker */
+export declare class StressTestHandle {
+  /** Stop the worker before its next task; in-flight tasks finish first */
+  cancel(): void
+  isCancelled(): boolean
+}
+
 export declare class ThreadId {
   static current(): ThreadId
   getId(): string
@@ -322,10 +421,49 @@ export declare class TokioTimer {
   constructor()
   elapsedMs(): number
   reset(): void
+  /**
+   * Invoke `callback` with the tick number every `period_ms`, replacing any
+   * interval already running on this timer
+   */
+  interval(periodMs: number, callback: ((err: Error | null, arg: number) => void)): void
+  /** Stop the running interval; no callbacks fire after this returns */
+  clear(): void
+  get isTicking(): boolean
 }
 
+/**
+ * Whether two `Float64Array`/`Float32Array` matrices (flattened in the same
+ * order) have the same length and every pair of entries is within
+ * `max(absTol, relTol * max(|a|, |b|))`. Compared in f64.
+ */
+export declare function approxEqualMatrix(a: Float64Array | Float32Array, b: Float64Array | Float32Array, relTol: number, absTol: number): boolean
+
 export declare function benchmarkParallelOperations(dataSize: number, operation: string): Promise<unknown>
 
+/**
+ * Active allocator and its memory usage. Byte counts are numbers rather
+ * than u32 so multi-gigabyte heaps don't overflow.
+ */
+export interface AllocatorInfo {
+  /** "mimalloc" or "system" */
+  name: string
+  /** Allocators compiled into this build */
+  available: Array<string>
+  /**
+   * Bytes currently allocated through the Rust global allocator; only
+   * counted in builds with the `allocation-counting` feature
+   */
+  allocatedBytes?: number
+  peakAllocatedBytes?: number
+  /** Address space mimalloc has reserved from the OS; absent for "system" */
+  reservedBytes?: number
+  /** Memory mimalloc has committed; absent for "system" */
+  committedBytes?: number
+  peakCommittedBytes?: number
+  /** mimalloc's version number, e.g. 30302 for 3.3.2 */
+  mimallocVersion?: number
+}
+
 export interface BenchmarkResult {
   operation: string
   dataSize: number
@@ -338,6 +476,8 @@ export declare function createBarrier(n: number): Barrier
 
 export declare function createBumpAllocator(): BumpAllocator
 
+export declare function createConcurrentCache(sweepIntervalMs?: number | undefined | null): ConcurrentCache
+
 export declare function createCrossbeamArrayQueue(capacity: number): CrossbeamArrayQueue
 
 export declare function createCrossbeamAtomicCell(initialValue: number): CrossbeamAtomicCell
@@ -358,6 +498,8 @@ export declare function createFlumeBounded(capacity: number): FlumeChannel
 
 export declare function createFlumeSelector(): FlumeSelector
 
+export declare function createFlumeStream(capacity?: number | undefined | null): FlumeStream
+
 export declare function createFlumeUnbounded(): FlumeChannel
 
 export declare function createMemoryPool(blockSize: number, initialBlocks: number): MemoryPool
@@ -392,8 +534,28 @@ export declare function createTokioRuntime(config?: TokioRuntimeConfig | undefin
 
 export declare function createTokioTimer(): TokioTimer
 
+/**
+ * Dot product of two `Float64Array`/`Float32Array` vectors of equal length.
+ * Mixed inputs are computed in f64.
+ */
+export declare function dotProduct(a: Float64Array | Float32Array, b: Float64Array | Float32Array): number
+
+/** Iterator result for `FlumeStream::next`, shaped like JS's `{ value, done }` */
+export interface FlumeStreamResult {
+  value?: string
+  done: boolean
+}
+
 export declare function getAllThreadNames(): Array<Array<string>>
 
+/**
+ * Which allocator the addon runs on, which ones it was built with, and how
+ * much memory it holds. Reserved and committed sizes come from mimalloc
+ * and are only reported when it is active; allocated sizes need the
+ * `allocation-counting` feature.
+ */
+export declare function getAllocatorInfo(): AllocatorInfo
+
 export declare function getMultithreadingInfo(): string
 
 export declare function getPerformanceMetrics(): PerformanceMetrics
@@ -406,15 +568,26 @@ export declare function getTokioRuntimeMetrics(): string
 
 export declare function initializeMultithreading(): string
 
+/** Euclidean (L2) norm of a `Float64Array`/`Float32Array` vector */
+export declare function l2Norm(v: Float64Array | Float32Array): number
+
 export declare function parallelChunkProcess(data: Array<number>, chunkSize: number, operation: string, pool?: RayonThreadPool | undefined | null): Promise<unknown>
 
 export declare function parallelFilter(data: Array<number>, operation: string, threshold?: number | undefined | null, pool?: RayonThreadPool | undefined | null): Promise<unknown>
 
 export declare function parallelMap(data: Array<number>, operation: string, pool?: RayonThreadPool | undefined | null): Promise<unknown>
 
+/**
+ * Map every element with a built-in op (identity, square, abs, log) and fold
+ * the results with another (sum, product, max, min), entirely on the rayon pool
+ */
+export declare function parallelMapReduce(data: Float64Array, mapOp: string, reduceOp: string, pool?: RayonThreadPool | undefined | null): Promise<number>
+
 export declare function parallelReduce(data: Array<number>, operation: string, initial?: number | undefined | null, pool?: RayonThreadPool | undefined | null): Promise<unknown>
 
-export declare function parallelSort(data: Array<number>, descending?: boolean | undefined | null, pool?: RayonThreadPool | undefined | null): Promise<unknown>
+export declare function parallelSort(data: Float64Array, descending?: boolean | undefined | null, pool?: RayonThreadPool | undefined | null): Promise<Float64Array>
+
+export declare function parallelSortBy(data: Float64Array, keyFn: ((err: Error | null, arg: Array<number>) => Array<number>), pool?: RayonThreadPool | undefined | null): Promise<Float64Array>
 
 export interface PerformanceMetrics {
   cpuCores: number
@@ -438,7 +611,24 @@ export interface SelectResult {
   message: string
 }
 
-export declare function shutdownMultithreading(): string
+/**
+ * Gracefully shut down every live `MultithreadingManager`, sharing one
+ * `timeout_ms` (default 5000) between them
+ */
+export declare function shutdownMultithreading(timeoutMs?: number | undefined | null): Promise<ShutdownSummary>
+
+/**
+ * Outcome of a graceful shutdown. Tasks are counted from the moment
+ * shutdown starts: those that finish before the timeout are completed,
+ * the rest are abandoned. Abandoned tokio tasks are aborted; abandoned
+ * rayon jobs can't be interrupted and finish detached from the pool.
+ */
+export interface ShutdownSummary {
+  completedTasks: number
+  abandonedTasks: number
+  timedOut: boolean
+  durationMs: number
+}
 
 export declare function simdDotProduct(a: Array<number>, b: Array<number>): number
 
@@ -448,13 +638,14 @@ export declare function simdVectorAdd(a: Array<number>, b: Array<number>): Array
 
 export declare function simdVectorScale(vec: Array<number>, scalar: number): Array<number>
 
-export declare function stressTestConcurrency(numTasks: number, taskDurationMs: number, callback: ((err: Error | null, arg: StressTestResult) => any)): void
+export declare function stressTestConcurrency(numTasks: number, taskDurationMs: number, callback: ((err: Error | null, arg: StressTestResult) => any)): StressTestHandle
 
 export interface StressTestResult {
   taskId: number
   durationMs: number
   threadId: string
   status: string
+  completedTasks: number
 }
 
 export interface SystemInfo {
@@ -395,6 +395,7 @@ if (!nativeBinding) {
 module.exports = nativeBinding
 module.exports.Barrier = nativeBinding.Barrier
 module.exports.BumpAllocator = nativeBinding.BumpAllocator
+module.exports.ConcurrentCache = nativeBinding.ConcurrentCache
 module.exports.CrossbeamArrayQueue = nativeBinding.CrossbeamArrayQueue
 module.exports.CrossbeamAtomicCell = nativeBinding.CrossbeamAtomicCell
 module.exports.CrossbeamChannel = nativeBinding.CrossbeamChannel
@@ -406,6 +407,8 @@ module.exports.FlumeChannel = nativeBinding.FlumeChannel
 module.exports.FlumeReceiver = nativeBinding.FlumeReceiver
 module.exports.FlumeSelector = nativeBinding.FlumeSelector
 module.exports.FlumeSender = nativeBinding.FlumeSender
+module.exports.FlumeStream = nativeBinding.FlumeStream
+module.exports.FlumeStreamSender = nativeBinding.FlumeStreamSender
 module.exports.MemoryMappedFile = nativeBinding.MemoryMappedFile
 module.exports.MemoryPool = nativeBinding.MemoryPool
 module.exports.MultithreadingManager = nativeBinding.MultithreadingManager
@@ -420,6 +423,7 @@ module.exports.SimdF32x4 = nativeBinding.SimdF32x4
 module.exports.SimdF32X8 = nativeBinding.SimdF32X8
 module.exports.SimdF32x8 = nativeBinding.SimdF32x8
 module.exports.SimdMatrix = nativeBinding.SimdMatrix
+module.exports.StressTestHandle = nativeBinding.StressTestHandle
 module.exports.ThreadId = nativeBinding.ThreadId
 module.exports.ThreadLocalCounter = nativeBinding.ThreadLocalCounter
 module.exports.ThreadLocalMap = nativeBinding.ThreadLocalMap
@@ -428,9 +432,11 @@ module.exports.TokioBroadcastChannel = nativeBinding.TokioBroadcastChannel
 module.exports.TokioMpscChannel = nativeBinding.TokioMpscChannel
 module.exports.TokioRuntime = nativeBinding.TokioRuntime
 module.exports.TokioTimer = nativeBinding.TokioTimer
+module.exports.approxEqualMatrix = nativeBinding.approxEqualMatrix
 module.exports.benchmarkParallelOperations = nativeBinding.benchmarkParallelOperations
 module.exports.createBarrier = nativeBinding.createBarrier
 module.exports.createBumpAllocator = nativeBinding.createBumpAllocator
+module.exports.createConcurrentCache = nativeBinding.createConcurrentCache
 module.exports.createCrossbeamArrayQueue = nativeBinding.createCrossbeamArrayQueue
 module.exports.createCrossbeamAtomicCell = nativeBinding.createCrossbeamAtomicCell
 module.exports.createCrossbeamChannel = nativeBinding.createCrossbeamChannel
@@ -441,6 +447,7 @@ module.exports.createDashset = nativeBinding.createDashset
 module.exports.createFairMutex = nativeBinding.createFairMutex
 module.exports.createFlumeBounded = nativeBinding.createFlumeBounded
 module.exports.createFlumeSelector = nativeBinding.createFlumeSelector
+module.exports.createFlumeStream = nativeBinding.createFlumeStream
 module.exports.createFlumeUnbounded = nativeBinding.createFlumeUnbounded
 module.exports.createMemoryPool = nativeBinding.createMemoryPool
 module.exports.createOnceCell = nativeBinding.createOnceCell
@@ -458,18 +465,23 @@ module.exports.createTokioBroadcastChannel = nativeBinding.createTokioBroadcastC
 module.exports.createTokioMpscChannel = nativeBinding.createTokioMpscChannel
 module.exports.createTokioRuntime = nativeBinding.createTokioRuntime
 module.exports.createTokioTimer = nativeBinding.createTokioTimer
+module.exports.dotProduct = nativeBinding.dotProduct
 module.exports.getAllThreadNames = nativeBinding.getAllThreadNames
+module.exports.getAllocatorInfo = nativeBinding.getAllocatorInfo
 module.exports.getMultithreadingInfo = nativeBinding.getMultithreadingInfo
 module.exports.getPerformanceMetrics = nativeBinding.getPerformanceMetrics
 module.exports.getRayonGlobalThreadCount = nativeBinding.getRayonGlobalThreadCount
 module.exports.getSystemInfo = nativeBinding.getSystemInfo
 module.exports.getTokioRuntimeMetrics = nativeBinding.getTokioRuntimeMetrics
 module.exports.initializeMultithreading = nativeBinding.initializeMultithreading
+module.exports.l2Norm = nativeBinding.l2Norm
 module.exports.parallelChunkProcess = nativeBinding.parallelChunkProcess
 module.exports.parallelFilter = nativeBinding.parallelFilter
 module.exports.parallelMap = nativeBinding.parallelMap
+module.exports.parallelMapReduce = nativeBinding.parallelMapReduce
 module.exports.parallelReduce = nativeBinding.parallelReduce
 module.exports.parallelSort = nativeBinding.parallelSort
+module.exports.parallelSortBy = nativeBinding.parallelSortBy
 module.exports.registerCurrentThread = nativeBinding.registerCurrentThread
 module.exports.shutdownMultithreading = nativeBinding.shutdownMultithreading
 module.exports.simdDotProduct = nativeBinding.simdDotProduct
@@ -1,5 +1,6 @@
 use async_channel::{bounded, unbounded, Receiver, Sender};
 use dashmap::DashMap;
+use futures::FutureExt;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 use parking_lot::RwLock;
@@ -7,9 +8,11 @@ use serde::{Deserialize, Serialize};
 use std::any::Any;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::panic::AssertUnwindSafe;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::watch;
 use tokio::task::JoinHandle;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
@@ -33,10 +36,17 @@ impl ActorId {
     }
 }
 
+impl std::fmt::Display for ActorId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 // Message types that actors can receive
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Message {
-    Call { id: u64, payload: Vec<u8> },
+    /// `from` is the calling actor, or `None` for callers outside the system
+    Call { id: u64, from: Option<ActorId>, payload: Vec<u8> },
     Cast { payload: Vec<u8> },
     Info { payload: Vec<u8> },
     Stop,
@@ -57,12 +67,99 @@ pub enum ActorState {
     Failed(String),
 }
 
+// Why an actor terminated (mirrors Elixir exit reasons)
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExitReason {
+    /// Stopped via `Message::Stop`
+    Normal,
+    /// Mailbox closed because every sender was dropped
+    Shutdown,
+    /// Handler panicked or returned an error
+    Error(String),
+}
+
+impl ExitReason {
+    pub fn is_abnormal(&self) -> bool {
+        matches!(self, ExitReason::Error(_))
+    }
+}
+
+// What `send` does when a bounded mailbox is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Wait until the actor frees a slot
+    Block,
+    /// Discard the message being sent
+    DropNewest,
+    /// Evict the oldest queued message to make room
+    DropOldest,
+    /// Reject the send with a `QueueFull` error
+    Fail,
+}
+
+impl OverflowPolicy {
+    pub fn parse(policy: &str) -> Result<Self> {
+        match policy {
+            "block" => Ok(OverflowPolicy::Block),
+            "drop_newest" => Ok(OverflowPolicy::DropNewest),
+            "drop_oldest" => Ok(OverflowPolicy::DropOldest),
+            "fail" => Ok(OverflowPolicy::Fail),
+            _ => Err(Error::new(
+                Status::InvalidArg,
+                format!("Unknown overflow policy '{}' (expected block, drop_newest, drop_oldest or fail)", policy),
+            )),
+        }
+    }
+}
+
+// Mailbox sizing; `capacity: None` is unbounded and never overflows
+#[derive(Debug, Clone, Copy)]
+pub struct MailboxConfig {
+    pub capacity: Option<usize>,
+    pub overflow: OverflowPolicy,
+}
+
+impl Default for MailboxConfig {
+    fn default() -> Self {
+        MailboxConfig {
+            capacity: None,
+            overflow: OverflowPolicy::Block,
+        }
+    }
+}
+
+// Result of a successful send under back-pressure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendOutcome {
+    /// Message queued
+    Delivered,
+    /// Mailbox full; the message was discarded (`drop_newest`)
+    Dropped,
+    /// Message queued after evicting the oldest one (`drop_oldest`)
+    DroppedOldest,
+}
+
+impl SendOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SendOutcome::Delivered => "delivered",
+            SendOutcome::Dropped => "dropped",
+            SendOutcome::DroppedOldest => "dropped_oldest",
+        }
+    }
+}
+
 // Actor behavior trait
 #[async_trait::async_trait]
 pub trait ActorBehavior: Send + Sync + 'static {
     async fn handle_message(&mut self, msg: Message) -> Option<Vec<u8>>;
     async fn on_start(&mut self) {}
     async fn on_stop(&mut self) {}
+
+    // Fallible variant of `handle_message`; an `Err` terminates the actor abnormally
+    async fn try_handle_message(&mut self, msg: Message) -> std::result::Result<Option<Vec<u8>>, String> {
+        Ok(self.handle_message(msg).await)
+    }
 }
 
 // Core actor structure
@@ -77,14 +174,25 @@ pub struct Actor {
     call_counter: Arc<AtomicU64>,
     pending_calls: Arc<DashMap<u64, Sender<Vec<u8>>>>,
     running: Arc<AtomicBool>,
+    overflow: OverflowPolicy,
+    exit_tx: watch::Sender<Option<ExitReason>>,
+    exit_rx: watch::Receiver<Option<ExitReason>>,
 }
 
 impl Actor {
     pub fn new(behavior: Box<dyn ActorBehavior>, bounded_size: Option<usize>) -> Self {
-        let (sender, receiver) = match bounded_size {
-            Some(size) => bounded(size),
+        Self::with_mailbox(behavior, MailboxConfig {
+            capacity: bounded_size,
+            ..MailboxConfig::default()
+        })
+    }
+
+    pub fn with_mailbox(behavior: Box<dyn ActorBehavior>, mailbox: MailboxConfig) -> Self {
+        let (sender, receiver) = match mailbox.capacity {
+            Some(size) => bounded(size.max(1)),
             None => unbounded(),
         };
+        let (exit_tx, exit_rx) = watch::channel(None);
 
         Actor {
             id: ActorId::new(),
@@ -97,6 +205,9 @@ impl Actor {
             call_counter: Arc::new(AtomicU64::new(0)),
             pending_calls: Arc::new(DashMap::new()),
             running: Arc::new(AtomicBool::new(false)),
+            overflow: mailbox.overflow,
+            exit_tx,
+            exit_rx,
         }
     }
 
@@ -108,7 +219,21 @@ impl Actor {
         self.sender.clone()
     }
 
-    pub async fn start(mut self) -> JoinHandle<()> {
+    // Handle used to message and monitor the actor once `start` consumes it
+    pub fn handle(&self) -> ActorHandle {
+        ActorHandle {
+            id: self.id.clone(),
+            state: self.state.clone(),
+            sender: self.sender.clone(),
+            mailbox: self.mailbox.clone(),
+            overflow: self.overflow,
+            call_counter: self.call_counter.clone(),
+            pending_calls: self.pending_calls.clone(),
+            exit: self.exit_rx.clone(),
+        }
+    }
+
+    pub fn start(mut self) -> JoinHandle<ExitReason> {
         let id = self.id.clone();
         let state = self.state.clone();
         let running = self.running.clone();
@@ -120,24 +245,38 @@ impl Actor {
             info!("Actor {:?} starting", id);
             self.behavior.on_start().await;
 
+            let mut reason = ExitReason::Shutdown;
             while running.load(Ordering::SeqCst) {
                 match self.mailbox.recv().await {
                     Ok(msg) => {
                         debug!("Actor {:?} received message: {:?}", id, msg);
-                        match msg {
+                        let call_id = match msg {
                             Message::Stop => {
+                                reason = ExitReason::Normal;
                                 running.store(false, Ordering::SeqCst);
                                 break;
                             }
-                            Message::Call { id: call_id, .. } => {
-                                if let Some(response) = self.behavior.handle_message(msg).await {
-                                    if let Some(sender) = self.pending_calls.get(&call_id) {
-                                        let _ = sender.send(response).await;
-                                    }
+                            Message::Call { id: call_id, .. } => Some(call_id),
+                            _ => None,
+                        };
+
+                        let handled = AssertUnwindSafe(self.behavior.try_handle_message(msg))
+                            .catch_unwind()
+                            .await
+                            .unwrap_or_else(|panic| Err(panic_message(panic)));
+
+                        match handled {
+                            Ok(Some(response)) => {
+                                if let Some(sender) = call_id.and_then(|call_id| self.pending_calls.get(&call_id).map(|s| s.clone())) {
+                                    let _ = sender.send(response).await;
                                 }
                             }
-                            _ => {
-                                self.behavior.handle_message(msg).await;
+                            Ok(None) => {}
+                            Err(error) => {
+                                error!("Actor {:?} failed: {}", id, error);
+                                reason = ExitReason::Error(error);
+                                running.store(false, Ordering::SeqCst);
+                                break;
                             }
                         }
                     }
@@ -148,51 +287,183 @@ impl Actor {
                 }
             }
 
+            // Handles keep the mailbox alive, so close it for them explicitly
+            self.mailbox.close();
+
             *state.write() = ActorState::Stopping;
             self.behavior.on_stop().await;
-            *state.write() = ActorState::Stopped;
-            info!("Actor {:?} stopped", id);
+            *state.write() = match &reason {
+                ExitReason::Error(error) => ActorState::Failed(error.clone()),
+                _ => ActorState::Stopped,
+            };
+            info!("Actor {:?} stopped: {:?}", id, reason);
+
+            let _ = self.exit_tx.send(Some(reason.clone()));
+            reason
         })
     }
+}
 
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        format!("panicked: {}", message)
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        format!("panicked: {}", message)
+    } else {
+        "panicked".to_string()
+    }
+}
+
+// Cloneable handle to a spawned actor
+#[derive(Clone)]
+pub struct ActorHandle {
+    id: ActorId,
+    state: Arc<RwLock<ActorState>>,
+    sender: Sender<Message>,
+    // Shared with the actor so `drop_oldest` can evict from the front
+    mailbox: Receiver<Message>,
+    overflow: OverflowPolicy,
+    call_counter: Arc<AtomicU64>,
+    pending_calls: Arc<DashMap<u64, Sender<Vec<u8>>>>,
+    exit: watch::Receiver<Option<ExitReason>>,
+}
+
+impl ActorHandle {
+    pub fn id(&self) -> ActorId {
+        self.id.clone()
+    }
+
+    pub fn sender(&self) -> Sender<Message> {
+        self.sender.clone()
+    }
+
+    pub fn state(&self) -> ActorState {
+        self.state.read().clone()
+    }
+
+    // Resolves once the actor has terminated, with the reason it exited
+    pub async fn exited(&self) -> ExitReason {
+        let mut exit = self.exit.clone();
+        loop {
+            let current = exit.borrow().clone();
+            if let Some(reason) = current {
+                return reason;
+            }
+            if exit.changed().await.is_err() {
+                return ExitReason::Error("actor task dropped".to_string());
+            }
+        }
+    }
+
+    pub fn mailbox_len(&self) -> usize {
+        self.sender.len()
+    }
+
+    pub fn mailbox_capacity(&self) -> Option<usize> {
+        self.sender.capacity()
+    }
+
+    // Queue a message, applying the mailbox overflow policy when it is full
+    pub async fn send(&self, msg: Message) -> Result<SendOutcome> {
+        if self.overflow == OverflowPolicy::Block {
+            return self.sender
+                .send(msg)
+                .await
+                .map(|_| SendOutcome::Delivered)
+                .map_err(|_| Error::new(Status::Closing, format!("Actor {} mailbox is closed", self.id)));
+        }
+
+        let mut msg = msg;
+        let mut outcome = SendOutcome::Delivered;
+        loop {
+            match self.sender.try_send(msg) {
+                Ok(()) => return Ok(outcome),
+                Err(async_channel::TrySendError::Closed(_)) => {
+                    return Err(Error::new(Status::Closing, format!("Actor {} mailbox is closed", self.id)));
+                }
+                Err(async_channel::TrySendError::Full(rejected)) => match self.overflow {
+                    OverflowPolicy::DropNewest => {
+                        self.discard(rejected);
+                        return Ok(SendOutcome::Dropped);
+                    }
+                    OverflowPolicy::Fail => {
+                        return Err(Error::new(Status::QueueFull, format!("Actor {} mailbox is full", self.id)));
+                    }
+                    _ => {
+                        if let Ok(oldest) = self.mailbox.try_recv() {
+                            self.discard(oldest);
+                            outcome = SendOutcome::DroppedOldest;
+                        }
+                        msg = rejected;
+                    }
+                },
+            }
+        }
+    }
+
+    // Account for a message that will never be handled
+    fn discard(&self, msg: Message) {
+        match msg {
+            // Fail the caller now instead of letting it wait for its timeout
+            Message::Call { id, .. } => {
+                self.pending_calls.remove(&id);
+            }
+            // Never lose a stop request; requeue it behind the new message
+            Message::Stop => {
+                let sender = self.sender.clone();
+                tokio::spawn(async move {
+                    let _ = sender.send(Message::Stop).await;
+                });
+            }
+            msg => debug!("Actor {:?} mailbox full, dropped {:?}", self.id, msg),
+        }
+    }
+
+    // Send a call tagged with a fresh ref and wait for the reply correlated to it
     pub async fn call(&self, payload: Vec<u8>, timeout: Duration) -> Result<Vec<u8>> {
+        self.call_from(None, payload, timeout).await
+    }
+
+    // `call` on behalf of the actor `from`, which the receiver sees as the caller
+    pub async fn call_from(&self, from: Option<ActorId>, payload: Vec<u8>, timeout: Duration) -> Result<Vec<u8>> {
         let call_id = self.call_counter.fetch_add(1, Ordering::SeqCst);
         let (response_tx, response_rx) = bounded(1);
         
         self.pending_calls.insert(call_id, response_tx);
         
-        self.sender
-            .send(Message::Call { id: call_id, payload })
-            .await
-            .map_err(|e| Error::from_reason(format!("Failed to send call: {}", e)))?;
+        let result = match self.send(Message::Call { id: call_id, from, payload }).await {
+            Ok(SendOutcome::Dropped) => Err(Error::new(
+                Status::QueueFull,
+                format!("Actor {} mailbox is full, call dropped", self.id),
+            )),
+            Ok(_) => match tokio::time::timeout(timeout, response_rx.recv()).await {
+                Ok(Ok(response)) => Ok(response),
+                Ok(Err(e)) => Err(Error::from_reason(format!("Call response error: {}", e))),
+                Err(_) => Err(Error::new(
+                    Status::Cancelled,
+                    format!("Call timed out after {}ms", timeout.as_millis()),
+                )),
+            },
+            Err(e) => Err(e),
+        };
 
-        match tokio::time::timeout(timeout, response_rx.recv()).await {
-            Ok(Ok(response)) => {
-                self.pending_calls.remove(&call_id);
-                Ok(response)
-            }
-            Ok(Err(e)) => {
-                self.pending_calls.remove(&call_id);
-                Err(Error::from_reason(format!("Call response error: {}", e)))
-            }
-            Err(_) => {
-                self.pending_calls.remove(&call_id);
-                Err(Error::from_reason("Call timeout"))
-            }
-        }
+        // A late reply finds no entry and is dropped
+        self.pending_calls.remove(&call_id);
+        result
     }
 
-    pub async fn cast(&self, payload: Vec<u8>) -> Result<()> {
-        self.sender
-            .send(Message::Cast { payload })
-            .await
-            .map_err(|e| Error::from_reason(format!("Failed to send cast: {}", e)))
+    pub fn pending_call_count(&self) -> usize {
+        self.pending_calls.len()
+    }
+
+    pub async fn cast(&self, payload: Vec<u8>) -> Result<SendOutcome> {
+        self.send(Message::Cast { payload }).await
     }
 }
 
 // Actor System manages all actors
 pub struct ActorSystem {
-    actors: Arc<DashMap<ActorId, Arc<Actor>>>,
+    actors: Arc<DashMap<ActorId, Arc<ActorHandle>>>,
     registry: Arc<DashMap<String, ActorId>>,
     supervisor_tree: Arc<RwLock<petgraph::Graph<ActorId, ()>>>,
 }
@@ -207,15 +478,23 @@ impl ActorSystem {
     }
 
     pub fn spawn(&self, behavior: Box<dyn ActorBehavior>) -> ActorId {
-        let actor = Actor::new(behavior, None);
-        let id = actor.id();
-        let actor = Arc::new(actor);
+        self.spawn_with_mailbox(behavior, MailboxConfig::default())
+    }
+
+    pub fn spawn_with_mailbox(&self, behavior: Box<dyn ActorBehavior>, mailbox: MailboxConfig) -> ActorId {
+        let actor = Actor::with_mailbox(behavior, mailbox);
+        let handle = Arc::new(actor.handle());
+        let id = handle.id();
         
-        self.actors.insert(id.clone(), actor.clone());
+        self.actors.insert(id.clone(), handle);
         
-        let actor_clone = Arc::clone(&actor);
+        // Drop the actor from the system once it exits, however it exits
+        let actors = Arc::clone(&self.actors);
+        let exited_id = id.clone();
+        let task = actor.start();
         tokio::spawn(async move {
-            let _ = actor_clone.clone().start().await;
+            let _ = task.await;
+            actors.remove(&exited_id);
         });
         
         id
@@ -233,18 +512,28 @@ impl ActorSystem {
         self.registry.get(name).map(|entry| entry.clone())
     }
 
-    pub fn get_actor(&self, id: &ActorId) -> Option<Arc<Actor>> {
+    pub fn get_actor(&self, id: &ActorId) -> Option<Arc<ActorHandle>> {
         self.actors.get(id).map(|entry| entry.clone())
     }
 
+    // Resolves with the actor's exit reason; `None` if it is not alive
+    pub fn monitor(&self, id: &ActorId) -> Option<impl std::future::Future<Output = ExitReason>> {
+        let handle = self.get_actor(id)?;
+        Some(async move { handle.exited().await })
+    }
+
     pub async fn call(&self, id: &ActorId, payload: Vec<u8>, timeout: Duration) -> Result<Vec<u8>> {
+        self.call_from(id, None, payload, timeout).await
+    }
+
+    pub async fn call_from(&self, id: &ActorId, from: Option<ActorId>, payload: Vec<u8>, timeout: Duration) -> Result<Vec<u8>> {
         self.get_actor(id)
             .ok_or_else(|| Error::from_reason("Actor not found"))?
-            .call(payload, timeout)
+            .call_from(from, payload, timeout)
             .await
     }
 
-    pub async fn cast(&self, id: &ActorId, payload: Vec<u8>) -> Result<()> {
+    pub async fn cast(&self, id: &ActorId, payload: Vec<u8>) -> Result<SendOutcome> {
         self.get_actor(id)
             .ok_or_else(|| Error::from_reason("Actor not found"))?
             .cast(payload)
@@ -263,11 +552,38 @@ impl ActorSystem {
         }
     }
 
+    pub fn mailbox_len(&self, id: &ActorId) -> Result<usize> {
+        self.get_actor(id)
+            .map(|actor| actor.mailbox_len())
+            .ok_or_else(|| Error::from_reason("Actor not found"))
+    }
+
     pub fn count(&self) -> usize {
         self.actors.len()
     }
 }
 
+// Mailbox options accepted by `JsActorSystem::spawn_actor`
+#[napi(object)]
+pub struct JsMailboxOptions {
+    /// Maximum queued messages; unbounded when omitted
+    pub capacity: Option<u32>,
+    /// "block" (default), "drop_newest", "drop_oldest" or "fail"
+    pub overflow: Option<String>,
+}
+
+impl JsMailboxOptions {
+    fn into_config(self) -> Result<MailboxConfig> {
+        Ok(MailboxConfig {
+            capacity: self.capacity.map(|capacity| capacity as usize),
+            overflow: match self.overflow {
+                Some(policy) => OverflowPolicy::parse(&policy)?,
+                None => OverflowPolicy::Block,
+            },
+        })
+    }
+}
+
 // NAPI bindings for JavaScript
 #[napi]
 pub struct JsActorSystem {
@@ -286,7 +602,7 @@ impl JsActorSystem {
     }
 
     #[napi]
-    pub fn spawn_actor(&self, behavior_type: String) -> Result<String> {
+    pub fn spawn_actor(&self, behavior_type: String, mailbox: Option<JsMailboxOptions>) -> Result<String> {
         // Create a simple echo actor for demonstration
         struct EchoActor;
         
@@ -304,7 +620,11 @@ impl JsActorSystem {
             }
         }
         
-        let actor_id = self.system.spawn(Box::new(EchoActor));
+        let mailbox = match mailbox {
+            Some(options) => options.into_config()?,
+            None => MailboxConfig::default(),
+        };
+        let actor_id = self.system.spawn_with_mailbox(Box::new(EchoActor), mailbox);
         Ok(actor_id.0)
     }
 
@@ -315,10 +635,18 @@ impl JsActorSystem {
         self.system.call(&id, message, timeout).await
     }
 
+    /// Returns "delivered", "dropped" or "dropped_oldest" per the mailbox policy
     #[napi]
-    pub async fn cast_actor(&self, actor_id: String, message: Vec<u8>) -> Result<()> {
+    pub async fn cast_actor(&self, actor_id: String, message: Vec<u8>) -> Result<String> {
         let id = ActorId::from_string(actor_id);
-        self.system.cast(&id, message).await
+        let outcome = self.system.cast(&id, message).await?;
+        Ok(outcome.as_str().to_string())
+    }
+
+    #[napi]
+    pub fn mailbox_len(&self, actor_id: String) -> Result<u32> {
+        let id = ActorId::from_string(actor_id);
+        self.system.mailbox_len(&id).map(|len| len as u32)
     }
 
     #[napi]
@@ -347,4 +675,119 @@ impl JsActorSystem {
 // Helper function to get the global actor system
 pub fn get_actor_system() -> Option<Arc<ActorSystem>> {
     ACTOR_SYSTEM.read().clone()
-}
\ No newline at end of file
+}
+
+// Global actor system, installing one if none has been created yet
+pub fn get_or_init_actor_system() -> Arc<ActorSystem> {
+    if let Some(system) = get_actor_system() {
+        return system;
+    }
+    ACTOR_SYSTEM
+        .write()
+        .get_or_insert_with(|| Arc::new(ActorSystem::new()))
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Handles one message every 200ms so its mailbox fills up
+    struct SlowActor;
+
+    #[async_trait::async_trait]
+    impl ActorBehavior for SlowActor {
+        async fn handle_message(&mut self, msg: Message) -> Option<Vec<u8>> {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            match msg {
+                Message::Call { payload, .. } => Some(payload),
+                _ => None,
+            }
+        }
+    }
+
+    // Spawn a slow actor and fill its mailbox while it is busy with `busy`
+    async fn saturated(system: &ActorSystem, overflow: OverflowPolicy) -> Arc<ActorHandle> {
+        let id = system.spawn_with_mailbox(Box::new(SlowActor), MailboxConfig {
+            capacity: Some(2),
+            overflow,
+        });
+        let actor = system.get_actor(&id).unwrap();
+
+        actor.cast(b"busy".to_vec()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        for _ in 0..2 {
+            assert_eq!(actor.cast(b"queued".to_vec()).await.unwrap(), SendOutcome::Delivered);
+        }
+        assert_eq!(actor.mailbox_len(), 2);
+        actor
+    }
+
+    #[tokio::test]
+    async fn test_block_waits_for_capacity() {
+        let system = ActorSystem::new();
+        let actor = saturated(&system, OverflowPolicy::Block).await;
+
+        let blocked = tokio::time::timeout(Duration::from_millis(50), actor.cast(b"late".to_vec())).await;
+        assert!(blocked.is_err(), "send should block while the mailbox is full");
+
+        let outcome = tokio::time::timeout(Duration::from_secs(1), actor.cast(b"late".to_vec())).await;
+        assert_eq!(outcome.unwrap().unwrap(), SendOutcome::Delivered);
+        assert_eq!(actor.mailbox_len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_drop_newest_discards_message() {
+        let system = ActorSystem::new();
+        let actor = saturated(&system, OverflowPolicy::DropNewest).await;
+
+        assert_eq!(actor.cast(b"late".to_vec()).await.unwrap(), SendOutcome::Dropped);
+        assert_eq!(actor.mailbox_len(), 2);
+
+        let err = actor.call(b"ping".to_vec(), Duration::from_secs(1)).await.unwrap_err();
+        assert_eq!(err.status, Status::QueueFull);
+        assert_eq!(actor.pending_call_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest_evicts_front() {
+        let system = ActorSystem::new();
+        let id = system.spawn_with_mailbox(Box::new(SlowActor), MailboxConfig {
+            capacity: Some(2),
+            overflow: OverflowPolicy::DropOldest,
+        });
+        let actor = system.get_actor(&id).unwrap();
+        actor.cast(b"busy".to_vec()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        // The queued call is evicted, so its caller fails instead of timing out
+        let evicted = {
+            let actor = actor.clone();
+            tokio::spawn(async move { actor.call(b"first".to_vec(), Duration::from_secs(5)).await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        actor.cast(b"second".to_vec()).await.unwrap();
+
+        assert_eq!(actor.cast(b"third".to_vec()).await.unwrap(), SendOutcome::DroppedOldest);
+        assert_eq!(actor.mailbox_len(), 2);
+        let started = std::time::Instant::now();
+        assert!(evicted.await.unwrap().is_err());
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_fail_rejects_send() {
+        let system = ActorSystem::new();
+        let actor = saturated(&system, OverflowPolicy::Fail).await;
+
+        let err = actor.cast(b"late".to_vec()).await.unwrap_err();
+        assert_eq!(err.status, Status::QueueFull);
+        assert_eq!(system.mailbox_len(&actor.id()).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_overflow_policy_parse() {
+        assert_eq!(OverflowPolicy::parse("drop_oldest").unwrap(), OverflowPolicy::DropOldest);
+        assert!(OverflowPolicy::parse("spill").is_err());
+    }
+}
@@ -0,0 +1,228 @@
+use napi_derive::napi;
+#[cfg(feature = "allocation-counting")]
+use std::alloc::{GlobalAlloc, Layout};
+#[cfg(feature = "allocation-counting")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Allocators this build can use, fixed by cargo features
+pub const AVAILABLE_ALLOCATORS: &[&str] = &[
+    "system",
+    #[cfg(feature = "mimalloc")]
+    "mimalloc",
+];
+
+#[cfg(feature = "mimalloc")]
+pub const ACTIVE_ALLOCATOR: &str = "mimalloc";
+#[cfg(not(feature = "mimalloc"))]
+pub const ACTIVE_ALLOCATOR: &str = "system";
+
+#[cfg(all(feature = "mimalloc", not(feature = "allocation-counting")))]
+#[global_allocator]
+static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
+
+#[cfg(all(feature = "allocation-counting", feature = "mimalloc"))]
+#[global_allocator]
+static GLOBAL: CountingAllocator<mimalloc::MiMalloc> = CountingAllocator::new(mimalloc::MiMalloc);
+#[cfg(all(feature = "allocation-counting", not(feature = "mimalloc")))]
+#[global_allocator]
+static GLOBAL: CountingAllocator<std::alloc::System> = CountingAllocator::new(std::alloc::System);
+
+/// Wraps the active allocator to count live and peak requested bytes, which
+/// neither the system allocator nor a release build of mimalloc reports.
+/// Every allocation touches two shared counters, so it is only installed
+/// with the opt-in `allocation-counting` feature.
+#[cfg(feature = "allocation-counting")]
+pub struct CountingAllocator<A> {
+    inner: A,
+    allocated: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+#[cfg(feature = "allocation-counting")]
+impl<A> CountingAllocator<A> {
+    pub const fn new(inner: A) -> Self {
+        CountingAllocator {
+            inner,
+            allocated: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn allocated(&self) -> usize {
+        self.allocated.load(Ordering::Relaxed)
+    }
+
+    pub fn peak(&self) -> usize {
+        self.peak.load(Ordering::Relaxed)
+    }
+
+    fn grow(&self, bytes: usize) {
+        let allocated = self.allocated.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        self.peak.fetch_max(allocated, Ordering::Relaxed);
+    }
+
+    fn shrink(&self, bytes: usize) {
+        self.allocated.fetch_sub(bytes, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "allocation-counting")]
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+        if !ptr.is_null() {
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        self.shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+        // Apply only the difference, so a growing realloc never counts the
+        // old and new blocks as live at once
+        if !new_ptr.is_null() {
+            match new_size.cmp(&layout.size()) {
+                std::cmp::Ordering::Greater => self.grow(new_size - layout.size()),
+                std::cmp::Ordering::Less => self.shrink(layout.size() - new_size),
+                std::cmp::Ordering::Equal => {}
+            }
+        }
+        new_ptr
+    }
+}
+
+/// Active allocator and its memory usage. Byte counts are numbers rather
+/// than u32 so multi-gigabyte heaps don't overflow.
+#[napi(object)]
+pub struct AllocatorInfo {
+    /// "mimalloc" or "system"
+    pub name: String,
+    /// Allocators compiled into this build
+    pub available: Vec<String>,
+    /// Bytes currently allocated through the Rust global allocator; only
+    /// counted in builds with the `allocation-counting` feature
+    pub allocated_bytes: Option<f64>,
+    pub peak_allocated_bytes: Option<f64>,
+    /// Address space mimalloc has reserved from the OS; absent for "system"
+    pub reserved_bytes: Option<f64>,
+    /// Memory mimalloc has committed; absent for "system"
+    pub committed_bytes: Option<f64>,
+    pub peak_committed_bytes: Option<f64>,
+    /// mimalloc's version number, e.g. 30302 for 3.3.2
+    pub mimalloc_version: Option<u32>,
+}
+
+/// Reserved, committed and peak committed bytes from mimalloc's JSON stats
+#[cfg(feature = "mimalloc")]
+fn mimalloc_stats() -> Option<(f64, f64, f64)> {
+    let json = unsafe {
+        let buffer = libmimalloc_sys::mi_stats_get_json(0, std::ptr::null_mut());
+        if buffer.is_null() {
+            return None;
+        }
+        let json = std::ffi::CStr::from_ptr(buffer).to_string_lossy().into_owned();
+        libmimalloc_sys::mi_free(buffer.cast());
+        json
+    };
+
+    let stats: serde_json::Value = serde_json::from_str(&json).ok()?;
+    Some((
+        stats["reserved"]["current"].as_f64()?,
+        stats["committed"]["current"].as_f64()?,
+        stats["committed"]["peak"].as_f64()?,
+    ))
+}
+
+/// Which allocator the addon runs on, which ones it was built with, and how
+/// much memory it holds. Reserved and committed sizes come from mimalloc
+/// and are only reported when it is active; allocated sizes need the
+/// `allocation-counting` feature.
+#[napi]
+pub fn get_allocator_info() -> AllocatorInfo {
+    #[cfg(feature = "mimalloc")]
+    let (stats, version) = (mimalloc_stats(), Some(unsafe { libmimalloc_sys::mi_version() } as u32));
+    #[cfg(not(feature = "mimalloc"))]
+    let (stats, version): (Option<(f64, f64, f64)>, Option<u32>) = (None, None);
+
+    #[cfg(feature = "allocation-counting")]
+    let (allocated, peak) = (Some(GLOBAL.allocated() as f64), Some(GLOBAL.peak() as f64));
+    #[cfg(not(feature = "allocation-counting"))]
+    let (allocated, peak) = (None, None);
+
+    AllocatorInfo {
+        name: ACTIVE_ALLOCATOR.to_string(),
+        available: AVAILABLE_ALLOCATORS.iter().map(|name| name.to_string()).collect(),
+        allocated_bytes: allocated,
+        peak_allocated_bytes: peak,
+        reserved_bytes: stats.map(|(reserved, _, _)| reserved),
+        committed_bytes: stats.map(|(_, committed, _)| committed),
+        peak_committed_bytes: stats.map(|(_, _, peak)| peak),
+        mimalloc_version: version,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocator_name_matches_compiled_feature() {
+        let info = get_allocator_info();
+        let expected = if cfg!(feature = "mimalloc") { "mimalloc" } else { "system" };
+        assert_eq!(info.name, expected);
+        assert!(info.available.iter().any(|name| name == expected));
+        assert!(info.available.iter().any(|name| name == "system"));
+
+        assert_eq!(info.reserved_bytes.is_some(), cfg!(feature = "mimalloc"));
+        assert_eq!(info.mimalloc_version.is_some(), cfg!(feature = "mimalloc"));
+    }
+
+    #[test]
+    fn test_memory_usage_matches_compiled_features() {
+        let buffer = vec![0u8; 8 << 20];
+        let info = get_allocator_info();
+        assert_eq!(info.allocated_bytes.is_some(), cfg!(feature = "allocation-counting"));
+        if let (Some(allocated), Some(peak)) = (info.allocated_bytes, info.peak_allocated_bytes) {
+            assert!(allocated >= buffer.len() as f64);
+            assert!(peak >= allocated);
+        }
+        if let (Some(reserved), Some(committed)) = (info.reserved_bytes, info.committed_bytes) {
+            assert!(reserved >= committed && committed > 0.0);
+        }
+        drop(buffer);
+    }
+
+    #[cfg(feature = "allocation-counting")]
+    #[test]
+    fn test_realloc_counts_only_the_size_difference() {
+        let counter = CountingAllocator::new(std::alloc::System);
+        let layout = Layout::from_size_align(1024, 8).unwrap();
+        unsafe {
+            let ptr = counter.alloc(layout);
+            let ptr = counter.realloc(ptr, layout, 4096);
+            assert_eq!(counter.allocated(), 4096);
+            assert_eq!(counter.peak(), 4096);
+
+            let grown = Layout::from_size_align(4096, 8).unwrap();
+            let ptr = counter.realloc(ptr, grown, 512);
+            assert_eq!(counter.allocated(), 512);
+            assert_eq!(counter.peak(), 4096);
+
+            counter.dealloc(ptr, Layout::from_size_align(512, 8).unwrap());
+        }
+        assert_eq!(counter.allocated(), 0);
+    }
+}
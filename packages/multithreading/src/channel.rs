@@ -17,11 +17,9 @@ use uuid::Uuid;
 use async_channel::{Receiver, Sender, unbounded};
 use serde::{Serialize, Deserialize};
 use napi::bindgen_prelude::*;
+use napi::threadsafe_function::ThreadsafeFunction;
 use napi_derive::napi;
 
-use crate::actor::{ActorId, ActorRef};
-use crate::registry::{ProcessRegistry, RegistryKey};
-
 /// Channel topic identifier
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Topic(pub String);
@@ -46,13 +44,16 @@ pub struct ChannelMessage {
 }
 
 /// Channel join parameters
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct JoinParams {
     /// Authentication token
+    #[serde(default)]
     pub auth_token: Option<String>,
     /// Client metadata
+    #[serde(default)]
     pub metadata: HashMap<String, String>,
     /// Requested permissions
+    #[serde(default)]
     pub permissions: HashSet<String>,
 }
 
@@ -69,6 +70,33 @@ pub struct ChannelReply {
     pub error: Option<String>,
 }
 
+impl ChannelReply {
+    /// Successful reply, the `{:ok, payload}` half of a Phoenix reply
+    pub fn ok(response: serde_json::Value) -> Self {
+        Self {
+            ref_id: Uuid::new_v4().to_string(),
+            status: ChannelStatus::Ok,
+            response,
+            error: None,
+        }
+    }
+
+    /// Failed reply, the `{:error, %{reason: reason}}` half of a Phoenix reply
+    pub fn error(status: ChannelStatus, reason: impl Into<String>) -> Self {
+        let reason = reason.into();
+        Self {
+            ref_id: Uuid::new_v4().to_string(),
+            status,
+            response: serde_json::json!({ "reason": reason }),
+            error: Some(reason),
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        matches!(self.status, ChannelStatus::Ok)
+    }
+}
+
 /// Channel status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ChannelStatus {
@@ -79,6 +107,18 @@ pub enum ChannelStatus {
     Forbidden,
 }
 
+impl ChannelStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChannelStatus::Ok => "ok",
+            ChannelStatus::Error => "error",
+            ChannelStatus::Timeout => "timeout",
+            ChannelStatus::Unauthorized => "unauthorized",
+            ChannelStatus::Forbidden => "forbidden",
+        }
+    }
+}
+
 /// Channel event types
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ChannelEvent {
@@ -109,8 +149,9 @@ pub struct ChannelClient {
     pub joined_at: u64,
     /// Last activity timestamp
     pub last_seen: u64,
-    /// Message sender
-    pub sender: Sender<ChannelMessage>,
+    /// Mailbox sender, only set once someone subscribes to the client's
+    /// messages so undelivered messages can't pile up
+    pub sender: Option<Sender<ChannelMessage>>,
     /// Authentication status
     pub authenticated: bool,
     /// Permissions
@@ -138,6 +179,20 @@ pub struct ChannelState {
 pub trait ChannelAuth: Send + Sync {
     /// Check if client can join topic
     fn can_join(&self, client_id: &str, topic: &Topic, params: &JoinParams) -> bool;
+
+    /// Authorize a join, returning the reply payload or a reject reason
+    fn authorize_join(
+        &self,
+        client_id: &str,
+        topic: &Topic,
+        params: &JoinParams,
+    ) -> std::result::Result<serde_json::Value, String> {
+        if self.can_join(client_id, topic, params) {
+            Ok(serde_json::Value::Null)
+        } else {
+            Err("unauthorized".to_string())
+        }
+    }
     
     /// Check if client can send to topic
     fn can_send(&self, client_id: &str, topic: &Topic, event: &str) -> bool;
@@ -182,7 +237,7 @@ pub struct ChannelSystem {
 }
 
 /// Channel system statistics
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ChannelSystemStats {
     pub total_clients: usize,
     pub total_channels: usize,
@@ -216,17 +271,26 @@ impl ChannelSystem {
 
     /// Join a client to a topic
     pub async fn join(&self, client_id: String, topic: Topic, params: JoinParams) -> Result<ChannelReply, String> {
-        // Check authorization
-        if !self.auth.can_join(&client_id, &topic, &params) {
-            return Ok(ChannelReply {
-                ref_id: Uuid::new_v4().to_string(),
-                status: ChannelStatus::Unauthorized,
-                response: serde_json::Value::Null,
-                error: Some("Unauthorized to join channel".to_string()),
-            });
-        }
+        let decision = self.auth.authorize_join(&client_id, &topic, &params);
+        self.join_with(client_id, topic, params, decision).await
+    }
+
+    /// Join a client using an authorization decision made by the caller,
+    /// e.g. a JavaScript callback. `Ok(payload)` admits the client and
+    /// replies with `payload` (or the topic and join time when null);
+    /// `Err(reason)` rejects the join without touching channel state.
+    pub async fn join_with(
+        &self,
+        client_id: String,
+        topic: Topic,
+        params: JoinParams,
+        decision: std::result::Result<serde_json::Value, String>,
+    ) -> Result<ChannelReply, String> {
+        let payload = match decision {
+            Ok(payload) => payload,
+            Err(reason) => return Ok(ChannelReply::error(ChannelStatus::Unauthorized, reason)),
+        };
 
-        let (sender, receiver) = unbounded();
         let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
 
         let client = ChannelClient {
@@ -236,12 +300,12 @@ impl ChannelSystem {
                 topics.insert(topic.clone());
                 topics
             },
-            metadata: params.metadata,
+            metadata: params.metadata.clone(),
             joined_at: now,
             last_seen: now,
-            sender,
+            sender: None,
             authenticated: params.auth_token.is_some(),
-            permissions: params.permissions,
+            permissions: params.permissions.clone(),
         };
 
         // Add client to channel
@@ -295,30 +359,39 @@ impl ChannelSystem {
             params,
         }).await;
 
-        Ok(ChannelReply {
-            ref_id: Uuid::new_v4().to_string(),
-            status: ChannelStatus::Ok,
-            response: serde_json::json!({
+        let response = if payload.is_null() {
+            serde_json::json!({
                 "topic": topic.0,
                 "joined_at": now
-            }),
-            error: None,
-        })
+            })
+        } else {
+            payload
+        };
+
+        Ok(ChannelReply::ok(response))
     }
 
-    /// Leave a topic
-    pub async fn leave(&self, client_id: &str, topic: &Topic) -> Result<(), String> {
+    /// Leave a topic, replying with an error if the client had not joined it
+    pub async fn leave(&self, client_id: &str, topic: &Topic) -> Result<ChannelReply, String> {
         // Remove client from channel
-        {
+        let removed = {
             let mut channels = self.channels.write().unwrap();
-            if let Some(channel_clients) = channels.get_mut(topic) {
-                channel_clients.remove(client_id);
-                
-                // Remove empty channels
-                if channel_clients.is_empty() {
-                    channels.remove(topic);
+            match channels.get_mut(topic) {
+                Some(channel_clients) => {
+                    let removed = channel_clients.remove(client_id).is_some();
+
+                    // Remove empty channels
+                    if channel_clients.is_empty() {
+                        channels.remove(topic);
+                    }
+                    removed
                 }
+                None => false,
             }
+        };
+
+        if !removed {
+            return Ok(ChannelReply::error(ChannelStatus::Error, "not_joined"));
         }
 
         // Update client topics mapping
@@ -364,10 +437,11 @@ impl ChannelSystem {
             topic: topic.clone(),
         }).await;
 
-        Ok(())
+        Ok(ChannelReply::ok(serde_json::json!({ "topic": topic.0 })))
     }
 
-    /// Broadcast message to all clients in a topic
+    /// Broadcast message to all subscribed clients in a topic, returning
+    /// how many received it
     pub async fn broadcast(&self, topic: &Topic, event: String, payload: serde_json::Value) -> Result<usize, String> {
         let message = ChannelMessage {
             id: Uuid::new_v4().to_string(),
@@ -379,19 +453,23 @@ impl ChannelSystem {
             metadata: HashMap::new(),
         };
 
-        let mut sent_count = 0;
-
-        // Send to all clients in the topic
-        {
+        // Collect recipients first so the lock isn't held across sends
+        let recipients: Vec<Sender<ChannelMessage>> = {
             let channels = self.channels.read().unwrap();
-            if let Some(channel_clients) = channels.get(topic) {
-                for (client_id, client) in channel_clients {
-                    if self.auth.can_receive(client_id, topic, &message.event) {
-                        if client.sender.send(message.clone()).await.is_ok() {
-                            sent_count += 1;
-                        }
-                    }
-                }
+            channels.get(topic)
+                .map(|channel_clients| {
+                    channel_clients.iter()
+                        .filter(|(client_id, _)| self.auth.can_receive(client_id, topic, &message.event))
+                        .filter_map(|(_, client)| client.sender.clone())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let mut sent_count = 0;
+        for sender in recipients {
+            if sender.send(message.clone()).await.is_ok() {
+                sent_count += 1;
             }
         }
 
@@ -427,19 +505,17 @@ impl ChannelSystem {
         }
 
         // Find and send to client
-        {
+        let sender = {
             let channels = self.channels.read().unwrap();
-            if let Some(channel_clients) = channels.get(topic) {
-                if let Some(client) = channel_clients.get(client_id) {
-                    client.sender.send(message.clone()).await
-                        .map_err(|_| "Failed to send message to client".to_string())?;
-                } else {
-                    return Err("Client not found in topic".to_string());
-                }
-            } else {
-                return Err("Topic not found".to_string());
-            }
-        }
+            let channel_clients = channels.get(topic)
+                .ok_or_else(|| "Topic not found".to_string())?;
+            let client = channel_clients.get(client_id)
+                .ok_or_else(|| "Client not found in topic".to_string())?;
+            client.sender.clone()
+                .ok_or_else(|| "Client has no subscriber".to_string())?
+        };
+        sender.send(message.clone()).await
+            .map_err(|_| "Failed to send message to client".to_string())?;
 
         // Update statistics
         {
@@ -464,6 +540,34 @@ impl ChannelSystem {
             .unwrap_or_default()
     }
 
+    /// Open a mailbox for messages delivered to a client on a topic.
+    /// Replaces any earlier subscription; dropping the receiver stops
+    /// delivery and the client no longer counts as a recipient.
+    pub fn subscribe(&self, client_id: &str, topic: &Topic) -> Result<Receiver<ChannelMessage>, String> {
+        let mut channels = self.channels.write().unwrap();
+        let client = channels.get_mut(topic)
+            .and_then(|clients| clients.get_mut(client_id))
+            .ok_or_else(|| "Client not found in topic".to_string())?;
+
+        let (sender, receiver) = unbounded();
+        client.sender = Some(sender);
+        Ok(receiver)
+    }
+
+    /// Number of clients present in a topic
+    pub fn presence_count(&self, topic: &Topic) -> usize {
+        let channels = self.channels.read().unwrap();
+        channels.get(topic).map(|clients| clients.len()).unwrap_or(0)
+    }
+
+    /// Client counts for every active topic
+    pub fn presence_counts(&self) -> HashMap<String, usize> {
+        let channels = self.channels.read().unwrap();
+        channels.iter()
+            .map(|(topic, clients)| (topic.0.clone(), clients.len()))
+            .collect()
+    }
+
     /// Get topics for a client
     pub fn get_client_topics(&self, client_id: &str) -> HashSet<Topic> {
         let client_topics = self.client_topics.read().unwrap();
@@ -515,6 +619,57 @@ impl ChannelSystem {
     }
 }
 
+/// Channel reply for JavaScript: `status` is "ok" or an error status,
+/// `response` is the JSON payload and `reason` is set on errors
+#[napi(object)]
+pub struct JsChannelReply {
+    pub status: String,
+    pub response: String,
+    pub reason: Option<String>,
+}
+
+impl From<ChannelReply> for JsChannelReply {
+    fn from(reply: ChannelReply) -> Self {
+        Self {
+            status: reply.status.as_str().to_string(),
+            response: reply.response.to_string(),
+            reason: reply.error,
+        }
+    }
+}
+
+/// Turn a JavaScript authorization result into a join decision. The callback
+/// resolves to `{"ok": payload}` to admit the client or `{"error": reason}`
+/// to reject it.
+fn parse_join_decision(raw: &str) -> Result<std::result::Result<serde_json::Value, String>> {
+    let value: serde_json::Value = serde_json::from_str(raw)
+        .map_err(|e| napi::Error::from_reason(format!("Invalid authorization result: {}", e)))?;
+
+    if let Some(reason) = value.get("error") {
+        let reason = match reason {
+            serde_json::Value::String(reason) => reason.clone(),
+            other => other.to_string(),
+        };
+        return Ok(Err(reason));
+    }
+
+    match value.get("ok") {
+        Some(payload) => Ok(Ok(payload.clone())),
+        None => Err(napi::Error::new(
+            Status::InvalidArg,
+            "Authorization result must be {\"ok\": payload} or {\"error\": reason}".to_string(),
+        )),
+    }
+}
+
+fn parse_join_params(params: Option<String>) -> Result<JoinParams> {
+    match params {
+        Some(raw) => serde_json::from_str(&raw)
+            .map_err(|e| napi::Error::from_reason(format!("Invalid join params: {}", e))),
+        None => Ok(JoinParams::default()),
+    }
+}
+
 // NAPI JavaScript bindings
 #[napi]
 pub struct JsChannelSystem {
@@ -532,7 +687,7 @@ impl JsChannelSystem {
 
     /// Join a topic
     #[napi]
-    pub async fn join(&self, client_id: String, topic: String, auth_token: Option<String>) -> Result<Object> {
+    pub async fn join(&self, client_id: String, topic: String, auth_token: Option<String>) -> Result<JsChannelReply> {
         let params = JoinParams {
             auth_token,
             metadata: HashMap::new(),
@@ -542,28 +697,16 @@ impl JsChannelSystem {
         let reply = self.inner.join(client_id, Topic(topic), params).await
             .map_err(|e| napi::Error::from_reason(e))?;
 
-        // Convert to JavaScript object
-        let mut obj = Object::new();
-        obj.set("status", match reply.status {
-            ChannelStatus::Ok => "ok",
-            ChannelStatus::Error => "error",
-            ChannelStatus::Timeout => "timeout",
-            ChannelStatus::Unauthorized => "unauthorized",
-            ChannelStatus::Forbidden => "forbidden",
-        })?;
-        obj.set("response", reply.response.to_string())?;
-        if let Some(error) = reply.error {
-            obj.set("error", error)?;
-        }
-
-        Ok(obj)
+        Ok(reply.into())
     }
 
     /// Leave a topic
     #[napi]
-    pub async fn leave(&self, client_id: String, topic: String) -> Result<()> {
-        self.inner.leave(&client_id, &Topic(topic)).await
-            .map_err(|e| napi::Error::from_reason(e))
+    pub async fn leave(&self, client_id: String, topic: String) -> Result<JsChannelReply> {
+        let reply = self.inner.leave(&client_id, &Topic(topic)).await
+            .map_err(|e| napi::Error::from_reason(e))?;
+
+        Ok(reply.into())
     }
 
     /// Broadcast to topic
@@ -588,6 +731,15 @@ impl JsChannelSystem {
             .map_err(|e| napi::Error::from_reason(e))
     }
 
+    /// Subscribe to the messages a client receives on a topic
+    #[napi]
+    pub fn subscribe(&self, client_id: String, topic: String) -> Result<JsChannelSubscription> {
+        let receiver = self.inner.subscribe(&client_id, &Topic(topic))
+            .map_err(|e| napi::Error::from_reason(e))?;
+
+        Ok(JsChannelSubscription { receiver })
+    }
+
     /// Get clients in topic
     #[napi]
     pub fn get_clients(&self, topic: String) -> Vec<String> {
@@ -612,6 +764,15 @@ impl JsChannelSystem {
             .collect()
     }
 
+    /// Number of clients present in each topic
+    #[napi]
+    pub fn presence_counts(&self) -> HashMap<String, u32> {
+        self.inner.presence_counts()
+            .into_iter()
+            .map(|(topic, count)| (topic, count as u32))
+            .collect()
+    }
+
     /// Get statistics
     #[napi]
     pub fn get_stats(&self) -> Object {
@@ -629,6 +790,53 @@ impl JsChannelSystem {
     }
 }
 
+/// Messages delivered to one client on one topic, as JSON strings
+#[napi]
+pub struct JsChannelSubscription {
+    receiver: Receiver<ChannelMessage>,
+}
+
+#[napi]
+impl JsChannelSubscription {
+    /// Wait for the next message; null once the client leaves or the
+    /// subscription is closed
+    #[napi]
+    pub async fn next(&self) -> Result<Option<String>> {
+        match self.receiver.recv().await {
+            Ok(message) => serde_json::to_string(&message)
+                .map(Some)
+                .map_err(|e| napi::Error::from_reason(e.to_string())),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Take every message waiting in the mailbox without blocking
+    #[napi]
+    pub fn drain(&self) -> Result<Vec<String>> {
+        std::iter::from_fn(|| self.receiver.try_recv().ok())
+            .map(|message| serde_json::to_string(&message)
+                .map_err(|e| napi::Error::from_reason(e.to_string())))
+            .collect()
+    }
+
+    /// Number of messages waiting in the mailbox
+    #[napi]
+    pub fn len(&self) -> u32 {
+        self.receiver.len() as u32
+    }
+
+    #[napi]
+    pub fn is_empty(&self) -> bool {
+        self.receiver.is_empty()
+    }
+
+    /// Stop receiving; the client no longer counts as a recipient
+    #[napi]
+    pub fn close(&self) -> bool {
+        self.receiver.close()
+    }
+}
+
 /// Global channel system
 static GLOBAL_CHANNELS: std::sync::OnceLock<Arc<ChannelSystem>> = std::sync::OnceLock::new();
 
@@ -637,28 +845,47 @@ pub fn global_channel_system() -> &'static Arc<ChannelSystem> {
 }
 
 /// Convenience functions for global channel system
+///
+/// `params` is a JSON join params object. When `authorize` is given it is
+/// called with `{"clientId", "topic", "params"}` and must resolve to
+/// `{"ok": payload}` or `{"error": reason}`.
 #[napi]
-pub async fn join_channel(client_id: String, topic: String) -> Result<Object> {
+pub async fn join_channel(
+    client_id: String,
+    topic: String,
+    params: Option<String>,
+    authorize: Option<ThreadsafeFunction<String, Promise<String>>>,
+) -> Result<JsChannelReply> {
     let system = global_channel_system();
-    let params = JoinParams {
-        auth_token: None,
-        metadata: HashMap::new(),
-        permissions: HashSet::new(),
+    let params = parse_join_params(params)?;
+    let topic = Topic(topic);
+
+    let decision = match authorize {
+        Some(authorize) => {
+            let request = serde_json::json!({
+                "clientId": client_id,
+                "topic": topic.0,
+                "params": params,
+            });
+            let raw = authorize.call_async(Ok(request.to_string())).await?.await?;
+            parse_join_decision(&raw)?
+        }
+        None => system.auth.authorize_join(&client_id, &topic, &params),
     };
 
-    let reply = system.join(client_id, Topic(topic), params).await
+    let reply = system.join_with(client_id, topic, params, decision).await
         .map_err(|e| napi::Error::from_reason(e))?;
 
-    let mut obj = Object::new();
-    obj.set("status", "ok")?;
-    Ok(obj)
+    Ok(reply.into())
 }
 
 #[napi]
-pub async fn leave_channel(client_id: String, topic: String) -> Result<()> {
+pub async fn leave_channel(client_id: String, topic: String) -> Result<JsChannelReply> {
     let system = global_channel_system();
-    system.leave(&client_id, &Topic(topic)).await
-        .map_err(|e| napi::Error::from_reason(e))
+    let reply = system.leave(&client_id, &Topic(topic)).await
+        .map_err(|e| napi::Error::from_reason(e))?;
+
+    Ok(reply.into())
 }
 
 #[napi]
@@ -673,6 +900,23 @@ pub async fn broadcast_to_channel(topic: String, event: String, payload: String)
     Ok(count as u32)
 }
 
+#[napi]
+pub fn subscribe_channel(client_id: String, topic: String) -> Result<JsChannelSubscription> {
+    let receiver = global_channel_system().subscribe(&client_id, &Topic(topic))
+        .map_err(|e| napi::Error::from_reason(e))?;
+
+    Ok(JsChannelSubscription { receiver })
+}
+
+#[napi]
+pub fn channel_presence_counts() -> HashMap<String, u32> {
+    global_channel_system()
+        .presence_counts()
+        .into_iter()
+        .map(|(topic, count)| (topic, count as u32))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -719,7 +963,8 @@ mod tests {
         };
 
         // Join client
-        system.join(client_id, topic.clone(), params).await.unwrap();
+        system.join(client_id.clone(), topic.clone(), params).await.unwrap();
+        let _inbox = system.subscribe(&client_id, &topic).unwrap();
 
         // Broadcast message
         let count = system.broadcast(
@@ -730,4 +975,138 @@ mod tests {
 
         assert_eq!(count, 1);
     }
-}
\ No newline at end of file
+
+    struct TokenAuth;
+
+    impl ChannelAuth for TokenAuth {
+        fn can_join(&self, _client_id: &str, _topic: &Topic, params: &JoinParams) -> bool {
+            params.auth_token.is_some()
+        }
+
+        fn authorize_join(
+            &self,
+            client_id: &str,
+            _topic: &Topic,
+            params: &JoinParams,
+        ) -> std::result::Result<serde_json::Value, String> {
+            match params.auth_token.as_deref() {
+                Some("secret") => Ok(serde_json::json!({ "user": client_id })),
+                Some(_) => Err("invalid_token".to_string()),
+                None => Err("unauthorized".to_string()),
+            }
+        }
+
+        fn can_send(&self, _client_id: &str, _topic: &Topic, _event: &str) -> bool {
+            true
+        }
+
+        fn can_receive(&self, _client_id: &str, _topic: &Topic, _event: &str) -> bool {
+            true
+        }
+    }
+
+    fn token_params(token: Option<&str>) -> JoinParams {
+        JoinParams {
+            auth_token: token.map(str::to_string),
+            ..JoinParams::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authorized_join_replies_with_payload() {
+        let system = ChannelSystem::new().with_auth(TokenAuth);
+        let topic = Topic("room:lobby".to_string());
+
+        let reply = system.join("alice".to_string(), topic.clone(), token_params(Some("secret"))).await.unwrap();
+        assert!(reply.is_ok());
+        assert_eq!(reply.response, serde_json::json!({ "user": "alice" }));
+        assert_eq!(system.presence_count(&topic), 1);
+
+        let reply = system.leave("alice", &topic).await.unwrap();
+        assert!(reply.is_ok());
+        assert_eq!(system.presence_count(&topic), 0);
+
+        // Leaving again is an error reply, not a panic or silent success
+        let reply = system.leave("alice", &topic).await.unwrap();
+        assert!(matches!(reply.status, ChannelStatus::Error));
+        assert_eq!(reply.error.as_deref(), Some("not_joined"));
+    }
+
+    #[tokio::test]
+    async fn test_rejected_join_leaves_channel_untouched() {
+        let system = ChannelSystem::new().with_auth(TokenAuth);
+        let topic = Topic("room:lobby".to_string());
+
+        let reply = system.join("mallory".to_string(), topic.clone(), token_params(Some("guess"))).await.unwrap();
+        assert!(matches!(reply.status, ChannelStatus::Unauthorized));
+        assert_eq!(reply.error.as_deref(), Some("invalid_token"));
+        assert_eq!(reply.response, serde_json::json!({ "reason": "invalid_token" }));
+
+        let reply = system.join("eve".to_string(), topic.clone(), token_params(None)).await.unwrap();
+        assert_eq!(reply.error.as_deref(), Some("unauthorized"));
+
+        assert_eq!(system.presence_count(&topic), 0);
+        assert!(system.list_topics().is_empty());
+        assert_eq!(system.get_stats().joins_total, 0);
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_reports_recipients_and_presence_counts() {
+        let system = ChannelSystem::new();
+        let lobby = Topic("room:lobby".to_string());
+        let other = Topic("room:other".to_string());
+
+        for client in ["a", "b", "c"] {
+            system.join(client.to_string(), lobby.clone(), JoinParams::default()).await.unwrap();
+        }
+        system.join("d".to_string(), other.clone(), JoinParams::default()).await.unwrap();
+        let inboxes: Vec<_> = ["a", "b", "c"].iter()
+            .map(|client| system.subscribe(client, &lobby).unwrap())
+            .collect();
+
+        let count = system.broadcast(&lobby, "shout".to_string(), serde_json::json!({"n": 1})).await.unwrap();
+        assert_eq!(count, 3);
+
+        let message = inboxes[1].try_recv().unwrap();
+        assert_eq!(message.event, "shout");
+
+        let counts = system.presence_counts();
+        assert_eq!(counts.get("room:lobby"), Some(&3));
+        assert_eq!(counts.get("room:other"), Some(&1));
+
+        let empty = system.broadcast(&Topic("room:empty".to_string()), "shout".to_string(), serde_json::Value::Null).await.unwrap();
+        assert_eq!(empty, 0);
+    }
+
+    #[tokio::test]
+    async fn test_only_subscribed_clients_receive_and_count() {
+        let system = ChannelSystem::new();
+        let lobby = Topic("room:lobby".to_string());
+
+        for client in ["a", "b", "c"] {
+            system.join(client.to_string(), lobby.clone(), JoinParams::default()).await.unwrap();
+        }
+        let a = system.subscribe("a", &lobby).unwrap();
+        let b = system.subscribe("b", &lobby).unwrap();
+
+        for n in 0..3 {
+            let count = system.broadcast(&lobby, "tick".to_string(), serde_json::json!({"n": n})).await.unwrap();
+            assert_eq!(count, 2);
+        }
+        assert_eq!(a.len(), 3);
+        assert_eq!(b.len(), 3);
+        assert_eq!(system.get_stats().messages_sent, 6);
+        assert!(system.push("c", &lobby, "tick".to_string(), serde_json::Value::Null).await.is_err());
+
+        // Draining empties the mailbox; dropping it stops delivery
+        let received: Vec<_> = std::iter::from_fn(|| a.try_recv().ok()).collect();
+        assert_eq!(received.len(), 3);
+        assert!(a.is_empty());
+        drop(b);
+
+        let count = system.broadcast(&lobby, "tick".to_string(), serde_json::json!({"n": 3})).await.unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(a.len(), 1);
+        assert_eq!(system.get_stats().messages_sent, 7);
+    }
+}
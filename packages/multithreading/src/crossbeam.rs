@@ -1,17 +1,24 @@
 use napi::bindgen_prelude::*;
 use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi_derive::napi;
-use crossbeam::channel::{self, Receiver, Sender};
+use crossbeam::channel::{self, Receiver, RecvTimeoutError, Sender, TryRecvError, TrySendError};
 use crossbeam::atomic::AtomicCell;
 use crossbeam::queue::{ArrayQueue, SegQueue};
+use parking_lot::RwLock;
 use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
+/// Crossbeam channel carrying JSON-serializable payloads.
+///
+/// Nothing here blocks the Node event loop: a full bounded channel surfaces
+/// as an error from `send` (or `false` from `try_send`), and the waiting
+/// receives resolve from a blocking thread. Receivers distinguish an empty
+/// channel (`null`) from a closed one (error).
 #[napi]
 pub struct CrossbeamChannel {
-    sender: Arc<Sender<String>>,
-    receiver: Arc<Receiver<String>>,
+    sender: Arc<RwLock<Option<Sender<serde_json::Value>>>>,
+    receiver: Arc<Receiver<serde_json::Value>>,
 }
 
 #[napi]
@@ -24,37 +31,124 @@ impl CrossbeamChannel {
         };
 
         CrossbeamChannel {
-            sender: Arc::new(sender),
+            sender: Arc::new(RwLock::new(Some(sender))),
             receiver: Arc::new(receiver),
         }
     }
 
+    /// Send a JSON payload, returning `true` once queued. A full or closed
+    /// channel is an error.
     #[napi]
     pub fn send(&self, message: String) -> Result<bool> {
-        match self.sender.try_send(message) {
-            Ok(_) => Ok(true),
-            Err(_) => Ok(false),
+        let value = parse_payload(&message)?;
+        let guard = self.sender.read();
+        let sender = guard.as_ref().ok_or_else(closed_error)?;
+
+        sender.try_send(value).map(|()| true).map_err(|e| match e {
+            TrySendError::Full(_) => Error::new(Status::QueueFull, "Channel is full".to_string()),
+            TrySendError::Disconnected(_) => closed_error(),
+        })
+    }
+
+    /// Send a JSON payload, returning `false` instead of failing when the channel is full
+    #[napi]
+    pub fn try_send(&self, message: String) -> Result<bool> {
+        let value = parse_payload(&message)?;
+        let guard = self.sender.read();
+        let sender = guard.as_ref().ok_or_else(closed_error)?;
+
+        match sender.try_send(value) {
+            Ok(()) => Ok(true),
+            Err(TrySendError::Full(_)) => Ok(false),
+            Err(TrySendError::Disconnected(_)) => Err(closed_error()),
+        }
+    }
+
+    /// Wait for a payload, failing once the channel is closed and drained.
+    /// `close` wakes a pending wait.
+    #[napi]
+    pub async fn recv(&self) -> Result<String> {
+        let receiver = Arc::clone(&self.receiver);
+        let value = blocking(move || receiver.recv()).await?.map_err(|_| closed_error())?;
+        Ok(value.to_string())
+    }
+
+    /// Receive a payload if one is queued; `null` means empty, an error means closed
+    #[napi]
+    pub fn try_recv(&self) -> Result<Option<String>> {
+        match self.receiver.try_recv() {
+            Ok(value) => Ok(Some(value.to_string())),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(closed_error()),
+        }
+    }
+
+    /// Wait up to `timeout_ms` for a payload; `null` means the wait timed out
+    #[napi]
+    pub async fn recv_timeout(&self, timeout_ms: u32) -> Result<Option<String>> {
+        let receiver = Arc::clone(&self.receiver);
+        let timeout = Duration::from_millis(timeout_ms as u64);
+        match blocking(move || receiver.recv_timeout(timeout)).await? {
+            Ok(value) => Ok(Some(value.to_string())),
+            Err(RecvTimeoutError::Timeout) => Ok(None),
+            Err(RecvTimeoutError::Disconnected) => Err(closed_error()),
         }
     }
 
     #[napi]
     pub fn receive(&self) -> Result<Option<String>> {
         match self.receiver.try_recv() {
-            Ok(message) => Ok(Some(message)),
+            Ok(value) => Ok(Some(value.to_string())),
             Err(_) => Ok(None),
         }
     }
 
+    /// Close the sending side; queued payloads can still be drained
+    #[napi]
+    pub fn close(&self) {
+        self.sender.write().take();
+    }
+
+    #[napi]
+    pub fn is_closed(&self) -> bool {
+        self.sender.read().is_none()
+    }
 
     #[napi]
     pub fn is_empty(&self) -> bool {
         self.receiver.is_empty()
     }
 
+    #[napi]
+    pub fn is_full(&self) -> bool {
+        self.receiver.is_full()
+    }
+
     #[napi]
     pub fn len(&self) -> u32 {
         self.receiver.len() as u32
     }
+
+    #[napi]
+    pub fn capacity(&self) -> Option<u32> {
+        self.receiver.capacity().map(|c| c as u32)
+    }
+}
+
+fn parse_payload(message: &str) -> Result<serde_json::Value> {
+    serde_json::from_str(message)
+        .map_err(|e| Error::new(Status::InvalidArg, format!("Invalid JSON payload: {}", e)))
+}
+
+/// Run a blocking receive off the JS thread
+async fn blocking<T: Send + 'static>(receive: impl FnOnce() -> T + Send + 'static) -> Result<T> {
+    tokio::task::spawn_blocking(receive)
+        .await
+        .map_err(|e| Error::from_reason(format!("Receive task failed: {}", e)))
+}
+
+fn closed_error() -> Error {
+    Error::new(Status::Closing, "Channel is closed".to_string())
 }
 
 #[napi]
@@ -188,3 +282,71 @@ impl CrossbeamSegQueue {
         self.queue.len() as u32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel_json_roundtrip() {
+        let channel = CrossbeamChannel::new(None);
+        channel.send(r#"{"id":1,"tags":["a","b"]}"#.to_string()).unwrap();
+
+        let received = channel.try_recv().unwrap().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&received).unwrap();
+        assert_eq!(value["id"], 1);
+        assert_eq!(value["tags"][1], "b");
+
+        assert!(channel.send("not json".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_channel_bounded_backpressure() {
+        let channel = CrossbeamChannel::new(Some(1));
+        channel.send("1".to_string()).unwrap();
+
+        assert!(!channel.try_send("2".to_string()).unwrap());
+        let err = channel.send("2".to_string()).unwrap_err();
+        assert_eq!(err.status, Status::QueueFull);
+    }
+
+    #[tokio::test]
+    async fn test_channel_recv_timeout() {
+        let channel = CrossbeamChannel::new(None);
+        let start = std::time::Instant::now();
+
+        assert!(channel.recv_timeout(20).await.unwrap().is_none());
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert!(channel.try_recv().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_channel_disconnected() {
+        let channel = CrossbeamChannel::new(None);
+        assert!(channel.send("\"queued\"".to_string()).unwrap());
+        channel.close();
+
+        assert!(channel.is_closed());
+        assert_eq!(channel.send("1".to_string()).unwrap_err().status, Status::Closing);
+
+        // Queued payloads are still delivered before the close is observed
+        assert_eq!(channel.recv().await.unwrap(), "\"queued\"");
+        assert_eq!(channel.try_recv().unwrap_err().status, Status::Closing);
+        assert_eq!(channel.recv_timeout(10).await.unwrap_err().status, Status::Closing);
+        assert!(channel.recv().await.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_close_wakes_pending_recv() {
+        let channel = Arc::new(CrossbeamChannel::new(None));
+        let waiting = Arc::clone(&channel);
+        let pending = tokio::spawn(async move { waiting.recv().await });
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!pending.is_finished());
+        channel.close();
+
+        let result = tokio::time::timeout(Duration::from_secs(1), pending).await.unwrap().unwrap();
+        assert_eq!(result.unwrap_err().status, Status::Closing);
+    }
+}
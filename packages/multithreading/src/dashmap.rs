@@ -1,7 +1,10 @@
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 use dashmap::DashMap as DM;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
 use ahash::RandomState;
 
 #[napi]
@@ -213,6 +216,127 @@ impl ShardedMap {
     }
 }
 
+type CacheEntries = DM<String, (String, Instant), RandomState>;
+
+/// Concurrent cache with per-entry TTL.
+///
+/// Expired entries are evicted lazily on read and periodically by a
+/// background sweeper thread, which exits once the cache is dropped.
+#[napi]
+pub struct ConcurrentCache {
+    entries: Arc<CacheEntries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[napi]
+impl ConcurrentCache {
+    #[napi(constructor)]
+    pub fn new(sweep_interval_ms: Option<u32>) -> Self {
+        let entries: Arc<CacheEntries> = Arc::new(DM::with_hasher(RandomState::new()));
+        let interval = Duration::from_millis(sweep_interval_ms.unwrap_or(1000).max(1) as u64);
+        Self::spawn_sweeper(Arc::downgrade(&entries), interval);
+
+        Self {
+            entries,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    fn spawn_sweeper(entries: Weak<CacheEntries>, interval: Duration) {
+        thread::spawn(move || loop {
+            thread::sleep(interval);
+            match entries.upgrade() {
+                Some(entries) => {
+                    purge_expired(&entries);
+                }
+                None => break,
+            }
+        });
+    }
+
+    #[napi]
+    pub fn set(&self, key: String, value: String, ttl_ms: u32) -> Option<String> {
+        let expires_at = Instant::now() + Duration::from_millis(ttl_ms as u64);
+        self.entries
+            .insert(key, (value, expires_at))
+            .filter(|(_, previous_expiry)| *previous_expiry > Instant::now())
+            .map(|(previous, _)| previous)
+    }
+
+    #[napi]
+    pub fn get(&self, key: String) -> Option<String> {
+        let now = Instant::now();
+        let value = self.entries
+            .get(&key)
+            .filter(|entry| entry.1 > now)
+            .map(|entry| entry.0.clone());
+
+        match value {
+            Some(value) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(value)
+            }
+            None => {
+                self.entries.remove_if(&key, |_, (_, expires_at)| *expires_at <= now);
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    #[napi]
+    pub fn remove(&self, key: String) -> Option<String> {
+        let now = Instant::now();
+        self.entries
+            .remove(&key)
+            .filter(|(_, (_, expires_at))| *expires_at > now)
+            .map(|(_, (value, _))| value)
+    }
+
+    /// Purge expired entries now, returning how many were removed
+    #[napi]
+    pub fn sweep(&self) -> u32 {
+        purge_expired(&self.entries) as u32
+    }
+
+    /// Number of stored entries, including expired ones not yet swept
+    #[napi]
+    pub fn len(&self) -> u32 {
+        self.entries.len() as u32
+    }
+
+    #[napi]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[napi]
+    pub fn clear(&self) {
+        self.entries.clear()
+    }
+
+    /// Fraction of `get` calls that found a live entry
+    #[napi]
+    pub fn hit_rate(&self) -> f64 {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let total = hits + self.misses.load(Ordering::Relaxed);
+        if total == 0 {
+            0.0
+        } else {
+            hits as f64 / total as f64
+        }
+    }
+}
+
+fn purge_expired(entries: &CacheEntries) -> usize {
+    let now = Instant::now();
+    let before = entries.len();
+    entries.retain(|_, (_, expires_at)| *expires_at > now);
+    before.saturating_sub(entries.len())
+}
+
 #[napi]
 pub fn create_dashmap() -> DashMap {
     DashMap::new()
@@ -231,4 +355,43 @@ pub fn create_dashset() -> DashSet {
 #[napi]
 pub fn create_sharded_map(shard_count: u32) -> ShardedMap {
     ShardedMap::new(shard_count)
-}
\ No newline at end of file
+}
+#[napi]
+pub fn create_concurrent_cache(sweep_interval_ms: Option<u32>) -> ConcurrentCache {
+    ConcurrentCache::new(sweep_interval_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_expires_on_read() {
+        let cache = ConcurrentCache::new(Some(60_000));
+        cache.set("short".to_string(), "a".to_string(), 20);
+        cache.set("long".to_string(), "b".to_string(), 60_000);
+
+        assert_eq!(cache.get("short".to_string()), Some("a".to_string()));
+        thread::sleep(Duration::from_millis(40));
+
+        assert_eq!(cache.get("short".to_string()), None);
+        assert_eq!(cache.get("long".to_string()), Some("b".to_string()));
+        assert_eq!(cache.len(), 1);
+        assert!((cache.hit_rate() - 2.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_cache_sweeper_removes_stale_keys() {
+        let cache = ConcurrentCache::new(Some(10));
+        for i in 0..100 {
+            cache.set(format!("key{}", i), i.to_string(), 5);
+        }
+        cache.set("fresh".to_string(), "x".to_string(), 60_000);
+
+        thread::sleep(Duration::from_millis(100));
+
+        // No reads happened, so only the sweeper could have evicted these
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get("fresh".to_string()), Some("x".to_string()));
+    }
+}
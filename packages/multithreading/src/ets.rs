@@ -85,6 +85,43 @@ impl ETSValue {
             ETSValue::Map(_) => "map",
         }
     }
+
+    /// Convert a JSON term into an ETS value. Arrays become tuples.
+    pub fn from_json(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => ETSValue::Nil,
+            serde_json::Value::Bool(b) => ETSValue::Boolean(b),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => ETSValue::Integer(i),
+                None => ETSValue::Float(ordered_float::OrderedFloat(n.as_f64().unwrap_or(f64::NAN))),
+            },
+            serde_json::Value::String(s) => ETSValue::String(s),
+            serde_json::Value::Array(items) => {
+                ETSValue::Tuple(items.into_iter().map(ETSValue::from_json).collect())
+            },
+            serde_json::Value::Object(map) => {
+                ETSValue::Map(map.into_iter().map(|(k, v)| (k, ETSValue::from_json(v))).collect())
+            },
+        }
+    }
+
+    /// Convert an ETS value into a JSON term
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            ETSValue::Nil => serde_json::Value::Null,
+            ETSValue::Boolean(b) => serde_json::Value::Bool(*b),
+            ETSValue::Integer(i) => serde_json::Value::from(*i),
+            ETSValue::Float(f) => serde_json::Value::from(f.into_inner()),
+            ETSValue::String(s) => serde_json::Value::String(s.clone()),
+            ETSValue::Binary(bytes) => serde_json::Value::from(bytes.clone()),
+            ETSValue::List(items) | ETSValue::Tuple(items) => {
+                serde_json::Value::Array(items.iter().map(ETSValue::to_json).collect())
+            },
+            ETSValue::Map(map) => {
+                serde_json::Value::Object(map.iter().map(|(k, v)| (k.clone(), v.to_json())).collect())
+            },
+        }
+    }
 }
 
 impl PartialOrd for ETSValue {
@@ -139,6 +176,8 @@ pub enum Pattern {
     Wildcard,
     /// Variable binding
     Variable(String),
+    /// Positional match over a tuple
+    Tuple(Vec<Pattern>),
     /// Guard conditions
     Guard {
         pattern: Box<Pattern>,
@@ -160,6 +199,116 @@ pub enum GuardCondition {
     Not(Box<GuardCondition>),
 }
 
+impl Pattern {
+    /// Parse a JSON pattern: `"_"` is a wildcard, strings starting with `$`
+    /// are variables, arrays are tuple patterns and anything else is a literal.
+    pub fn from_json(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::String(s) if s == "_" => Pattern::Wildcard,
+            serde_json::Value::String(s) if s.starts_with('$') => Pattern::Variable(s),
+            serde_json::Value::Array(items) => {
+                Pattern::Tuple(items.into_iter().map(Pattern::from_json).collect())
+            },
+            other => Pattern::Value(ETSValue::from_json(other)),
+        }
+    }
+
+    /// Match a value, recording variable bindings. A variable that appears
+    /// more than once must bind the same value each time.
+    pub fn matches(&self, value: &ETSValue, bindings: &mut HashMap<String, ETSValue>) -> bool {
+        match self {
+            Pattern::Value(expected) => expected == value,
+            Pattern::Wildcard => true,
+            Pattern::Variable(name) => match bindings.get(name) {
+                Some(bound) => bound == value,
+                None => {
+                    bindings.insert(name.clone(), value.clone());
+                    true
+                },
+            },
+            Pattern::Tuple(patterns) => match value {
+                ETSValue::Tuple(items) if items.len() == patterns.len() => {
+                    patterns.iter().zip(items).all(|(pattern, item)| pattern.matches(item, bindings))
+                },
+                _ => false,
+            },
+            Pattern::Guard { pattern, condition } => {
+                pattern.matches(value, bindings) && condition.evaluate(value)
+            },
+        }
+    }
+
+    /// Variables in Erlang order: `$1`, `$2`, ... numerically, then any others by name
+    pub fn variables(&self) -> Vec<String> {
+        fn collect(pattern: &Pattern, out: &mut Vec<String>) {
+            match pattern {
+                Pattern::Variable(name) if !out.contains(name) => out.push(name.clone()),
+                Pattern::Tuple(patterns) => patterns.iter().for_each(|p| collect(p, out)),
+                Pattern::Guard { pattern, .. } => collect(pattern, out),
+                _ => {},
+            }
+        }
+
+        let mut variables = Vec::new();
+        collect(self, &mut variables);
+        variables.sort_by_key(|name| (name[1..].parse::<u64>().map_err(|_| name.clone()), name.clone()));
+        variables
+    }
+
+    /// Attach a guard to every occurrence of `variable` in this pattern
+    fn guard_variable(&mut self, variable: &str, condition: &GuardCondition) {
+        match self {
+            Pattern::Variable(name) if name == variable => {
+                let pattern = std::mem::replace(self, Pattern::Wildcard);
+                *self = Pattern::Guard {
+                    pattern: Box::new(pattern),
+                    condition: condition.clone(),
+                };
+            },
+            Pattern::Tuple(patterns) => {
+                patterns.iter_mut().for_each(|p| p.guard_variable(variable, condition));
+            },
+            Pattern::Guard { pattern, .. } => pattern.guard_variable(variable, condition),
+            _ => {},
+        }
+    }
+}
+
+impl GuardCondition {
+    /// Evaluate the condition against a single value
+    pub fn evaluate(&self, value: &ETSValue) -> bool {
+        match self {
+            GuardCondition::Equal(other) => value == other,
+            GuardCondition::Greater(other) => value > other,
+            GuardCondition::Less(other) => value < other,
+            GuardCondition::GreaterEqual(other) => value >= other,
+            GuardCondition::LessEqual(other) => value <= other,
+            GuardCondition::TypeCheck(type_name) => value.type_name() == type_name,
+            GuardCondition::And(a, b) => a.evaluate(value) && b.evaluate(value),
+            GuardCondition::Or(a, b) => a.evaluate(value) || b.evaluate(value),
+            GuardCondition::Not(condition) => !condition.evaluate(value),
+        }
+    }
+
+    /// Parse an Erlang-style guard operator and operand, e.g. `">"` and `10`
+    fn from_operator(op: &str, operand: serde_json::Value) -> std::result::Result<Self, String> {
+        let operand = ETSValue::from_json(operand);
+        Ok(match op {
+            "==" | "=:=" => GuardCondition::Equal(operand),
+            "/=" | "=/=" | "!=" => GuardCondition::Not(Box::new(GuardCondition::Equal(operand))),
+            ">" => GuardCondition::Greater(operand),
+            "<" => GuardCondition::Less(operand),
+            ">=" => GuardCondition::GreaterEqual(operand),
+            "=<" | "<=" => GuardCondition::LessEqual(operand),
+            "is" => match operand {
+                ETSValue::String(type_name) => GuardCondition::TypeCheck(type_name),
+                _ => return Err("Type guard expects a type name".to_string()),
+            },
+            _ => return Err(format!("Unsupported guard operator '{}'", op)),
+        })
+    }
+}
+
 /// Match specification for complex queries
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchSpec {
@@ -183,6 +332,56 @@ pub enum MatchBody {
     Delete,
 }
 
+impl MatchSpec {
+    /// Parse a JSON match spec of the form
+    /// `{"head": [...], "guards": [[">", "$1", 10]], "result": ["$1"]}`.
+    /// `result` may also be `"$_"` (whole object), `"$$"` (all bindings)
+    /// or `"count"`; it defaults to `"$_"`.
+    pub fn from_json(value: serde_json::Value) -> std::result::Result<Self, String> {
+        let serde_json::Value::Object(mut spec) = value else {
+            return Err("Match spec must be an object".to_string());
+        };
+
+        let mut head = Pattern::from_json(spec.remove("head").ok_or("Match spec is missing 'head'")?);
+
+        if let Some(guards) = spec.remove("guards") {
+            let serde_json::Value::Array(guards) = guards else {
+                return Err("Match spec 'guards' must be an array".to_string());
+            };
+            for guard in guards {
+                let parts = match guard {
+                    serde_json::Value::Array(parts) if parts.len() == 3 => parts,
+                    _ => return Err("Guards must be [operator, variable, value] triples".to_string()),
+                };
+                let mut parts = parts.into_iter();
+                let (op, variable, operand) = (parts.next().unwrap(), parts.next().unwrap(), parts.next().unwrap());
+                let (Some(op), Some(variable)) = (op.as_str(), variable.as_str()) else {
+                    return Err("Guard operator and variable must be strings".to_string());
+                };
+                head.guard_variable(variable, &GuardCondition::from_operator(op, operand)?);
+            }
+        }
+
+        let body = match spec.remove("result") {
+            None => MatchBody::WholeObject,
+            Some(serde_json::Value::String(s)) => match s.as_str() {
+                "$_" => MatchBody::WholeObject,
+                "$$" => MatchBody::Fields(head.variables()),
+                "count" => MatchBody::Count,
+                _ => MatchBody::Fields(vec![s]),
+            },
+            Some(serde_json::Value::Array(fields)) => MatchBody::Fields(
+                fields.into_iter()
+                    .map(|field| field.as_str().map(str::to_string).ok_or("Result fields must be strings"))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Some(_) => return Err("Match spec 'result' must be a string or an array".to_string()),
+        };
+
+        Ok(MatchSpec { head, guards: Vec::new(), body })
+    }
+}
+
 /// Table configuration
 #[derive(Debug, Clone)]
 pub struct TableConfig {
@@ -232,6 +431,19 @@ pub struct ETSTable {
     owner: String,
 }
 
+impl ETSObject {
+    /// View the object as an Erlang-style tuple: the key followed by the
+    /// elements of a tuple value, or `{key, value}` for any other value.
+    pub fn as_tuple(&self) -> ETSValue {
+        let mut elements = vec![self.key.clone()];
+        match &self.value {
+            ETSValue::Tuple(items) => elements.extend(items.iter().cloned()),
+            other => elements.push(other.clone()),
+        }
+        ETSValue::Tuple(elements)
+    }
+}
+
 impl ETSTable {
     /// Create new ETS table
     pub fn new(id: TableId, config: TableConfig, owner: String) -> Self {
@@ -472,6 +684,88 @@ impl ETSTable {
         }
     }
 
+    /// Snapshot of every object, in key order for ordered sets
+    fn objects(&self) -> Vec<ETSObject> {
+        match self.config.table_type {
+            TableType::Set => {
+                let storage = self.set_storage.read().unwrap();
+                storage.values().cloned().collect()
+            },
+
+            TableType::OrderedSet => {
+                let storage = self.ordered_storage.read().unwrap();
+                storage.values().cloned().collect()
+            },
+
+            TableType::Bag | TableType::DuplicateBag => {
+                let storage = self.bag_storage.read().unwrap();
+                storage.values().flatten().cloned().collect()
+            },
+        }
+    }
+
+    /// Match objects against a tuple pattern, returning the bound variables
+    /// of each match (like `:ets.match/2`)
+    pub fn match_pattern(&self, pattern: &Pattern) -> Vec<Vec<ETSValue>> {
+        self.read_count.fetch_add(1, Ordering::Relaxed);
+
+        let variables = pattern.variables();
+        self.objects()
+            .iter()
+            .filter_map(|object| {
+                let mut bindings = HashMap::new();
+                if !pattern.matches(&object.as_tuple(), &mut bindings) {
+                    return None;
+                }
+                Some(variables.iter().map(|name| bindings.remove(name).unwrap_or(ETSValue::Nil)).collect())
+            })
+            .collect()
+    }
+
+    /// Run a match spec (like `:ets.select/2`)
+    pub fn select(&self, spec: &MatchSpec) -> std::result::Result<Vec<ETSValue>, String> {
+        self.read_count.fetch_add(1, Ordering::Relaxed);
+
+        if let MatchBody::Computed(_) = spec.body {
+            return Err("Computed match bodies are not supported".to_string());
+        }
+
+        let mut matched = Vec::new();
+        for object in self.objects() {
+            let tuple = object.as_tuple();
+            let mut bindings = HashMap::new();
+            if spec.head.matches(&tuple, &mut bindings) && spec.guards.iter().all(|guard| guard.evaluate(&tuple)) {
+                matched.push((object, tuple, bindings));
+            }
+        }
+
+        match &spec.body {
+            MatchBody::WholeObject => Ok(matched.into_iter().map(|(_, tuple, _)| tuple).collect()),
+            MatchBody::Fields(fields) => Ok(matched.into_iter()
+                .map(|(_, tuple, bindings)| {
+                    let mut projected: Vec<ETSValue> = fields.iter()
+                        .map(|field| match field.as_str() {
+                            "$_" => tuple.clone(),
+                            name if name.starts_with('$') => bindings.get(name).cloned().unwrap_or(ETSValue::Nil),
+                            constant => ETSValue::String(constant.to_string()),
+                        })
+                        .collect();
+                    if projected.len() == 1 {
+                        projected.remove(0)
+                    } else {
+                        ETSValue::Tuple(projected)
+                    }
+                })
+                .collect()),
+            MatchBody::Count => Ok(vec![ETSValue::Integer(matched.len() as i64)]),
+            MatchBody::Delete => {
+                let deleted = matched.iter().filter(|(object, _, _)| self.delete_object(object)).count();
+                Ok(vec![ETSValue::Integer(deleted as i64)])
+            },
+            MatchBody::Computed(_) => unreachable!(),
+        }
+    }
+
     /// Get table info
     pub fn info(&self) -> TableInfo {
         TableInfo {
@@ -850,6 +1144,95 @@ pub fn ets_lookup(table: String, key: String) -> Result<Vec<String>> {
     Ok(result)
 }
 
+fn parse_json(input: &str, what: &str) -> Result<serde_json::Value> {
+    serde_json::from_str(input)
+        .map_err(|e| napi::Error::new(Status::InvalidArg, format!("Invalid {} JSON: {}", what, e)))
+}
+
+fn values_to_json(values: Vec<ETSValue>) -> String {
+    serde_json::Value::Array(values.iter().map(ETSValue::to_json).collect()).to_string()
+}
+
+/// Insert a tuple given as a JSON array; the first element is the key
+#[napi]
+pub fn ets_insert_tuple(table: String, tuple: String) -> Result<bool> {
+    let system = global_ets();
+    let table = system.get_table_by_name(&table)
+        .ok_or_else(|| napi::Error::from_reason("Table not found"))?;
+
+    let mut elements = match ETSValue::from_json(parse_json(&tuple, "tuple")?) {
+        ETSValue::Tuple(elements) if !elements.is_empty() => elements,
+        _ => return Err(napi::Error::new(Status::InvalidArg, "Tuple must be a non-empty array")),
+    };
+    let key = elements.remove(0);
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis() as u64;
+
+    let object = ETSObject {
+        key,
+        value: ETSValue::Tuple(elements),
+        metadata: HashMap::new(),
+        created_at: now,
+        updated_at: now,
+    };
+
+    table.insert(object)
+        .map_err(|e| napi::Error::from_reason(e))
+}
+
+/// Match stored tuples against a JSON pattern such as `["user", "$1", "_"]`,
+/// returning a JSON array with the variable bindings of every match
+#[napi]
+pub fn ets_match(table: String, pattern: String) -> Result<String> {
+    let system = global_ets();
+    let table = system.get_table_by_name(&table)
+        .ok_or_else(|| napi::Error::from_reason("Table not found"))?;
+
+    let pattern = Pattern::from_json(parse_json(&pattern, "pattern")?);
+    let matches = table.match_pattern(&pattern)
+        .into_iter()
+        .map(ETSValue::Tuple)
+        .collect();
+
+    Ok(values_to_json(matches))
+}
+
+/// Run a JSON match spec (see `MatchSpec::from_json`), returning the
+/// projected results as a JSON array
+#[napi]
+pub fn ets_select(table: String, matchspec: String) -> Result<String> {
+    let system = global_ets();
+    let table = system.get_table_by_name(&table)
+        .ok_or_else(|| napi::Error::from_reason("Table not found"))?;
+
+    let spec = MatchSpec::from_json(parse_json(&matchspec, "match spec")?)
+        .map_err(|e| napi::Error::new(Status::InvalidArg, e))?;
+    let results = table.select(&spec)
+        .map_err(|e| napi::Error::from_reason(e))?;
+
+    Ok(values_to_json(results))
+}
+
+/// First key of the table as a JSON term (key order for ordered sets)
+#[napi]
+pub fn ets_first(table: String) -> Result<Option<String>> {
+    let system = global_ets();
+    let table = system.get_table_by_name(&table)
+        .ok_or_else(|| napi::Error::from_reason("Table not found"))?;
+
+    Ok(table.first().map(|key| key.to_json().to_string()))
+}
+
+/// Key following `key` (a JSON term), or `null` at the end of the table
+#[napi]
+pub fn ets_next(table: String, key: String) -> Result<Option<String>> {
+    let system = global_ets();
+    let table = system.get_table_by_name(&table)
+        .ok_or_else(|| napi::Error::from_reason("Table not found"))?;
+
+    let key = ETSValue::from_json(parse_json(&key, "key")?);
+    Ok(table.next(&key).map(|key| key.to_json().to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -913,4 +1296,90 @@ mod tests {
         sorted_keys.sort();
         assert_eq!(keys, sorted_keys);
     }
-}
\ No newline at end of file
+
+    fn tuple_table(table_type: TableType) -> Arc<ETSTable> {
+        let system = ETSSystem::new();
+        let config = TableConfig {
+            table_type,
+            ..Default::default()
+        };
+        let table_id = system.new_table(Some("tuples".to_string()), config, "test_owner".to_string()).unwrap();
+        let table = system.get_table(&table_id).unwrap();
+
+        let rows = serde_json::json!([
+            [3, "alice", "admin", 34],
+            [1, "bob", "user", 27],
+            [2, "carol", "admin", 41],
+            [4, "dave", "user", 19],
+        ]);
+        for row in rows.as_array().unwrap() {
+            let ETSValue::Tuple(mut elements) = ETSValue::from_json(row.clone()) else { unreachable!() };
+            let key = elements.remove(0);
+            table.insert(ETSObject {
+                key,
+                value: ETSValue::Tuple(elements),
+                metadata: HashMap::new(),
+                created_at: 0,
+                updated_at: 0,
+            }).unwrap();
+        }
+        table
+    }
+
+    #[test]
+    fn test_ets_match_pattern() {
+        let table = tuple_table(TableType::OrderedSet);
+
+        // Variables come back in $1, $2 order regardless of position
+        let pattern = Pattern::from_json(serde_json::json!(["_", "$2", "admin", "$1"]));
+        let matches = table.match_pattern(&pattern);
+        assert_eq!(matches, vec![
+            vec![ETSValue::Integer(41), ETSValue::String("carol".to_string())],
+            vec![ETSValue::Integer(34), ETSValue::String("alice".to_string())],
+        ]);
+
+        let pattern = Pattern::from_json(serde_json::json!(["_", "_", "guest", "_"]));
+        assert!(table.match_pattern(&pattern).is_empty());
+
+        // Repeated variables must bind equal values
+        let pattern = Pattern::from_json(serde_json::json!(["$1", "$1", "_", "_"]));
+        assert!(table.match_pattern(&pattern).is_empty());
+    }
+
+    #[test]
+    fn test_ets_select_projection_and_guards() {
+        let table = tuple_table(TableType::Set);
+
+        let spec = MatchSpec::from_json(serde_json::json!({
+            "head": ["$1", "$2", "_", "$3"],
+            "guards": [[">=", "$3", 30]],
+            "result": ["$2"],
+        })).unwrap();
+        let mut names = table.select(&spec).unwrap();
+        names.sort();
+        assert_eq!(names, vec![ETSValue::String("alice".to_string()), ETSValue::String("carol".to_string())]);
+
+        let spec = MatchSpec::from_json(serde_json::json!({
+            "head": ["_", "_", "user", "_"],
+            "result": "count",
+        })).unwrap();
+        assert_eq!(table.select(&spec).unwrap(), vec![ETSValue::Integer(2)]);
+
+        let spec = MatchSpec::from_json(serde_json::json!({ "head": [1, "_", "_", "_"] })).unwrap();
+        assert_eq!(table.select(&spec).unwrap(), vec![ETSValue::from_json(serde_json::json!([1, "bob", "user", 27]))]);
+    }
+
+    #[test]
+    fn test_ets_ordered_traversal() {
+        let table = tuple_table(TableType::OrderedSet);
+
+        let mut keys = Vec::new();
+        let mut cursor = table.first();
+        while let Some(key) = cursor {
+            cursor = table.next(&key);
+            keys.push(key);
+        }
+
+        assert_eq!(keys, (1..=4).map(ETSValue::Integer).collect::<Vec<_>>());
+    }
+}
@@ -1,7 +1,7 @@
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 use flume;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 #[napi]
@@ -304,6 +304,116 @@ pub struct SelectResult {
     pub message: String,
 }
 
+/// Iterator result for `FlumeStream::next`, shaped like JS's `{ value, done }`
+#[napi(object)]
+pub struct FlumeStreamResult {
+    pub value: Option<String>,
+    pub done: bool,
+}
+
+/// Flume channel consumed from Node with `for await`. `next` resolves with
+/// each value as it arrives and reports `done` once the sender is closed
+/// and everything already sent has been read.
+#[napi]
+pub struct FlumeStream {
+    receiver: flume::Receiver<String>,
+    sender: Arc<Mutex<Option<flume::Sender<String>>>>,
+}
+
+#[napi]
+impl FlumeStream {
+    /// Unbounded unless `capacity` is given
+    #[napi(constructor)]
+    pub fn new(capacity: Option<u32>) -> Self {
+        let (sender, receiver) = match capacity {
+            Some(capacity) => flume::bounded(capacity as usize),
+            None => flume::unbounded(),
+        };
+        Self {
+            receiver,
+            sender: Arc::new(Mutex::new(Some(sender))),
+        }
+    }
+
+    /// Producer handle; every handle shares one sender, so closing any of
+    /// them ends the stream
+    #[napi]
+    pub fn sender(&self) -> FlumeStreamSender {
+        FlumeStreamSender {
+            inner: Arc::clone(&self.sender),
+        }
+    }
+
+    #[napi]
+    pub async fn next(&self) -> Result<FlumeStreamResult> {
+        Ok(match self.receiver.recv_async().await {
+            Ok(value) => FlumeStreamResult { value: Some(value), done: false },
+            Err(flume::RecvError::Disconnected) => FlumeStreamResult { value: None, done: true },
+        })
+    }
+
+    #[napi]
+    pub fn len(&self) -> u32 {
+        self.receiver.len() as u32
+    }
+
+    #[napi]
+    pub fn is_empty(&self) -> bool {
+        self.receiver.is_empty()
+    }
+}
+
+#[napi]
+pub struct FlumeStreamSender {
+    inner: Arc<Mutex<Option<flume::Sender<String>>>>,
+}
+
+impl FlumeStreamSender {
+    // Cloned out so a send waiting on a full channel doesn't hold the lock
+    // that `close` needs
+    fn sender(&self) -> Result<flume::Sender<String>> {
+        self.inner
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| Error::from_reason("Stream sender is closed"))
+    }
+}
+
+#[napi]
+impl FlumeStreamSender {
+    /// Send a value, resolving once there is room if the stream is bounded
+    /// and full. Waiting never blocks the JS thread, so the producer can
+    /// share it with a `for await` consumer.
+    #[napi]
+    pub async fn send(&self, message: String) -> Result<()> {
+        self.sender()?
+            .send_async(message)
+            .await
+            .map_err(|e| Error::from_reason(e.to_string()))
+    }
+
+    #[napi]
+    pub fn try_send(&self, message: String) -> Result<bool> {
+        match self.sender()?.try_send(message) {
+            Ok(()) => Ok(true),
+            Err(flume::TrySendError::Full(_)) => Ok(false),
+            Err(e) => Err(Error::from_reason(e.to_string())),
+        }
+    }
+
+    /// End the stream once the values already sent have been consumed
+    #[napi]
+    pub fn close(&self) {
+        self.inner.lock().unwrap().take();
+    }
+
+    #[napi]
+    pub fn is_closed(&self) -> bool {
+        self.inner.lock().unwrap().is_none()
+    }
+}
+
 #[napi]
 pub fn create_flume_unbounded() -> FlumeChannel {
     FlumeChannel::unbounded()
@@ -317,4 +427,84 @@ pub fn create_flume_bounded(capacity: u32) -> FlumeChannel {
 #[napi]
 pub fn create_flume_selector() -> FlumeSelector {
     FlumeSelector::new()
-}
\ No newline at end of file
+}
+
+#[napi]
+pub fn create_flume_stream(capacity: Option<u32>) -> FlumeStream {
+    FlumeStream::new(capacity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_stream_yields_every_value_then_ends_on_close() {
+        let stream = FlumeStream::new(Some(4));
+        let sender = stream.sender();
+
+        // Bounded below the value count, so the producer waits on the consumer
+        let producer = tokio::spawn(async move {
+            for i in 0..100 {
+                sender.send(format!("value-{}", i)).await.unwrap();
+            }
+            sender.close();
+            assert!(sender.is_closed());
+            assert!(sender.send("late".to_string()).await.is_err());
+        });
+
+        let mut values = Vec::new();
+        loop {
+            let result = stream.next().await.unwrap();
+            if result.done {
+                assert!(result.value.is_none());
+                break;
+            }
+            values.push(result.value.unwrap());
+        }
+        producer.await.unwrap();
+
+        assert_eq!(values, (0..100).map(|i| format!("value-{}", i)).collect::<Vec<_>>());
+        // Stays done once ended
+        assert!(stream.next().await.unwrap().done);
+    }
+
+    #[tokio::test]
+    async fn test_try_send_reports_full_stream() {
+        let stream = FlumeStream::new(Some(1));
+        let sender = stream.sender();
+        assert!(sender.try_send("a".to_string()).unwrap());
+        assert!(!sender.try_send("b".to_string()).unwrap());
+
+        sender.close();
+        assert!(sender.try_send("c".to_string()).is_err());
+        assert_eq!(stream.next().await.unwrap().value.as_deref(), Some("a"));
+        assert!(stream.next().await.unwrap().done);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_send_on_a_full_stream_yields_to_a_consumer_on_the_same_thread() {
+        let stream = FlumeStream::new(Some(2));
+        let sender = stream.sender();
+
+        let producer = async {
+            for i in 0..20 {
+                sender.send(format!("value-{}", i)).await.unwrap();
+            }
+            sender.close();
+        };
+        let consumer = async {
+            let mut values = Vec::new();
+            while let Some(value) = stream.next().await.unwrap().value {
+                values.push(value);
+            }
+            values
+        };
+
+        let ((), values) = tokio::time::timeout(Duration::from_secs(5), async { tokio::join!(producer, consumer) })
+            .await
+            .expect("send deadlocked the consumer");
+        assert_eq!(values.len(), 20);
+        assert_eq!(values[19], "value-19");
+    }
+}
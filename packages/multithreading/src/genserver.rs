@@ -1,6 +1,7 @@
-use crate::actor::{Actor, ActorBehavior, ActorId, ActorState, Message, get_actor_system};
+use crate::actor::{Actor, ActorBehavior, ActorId, ActorState, Message, get_actor_system, get_or_init_actor_system};
 use async_channel::{bounded, Receiver, Sender};
 use napi::bindgen_prelude::*;
+use napi::threadsafe_function::ThreadsafeFunction;
 use napi_derive::napi;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -23,8 +24,9 @@ pub trait GenServerBehavior: Send + Sync + 'static {
     // Called when the server starts
     async fn init(&mut self, args: Vec<u8>) -> Result<Vec<u8>>;
     
-    // Handle synchronous calls (like Elixir's handle_call)
-    async fn handle_call(&mut self, request: Vec<u8>, from: ActorId, state: Vec<u8>) -> GenServerResponse;
+    // Handle synchronous calls (like Elixir's handle_call). `from` is the
+    // calling actor, or `None` when the caller isn't an actor.
+    async fn handle_call(&mut self, request: Vec<u8>, from: Option<ActorId>, state: Vec<u8>) -> GenServerResponse;
     
     // Handle asynchronous casts (like Elixir's handle_cast)
     async fn handle_cast(&mut self, request: Vec<u8>, state: Vec<u8>) -> GenServerResponse;
@@ -67,12 +69,8 @@ impl GenServer {
         };
         
         // Spawn the actor in the actor system
-        if let Some(system) = get_actor_system() {
-            let actor_id = system.spawn(Box::new(genserver_actor));
-            Ok(actor_id)
-        } else {
-            Err(Error::from_reason("Actor system not initialized"))
-        }
+        let actor_id = get_or_init_actor_system().spawn(Box::new(genserver_actor));
+        Ok(actor_id)
     }
     
     pub async fn start_link(self, args: Vec<u8>) -> Result<ActorId> {
@@ -89,12 +87,12 @@ struct GenServerActor {
 #[async_trait::async_trait]
 impl ActorBehavior for GenServerActor {
     async fn handle_message(&mut self, msg: Message) -> Option<Vec<u8>> {
-        let mut genserver = self.genserver.write().await;
+        // Reborrow through the guard so `behavior` and `state` borrow separately
+        let mut guard = self.genserver.write().await;
+        let genserver = &mut *guard;
         
         match msg {
-            Message::Call { payload, .. } => {
-                // Deserialize the call request
-                let from = ActorId::new(); // In real implementation, extract from message
+            Message::Call { payload, from, .. } => {
                 let response = genserver.behavior.handle_call(
                     payload,
                     from,
@@ -155,7 +153,8 @@ impl ActorBehavior for GenServerActor {
     }
     
     async fn on_stop(&mut self) {
-        let genserver = self.genserver.write().await;
+        let mut guard = self.genserver.write().await;
+        let genserver = &mut *guard;
         genserver.behavior.terminate("normal".to_string(), genserver.state.clone()).await;
     }
 }
@@ -173,7 +172,7 @@ impl GenServerBehavior for CounterServer {
         Ok(initial_count.to_ne_bytes().to_vec())
     }
     
-    async fn handle_call(&mut self, request: Vec<u8>, _from: ActorId, state: Vec<u8>) -> GenServerResponse {
+    async fn handle_call(&mut self, request: Vec<u8>, _from: Option<ActorId>, state: Vec<u8>) -> GenServerResponse {
         let count = i32::from_ne_bytes(state.clone().try_into().unwrap_or([0; 4]));
         
         // Parse request type
@@ -214,6 +213,93 @@ impl GenServerBehavior for CounterServer {
     }
 }
 
+// Shape of the JSON a JS handler returns, matching `CallResponse`/`CastResponse` in genserver.ts.
+// `newState` may be omitted when the caller keeps state on the JS side.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HandlerResponse {
+    reply: Option<serde_json::Value>,
+    #[serde(default)]
+    no_reply: bool,
+    stop: Option<String>,
+    new_state: Option<serde_json::Value>,
+}
+
+// GenServer whose callbacks are a single JS handler.
+// The handler receives `{"type": "call" | "cast" | "info", "request", "state", "from"}`
+// as JSON, where `from` is the calling actor's id on calls and null otherwise.
+pub struct JsHandlerServer {
+    handler: ThreadsafeFunction<String, Promise<String>>,
+}
+
+impl JsHandlerServer {
+    async fn invoke(&self, kind: &str, request: Vec<u8>, from: Option<ActorId>, state: Vec<u8>) -> GenServerResponse {
+        let to_json = |bytes: &[u8]| {
+            serde_json::from_slice::<serde_json::Value>(bytes)
+                .unwrap_or_else(|_| serde_json::Value::String(String::from_utf8_lossy(bytes).into_owned()))
+        };
+        let envelope = serde_json::json!({
+            "type": kind,
+            "request": to_json(&request),
+            "state": to_json(&state),
+            "from": from.map(|from| from.to_string()),
+        });
+
+        let response = match self.handler.call_async(Ok(envelope.to_string())).await {
+            Ok(promise) => promise.await,
+            Err(e) => Err(e),
+        };
+        let response = response.and_then(|raw| {
+            serde_json::from_str::<HandlerResponse>(&raw)
+                .map_err(|e| Error::from_reason(format!("Invalid handler response: {}", e)))
+        });
+
+        match response {
+            Ok(response) => {
+                let new_state = response.new_state.map(|s| s.to_string().into_bytes()).unwrap_or(state);
+                let reply = response.reply.map(|r| r.to_string().into_bytes());
+                if let Some(reason) = response.stop {
+                    GenServerResponse::Stop { reason, reply }
+                } else if response.no_reply {
+                    GenServerResponse::NoReply { new_state }
+                } else {
+                    GenServerResponse::Reply {
+                        value: reply.unwrap_or_else(|| b"null".to_vec()),
+                        new_state,
+                    }
+                }
+            }
+            Err(e) => {
+                error!("GenServer handler failed on {}: {}", kind, e);
+                GenServerResponse::Stop { reason: e.to_string(), reply: None }
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl GenServerBehavior for JsHandlerServer {
+    async fn init(&mut self, args: Vec<u8>) -> Result<Vec<u8>> {
+        Ok(args)
+    }
+
+    async fn handle_call(&mut self, request: Vec<u8>, from: Option<ActorId>, state: Vec<u8>) -> GenServerResponse {
+        self.invoke("call", request, from, state).await
+    }
+
+    async fn handle_cast(&mut self, request: Vec<u8>, state: Vec<u8>) -> GenServerResponse {
+        self.invoke("cast", request, None, state).await
+    }
+
+    async fn handle_info(&mut self, info: Vec<u8>, state: Vec<u8>) -> GenServerResponse {
+        self.invoke("info", info, None, state).await
+    }
+
+    async fn terminate(&mut self, reason: String, _state: Vec<u8>) {
+        info!("JS GenServer terminating: {}", reason);
+    }
+}
+
 // NAPI bindings for JavaScript
 #[napi]
 pub struct JsGenServer {
@@ -234,7 +320,7 @@ impl JsGenServer {
         
         match genserver.start(Vec::new()).await {
             Ok(actor_id) => {
-                let id_str = format!("{:?}", actor_id);
+                let id_str = actor_id.to_string();
                 self.actor_id = Some(id_str.clone());
                 
                 // Register with a name for easy lookup
@@ -248,25 +334,50 @@ impl JsGenServer {
         }
     }
     
+    /// Start a GenServer driven by a JS handler, returning its actor id
     #[napi]
-    pub async fn call(&self, actor_id: String, request: Vec<u8>, timeout_ms: u32) -> Result<Vec<u8>> {
-        if let Some(system) = get_actor_system() {
-            let id = ActorId::from_string(actor_id);
-            let timeout = Duration::from_millis(timeout_ms as u64);
-            system.call(&id, request, timeout).await
-        } else {
-            Err(Error::from_reason("Actor system not initialized"))
+    pub async fn start(
+        &mut self,
+        initial_state: String,
+        handler: ThreadsafeFunction<String, Promise<String>>,
+        name: Option<String>,
+    ) -> Result<String> {
+        let genserver = GenServer::new(Box::new(JsHandlerServer { handler }));
+        let actor_id = genserver.start(initial_state.into_bytes()).await?;
+
+        if let Some(name) = name {
+            get_or_init_actor_system().register(name, actor_id.clone())?;
         }
+
+        let id_str = actor_id.to_string();
+        self.actor_id = Some(id_str.clone());
+        Ok(id_str)
     }
     
+    /// Call the server and wait up to `timeout_ms` (default 5000) for its
+    /// reply. `from` is the calling actor's id, passed to the handler.
     #[napi]
-    pub async fn cast(&self, actor_id: String, request: Vec<u8>) -> Result<()> {
-        if let Some(system) = get_actor_system() {
-            let id = ActorId::from_string(actor_id);
-            system.cast(&id, request).await
-        } else {
-            Err(Error::from_reason("Actor system not initialized"))
-        }
+    pub async fn call(&self, message: String, timeout_ms: Option<u32>, from: Option<String>) -> Result<String> {
+        let actor_id = self.started_actor_id()?;
+        let timeout = Duration::from_millis(timeout_ms.unwrap_or(5000) as u64);
+        let reply = get_or_init_actor_system()
+            .call_from(&actor_id, from.map(ActorId::from_string), message.into_bytes(), timeout)
+            .await?;
+        String::from_utf8(reply).map_err(|e| Error::from_reason(e.to_string()))
+    }
+    
+    /// Fire-and-forget message to the server
+    #[napi]
+    pub async fn cast(&self, message: String) -> Result<()> {
+        let actor_id = self.started_actor_id()?;
+        get_or_init_actor_system().cast(&actor_id, message.into_bytes()).await.map(|_| ())
+    }
+
+    fn started_actor_id(&self) -> Result<ActorId> {
+        self.actor_id
+            .clone()
+            .map(ActorId::from_string)
+            .ok_or_else(|| Error::from_reason("GenServer not started"))
     }
     
     #[napi]
@@ -313,7 +424,7 @@ impl JsGenServer {
     pub async fn reset_counter(&self, counter_name: String) -> Result<()> {
         if let Some(system) = get_actor_system() {
             if let Some(actor_id) = system.whereis(&counter_name) {
-                system.cast(&actor_id, b"reset".to_vec()).await
+                system.cast(&actor_id, b"reset".to_vec()).await.map(|_| ())
             } else {
                 Err(Error::from_reason(format!("Counter {} not found", counter_name)))
             }
@@ -331,8 +442,85 @@ pub async fn create_genserver(behavior_type: String, args: Vec<u8>) -> Result<St
             let counter = CounterServer { name: "default".to_string() };
             let genserver = GenServer::new(Box::new(counter));
             let actor_id = genserver.start(args).await?;
-            Ok(format!("{:?}", actor_id))
+            Ok(actor_id.to_string())
         }
         _ => Err(Error::from_reason(format!("Unknown GenServer type: {}", behavior_type)))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Replies to `ping` and `whoami`, deliberately never replies to anything else
+    struct PingServer;
+
+    #[async_trait::async_trait]
+    impl GenServerBehavior for PingServer {
+        async fn init(&mut self, args: Vec<u8>) -> Result<Vec<u8>> {
+            Ok(args)
+        }
+
+        async fn handle_call(&mut self, request: Vec<u8>, from: Option<ActorId>, state: Vec<u8>) -> GenServerResponse {
+            if request == b"ping" {
+                GenServerResponse::Reply { value: b"pong".to_vec(), new_state: state }
+            } else if request == b"whoami" {
+                let caller = from.map(|from| from.to_string()).unwrap_or_default();
+                GenServerResponse::Reply { value: caller.into_bytes(), new_state: state }
+            } else {
+                GenServerResponse::NoReply { new_state: state }
+            }
+        }
+
+        async fn handle_cast(&mut self, _request: Vec<u8>, state: Vec<u8>) -> GenServerResponse {
+            GenServerResponse::NoReply { new_state: state }
+        }
+
+        async fn handle_info(&mut self, _info: Vec<u8>, state: Vec<u8>) -> GenServerResponse {
+            GenServerResponse::NoReply { new_state: state }
+        }
+
+        async fn terminate(&mut self, _reason: String, _state: Vec<u8>) {}
+    }
+
+    #[tokio::test]
+    async fn test_call_returns_reply() {
+        let system = get_or_init_actor_system();
+        let actor_id = GenServer::new(Box::new(PingServer)).start(Vec::new()).await.unwrap();
+
+        let reply = system.call(&actor_id, b"ping".to_vec(), Duration::from_secs(1)).await.unwrap();
+        assert_eq!(reply, b"pong");
+        assert_eq!(system.get_actor(&actor_id).unwrap().pending_call_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_call_times_out_without_leaking() {
+        let system = get_or_init_actor_system();
+        let actor_id = GenServer::new(Box::new(PingServer)).start(Vec::new()).await.unwrap();
+
+        let err = system.call(&actor_id, b"ignore".to_vec(), Duration::from_millis(50)).await.unwrap_err();
+        assert_eq!(err.status, Status::Cancelled);
+        assert_eq!(system.get_actor(&actor_id).unwrap().pending_call_count(), 0);
+
+        // The server is still responsive after a timed-out call
+        let reply = system.call(&actor_id, b"ping".to_vec(), Duration::from_secs(1)).await.unwrap();
+        assert_eq!(reply, b"pong");
+    }
+
+    #[tokio::test]
+    async fn test_call_passes_the_caller_not_the_server() {
+        let system = get_or_init_actor_system();
+        let actor_id = GenServer::new(Box::new(PingServer)).start(Vec::new()).await.unwrap();
+        let caller = ActorId::new();
+
+        let reply = system
+            .call_from(&actor_id, Some(caller.clone()), b"whoami".to_vec(), Duration::from_secs(1))
+            .await
+            .unwrap();
+        assert_eq!(reply, caller.to_string().into_bytes());
+
+        // Callers outside the actor system have no id to reply to
+        let reply = system.call(&actor_id, b"whoami".to_vec(), Duration::from_secs(1)).await.unwrap();
+        assert!(reply.is_empty());
+    }
+}
@@ -1,12 +1,10 @@
 #![deny(clippy::all)]
 
-#[cfg(feature = "mimalloc")]
-#[global_allocator]
-static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
-
 use napi_derive::napi;
 use napi::bindgen_prelude::*;
 use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
 
 // Core modules
 mod crossbeam;
@@ -20,6 +18,8 @@ mod flume;
 mod simd;
 mod thread_local;
 mod memory_pool;
+mod allocator;
+mod lifecycle;
 
 // Elixir/Phoenix-inspired modules
 mod actor;
@@ -41,16 +41,18 @@ pub use flume::*;
 pub use simd::*;
 pub use thread_local::*;
 pub use memory_pool::*;
+pub use allocator::{get_allocator_info, AllocatorInfo};
+pub use lifecycle::ShutdownSummary;
 
 // Re-export Elixir/Phoenix-inspired APIs
 pub use actor::{JsActorSystem, ActorId};
 pub use genserver::{JsGenServer, create_genserver};
 pub use supervisor::JsSupervisor;
 pub use pubsub::{JsPubSub, JsTopicChannel, create_topic_channel};
-pub use registry::{JsProcessRegistry, register_name, unregister_name, whereis_name};
-pub use channel::{JsChannelSystem, join_channel, leave_channel, broadcast_to_channel};
-pub use ets::{JsETSSystem, ets_new, ets_insert, ets_lookup};
-pub use presence::{JsPresenceSystem, presence_track, presence_untrack, presence_list};
+pub use registry::{JsProcessRegistry, register_name, unregister_name, whereis_name, list_names};
+pub use channel::{JsChannelSystem, JsChannelReply, join_channel, leave_channel, broadcast_to_channel, channel_presence_counts};
+pub use ets::{JsETSSystem, ets_new, ets_insert, ets_lookup, ets_insert_tuple, ets_match, ets_select, ets_first, ets_next};
+pub use presence::{JsPresenceSystem, presence_track, presence_untrack, presence_list, presence_state, presence_merge, presence_diff};
 
 #[napi]
 pub fn get_multithreading_info() -> String {
@@ -85,6 +87,8 @@ pub fn get_multithreading_info() -> String {
 
 #[napi]
 pub fn initialize_multithreading() -> napi::Result<String> {
+    MODULE_START.get_or_init(std::time::Instant::now);
+
     std::panic::set_hook(Box::new(|panic_info| {
         eprintln!("Rust panic in multithreading module: {:?}", panic_info);
     }));
@@ -114,8 +118,7 @@ pub struct SystemInfo {
 
 #[napi]
 pub struct MultithreadingManager {
-    rayon_pool: Option<RayonThreadPool>,
-    tokio_runtime: Option<TokioRuntime>,
+    pools: Arc<lifecycle::ManagedPools>,
 }
 
 #[napi]
@@ -123,47 +126,112 @@ impl MultithreadingManager {
     #[napi(constructor)]
     pub fn new() -> napi::Result<Self> {
         Ok(MultithreadingManager {
-            rayon_pool: None,
-            tokio_runtime: None,
+            pools: lifecycle::ManagedPools::new(),
         })
     }
 
     #[napi]
     pub fn initialize_rayon(&mut self, config: Option<RayonConfig>) -> napi::Result<()> {
-        self.rayon_pool = Some(RayonThreadPool::new(config));
+        self.pools.set_rayon_pool(RayonThreadPool::new(config).shared_pool());
         Ok(())
     }
 
     #[napi]
     pub fn initialize_tokio(&mut self, config: Option<TokioRuntimeConfig>) -> napi::Result<()> {
-        self.tokio_runtime = Some(TokioRuntime::new(config));
+        self.pools.set_tokio_runtime(TokioRuntime::new(config).shared_runtime());
         Ok(())
     }
 
     #[napi]
     pub fn get_rayon_pool(&self) -> bool {
-        self.rayon_pool.is_some()
+        self.pools.has_rayon_pool()
     }
 
     #[napi]
     pub fn get_tokio_runtime(&self) -> bool {
-        self.tokio_runtime.is_some()
+        self.pools.has_tokio_runtime()
     }
 
     #[napi]
     pub fn is_rayon_initialized(&self) -> bool {
-        self.rayon_pool.is_some()
+        self.pools.has_rayon_pool()
     }
 
     #[napi]
     pub fn is_tokio_initialized(&self) -> bool {
-        self.tokio_runtime.is_some()
+        self.pools.has_tokio_runtime()
+    }
+
+    /// False once shutdown has started, until a pool is initialized again
+    #[napi]
+    pub fn is_accepting(&self) -> bool {
+        self.pools.is_accepting()
+    }
+
+    /// Tasks spawned through this manager that haven't finished
+    #[napi]
+    pub fn pending_tasks(&self) -> u32 {
+        self.pools.pending_tasks() as u32
+    }
+
+    /// Map `data` with `operation` ("square", "double" or "increment") on
+    /// this manager's rayon pool, as a task `cleanup` waits for
+    #[napi]
+    pub async fn parallel_map(&self, data: Vec<i32>, operation: String) -> napi::Result<Vec<i32>> {
+        self.pools
+            .run_rayon(move || {
+                let _active = ActiveTaskGuard::start();
+                rayon::map_values(&data, &operation)
+            })
+            .await
+            .map_err(napi::Error::from_reason)?
     }
 
+    /// Resolve after `duration_ms` on this manager's tokio runtime, as a
+    /// task `cleanup` waits for and aborts once it times out
     #[napi]
-    pub fn cleanup(&mut self) {
-        self.rayon_pool = None;
-        self.tokio_runtime = None;
+    pub async fn delay(&self, duration_ms: u32) -> napi::Result<()> {
+        let duration = std::time::Duration::from_millis(duration_ms as u64);
+        self.pools
+            .run_tokio(async move {
+                let _active = ActiveTaskGuard::start();
+                ::tokio::time::sleep(duration).await;
+            })
+            .await
+            .map_err(napi::Error::from_reason)
+    }
+
+    /// Gracefully shut down: stop accepting work, wait up to `timeout_ms`
+    /// (default 5000) for in-flight tasks, then release both pools. New
+    /// work is refused right away; the promise resolves once the wait is
+    /// over. Initializing a pool again reopens the manager.
+    #[napi]
+    pub fn cleanup(&self, timeout_ms: Option<u32>) -> AsyncTask<ShutdownTask> {
+        let shutdown = lifecycle::PendingShutdown::begin(vec![Arc::clone(&self.pools)], shutdown_timeout(timeout_ms));
+        AsyncTask::new(ShutdownTask(Some(shutdown)))
+    }
+}
+
+fn shutdown_timeout(timeout_ms: Option<u32>) -> std::time::Duration {
+    timeout_ms
+        .map(|ms| std::time::Duration::from_millis(ms as u64))
+        .unwrap_or(lifecycle::DEFAULT_SHUTDOWN_TIMEOUT)
+}
+
+/// Waits out a shutdown on the libuv thread pool so the JS thread never
+/// blocks on in-flight tasks
+pub struct ShutdownTask(Option<lifecycle::PendingShutdown>);
+
+impl Task for ShutdownTask {
+    type Output = ShutdownSummary;
+    type JsValue = ShutdownSummary;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        Ok(self.0.take().map(lifecycle::PendingShutdown::wait).unwrap_or_default())
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
     }
 }
 
@@ -233,24 +301,11 @@ impl napi::Task for BenchmarkTask {
     type JsValue = BenchmarkResult;
 
     fn compute(&mut self) -> napi::Result<Self::Output> {
+        let _active = ActiveTaskGuard::start();
         let data: Vec<i32> = (0..self.data_size).map(|i| i as i32).collect();
         let start = std::time::Instant::now();
 
-        let result = match self.operation.as_str() {
-            "sequential_sum" => {
-                data.iter().sum::<i32>()
-            }
-            "parallel_sum" => {
-                data.iter().sum::<i32>()
-            }
-            "sequential_square" => {
-                data.iter().map(|x| x * x).sum::<i32>()
-            }
-            "parallel_square" => {
-                data.iter().map(|x| x * x).sum::<i32>()
-            }
-            _ => return Err(napi::Error::from_reason("Unknown benchmark operation")),
-        };
+        let result = run_benchmark_operation(&self.operation, &data)?;
 
         let duration = start.elapsed();
 
@@ -268,6 +323,28 @@ impl napi::Task for BenchmarkTask {
     }
 }
 
+fn run_benchmark_operation(operation: &str, data: &[i32]) -> napi::Result<i32> {
+    use ::rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+    let result = match operation {
+        "sequential_sum" => {
+            data.iter().sum::<i32>()
+        }
+        "parallel_sum" => {
+            data.par_iter().sum::<i32>()
+        }
+        "sequential_square" => {
+            data.iter().map(|x| x * x).sum::<i32>()
+        }
+        "parallel_square" => {
+            data.par_iter().map(|x| x * x).sum::<i32>()
+        }
+        _ => return Err(napi::Error::from_reason("Unknown benchmark operation")),
+    };
+
+    Ok(result)
+}
+
 #[napi(object)]
 pub struct BenchmarkResult {
     pub operation: String,
@@ -282,30 +359,60 @@ pub fn stress_test_concurrency(
     num_tasks: u32,
     task_duration_ms: u32,
     callback: ThreadsafeFunction<StressTestResult>,
-) -> napi::Result<()> {
-    use napi::threadsafe_function::*;
-    
+) -> napi::Result<StressTestHandle> {
     let tsfn = callback;
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let worker_cancelled = Arc::clone(&cancelled);
 
     std::thread::spawn(move || {
-        for i in 0..num_tasks {
-            let task_duration = std::time::Duration::from_millis(task_duration_ms as u64);
-            let start = std::time::Instant::now();
-            std::thread::sleep(task_duration);
-            let actual_duration = start.elapsed();
-            
-            let result = StressTestResult {
-                task_id: i,
-                duration_ms: actual_duration.as_millis() as u32,
-                thread_id: format!("{:?}", std::thread::current().id()),
-                status: "completed".to_string(),
-            };
-            
+        run_stress_test(num_tasks, task_duration_ms, &worker_cancelled, |result| {
             tsfn.call(Ok(result), ThreadsafeFunctionCallMode::Blocking);
-        }
+        });
     });
 
-    Ok(())
+    Ok(StressTestHandle { cancelled })
+}
+
+/// Runs stress tasks until done or cancelled, then reports a final summary
+/// with status `"finished"` or `"cancelled"`.
+fn run_stress_test(
+    num_tasks: u32,
+    task_duration_ms: u32,
+    cancelled: &AtomicBool,
+    mut report: impl FnMut(StressTestResult),
+) {
+    let task_duration = std::time::Duration::from_millis(task_duration_ms as u64);
+    let thread_id = format!("{:?}", std::thread::current().id());
+    let run_start = std::time::Instant::now();
+    let mut completed = 0;
+
+    for i in 0..num_tasks {
+        if cancelled.load(Ordering::SeqCst) {
+            break;
+        }
+
+        let start = std::time::Instant::now();
+        std::thread::sleep(task_duration);
+        let actual_duration = start.elapsed();
+        completed += 1;
+
+        report(StressTestResult {
+            task_id: i,
+            duration_ms: actual_duration.as_millis() as u32,
+            thread_id: thread_id.clone(),
+            status: "completed".to_string(),
+            completed_tasks: completed,
+        });
+    }
+
+    let status = if completed < num_tasks { "cancelled" } else { "finished" };
+    report(StressTestResult {
+        task_id: completed,
+        duration_ms: run_start.elapsed().as_millis() as u32,
+        thread_id,
+        status: status.to_string(),
+        completed_tasks: completed,
+    });
 }
 
 #[napi(object)]
@@ -314,6 +421,27 @@ pub struct StressTestResult {
     pub duration_ms: u32,
     pub thread_id: String,
     pub status: String,
+    pub completed_tasks: u32,
+}
+
+/// Cancellation handle for a running `stress_test_concurrency` worker
+#[napi]
+pub struct StressTestHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+#[napi]
+impl StressTestHandle {
+    /// Stop the worker before its next task; in-flight tasks finish first
+    #[napi]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    #[napi]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
 }
 
 #[napi]
@@ -339,21 +467,129 @@ pub struct PerformanceMetrics {
 }
 
 fn get_memory_usage_mb() -> u32 {
-    0
+    let Ok(pid) = sysinfo::get_current_pid() else {
+        return 0;
+    };
+
+    let mut system = sysinfo::System::new();
+    system.refresh_process(pid);
+    system
+        .process(pid)
+        .map(|process| (process.memory() / (1024 * 1024)) as u32)
+        .unwrap_or(0)
 }
 
+/// Captured on `initialize_multithreading`, or lazily on the first uptime query
+static MODULE_START: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+
 fn get_uptime_ms() -> f64 {
-    std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
+    MODULE_START
+        .get_or_init(std::time::Instant::now)
+        .elapsed()
         .as_millis() as f64
 }
 
+static ACTIVE_TASKS: AtomicU32 = AtomicU32::new(0);
+
+/// Marks a task spawned through the crate's rayon/tokio helpers as active
+/// until the guard is dropped.
+pub(crate) struct ActiveTaskGuard;
+
+impl ActiveTaskGuard {
+    pub(crate) fn start() -> Self {
+        ACTIVE_TASKS.fetch_add(1, Ordering::SeqCst);
+        ActiveTaskGuard
+    }
+}
+
+impl Drop for ActiveTaskGuard {
+    fn drop(&mut self) {
+        ACTIVE_TASKS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 fn get_active_task_count() -> u32 {
-    0
+    ACTIVE_TASKS.load(Ordering::SeqCst)
 }
 
+/// Gracefully shut down every live `MultithreadingManager`, sharing one
+/// `timeout_ms` (default 5000) between them
 #[napi]
-pub fn shutdown_multithreading() -> napi::Result<String> {
-    Ok("Multithreading module shutdown completed".to_string())
+pub fn shutdown_multithreading(timeout_ms: Option<u32>) -> AsyncTask<ShutdownTask> {
+    let shutdown = lifecycle::PendingShutdown::begin_all(shutdown_timeout(timeout_ms));
+    AsyncTask::new(ShutdownTask(Some(shutdown)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_benchmark_parallel_matches_sequential() {
+        let data: Vec<i32> = (0..1000).collect();
+
+        let sequential_sum = run_benchmark_operation("sequential_sum", &data).unwrap();
+        let parallel_sum = run_benchmark_operation("parallel_sum", &data).unwrap();
+        assert_eq!(sequential_sum, 499_500);
+        assert_eq!(parallel_sum, sequential_sum);
+
+        let sequential_square = run_benchmark_operation("sequential_square", &data).unwrap();
+        let parallel_square = run_benchmark_operation("parallel_square", &data).unwrap();
+        assert_eq!(sequential_square, 332_833_500);
+        assert_eq!(parallel_square, sequential_square);
+
+        assert!(run_benchmark_operation("parallel_product", &data).is_err());
+    }
+
+    #[test]
+    fn test_uptime_measures_since_module_init() {
+        initialize_multithreading().unwrap();
+        let before = get_uptime_ms();
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        let elapsed = get_uptime_ms() - before;
+
+        assert!(elapsed >= 50.0, "uptime advanced by {}ms", elapsed);
+        assert!(elapsed < 1000.0, "uptime advanced by {}ms", elapsed);
+        // Uptime since module start, not a wall-clock timestamp
+        assert!(get_uptime_ms() < 24.0 * 60.0 * 60.0 * 1000.0);
+    }
+
+    #[test]
+    fn test_stress_test_cancellation_stops_further_tasks() {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let canceller = Arc::clone(&cancelled);
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        let worker = std::thread::spawn(move || {
+            run_stress_test(100, 50, &cancelled, |result| tx.send(result).unwrap());
+        });
+
+        for _ in 0..3 {
+            assert_eq!(rx.recv().unwrap().status, "completed");
+        }
+        canceller.store(true, Ordering::SeqCst);
+        worker.join().unwrap();
+
+        let results: Vec<StressTestResult> = rx.try_iter().collect();
+        let summary = results.last().unwrap();
+        assert_eq!(summary.status, "cancelled");
+        assert!(summary.completed_tasks >= 3 && summary.completed_tasks < 100);
+        // Only the task in flight at cancellation time may still report
+        assert!(results.len() <= 2);
+    }
+
+    #[test]
+    fn test_stress_test_reports_finished_summary() {
+        let mut results = Vec::new();
+        run_stress_test(3, 1, &AtomicBool::new(false), |result| results.push(result));
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[3].status, "finished");
+        assert_eq!(results[3].completed_tasks, 3);
+    }
+
+    #[test]
+    fn test_memory_usage_reports_rss() {
+        assert!(get_performance_metrics().unwrap().memory_usage_mb > 0);
+    }
 }
@@ -0,0 +1,391 @@
+use napi_derive::napi;
+use rayon::ThreadPool;
+use std::future::Future;
+use std::sync::{Arc, Condvar, Mutex, Weak};
+use std::time::{Duration, Instant};
+use tokio::runtime::Runtime;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+/// How long shutdown waits for in-flight tasks when no timeout is given
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Every live set of managed pools, so `shutdown_multithreading` can reach
+/// managers it doesn't own
+static LIVE_POOLS: Mutex<Vec<Weak<ManagedPools>>> = Mutex::new(Vec::new());
+
+/// Outcome of a graceful shutdown. Tasks are counted from the moment
+/// shutdown starts: those that finish before the timeout are completed,
+/// the rest are abandoned. Abandoned tokio tasks are aborted; abandoned
+/// rayon jobs can't be interrupted and finish detached from the pool.
+#[napi(object)]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShutdownSummary {
+    pub completed_tasks: u32,
+    pub abandoned_tasks: u32,
+    pub timed_out: bool,
+    pub duration_ms: f64,
+}
+
+impl ShutdownSummary {
+    fn merge(&mut self, other: ShutdownSummary) {
+        self.completed_tasks += other.completed_tasks;
+        self.abandoned_tasks += other.abandoned_tasks;
+        self.timed_out |= other.timed_out;
+    }
+}
+
+/// Count of running tasks that shutdown can wait on
+#[derive(Default)]
+struct TaskCounter {
+    running: Mutex<usize>,
+    idle: Condvar,
+}
+
+impl TaskCounter {
+    fn start(self: &Arc<Self>) -> TaskGuard {
+        *self.running.lock().unwrap() += 1;
+        TaskGuard(Arc::clone(self))
+    }
+
+    fn running(&self) -> usize {
+        *self.running.lock().unwrap()
+    }
+
+    /// Block until no tasks run or `deadline` passes, returning how many
+    /// are still running
+    fn wait_idle(&self, deadline: Instant) -> usize {
+        let running = self.running.lock().unwrap();
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        let (running, _) = self.idle.wait_timeout_while(running, timeout, |running| *running > 0).unwrap();
+        *running
+    }
+}
+
+/// Held by a task for as long as it runs, including when it panics or is
+/// aborted
+struct TaskGuard(Arc<TaskCounter>);
+
+impl Drop for TaskGuard {
+    fn drop(&mut self) {
+        let mut running = self.0.running.lock().unwrap();
+        *running -= 1;
+        if *running == 0 {
+            self.0.idle.notify_all();
+        }
+    }
+}
+
+/// The rayon pool and tokio runtime behind a `MultithreadingManager`, with
+/// the tasks spawned onto them
+pub struct ManagedPools {
+    rayon_pool: Mutex<Option<Arc<ThreadPool>>>,
+    tokio_runtime: Mutex<Option<Arc<Runtime>>>,
+    tokio_tasks: Mutex<Vec<JoinHandle<()>>>,
+    tasks: Arc<TaskCounter>,
+    accepting: Mutex<bool>,
+}
+
+impl ManagedPools {
+    pub fn new() -> Arc<Self> {
+        let pools = Arc::new(ManagedPools {
+            rayon_pool: Mutex::new(None),
+            tokio_runtime: Mutex::new(None),
+            tokio_tasks: Mutex::new(Vec::new()),
+            tasks: Arc::default(),
+            accepting: Mutex::new(true),
+        });
+
+        let mut live = LIVE_POOLS.lock().unwrap();
+        live.retain(|pools| pools.strong_count() > 0);
+        live.push(Arc::downgrade(&pools));
+        pools
+    }
+
+    /// Install a rayon pool, reopening the pools if they were shut down
+    pub fn set_rayon_pool(&self, pool: Arc<ThreadPool>) {
+        *self.rayon_pool.lock().unwrap() = Some(pool);
+        *self.accepting.lock().unwrap() = true;
+    }
+
+    /// Install a tokio runtime, reopening the pools if they were shut down
+    pub fn set_tokio_runtime(&self, runtime: Arc<Runtime>) {
+        *self.tokio_runtime.lock().unwrap() = Some(runtime);
+        *self.accepting.lock().unwrap() = true;
+    }
+
+    pub fn has_rayon_pool(&self) -> bool {
+        self.rayon_pool.lock().unwrap().is_some()
+    }
+
+    pub fn has_tokio_runtime(&self) -> bool {
+        self.tokio_runtime.lock().unwrap().is_some()
+    }
+
+    pub fn is_accepting(&self) -> bool {
+        *self.accepting.lock().unwrap()
+    }
+
+    /// Tasks spawned through these pools that haven't finished
+    pub fn pending_tasks(&self) -> usize {
+        self.tasks.running()
+    }
+
+    /// Run `job` on the rayon pool
+    pub fn spawn_rayon<F: FnOnce() + Send + 'static>(&self, job: F) -> Result<(), String> {
+        // Holding the accepting lock keeps shutdown from starting between
+        // the check and the task being counted
+        let accepting = self.accepting.lock().unwrap();
+        if !*accepting {
+            return Err("Multithreading manager is shutting down".to_string());
+        }
+        let pool = self.rayon_pool.lock().unwrap();
+        let pool = pool.as_ref().ok_or("Rayon pool is not initialized")?;
+
+        let guard = self.tasks.start();
+        pool.spawn(move || {
+            let _guard = guard;
+            job();
+        });
+        Ok(())
+    }
+
+    /// Run `task` on the tokio runtime
+    pub fn spawn_tokio<F: Future<Output = ()> + Send + 'static>(&self, task: F) -> Result<(), String> {
+        let accepting = self.accepting.lock().unwrap();
+        if !*accepting {
+            return Err("Multithreading manager is shutting down".to_string());
+        }
+        let runtime = self.tokio_runtime.lock().unwrap();
+        let runtime = runtime.as_ref().ok_or("Tokio runtime is not initialized")?;
+
+        let guard = self.tasks.start();
+        let handle = runtime.spawn(async move {
+            let _guard = guard;
+            task.await;
+        });
+
+        let mut tasks = self.tokio_tasks.lock().unwrap();
+        tasks.retain(|task| !task.is_finished());
+        tasks.push(handle);
+        Ok(())
+    }
+
+    /// Run `job` on the rayon pool and wait for what it returns
+    pub async fn run_rayon<T, F>(&self, job: F) -> Result<T, String>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let (sender, receiver) = oneshot::channel();
+        self.spawn_rayon(move || {
+            let _ = sender.send(job());
+        })?;
+        receiver.await.map_err(|_| "Rayon job panicked".to_string())
+    }
+
+    /// Run `task` on the tokio runtime and wait for its output
+    pub async fn run_tokio<T, F>(&self, task: F) -> Result<T, String>
+    where
+        T: Send + 'static,
+        F: Future<Output = T> + Send + 'static,
+    {
+        let (sender, receiver) = oneshot::channel();
+        self.spawn_tokio(async move {
+            let _ = sender.send(task.await);
+        })?;
+        receiver
+            .await
+            .map_err(|_| "Task was aborted by shutdown".to_string())
+    }
+
+    /// Stop accepting work, returning how many tasks are still running
+    fn close(&self) -> usize {
+        let mut accepting = self.accepting.lock().unwrap();
+        *accepting = false;
+        self.tasks.running()
+    }
+
+    /// Wait until `deadline` for `pending` tasks, then abort what's left
+    /// and release both pools
+    fn drain(&self, pending: usize, deadline: Instant) -> ShutdownSummary {
+        let abandoned = self.tasks.wait_idle(deadline);
+        for task in self.tokio_tasks.lock().unwrap().drain(..) {
+            task.abort();
+        }
+
+        // Only the last owner shuts the runtime down; shutting down in the
+        // background keeps this safe to call from async contexts
+        if let Some(runtime) = self.tokio_runtime.lock().unwrap().take() {
+            if let Ok(runtime) = Arc::try_unwrap(runtime) {
+                runtime.shutdown_background();
+            }
+        }
+        self.rayon_pool.lock().unwrap().take();
+
+        ShutdownSummary {
+            completed_tasks: pending.saturating_sub(abandoned) as u32,
+            abandoned_tasks: abandoned as u32,
+            timed_out: abandoned > 0,
+            duration_ms: 0.0,
+        }
+    }
+}
+
+/// A shutdown that has already stopped its pools accepting work and still
+/// has to wait for their running tasks. Starting and waiting are split so
+/// the wait can run off the JS thread while new work is refused at once.
+pub struct PendingShutdown {
+    pools: Vec<(Arc<ManagedPools>, usize)>,
+    start: Instant,
+    deadline: Instant,
+}
+
+impl PendingShutdown {
+    /// Stop `pools` accepting work, giving their tasks `timeout` to finish
+    pub fn begin(pools: Vec<Arc<ManagedPools>>, timeout: Duration) -> Self {
+        let start = Instant::now();
+        PendingShutdown {
+            pools: pools
+                .into_iter()
+                .map(|pools| {
+                    let pending = pools.close();
+                    (pools, pending)
+                })
+                .collect(),
+            start,
+            deadline: start + timeout,
+        }
+    }
+
+    /// Stop every live set of managed pools within one shared timeout
+    pub fn begin_all(timeout: Duration) -> Self {
+        let live = LIVE_POOLS.lock().unwrap().iter().filter_map(Weak::upgrade).collect();
+        Self::begin(live, timeout)
+    }
+
+    /// Block until the tasks finish or the deadline passes, abort the rest
+    /// and release the pools
+    pub fn wait(self) -> ShutdownSummary {
+        let mut summary = ShutdownSummary::default();
+        for (pools, pending) in &self.pools {
+            summary.merge(pools.drain(*pending, self.deadline));
+        }
+        summary.duration_ms = self.start.elapsed().as_millis() as f64;
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn pools() -> Arc<ManagedPools> {
+        let pools = ManagedPools::new();
+        pools.set_rayon_pool(Arc::new(rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap()));
+        pools.set_tokio_runtime(Arc::new(
+            tokio::runtime::Builder::new_multi_thread().worker_threads(2).enable_all().build().unwrap(),
+        ));
+        pools
+    }
+
+    #[test]
+    fn test_shutdown_waits_for_pending_tasks() {
+        let pools = pools();
+        let finished = Arc::new(AtomicU32::new(0));
+        for _ in 0..3 {
+            let async_finished = Arc::clone(&finished);
+            pools
+                .spawn_tokio(async move {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    async_finished.fetch_add(1, Ordering::SeqCst);
+                })
+                .unwrap();
+            let job_finished = Arc::clone(&finished);
+            pools
+                .spawn_rayon(move || {
+                    std::thread::sleep(Duration::from_millis(50));
+                    job_finished.fetch_add(1, Ordering::SeqCst);
+                })
+                .unwrap();
+        }
+        assert_eq!(pools.pending_tasks(), 6);
+
+        let summary = PendingShutdown::begin(vec![Arc::clone(&pools)], Duration::from_secs(5)).wait();
+        assert_eq!(summary.completed_tasks, 6);
+        assert_eq!(summary.abandoned_tasks, 0);
+        assert!(!summary.timed_out);
+        assert_eq!(finished.load(Ordering::SeqCst), 6);
+
+        assert!(!pools.is_accepting());
+        assert!(!pools.has_rayon_pool() && !pools.has_tokio_runtime());
+        assert!(pools.spawn_rayon(|| {}).is_err());
+        assert!(pools.spawn_tokio(async {}).is_err());
+    }
+
+    #[test]
+    fn test_shutdown_reports_tasks_past_the_timeout_as_abandoned() {
+        let pools = pools();
+        let finished = Arc::new(AtomicU32::new(0));
+        pools
+            .spawn_tokio(async { tokio::time::sleep(Duration::from_millis(10)).await })
+            .unwrap();
+        let late = Arc::clone(&finished);
+        pools
+            .spawn_tokio(async move {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+                late.fetch_add(1, Ordering::SeqCst);
+            })
+            .unwrap();
+        pools.spawn_rayon(|| std::thread::sleep(Duration::from_millis(400))).unwrap();
+
+        let summary = PendingShutdown::begin(vec![Arc::clone(&pools)], Duration::from_millis(100)).wait();
+        assert_eq!(summary.completed_tasks, 1);
+        assert_eq!(summary.abandoned_tasks, 2);
+        assert!(summary.timed_out);
+        assert!(summary.duration_ms < 1000.0);
+
+        // The aborted tokio task never gets to run to completion
+        std::thread::sleep(Duration::from_millis(50));
+        assert_eq!(finished.load(Ordering::SeqCst), 0);
+        assert_eq!(pools.pending_tasks(), 1);
+    }
+
+    #[test]
+    fn test_work_is_refused_as_soon_as_shutdown_begins() {
+        let pools = pools();
+        pools.spawn_rayon(|| std::thread::sleep(Duration::from_millis(50))).unwrap();
+
+        let shutdown = PendingShutdown::begin(vec![Arc::clone(&pools)], Duration::from_secs(5));
+        assert!(!pools.is_accepting());
+        assert!(pools.spawn_rayon(|| {}).is_err());
+        assert_eq!(pools.pending_tasks(), 1);
+
+        let summary = shutdown.wait();
+        assert_eq!(summary.completed_tasks, 1);
+        assert_eq!(pools.pending_tasks(), 0);
+    }
+
+    #[test]
+    fn test_run_helpers_are_tracked_and_aborted_by_shutdown() {
+        let pools = pools();
+        let driver = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+
+        let sum = driver.block_on(pools.run_rayon(|| (1..=10).sum::<i32>())).unwrap();
+        assert_eq!(sum, 55);
+
+        let waiting = Arc::clone(&pools);
+        let slow = driver.spawn(async move {
+            waiting
+                .run_tokio(async { tokio::time::sleep(Duration::from_secs(10)).await })
+                .await
+        });
+        driver.block_on(async { tokio::time::sleep(Duration::from_millis(20)).await });
+        assert_eq!(pools.pending_tasks(), 1);
+
+        let summary = PendingShutdown::begin(vec![Arc::clone(&pools)], Duration::from_millis(50)).wait();
+        assert_eq!(summary.abandoned_tasks, 1);
+        assert!(driver.block_on(slow).unwrap().is_err());
+    }
+}
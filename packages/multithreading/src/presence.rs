@@ -25,7 +25,7 @@ use crate::pubsub::PubSubSystem;
 pub struct PresenceKey(pub String);
 
 /// Presence metadata for a single connection/session
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PresenceMeta {
     /// Unique connection ID
     pub connection_id: String,
@@ -42,7 +42,7 @@ pub struct PresenceMeta {
 }
 
 /// Connection quality information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConnectionQuality {
     pub latency_ms: Option<u32>,
     pub signal_strength: Option<f32>, // 0.0 to 1.0
@@ -167,10 +167,144 @@ impl Default for PresenceConfig {
     }
 }
 
+/// Unique tag for one tracked connection: the node that added it and that
+/// node's logical clock at the time. Dots are never reused, so a removal is
+/// recognised on other nodes once their causal context covers the dot.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Dot {
+    pub node_id: String,
+    pub clock: u64,
+}
+
+/// A single tracked connection inside the presence CRDT
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PresenceEntry {
+    pub dot: Dot,
+    pub topic: Topic,
+    pub key: PresenceKey,
+    pub meta: PresenceMeta,
+}
+
+/// Observed-remove set of presence entries (the model behind Phoenix.Tracker).
+///
+/// After a merge an entry survives if both sides hold it, or if one side holds
+/// it and the other side's context has not observed its dot yet. This makes
+/// `merge` commutative, associative and idempotent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "PresenceCrdtWire", into = "PresenceCrdtWire")]
+pub struct PresenceCrdt {
+    /// Live entries by dot
+    entries: BTreeMap<Dot, PresenceEntry>,
+    /// Causal context: highest clock observed per node
+    context: BTreeMap<String, u64>,
+}
+
+/// Serialized form of `PresenceCrdt` (JSON objects need string keys)
+#[derive(Serialize, Deserialize)]
+struct PresenceCrdtWire {
+    entries: Vec<PresenceEntry>,
+    context: BTreeMap<String, u64>,
+}
+
+impl From<PresenceCrdtWire> for PresenceCrdt {
+    fn from(wire: PresenceCrdtWire) -> Self {
+        Self {
+            entries: wire.entries.into_iter().map(|entry| (entry.dot.clone(), entry)).collect(),
+            context: wire.context,
+        }
+    }
+}
+
+impl From<PresenceCrdt> for PresenceCrdtWire {
+    fn from(crdt: PresenceCrdt) -> Self {
+        Self {
+            entries: crdt.entries.into_values().collect(),
+            context: crdt.context,
+        }
+    }
+}
+
+impl PresenceCrdt {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether this replica has already seen `dot` (added or removed)
+    fn observed(&self, dot: &Dot) -> bool {
+        self.context.get(&dot.node_id).map_or(false, |clock| *clock >= dot.clock)
+    }
+
+    /// Add an entry under a fresh dot from `node_id`
+    pub fn add(&mut self, node_id: &str, topic: Topic, key: PresenceKey, meta: PresenceMeta) -> Dot {
+        let clock = self.context.entry(node_id.to_string()).or_insert(0);
+        *clock += 1;
+
+        let dot = Dot {
+            node_id: node_id.to_string(),
+            clock: *clock,
+        };
+        self.entries.insert(dot.clone(), PresenceEntry { dot: dot.clone(), topic, key, meta });
+        dot
+    }
+
+    /// Remove an entry. Its dot stays in the context so merges cannot revive it.
+    pub fn remove(&mut self, dot: &Dot) -> Option<PresenceEntry> {
+        self.entries.remove(dot)
+    }
+
+    /// Merge a remote replica into this one
+    pub fn merge(&mut self, other: &PresenceCrdt) {
+        // Drop local entries the other side has seen and removed
+        self.entries.retain(|dot, _| other.entries.contains_key(dot) || !other.observed(dot));
+
+        for (dot, entry) in &other.entries {
+            match self.entries.get_mut(dot) {
+                // Heartbeats are the only part of an entry that changes in place
+                Some(local) => {
+                    local.meta.last_heartbeat = local.meta.last_heartbeat.max(entry.meta.last_heartbeat);
+                },
+                None if !self.observed(dot) => {
+                    self.entries.insert(dot.clone(), entry.clone());
+                },
+                None => {},
+            }
+        }
+
+        for (node_id, clock) in &other.context {
+            let local = self.context.entry(node_id.clone()).or_insert(0);
+            *local = (*local).max(*clock);
+        }
+    }
+
+    /// Entries for a topic, in dot order
+    pub fn entries<'a>(&'a self, topic: &'a Topic) -> impl Iterator<Item = &'a PresenceEntry> + 'a {
+        self.entries.values().filter(move |entry| &entry.topic == topic)
+    }
+
+    /// Find the entry for a connection
+    pub fn find(&self, topic: &Topic, key: &PresenceKey, connection_id: &str) -> Option<&PresenceEntry> {
+        self.entries(topic)
+            .find(|entry| &entry.key == key && entry.meta.connection_id == connection_id)
+    }
+
+    /// All topics with at least one entry
+    pub fn topics(&self) -> HashSet<Topic> {
+        self.entries.values().map(|entry| entry.topic.clone()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
 /// Phoenix Presence system
 pub struct PresenceSystem {
-    /// Topic -> Presence state mapping
-    presence_state: Arc<RwLock<HashMap<Topic, HashMap<PresenceKey, PresenceState>>>>,
+    /// Replicated presence state for every topic
+    state: Arc<RwLock<PresenceCrdt>>,
     /// Configuration
     config: PresenceConfig,
     /// Channel system integration
@@ -182,7 +316,7 @@ pub struct PresenceSystem {
 }
 
 /// Presence statistics
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct PresenceStats {
     pub total_topics: usize,
     pub total_users: usize,
@@ -198,7 +332,7 @@ impl PresenceSystem {
     /// Create new presence system
     pub fn new(config: PresenceConfig) -> Self {
         Self {
-            presence_state: Arc::new(RwLock::new(HashMap::new())),
+            state: Arc::new(RwLock::new(PresenceCrdt::new())),
             config,
             channel_system: None,
             pubsub: None,
@@ -220,226 +354,189 @@ impl PresenceSystem {
 
     /// Track user presence in a topic
     pub fn track(&self, topic: &Topic, key: PresenceKey, connection_id: String, metadata: HashMap<String, serde_json::Value>) -> Result<PresenceDiff, String> {
-        let mut state = self.presence_state.write().unwrap();
-        let topic_state = state.entry(topic.clone()).or_insert_with(HashMap::new);
+        let mut state = self.state.write().unwrap();
+        let before = self.presence_of(&state, topic, &key);
 
-        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        // Re-tracking a connection replaces its previous entry
+        if let Some(dot) = state.find(topic, &key, &connection_id).map(|entry| entry.dot.clone()) {
+            state.remove(&dot);
+        }
 
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
         let meta = PresenceMeta {
-            connection_id: connection_id.clone(),
+            connection_id,
             node_id: self.config.node_id.clone(),
             online_at: now,
-            metadata: metadata.clone(),
+            metadata,
             last_heartbeat: now,
             connection_quality: ConnectionQuality::default(),
         };
+        state.add(&self.config.node_id, topic.clone(), key.clone(), meta);
 
         let mut diff = PresenceDiff::new();
-
-        if let Some(presence_state) = topic_state.get_mut(&key) {
-            // User already present, add new connection
-            let was_first_connection = presence_state.connections.is_empty();
-            presence_state.connections.insert(connection_id.clone(), meta);
-            
-            // Update merged metadata
-            presence_state.merged_metadata = self.merge_metadata(&presence_state.merged_metadata, &metadata);
-            presence_state.updated_at = now;
-
-            if was_first_connection {
-                // First connection for this user
-                presence_state.first_joined_at = now;
-                diff.joins.insert(key.clone(), presence_state.clone());
-                
-                let mut stats = self.stats.write().unwrap();
-                stats.join_events += 1;
-            } else {
-                // Additional connection or metadata update
-                diff.updates.insert(key.clone(), presence_state.clone());
-                
-                let mut stats = self.stats.write().unwrap();
-                stats.update_events += 1;
-            }
-        } else {
-            // New user presence
-            let new_state = PresenceState {
-                key: key.clone(),
-                connections: {
-                    let mut connections = HashMap::new();
-                    connections.insert(connection_id, meta);
-                    connections
-                },
-                merged_metadata: metadata,
-                first_joined_at: now,
-                updated_at: now,
-            };
-
-            topic_state.insert(key.clone(), new_state.clone());
-            diff.joins.insert(key, new_state);
-
-            let mut stats = self.stats.write().unwrap();
+        let after = self.presence_of(&state, topic, &key).expect("entry was just added");
+        let mut stats = self.stats.write().unwrap();
+        if before.is_none() {
+            diff.joins.insert(key, after);
             stats.join_events += 1;
-            stats.total_users = self.count_unique_users();
-        }
-
-        // Update stats
-        {
-            let mut stats = self.stats.write().unwrap();
-            stats.total_topics = state.len();
-            stats.total_connections = self.count_total_connections(&state);
+        } else {
+            // Additional connection or metadata update
+            diff.updates.insert(key, after);
+            stats.update_events += 1;
         }
+        Self::refresh_stats(&mut stats, &state);
 
         Ok(diff)
     }
 
     /// Untrack user presence (remove connection)
     pub fn untrack(&self, topic: &Topic, key: &PresenceKey, connection_id: &str) -> Result<PresenceDiff, String> {
-        let mut state = self.presence_state.write().unwrap();
-        let mut diff = PresenceDiff::new();
+        let mut state = self.state.write().unwrap();
+        let dot = state.find(topic, key, connection_id)
+            .map(|entry| entry.dot.clone())
+            .ok_or_else(|| format!("Connection {} for user {:?} not found in topic {:?}", connection_id, key, topic))?;
 
-        if let Some(topic_state) = state.get_mut(topic) {
-            if let Some(presence_state) = topic_state.get_mut(key) {
-                if presence_state.connections.remove(connection_id).is_some() {
-                    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
-                    presence_state.updated_at = now;
-
-                    if presence_state.connections.is_empty() {
-                        // Last connection, user is leaving
-                        let leaving_state = presence_state.clone();
-                        topic_state.remove(key);
-                        diff.leaves.insert(key.clone(), leaving_state);
-
-                        // Clean up empty topics
-                        if topic_state.is_empty() {
-                            state.remove(topic);
-                        }
-                    } else {
-                        // Still has other connections, just update
-                        // Recompute merged metadata from remaining connections
-                        presence_state.merged_metadata = self.recompute_merged_metadata(&presence_state.connections);
-                        diff.updates.insert(key.clone(), presence_state.clone());
-                    }
-
-                    let mut stats = self.stats.write().unwrap();
-                    stats.leave_events += 1;
-                    stats.total_users = self.count_unique_users();
-                    stats.total_connections = self.count_total_connections(&state);
-                    
-                    return Ok(diff);
-                }
-            }
+        let before = self.presence_of(&state, topic, key);
+        state.remove(&dot);
+
+        let mut diff = PresenceDiff::new();
+        match self.presence_of(&state, topic, key) {
+            // Still has other connections, just update
+            Some(after) => {
+                diff.updates.insert(key.clone(), after);
+            },
+            // Last connection, user is leaving
+            None => {
+                diff.leaves.insert(key.clone(), before.expect("connection was present"));
+            },
         }
 
-        Err(format!("Connection {} for user {:?} not found in topic {:?}", connection_id, key, topic))
+        let mut stats = self.stats.write().unwrap();
+        stats.leave_events += 1;
+        Self::refresh_stats(&mut stats, &state);
+
+        Ok(diff)
     }
 
     /// Update presence metadata for a connection
     pub fn update(&self, topic: &Topic, key: &PresenceKey, connection_id: &str, metadata: HashMap<String, serde_json::Value>) -> Result<PresenceDiff, String> {
-        let mut state = self.presence_state.write().unwrap();
-        let mut diff = PresenceDiff::new();
-
-        if let Some(topic_state) = state.get_mut(topic) {
-            if let Some(presence_state) = topic_state.get_mut(key) {
-                if let Some(connection_meta) = presence_state.connections.get_mut(connection_id) {
-                    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
-                    
-                    connection_meta.metadata = metadata.clone();
-                    connection_meta.last_heartbeat = now;
-                    presence_state.updated_at = now;
-
-                    // Update merged metadata
-                    presence_state.merged_metadata = self.recompute_merged_metadata(&presence_state.connections);
+        let mut state = self.state.write().unwrap();
+        let entry = state.find(topic, key, connection_id)
+            .cloned()
+            .ok_or_else(|| format!("Connection {} for user {:?} not found in topic {:?}", connection_id, key, topic))?;
 
-                    diff.updates.insert(key.clone(), presence_state.clone());
+        // Entries are immutable in the CRDT, so an update is a remove plus a re-add
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        let meta = PresenceMeta {
+            metadata,
+            last_heartbeat: now,
+            node_id: self.config.node_id.clone(),
+            ..entry.meta
+        };
+        state.remove(&entry.dot);
+        state.add(&self.config.node_id, topic.clone(), key.clone(), meta);
 
-                    let mut stats = self.stats.write().unwrap();
-                    stats.update_events += 1;
+        let mut diff = PresenceDiff::new();
+        diff.updates.insert(key.clone(), self.presence_of(&state, topic, key).expect("entry was just added"));
 
-                    return Ok(diff);
-                }
-            }
-        }
+        let mut stats = self.stats.write().unwrap();
+        stats.update_events += 1;
 
-        Err(format!("Connection {} for user {:?} not found in topic {:?}", connection_id, key, topic))
+        Ok(diff)
     }
 
     /// Heartbeat to keep connection alive
     pub fn heartbeat(&self, topic: &Topic, key: &PresenceKey, connection_id: &str) -> Result<(), String> {
-        let mut state = self.presence_state.write().unwrap();
-
-        if let Some(topic_state) = state.get_mut(topic) {
-            if let Some(presence_state) = topic_state.get_mut(key) {
-                if let Some(connection_meta) = presence_state.connections.get_mut(connection_id) {
-                    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
-                    connection_meta.last_heartbeat = now;
-                    return Ok(());
-                }
-            }
-        }
+        let mut state = self.state.write().unwrap();
+        let dot = state.find(topic, key, connection_id)
+            .map(|entry| entry.dot.clone())
+            .ok_or_else(|| format!("Connection {} for user {:?} not found in topic {:?}", connection_id, key, topic))?;
 
-        Err(format!("Connection {} for user {:?} not found in topic {:?}", connection_id, key, topic))
+        if let Some(entry) = state.entries.get_mut(&dot) {
+            entry.meta.last_heartbeat = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        }
+        Ok(())
     }
 
     /// Get current presence state for a topic
     pub fn list(&self, topic: &Topic) -> HashMap<PresenceKey, PresenceState> {
-        let state = self.presence_state.read().unwrap();
-        state.get(topic).cloned().unwrap_or_default()
+        let state = self.state.read().unwrap();
+        self.topic_view(state.entries(topic))
     }
 
     /// Get presence state for specific user
     pub fn get(&self, topic: &Topic, key: &PresenceKey) -> Option<PresenceState> {
-        let state = self.presence_state.read().unwrap();
-        state.get(topic)?.get(key).cloned()
+        let state = self.state.read().unwrap();
+        self.presence_of(&state, topic, key)
+    }
+
+    /// Snapshot of the replicated state, for sending to other nodes
+    pub fn snapshot(&self) -> PresenceCrdt {
+        self.state.read().unwrap().clone()
+    }
+
+    /// Merge presence state from another node, returning the joins and
+    /// leaves it caused per topic
+    pub fn merge(&self, remote: &PresenceCrdt) -> HashMap<Topic, PresenceDiff> {
+        let mut state = self.state.write().unwrap();
+        let prior = state.clone();
+        state.merge(remote);
+
+        let mut topics = prior.topics();
+        topics.extend(state.topics());
+
+        let diffs: HashMap<Topic, PresenceDiff> = topics.into_iter()
+            .map(|topic| {
+                let diff = self.diff_between(&prior, &state, &topic);
+                (topic, diff)
+            })
+            .filter(|(_, diff)| !diff.is_empty())
+            .collect();
+
+        let mut stats = self.stats.write().unwrap();
+        for diff in diffs.values() {
+            stats.join_events += diff.joins.len() as u64;
+            stats.leave_events += diff.leaves.len() as u64;
+        }
+        Self::refresh_stats(&mut stats, &state);
+
+        diffs
+    }
+
+    /// Joins and leaves in a topic since `prior` (like `Phoenix.Presence` diffs:
+    /// joins hold the connections added, leaves the connections removed)
+    pub fn diff(&self, topic: &Topic, prior: &PresenceCrdt) -> PresenceDiff {
+        let state = self.state.read().unwrap();
+        self.diff_between(prior, &state, topic)
     }
 
     /// Clean up stale connections
     pub fn cleanup_stale_connections(&self) -> u64 {
-        let mut state = self.presence_state.write().unwrap();
+        let mut state = self.state.write().unwrap();
         let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
         let timeout_ms = self.config.connection_timeout.as_millis() as u64;
-        let mut removed_count = 0;
 
-        let topics_to_remove: Vec<Topic> = Vec::new();
-        
-        for (topic, topic_state) in state.iter_mut() {
-            let users_to_remove: Vec<PresenceKey> = Vec::new();
-
-            for (user_key, presence_state) in topic_state.iter_mut() {
-                let connections_to_remove: Vec<String> = presence_state
-                    .connections
-                    .iter()
-                    .filter(|(_, meta)| now - meta.last_heartbeat > timeout_ms)
-                    .map(|(conn_id, _)| conn_id.clone())
-                    .collect();
-
-                for conn_id in connections_to_remove {
-                    presence_state.connections.remove(&conn_id);
-                    removed_count += 1;
-                }
-
-                // Recompute merged metadata if connections remain
-                if !presence_state.connections.is_empty() {
-                    presence_state.merged_metadata = self.recompute_merged_metadata(&presence_state.connections);
-                }
-            }
-
-            // Remove users with no connections
-            topic_state.retain(|_, presence_state| !presence_state.connections.is_empty());
+        let stale: Vec<Dot> = state.entries.values()
+            .filter(|entry| now.saturating_sub(entry.meta.last_heartbeat) > timeout_ms)
+            .map(|entry| entry.dot.clone())
+            .collect();
+        for dot in &stale {
+            state.remove(dot);
         }
-
-        // Remove empty topics
-        state.retain(|_, topic_state| !topic_state.is_empty());
+        let removed_count = stale.len() as u64;
 
         let mut stats = self.stats.write().unwrap();
         stats.cleanup_runs += 1;
         stats.stale_connections_removed += removed_count;
+        Self::refresh_stats(&mut stats, &state);
 
         removed_count
     }
 
     /// Get all topics with presence
     pub fn topics(&self) -> Vec<Topic> {
-        let state = self.presence_state.read().unwrap();
-        state.keys().cloned().collect()
+        let state = self.state.read().unwrap();
+        state.topics().into_iter().collect()
     }
 
     /// Get system statistics
@@ -464,31 +561,66 @@ impl PresenceSystem {
         }
     }
 
-    /// Helper: recompute merged metadata from all connections
-    fn recompute_merged_metadata(&self, connections: &HashMap<String, PresenceMeta>) -> HashMap<String, serde_json::Value> {
-        let mut merged = HashMap::new();
+    /// Helper: aggregate a user's connections into a presence state. Metadata
+    /// is merged in join order so every node computes the same result.
+    fn build_presence(&self, key: PresenceKey, mut entries: Vec<&PresenceEntry>) -> PresenceState {
+        entries.sort_by(|a, b| (a.meta.online_at, &a.dot).cmp(&(b.meta.online_at, &b.dot)));
+
+        let mut merged_metadata = HashMap::new();
+        for entry in &entries {
+            merged_metadata = self.merge_metadata(&merged_metadata, &entry.meta.metadata);
+        }
 
-        for connection in connections.values() {
-            merged = self.merge_metadata(&merged, &connection.metadata);
+        PresenceState {
+            key,
+            first_joined_at: entries.iter().map(|entry| entry.meta.online_at).min().unwrap_or(0),
+            updated_at: entries.iter().map(|entry| entry.meta.last_heartbeat).max().unwrap_or(0),
+            connections: entries.into_iter()
+                .map(|entry| (entry.meta.connection_id.clone(), entry.meta.clone()))
+                .collect(),
+            merged_metadata,
         }
+    }
 
-        merged
+    /// Helper: group entries by user
+    fn topic_view<'a>(&self, entries: impl Iterator<Item = &'a PresenceEntry>) -> HashMap<PresenceKey, PresenceState> {
+        let mut by_key: HashMap<PresenceKey, Vec<&PresenceEntry>> = HashMap::new();
+        for entry in entries {
+            by_key.entry(entry.key.clone()).or_default().push(entry);
+        }
+
+        by_key.into_iter()
+            .map(|(key, entries)| (key.clone(), self.build_presence(key, entries)))
+            .collect()
     }
 
-    /// Helper: count unique users across all topics
-    fn count_unique_users(&self) -> usize {
-        let state = self.presence_state.read().unwrap();
-        state.values()
-            .map(|topic_state| topic_state.len())
-            .sum()
+    /// Helper: presence state of one user in a topic
+    fn presence_of(&self, state: &PresenceCrdt, topic: &Topic, key: &PresenceKey) -> Option<PresenceState> {
+        let entries: Vec<&PresenceEntry> = state.entries(topic).filter(|entry| &entry.key == key).collect();
+        if entries.is_empty() {
+            None
+        } else {
+            Some(self.build_presence(key.clone(), entries))
+        }
     }
 
-    /// Helper: count total connections across all topics
-    fn count_total_connections(&self, state: &HashMap<Topic, HashMap<PresenceKey, PresenceState>>) -> usize {
-        state.values()
-            .flat_map(|topic_state| topic_state.values())
-            .map(|presence_state| presence_state.connections.len())
-            .sum()
+    /// Helper: connections added and removed in a topic between two states
+    fn diff_between(&self, prior: &PresenceCrdt, current: &PresenceCrdt, topic: &Topic) -> PresenceDiff {
+        let mut diff = PresenceDiff::new();
+        diff.joins = self.topic_view(current.entries(topic).filter(|entry| !prior.entries.contains_key(&entry.dot)));
+        diff.leaves = self.topic_view(prior.entries(topic).filter(|entry| !current.entries.contains_key(&entry.dot)));
+        diff
+    }
+
+    /// Helper: recount users, connections and topics
+    fn refresh_stats(stats: &mut PresenceStats, state: &PresenceCrdt) {
+        let users: HashSet<(&Topic, &PresenceKey)> = state.entries.values()
+            .map(|entry| (&entry.topic, &entry.key))
+            .collect();
+
+        stats.total_topics = state.topics().len();
+        stats.total_users = users.len();
+        stats.total_connections = state.len();
     }
 
     /// Start background cleanup task
@@ -510,7 +642,7 @@ impl PresenceSystem {
 impl Clone for PresenceSystem {
     fn clone(&self) -> Self {
         Self {
-            presence_state: Arc::clone(&self.presence_state),
+            state: Arc::clone(&self.state),
             config: self.config.clone(),
             channel_system: self.channel_system.clone(),
             pubsub: self.pubsub.clone(),
@@ -717,6 +849,36 @@ pub fn presence_list(topic: String) -> Vec<String> {
         .collect()
 }
 
+fn parse_presence_state(state: &str) -> Result<PresenceCrdt> {
+    serde_json::from_str(state)
+        .map_err(|e| napi::Error::new(Status::InvalidArg, format!("Invalid presence state: {}", e)))
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String> {
+    serde_json::to_string(value)
+        .map_err(|e| napi::Error::from_reason(format!("Failed to serialize presence: {}", e)))
+}
+
+/// Serialized CRDT state of this node, for `presence_merge` on other nodes
+#[napi]
+pub fn presence_state() -> Result<String> {
+    to_json(&global_presence().snapshot())
+}
+
+/// Merge a remote node's state; returns the resulting joins/leaves per topic
+#[napi]
+pub fn presence_merge(remote_state: String) -> Result<String> {
+    let remote = parse_presence_state(&remote_state)?;
+    to_json(&global_presence().merge(&remote))
+}
+
+/// Joins/leaves in a topic since a state previously returned by `presence_state`
+#[napi]
+pub fn presence_diff(topic: String, prior_state: String) -> Result<String> {
+    let prior = parse_presence_state(&prior_state)?;
+    to_json(&global_presence().diff(&Topic(topic), &prior))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -778,4 +940,79 @@ mod tests {
         let diff = system.untrack(&topic, &user_key, "conn_2").unwrap();
         assert_eq!(diff.leaves.len(), 1);
     }
-}
\ No newline at end of file
+
+    fn node(node_id: &str) -> PresenceSystem {
+        PresenceSystem::new(PresenceConfig {
+            node_id: node_id.to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn merged(a: &PresenceCrdt, b: &PresenceCrdt) -> PresenceCrdt {
+        let mut result = a.clone();
+        result.merge(b);
+        result
+    }
+
+    #[test]
+    fn test_presence_merge_converges() {
+        let topic = Topic("room:1".to_string());
+        let node_a = node("a");
+        let node_b = node("b");
+        let node_c = node("c");
+
+        node_a.track(&topic, PresenceKey("alice".to_string()), "conn_1".to_string(), HashMap::new()).unwrap();
+        node_a.track(&topic, PresenceKey("bob".to_string()), "conn_2".to_string(), HashMap::new()).unwrap();
+        node_b.track(&topic, PresenceKey("carol".to_string()), "conn_3".to_string(), HashMap::new()).unwrap();
+
+        // Node c has seen bob and then observed him leave
+        node_c.merge(&node_a.snapshot());
+        node_c.untrack(&topic, &PresenceKey("bob".to_string()), "conn_2").unwrap();
+        node_c.track(&topic, PresenceKey("dave".to_string()), "conn_4".to_string(), HashMap::new()).unwrap();
+
+        let (a, b, c) = (node_a.snapshot(), node_b.snapshot(), node_c.snapshot());
+
+        // Commutative, associative and idempotent
+        assert_eq!(merged(&a, &b), merged(&b, &a));
+        assert_eq!(merged(&merged(&a, &b), &c), merged(&a, &merged(&b, &c)));
+        assert_eq!(merged(&a, &a), a);
+        let all = merged(&merged(&a, &b), &c);
+        assert_eq!(merged(&all, &b), all);
+
+        // The observed removal wins over the stale copy held by node a
+        let mut keys: Vec<String> = all.entries(&topic).map(|entry| entry.key.0.clone()).collect();
+        keys.sort();
+        assert_eq!(keys, vec!["alice", "carol", "dave"]);
+
+        // Round-trips through the JSON wire format
+        let json = serde_json::to_string(&all).unwrap();
+        assert_eq!(serde_json::from_str::<PresenceCrdt>(&json).unwrap(), all);
+    }
+
+    #[test]
+    fn test_presence_merge_and_diff() {
+        let topic = Topic("room:1".to_string());
+        let node_a = node("a");
+        let node_b = node("b");
+
+        node_a.track(&topic, PresenceKey("alice".to_string()), "conn_1".to_string(), HashMap::new()).unwrap();
+        let prior = node_a.snapshot();
+
+        node_b.track(&topic, PresenceKey("bob".to_string()), "conn_2".to_string(), HashMap::new()).unwrap();
+        let diffs = node_a.merge(&node_b.snapshot());
+        assert_eq!(diffs[&topic].joins.len(), 1);
+        assert!(diffs[&topic].joins.contains_key(&PresenceKey("bob".to_string())));
+        assert!(diffs[&topic].leaves.is_empty());
+
+        // Merging the same state again changes nothing
+        assert!(node_a.merge(&node_b.snapshot()).is_empty());
+
+        node_a.untrack(&topic, &PresenceKey("alice".to_string()), "conn_1").unwrap();
+        let diff = node_a.diff(&topic, &prior);
+        assert_eq!(diff.joins.keys().collect::<Vec<_>>(), vec![&PresenceKey("bob".to_string())]);
+        assert_eq!(diff.leaves.keys().collect::<Vec<_>>(), vec![&PresenceKey("alice".to_string())]);
+
+        let bob = node_a.get(&topic, &PresenceKey("bob".to_string())).unwrap();
+        assert_eq!(bob.connections["conn_2"].node_id, "b");
+    }
+}
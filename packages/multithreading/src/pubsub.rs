@@ -30,6 +30,92 @@ struct Subscription {
     sender: Sender<PubSubMessage>,
 }
 
+// Topic trie for wildcard subscriptions. Topics are dot-delimited; `*` matches
+// exactly one segment and `#` (or the legacy `**`) matches zero or more.
+#[derive(Default)]
+struct TopicTrie {
+    root: TrieNode,
+}
+
+#[derive(Default)]
+struct TrieNode {
+    children: HashMap<String, TrieNode>,
+    subscriptions: HashSet<String>,
+}
+
+impl TrieNode {
+    fn is_empty(&self) -> bool {
+        self.children.is_empty() && self.subscriptions.is_empty()
+    }
+}
+
+impl TopicTrie {
+    fn normalize(segment: &str) -> &str {
+        if segment == "**" { "#" } else { segment }
+    }
+
+    fn is_pattern(topic: &str) -> bool {
+        topic.split('.').any(|segment| matches!(segment, "*" | "#" | "**"))
+    }
+
+    fn insert(&mut self, pattern: &str, subscription_id: String) {
+        let mut node = &mut self.root;
+        for segment in pattern.split('.') {
+            node = node.children.entry(Self::normalize(segment).to_string()).or_default();
+        }
+        node.subscriptions.insert(subscription_id);
+    }
+
+    fn remove(&mut self, pattern: &str, subscription_id: &str) -> bool {
+        let segments: Vec<&str> = pattern.split('.').map(Self::normalize).collect();
+        Self::remove_from(&mut self.root, &segments, subscription_id)
+    }
+
+    fn remove_from(node: &mut TrieNode, segments: &[&str], subscription_id: &str) -> bool {
+        let Some((first, rest)) = segments.split_first() else {
+            return node.subscriptions.remove(subscription_id);
+        };
+
+        let Some(child) = node.children.get_mut(*first) else {
+            return false;
+        };
+        let removed = Self::remove_from(child, rest, subscription_id);
+        if child.is_empty() {
+            node.children.remove(*first);
+        }
+        removed
+    }
+
+    /// Returns the ids of every subscription whose pattern matches `topic`.
+    fn matches(&self, topic: &str) -> HashSet<String> {
+        let segments: Vec<&str> = topic.split('.').collect();
+        let mut matched = HashSet::new();
+        Self::collect(&self.root, &segments, &mut matched);
+        matched
+    }
+
+    fn collect(node: &TrieNode, segments: &[&str], matched: &mut HashSet<String>) {
+        // `#` may swallow any number of the remaining segments, including none
+        if let Some(multi) = node.children.get("#") {
+            for skip in 0..=segments.len() {
+                Self::collect(multi, &segments[skip..], matched);
+            }
+        }
+
+        let Some((first, rest)) = segments.split_first() else {
+            matched.extend(node.subscriptions.iter().cloned());
+            return;
+        };
+
+        if let Some(child) = node.children.get(*first) {
+            Self::collect(child, rest, matched);
+        }
+        if let Some(single) = node.children.get("*") {
+            Self::collect(single, rest, matched);
+        }
+    }
+}
+
 // PubSub system (inspired by Phoenix.PubSub)
 pub struct PubSub {
     name: String,
@@ -40,7 +126,7 @@ pub struct PubSub {
     // Subscriber ID -> Set of subscription IDs
     subscribers: Arc<DashMap<String, HashSet<String>>>,
     // For pattern-based subscriptions
-    patterns: Arc<DashMap<String, HashSet<String>>>,
+    patterns: Arc<RwLock<TopicTrie>>,
     // Broadcast channel for all messages (for monitoring)
     broadcast_tx: broadcast::Sender<PubSubMessage>,
     // Metrics
@@ -57,7 +143,7 @@ impl PubSub {
             topics: Arc::new(DashMap::new()),
             subscriptions: Arc::new(DashMap::new()),
             subscribers: Arc::new(DashMap::new()),
-            patterns: Arc::new(DashMap::new()),
+            patterns: Arc::new(RwLock::new(TopicTrie::default())),
             broadcast_tx,
             message_count: Arc::new(std::sync::atomic::AtomicU64::new(0)),
             subscription_count: Arc::new(std::sync::atomic::AtomicU32::new(0)),
@@ -65,6 +151,10 @@ impl PubSub {
     }
 
     pub async fn subscribe(&self, subscriber_id: String, topic: String) -> Result<Receiver<PubSubMessage>> {
+        if TopicTrie::is_pattern(&topic) {
+            return self.subscribe_pattern(subscriber_id, topic).await;
+        }
+
         let subscription_id = Uuid::new_v4().to_string();
         let (sender, receiver) = unbounded();
         
@@ -111,9 +201,7 @@ impl PubSub {
         self.subscriptions.insert(subscription_id.clone(), subscription);
         
         // Add to patterns
-        self.patterns.entry(pattern.clone())
-            .or_insert_with(HashSet::new)
-            .insert(subscription_id.clone());
+        self.patterns.write().insert(&pattern, subscription_id.clone());
         
         // Add to subscribers
         self.subscribers.entry(subscriber_id)
@@ -128,23 +216,29 @@ impl PubSub {
 
     pub async fn unsubscribe(&self, subscriber_id: String, topic: String) -> Result<()> {
         // Find and remove subscriptions
-        if let Some(subscription_ids) = self.subscribers.get(&subscriber_id) {
-            for sub_id in subscription_ids.iter() {
-                if let Some(subscription) = self.subscriptions.get(sub_id) {
-                    if subscription.topic == topic {
-                        // Remove from topics
-                        if let Some(mut topic_subs) = self.topics.get_mut(&topic) {
-                            topic_subs.remove(sub_id);
-                        }
-                        
-                        // Remove subscription
-                        self.subscriptions.remove(sub_id);
-                        
-                        self.subscription_count.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
-                        info!("Subscriber {} unsubscribed from topic {}", subscriber_id, topic);
-                        break;
+        if let Some(mut subscription_ids) = self.subscribers.get_mut(&subscriber_id) {
+            let found = subscription_ids.iter()
+                .find(|sub_id| {
+                    self.subscriptions.get(*sub_id).map_or(false, |subscription| {
+                        subscription.topic == topic || subscription.pattern.as_deref() == Some(topic.as_str())
+                    })
+                })
+                .cloned();
+
+            if let Some(sub_id) = found {
+                subscription_ids.remove(&sub_id);
+
+                // Remove from topics or patterns
+                if let Some((_, subscription)) = self.subscriptions.remove(&sub_id) {
+                    if let Some(pattern) = subscription.pattern {
+                        self.patterns.write().remove(&pattern, &sub_id);
+                    } else if let Some(mut topic_subs) = self.topics.get_mut(&topic) {
+                        topic_subs.remove(&sub_id);
                     }
                 }
+
+                self.subscription_count.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+                info!("Subscriber {} unsubscribed from topic {}", subscriber_id, topic);
             }
         }
         
@@ -163,9 +257,7 @@ impl PubSub {
                     }
                     
                     if let Some(pattern) = subscription.pattern {
-                        if let Some(mut pattern_subs) = self.patterns.get_mut(&pattern) {
-                            pattern_subs.remove(&sub_id);
-                        }
+                        self.patterns.write().remove(&pattern, &sub_id);
                     }
                     
                     self.subscription_count.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
@@ -200,17 +292,16 @@ impl PubSub {
             }
         }
         
-        // Send to pattern subscribers
-        for pattern_entry in self.patterns.iter() {
-            let pattern = pattern_entry.key();
-            if Self::matches_pattern(&topic, pattern) {
-                for sub_id in pattern_entry.value() {
-                    if let Some(subscription) = self.subscriptions.get(sub_id) {
-                        if subscription.sender.send(message.clone()).await.is_ok() {
-                            delivered += 1;
-                        }
-                    }
-                }
+        // Send to pattern subscribers (the trie lock is released before awaiting)
+        let pattern_senders: Vec<Sender<PubSubMessage>> = {
+            let matched = self.patterns.read().matches(&topic);
+            matched.iter()
+                .filter_map(|sub_id| self.subscriptions.get(sub_id).map(|sub| sub.sender.clone()))
+                .collect()
+        };
+        for sender in pattern_senders {
+            if sender.send(message.clone()).await.is_ok() {
+                delivered += 1;
             }
         }
         
@@ -247,32 +338,6 @@ impl PubSub {
         Ok(delivered)
     }
 
-    fn matches_pattern(topic: &str, pattern: &str) -> bool {
-        // Simple pattern matching: * matches any single segment, ** matches any number of segments
-        if pattern == "*" || pattern == "**" {
-            return true;
-        }
-        
-        let topic_parts: Vec<&str> = topic.split('.').collect();
-        let pattern_parts: Vec<&str> = pattern.split('.').collect();
-        
-        let mut t_idx = 0;
-        let mut p_idx = 0;
-        
-        while t_idx < topic_parts.len() && p_idx < pattern_parts.len() {
-            if pattern_parts[p_idx] == "**" {
-                return true; // ** matches everything after
-            } else if pattern_parts[p_idx] == "*" || pattern_parts[p_idx] == topic_parts[t_idx] {
-                t_idx += 1;
-                p_idx += 1;
-            } else {
-                return false;
-            }
-        }
-        
-        t_idx == topic_parts.len() && p_idx == pattern_parts.len()
-    }
-
     pub fn get_metrics(&self) -> PubSubMetrics {
         PubSubMetrics {
             message_count: self.message_count.load(std::sync::atomic::Ordering::SeqCst),
@@ -455,4 +520,64 @@ impl JsTopicChannel {
             Err(Error::from_reason("PubSub not initialized"))
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(receiver: &Receiver<PubSubMessage>) -> Vec<String> {
+        let mut topics = Vec::new();
+        while let Ok(message) = receiver.try_recv() {
+            topics.push(message.topic);
+        }
+        topics
+    }
+
+    #[tokio::test]
+    async fn test_exact_and_wildcard_delivery() {
+        let pubsub = PubSub::new("test".to_string());
+        let exact = pubsub.subscribe("a".to_string(), "user.123.login".to_string()).await.unwrap();
+        let single = pubsub.subscribe("b".to_string(), "user.*.login".to_string()).await.unwrap();
+        let multi = pubsub.subscribe_pattern("c".to_string(), "user.#".to_string()).await.unwrap();
+        let other = pubsub.subscribe("d".to_string(), "metrics.#".to_string()).await.unwrap();
+
+        let delivered = pubsub.publish("user.123.login".to_string(), "event".to_string(), vec![], "test".to_string()).await.unwrap();
+        assert_eq!(delivered, 3);
+        assert_eq!(drain(&exact), vec!["user.123.login"]);
+        assert_eq!(drain(&single), vec!["user.123.login"]);
+        assert_eq!(drain(&multi), vec!["user.123.login"]);
+        assert!(drain(&other).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wildcard_non_matches() {
+        let pubsub = PubSub::new("test".to_string());
+        let single = pubsub.subscribe("a".to_string(), "user.*".to_string()).await.unwrap();
+        let multi = pubsub.subscribe("b".to_string(), "user.#.logout".to_string()).await.unwrap();
+
+        // `*` matches exactly one segment, so deeper topics are not delivered
+        pubsub.publish("user.123.login".to_string(), "event".to_string(), vec![], "test".to_string()).await.unwrap();
+        pubsub.publish("account.123".to_string(), "event".to_string(), vec![], "test".to_string()).await.unwrap();
+        assert!(drain(&single).is_empty());
+        assert!(drain(&multi).is_empty());
+
+        // `#` matches zero or more segments
+        pubsub.publish("user.logout".to_string(), "event".to_string(), vec![], "test".to_string()).await.unwrap();
+        pubsub.publish("user.1.2.logout".to_string(), "event".to_string(), vec![], "test".to_string()).await.unwrap();
+        assert_eq!(drain(&multi), vec!["user.logout", "user.1.2.logout"]);
+        assert_eq!(drain(&single), vec!["user.logout"]);
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_pattern() {
+        let pubsub = PubSub::new("test".to_string());
+        let receiver = pubsub.subscribe("a".to_string(), "user.#".to_string()).await.unwrap();
+        pubsub.unsubscribe("a".to_string(), "user.#".to_string()).await.unwrap();
+
+        let delivered = pubsub.publish("user.1".to_string(), "event".to_string(), vec![], "test".to_string()).await.unwrap();
+        assert_eq!(delivered, 0);
+        assert!(drain(&receiver).is_empty());
+        assert!(pubsub.patterns.read().root.is_empty());
+    }
+}
@@ -1,7 +1,7 @@
 use napi::bindgen_prelude::*;
 use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi_derive::napi;
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator, IntoParallelIterator};
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator, IntoParallelIterator};
 use rayon::slice::{ParallelSlice, ParallelSliceMut};
 use rayon::{ThreadPool, ThreadPoolBuilder};
 use std::sync::Arc;
@@ -58,6 +58,12 @@ impl RayonThreadPool {
 
 }
 
+impl RayonThreadPool {
+    pub(crate) fn shared_pool(&self) -> Arc<ThreadPool> {
+        Arc::clone(&self.pool)
+    }
+}
+
 pub struct ParallelMapTask {
     data: Vec<i32>,
     operation: String,
@@ -69,38 +75,11 @@ impl Task for ParallelMapTask {
     type JsValue = Vec<i32>;
 
     fn compute(&mut self) -> Result<Self::Output> {
-        let result = match self.operation.as_str() {
-            "square" => {
-                if let Some(pool) = &self.pool {
-                    pool.install(|| {
-                        self.data.par_iter().map(|x| x * x).collect()
-                    })
-                } else {
-                    self.data.par_iter().map(|x| x * x).collect()
-                }
-            }
-            "double" => {
-                if let Some(pool) = &self.pool {
-                    pool.install(|| {
-                        self.data.par_iter().map(|x| x * 2).collect()
-                    })
-                } else {
-                    self.data.par_iter().map(|x| x * 2).collect()
-                }
-            }
-            "increment" => {
-                if let Some(pool) = &self.pool {
-                    pool.install(|| {
-                        self.data.par_iter().map(|x| x + 1).collect()
-                    })
-                } else {
-                    self.data.par_iter().map(|x| x + 1).collect()
-                }
-            }
-            _ => return Err(Error::from_reason("Unknown operation")),
-        };
-
-        Ok(result)
+        let _active = crate::ActiveTaskGuard::start();
+        match &self.pool {
+            Some(pool) => pool.install(|| map_values(&self.data, &self.operation)),
+            None => map_values(&self.data, &self.operation),
+        }
     }
 
     fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
@@ -108,6 +87,16 @@ impl Task for ParallelMapTask {
     }
 }
 
+/// Map `data` with `operation` on whichever rayon pool the caller runs in
+pub(crate) fn map_values(data: &[i32], operation: &str) -> Result<Vec<i32>> {
+    match operation {
+        "square" => Ok(data.par_iter().map(|x| x * x).collect()),
+        "double" => Ok(data.par_iter().map(|x| x * 2).collect()),
+        "increment" => Ok(data.par_iter().map(|x| x + 1).collect()),
+        _ => Err(Error::from_reason("Unknown operation")),
+    }
+}
+
 #[napi]
 pub fn parallel_map(
     data: Vec<i32>,
@@ -133,6 +122,7 @@ impl Task for ParallelReduceTask {
     type JsValue = i32;
 
     fn compute(&mut self) -> Result<Self::Output> {
+        let _active = crate::ActiveTaskGuard::start();
         let result = match self.operation.as_str() {
             "sum" => {
                 if let Some(pool) = &self.pool {
@@ -208,6 +198,7 @@ impl Task for ParallelFilterTask {
     type JsValue = Vec<i32>;
 
     fn compute(&mut self) -> Result<Self::Output> {
+        let _active = crate::ActiveTaskGuard::start();
         let result = match self.operation.as_str() {
             "greater_than" => {
                 if let Some(pool) = &self.pool {
@@ -280,56 +271,147 @@ pub fn parallel_filter(
     })
 }
 
+/// Number of elements handed to a JS key function per callback.
+const SORT_KEY_BATCH_SIZE: usize = 8192;
+
+fn sort_f64(data: &mut [f64], descending: bool, pool: Option<&ThreadPool>) {
+    let sort = |data: &mut [f64]| {
+        if descending {
+            data.par_sort_unstable_by(|a, b| b.total_cmp(a));
+        } else {
+            data.par_sort_unstable_by(|a, b| a.total_cmp(b));
+        }
+    };
+
+    match pool {
+        Some(pool) => pool.install(|| sort(data)),
+        None => sort(data),
+    }
+}
+
+fn sort_f64_by_keys(data: Vec<f64>, keys: Vec<f64>, pool: Option<&ThreadPool>) -> Vec<f64> {
+    let sort = move || {
+        let mut pairs: Vec<(f64, f64)> = keys.into_par_iter().zip(data.into_par_iter()).collect();
+        pairs.par_sort_by(|a, b| a.0.total_cmp(&b.0));
+        pairs.into_par_iter().map(|(_, value)| value).collect()
+    };
+
+    match pool {
+        Some(pool) => pool.install(sort),
+        None => sort(),
+    }
+}
+
 pub struct ParallelSortTask {
-    data: Vec<i32>,
+    data: Vec<f64>,
     descending: bool,
     pool: Option<Arc<ThreadPool>>,
 }
 
 impl Task for ParallelSortTask {
-    type Output = Vec<i32>;
-    type JsValue = Vec<i32>;
+    type Output = Vec<f64>;
+    type JsValue = Float64Array;
 
     fn compute(&mut self) -> Result<Self::Output> {
-        let mut result = self.data.clone();
-        
-        if let Some(pool) = &self.pool {
-            pool.install(|| {
-                if self.descending {
-                    result.par_sort_by(|a, b| b.cmp(a));
-                } else {
-                    result.par_sort();
-                }
-            });
-        } else {
-            if self.descending {
-                result.par_sort_by(|a, b| b.cmp(a));
-            } else {
-                result.par_sort();
-            }
-        }
-
+        let _active = crate::ActiveTaskGuard::start();
+        let mut result = std::mem::take(&mut self.data);
+        sort_f64(&mut result, self.descending, self.pool.as_deref());
         Ok(result)
     }
 
     fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
-        Ok(output)
+        Ok(Float64Array::new(output))
     }
 }
 
 #[napi]
 pub fn parallel_sort(
-    data: Vec<i32>,
+    data: Float64Array,
     descending: Option<bool>,
     pool: Option<&RayonThreadPool>,
 ) -> AsyncTask<ParallelSortTask> {
     AsyncTask::new(ParallelSortTask {
-        data,
+        data: data.to_vec(),
         descending: descending.unwrap_or(false),
         pool: pool.map(|p| Arc::clone(&p.pool)),
     })
 }
 
+pub struct ParallelSortByTask {
+    data: Vec<f64>,
+    key_fn: ThreadsafeFunction<Vec<f64>, Vec<f64>>,
+    pool: Option<Arc<ThreadPool>>,
+}
+
+impl ParallelSortByTask {
+    /// Compute sort keys in batches so JS is called once per batch, not per element
+    fn compute_keys(&self) -> Result<Vec<f64>> {
+        let mut keys = Vec::with_capacity(self.data.len());
+
+        for batch in self.data.chunks(SORT_KEY_BATCH_SIZE) {
+            let (tx, rx) = std::sync::mpsc::channel();
+            let status = self.key_fn.call_with_return_value(
+                Ok(batch.to_vec()),
+                ThreadsafeFunctionCallMode::Blocking,
+                move |result: Result<Vec<f64>>, _env| {
+                    let _ = tx.send(result);
+                    Ok(())
+                },
+            );
+            if status != Status::Ok {
+                return Err(Error::new(status, "Failed to call key function".to_string()));
+            }
+
+            let batch_keys = rx
+                .recv()
+                .map_err(|_| Error::from_reason("Key function was dropped before returning"))??;
+            if batch_keys.len() != batch.len() {
+                return Err(Error::from_reason(format!(
+                    "Key function returned {} keys for a batch of {} elements",
+                    batch_keys.len(),
+                    batch.len()
+                )));
+            }
+            keys.extend(batch_keys);
+        }
+
+        Ok(keys)
+    }
+}
+
+impl Task for ParallelSortByTask {
+    type Output = Vec<f64>;
+    type JsValue = Float64Array;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let _active = crate::ActiveTaskGuard::start();
+        let keys = self.compute_keys()?;
+        let data = std::mem::take(&mut self.data);
+        Ok(sort_f64_by_keys(data, keys, self.pool.as_deref()))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(Float64Array::new(output))
+    }
+}
+
+/// Stable parallel sort by a JS key function.
+///
+/// `key_fn` receives batches of values as an array and must return one numeric
+/// key per value, in the same order.
+#[napi]
+pub fn parallel_sort_by(
+    data: Float64Array,
+    key_fn: ThreadsafeFunction<Vec<f64>, Vec<f64>>,
+    pool: Option<&RayonThreadPool>,
+) -> AsyncTask<ParallelSortByTask> {
+    AsyncTask::new(ParallelSortByTask {
+        data: data.to_vec(),
+        key_fn,
+        pool: pool.map(|p| Arc::clone(&p.pool)),
+    })
+}
+
 pub struct ParallelChunkTask {
     data: Vec<i32>,
     chunk_size: usize,
@@ -342,6 +424,7 @@ impl Task for ParallelChunkTask {
     type JsValue = Vec<i32>;
 
     fn compute(&mut self) -> Result<Self::Output> {
+        let _active = crate::ActiveTaskGuard::start();
         let result = match self.operation.as_str() {
             "sum_chunks" => {
                 if let Some(pool) = &self.pool {
@@ -414,7 +497,195 @@ pub fn parallel_chunk_process(
     })
 }
 
+// Element-wise ops accepted by `parallel_map_reduce`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MapOp {
+    Identity,
+    Square,
+    Abs,
+    Log,
+}
+
+impl MapOp {
+    fn parse(op: &str) -> Result<Self> {
+        match op {
+            "identity" => Ok(MapOp::Identity),
+            "square" => Ok(MapOp::Square),
+            "abs" => Ok(MapOp::Abs),
+            "log" => Ok(MapOp::Log),
+            _ => Err(Error::new(Status::InvalidArg, format!("Unknown map operation '{}'", op))),
+        }
+    }
+
+    fn apply(self, x: f64) -> f64 {
+        match self {
+            MapOp::Identity => x,
+            MapOp::Square => x * x,
+            MapOp::Abs => x.abs(),
+            MapOp::Log => x.ln(),
+        }
+    }
+}
+
+// Associative reductions accepted by `parallel_map_reduce`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReduceOp {
+    Sum,
+    Product,
+    Max,
+    Min,
+}
+
+impl ReduceOp {
+    fn parse(op: &str) -> Result<Self> {
+        match op {
+            "sum" => Ok(ReduceOp::Sum),
+            "product" => Ok(ReduceOp::Product),
+            "max" => Ok(ReduceOp::Max),
+            "min" => Ok(ReduceOp::Min),
+            _ => Err(Error::new(Status::InvalidArg, format!("Unknown reduce operation '{}'", op))),
+        }
+    }
+
+    // Result for an empty array
+    fn identity(self) -> f64 {
+        match self {
+            ReduceOp::Sum => 0.0,
+            ReduceOp::Product => 1.0,
+            ReduceOp::Max => f64::NEG_INFINITY,
+            ReduceOp::Min => f64::INFINITY,
+        }
+    }
+
+    fn combine(self, a: f64, b: f64) -> f64 {
+        match self {
+            ReduceOp::Sum => a + b,
+            ReduceOp::Product => a * b,
+            ReduceOp::Max => a.max(b),
+            ReduceOp::Min => a.min(b),
+        }
+    }
+}
+
+fn map_reduce_f64(data: &[f64], map: MapOp, reduce: ReduceOp, pool: Option<&ThreadPool>) -> f64 {
+    let run = || {
+        data.par_iter()
+            .map(|&x| map.apply(x))
+            .reduce(|| reduce.identity(), |a, b| reduce.combine(a, b))
+    };
+    match pool {
+        Some(pool) => pool.install(run),
+        None => run(),
+    }
+}
+
+pub struct ParallelMapReduceTask {
+    data: Vec<f64>,
+    map: MapOp,
+    reduce: ReduceOp,
+    pool: Option<Arc<ThreadPool>>,
+}
+
+impl Task for ParallelMapReduceTask {
+    type Output = f64;
+    type JsValue = f64;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let _active = crate::ActiveTaskGuard::start();
+        Ok(map_reduce_f64(&self.data, self.map, self.reduce, self.pool.as_deref()))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// Map every element with a built-in op (identity, square, abs, log) and fold
+/// the results with another (sum, product, max, min), entirely on the rayon pool
+#[napi]
+pub fn parallel_map_reduce(
+    data: Float64Array,
+    map_op: String,
+    reduce_op: String,
+    pool: Option<&RayonThreadPool>,
+) -> Result<AsyncTask<ParallelMapReduceTask>> {
+    Ok(AsyncTask::new(ParallelMapReduceTask {
+        map: MapOp::parse(&map_op)?,
+        reduce: ReduceOp::parse(&reduce_op)?,
+        data: data.to_vec(),
+        pool: pool.map(|p| Arc::clone(&p.pool)),
+    }))
+}
+
 #[napi]
 pub fn get_rayon_global_thread_count() -> u32 {
     rayon::current_num_threads() as u32
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn random_data(len: usize) -> Vec<f64> {
+        // xorshift64 keeps the fixture deterministic without pulling in `rand`
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state as f64 / u64::MAX as f64) * 2.0e6 - 1.0e6
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parallel_sort_matches_sequential() {
+        let data = random_data(10_000);
+        let pool = ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+        let mut expected = data.clone();
+        expected.sort_unstable_by(|a, b| a.total_cmp(b));
+
+        let mut ascending = data.clone();
+        sort_f64(&mut ascending, false, Some(&pool));
+        assert_eq!(ascending, expected);
+
+        let mut descending = data;
+        sort_f64(&mut descending, true, None);
+        expected.reverse();
+        assert_eq!(descending, expected);
+    }
+
+    #[test]
+    fn test_parallel_sort_by_keys_is_stable() {
+        let data = random_data(200_000);
+        let keys: Vec<f64> = data.iter().map(|x| (x.abs() / 1000.0).floor()).collect();
+
+        let mut expected: Vec<(f64, f64)> = keys.iter().copied().zip(data.iter().copied()).collect();
+        expected.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let expected: Vec<f64> = expected.into_iter().map(|(_, value)| value).collect();
+
+        assert_eq!(sort_f64_by_keys(data, keys, None), expected);
+    }
+
+    #[test]
+    fn test_map_reduce_sum_of_squares() {
+        let data = random_data(1_000_000);
+        let pool = ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+        let expected: f64 = data.iter().map(|x| x * x).sum();
+        let actual = map_reduce_f64(&data, MapOp::Square, ReduceOp::Sum, Some(&pool));
+        assert!((actual - expected).abs() <= expected * 1e-9);
+    }
+
+    #[test]
+    fn test_map_reduce_max_of_abs() {
+        let data = random_data(1_000_000);
+
+        let expected = data.iter().map(|x| x.abs()).fold(f64::NEG_INFINITY, f64::max);
+        assert_eq!(map_reduce_f64(&data, MapOp::Abs, ReduceOp::Max, None), expected);
+        assert_eq!(map_reduce_f64(&[], MapOp::Abs, ReduceOp::Max, None), f64::NEG_INFINITY);
+        assert!(MapOp::parse("cube").is_err());
+    }
+}
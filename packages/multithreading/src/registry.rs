@@ -12,13 +12,12 @@
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH};
-use uuid::Uuid;
 use async_channel::{Receiver, Sender};
 use serde::{Serialize, Deserialize};
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
-use crate::actor::{ActorId, ActorRef};
+use crate::actor::{get_or_init_actor_system, ActorId, ActorSystem};
 
 /// Registry key types
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -53,7 +52,7 @@ pub struct ProcessMeta {
 /// Registry entry
 #[derive(Debug, Clone)]
 struct RegistryEntry {
-    actor_ref: ActorRef,
+    actor_id: ActorId,
     meta: ProcessMeta,
 }
 
@@ -83,6 +82,7 @@ pub struct RegistryStats {
 }
 
 /// Core process registry
+#[derive(Clone)]
 pub struct ProcessRegistry {
     /// Name -> Process mapping
     registry: Arc<RwLock<HashMap<RegistryKey, RegistryEntry>>>,
@@ -95,6 +95,8 @@ pub struct ProcessRegistry {
     /// Event channel for registry changes
     event_sender: Sender<RegistryEvent>,
     event_receiver: Receiver<RegistryEvent>,
+    /// Actor system whose actors are monitored; names are dropped when their owner exits
+    actor_system: Option<Arc<ActorSystem>>,
 }
 
 /// Registry events
@@ -114,6 +116,12 @@ pub enum RegistryEvent {
         actor_id: ActorId,
         properties: HashMap<String, String>,
     },
+    /// The owning actor terminated and the key was unregistered automatically
+    ProcessDown {
+        key: RegistryKey,
+        actor_id: ActorId,
+        reason: String,
+    },
 }
 
 impl ProcessRegistry {
@@ -128,24 +136,38 @@ impl ProcessRegistry {
             created_at: SystemTime::now(),
             event_sender,
             event_receiver,
+            actor_system: None,
         }
     }
 
+    /// Monitor registered actors in `actor_system`: registering a dead actor
+    /// fails, and a name is unregistered as soon as its owner terminates
+    pub fn with_actor_system(mut self, actor_system: Arc<ActorSystem>) -> Self {
+        self.actor_system = Some(actor_system);
+        self
+    }
+
     /// Register a process with a name
-    pub fn register(&self, key: RegistryKey, actor_ref: ActorRef, properties: Option<HashMap<String, String>>) -> Result<(), String> {
+    pub fn register(&self, key: RegistryKey, actor_id: ActorId, properties: Option<HashMap<String, String>>) -> Result<(), String> {
+        // Like Erlang's register/2, a process that is not alive cannot take a name
+        let exit = match &self.actor_system {
+            Some(system) => Some(system.monitor(&actor_id)
+                .ok_or_else(|| format!("Actor {} is not alive", actor_id))?),
+            None => None,
+        };
+
         let mut registry = self.registry.write().unwrap();
         let mut reverse = self.reverse_lookup.write().unwrap();
 
         // Check if key already exists
-        if registry.contains_key(&key) {
-            return Err(format!("Key {:?} already registered", key));
+        if let Some(existing) = registry.get(&key) {
+            return Err(format!("Key {:?} already registered to {}", key, existing.actor_id));
         }
 
-        let actor_id = actor_ref.id;
         let props = properties.unwrap_or_default();
         
         let meta = ProcessMeta {
-            id: actor_id,
+            id: actor_id.clone(),
             key: key.clone(),
             registered_at: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
@@ -157,12 +179,34 @@ impl ProcessRegistry {
         };
 
         let entry = RegistryEntry {
-            actor_ref,
+            actor_id: actor_id.clone(),
             meta,
         };
 
         registry.insert(key.clone(), entry);
-        reverse.entry(actor_id).or_insert_with(HashSet::new).insert(key.clone());
+        let keys = reverse.entry(actor_id.clone()).or_insert_with(HashSet::new);
+        let first_key = keys.is_empty();
+        keys.insert(key.clone());
+        drop(reverse);
+        drop(registry);
+
+        // One monitor per actor covers every name it holds
+        if let (Some(exit), true) = (exit, first_key) {
+            let registry = self.clone();
+            let monitored_id = actor_id.clone();
+            let monitor = async move {
+                let reason = exit.await;
+                registry.unregister_down(&monitored_id, &format!("{:?}", reason));
+            };
+            match tokio::runtime::Handle::try_current() {
+                Ok(handle) => {
+                    handle.spawn(monitor);
+                }
+                Err(_) => {
+                    napi::bindgen_prelude::spawn(monitor);
+                }
+            }
+        }
 
         // Send registration event
         let _ = self.event_sender.try_send(RegistryEvent::ProcessRegistered {
@@ -180,7 +224,7 @@ impl ProcessRegistry {
         let mut reverse = self.reverse_lookup.write().unwrap();
 
         if let Some(entry) = registry.remove(key) {
-            let actor_id = entry.actor_ref.id;
+            let actor_id = entry.actor_id;
             
             // Update reverse lookup
             if let Some(keys) = reverse.get_mut(&actor_id) {
@@ -222,10 +266,25 @@ impl ProcessRegistry {
         Ok(count)
     }
 
+    /// Drop every key held by an actor that terminated
+    fn unregister_down(&self, actor_id: &ActorId, reason: &str) {
+        let mut registry = self.registry.write().unwrap();
+        let mut reverse = self.reverse_lookup.write().unwrap();
+
+        for key in reverse.remove(actor_id).unwrap_or_default() {
+            registry.remove(&key);
+            let _ = self.event_sender.try_send(RegistryEvent::ProcessDown {
+                key,
+                actor_id: actor_id.clone(),
+                reason: reason.to_string(),
+            });
+        }
+    }
+
     /// Look up a process by key
-    pub fn whereis(&self, key: &RegistryKey) -> Option<ActorRef> {
+    pub fn whereis(&self, key: &RegistryKey) -> Option<ActorId> {
         let registry = self.registry.read().unwrap();
-        registry.get(key).map(|entry| entry.actor_ref)
+        registry.get(key).map(|entry| entry.actor_id.clone())
     }
 
     /// Get process metadata
@@ -244,7 +303,7 @@ impl ProcessRegistry {
             // Send update event
             let _ = self.event_sender.try_send(RegistryEvent::ProcessUpdated {
                 key: key.clone(),
-                actor_id: entry.actor_ref.id,
+                actor_id: entry.actor_id.clone(),
                 properties,
             });
 
@@ -327,6 +386,19 @@ impl ProcessRegistry {
         registry.keys().cloned().collect()
     }
 
+    /// List registered atom-style names, sorted
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.keys()
+            .into_iter()
+            .filter_map(|key| match key {
+                RegistryKey::Name(name) => Some(name),
+                _ => None,
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
     /// Get registry statistics
     pub fn stats(&self) -> RegistryStats {
         let registry = self.registry.read().unwrap();
@@ -373,7 +445,7 @@ impl ProcessRegistry {
     /// Global registry instance (singleton pattern for default registry)
     pub fn global() -> &'static Arc<ProcessRegistry> {
         static GLOBAL_REGISTRY: std::sync::OnceLock<Arc<ProcessRegistry>> = std::sync::OnceLock::new();
-        GLOBAL_REGISTRY.get_or_init(|| Arc::new(ProcessRegistry::new()))
+        GLOBAL_REGISTRY.get_or_init(|| Arc::new(ProcessRegistry::new().with_actor_system(get_or_init_actor_system())))
     }
 }
 
@@ -388,7 +460,7 @@ impl JsProcessRegistry {
     #[napi(constructor)]
     pub fn new() -> Self {
         Self {
-            inner: Arc::new(ProcessRegistry::new()),
+            inner: Arc::new(ProcessRegistry::new().with_actor_system(get_or_init_actor_system())),
         }
     }
 
@@ -396,9 +468,7 @@ impl JsProcessRegistry {
     #[napi]
     pub fn register(&self, name: String, actor_id: String, properties: Option<HashMap<String, String>>) -> Result<()> {
         let key = RegistryKey::Name(name);
-        let actor_ref = ActorRef { id: ActorId(Uuid::parse_str(&actor_id).unwrap()) };
-        
-        self.inner.register(key, actor_ref, properties)
+        self.inner.register(key, ActorId::from_string(actor_id), properties)
             .map_err(|e| napi::Error::from_reason(e))
     }
 
@@ -406,9 +476,7 @@ impl JsProcessRegistry {
     #[napi]
     pub fn register_tuple(&self, tuple: Vec<String>, actor_id: String, properties: Option<HashMap<String, String>>) -> Result<()> {
         let key = RegistryKey::Tuple(tuple);
-        let actor_ref = ActorRef { id: ActorId(Uuid::parse_str(&actor_id).unwrap()) };
-        
-        self.inner.register(key, actor_ref, properties)
+        self.inner.register(key, ActorId::from_string(actor_id), properties)
             .map_err(|e| napi::Error::from_reason(e))
     }
 
@@ -432,14 +500,14 @@ impl JsProcessRegistry {
     #[napi]
     pub fn whereis(&self, name: String) -> Option<String> {
         let key = RegistryKey::Name(name);
-        self.inner.whereis(&key).map(|actor_ref| actor_ref.id.0.to_string())
+        self.inner.whereis(&key).map(|actor_id| actor_id.to_string())
     }
 
     /// Look up by tuple
     #[napi]
     pub fn whereis_tuple(&self, tuple: Vec<String>) -> Option<String> {
         let key = RegistryKey::Tuple(tuple);
-        self.inner.whereis(&key).map(|actor_ref| actor_ref.id.0.to_string())
+        self.inner.whereis(&key).map(|actor_id| actor_id.to_string())
     }
 
     /// Update process properties
@@ -488,13 +556,7 @@ impl JsProcessRegistry {
     /// Get all registered keys
     #[napi]
     pub fn keys(&self) -> Vec<String> {
-        self.inner.keys()
-            .into_iter()
-            .filter_map(|key| match key {
-                RegistryKey::Name(name) => Some(name),
-                _ => None,
-            })
-            .collect()
+        self.inner.names()
     }
 
     /// Get registry statistics
@@ -512,9 +574,8 @@ impl JsProcessRegistry {
 pub fn register_name(name: String, actor_id: String) -> Result<()> {
     let registry = ProcessRegistry::global();
     let key = RegistryKey::Name(name);
-    let actor_ref = ActorRef { id: ActorId(Uuid::parse_str(&actor_id).unwrap()) };
     
-    registry.register(key, actor_ref, None)
+    registry.register(key, ActorId::from_string(actor_id), None)
         .map_err(|e| napi::Error::from_reason(e))
 }
 
@@ -532,21 +593,38 @@ pub fn whereis_name(name: String) -> Option<String> {
     let registry = ProcessRegistry::global();
     let key = RegistryKey::Name(name);
     
-    registry.whereis(&key).map(|actor_ref| actor_ref.id.0.to_string())
+    registry.whereis(&key).map(|actor_id| actor_id.to_string())
+}
+
+/// Names currently registered in the global registry
+#[napi]
+pub fn list_names() -> Vec<String> {
+    ProcessRegistry::global().names()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::actor::{ActorBehavior, Message};
+    use std::time::Duration;
+
+    struct IdleActor;
+
+    #[async_trait::async_trait]
+    impl ActorBehavior for IdleActor {
+        async fn handle_message(&mut self, _msg: Message) -> Option<Vec<u8>> {
+            None
+        }
+    }
 
     #[test]
     fn test_registry_basic_operations() {
         let registry = ProcessRegistry::new();
-        let actor_ref = ActorRef { id: ActorId(Uuid::new_v4()) };
+        let actor_id = ActorId::new();
         let key = RegistryKey::Name("test_process".to_string());
 
         // Register
-        assert!(registry.register(key.clone(), actor_ref, None).is_ok());
+        assert!(registry.register(key.clone(), actor_id, None).is_ok());
 
         // Look up
         assert!(registry.whereis(&key).is_some());
@@ -561,27 +639,27 @@ mod tests {
     #[test]
     fn test_registry_duplicate_keys() {
         let registry = ProcessRegistry::new();
-        let actor_ref1 = ActorRef { id: ActorId(Uuid::new_v4()) };
-        let actor_ref2 = ActorRef { id: ActorId(Uuid::new_v4()) };
+        let actor_id1 = ActorId::new();
+        let actor_id2 = ActorId::new();
         let key = RegistryKey::Name("duplicate".to_string());
 
         // Register first
-        assert!(registry.register(key.clone(), actor_ref1, None).is_ok());
+        assert!(registry.register(key.clone(), actor_id1, None).is_ok());
 
         // Try to register duplicate
-        assert!(registry.register(key, actor_ref2, None).is_err());
+        assert!(registry.register(key, actor_id2, None).is_err());
     }
 
     #[test]
     fn test_registry_query() {
         let registry = ProcessRegistry::new();
-        let actor_ref = ActorRef { id: ActorId(Uuid::new_v4()) };
+        let actor_id = ActorId::new();
         let key = RegistryKey::Name("queryable".to_string());
 
         let mut props = HashMap::new();
         props.insert("type".to_string(), "worker".to_string());
 
-        registry.register(key, actor_ref, Some(props)).unwrap();
+        registry.register(key, actor_id, Some(props)).unwrap();
 
         let mut query_props = HashMap::new();
         query_props.insert("type".to_string(), "worker".to_string());
@@ -597,4 +675,45 @@ mod tests {
         let results = registry.query(&query);
         assert_eq!(results.len(), 1);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_registry_unregisters_dead_owner() {
+        let system = Arc::new(ActorSystem::new());
+        let registry = ProcessRegistry::new().with_actor_system(system.clone());
+        let events = registry.events();
+
+        let actor_id = system.spawn(Box::new(IdleActor));
+        registry.register(RegistryKey::Name("worker".to_string()), actor_id.clone(), None).unwrap();
+        registry.register(RegistryKey::Tuple(vec!["pool".to_string(), "1".to_string()]), actor_id.clone(), None).unwrap();
+        assert_eq!(registry.names(), vec!["worker"]);
+
+        system.stop(&actor_id).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert!(registry.whereis(&RegistryKey::Name("worker".to_string())).is_none());
+        assert!(registry.keys().is_empty());
+
+        let downs = std::iter::from_fn(|| events.try_recv().ok())
+            .filter(|event| matches!(event, RegistryEvent::ProcessDown { actor_id: id, .. } if *id == actor_id))
+            .count();
+        assert_eq!(downs, 2);
+
+        // The name is free again, but a dead actor cannot take it
+        let err = registry.register(RegistryKey::Name("worker".to_string()), actor_id, None).unwrap_err();
+        assert!(err.contains("not alive"));
+    }
+
+    #[tokio::test]
+    async fn test_registry_rejects_taken_name() {
+        let system = Arc::new(ActorSystem::new());
+        let registry = ProcessRegistry::new().with_actor_system(system.clone());
+        let first = system.spawn(Box::new(IdleActor));
+        let second = system.spawn(Box::new(IdleActor));
+        let key = RegistryKey::Name("singleton".to_string());
+
+        registry.register(key.clone(), first.clone(), None).unwrap();
+        let err = registry.register(key.clone(), second, None).unwrap_err();
+        assert!(err.contains("already registered"));
+        assert_eq!(registry.whereis(&key), Some(first));
+    }
+}
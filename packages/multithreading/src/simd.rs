@@ -1,6 +1,6 @@
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
-use wide::{f32x4, f32x8, i32x4, i32x8, u32x4, u32x8};
+use wide::{f32x4, f32x8, f64x4, i32x4, i32x8, u32x4, u32x8};
 use nalgebra::{DMatrix, DVector};
 use rayon::prelude::*;
 
@@ -208,8 +208,19 @@ impl SimdMatrix {
                 "Invalid matrix dimensions for multiplication"
             ));
         }
+        // Column-major storage is the row-major transpose, and
+        // (AB)ᵀ = BᵀAᵀ, so the row-major core product of the operands'
+        // storage in swapped order is the column-major product
+        let product = katalyst_compute_core::matmul(
+            other.data.as_slice(),
+            self.data.as_slice(),
+            other.data.ncols(),
+            other.data.nrows(),
+            self.data.nrows(),
+        )
+        .map_err(Error::from_reason)?;
         Ok(SimdMatrix {
-            data: &self.data * &other.data,
+            data: DMatrix::from_vec(self.data.nrows(), other.data.ncols(), product),
         })
     }
 
@@ -303,6 +314,103 @@ pub fn simd_dot_product(a: Vec<f64>, b: Vec<f64>) -> Result<f64> {
     Ok(sum as f64)
 }
 
+// `wide` lowers these lanes to SSE/AVX/NEON where available and to plain
+// scalar code elsewhere; tails shorter than a lane are summed scalar.
+pub(crate) fn dot_f64(a: &[f64], b: &[f64]) -> f64 {
+    let mut acc = f64x4::splat(0.0);
+    let chunks_a = a.chunks_exact(4);
+    let chunks_b = b.chunks_exact(4);
+    let tail: f64 = chunks_a.remainder().iter()
+        .zip(chunks_b.remainder())
+        .map(|(x, y)| x * y)
+        .sum();
+
+    for (chunk_a, chunk_b) in chunks_a.zip(chunks_b) {
+        let va = f64x4::new(chunk_a.try_into().unwrap());
+        let vb = f64x4::new(chunk_b.try_into().unwrap());
+        acc = acc + va * vb;
+    }
+
+    let lanes: [f64; 4] = acc.into();
+    lanes.iter().sum::<f64>() + tail
+}
+
+pub(crate) fn dot_f32(a: &[f32], b: &[f32]) -> f32 {
+    let mut acc = f32x8::splat(0.0);
+    let chunks_a = a.chunks_exact(8);
+    let chunks_b = b.chunks_exact(8);
+    let tail: f32 = chunks_a.remainder().iter()
+        .zip(chunks_b.remainder())
+        .map(|(x, y)| x * y)
+        .sum();
+
+    for (chunk_a, chunk_b) in chunks_a.zip(chunks_b) {
+        let va = f32x8::new(chunk_a.try_into().unwrap());
+        let vb = f32x8::new(chunk_b.try_into().unwrap());
+        acc = acc + va * vb;
+    }
+
+    let lanes: [f32; 8] = acc.into();
+    lanes.iter().sum::<f32>() + tail
+}
+
+fn typed_array_len(array: &Either<Float64Array, Float32Array>) -> usize {
+    match array {
+        Either::A(values) => values.len(),
+        Either::B(values) => values.len(),
+    }
+}
+
+fn widen(values: &[f32]) -> Vec<f64> {
+    values.iter().map(|&x| x as f64).collect()
+}
+
+/// Dot product of two `Float64Array`/`Float32Array` vectors of equal length.
+/// Mixed inputs are computed in f64.
+#[napi]
+pub fn dot_product(a: Either<Float64Array, Float32Array>, b: Either<Float64Array, Float32Array>) -> Result<f64> {
+    let (len_a, len_b) = (typed_array_len(&a), typed_array_len(&b));
+    if len_a != len_b {
+        return Err(Error::new(
+            Status::InvalidArg,
+            format!("Vectors must have the same length ({} != {})", len_a, len_b),
+        ));
+    }
+
+    Ok(match (&a, &b) {
+        (Either::A(a), Either::A(b)) => dot_f64(a, b),
+        (Either::B(a), Either::B(b)) => dot_f32(a, b) as f64,
+        (Either::A(a), Either::B(b)) => dot_f64(a, &widen(b)),
+        (Either::B(a), Either::A(b)) => dot_f64(&widen(a), b),
+    })
+}
+
+/// Euclidean (L2) norm of a `Float64Array`/`Float32Array` vector
+#[napi]
+pub fn l2_norm(v: Either<Float64Array, Float32Array>) -> f64 {
+    match &v {
+        Either::A(values) => dot_f64(values, values).sqrt(),
+        Either::B(values) => (dot_f32(values, values) as f64).sqrt(),
+    }
+}
+
+/// Whether two `Float64Array`/`Float32Array` matrices (flattened in the same
+/// order) have the same length and every pair of entries is within
+/// `max(absTol, relTol * max(|a|, |b|))`. Compared in f64.
+#[napi]
+pub fn approx_equal_matrix(
+    a: Either<Float64Array, Float32Array>,
+    b: Either<Float64Array, Float32Array>,
+    rel_tol: f64,
+    abs_tol: f64,
+) -> bool {
+    let widened = |values: &Either<Float64Array, Float32Array>| match values {
+        Either::A(values) => values.to_vec(),
+        Either::B(values) => widen(values),
+    };
+    katalyst_compute_core::approx_equal_matrix(&widened(&a), &widened(&b), rel_tol, abs_tol)
+}
+
 #[napi]
 pub fn simd_vector_add(a: Vec<f64>, b: Vec<f64>) -> Result<Vec<f64>> {
     if a.len() != b.len() {
@@ -409,4 +517,80 @@ pub fn create_simd_f32x4(a: f64, b: f64, c: f64, d: f64) -> SimdF32x4 {
 #[napi]
 pub fn create_simd_matrix(rows: u32, cols: u32) -> SimdMatrix {
     SimdMatrix::new(rows, cols)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Deterministic xorshift values in [-1, 1)
+    fn random_vector(len: usize, mut seed: u64) -> Vec<f64> {
+        (0..len)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                (seed >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+            })
+            .collect()
+    }
+
+    fn naive_dot(a: &[f64], b: &[f64]) -> f64 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    #[test]
+    fn test_dot_matches_naive_including_tails() {
+        for len in [0, 1, 3, 4, 7, 8, 9, 31, 1000, 1003] {
+            let a = random_vector(len, 0x9E37_79B9 + len as u64);
+            let b = random_vector(len, 0x85EB_CA6B + len as u64);
+            let expected = naive_dot(&a, &b);
+
+            assert!((dot_f64(&a, &b) - expected).abs() < 1e-9, "f64 len {}", len);
+
+            let a32: Vec<f32> = a.iter().map(|&x| x as f32).collect();
+            let b32: Vec<f32> = b.iter().map(|&x| x as f32).collect();
+            assert!((dot_f32(&a32, &b32) as f64 - expected).abs() < 1e-3, "f32 len {}", len);
+        }
+    }
+
+    #[test]
+    fn test_l2_norm_matches_naive() {
+        let v = random_vector(1027, 42);
+        let expected = naive_dot(&v, &v).sqrt();
+        assert!((l2_norm(Either::A(Float64Array::new(v.clone()))) - expected).abs() < 1e-9);
+
+        let v32: Vec<f32> = v.iter().map(|&x| x as f32).collect();
+        assert!((l2_norm(Either::B(Float32Array::new(v32))) - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_matrix_mul_matches_nalgebra() {
+        let a = SimdMatrix::from_vec(2, 3, random_vector(6, 7)).unwrap();
+        let b = SimdMatrix::from_vec(3, 4, random_vector(12, 11)).unwrap();
+        let product = a.mul(&b).unwrap();
+        let expected = &a.data * &b.data;
+
+        assert_eq!((product.rows(), product.cols()), (2, 4));
+        assert!(katalyst_compute_core::approx_equal_matrix(product.data.as_slice(), expected.as_slice(), 1e-5, 1e-6));
+        assert!(b.mul(&a).is_err());
+    }
+
+    #[test]
+    fn test_approx_equal_matrix_mixed_precision() {
+        let exact = vec![0.1, 0.2, 0.3];
+        let single: Vec<f32> = exact.iter().map(|&x| x as f32).collect();
+
+        assert!(approx_equal_matrix(Either::A(Float64Array::new(exact.clone())), Either::B(Float32Array::new(single.clone())), 1e-6, 0.0));
+        assert!(!approx_equal_matrix(Either::A(Float64Array::new(exact.clone())), Either::B(Float32Array::new(single)), 1e-12, 0.0));
+        assert!(!approx_equal_matrix(Either::A(Float64Array::new(exact)), Either::A(Float64Array::new(vec![0.1, 0.2])), 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_dot_product_length_mismatch() {
+        let a = Either::A(Float64Array::new(vec![1.0, 2.0, 3.0]));
+        let b = Either::B(Float32Array::new(vec![1.0, 2.0]));
+        let err = dot_product(a, b).unwrap_err();
+        assert_eq!(err.status, Status::InvalidArg);
+    }
+}
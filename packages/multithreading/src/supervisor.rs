@@ -1,16 +1,14 @@
-use crate::actor::{Actor, ActorBehavior, ActorId, Message, get_actor_system};
+use crate::actor::{ActorBehavior, ActorId, ExitReason, Message, get_actor_system};
 use crate::genserver::{GenServer, GenServerBehavior};
 use async_channel::{bounded, unbounded, Receiver, Sender};
-use dashmap::DashMap;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 use parking_lot::RwLock;
 use petgraph::graph::{DiGraph, NodeIndex};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::time::{interval, sleep};
+use std::time::{Duration, Instant};
 use tracing::{debug, error, info, warn};
 
 // Supervisor restart strategies (inspired by Elixir/OTP)
@@ -56,11 +54,13 @@ pub enum ChildType {
 
 // Supervisor state
 pub struct SupervisorState {
-    children: Arc<DashMap<String, ChildInfo>>,
+    children: Vec<ChildInfo>, // in start order, which rest_for_one relies on
     restart_strategy: RestartStrategy,
     max_restarts: u32,
     max_seconds: u32,
-    restart_counts: Arc<DashMap<String, Vec<std::time::Instant>>>,
+    restarts: VecDeque<Instant>,
+    terminated: bool,
+    supervisor_actor: Option<ActorId>,
 }
 
 struct ChildInfo {
@@ -78,11 +78,11 @@ enum ChildStatus {
     Failed(String),
 }
 
-// Supervisor implementation
+// Supervisor implementation; clones share the same children and state
+#[derive(Clone)]
 pub struct Supervisor {
     name: String,
     state: Arc<RwLock<SupervisorState>>,
-    actor_id: Option<ActorId>,
 }
 
 impl Supervisor {
@@ -95,137 +95,195 @@ impl Supervisor {
         Supervisor {
             name,
             state: Arc::new(RwLock::new(SupervisorState {
-                children: Arc::new(DashMap::new()),
+                children: Vec::new(),
                 restart_strategy,
                 max_restarts,
                 max_seconds,
-                restart_counts: Arc::new(DashMap::new()),
+                restarts: VecDeque::new(),
+                terminated: false,
+                supervisor_actor: None,
             })),
-            actor_id: None,
         }
     }
 
-    pub fn add_child(&self, spec: ChildSpec) -> Result<()> {
-        let state = self.state.read();
+    pub fn add_child(&self, spec: ChildSpec) -> Result<ActorId> {
+        let mut state = self.state.write();
         
-        if state.children.contains_key(&spec.id) {
+        if state.terminated {
+            return Err(Error::from_reason(format!("Supervisor {} has terminated", self.name)));
+        }
+        if state.children.iter().any(|child| child.spec.id == spec.id) {
             return Err(Error::from_reason(format!("Child {} already exists", spec.id)));
         }
 
         // Start the child
         let actor_id = (spec.start)();
         
-        let child_info = ChildInfo {
+        state.children.push(ChildInfo {
             spec: spec.clone(),
             actor_id: actor_id.clone(),
             restart_count: 0,
             status: ChildStatus::Running,
-        };
-
-        state.children.insert(spec.id.clone(), child_info);
+        });
+        drop(state);
         
         // Monitor the child for failures
-        self.monitor_child(spec.id, actor_id);
+        self.monitor_child(spec.id, actor_id.clone());
         
-        Ok(())
+        Ok(actor_id)
     }
 
     pub fn remove_child(&self, child_id: String) -> Result<()> {
-        let state = self.state.read();
+        let mut state = self.state.write();
         
-        if let Some((_, child_info)) = state.children.remove(&child_id) {
-            // Stop the child actor
-            if let Some(system) = get_actor_system() {
-                let _ = system.stop(&child_info.actor_id);
+        match state.children.iter().position(|child| child.spec.id == child_id) {
+            Some(index) => {
+                // Removing it first means the monitor ignores the resulting exit
+                let child_info = state.children.remove(index);
+                stop_actor(&child_info.actor_id);
+                Ok(())
             }
-            Ok(())
-        } else {
-            Err(Error::from_reason(format!("Child {} not found", child_id)))
+            None => Err(Error::from_reason(format!("Child {} not found", child_id))),
         }
     }
 
     fn monitor_child(&self, child_id: String, actor_id: ActorId) {
-        let state = self.state.clone();
-        let supervisor_name = self.name.clone();
+        let supervisor = self.clone();
+        let exit = get_actor_system().and_then(|system| system.monitor(&actor_id));
         
         tokio::spawn(async move {
-            loop {
-                sleep(Duration::from_secs(1)).await;
-                
-                // Check if child is still running
-                if let Some(system) = get_actor_system() {
-                    if system.get_actor(&actor_id).is_none() {
-                        warn!("Child {} has failed in supervisor {}", child_id, supervisor_name);
-                        
-                        // Handle restart based on strategy
-                        let should_restart = {
-                            let state = state.read();
-                            if let Some(child_info) = state.children.get(&child_id) {
-                                match child_info.spec.restart {
-                                    Restart::Permanent => true,
-                                    Restart::Temporary => false,
-                                    Restart::Transient => {
-                                        // Check if it was an abnormal termination
-                                        matches!(child_info.status, ChildStatus::Failed(_))
-                                    }
-                                }
-                            } else {
-                                false
-                            }
-                        };
-
-                        if should_restart {
-                            // Check restart frequency
-                            let can_restart = {
-                                let state = state.read();
-                                let mut restart_counts = state.restart_counts.entry(child_id.clone())
-                                    .or_insert_with(Vec::new);
-                                
-                                let now = std::time::Instant::now();
-                                restart_counts.push(now);
-                                
-                                // Remove old restart times
-                                let cutoff = now - Duration::from_secs(state.max_seconds as u64);
-                                restart_counts.retain(|&time| time > cutoff);
-                                
-                                restart_counts.len() <= state.max_restarts as usize
-                            };
-
-                            if can_restart {
-                                // Restart the child
-                                let state = state.read();
-                                if let Some(mut child_info) = state.children.get_mut(&child_id) {
-                                    info!("Restarting child {} in supervisor {}", child_id, supervisor_name);
-                                    
-                                    let new_actor_id = (child_info.spec.start)();
-                                    child_info.actor_id = new_actor_id;
-                                    child_info.restart_count += 1;
-                                    child_info.status = ChildStatus::Running;
-                                }
-                            } else {
-                                error!(
-                                    "Child {} in supervisor {} exceeded restart limit",
-                                    child_id, supervisor_name
-                                );
-                                break;
-                            }
-                        } else {
-                            break;
-                        }
-                    }
-                }
-            }
+            let reason = match exit {
+                Some(exit) => exit.await,
+                // Child never registered with the actor system, so it is already gone
+                None => ExitReason::Error("child not found in actor system".to_string()),
+            };
+            supervisor.handle_child_exit(&child_id, &actor_id, reason);
         });
     }
 
-    pub async fn start(mut self) -> Result<ActorId> {
+    fn handle_child_exit(&self, child_id: &str, actor_id: &ActorId, reason: ExitReason) {
+        let mut state = self.state.write();
+        if state.terminated {
+            return;
+        }
+
+        // Exits of instances we already replaced or removed are expected
+        let Some(index) = state.children.iter().position(|child| {
+            child.spec.id == child_id && &child.actor_id == actor_id
+        }) else {
+            return;
+        };
+
+        warn!("Child {} exited in supervisor {}: {:?}", child_id, self.name, reason);
+
+        let should_restart = match state.children[index].spec.restart {
+            Restart::Permanent => true,
+            Restart::Temporary => false,
+            Restart::Transient => reason.is_abnormal(),
+        };
+
+        if !should_restart {
+            if matches!(state.children[index].spec.restart, Restart::Temporary) {
+                state.children.remove(index);
+            } else {
+                state.children[index].status = match reason {
+                    ExitReason::Error(error) => ChildStatus::Failed(error),
+                    _ => ChildStatus::Stopped,
+                };
+            }
+            return;
+        }
+
+        // Restart intensity: more than max_restarts within max_seconds tears down the tree
+        let now = Instant::now();
+        let window = Duration::from_secs(state.max_seconds as u64);
+        state.restarts.push_back(now);
+        while state.restarts.front().map_or(false, |&time| now.duration_since(time) > window) {
+            state.restarts.pop_front();
+        }
+
+        if state.restarts.len() > state.max_restarts as usize {
+            error!(
+                "Supervisor {} exceeded {} restarts in {}s, shutting down",
+                self.name, state.max_restarts, state.max_seconds
+            );
+            drop(state);
+            self.shutdown();
+            return;
+        }
+
+        // Which children to restart, in start order
+        let restart_range = match state.restart_strategy {
+            RestartStrategy::OneForOne | RestartStrategy::SimpleOneForOne => index..index + 1,
+            RestartStrategy::OneForAll => 0..state.children.len(),
+            RestartStrategy::RestForOne => index..state.children.len(),
+        };
+
+        // Siblings are stopped in reverse start order before anything restarts
+        for sibling in state.children[restart_range.clone()].iter_mut().rev() {
+            if &sibling.actor_id != actor_id {
+                sibling.status = ChildStatus::Stopping;
+                stop_actor(&sibling.actor_id);
+            }
+        }
+
+        let mut restarted = Vec::new();
+        for child in state.children[restart_range].iter_mut() {
+            info!("Restarting child {} in supervisor {}", child.spec.id, self.name);
+            child.actor_id = (child.spec.start)();
+            child.restart_count += 1;
+            child.status = ChildStatus::Running;
+            restarted.push((child.spec.id.clone(), child.actor_id.clone()));
+        }
+        drop(state);
+
+        for (child_id, actor_id) in restarted {
+            self.monitor_child(child_id, actor_id);
+        }
+    }
+
+    // Stop every child in reverse start order and refuse further restarts
+    pub fn shutdown(&self) {
+        let mut state = self.state.write();
+        if state.terminated {
+            return;
+        }
+        state.terminated = true;
+
+        for child in state.children.iter_mut().rev() {
+            child.status = ChildStatus::Stopped;
+            stop_actor(&child.actor_id);
+        }
+        if let Some(actor_id) = state.supervisor_actor.take() {
+            stop_actor(&actor_id);
+        }
+    }
+
+    pub fn is_terminated(&self) -> bool {
+        self.state.read().terminated
+    }
+
+    pub fn child_actor_id(&self, child_id: &str) -> Option<ActorId> {
+        self.state.read().children.iter()
+            .find(|child| child.spec.id == child_id)
+            .map(|child| child.actor_id.clone())
+    }
+
+    pub fn restart_count(&self, child_id: &str) -> Option<u32> {
+        self.state.read().children.iter()
+            .find(|child| child.spec.id == child_id)
+            .map(|child| child.restart_count)
+    }
+
+    pub async fn start(self) -> Result<ActorId> {
         // Create supervisor actor
         let supervisor_actor = SupervisorActor {
-            supervisor: Arc::new(self),
+            supervisor: self.clone(),
         };
         
         if let Some(system) = get_actor_system() {
             let actor_id = system.spawn(Box::new(supervisor_actor));
+            self.state.write().supervisor_actor = Some(actor_id.clone());
             Ok(actor_id)
         } else {
             Err(Error::from_reason("Actor system not initialized"))
@@ -235,10 +293,7 @@ impl Supervisor {
     pub fn which_children(&self) -> Vec<(String, String, ChildType)> {
         let state = self.state.read();
         state.children.iter()
-            .map(|entry| {
-                let (id, info) = entry.pair();
-                (id.clone(), format!("{:?}", info.actor_id), info.spec.child_type.clone())
-            })
+            .map(|info| (info.spec.id.clone(), format!("{:?}", info.actor_id), info.spec.child_type.clone()))
             .collect()
     }
 
@@ -246,10 +301,10 @@ impl Supervisor {
         let state = self.state.read();
         let total = state.children.len();
         let active = state.children.iter()
-            .filter(|entry| matches!(entry.status, ChildStatus::Running))
+            .filter(|child| matches!(child.status, ChildStatus::Running))
             .count();
         let supervisors = state.children.iter()
-            .filter(|entry| matches!(entry.spec.child_type, ChildType::Supervisor))
+            .filter(|child| matches!(child.spec.child_type, ChildType::Supervisor))
             .count();
         let workers = total - supervisors;
         
@@ -257,9 +312,15 @@ impl Supervisor {
     }
 }
 
+fn stop_actor(actor_id: &ActorId) {
+    if let Some(system) = get_actor_system() {
+        let _ = system.stop(actor_id);
+    }
+}
+
 // Actor wrapper for Supervisor
 struct SupervisorActor {
-    supervisor: Arc<Supervisor>,
+    supervisor: Supervisor,
 }
 
 #[async_trait::async_trait]
@@ -276,13 +337,8 @@ impl ActorBehavior for SupervisorActor {
     }
     
     async fn on_stop(&mut self) {
-        // Stop all children
-        let state = self.supervisor.state.read();
-        for entry in state.children.iter() {
-            if let Some(system) = get_actor_system() {
-                let _ = system.stop(&entry.actor_id);
-            }
-        }
+        // Stopping the supervisor takes its children down with it
+        self.supervisor.shutdown();
     }
 }
 
@@ -311,7 +367,6 @@ impl DynamicSupervisor {
     }
 
     pub fn start_child(&self) -> Result<ActorId> {
-        let actor_id = (self.child_factory)();
         let child_id = format!("child_{}", uuid::Uuid::new_v4());
         
         let spec = ChildSpec {
@@ -322,8 +377,7 @@ impl DynamicSupervisor {
             child_type: ChildType::Worker,
         };
         
-        self.supervisor.add_child(spec)?;
-        Ok(actor_id)
+        self.supervisor.add_child(spec)
     }
 
     pub fn terminate_child(&self, child_id: String) -> Result<()> {
@@ -362,14 +416,7 @@ impl JsSupervisor {
     #[napi]
     pub async fn start(&self) -> Result<String> {
         if let Some(supervisor) = &self.supervisor {
-            let supervisor_clone = Supervisor::new(
-                supervisor.name.clone(),
-                supervisor.state.read().restart_strategy.clone(),
-                supervisor.state.read().max_restarts,
-                supervisor.state.read().max_seconds,
-            );
-            
-            let actor_id = supervisor_clone.start().await?;
+            let actor_id = supervisor.as_ref().clone().start().await?;
             Ok(format!("{:?}", actor_id))
         } else {
             Err(Error::from_reason("Supervisor not created"))
@@ -413,7 +460,7 @@ impl JsSupervisor {
                 child_type: ChildType::Worker,
             };
             
-            supervisor.add_child(spec)
+            supervisor.add_child(spec).map(|_| ())
         } else {
             Err(Error::from_reason("Supervisor not created"))
         }
@@ -433,6 +480,18 @@ impl JsSupervisor {
         }
     }
     
+    #[napi]
+    pub fn is_terminated(&self) -> bool {
+        self.supervisor.as_ref().map_or(false, |supervisor| supervisor.is_terminated())
+    }
+    
+    #[napi]
+    pub fn shutdown(&self) {
+        if let Some(supervisor) = &self.supervisor {
+            supervisor.shutdown();
+        }
+    }
+    
     #[napi]
     pub fn count_children(&self) -> String {
         if let Some(supervisor) = &self.supervisor {
@@ -445,4 +504,103 @@ impl JsSupervisor {
             "No supervisor".to_string()
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actor::get_or_init_actor_system;
+
+    // Worker that panics when cast `crash`
+    struct CrashingWorker;
+
+    #[async_trait::async_trait]
+    impl ActorBehavior for CrashingWorker {
+        async fn handle_message(&mut self, msg: Message) -> Option<Vec<u8>> {
+            match msg {
+                Message::Cast { payload } if payload == b"crash" => panic!("worker crashed"),
+                _ => None,
+            }
+        }
+    }
+
+    fn worker_spec(id: &str, restart: Restart) -> ChildSpec {
+        ChildSpec {
+            id: id.to_string(),
+            start: Arc::new(|| get_or_init_actor_system().spawn(Box::new(CrashingWorker))),
+            restart,
+            shutdown: Shutdown::Brutal,
+            child_type: ChildType::Worker,
+        }
+    }
+
+    async fn crash(supervisor: &Supervisor, child_id: &str) -> ActorId {
+        let actor_id = supervisor.child_actor_id(child_id).unwrap();
+        get_or_init_actor_system().cast(&actor_id, b"crash".to_vec()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        actor_id
+    }
+
+    #[tokio::test]
+    async fn test_permanent_child_is_restarted() {
+        let system = get_or_init_actor_system();
+        let supervisor = Supervisor::new("one_for_one".to_string(), RestartStrategy::OneForOne, 3, 5);
+        supervisor.add_child(worker_spec("a", Restart::Permanent)).unwrap();
+        supervisor.add_child(worker_spec("b", Restart::Permanent)).unwrap();
+        let sibling = supervisor.child_actor_id("b").unwrap();
+
+        let crashed = crash(&supervisor, "a").await;
+
+        let restarted = supervisor.child_actor_id("a").unwrap();
+        assert_ne!(restarted, crashed);
+        assert!(system.get_actor(&restarted).is_some());
+        assert_eq!(supervisor.restart_count("a"), Some(1));
+        // one_for_one leaves siblings alone
+        assert_eq!(supervisor.child_actor_id("b"), Some(sibling));
+    }
+
+    #[tokio::test]
+    async fn test_rest_for_one_restarts_later_children() {
+        let supervisor = Supervisor::new("rest_for_one".to_string(), RestartStrategy::RestForOne, 3, 5);
+        for id in ["a", "b", "c"] {
+            supervisor.add_child(worker_spec(id, Restart::Permanent)).unwrap();
+        }
+        let first = supervisor.child_actor_id("a").unwrap();
+        let last = supervisor.child_actor_id("c").unwrap();
+
+        crash(&supervisor, "b").await;
+
+        assert_eq!(supervisor.child_actor_id("a"), Some(first));
+        assert_eq!(supervisor.restart_count("b"), Some(1));
+        assert_ne!(supervisor.child_actor_id("c"), Some(last));
+    }
+
+    #[tokio::test]
+    async fn test_temporary_child_is_not_restarted() {
+        let supervisor = Supervisor::new("temporary".to_string(), RestartStrategy::OneForAll, 3, 5);
+        supervisor.add_child(worker_spec("temp", Restart::Temporary)).unwrap();
+
+        crash(&supervisor, "temp").await;
+
+        assert!(supervisor.child_actor_id("temp").is_none());
+        assert!(!supervisor.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_exceeding_restart_intensity_tears_down_tree() {
+        let system = get_or_init_actor_system();
+        let supervisor = Supervisor::new("intensity".to_string(), RestartStrategy::OneForAll, 1, 5);
+        supervisor.add_child(worker_spec("a", Restart::Permanent)).unwrap();
+        supervisor.add_child(worker_spec("b", Restart::Permanent)).unwrap();
+
+        crash(&supervisor, "a").await;
+        assert!(!supervisor.is_terminated());
+
+        crash(&supervisor, "a").await;
+        assert!(supervisor.is_terminated());
+
+        let sibling = supervisor.child_actor_id("b").unwrap();
+        assert!(system.get_actor(&sibling).is_none());
+        assert!(supervisor.add_child(worker_spec("c", Restart::Permanent)).is_err());
+    }
+}
@@ -3,9 +3,11 @@ use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi_derive::napi;
 use tokio::runtime::{Runtime, Builder};
 use tokio::sync::{mpsc, broadcast};
-use tokio::time::{sleep, timeout, Duration, Instant};
+use tokio::time::{sleep, timeout, Duration, Instant, MissedTickBehavior};
 use tokio::task::spawn_blocking;
-use std::sync::Arc;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 use serde::{Deserialize, Serialize};
 
 #[napi(object)]
@@ -63,6 +65,12 @@ impl TokioRuntime {
 
 }
 
+impl TokioRuntime {
+    pub(crate) fn shared_runtime(&self) -> Arc<Runtime> {
+        Arc::clone(&self.runtime)
+    }
+}
+
 #[napi]
 pub struct TokioMpscChannel {
     sender: Arc<tokio::sync::Mutex<mpsc::UnboundedSender<String>>>,
@@ -123,6 +131,7 @@ impl Task for TokioDelayTask {
     type JsValue = String;
 
     fn compute(&mut self) -> Result<Self::Output> {
+        let _active = crate::ActiveTaskGuard::start();
         let rt = tokio::runtime::Runtime::new().map_err(|e| {
             Error::from_reason(format!("Failed to create runtime: {}", e))
         })?;
@@ -162,6 +171,7 @@ impl Task for TokioTimeoutTask {
     type JsValue = String;
 
     fn compute(&mut self) -> Result<Self::Output> {
+        let _active = crate::ActiveTaskGuard::start();
         let rt = tokio::runtime::Runtime::new().map_err(|e| {
             Error::from_reason(format!("Failed to create runtime: {}", e))
         })?;
@@ -214,6 +224,7 @@ impl Task for TokioParallelTask {
     type JsValue = Vec<String>;
 
     fn compute(&mut self) -> Result<Self::Output> {
+        let _active = crate::ActiveTaskGuard::start();
         let rt = tokio::runtime::Runtime::new().map_err(|e| {
             Error::from_reason(format!("Failed to create runtime: {}", e))
         })?;
@@ -259,6 +270,63 @@ pub fn tokio_parallel_tasks(
 #[napi]
 pub struct TokioTimer {
     start_time: Instant,
+    ticker: Option<Ticker<ThreadsafeFunction<u32, ()>>>,
+}
+
+// A running `interval`. The callback lives behind a lock shared with the tick
+// task so `stop` can take it, which also discards ticks that are already
+// queued for the JS thread.
+struct Ticker<C> {
+    task: tokio::task::JoinHandle<()>,
+    callback: Arc<Mutex<Option<C>>>,
+}
+
+impl<C: Send + 'static> Ticker<C> {
+    // `fire` runs for every tick while the callback is still in place; the
+    // tick task goes to `spawn` so tests can use their own runtime
+    fn start(
+        period: Duration,
+        callback: C,
+        fire: impl Fn(&C, u32) + Send + 'static,
+        spawn: impl FnOnce(Pin<Box<dyn Future<Output = ()> + Send>>) -> tokio::task::JoinHandle<()>,
+    ) -> Self {
+        let slot = Arc::new(Mutex::new(Some(callback)));
+        let tick_slot = Arc::clone(&slot);
+        let task = spawn(Box::pin(run_ticker(period, move |tick| {
+            match tick_slot.lock().unwrap().as_ref() {
+                Some(callback) => {
+                    fire(callback, tick);
+                    true
+                }
+                None => false,
+            }
+        })));
+
+        Ticker { task, callback: slot }
+    }
+
+    // Returns the callback so the caller can release it
+    fn stop(self) -> Option<C> {
+        let callback = self.callback.lock().unwrap().take();
+        self.task.abort();
+        callback
+    }
+}
+
+// Calls `on_tick` with the 1-based tick number every `period`, starting one
+// period from now like `setInterval`, until it returns false
+async fn run_ticker(period: Duration, mut on_tick: impl FnMut(u32) -> bool) {
+    let mut interval = tokio::time::interval_at(Instant::now() + period, period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let mut tick = 0u32;
+    loop {
+        interval.tick().await;
+        tick = tick.wrapping_add(1);
+        if !on_tick(tick) {
+            break;
+        }
+    }
 }
 
 #[napi]
@@ -267,6 +335,7 @@ impl TokioTimer {
     pub fn new() -> Self {
         TokioTimer {
             start_time: Instant::now(),
+            ticker: None,
         }
     }
 
@@ -280,6 +349,44 @@ impl TokioTimer {
         self.start_time = Instant::now();
     }
 
+    /// Invoke `callback` with the tick number every `period_ms`, replacing any
+    /// interval already running on this timer
+    #[napi]
+    pub fn interval(&mut self, period_ms: u32, callback: ThreadsafeFunction<u32, ()>) -> Result<()> {
+        if period_ms == 0 {
+            return Err(Error::new(Status::InvalidArg, "Interval period must be greater than 0ms"));
+        }
+        self.clear();
+
+        self.ticker = Some(Ticker::start(
+            Duration::from_millis(period_ms as u64),
+            callback,
+            |callback, tick| {
+                callback.call(Ok(tick), ThreadsafeFunctionCallMode::NonBlocking);
+            },
+            |task| napi::bindgen_prelude::spawn(task),
+        ));
+        Ok(())
+    }
+
+    /// Stop the running interval; no callbacks fire after this returns
+    #[napi]
+    pub fn clear(&mut self) {
+        if let Some(callback) = self.ticker.take().and_then(Ticker::stop) {
+            let _ = callback.abort();
+        }
+    }
+
+    #[napi(getter)]
+    pub fn is_ticking(&self) -> bool {
+        self.ticker.is_some()
+    }
+}
+
+impl Drop for TokioTimer {
+    fn drop(&mut self) {
+        self.clear();
+    }
 }
 
 
@@ -298,3 +405,71 @@ pub fn get_tokio_runtime_metrics() -> Result<String> {
 
     Ok(metrics)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_active_task_count_rises_while_task_runs() {
+        let mut task = TokioDelayTask {
+            duration_ms: 200,
+            message: "tick".to_string(),
+        };
+        let handle = std::thread::spawn(move || task.compute());
+
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(crate::get_performance_metrics().unwrap().active_tasks >= 1);
+
+        assert!(handle.join().unwrap().unwrap().contains("tick"));
+    }
+
+    #[tokio::test]
+    async fn test_ticker_fires_each_period_until_cleared() {
+        use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+        let ticks = Arc::new(AtomicU32::new(0));
+        let active = Arc::new(AtomicBool::new(true));
+        let task = tokio::spawn(run_ticker(Duration::from_millis(50), {
+            let ticks = Arc::clone(&ticks);
+            let active = Arc::clone(&active);
+            move |_| {
+                if !active.load(Ordering::SeqCst) {
+                    return false;
+                }
+                ticks.fetch_add(1, Ordering::SeqCst);
+                true
+            }
+        }));
+
+        // Five periods
+        sleep(Duration::from_millis(275)).await;
+        active.store(false, Ordering::SeqCst);
+        task.abort();
+        let fired = ticks.load(Ordering::SeqCst);
+        assert!((4..=6).contains(&fired), "expected ~5 ticks, got {}", fired);
+
+        sleep(Duration::from_millis(150)).await;
+        assert_eq!(ticks.load(Ordering::SeqCst), fired);
+    }
+
+    #[tokio::test]
+    async fn test_clear_before_first_tick_never_fires() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let fired = Arc::new(AtomicU32::new(0));
+        let ticker = Ticker::start(
+            Duration::from_millis(30),
+            Arc::clone(&fired),
+            |fired, _| {
+                fired.fetch_add(1, Ordering::SeqCst);
+            },
+            tokio::spawn,
+        );
+
+        // Cleared while the first tick is still pending
+        assert!(ticker.stop().is_some());
+        sleep(Duration::from_millis(120)).await;
+        assert_eq!(fired.load(Ordering::SeqCst), 0);
+    }
+}
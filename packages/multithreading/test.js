@@ -4,7 +4,7 @@
  * Validates the complete multithreading module functionality
  */
 
-const { threadController, createThreadPool, createChannel, createAtomicCell } = require('./wrapper');
+const { threadController, createThreadPool, createChannel, createAtomicCell, FlumeStream } = require('./wrapper');
 
 // Test configuration
 const TEST_CONFIG = {
@@ -377,6 +377,57 @@ suite.test('Performance Metrics', async () => {
   suite.assert(typeof metrics.activeTasks === 'number', 'Invalid active tasks type');
 });
 
+// Test 12: Flume Stream Async Iteration
+suite.test('Flume Stream Async Iteration', async () => {
+  const stream = new FlumeStream(4);
+  const sender = stream.sender();
+  const count = 50;
+
+  // Produce from timers so the consumer is awaiting while values arrive
+  (async () => {
+    for (let i = 0; i < count; i++) {
+      while (!sender.trySend(`value-${i}`)) {
+        await new Promise((resolve) => setImmediate(resolve));
+      }
+    }
+    sender.close();
+  })();
+
+  const received = [];
+  for await (const value of stream) {
+    received.push(value);
+  }
+
+  suite.assertEqual(received.length, count, 'Stream ended early');
+  suite.assertEqual(received[count - 1], `value-${count - 1}`, 'Stream values out of order');
+  suite.assert(sender.isClosed(), 'Sender should be closed');
+});
+
+// Test 13: Flume Stream Awaited Send
+suite.test('Flume Stream Awaited Send', async () => {
+  const stream = new FlumeStream(2);
+  const sender = stream.sender();
+  const count = 20;
+
+  // Capacity is below the value count, so sends wait on the consumer
+  // running on this same thread
+  const producer = (async () => {
+    for (let i = 0; i < count; i++) {
+      await sender.send(`value-${i}`);
+    }
+    sender.close();
+  })();
+
+  const received = [];
+  for await (const value of stream) {
+    received.push(value);
+  }
+  await producer;
+
+  suite.assertEqual(received.length, count, 'Stream ended early');
+  suite.assertEqual(received[count - 1], `value-${count - 1}`, 'Stream values out of order');
+});
+
 // Run the test suite
 async function runTests() {
   try {
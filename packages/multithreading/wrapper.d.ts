@@ -47,6 +47,8 @@ export interface BenchmarkOptions {
 export interface StressTestOptions {
   numTasks?: number;
   taskDurationMs?: number;
+  /** Aborting cancels the remaining tasks and resolves with the results so far */
+  signal?: AbortSignal;
 }
 
 export interface ThreadPoolController {
@@ -160,5 +162,12 @@ export declare function createManagedRuntime(name: string, config?: RuntimeConfi
 // Re-export all native bindings
 export * from './index';
 
+// wrapper.js makes every FlumeStream its own async iterator
+declare module './index' {
+  interface FlumeStream {
+    [Symbol.asyncIterator](): AsyncIterator<string, undefined>;
+  }
+}
+
 // Export the wrapper as default
 export { ThreadController as default };
\ No newline at end of file
@@ -91,13 +91,30 @@ class ThreadController {
       async reduce(data, operation, initial) {
         return await nativeBinding.parallelReduce(data, operation, initial, pool);
       },
+
+      async mapReduce(data, mapOp, reduceOp) {
+        return await nativeBinding.parallelMapReduce(Float64Array.from(data), mapOp, reduceOp, pool);
+      },
       
       async filter(data, operation, threshold) {
         return await nativeBinding.parallelFilter(data, operation, threshold, pool);
       },
       
       async sort(data, descending = false) {
-        return await nativeBinding.parallelSort(data, descending, pool);
+        const sorted = await nativeBinding.parallelSort(Float64Array.from(data), descending, pool);
+        return Array.from(sorted);
+      },
+
+      async sortBy(data, keyFn) {
+        const sorted = await nativeBinding.parallelSortBy(
+          Float64Array.from(data),
+          (err, batch) => {
+            if (err) throw err;
+            return batch.map(keyFn);
+          },
+          pool
+        );
+        return Array.from(sorted);
       },
       
       async chunkProcess(data, chunkSize, operation) {
@@ -140,8 +157,14 @@ class ThreadController {
           name,
           type: 'crossbeam',
           channel,
-          send: (message) => channel.send(message),
-          receive: () => channel.receive(),
+          // trySend keeps the old `false` on a full or closed channel;
+          // the native send() now throws instead
+          send: (message) => channel.trySend(JSON.stringify(message)),
+          receive: () => {
+            const payload = channel.receive();
+            return payload === null ? null : JSON.parse(payload);
+          },
+          close: () => channel.close(),
           isEmpty: () => channel.isEmpty(),
           length: () => channel.len(),
         };
@@ -201,8 +224,14 @@ class ThreadController {
           name,
           type: 'crossbeam',
           channel,
-          send: (message) => channel.send(message),
-          receive: () => channel.receive(),
+          // trySend keeps the old `false` on a full or closed channel;
+          // the native send() now throws instead
+          send: (message) => channel.trySend(JSON.stringify(message)),
+          receive: () => {
+            const payload = channel.receive();
+            return payload === null ? null : JSON.parse(payload);
+          },
+          close: () => channel.close(),
           isEmpty: () => channel.isEmpty(),
           length: () => channel.len(),
         };
@@ -352,18 +381,24 @@ class ThreadController {
     return new Promise((resolve, reject) => {
       const results = [];
       
-      nativeBinding.stressTestConcurrency(numTasks, taskDurationMs, (err, result) => {
+      const handle = nativeBinding.stressTestConcurrency(numTasks, taskDurationMs, (err, result) => {
         if (err) {
           reject(err);
           return;
         }
         
-        results.push(result);
-        
-        if (results.length === numTasks) {
-          resolve(results);
+        if (result.status === 'completed') {
+          results.push(result);
+          return;
         }
+
+        // Final summary: 'finished' or 'cancelled'
+        resolve(results);
       });
+
+      if (options.signal) {
+        options.signal.addEventListener('abort', () => handle.cancel(), { once: true });
+      }
     });
   }
 
@@ -431,14 +466,20 @@ class ThreadController {
     this.threadControllers.clear();
     
     // Cleanup manager
-    this.manager.cleanup();
+    await this.manager.cleanup();
     
     // Final shutdown
-    nativeBinding.shutdownMultithreading();
+    await nativeBinding.shutdownMultithreading();
     this.initialized = false;
   }
 }
 
+// FlumeStream's native next() already resolves to { value, done }, so a
+// stream is its own async iterator and can be consumed with `for await`
+nativeBinding.FlumeStream.prototype[Symbol.asyncIterator] = function () {
+  return this;
+};
+
 // Create global thread controller instance
 const threadController = new ThreadController();
 
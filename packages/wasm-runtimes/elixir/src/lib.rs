@@ -8,9 +8,12 @@
 
 use wasm_bindgen::prelude::*;
 use js_sys::*;
+use katalyst_phoenix_reconnect::ReconnectPolicy;
 use web_sys::*;
 use serde::{Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
+use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
 
@@ -34,62 +37,240 @@ pub struct PhoenixSocket {
     endpoint: String,
     params: HashMap<String, String>,
     channels: Arc<Mutex<HashMap<String, Channel>>>,
-    socket: Option<WebSocket>,
-    connected: bool,
+    connection: Rc<Connection>,
 }
 
 #[wasm_bindgen]
-impl PhoenixSocket {
-    #[wasm_bindgen(constructor)]
-    pub fn new(endpoint: &str, params: &str) -> Result<PhoenixSocket, JsValue> {
-        let params_map: HashMap<String, String> = serde_json::from_str(params)
-            .map_err(|e| JsValue::from_str(&format!("Invalid params JSON: {}", e)))?;
+extern "C" {
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &Function, timeout_ms: i32) -> JsValue;
 
-        Ok(PhoenixSocket {
-            endpoint: endpoint.to_string(),
-            params: params_map,
-            channels: Arc::new(Mutex::new(HashMap::new())),
-            socket: None,
-            connected: false,
-        })
-    }
+    #[wasm_bindgen(js_name = clearTimeout)]
+    fn clear_timeout(handle: &JsValue);
+}
 
-    /// Connect to the Phoenix server
-    #[wasm_bindgen]
-    pub fn connect(&mut self) -> Result<(), JsValue> {
+struct SocketHandlers {
+    _onopen: Closure<dyn FnMut(JsValue)>,
+    _onerror: Closure<dyn FnMut(ErrorEvent)>,
+    _onclose: Closure<dyn FnMut(JsValue)>,
+}
+
+/// WebSocket state shared with the socket and timer callbacks, which hold it weakly
+struct Connection {
+    endpoint: String,
+    socket: RefCell<Option<WebSocket>>,
+    handlers: RefCell<Option<SocketHandlers>>,
+    connected: Cell<bool>,
+    channels: Arc<Mutex<HashMap<String, Channel>>>,
+    policy: Cell<ReconnectPolicy>,
+    attempts: Cell<u32>,
+    reconnect_timer: RefCell<Option<(JsValue, Closure<dyn FnMut()>)>>,
+    on_reconnect_failed: RefCell<Option<Function>>,
+}
+
+impl Connection {
+    fn open(self: &Rc<Self>) -> Result<(), JsValue> {
         let socket = WebSocket::new(&self.endpoint)?;
         socket.set_binary_type(BinaryType::Arraybuffer);
-        
+
         // Set up event handlers
+        let weak = Rc::downgrade(self);
         let onopen_callback = Closure::wrap(Box::new(move |_| {
             console::log_1(&"Phoenix socket connected".into());
+            if let Some(connection) = weak.upgrade() {
+                connection.connected.set(true);
+                if connection.attempts.replace(0) > 0 {
+                    connection.rejoin();
+                }
+            }
         }) as Box<dyn FnMut(JsValue)>);
         socket.set_onopen(Some(onopen_callback.as_ref().unchecked_ref()));
-        onopen_callback.forget();
 
         let onerror_callback = Closure::wrap(Box::new(move |e: ErrorEvent| {
             console::error_1(&format!("Phoenix socket error: {:?}", e).into());
         }) as Box<dyn FnMut(ErrorEvent)>);
         socket.set_onerror(Some(onerror_callback.as_ref().unchecked_ref()));
-        onerror_callback.forget();
 
-        self.socket = Some(socket);
-        self.connected = true;
-        
+        // An unexpected close of an open socket, or a failed reconnect
+        // attempt, schedules the next reconnect
+        let weak = Rc::downgrade(self);
+        let onclose_callback = Closure::wrap(Box::new(move |_| {
+            if let Some(connection) = weak.upgrade() {
+                let was_connected = connection.connected.replace(false);
+                if was_connected || connection.attempts.get() > 0 {
+                    connection.schedule_reconnect();
+                }
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+        socket.set_onclose(Some(onclose_callback.as_ref().unchecked_ref()));
+
+        self.detach();
+        *self.socket.borrow_mut() = Some(socket);
+        *self.handlers.borrow_mut() = Some(SocketHandlers {
+            _onopen: onopen_callback,
+            _onerror: onerror_callback,
+            _onclose: onclose_callback,
+        });
+
+        Ok(())
+    }
+
+    fn schedule_reconnect(self: &Rc<Self>) {
+        let policy = self.policy.get();
+        let attempt = self.attempts.get() + 1;
+
+        if attempt > policy.max_attempts {
+            console::error_1(&format!("Phoenix socket gave up reconnecting after {} attempts", attempt - 1).into());
+            self.attempts.set(0);
+            self.socket.borrow_mut().take();
+            // Release the borrow first so the callback can register a new one
+            let callback = self.on_reconnect_failed.borrow().clone();
+            if let Some(callback) = callback {
+                let _ = callback.call1(&JsValue::NULL, &JsValue::from(attempt - 1));
+            }
+            return;
+        }
+
+        self.attempts.set(attempt);
+        let weak = Rc::downgrade(self);
+        let callback = Closure::wrap(Box::new(move || {
+            if let Some(connection) = weak.upgrade() {
+                if connection.open().is_err() {
+                    connection.schedule_reconnect();
+                }
+            }
+        }) as Box<dyn FnMut()>);
+
+        let delay = policy.delay_ms(attempt, Math::random());
+        let handle = set_timeout(callback.as_ref().unchecked_ref(), delay as i32);
+
+        self.cancel_reconnect();
+        *self.reconnect_timer.borrow_mut() = Some((handle, callback));
+    }
+
+    fn cancel_reconnect(&self) {
+        if let Some((handle, _callback)) = self.reconnect_timer.borrow_mut().take() {
+            clear_timeout(&handle);
+        }
+    }
+
+    /// Close the current socket without triggering a reconnect
+    fn detach(&self) {
+        if let Some(socket) = self.socket.borrow_mut().take() {
+            socket.set_onopen(None);
+            socket.set_onerror(None);
+            socket.set_onclose(None);
+            let _ = socket.close();
+        }
+        self.handlers.borrow_mut().take();
+    }
+
+    fn disconnect(&self) {
+        self.connected.set(false);
+        self.attempts.set(0);
+        self.cancel_reconnect();
+        self.detach();
+    }
+
+    /// Re-send joins for every channel that was joined before the drop
+    fn rejoin(&self) {
+        let joined: Vec<(String, String)> = match self.channels.lock() {
+            Ok(channels) => channels
+                .values()
+                .filter(|channel| channel.joined)
+                .map(|channel| {
+                    let payload = channel.join_payload.clone().unwrap_or_else(|| "{}".to_string());
+                    (channel.topic.clone(), payload)
+                })
+                .collect(),
+            Err(_) => return,
+        };
+
+        for (topic, payload) in joined {
+            console::log_1(&format!("Rejoining channel: {}", topic).into());
+            let _ = self.send(&topic, "phx_join", &payload);
+        }
+    }
+
+    fn send(&self, topic: &str, event: &str, payload: &str) -> Result<(), JsValue> {
+        if let Some(socket) = self.socket.borrow().as_ref() {
+            let message = PhoenixMessage {
+                topic: topic.to_string(),
+                event: event.to_string(),
+                payload: serde_json::from_str(payload).unwrap_or(serde_json::Value::Null),
+                r#ref: Some(uuid::Uuid::new_v4().to_string()),
+            };
+            
+            let message_json = serde_json::to_string(&message)
+                .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))?;
+            
+            socket.send_with_str(&message_json)?;
+        }
         Ok(())
     }
+}
+
+#[wasm_bindgen]
+impl PhoenixSocket {
+    #[wasm_bindgen(constructor)]
+    pub fn new(endpoint: &str, params: &str) -> Result<PhoenixSocket, JsValue> {
+        let params_map: HashMap<String, String> = serde_json::from_str(params)
+            .map_err(|e| JsValue::from_str(&format!("Invalid params JSON: {}", e)))?;
+
+        let channels = Arc::new(Mutex::new(HashMap::new()));
+
+        Ok(PhoenixSocket {
+            endpoint: endpoint.to_string(),
+            params: params_map,
+            channels: channels.clone(),
+            connection: Rc::new(Connection {
+                endpoint: endpoint.to_string(),
+                socket: RefCell::new(None),
+                handlers: RefCell::new(None),
+                connected: Cell::new(false),
+                channels,
+                policy: Cell::new(ReconnectPolicy::default()),
+                attempts: Cell::new(0),
+                reconnect_timer: RefCell::new(None),
+                on_reconnect_failed: RefCell::new(None),
+            }),
+        })
+    }
+
+    /// Connect to the Phoenix server
+    #[wasm_bindgen]
+    pub fn connect(&mut self) -> Result<(), JsValue> {
+        self.connection.disconnect();
+        self.connection.open()
+    }
 
     /// Disconnect from the Phoenix server
     #[wasm_bindgen]
     pub fn disconnect(&mut self) -> Result<(), JsValue> {
-        if let Some(socket) = &self.socket {
-            socket.close()?;
-        }
-        self.connected = false;
-        self.socket = None;
+        self.connection.disconnect();
         Ok(())
     }
 
+    /// Configure reconnection after an unexpected close: delays double from
+    /// `base_ms` up to `max_ms` with up to `jitter` (0.0-1.0) randomised,
+    /// and `max_attempts` of 0 disables reconnection
+    #[wasm_bindgen]
+    pub fn set_reconnect_policy(&mut self, base_ms: u32, max_ms: u32, jitter: f64, max_attempts: u32) {
+        self.connection.policy.set(ReconnectPolicy::new(base_ms, max_ms, jitter, max_attempts));
+    }
+
+    /// Register a callback invoked with the attempt count when reconnection gives up
+    #[wasm_bindgen]
+    pub fn on_reconnect_failed(&mut self, callback: Function) {
+        *self.connection.on_reconnect_failed.borrow_mut() = Some(callback);
+    }
+
+    /// Reconnect attempts made since the socket last dropped
+    #[wasm_bindgen]
+    pub fn reconnect_attempts(&self) -> u32 {
+        self.connection.attempts.get()
+    }
+
     /// Join a channel
     #[wasm_bindgen]
     pub fn channel(&mut self, topic: &str) -> Channel {
@@ -103,29 +284,49 @@ impl PhoenixSocket {
         channel
     }
 
-    /// Send a message through the socket
+    /// Send `phx_join` for a topic; joined topics are re-joined after a reconnect
     #[wasm_bindgen]
-    pub fn push(&self, topic: &str, event: &str, payload: &str) -> Result<(), JsValue> {
-        if let Some(socket) = &self.socket {
-            let message = PhoenixMessage {
-                topic: topic.to_string(),
-                event: event.to_string(),
-                payload: serde_json::from_str(payload).unwrap_or(serde_json::Value::Null),
-                r#ref: Some(uuid::Uuid::new_v4().to_string()),
-            };
-            
-            let message_json = serde_json::to_string(&message)
-                .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))?;
-            
-            socket.send_with_str(&message_json)?;
+    pub fn join(&mut self, topic: &str, payload: &str) -> Result<(), JsValue> {
+        self.connection.send(topic, "phx_join", payload)?;
+
+        if let Ok(mut channels) = self.channels.lock() {
+            let channel = channels
+                .entry(topic.to_string())
+                .or_insert_with(|| Channel::new(topic.to_string()));
+            channel.joined = true;
+            channel.join_payload = Some(payload.to_string());
         }
         Ok(())
     }
 
+    /// Send `phx_leave` for a topic and stop re-joining it
+    #[wasm_bindgen]
+    pub fn leave(&mut self, topic: &str) -> Result<(), JsValue> {
+        if let Ok(mut channels) = self.channels.lock() {
+            if let Some(channel) = channels.get_mut(topic) {
+                channel.joined = false;
+                channel.join_payload = None;
+            }
+        }
+        self.connection.send(topic, "phx_leave", "{}")
+    }
+
+    /// Send a message through the socket
+    #[wasm_bindgen]
+    pub fn push(&self, topic: &str, event: &str, payload: &str) -> Result<(), JsValue> {
+        self.connection.send(topic, event, payload)
+    }
+
     /// Get connection status
     #[wasm_bindgen]
     pub fn is_connected(&self) -> bool {
-        self.connected
+        self.connection.connected.get()
+    }
+}
+
+impl Drop for PhoenixSocket {
+    fn drop(&mut self) {
+        self.connection.disconnect();
     }
 }
 
@@ -135,6 +336,7 @@ impl PhoenixSocket {
 pub struct Channel {
     topic: String,
     joined: bool,
+    join_payload: Option<String>,
     binding: HashMap<String, String>,
 }
 
@@ -145,6 +347,7 @@ impl Channel {
         Channel {
             topic,
             joined: false,
+            join_payload: None,
             binding: HashMap::new(),
         }
     }
@@ -380,4 +583,69 @@ pub fn get_build_info() -> String {
         "target": "wasm32-unknown-unknown",
         "optimization": if cfg!(debug_assertions) { "debug" } else { "release" }
     }).to_string()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use katalyst_phoenix_reconnect::mock_websocket::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn test_reconnect_rejoins_channels() {
+        install_mock_websocket(false);
+        let mut socket = PhoenixSocket::new("ws://localhost:4000/socket/websocket", "{}").unwrap();
+        socket.set_reconnect_policy(20, 100, 0.0, 3);
+        socket.connect().unwrap();
+        sleep(5).await;
+        assert!(socket.is_connected());
+
+        socket.join("room:lobby", r#"{"user":"alice"}"#).unwrap();
+        drop_last_mock();
+        assert!(!socket.is_connected());
+        assert_eq!(socket.reconnect_attempts(), 1);
+        assert_eq!(mock_socket_count(), 1);
+
+        sleep(60).await;
+        assert_eq!(mock_socket_count(), 2);
+        assert!(socket.is_connected());
+        assert_eq!(socket.reconnect_attempts(), 0);
+
+        let frames: Vec<String> = serde_json::from_str(&last_mock_sent()).unwrap();
+        assert_eq!(frames.len(), 1);
+        let sent: serde_json::Value = serde_json::from_str(&frames[0]).unwrap();
+        assert_eq!(sent["topic"], "room:lobby");
+        assert_eq!(sent["event"], "phx_join");
+        assert_eq!(sent["payload"]["user"], "alice");
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_reconnect_gives_up() {
+        install_mock_websocket(false);
+        let mut socket = PhoenixSocket::new("ws://localhost:4000/socket/websocket", "{}").unwrap();
+        socket.set_reconnect_policy(5, 5, 0.0, 2);
+
+        // The handler replaces itself, which needs the registration borrow released
+        let gave_up = Rc::new(Cell::new(0u32));
+        let on_failed = {
+            let gave_up = gave_up.clone();
+            let connection = socket.connection.clone();
+            Closure::wrap(Box::new(move |attempts: u32| {
+                gave_up.set(attempts);
+                connection.on_reconnect_failed.borrow_mut().take();
+            }) as Box<dyn FnMut(u32)>)
+        };
+        socket.on_reconnect_failed(on_failed.as_ref().unchecked_ref::<Function>().clone());
+
+        socket.connect().unwrap();
+        sleep(5).await;
+        set_mock_fail(true);
+        drop_last_mock();
+
+        sleep(100).await;
+        assert_eq!(gave_up.get(), 2);
+        assert_eq!(mock_socket_count(), 3);
+        assert!(!socket.is_connected());
+        assert!(socket.connection.on_reconnect_failed.borrow().is_none());
+    }
+}
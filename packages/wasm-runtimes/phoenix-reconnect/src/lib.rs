@@ -0,0 +1,78 @@
+//! Reconnect backoff shared by the Phoenix socket bindings
+//!
+//! With the `test-support` feature this crate also provides the mock
+//! WebSocket those bindings test reconnection against.
+
+#[cfg(feature = "test-support")]
+pub mod mock_websocket;
+
+/// Reconnection policy applied when an established connection drops
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReconnectPolicy {
+    pub base_ms: u32,
+    pub max_ms: u32,
+    /// Fraction (0.0-1.0) of each delay that may be shaved off at random
+    pub jitter: f64,
+    /// Attempts before giving up; 0 disables reconnection
+    pub max_attempts: u32,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            base_ms: 1_000,
+            max_ms: 30_000,
+            jitter: 0.2,
+            max_attempts: 10,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Policy from user-supplied settings: `max_ms` is raised to at least
+    /// `base_ms` and `jitter` is clamped to 0.0-1.0
+    pub fn new(base_ms: u32, max_ms: u32, jitter: f64, max_attempts: u32) -> Self {
+        Self {
+            base_ms,
+            max_ms: max_ms.max(base_ms),
+            jitter: jitter.clamp(0.0, 1.0),
+            max_attempts,
+        }
+    }
+
+    /// Delay before the 1-based `attempt`: doubles from `base_ms` up to `max_ms`,
+    /// then `random` (0.0-1.0) scales how much of the jitter is applied
+    pub fn delay_ms(&self, attempt: u32, random: f64) -> u32 {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = (self.base_ms as u64)
+            .saturating_mul(1u64 << exponent)
+            .min(self.max_ms as u64) as f64;
+        let jitter = self.jitter.clamp(0.0, 1.0) * random.clamp(0.0, 1.0);
+        (delay * (1.0 - jitter)).round() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_max_with_jitter() {
+        let policy = ReconnectPolicy::new(100, 1_000, 0.5, 5);
+
+        assert_eq!(policy.delay_ms(1, 0.0), 100);
+        assert_eq!(policy.delay_ms(2, 0.0), 200);
+        assert_eq!(policy.delay_ms(4, 0.0), 800);
+        assert_eq!(policy.delay_ms(5, 0.0), 1_000);
+        assert_eq!(policy.delay_ms(40, 0.0), 1_000);
+        assert_eq!(policy.delay_ms(2, 1.0), 100);
+    }
+
+    #[test]
+    fn new_normalises_settings() {
+        let policy = ReconnectPolicy::new(500, 100, 3.0, 2);
+        assert_eq!(policy.max_ms, 500);
+        assert_eq!(policy.jitter, 1.0);
+        assert_eq!(policy.delay_ms(3, 0.0), 500);
+    }
+}
@@ -0,0 +1,86 @@
+//! A mock `WebSocket` installed on `globalThis` for wasm-bindgen tests.
+//! Each mock settles on the next tick: it opens, or errors and closes
+//! when failure is switched on.
+
+use js_sys::{Function, Promise};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+#[wasm_bindgen(inline_js = r#"
+    export function install_mock_websocket(fail) {
+        globalThis.__mockSockets = [];
+        globalThis.__mockFail = fail;
+        class MockWebSocket {
+            constructor(url) {
+                this.url = url;
+                this.readyState = 0;
+                this.sent = [];
+                globalThis.__mockSockets.push(this);
+                setTimeout(() => {
+                    if (globalThis.__mockFail) {
+                        this.readyState = 3;
+                        if (this.onerror) this.onerror({ type: "error" });
+                        if (this.onclose) this.onclose({ type: "close", code: 1006 });
+                    } else {
+                        this.readyState = 1;
+                        if (this.onopen) this.onopen({ type: "open" });
+                    }
+                }, 0);
+            }
+            send(data) { this.sent.push(data); }
+            close() {
+                this.readyState = 3;
+                if (this.onclose) this.onclose({ type: "close", code: 1000 });
+            }
+        }
+        MockWebSocket.OPEN = 1;
+        globalThis.WebSocket = MockWebSocket;
+    }
+
+    export function set_mock_fail(fail) {
+        globalThis.__mockFail = fail;
+    }
+
+    export function drop_last_mock() {
+        const sockets = globalThis.__mockSockets;
+        const socket = sockets[sockets.length - 1];
+        socket.readyState = 3;
+        if (socket.onclose) socket.onclose({ type: "close", code: 1006 });
+    }
+
+    export function mock_socket_count() {
+        return globalThis.__mockSockets.length;
+    }
+
+    export function last_mock_url() {
+        const sockets = globalThis.__mockSockets;
+        return sockets[sockets.length - 1].url;
+    }
+
+    export function last_mock_sent() {
+        const sockets = globalThis.__mockSockets;
+        return JSON.stringify(sockets[sockets.length - 1].sent);
+    }
+"#)]
+extern "C" {
+    /// Replace `globalThis.WebSocket`; with `fail` every new socket fails
+    pub fn install_mock_websocket(fail: bool);
+    pub fn set_mock_fail(fail: bool);
+    /// Close the newest socket as if the connection dropped
+    pub fn drop_last_mock();
+    pub fn mock_socket_count() -> u32;
+    pub fn last_mock_url() -> String;
+    /// Frames sent on the newest socket, as a JSON array of strings
+    pub fn last_mock_sent() -> String;
+
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &Function, timeout_ms: i32) -> JsValue;
+}
+
+/// Resolve after `ms` milliseconds of timers have run
+pub async fn sleep(ms: i32) {
+    let elapsed = Promise::new(&mut |resolve: Function, _reject: Function| {
+        set_timeout(&resolve, ms);
+    });
+    JsFuture::from(elapsed).await.unwrap();
+}
@@ -0,0 +1,31 @@
+//! Numeric cores behind `KatalystCompute`. The algorithms live in
+//! `katalyst-compute-core`, shared with braun and the Node addon, and are
+//! re-exported here; this module adds what depends on the browser.
+
+pub use katalyst_compute_core::*;
+
+/// Milliseconds from a monotonic clock: `performance.now()` in the browser,
+/// `Instant` since first use everywhere else
+#[cfg(target_arch = "wasm32")]
+pub fn now_ms() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map_or(0.0, |performance| performance.now())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn now_ms() -> f64 {
+    static START: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+    START.get_or_init(std::time::Instant::now).elapsed().as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_now_ms_is_monotonic() {
+        let start = now_ms();
+        assert!(now_ms() >= start);
+    }
+}
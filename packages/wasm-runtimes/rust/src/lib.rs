@@ -6,14 +6,42 @@
 //! - K-means clustering algorithms
 //! - Multithreading support
 //! - Performance monitoring and benchmarking
+//!
+//! Panics abort the whole WASM instance, so `KatalystCompute` validates its
+//! inputs up front instead. `matrix_multiply`, `matmul_handles`, `fft`,
+//! `fft_windowed`, `fft_2d`, `parallel_for_each_chunk` and every
+//! `k_means_*` method return `Result<_, JsValue>` and reject bad shapes with
+//! a message, leaving the instance usable for the next call.
+//!
+//! `dispose()` (also run on `Drop`, i.e. JS `free()`) releases the thread
+//! pool and GPU device; the instance's compute methods then fail with an
+//! error instead of quietly rebuilding them.
+//!
+//! The numeric algorithms live in the `katalyst-compute-core` crate, shared
+//! with braun and the Node addon and re-exported through [`compute`]; the
+//! `wasm_bindgen` types here are thin wrappers that add input validation,
+//! timing and stats.
+//!
+//! With the `webgpu` feature, `enable_webgpu` attaches a GPU device and
+//! `matrix_multiply_auto` sends large products to a tiled WGSL shader,
+//! falling back to the CPU when WebGPU is unavailable or the GPU call fails.
+//! GPU readback is asynchronous, so `matrix_multiply` itself stays on the CPU.
 
 use wasm_bindgen::prelude::*;
 use js_sys::*;
 use web_sys::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use ndarray::{Array1, Array2};
-use rayon::prelude::*;
+
+pub mod compute;
+#[cfg(feature = "webgpu")]
+mod webgpu;
+
+use compute::{k_means, validate_k_means_input, DistanceMetric, WindowFunction};
 
 // Initialize WASM module
 #[wasm_bindgen(start)]
@@ -25,168 +53,383 @@ pub fn main() {
 // Export the main compute interface
 #[wasm_bindgen]
 pub struct KatalystCompute {
-    stats: HashMap<String, f64>,
+    // Shared so async GPU operations can record their timings when they finish
+    recorder: Rc<RefCell<StatsRecorder>>,
     threads: usize,
+    thread_pool: Option<rayon::ThreadPool>,
+    thread_pool_failed: bool,
+    #[cfg(feature = "webgpu")]
+    gpu: Rc<RefCell<Option<Rc<webgpu::GpuMatmul>>>>,
+    /// Owner tag for buffers from this instance's `allocate_buffer`
+    id: u64,
+    disposed: bool,
 }
 
+static NEXT_INSTANCE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Products with fewer multiply-adds than this stay on the CPU in
+/// `matrix_multiply_auto`; below it, buffer upload and readback cost more
+/// than the GPU saves
+pub const GPU_MATMUL_MIN_OPS: usize = 64 * 64 * 64;
+
 #[wasm_bindgen]
 impl KatalystCompute {
     #[wasm_bindgen(constructor)]
     pub fn new() -> KatalystCompute {
         KatalystCompute {
-            stats: HashMap::new(),
+            recorder: Rc::new(RefCell::new(StatsRecorder::default())),
             threads: 4, // Default thread count
+            thread_pool: None,
+            thread_pool_failed: false,
+            #[cfg(feature = "webgpu")]
+            gpu: Rc::new(RefCell::new(None)),
+            id: NEXT_INSTANCE_ID.fetch_add(1, Ordering::Relaxed),
+            disposed: false,
         }
     }
 
-    /// High-performance matrix multiplication using SIMD when available
+    /// Release the thread pool and GPU device now rather than when JS
+    /// garbage-collects the wrapper, and warn about buffers from this
+    /// instance's `allocate_buffer` that were never freed. Calling it again
+    /// does nothing; every compute method on a disposed instance fails.
     #[wasm_bindgen]
-    pub fn matrix_multiply(&mut self, a_data: &[f32], b_data: &[f32], rows_a: usize, cols_a: usize, cols_b: usize) -> Vec<f32> {
-        let start = performance().now();
-        
-        let a = Array2::from_shape_vec((rows_a, cols_a), a_data.to_vec()).unwrap();
-        let b = Array2::from_shape_vec((cols_a, cols_b), b_data.to_vec()).unwrap();
+    pub fn dispose(&mut self) {
+        if self.disposed {
+            return;
+        }
+        self.disposed = true;
+        self.thread_pool = None;
+
+        #[cfg(feature = "webgpu")]
+        if let Some(gpu) = self.gpu.borrow_mut().take() {
+            gpu.destroy();
+        }
+
+        let (count, bytes) = owned_allocations(self.id);
+        if count > 0 {
+            console::warn_1(
+                &format!("{} buffers ({} bytes) from this KatalystCompute's allocate_buffer were never deallocated", count, bytes).into(),
+            );
+        }
+    }
+
+    /// Like the free `allocate_buffer`, but the buffer counts against this
+    /// instance, so `dispose` reports it if it is still allocated. Free it
+    /// with either `deallocate_buffer`.
+    #[wasm_bindgen]
+    pub fn allocate_buffer(&self, size: usize) -> Result<*mut u8, JsValue> {
+        self.ensure_active()?;
+        Ok(register_buffer(size, Some(self.id)))
+    }
+
+    #[wasm_bindgen]
+    pub fn deallocate_buffer(&self, ptr: *mut u8, size: usize) {
+        deallocate_buffer(ptr, size);
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn disposed(&self) -> bool {
+        self.disposed
+    }
+
+    /// High-performance matrix multiplication using SIMD when available.
+    /// Fails if either buffer doesn't match its stated shape.
+    #[wasm_bindgen]
+    pub fn matrix_multiply(&mut self, a_data: &[f32], b_data: &[f32], rows_a: usize, cols_a: usize, cols_b: usize) -> Result<Vec<f32>, JsValue> {
+        self.ensure_active()?;
+        let start = compute::now_ms();
         
-        let result = a.dot(&b);
-        let duration = performance().now() - start;
+        let result = compute::matmul(a_data, b_data, rows_a, cols_a, cols_b)
+            .map_err(|e| JsValue::from_str(&e))?;
+        let duration = compute::now_ms() - start;
         
-        self.stats.insert("matrix_multiply_ms".to_string(), duration);
-        result.into_raw_vec()
+        self.record_operation("matrix_multiply", duration, a_data.len() + b_data.len(), "ndarray", 1);
+        Ok(result)
     }
 
-    /// Fast Fourier Transform implementation
+    /// Request a WebGPU device for `matrix_multiply_auto`. Resolves to
+    /// whether a device was attached; without the `webgpu` feature or a
+    /// usable adapter it resolves to `false` and everything stays on the CPU.
     #[wasm_bindgen]
-    pub fn fft(&mut self, real: &mut [f32], imag: &mut [f32], inverse: bool) {
-        let start = performance().now();
-        let n = real.len();
-        
-        if n <= 1 {
-            return;
+    pub fn enable_webgpu(&self) -> Promise {
+        if self.disposed {
+            return Promise::resolve(&JsValue::FALSE);
         }
-        
-        // Bit-reversal permutation
-        let mut j = 0;
-        for i in 1..n {
-            let mut bit = n >> 1;
-            while j & bit != 0 {
-                j ^= bit;
-                bit >>= 1;
-            }
-            j ^= bit;
-            
-            if i < j {
-                real.swap(i, j);
-                imag.swap(i, j);
-            }
+
+        #[cfg(feature = "webgpu")]
+        {
+            let gpu = self.gpu.clone();
+            wasm_bindgen_futures::future_to_promise(async move {
+                let attached = match webgpu::GpuMatmul::new().await {
+                    Ok(device) => {
+                        *gpu.borrow_mut() = Some(Rc::new(device));
+                        true
+                    }
+                    Err(_) => false,
+                };
+                Ok(JsValue::from_bool(attached))
+            })
         }
-        
-        // Cooley-Tukey FFT
-        let mut length = 2;
-        while length <= n {
-            let angle = if inverse { 2.0 * std::f32::consts::PI / length as f32 } else { -2.0 * std::f32::consts::PI / length as f32 };
-            let wlen_real = angle.cos();
-            let wlen_imag = angle.sin();
-            
-            for i in (0..n).step_by(length) {
-                let mut w_real = 1.0;
-                let mut w_imag = 0.0;
-                
-                for j in 0..(length / 2) {
-                    let u_real = real[i + j];
-                    let u_imag = imag[i + j];
-                    let v_real = real[i + j + length / 2] * w_real - imag[i + j + length / 2] * w_imag;
-                    let v_imag = real[i + j + length / 2] * w_imag + imag[i + j + length / 2] * w_real;
-                    
-                    real[i + j] = u_real + v_real;
-                    imag[i + j] = u_imag + v_imag;
-                    real[i + j + length / 2] = u_real - v_real;
-                    imag[i + j + length / 2] = u_imag - v_imag;
-                    
-                    let w_temp = w_real * wlen_real - w_imag * wlen_imag;
-                    w_imag = w_real * wlen_imag + w_imag * wlen_real;
-                    w_real = w_temp;
-                }
+
+        #[cfg(not(feature = "webgpu"))]
+        Promise::resolve(&JsValue::FALSE)
+    }
+
+    /// Matrix multiplication that picks its backend: products of at least
+    /// `GPU_MATMUL_MIN_OPS` multiply-adds run on the GPU once `enable_webgpu`
+    /// has attached a device, everything else runs the CPU path. A failed GPU
+    /// call falls back to the CPU. Resolves to a `Float32Array`; the chosen
+    /// path (`"webgpu"`, `"ndarray"` or `"ndarray_fallback"`) is recorded in
+    /// the last operation stats.
+    #[wasm_bindgen]
+    pub fn matrix_multiply_auto(&mut self, a_data: Vec<f32>, b_data: Vec<f32>, rows_a: usize, cols_a: usize, cols_b: usize) -> Result<Promise, JsValue> {
+        self.ensure_active()?;
+        compute::validate_matmul_input(a_data.len(), b_data.len(), rows_a, cols_a, cols_b)
+            .map_err(|e| JsValue::from_str(&e))?;
+
+        #[cfg(feature = "webgpu")]
+        if rows_a * cols_a * cols_b >= GPU_MATMUL_MIN_OPS {
+            if let Some(gpu) = self.gpu.borrow().clone() {
+                let recorder = self.recorder.clone();
+                return Ok(wasm_bindgen_futures::future_to_promise(async move {
+                    let start = compute::now_ms();
+                    let input_size = a_data.len() + b_data.len();
+                    let (result, path) = match gpu.multiply(&a_data, &b_data, rows_a, cols_a, cols_b).await {
+                        Ok(result) => (result, "webgpu"),
+                        Err(_) => (
+                            compute::matmul(&a_data, &b_data, rows_a, cols_a, cols_b).map_err(|e| JsValue::from_str(&e))?,
+                            "ndarray_fallback",
+                        ),
+                    };
+                    let duration = compute::now_ms() - start;
+                    recorder.borrow_mut().record("matrix_multiply", duration, input_size, path, 1);
+                    Ok(Float32Array::from(&result[..]).into())
+                }));
             }
-            length <<= 1;
         }
+
+        let result = self.matrix_multiply(&a_data, &b_data, rows_a, cols_a, cols_b)?;
+        Ok(Promise::resolve(&Float32Array::from(&result[..])))
+    }
+
+    /// Multiply two persistent matrices without copying either operand out of
+    /// WASM memory. The product is returned as a new handle so chained
+    /// multiplies stay in WASM memory too.
+    #[wasm_bindgen]
+    pub fn matmul_handles(&mut self, a: &MatrixHandle, b: &MatrixHandle) -> Result<MatrixHandle, JsValue> {
+        self.ensure_active()?;
+        if a.cols() != b.rows() {
+            return Err(JsValue::from_str(&format!(
+                "Cannot multiply {}x{} by {}x{} matrix",
+                a.rows(), a.cols(), b.rows(), b.cols()
+            )));
+        }
+
+        let start = compute::now_ms();
+        let result = a.data.dot(&b.data);
+        let duration = compute::now_ms() - start;
+
+        self.record_operation("matmul_handles", duration, a.data.len() + b.data.len(), "ndarray_handles", 1);
+        Ok(MatrixHandle { data: result })
+    }
+
+    /// Fast Fourier Transform of any length, using the core's cached plan
+    /// for that size. Fails unless both buffers have the same length.
+    #[wasm_bindgen]
+    pub fn fft(&mut self, real: &mut [f32], imag: &mut [f32], inverse: bool) -> Result<(), JsValue> {
+        self.ensure_active()?;
+        let start = compute::now_ms();
         
-        if inverse {
-            let n_f = n as f32;
-            for i in 0..n {
-                real[i] /= n_f;
-                imag[i] /= n_f;
-            }
+        compute::fft(real, imag, inverse).map_err(|e| JsValue::from_str(&e))?;
+        
+        let duration = compute::now_ms() - start;
+        let algorithm = if real.len().is_power_of_two() { "radix2" } else { "bluestein" };
+        self.record_operation("fft", duration, real.len(), algorithm, 1);
+        Ok(())
+    }
+
+    /// Plan repeated FFTs of size `n`, precomputing the bit-reversal
+    /// permutation and twiddle factors (and for non-powers of two the
+    /// Bluestein chirp) once
+    #[wasm_bindgen]
+    pub fn create_fft_plan(&self, n: usize) -> Result<FftPlan, JsValue> {
+        self.ensure_active()?;
+        let inner = compute::FftPlan::new(n).map_err(|e| JsValue::from_str(&e))?;
+        Ok(FftPlan { inner })
+    }
+
+    /// Streaming STFT over `window_size`-sample frames advancing by
+    /// `hop_size` samples, with an optional window function ("hann",
+    /// "hamming" or "blackman"). Each frame's timing is recorded in this
+    /// instance's stats as `stft_frame`.
+    #[wasm_bindgen]
+    pub fn create_stft(&self, window_size: usize, hop_size: usize, window: Option<String>) -> Result<Stft, JsValue> {
+        self.ensure_active()?;
+        let window = window
+            .map(|window| WindowFunction::parse(&window))
+            .transpose()
+            .map_err(|e| JsValue::from_str(&e))?;
+        let inner = compute::Stft::new(window_size, hop_size, window).map_err(|e| JsValue::from_str(&e))?;
+        Ok(Stft { inner, recorder: self.recorder.clone() })
+    }
+
+    /// Multiply `data` in place by a window function ("hann", "hamming" or
+    /// "blackman") to reduce spectral leakage
+    #[wasm_bindgen]
+    pub fn apply_window(&self, data: &mut [f32], window: &str) -> Result<(), JsValue> {
+        self.ensure_active()?;
+        let window = WindowFunction::parse(window).map_err(|e| JsValue::from_str(&e))?;
+        window.apply(data);
+        Ok(())
+    }
+
+    /// Forward FFT with an optional window applied to both input buffers first
+    #[wasm_bindgen]
+    pub fn fft_windowed(&mut self, real: &mut [f32], imag: &mut [f32], window: Option<String>) -> Result<(), JsValue> {
+        self.ensure_active()?;
+        if let Some(window) = window {
+            let window = WindowFunction::parse(&window).map_err(|e| JsValue::from_str(&e))?;
+            window.apply(real);
+            window.apply(imag);
         }
+        self.fft(real, imag, false)
+    }
+
+    /// 2D FFT over a row-major `rows x cols` grid: the 1D FFT is applied to
+    /// every row, then every column. Either dimension may be any size.
+    #[wasm_bindgen]
+    pub fn fft_2d(&mut self, real: &mut [f32], imag: &mut [f32], rows: usize, cols: usize, inverse: bool) -> Result<(), JsValue> {
+        self.ensure_active()?;
+        let start = compute::now_ms();
+
+        compute::fft_2d(real, imag, rows, cols, inverse).map_err(|e| JsValue::from_str(&e))?;
+
+        let duration = compute::now_ms() - start;
+        self.record_operation("fft_2d", duration, real.len(), "rows_columns", 1);
+        Ok(())
+    }
+
+    /// K-means clustering algorithm. Fails unless `data` holds a whole
+    /// number of `dimensions`-sized points and `1 <= k <= points`.
+    #[wasm_bindgen]
+    pub fn k_means_clustering(&mut self, data: &[f32], dimensions: usize, k: usize, max_iterations: usize) -> Result<Vec<u32>, JsValue> {
+        self.ensure_active()?;
+        validate_k_means_input(data, dimensions, k).map_err(|e| JsValue::from_str(&e))?;
+        let start = compute::now_ms();
+        
+        let assignments = k_means(data, dimensions, k, max_iterations, DistanceMetric::Euclidean, None).assignments;
+        
+        let duration = compute::now_ms() - start;
+        self.record_operation("k_means", duration, data.len(), "sequential", 1);
         
-        let duration = performance().now() - start;
-        self.stats.insert("fft_ms".to_string(), duration);
+        Ok(assignments)
     }
 
-    /// K-means clustering algorithm
+    /// `k_means_clustering` over a `Float64Array`, clustered in f64 without
+    /// first copying into a `Float32Array`. The f32 method halves the memory
+    /// traffic and is usually faster; this one avoids the copy and keeps
+    /// full precision, which matters when coordinates are large (beyond
+    /// about 2^24, f32 cannot resolve unit differences) or clusters are
+    /// separated by less than f32's ~7 significant digits. Points nearly
+    /// equidistant from two centroids may be assigned differently by the two
+    /// methods.
     #[wasm_bindgen]
-    pub fn k_means_clustering(&mut self, data: &[f32], dimensions: usize, k: usize, max_iterations: usize) -> Vec<u32> {
-        let start = performance().now();
-        let n_points = data.len() / dimensions;
+    pub fn k_means_clustering_f64(&mut self, data: &[f64], dimensions: usize, k: usize, max_iterations: usize) -> Result<Vec<u32>, JsValue> {
+        self.ensure_active()?;
+        validate_k_means_input(data, dimensions, k).map_err(|e| JsValue::from_str(&e))?;
+        let start = compute::now_ms();
         
-        // Initialize centroids randomly
-        let mut centroids = vec![0.0; k * dimensions];
-        for i in 0..k {
-            for j in 0..dimensions {
-                centroids[i * dimensions + j] = data[(i * n_points / k) * dimensions + j];
-            }
-        }
+        let assignments = k_means(data, dimensions, k, max_iterations, DistanceMetric::Euclidean, None).assignments;
         
-        let mut assignments = vec![0u32; n_points];
+        let duration = compute::now_ms() - start;
+        self.record_operation("k_means_f64", duration, data.len(), "sequential", 1);
         
-        for _iteration in 0..max_iterations {
-            // Assign points to closest centroids
-            for point_idx in 0..n_points {
-                let mut best_distance = f32::INFINITY;
-                let mut best_centroid = 0;
-                
-                for centroid_idx in 0..k {
-                    let mut distance = 0.0;
-                    for dim in 0..dimensions {
-                        let diff = data[point_idx * dimensions + dim] - centroids[centroid_idx * dimensions + dim];
-                        distance += diff * diff;
-                    }
-                    
-                    if distance < best_distance {
-                        best_distance = distance;
-                        best_centroid = centroid_idx;
-                    }
-                }
-                
-                assignments[point_idx] = best_centroid as u32;
-            }
-            
-            // Update centroids
-            let mut new_centroids = vec![0.0; k * dimensions];
-            let mut counts = vec![0; k];
-            
-            for point_idx in 0..n_points {
-                let cluster = assignments[point_idx] as usize;
-                counts[cluster] += 1;
-                for dim in 0..dimensions {
-                    new_centroids[cluster * dimensions + dim] += data[point_idx * dimensions + dim];
-                }
-            }
-            
-            for cluster in 0..k {
-                if counts[cluster] > 0 {
-                    for dim in 0..dimensions {
-                        new_centroids[cluster * dimensions + dim] /= counts[cluster] as f32;
-                    }
-                }
-            }
-            
-            centroids = new_centroids;
-        }
+        Ok(assignments)
+    }
+
+    /// K-means with a selectable distance metric: "euclidean" (mean
+    /// centroids), "manhattan" (per-dimension median centroids) or "cosine"
+    /// (unit-length mean centroids, for direction-only data such as embeddings)
+    #[wasm_bindgen]
+    pub fn k_means_clustering_with_metric(&mut self, data: &[f32], dimensions: usize, k: usize, max_iterations: usize, metric: &str) -> Result<Vec<u32>, JsValue> {
+        self.ensure_active()?;
+        let metric = DistanceMetric::parse(metric).map_err(|e| JsValue::from_str(&e))?;
+        validate_k_means_input(data, dimensions, k).map_err(|e| JsValue::from_str(&e))?;
+        let start = compute::now_ms();
         
-        let duration = performance().now() - start;
-        self.stats.insert("k_means_ms".to_string(), duration);
+        let assignments = k_means(data, dimensions, k, max_iterations, metric, None).assignments;
         
-        assignments
+        let duration = compute::now_ms() - start;
+        self.record_operation(&format!("k_means_{}", metric.name()), duration, data.len(), "sequential", 1);
+        
+        Ok(assignments)
+    }
+
+    /// K-means returning inertia, iteration count and per-iteration inertia
+    /// alongside the assignments. `metric` defaults to "euclidean".
+    #[wasm_bindgen]
+    pub fn k_means_with_diagnostics(&mut self, data: &[f32], dimensions: usize, k: usize, max_iterations: usize, metric: Option<String>) -> Result<KMeansResult, JsValue> {
+        self.ensure_active()?;
+        let metric = match metric {
+            Some(metric) => DistanceMetric::parse(&metric).map_err(|e| JsValue::from_str(&e))?,
+            None => DistanceMetric::Euclidean,
+        };
+        validate_k_means_input(data, dimensions, k).map_err(|e| JsValue::from_str(&e))?;
+        let start = compute::now_ms();
+        
+        let result = k_means(data, dimensions, k, max_iterations, metric, None);
+        
+        let duration = compute::now_ms() - start;
+        self.record_operation("k_means_diagnostics", duration, data.len(), "sequential", 1);
+        
+        Ok(result.into())
+    }
+
+    /// K-means with the assignment step spread over a rayon pool of
+    /// `self.threads` workers. Falls back to the sequential path when the
+    /// `threads` feature is off or no shared-memory pool can be started;
+    /// either way the assignments match `k_means_clustering` exactly.
+    #[wasm_bindgen]
+    pub fn k_means_clustering_parallel(&mut self, data: &[f32], dimensions: usize, k: usize, max_iterations: usize) -> Result<Vec<u32>, JsValue> {
+        self.ensure_active()?;
+        validate_k_means_input(data, dimensions, k).map_err(|e| JsValue::from_str(&e))?;
+        let start = compute::now_ms();
+        
+        let pool = self.thread_pool();
+        let threads = pool.map_or(1, |pool| pool.current_num_threads());
+        let path = if pool.is_some() { "parallel" } else { "sequential_fallback" };
+        let assignments = k_means(data, dimensions, k, max_iterations, DistanceMetric::Euclidean, pool).assignments;
+        
+        let duration = compute::now_ms() - start;
+        self.record_operation("k_means_parallel", duration, data.len(), path, threads);
+        self.recorder.borrow_mut().stats.insert("k_means_parallel_threads".to_string(), threads as f64);
+        
+        Ok(assignments)
+    }
+
+    /// Split `data` into `chunk`-sized rows and apply a built-in op to each:
+    /// "normalize" (unit L2 norm), "scale" (multiply by `factor`, which it
+    /// requires), "relu" or "softmax-row". Rows are spread across the
+    /// thread pool when the `threads` feature is active and fall back to a
+    /// sequential loop otherwise; both give the same result. Fails unless
+    /// `chunk` divides `data.len()`.
+    #[wasm_bindgen]
+    pub fn parallel_for_each_chunk(&mut self, data: &[f32], chunk: usize, op: &str, factor: Option<f32>) -> Result<Vec<f32>, JsValue> {
+        self.ensure_active()?;
+        let op = compute::ChunkOp::parse(op, factor).map_err(|e| JsValue::from_str(&e))?;
+        compute::validate_chunking(data.len(), chunk).map_err(|e| JsValue::from_str(&e))?;
+        let start = compute::now_ms();
+        
+        let mut result = data.to_vec();
+        let pool = self.thread_pool();
+        let threads = pool.map_or(1, |pool| pool.current_num_threads());
+        let path = if pool.is_some() { "parallel" } else { "sequential_fallback" };
+        compute::for_each_chunk(&mut result, chunk, op, pool).map_err(|e| JsValue::from_str(&e))?;
+        
+        let duration = compute::now_ms() - start;
+        self.record_operation("parallel_for_each_chunk", duration, data.len(), path, threads);
+        
+        Ok(result)
     }
 
     /// Run comprehensive benchmark suite
@@ -199,21 +442,21 @@ impl KatalystCompute {
         let a = vec![1.0f32; size * size];
         let b = vec![2.0f32; size * size];
         let _ = self.matrix_multiply(&a, &b, size, size, size);
-        results.insert("matrix_multiply_128x128", self.stats.get("matrix_multiply_ms").unwrap_or(&0.0).clone());
+        results.insert("matrix_multiply_128x128", self.stat("matrix_multiply_ms"));
         
         // FFT benchmark
         let fft_size = 1024;
         let mut real = vec![1.0f32; fft_size];
         let mut imag = vec![0.0f32; fft_size];
-        self.fft(&mut real, &mut imag, false);
-        results.insert("fft_1024", self.stats.get("fft_ms").unwrap_or(&0.0).clone());
+        let _ = self.fft(&mut real, &mut imag, false);
+        results.insert("fft_1024", self.stat("fft_ms"));
         
         // K-means benchmark
         let n_points = 1000;
         let dimensions = 3;
         let data: Vec<f32> = (0..(n_points * dimensions)).map(|i| (i as f32).sin()).collect();
         let _ = self.k_means_clustering(&data, dimensions, 5, 10);
-        results.insert("k_means_1000pts_3d", self.stats.get("k_means_ms").unwrap_or(&0.0).clone());
+        results.insert("k_means_1000pts_3d", self.stat("k_means_ms"));
         
         serde_json::to_string(&results).unwrap_or_else(|_| "{}".to_string())
     }
@@ -221,7 +464,21 @@ impl KatalystCompute {
     /// Get performance statistics
     #[wasm_bindgen]
     pub fn get_performance_stats(&self) -> String {
-        serde_json::to_string(&self.stats).unwrap_or_else(|_| "{}".to_string())
+        serde_json::to_string(&self.recorder.borrow().stats).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// Details of the most recent timed operation, if any
+    #[wasm_bindgen]
+    pub fn get_last_operation_stats(&self) -> Option<OperationStats> {
+        self.recorder.borrow().last_operation.clone()
+    }
+
+    /// Forget all recorded timings
+    #[wasm_bindgen]
+    pub fn clear_stats(&mut self) {
+        let mut recorder = self.recorder.borrow_mut();
+        recorder.stats.clear();
+        recorder.last_operation = None;
     }
 
     /// Get WASM capabilities
@@ -231,6 +488,8 @@ impl KatalystCompute {
             "simd": cfg!(feature = "simd"),
             "threads": cfg!(feature = "threads"),
             "thread_count": self.threads,
+            "webgpu": webgpu_available(),
+            "webgpu_enabled": self.webgpu_enabled(),
             "memory_64": false, // wasm32 doesn't support 64-bit memory
             "bulk_memory": true,
             "multivalue": true,
@@ -244,6 +503,235 @@ impl KatalystCompute {
     #[wasm_bindgen]
     pub fn set_thread_count(&mut self, threads: usize) {
         self.threads = threads.max(1);
+        self.thread_pool = None;
+        self.thread_pool_failed = false;
+    }
+}
+
+impl KatalystCompute {
+    fn ensure_active(&self) -> Result<(), JsValue> {
+        if self.disposed {
+            return Err(JsValue::from_str("KatalystCompute has been disposed"));
+        }
+        Ok(())
+    }
+
+    fn record_operation(&mut self, operation: &str, duration_ms: f64, input_size: usize, path: &str, threads: usize) {
+        self.recorder.borrow_mut().record(operation, duration_ms, input_size, path, threads);
+    }
+
+    fn stat(&self, key: &str) -> f64 {
+        self.recorder.borrow().stats.get(key).copied().unwrap_or(0.0)
+    }
+
+    fn webgpu_enabled(&self) -> bool {
+        #[cfg(feature = "webgpu")]
+        return self.gpu.borrow().is_some();
+
+        #[cfg(not(feature = "webgpu"))]
+        false
+    }
+
+    /// Lazily start the rayon pool used by parallel operations. Returns
+    /// `None` when threads are disabled or the host has no shared-memory
+    /// workers, in which case callers run sequentially.
+    fn thread_pool(&mut self) -> Option<&rayon::ThreadPool> {
+        if !cfg!(feature = "threads") || self.threads <= 1 || self.disposed {
+            return None;
+        }
+        
+        if self.thread_pool.is_none() && !self.thread_pool_failed {
+            match rayon::ThreadPoolBuilder::new().num_threads(self.threads).build() {
+                Ok(pool) => self.thread_pool = Some(pool),
+                Err(e) => {
+                    console::warn_1(&format!("Thread pool unavailable, running sequentially: {}", e).into());
+                    self.thread_pool_failed = true;
+                }
+            }
+        }
+        
+        self.thread_pool.as_ref()
+    }
+}
+
+/// Whether the host exposes WebGPU and this build can use it. A `true`
+/// result can still fail to produce a device; see
+/// `KatalystCompute::enable_webgpu`.
+#[wasm_bindgen]
+pub fn webgpu_available() -> bool {
+    #[cfg(feature = "webgpu")]
+    return webgpu::is_supported();
+
+    #[cfg(not(feature = "webgpu"))]
+    false
+}
+
+/// Cumulative timings plus the most recent operation
+#[derive(Default)]
+struct StatsRecorder {
+    stats: HashMap<String, f64>,
+    last_operation: Option<OperationStats>,
+}
+
+impl StatsRecorder {
+    /// Record a finished operation as the last operation and under
+    /// `"{operation}_ms"` in the cumulative stats map
+    fn record(&mut self, operation: &str, duration_ms: f64, input_size: usize, path: &str, threads: usize) {
+        self.stats.insert(format!("{}_ms", operation), duration_ms);
+        self.last_operation = Some(OperationStats {
+            operation: operation.to_string(),
+            duration_ms,
+            input_size,
+            path: path.to_string(),
+            threads,
+        });
+    }
+}
+
+/// Timing and execution details for a single operation
+#[wasm_bindgen]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationStats {
+    operation: String,
+    duration_ms: f64,
+    input_size: usize,
+    path: String,
+    threads: usize,
+}
+
+#[wasm_bindgen]
+impl OperationStats {
+    #[wasm_bindgen(getter)]
+    pub fn operation(&self) -> String {
+        self.operation.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn duration_ms(&self) -> f64 {
+        self.duration_ms
+    }
+
+    /// Number of input elements processed
+    #[wasm_bindgen(getter)]
+    pub fn input_size(&self) -> usize {
+        self.input_size
+    }
+
+    /// Implementation path taken, e.g. "parallel" or "sequential_fallback"
+    #[wasm_bindgen(getter)]
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    #[wasm_bindgen]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| "{}".to_string())
+    }
+}
+
+/// Reusable FFT for one fixed size, created by `KatalystCompute::create_fft_plan`
+#[wasm_bindgen]
+pub struct FftPlan {
+    inner: compute::FftPlan<f32>,
+}
+
+#[wasm_bindgen]
+impl FftPlan {
+    #[wasm_bindgen(getter)]
+    pub fn size(&self) -> usize {
+        self.inner.size()
+    }
+
+    /// Transform in place; matches `KatalystCompute::fft` exactly
+    #[wasm_bindgen]
+    pub fn execute(&self, real: &mut [f32], imag: &mut [f32], inverse: bool) -> Result<(), JsValue> {
+        self.inner.execute(real, imag, inverse).map_err(|e| JsValue::from_str(&e))
+    }
+}
+
+/// Sliding STFT created by `KatalystCompute::create_stft`. Samples can be
+/// pushed in chunks of any size; the overlap between frames stays buffered
+/// here, and the FFT plan and scratch buffers are reused for every frame.
+#[wasm_bindgen]
+pub struct Stft {
+    inner: compute::Stft,
+    recorder: Rc<RefCell<StatsRecorder>>,
+}
+
+#[wasm_bindgen]
+impl Stft {
+    /// Magnitudes per frame: `window_size / 2 + 1`
+    #[wasm_bindgen(getter)]
+    pub fn bins(&self) -> usize {
+        self.inner.bins()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn frames_emitted(&self) -> usize {
+        self.inner.frames_emitted()
+    }
+
+    /// Buffer `samples` and return the magnitude spectra of every frame
+    /// they complete, concatenated: `frames * bins` values, oldest first
+    #[wasm_bindgen]
+    pub fn push(&mut self, samples: &[f32]) -> Vec<f32> {
+        self.inner.push(samples);
+
+        let path = self.inner.window().map_or("rectangular", |window| window.name());
+        let mut spectra = Vec::new();
+        loop {
+            let start = compute::now_ms();
+            let Some(frame) = self.inner.next_frame() else { break };
+            let duration = compute::now_ms() - start;
+
+            self.recorder.borrow_mut().record("stft_frame", duration, self.inner.window_size(), path, 1);
+            spectra.extend(frame);
+        }
+        spectra
+    }
+
+    /// Drop buffered samples so the next frame starts from fresh input
+    #[wasm_bindgen]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+/// Row-major matrix owned by WASM memory, created once from a JS buffer and
+/// reused across operations
+#[wasm_bindgen]
+pub struct MatrixHandle {
+    data: Array2<f32>,
+}
+
+#[wasm_bindgen]
+impl MatrixHandle {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f32>, rows: usize, cols: usize) -> Result<MatrixHandle, JsValue> {
+        let data = Array2::from_shape_vec((rows, cols), data)
+            .map_err(|e| JsValue::from_str(&format!("Invalid {}x{} matrix: {}", rows, cols, e)))?;
+        Ok(MatrixHandle { data })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn rows(&self) -> usize {
+        self.data.nrows()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn cols(&self) -> usize {
+        self.data.ncols()
+    }
+
+    /// Copy the matrix out as a row-major buffer
+    #[wasm_bindgen]
+    pub fn to_vec(&self) -> Vec<f32> {
+        self.data.iter().copied().collect()
     }
 }
 
@@ -254,19 +742,83 @@ pub fn get_wasm_capabilities() -> String {
     compute.get_capabilities()
 }
 
+/// Whether two flattened matrices have the same length and every pair of
+/// entries is within `max(abs_tol, rel_tol * max(|a|, |b|))`, for checking
+/// results against a reference without brittle `==` on floats
 #[wasm_bindgen]
-pub fn allocate_buffer(size: usize) -> *mut u8 {
-    let mut vec = Vec::with_capacity(size);
-    vec.resize(size, 0);
+pub fn approx_equal_matrix(a: &[f32], b: &[f32], rel_tol: f32, abs_tol: f32) -> bool {
+    compute::approx_equal_matrix(a, b, rel_tol, abs_tol)
+}
+
+impl Drop for KatalystCompute {
+    fn drop(&mut self) {
+        self.dispose();
+    }
+}
+
+struct Allocation {
+    size: usize,
+    /// The `KatalystCompute` that allocated the buffer, if any
+    owner: Option<u64>,
+}
+
+/// Buffers `allocate_buffer` has handed out and `deallocate_buffer` hasn't
+/// freed yet, by address
+static ALLOCATIONS: std::sync::Mutex<BTreeMap<usize, Allocation>> = std::sync::Mutex::new(BTreeMap::new());
+
+/// Number and total bytes of buffers from `allocate_buffer` not yet freed
+fn outstanding_allocations() -> (usize, usize) {
+    let allocations = ALLOCATIONS.lock().unwrap_or_else(|e| e.into_inner());
+    (allocations.len(), allocations.values().map(|allocation| allocation.size).sum())
+}
+
+/// `outstanding_allocations`, counting only buffers `owner` allocated
+fn owned_allocations(owner: u64) -> (usize, usize) {
+    let allocations = ALLOCATIONS.lock().unwrap_or_else(|e| e.into_inner());
+    allocations
+        .values()
+        .filter(|allocation| allocation.owner == Some(owner))
+        .fold((0, 0), |(count, bytes), allocation| (count + 1, bytes + allocation.size))
+}
+
+/// Bytes currently held by buffers from `allocate_buffer`
+#[wasm_bindgen]
+pub fn outstanding_buffer_bytes() -> usize {
+    outstanding_allocations().1
+}
+
+fn register_buffer(size: usize, owner: Option<u64>) -> *mut u8 {
+    let mut vec = vec![0u8; size].into_boxed_slice();
     let ptr = vec.as_mut_ptr();
     std::mem::forget(vec);
+    ALLOCATIONS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(ptr as usize, Allocation { size, owner });
     ptr
 }
 
+#[wasm_bindgen]
+pub fn allocate_buffer(size: usize) -> *mut u8 {
+    register_buffer(size, None)
+}
+
+/// Free a buffer from `allocate_buffer`. Pointers it didn't hand out, sizes
+/// that don't match and repeated frees are ignored with a warning instead of
+/// corrupting the heap.
 #[wasm_bindgen]
 pub fn deallocate_buffer(ptr: *mut u8, size: usize) {
-    unsafe {
-        let _ = Vec::from_raw_parts(ptr, size, size);
+    let registered = ALLOCATIONS.lock().unwrap_or_else(|e| e.into_inner()).remove(&(ptr as usize));
+    match registered {
+        Some(allocation) if allocation.size == size => unsafe {
+            let _ = Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, size));
+        },
+        Some(allocation) => {
+            let allocated = allocation.size;
+            ALLOCATIONS.lock().unwrap_or_else(|e| e.into_inner()).insert(ptr as usize, allocation);
+            console::warn_1(&format!("deallocate_buffer: buffer is {} bytes, not {}", allocated, size).into());
+        }
+        None => console::warn_1(&"deallocate_buffer: pointer was not allocated by allocate_buffer".into()),
     }
 }
 
@@ -291,7 +843,639 @@ pub fn get_build_info() -> String {
     }).to_string()
 }
 
-// Helper to get performance API
-fn performance() -> Performance {
-    web_sys::window().unwrap().performance().unwrap()
-}
\ No newline at end of file
+/// Smallest module using a SIMD instruction (`i8x16.popcnt`); it only
+/// validates where the engine supports fixed-width SIMD
+const SIMD_PROBE_MODULE: [u8; 31] = [
+    0, 97, 115, 109, 1, 0, 0, 0, 1, 5, 1, 96, 0, 1, 123, 3, 2, 1, 0, 10, 10, 1, 8, 0, 65, 0, 253, 15, 253, 98, 11,
+];
+
+/// Smallest module using `memory.copy`; it only validates where the engine
+/// supports bulk memory operations
+const BULK_MEMORY_PROBE_MODULE: [u8; 39] = [
+    0, 97, 115, 109, 1, 0, 0, 0, 1, 4, 1, 96, 0, 0, 3, 2, 1, 0, 5, 3, 1, 0, 1, 10, 14, 1, 12, 0, 65, 0, 65, 0, 65, 0,
+    252, 10, 0, 0, 11,
+];
+
+/// Whether one capability was built in and whether it actually works here
+#[derive(Debug, Clone, PartialEq)]
+struct CapabilityProbe {
+    compiled: bool,
+    runtime_available: bool,
+    error: Option<String>,
+}
+
+impl CapabilityProbe {
+    fn new(compiled: bool, probe: Result<(), String>) -> Self {
+        CapabilityProbe {
+            compiled,
+            runtime_available: probe.is_ok(),
+            error: probe.err(),
+        }
+    }
+
+    /// "available", "compiled_but_unavailable", "available_not_compiled" or
+    /// "unavailable"; the second is the usual answer to "why isn't the SIMD
+    /// build faster"
+    fn status(&self) -> &'static str {
+        match (self.compiled, self.runtime_available) {
+            (true, true) => "available",
+            (true, false) => "compiled_but_unavailable",
+            (false, true) => "available_not_compiled",
+            (false, false) => "unavailable",
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "compiled": self.compiled,
+            "runtime_available": self.runtime_available,
+            "error": self.error,
+            "status": self.status(),
+        })
+    }
+}
+
+fn validate_module(bytes: &[u8]) -> Result<(), String> {
+    match WebAssembly::validate(&Uint8Array::from(bytes).into()) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err("the engine rejected a probe module using this feature".to_string()),
+        Err(e) => Err(format!("WebAssembly.validate failed: {:?}", e)),
+    }
+}
+
+fn probe_simd() -> Result<(), String> {
+    validate_module(&SIMD_PROBE_MODULE)?;
+
+    // Run a lane-wise add to confirm the instructions this build uses work
+    #[cfg(target_feature = "simd128")]
+    {
+        use std::arch::wasm32::{f32x4, f32x4_add, f32x4_extract_lane};
+        let sum = f32x4_add(f32x4(1.0, 2.0, 3.0, 4.0), f32x4(4.0, 3.0, 2.0, 1.0));
+        if f32x4_extract_lane::<0>(sum) != 5.0 || f32x4_extract_lane::<3>(sum) != 5.0 {
+            return Err("a SIMD add returned the wrong result".to_string());
+        }
+    }
+    Ok(())
+}
+
+fn probe_threads() -> Result<(), String> {
+    let global = js_sys::global();
+    let has_shared_memory = Reflect::has(&global, &JsValue::from_str("SharedArrayBuffer")).unwrap_or(false);
+    if !has_shared_memory {
+        return Err("SharedArrayBuffer is missing; serve the page with COOP/COEP headers".to_string());
+    }
+    let isolated = Reflect::get(&global, &JsValue::from_str("crossOriginIsolated"))
+        .map(|value| value.is_truthy())
+        .unwrap_or(false);
+    if !isolated {
+        return Err("the page is not cross-origin isolated, so workers cannot share memory".to_string());
+    }
+
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .map(drop)
+        .map_err(|e| format!("could not spawn a worker thread: {}", e))
+}
+
+fn probe_bulk_memory() -> Result<(), String> {
+    validate_module(&BULK_MEMORY_PROBE_MODULE)?;
+
+    // Lowered to `memory.copy` when the build enables bulk memory
+    let mut buffer: Vec<u8> = (0..64).collect();
+    buffer.copy_within(0..32, 32);
+    if buffer[32..] != buffer[..32] {
+        return Err("an overlapping memory copy returned the wrong bytes".to_string());
+    }
+    Ok(())
+}
+
+fn probe_webgpu() -> Result<(), String> {
+    if webgpu_available() {
+        Ok(())
+    } else if cfg!(feature = "webgpu") {
+        Err("navigator.gpu is missing".to_string())
+    } else {
+        Err("built without the webgpu feature".to_string())
+    }
+}
+
+/// Probe each capability `get_build_info` advertises instead of trusting
+/// compile-time flags: validate a SIMD and a bulk-memory probe module and
+/// run the matching operation, and try to spawn a worker thread. Returns
+/// JSON of `{feature: {compiled, runtime_available, error, status}}`.
+#[wasm_bindgen]
+pub fn diagnose_runtime() -> String {
+    let probes = [
+        ("simd", CapabilityProbe::new(cfg!(feature = "simd") && cfg!(target_feature = "simd128"), probe_simd())),
+        ("threads", CapabilityProbe::new(cfg!(feature = "threads") && cfg!(target_feature = "atomics"), probe_threads())),
+        ("bulk_memory", CapabilityProbe::new(cfg!(target_feature = "bulk-memory"), probe_bulk_memory())),
+        ("webgpu", CapabilityProbe::new(cfg!(feature = "webgpu"), probe_webgpu())),
+    ];
+
+    let report: serde_json::Map<String, serde_json::Value> = probes
+        .iter()
+        .map(|(feature, probe)| (feature.to_string(), probe.to_json()))
+        .collect();
+    serde_json::Value::Object(report).to_string()
+}
+
+/// K-means assignments with convergence diagnostics
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct KMeansResult {
+    assignments: Vec<u32>,
+    inertia_history: Vec<f64>,
+    iterations: usize,
+    converged: bool,
+}
+
+#[wasm_bindgen]
+impl KMeansResult {
+    #[wasm_bindgen(getter)]
+    pub fn assignments(&self) -> Vec<u32> {
+        self.assignments.clone()
+    }
+
+    /// Sum of each point's distance to its assigned centroid (squared for
+    /// euclidean) after the final iteration
+    #[wasm_bindgen(getter)]
+    pub fn inertia(&self) -> f64 {
+        self.inertia_history.last().copied().unwrap_or(0.0)
+    }
+
+    /// Inertia after each iteration's assignment step
+    #[wasm_bindgen(getter)]
+    pub fn inertia_history(&self) -> Vec<f64> {
+        self.inertia_history.clone()
+    }
+
+    /// Iterations actually run, at most `max_iterations`
+    #[wasm_bindgen(getter)]
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Whether assignments stopped changing before `max_iterations`
+    #[wasm_bindgen(getter)]
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+}
+
+impl<T> From<compute::KMeans<T>> for KMeansResult {
+    fn from(result: compute::KMeans<T>) -> Self {
+        KMeansResult {
+            assignments: result.assignments,
+            inertia_history: result.inertia_history,
+            iterations: result.iterations,
+            converged: result.converged,
+        }
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn sequence(len: usize, scale: f32) -> Vec<f32> {
+        (0..len).map(|i| ((i % 7) as f32 - 3.0) * scale).collect()
+    }
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert!(approx_equal_matrix(actual, expected, 1e-4, 1e-4), "{:?} != {:?}", actual, expected);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_chained_matmul_handles_match_one_shot() {
+        let mut compute = KatalystCompute::new();
+        let (a, b, c, d) = (sequence(4 * 3, 0.5), sequence(3 * 5, 0.25), sequence(5 * 2, 1.0), sequence(2 * 6, 0.75));
+
+        let ab = compute.matrix_multiply(&a, &b, 4, 3, 5).unwrap();
+        let abc = compute.matrix_multiply(&ab, &c, 4, 5, 2).unwrap();
+        let expected = compute.matrix_multiply(&abc, &d, 4, 2, 6).unwrap();
+
+        let a = MatrixHandle::new(a, 4, 3).unwrap();
+        let b = MatrixHandle::new(b, 3, 5).unwrap();
+        let c = MatrixHandle::new(c, 5, 2).unwrap();
+        let d = MatrixHandle::new(d, 2, 6).unwrap();
+
+        let ab = compute.matmul_handles(&a, &b).unwrap();
+        let abc = compute.matmul_handles(&ab, &c).unwrap();
+        let abcd = compute.matmul_handles(&abc, &d).unwrap();
+
+        assert_eq!((abcd.rows(), abcd.cols()), (4, 6));
+        assert_close(&abcd.to_vec(), &expected);
+        assert!(compute.matmul_handles(&a, &c).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_fft_2d_round_trip() {
+        let mut compute = KatalystCompute::new();
+        let (rows, cols) = (8, 16);
+        let image: Vec<f32> = (0..rows * cols)
+            .map(|i| {
+                let (r, c) = ((i / cols) as f32, (i % cols) as f32);
+                (r * 0.7).sin() + (c * 0.3).cos() + if (r + c) as usize % 5 == 0 { 1.0 } else { 0.0 }
+            })
+            .collect();
+
+        let mut real = image.clone();
+        let mut imag = vec![0.0f32; rows * cols];
+        compute.fft_2d(&mut real, &mut imag, rows, cols, false).unwrap();
+
+        // DC term is the sum of the image
+        let sum: f32 = image.iter().sum();
+        assert!((real[0] - sum).abs() < 1e-3);
+
+        compute.fft_2d(&mut real, &mut imag, rows, cols, true).unwrap();
+        assert_close(&real, &image);
+        assert!(imag.iter().all(|v| v.abs() < 1e-4));
+
+        let stats: HashMap<String, f64> = serde_json::from_str(&compute.get_performance_stats()).unwrap();
+        assert!(stats.contains_key("fft_2d_ms"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_fft_2d_rejects_bad_dimensions() {
+        let mut compute = KatalystCompute::new();
+        let mut real = vec![0.0f32; 12];
+        let mut imag = vec![0.0f32; 12];
+
+        compute.fft_2d(&mut real, &mut imag, 3, 4, false).unwrap();
+        assert!(compute.fft_2d(&mut real, &mut imag, 4, 4, false).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_fft_matches_core_spectrum_exactly() {
+        let mut compute = KatalystCompute::new();
+        for n in [8, 12, 100] {
+            let signal = sequence(n, 0.5);
+            let (mut real, mut imag) = (signal.clone(), vec![0.0f32; n]);
+            compute.fft(&mut real, &mut imag, false).unwrap();
+
+            let (mut core_real, mut core_imag) = (signal, vec![0.0f32; n]);
+            compute::fft(&mut core_real, &mut core_imag, false).unwrap();
+            assert_eq!(real, core_real);
+            assert_eq!(imag, core_imag);
+        }
+        assert_eq!(compute.get_last_operation_stats().unwrap().path(), "bluestein");
+    }
+
+    /// Fraction of spectral energy more than three bins away from the peak
+    fn sidelobe_energy(real: &[f32], imag: &[f32]) -> f32 {
+        let half = real.len() / 2;
+        let power: Vec<f32> = (0..half).map(|i| real[i] * real[i] + imag[i] * imag[i]).collect();
+        let peak = (0..half).max_by(|&a, &b| power[a].total_cmp(&power[b])).unwrap();
+        let total: f32 = power.iter().sum();
+        let leaked: f32 = power
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i.abs_diff(peak) > 3)
+            .map(|(_, p)| p)
+            .sum();
+        leaked / total
+    }
+
+    #[wasm_bindgen_test]
+    fn test_hann_window_endpoints() {
+        let compute = KatalystCompute::new();
+        let mut data = vec![1.0f32; 65];
+        compute.apply_window(&mut data, "hann").unwrap();
+
+        assert!(data[0].abs() < 1e-6);
+        assert!(data[64].abs() < 1e-6);
+        assert!((data[32] - 1.0).abs() < 1e-6);
+
+        let err = compute.apply_window(&mut data, "kaiser").unwrap_err();
+        assert!(err.as_string().unwrap().contains("kaiser"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_windowing_reduces_sidelobes() {
+        let mut compute = KatalystCompute::new();
+        let n = 256;
+        // 10.5 cycles per frame falls between bins, the worst case for leakage
+        let tone: Vec<f32> = (0..n)
+            .map(|i| (2.0 * std::f32::consts::PI * 10.5 * i as f32 / n as f32).sin())
+            .collect();
+
+        let mut plain_real = tone.clone();
+        let mut plain_imag = vec![0.0f32; n];
+        compute.fft_windowed(&mut plain_real, &mut plain_imag, None).unwrap();
+
+        for window in ["hann", "hamming", "blackman"] {
+            let mut real = tone.clone();
+            let mut imag = vec![0.0f32; n];
+            compute.fft_windowed(&mut real, &mut imag, Some(window.to_string())).unwrap();
+
+            assert!(
+                sidelobe_energy(&real, &imag) < sidelobe_energy(&plain_real, &plain_imag),
+                "{} window did not reduce leakage",
+                window
+            );
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_stft_tone_has_stable_peak() {
+        let compute = KatalystCompute::new();
+        let mut stft = compute.create_stft(128, 32, Some("hann".to_string())).unwrap();
+        let bins = stft.bins();
+        // Bin 12 of a 128-point frame
+        let tone: Vec<f32> = (0..1024)
+            .map(|i| (2.0 * std::f32::consts::PI * 12.0 * i as f32 / 128.0).sin())
+            .collect();
+
+        let mut spectra = Vec::new();
+        for chunk in tone.chunks(100) {
+            spectra.extend(stft.push(chunk));
+        }
+
+        assert_eq!(spectra.len() % bins, 0);
+        assert_eq!(spectra.len() / bins, (1024 - 128) / 32 + 1);
+        assert_eq!(stft.frames_emitted(), spectra.len() / bins);
+        for frame in spectra.chunks(bins) {
+            let peak = (0..bins).max_by(|&a, &b| frame[a].total_cmp(&frame[b])).unwrap();
+            assert_eq!(peak, 12);
+        }
+
+        let stats = compute.get_last_operation_stats().unwrap();
+        assert_eq!(stats.operation(), "stft_frame");
+        assert_eq!(stats.path(), "hann");
+        assert!(compute.create_stft(128, 0, None).is_err());
+        assert!(compute.create_stft(128, 32, Some("kaiser".to_string())).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parallel_for_each_chunk_matches_sequential() {
+        let data = sequence(64 * 32, 0.75);
+        let mut parallel = KatalystCompute::new();
+        parallel.set_thread_count(4);
+        let mut sequential = KatalystCompute::new();
+        sequential.set_thread_count(1);
+
+        for (op, factor) in [("normalize", None), ("scale", Some(-1.5)), ("relu", None), ("softmax-row", None)] {
+            let expected = sequential.parallel_for_each_chunk(&data, 32, op, factor).unwrap();
+            assert_eq!(sequential.get_last_operation_stats().unwrap().path(), "sequential_fallback");
+            assert_eq!(parallel.parallel_for_each_chunk(&data, 32, op, factor).unwrap(), expected, "{}", op);
+        }
+
+        assert!(parallel.parallel_for_each_chunk(&data, 31, "relu", None).is_err());
+        assert!(parallel.parallel_for_each_chunk(&data, 32, "scale", None).is_err());
+        assert!(parallel.parallel_for_each_chunk(&data, 32, "gelu", None).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_dispose_releases_resources() {
+        let mut compute = KatalystCompute::new();
+        compute.set_thread_count(4);
+        compute.parallel_for_each_chunk(&[1.0; 8], 4, "relu", None).unwrap();
+
+        let before = outstanding_buffer_bytes();
+        let buffer = compute.allocate_buffer(64).unwrap();
+        assert_eq!(outstanding_buffer_bytes(), before + 64);
+
+        // Buffers from other instances and the free function aren't this
+        // instance's leaks
+        let other = KatalystCompute::new();
+        let other_buffer = other.allocate_buffer(16).unwrap();
+        let unowned = allocate_buffer(8);
+        assert_eq!(owned_allocations(compute.id), (1, 64));
+        assert_eq!(owned_allocations(other.id), (1, 16));
+
+        compute.dispose();
+        assert!(compute.disposed());
+        assert!(compute.thread_pool.is_none());
+        assert!(compute.thread_pool().is_none());
+
+        let err = compute.matrix_multiply(&[1.0; 4], &[1.0; 4], 2, 2, 2).unwrap_err();
+        assert!(err.as_string().unwrap().contains("disposed"));
+        let (mut real, mut imag) = (vec![1.0; 4], vec![0.0; 4]);
+        assert!(compute.fft(&mut real, &mut imag, false).is_err());
+        assert!(compute.create_stft(8, 4, None).is_err());
+        compute.dispose();
+
+        // A mismatched size is refused, so the buffer stays tracked
+        deallocate_buffer(buffer, 32);
+        assert_eq!(outstanding_buffer_bytes(), before + 64);
+        deallocate_buffer(buffer, 64);
+        assert_eq!(owned_allocations(compute.id), (0, 0));
+        assert!(compute.allocate_buffer(8).is_err());
+
+        other.deallocate_buffer(other_buffer, 16);
+        deallocate_buffer(unowned, 8);
+        assert_eq!(outstanding_buffer_bytes(), before);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_k_means_f64_agrees_with_f32() {
+        let mut compute = KatalystCompute::new();
+        let dimensions = 2;
+        let data: Vec<f64> = (0..(300 * dimensions))
+            .map(|i| (i as f64 * 0.61).sin() * 3.0 + (i / (100 * dimensions)) as f64 * 40.0)
+            .collect();
+        let single: Vec<f32> = data.iter().map(|&x| x as f32).collect();
+
+        let double = compute.k_means_clustering_f64(&data, dimensions, 3, 20).unwrap();
+        assert_eq!(compute.get_last_operation_stats().unwrap().operation(), "k_means_f64");
+        assert_eq!(double, compute.k_means_clustering(&single, dimensions, 3, 20).unwrap());
+        assert!(compute.k_means_clustering_f64(&data[..5], dimensions, 3, 20).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_capability_status_separates_compiled_from_available() {
+        let available = CapabilityProbe::new(true, Ok(()));
+        assert_eq!(available.status(), "available");
+        assert!(available.error.is_none());
+
+        let blocked = CapabilityProbe::new(true, Err("no SharedArrayBuffer".to_string()));
+        assert_eq!(blocked.status(), "compiled_but_unavailable");
+        assert_eq!(blocked.to_json()["error"], "no SharedArrayBuffer");
+
+        assert_eq!(CapabilityProbe::new(false, Ok(())).status(), "available_not_compiled");
+        assert_eq!(CapabilityProbe::new(false, Err(String::new())).status(), "unavailable");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_diagnose_runtime_reports_every_feature() {
+        let report: serde_json::Value = serde_json::from_str(&diagnose_runtime()).unwrap();
+        for feature in ["simd", "threads", "bulk_memory", "webgpu"] {
+            let probe = &report[feature];
+            let available = probe["runtime_available"].as_bool().unwrap();
+            assert!(probe["compiled"].is_boolean(), "{}", feature);
+            // An unavailable feature always says why
+            assert_eq!(probe["error"].is_null(), available, "{}", feature);
+        }
+
+        // Running this test at all proves bulk memory works when compiled in
+        if cfg!(target_feature = "bulk-memory") {
+            assert_eq!(report["bulk_memory"]["status"], "available");
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parallel_k_means_matches_sequential() {
+        let mut compute = KatalystCompute::new();
+        compute.set_thread_count(4);
+        let dimensions = 3;
+        let data: Vec<f32> = (0..(600 * dimensions))
+            .map(|i| (i as f32 * 0.37).sin() * 10.0 + (i / (200 * dimensions)) as f32 * 25.0)
+            .collect();
+
+        let sequential = compute.k_means_clustering(&data, dimensions, 4, 15).unwrap();
+        let parallel = compute.k_means_clustering_parallel(&data, dimensions, 4, 15).unwrap();
+        assert_eq!(sequential, parallel);
+
+        let stats: HashMap<String, f64> = serde_json::from_str(&compute.get_performance_stats()).unwrap();
+        assert!(stats.contains_key("k_means_parallel_ms"));
+        assert!(stats["k_means_parallel_threads"] >= 1.0);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_cosine_k_means_groups_by_direction() {
+        let mut compute = KatalystCompute::new();
+        // Two directions at wildly different magnitudes; points 0 and 4 seed the centroids
+        let a = [1.0f32, 0.1, 0.0];
+        let b = [0.0f32, 0.2, 1.0];
+        let scales = [1.0f32, 50.0, 0.02, 400.0];
+        let mut data = Vec::new();
+        for direction in [a, b] {
+            for scale in scales {
+                data.extend(direction.iter().map(|v| v * scale));
+            }
+        }
+
+        let assignments = compute.k_means_clustering_with_metric(&data, 3, 2, 10, "cosine").unwrap();
+        assert!(assignments[..4].iter().all(|&c| c == assignments[0]));
+        assert!(assignments[4..].iter().all(|&c| c == assignments[4]));
+        assert_ne!(assignments[0], assignments[4]);
+
+        // Euclidean distance lumps the large-magnitude points together instead
+        let euclidean = compute.k_means_clustering_with_metric(&data, 3, 2, 10, "euclidean").unwrap();
+        assert_ne!(euclidean, assignments);
+        assert_eq!(euclidean, compute.k_means_clustering(&data, 3, 2, 10).unwrap());
+
+        assert!(compute.k_means_clustering_with_metric(&data, 3, 2, 10, "chebyshev").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_manhattan_k_means_uses_medians() {
+        let mut compute = KatalystCompute::new();
+        // Two well separated 1D groups, seeded from points 0 and 5
+        let data = [0.0f32, 1.0, 2.0, 1.5, 0.5, 30.0, 31.0, 32.0, 30.5, 31.5];
+        let assignments = compute.k_means_clustering_with_metric(&data, 1, 2, 10, "manhattan").unwrap();
+
+        assert!(assignments[..5].iter().all(|&c| c == assignments[0]));
+        assert!(assignments[5..].iter().all(|&c| c == assignments[5]));
+        assert_ne!(assignments[0], assignments[5]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_k_means_inertia_is_non_increasing() {
+        let mut compute = KatalystCompute::new();
+        let dimensions = 2;
+        let data: Vec<f32> = (0..(300 * dimensions))
+            .map(|i| (i as f32 * 0.61).sin() * 4.0 + (i % 3) as f32 * 9.0)
+            .collect();
+
+        for metric in ["euclidean", "manhattan", "cosine"] {
+            let result = compute.k_means_with_diagnostics(&data, dimensions, 3, 50, Some(metric.to_string())).unwrap();
+            let history = result.inertia_history();
+
+            assert_eq!(history.len(), result.iterations());
+            assert!(result.iterations() <= 50);
+            for pair in history.windows(2) {
+                assert!(pair[1] <= pair[0] * (1.0 + 1e-5), "{} inertia rose: {:?}", metric, history);
+            }
+            assert_eq!(result.inertia(), *history.last().unwrap());
+        }
+
+        let result = compute.k_means_with_diagnostics(&data, dimensions, 3, 50, None).unwrap();
+        assert!(result.converged());
+        assert_eq!(result.assignments(), compute.k_means_clustering(&data, dimensions, 3, 50).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_last_operation_stats_and_clear() {
+        let mut compute = KatalystCompute::new();
+        assert!(compute.get_last_operation_stats().is_none());
+
+        compute.matrix_multiply(&[1.0; 6], &[2.0; 6], 2, 3, 2).unwrap();
+        let stats = compute.get_last_operation_stats().unwrap();
+        assert_eq!(stats.operation(), "matrix_multiply");
+        assert_eq!(stats.input_size(), 12);
+        assert!(stats.duration_ms() >= 0.0);
+
+        let mut real = vec![1.0f32; 8];
+        let mut imag = vec![0.0f32; 8];
+        compute.fft(&mut real, &mut imag, false).unwrap();
+        let stats = compute.get_last_operation_stats().unwrap();
+        assert_eq!(stats.operation(), "fft");
+        assert_eq!(stats.path(), "radix2");
+        assert_eq!(stats.threads(), 1);
+        assert!(stats.to_json().contains("\"operation\":\"fft\""));
+
+        compute.clear_stats();
+        assert!(compute.get_last_operation_stats().is_none());
+        assert_eq!(compute.get_performance_stats(), "{}");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_bad_shapes_leave_instance_usable() {
+        let mut compute = KatalystCompute::new();
+
+        assert!(compute.matrix_multiply(&[1.0; 5], &[1.0; 6], 2, 3, 2).is_err());
+        assert_eq!(compute.matrix_multiply(&[1.0; 6], &[2.0; 6], 2, 3, 2).unwrap(), vec![6.0; 4]);
+
+        let (mut real, mut imag) = (vec![1.0; 8], vec![0.0; 4]);
+        assert!(compute.fft(&mut real, &mut imag, false).is_err());
+        let (mut real, mut imag) = (vec![1.0; 4], vec![0.0; 4]);
+        compute.fft(&mut real, &mut imag, false).unwrap();
+        assert_close(&real, &[4.0, 0.0, 0.0, 0.0]);
+
+        let data = [0.0, 0.0, 10.0, 10.0];
+        assert!(compute.k_means_clustering(&[], 2, 1, 10).is_err());
+        assert!(compute.k_means_clustering(&data, 0, 1, 10).is_err());
+        assert!(compute.k_means_clustering(&data, 3, 1, 10).is_err());
+        assert!(compute.k_means_clustering(&data, 2, 0, 10).is_err());
+        assert!(compute.k_means_clustering_parallel(&data, 2, 3, 10).is_err());
+        assert!(compute.k_means_with_diagnostics(&data, 2, 3, 10, None).is_err());
+        assert_eq!(compute.k_means_clustering(&data, 2, 2, 10).unwrap(), vec![0, 1]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_matrix_multiply_auto_small_product_uses_cpu() {
+        let mut compute = KatalystCompute::new();
+        let promise = compute.matrix_multiply_auto(vec![1.0; 6], vec![2.0; 6], 2, 3, 2).unwrap();
+        let result = Float32Array::from(wasm_bindgen_futures::JsFuture::from(promise).await.unwrap()).to_vec();
+        assert_eq!(result, vec![6.0; 4]);
+        assert_eq!(compute.get_last_operation_stats().unwrap().path(), "ndarray");
+        assert!(compute.matrix_multiply_auto(vec![1.0; 5], vec![2.0; 6], 2, 3, 2).is_err());
+    }
+
+    #[cfg(feature = "webgpu")]
+    #[wasm_bindgen_test]
+    async fn test_webgpu_matmul_matches_cpu() {
+        let mut compute = KatalystCompute::new();
+        let attached = wasm_bindgen_futures::JsFuture::from(compute.enable_webgpu()).await.unwrap();
+        if !attached.as_bool().unwrap_or(false) {
+            // No adapter in this browser; the CPU path is covered elsewhere
+            return;
+        }
+
+        // Odd sizes exercise the partial tiles at the edges
+        let (m, k, n) = (70, 65, 67);
+        let a = sequence(m * k, 0.25);
+        let b = sequence(k * n, 0.5);
+        let expected = compute.matrix_multiply(&a, &b, m, k, n).unwrap();
+
+        let promise = compute.matrix_multiply_auto(a, b, m, k, n).unwrap();
+        let result = Float32Array::from(wasm_bindgen_futures::JsFuture::from(promise).await.unwrap()).to_vec();
+        assert_eq!(compute.get_last_operation_stats().unwrap().path(), "webgpu");
+        assert_close(&result, &expected);
+    }
+}
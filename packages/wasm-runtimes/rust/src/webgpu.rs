@@ -0,0 +1,184 @@
+//! WebGPU matrix multiply. Operands are uploaded to storage buffers, a tiled
+//! WGSL compute shader produces the product and the result is copied into a
+//! mappable buffer for readback.
+//!
+//! The `web_sys` WebGPU bindings are unstable, so building with the `webgpu`
+//! feature also needs `RUSTFLAGS=--cfg=web_sys_unstable_apis`.
+
+use js_sys::{Array, Float32Array, Reflect, Uint32Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    GpuAdapter, GpuBindGroupDescriptor, GpuBindGroupEntry, GpuBuffer, GpuBufferBinding,
+    GpuBufferDescriptor, GpuComputePipeline, GpuComputePipelineDescriptor, GpuDevice,
+    GpuProgrammableStage, GpuShaderModuleDescriptor,
+};
+
+// GPUBufferUsage and GPUMapMode flag values from the WebGPU spec
+const USAGE_MAP_READ: u32 = 0x0001;
+const USAGE_COPY_SRC: u32 = 0x0004;
+const USAGE_COPY_DST: u32 = 0x0008;
+const USAGE_UNIFORM: u32 = 0x0040;
+const USAGE_STORAGE: u32 = 0x0080;
+const MAP_MODE_READ: u32 = 0x0001;
+
+/// Edge length of the square tiles each workgroup stages in shared memory
+const TILE: u32 = 16;
+
+const MATMUL_SHADER: &str = r#"
+struct Dims {
+    m: u32,
+    k: u32,
+    n: u32,
+    _pad: u32,
+}
+
+@group(0) @binding(0) var<storage, read> a: array<f32>;
+@group(0) @binding(1) var<storage, read> b: array<f32>;
+@group(0) @binding(2) var<storage, read_write> c: array<f32>;
+@group(0) @binding(3) var<uniform> dims: Dims;
+
+const TILE: u32 = 16u;
+var<workgroup> tile_a: array<f32, 256>;
+var<workgroup> tile_b: array<f32, 256>;
+
+@compute @workgroup_size(16, 16)
+fn main(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+    @builtin(local_invocation_id) lid: vec3<u32>,
+) {
+    let row = gid.y;
+    let col = gid.x;
+    let tiles = (dims.k + TILE - 1u) / TILE;
+    var sum = 0.0;
+
+    for (var t = 0u; t < tiles; t = t + 1u) {
+        let a_col = t * TILE + lid.x;
+        let b_row = t * TILE + lid.y;
+        var a_value = 0.0;
+        var b_value = 0.0;
+        if (row < dims.m && a_col < dims.k) {
+            a_value = a[row * dims.k + a_col];
+        }
+        if (b_row < dims.k && col < dims.n) {
+            b_value = b[b_row * dims.n + col];
+        }
+        tile_a[lid.y * TILE + lid.x] = a_value;
+        tile_b[lid.y * TILE + lid.x] = b_value;
+        workgroupBarrier();
+
+        for (var i = 0u; i < TILE; i = i + 1u) {
+            sum = sum + tile_a[lid.y * TILE + i] * tile_b[i * TILE + lid.x];
+        }
+        workgroupBarrier();
+    }
+
+    if (row < dims.m && col < dims.n) {
+        c[row * dims.n + col] = sum;
+    }
+}
+"#;
+
+/// Whether the host exposes `navigator.gpu`. This only says WebGPU may be
+/// usable; adapter or device creation can still fail.
+pub fn is_supported() -> bool {
+    web_sys::window()
+        .and_then(|window| Reflect::get(&window.navigator(), &JsValue::from_str("gpu")).ok())
+        .map_or(false, |gpu| !gpu.is_undefined() && !gpu.is_null())
+}
+
+/// A GPU device with the matmul pipeline compiled, reused across calls
+pub struct GpuMatmul {
+    device: GpuDevice,
+    pipeline: GpuComputePipeline,
+}
+
+impl GpuMatmul {
+    /// Request an adapter and device and compile the shader. Fails when
+    /// WebGPU is missing or no adapter is available.
+    pub async fn new() -> Result<GpuMatmul, JsValue> {
+        if !is_supported() {
+            return Err(JsValue::from_str("WebGPU is not available"));
+        }
+        let window = web_sys::window().ok_or_else(|| JsValue::from_str("No window"))?;
+
+        let adapter = JsFuture::from(window.navigator().gpu().request_adapter()).await?;
+        if adapter.is_null() || adapter.is_undefined() {
+            return Err(JsValue::from_str("No WebGPU adapter available"));
+        }
+        let adapter: GpuAdapter = adapter.dyn_into()?;
+        let device: GpuDevice = JsFuture::from(adapter.request_device()).await?.dyn_into()?;
+
+        let module = device.create_shader_module(&GpuShaderModuleDescriptor::new(MATMUL_SHADER));
+        let stage = GpuProgrammableStage::new(&module);
+        stage.set_entry_point("main");
+        let pipeline = device.create_compute_pipeline(&GpuComputePipelineDescriptor::new(&JsValue::from_str("auto"), &stage));
+
+        Ok(GpuMatmul { device, pipeline })
+    }
+
+    /// Row-major `m x k` times `k x n` product. Shapes are assumed to have
+    /// been validated by the caller.
+    pub async fn multiply(&self, a: &[f32], b: &[f32], m: usize, k: usize, n: usize) -> Result<Vec<f32>, JsValue> {
+        let output_bytes = (m * n * 4) as u32;
+
+        let a_buffer = self.upload_f32(a, USAGE_STORAGE)?;
+        let b_buffer = self.upload_f32(b, USAGE_STORAGE)?;
+        let dims_buffer = self.upload_u32(&[m as u32, k as u32, n as u32, 0], USAGE_UNIFORM)?;
+        let c_buffer = self.create_buffer(output_bytes, USAGE_STORAGE | USAGE_COPY_SRC, false)?;
+        let readback = self.create_buffer(output_bytes, USAGE_MAP_READ | USAGE_COPY_DST, false)?;
+
+        let entries = Array::new();
+        for (binding, buffer) in [&a_buffer, &b_buffer, &c_buffer, &dims_buffer].into_iter().enumerate() {
+            entries.push(&GpuBindGroupEntry::new(binding as u32, &GpuBufferBinding::new(buffer)));
+        }
+        let layout = self.pipeline.get_bind_group_layout(0);
+        let bind_group = self.device.create_bind_group(&GpuBindGroupDescriptor::new(&entries, &layout));
+
+        let encoder = self.device.create_command_encoder();
+        let pass = encoder.begin_compute_pass();
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, Some(&bind_group));
+        pass.dispatch_workgroups_with_workgroup_count_y((n as u32).div_ceil(TILE), (m as u32).div_ceil(TILE));
+        pass.end();
+        encoder.copy_buffer_to_buffer_with_u32_and_u32_and_u32(&c_buffer, 0, &readback, 0, output_bytes)?;
+        self.device.queue().submit(&Array::of1(&encoder.finish()));
+
+        JsFuture::from(readback.map_async(MAP_MODE_READ)).await?;
+        let result = Float32Array::new(&readback.get_mapped_range()?).to_vec();
+        readback.unmap();
+
+        for buffer in [a_buffer, b_buffer, c_buffer, dims_buffer, readback] {
+            buffer.destroy();
+        }
+
+        Ok(result)
+    }
+
+    /// Release the device; any later `multiply` fails
+    pub fn destroy(&self) {
+        self.device.destroy();
+    }
+
+    fn create_buffer(&self, size: u32, usage: u32, mapped: bool) -> Result<GpuBuffer, JsValue> {
+        // Buffer sizes must be non-zero multiples of four bytes
+        let descriptor = GpuBufferDescriptor::new(size.max(4) as f64, usage);
+        descriptor.set_mapped_at_creation(mapped);
+        self.device.create_buffer(&descriptor)
+    }
+
+    fn upload_f32(&self, data: &[f32], usage: u32) -> Result<GpuBuffer, JsValue> {
+        let buffer = self.create_buffer((data.len() * 4) as u32, usage, true)?;
+        Float32Array::new(&buffer.get_mapped_range()?).subarray(0, data.len() as u32).copy_from(data);
+        buffer.unmap();
+        Ok(buffer)
+    }
+
+    fn upload_u32(&self, data: &[u32], usage: u32) -> Result<GpuBuffer, JsValue> {
+        let buffer = self.create_buffer((data.len() * 4) as u32, usage, true)?;
+        Uint32Array::new(&buffer.get_mapped_range()?).subarray(0, data.len() as u32).copy_from(data);
+        buffer.unmap();
+        Ok(buffer)
+    }
+}
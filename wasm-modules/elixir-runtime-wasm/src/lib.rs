@@ -2,7 +2,15 @@
 //! 
 //! This crate provides WASM bindings for Phoenix LiveView and GenServer functionality.
 
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use js_sys::{Function, Promise};
+use katalyst_phoenix_reconnect::ReconnectPolicy;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::WebSocket;
 
 // Re-export the Elixir runtime WASM interface
 pub use katalyst_elixir_runtime::WasmElixirRuntime;
@@ -13,11 +21,199 @@ pub fn main() {
     console_error_panic_hook::set_once();
 }
 
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &Function, timeout_ms: i32) -> JsValue;
+
+    #[wasm_bindgen(js_name = clearTimeout)]
+    fn clear_timeout(handle: &JsValue);
+}
+
+/// Event handlers attached to the live WebSocket. They are kept here rather
+/// than leaked with `forget` so they are dropped together with the socket.
+struct SocketHandlers {
+    _onopen: Closure<dyn FnMut(JsValue)>,
+    _onerror: Closure<dyn FnMut(JsValue)>,
+    _onclose: Closure<dyn FnMut(JsValue)>,
+}
+
+/// Connection state shared between `PhoenixSocket` and the WebSocket and
+/// timer callbacks, which hold it weakly
+struct SocketInner {
+    url: String,
+    socket: RefCell<Option<WebSocket>>,
+    handlers: RefCell<Option<SocketHandlers>>,
+    ref_counter: Cell<u64>,
+    /// Joined topics and their join payloads, replayed after a reconnect
+    joined: RefCell<BTreeMap<String, serde_json::Value>>,
+    policy: Cell<ReconnectPolicy>,
+    /// Set once a connection opens; only established connections reconnect
+    established: Cell<bool>,
+    attempts: Cell<u32>,
+    reconnect_timer: RefCell<Option<(JsValue, Closure<dyn FnMut()>)>>,
+    on_reconnect_failed: RefCell<Option<Function>>,
+}
+
+impl SocketInner {
+    /// Open a WebSocket to `url`. `settle` is the resolve/reject pair of a
+    /// pending `connect` promise; reconnect attempts pass `None`.
+    fn open(self: &Rc<Self>, settle: Option<(Function, Function)>) -> Result<(), JsValue> {
+        let socket = WebSocket::new(&self.url)?;
+        let (resolve, reject) = match settle {
+            Some((resolve, reject)) => (Some(resolve), Some(reject)),
+            None => (None, None),
+        };
+
+        let weak = Rc::downgrade(self);
+        let onopen = Closure::wrap(Box::new(move |_event: JsValue| {
+            if let Some(inner) = weak.upgrade() {
+                inner.established.set(true);
+                inner.attempts.set(0);
+                inner.rejoin();
+            }
+            if let Some(resolve) = &resolve {
+                let _ = resolve.call0(&JsValue::NULL);
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+
+        let error_reject = reject.clone();
+        let error_url = self.url.clone();
+        let onerror = Closure::wrap(Box::new(move |_event: JsValue| {
+            if let Some(reject) = &error_reject {
+                let error = JsValue::from_str(&format!("WebSocket connection to {} failed", error_url));
+                let _ = reject.call1(&JsValue::NULL, &error);
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+
+        // A close before open also fails a pending connect; once the promise
+        // has settled further rejections are ignored
+        let weak = Rc::downgrade(self);
+        let close_url = self.url.clone();
+        let onclose = Closure::wrap(Box::new(move |_event: JsValue| {
+            if let Some(reject) = &reject {
+                let error = JsValue::from_str(&format!("WebSocket connection to {} closed", close_url));
+                let _ = reject.call1(&JsValue::NULL, &error);
+            }
+            if let Some(inner) = weak.upgrade() {
+                if inner.established.get() {
+                    inner.schedule_reconnect();
+                }
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+
+        socket.set_onopen(Some(onopen.as_ref().unchecked_ref()));
+        socket.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(onclose.as_ref().unchecked_ref()));
+
+        self.detach();
+        *self.socket.borrow_mut() = Some(socket);
+        *self.handlers.borrow_mut() = Some(SocketHandlers {
+            _onopen: onopen,
+            _onerror: onerror,
+            _onclose: onclose,
+        });
+
+        Ok(())
+    }
+
+    /// Schedule the next reconnect attempt, or give up once the policy's
+    /// attempts are exhausted
+    fn schedule_reconnect(self: &Rc<Self>) {
+        let policy = self.policy.get();
+        let attempt = self.attempts.get() + 1;
+
+        if attempt > policy.max_attempts {
+            self.established.set(false);
+            self.socket.borrow_mut().take();
+            // Release the borrow first so the callback can register a new one
+            let callback = self.on_reconnect_failed.borrow().clone();
+            if let Some(callback) = callback {
+                let _ = callback.call1(&JsValue::NULL, &JsValue::from(self.attempts.get()));
+            }
+            return;
+        }
+
+        self.attempts.set(attempt);
+        let weak = Rc::downgrade(self);
+        let callback = Closure::wrap(Box::new(move || {
+            if let Some(inner) = weak.upgrade() {
+                if inner.open(None).is_err() {
+                    inner.schedule_reconnect();
+                }
+            }
+        }) as Box<dyn FnMut()>);
+
+        let delay = policy.delay_ms(attempt, js_sys::Math::random());
+        let handle = set_timeout(callback.as_ref().unchecked_ref(), delay as i32);
+
+        self.cancel_reconnect();
+        *self.reconnect_timer.borrow_mut() = Some((handle, callback));
+    }
+
+    fn cancel_reconnect(&self) {
+        if let Some((handle, _callback)) = self.reconnect_timer.borrow_mut().take() {
+            clear_timeout(&handle);
+        }
+    }
+
+    /// Close the current WebSocket without triggering a reconnect
+    fn detach(&self) {
+        if let Some(socket) = self.socket.borrow_mut().take() {
+            socket.set_onopen(None);
+            socket.set_onerror(None);
+            socket.set_onclose(None);
+            let _ = socket.close();
+        }
+        self.handlers.borrow_mut().take();
+    }
+
+    fn disconnect(&self) {
+        self.established.set(false);
+        self.attempts.set(0);
+        self.cancel_reconnect();
+        self.detach();
+    }
+
+    fn rejoin(&self) {
+        let joined = self.joined.borrow().clone();
+        for (topic, payload) in joined {
+            let join_ref = self.next_ref();
+            let _ = self.send_frame(Some(&join_ref), &join_ref, &topic, "phx_join", payload);
+        }
+    }
+
+    fn next_ref(&self) -> String {
+        let message_ref = self.ref_counter.get() + 1;
+        self.ref_counter.set(message_ref);
+        message_ref.to_string()
+    }
+
+    /// Send a Phoenix V2 frame `[join_ref, ref, topic, event, payload]`
+    fn send_frame(
+        &self,
+        join_ref: Option<&str>,
+        message_ref: &str,
+        topic: &str,
+        event: &str,
+        payload: serde_json::Value,
+    ) -> Result<(), JsValue> {
+        let socket = self.socket.borrow();
+        let socket = socket
+            .as_ref()
+            .filter(|socket| socket.ready_state() == WebSocket::OPEN)
+            .ok_or_else(|| JsValue::from_str("Socket is not connected"))?;
+
+        let frame = serde_json::json!([join_ref, message_ref, topic, event, payload]);
+        socket.send_with_str(&frame.to_string())
+    }
+}
+
 // Phoenix LiveView utilities for WASM
 #[wasm_bindgen]
 pub struct PhoenixSocket {
     endpoint: String,
-    params: String,
+    inner: Rc<SocketInner>,
 }
 
 #[wasm_bindgen]
@@ -26,19 +222,151 @@ impl PhoenixSocket {
     pub fn new(endpoint: &str, params: &str) -> PhoenixSocket {
         PhoenixSocket {
             endpoint: endpoint.to_string(),
-            params: params.to_string(),
+            inner: Rc::new(SocketInner {
+                url: socket_url(endpoint, params),
+                socket: RefCell::new(None),
+                handlers: RefCell::new(None),
+                ref_counter: Cell::new(0),
+                joined: RefCell::new(BTreeMap::new()),
+                policy: Cell::new(ReconnectPolicy::default()),
+                established: Cell::new(false),
+                attempts: Cell::new(0),
+                reconnect_timer: RefCell::new(None),
+                on_reconnect_failed: RefCell::new(None),
+            }),
         }
     }
 
+    /// Open the WebSocket, resolving once the server accepts the connection
+    /// and rejecting if it errors or closes first
     #[wasm_bindgen(js_name = connect)]
     pub async fn connect(&self) -> Result<String, JsValue> {
-        // In a real implementation, this would establish a WebSocket connection
+        self.inner.disconnect();
+
+        let mut opened = Ok(());
+        let promise = Promise::new(&mut |resolve: Function, reject: Function| {
+            opened = self.inner.open(Some((resolve, reject)));
+        });
+        opened?;
+
+        if let Err(error) = JsFuture::from(promise).await {
+            self.inner.disconnect();
+            return Err(error);
+        }
+
         Ok(format!("Connected to {}", self.endpoint))
     }
 
     #[wasm_bindgen(js_name = disconnect)]
     pub fn disconnect(&self) {
-        // Implementation for disconnecting
+        self.inner.disconnect();
+    }
+
+    #[wasm_bindgen(js_name = isConnected)]
+    pub fn is_connected(&self) -> bool {
+        self.inner
+            .socket
+            .borrow()
+            .as_ref()
+            .map(|socket| socket.ready_state() == WebSocket::OPEN)
+            .unwrap_or(false)
+    }
+
+    /// Configure reconnection after an unexpected close. Delays double from
+    /// `base_ms` up to `max_ms`, with up to `jitter` (0.0-1.0) of each delay
+    /// randomised; `max_attempts` of 0 disables reconnection.
+    #[wasm_bindgen(js_name = setReconnectPolicy)]
+    pub fn set_reconnect_policy(&self, base_ms: u32, max_ms: u32, jitter: f64, max_attempts: u32) {
+        self.inner.policy.set(ReconnectPolicy::new(base_ms, max_ms, jitter, max_attempts));
+    }
+
+    /// Register a callback invoked with the attempt count when reconnection gives up
+    #[wasm_bindgen(js_name = onReconnectFailed)]
+    pub fn on_reconnect_failed(&self, callback: Function) {
+        *self.inner.on_reconnect_failed.borrow_mut() = Some(callback);
+    }
+
+    /// Reconnect attempts made since the connection last dropped
+    #[wasm_bindgen(getter, js_name = reconnectAttempts)]
+    pub fn reconnect_attempts(&self) -> u32 {
+        self.inner.attempts.get()
+    }
+
+    /// Join a topic, returning the join ref. Joined topics are re-joined
+    /// automatically after a reconnect.
+    #[wasm_bindgen(js_name = join)]
+    pub fn join(&self, topic: &str, payload: &str) -> Result<String, JsValue> {
+        let payload = parse_payload(payload)?;
+        let join_ref = self.inner.next_ref();
+        self.inner
+            .send_frame(Some(&join_ref), &join_ref, topic, "phx_join", payload.clone())?;
+        self.inner.joined.borrow_mut().insert(topic.to_string(), payload);
+        Ok(join_ref)
+    }
+
+    #[wasm_bindgen(js_name = leave)]
+    pub fn leave(&self, topic: &str) -> Result<String, JsValue> {
+        self.inner.joined.borrow_mut().remove(topic);
+        let message_ref = self.inner.next_ref();
+        self.inner
+            .send_frame(None, &message_ref, topic, "phx_leave", serde_json::json!({}))?;
+        Ok(message_ref)
+    }
+
+    /// Send an event as a Phoenix V2 frame `[join_ref, ref, topic, event, payload]`,
+    /// returning the message ref
+    #[wasm_bindgen(js_name = push)]
+    pub fn push(&self, topic: &str, event: &str, payload: &str) -> Result<String, JsValue> {
+        let payload = parse_payload(payload)?;
+        let message_ref = self.inner.next_ref();
+        self.inner.send_frame(None, &message_ref, topic, event, payload)?;
+        Ok(message_ref)
+    }
+}
+
+impl Drop for PhoenixSocket {
+    fn drop(&mut self) {
+        self.inner.disconnect();
+    }
+}
+
+fn parse_payload(payload: &str) -> Result<serde_json::Value, JsValue> {
+    serde_json::from_str(payload).map_err(|e| JsValue::from_str(&format!("Invalid payload JSON: {}", e)))
+}
+
+/// Append connection params to the endpoint as a query string. Params may be
+/// a JSON object or an already encoded query string.
+fn socket_url(endpoint: &str, params: &str) -> String {
+    let params = params.trim();
+    if params.is_empty() {
+        return endpoint.to_string();
+    }
+
+    let query = match serde_json::from_str::<serde_json::Value>(params) {
+        Ok(serde_json::Value::Object(map)) => map
+            .iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    serde_json::Value::String(value) => value.clone(),
+                    other => other.to_string(),
+                };
+                format!(
+                    "{}={}",
+                    String::from(js_sys::encode_uri_component(key)),
+                    String::from(js_sys::encode_uri_component(&value))
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("&"),
+        _ => params.trim_start_matches('?').to_string(),
+    };
+
+    if query.is_empty() {
+        endpoint.to_string()
+    } else if endpoint.contains('?') {
+        format!("{}&{}", endpoint, query)
+    } else {
+        format!("{}?{}", endpoint, query)
     }
 }
 
@@ -135,4 +463,97 @@ pub fn get_elixir_runtime_info() -> String {
         "features": ["phoenix_liveview", "genserver", "channels"],
         "target": "wasm32-unknown-unknown"
     }).to_string()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use katalyst_phoenix_reconnect::mock_websocket::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn connect_resolves_on_open() {
+        install_mock_websocket(false);
+        let socket = PhoenixSocket::new("ws://localhost:4000/socket/websocket", r#"{"token":"a b"}"#);
+
+        let reply = socket.connect().await.unwrap();
+        assert_eq!(reply, "Connected to ws://localhost:4000/socket/websocket");
+        assert_eq!(last_mock_url(), "ws://localhost:4000/socket/websocket?token=a%20b");
+        assert!(socket.is_connected());
+
+        let message_ref = socket.push("room:lobby", "shout", r#"{"body":"hi"}"#).unwrap();
+        assert_eq!(message_ref, "1");
+        assert_eq!(
+            last_mock_sent(),
+            r#"["[null,\"1\",\"room:lobby\",\"shout\",{\"body\":\"hi\"}]"]"#
+        );
+
+        socket.disconnect();
+        assert!(!socket.is_connected());
+        assert!(socket.push("room:lobby", "shout", "{}").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn connect_rejects_on_error() {
+        install_mock_websocket(true);
+        let socket = PhoenixSocket::new("ws://localhost:4000/socket/websocket", "");
+
+        assert!(socket.connect().await.is_err());
+        assert!(!socket.is_connected());
+    }
+
+    #[wasm_bindgen_test]
+    async fn dropped_connection_reconnects_and_rejoins() {
+        install_mock_websocket(false);
+        let socket = PhoenixSocket::new("ws://localhost:4000/socket/websocket", "");
+        socket.set_reconnect_policy(20, 100, 0.0, 3);
+        socket.connect().await.unwrap();
+        socket.join("room:lobby", r#"{"user":"alice"}"#).unwrap();
+
+        drop_last_mock();
+        assert!(!socket.is_connected());
+        assert_eq!(socket.reconnect_attempts(), 1);
+
+        // Nothing happens before the backoff delay elapses
+        sleep(5).await;
+        assert_eq!(mock_socket_count(), 1);
+
+        sleep(60).await;
+        assert_eq!(mock_socket_count(), 2);
+        assert!(socket.is_connected());
+        assert_eq!(socket.reconnect_attempts(), 0);
+        assert_eq!(
+            last_mock_sent(),
+            r#"["[\"2\",\"2\",\"room:lobby\",\"phx_join\",{\"user\":\"alice\"}]"]"#
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn reconnect_gives_up_after_max_attempts() {
+        install_mock_websocket(false);
+        let socket = PhoenixSocket::new("ws://localhost:4000/socket/websocket", "");
+        socket.set_reconnect_policy(5, 5, 0.0, 2);
+
+        // The handler replaces itself, which needs the registration borrow released
+        let gave_up = Rc::new(Cell::new(0u32));
+        let on_failed = {
+            let gave_up = gave_up.clone();
+            let inner = socket.inner.clone();
+            Closure::wrap(Box::new(move |attempts: u32| {
+                gave_up.set(attempts);
+                inner.on_reconnect_failed.borrow_mut().take();
+            }) as Box<dyn FnMut(u32)>)
+        };
+        socket.on_reconnect_failed(on_failed.as_ref().unchecked_ref::<Function>().clone());
+
+        socket.connect().await.unwrap();
+        set_mock_fail(true);
+        drop_last_mock();
+
+        sleep(100).await;
+        assert_eq!(gave_up.get(), 2);
+        assert_eq!(mock_socket_count(), 3);
+        assert!(!socket.is_connected());
+        assert!(socket.inner.on_reconnect_failed.borrow().is_none());
+    }
+}